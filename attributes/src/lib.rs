@@ -0,0 +1,211 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Attributes recognized by cargo-quality.
+//!
+//! The attributes expand to the annotated item unchanged; they exist so that
+//! cargo-quality can read them from the source, while the compiler checks
+//! their spelling. Depend on the crate under the name `quality` to use the
+//! paths cargo-quality looks for:
+//!
+//! ```toml
+//! [dependencies]
+//! quality = { package = "cargo-quality-attributes", version = "0.1" }
+//! ```
+//!
+//! - [`allow`] - `#[quality::allow(rule, ...)]` suppresses the named analyzers
+//!   inside the item
+//! - [`generated`] - `#[quality::generated]` exempts generated code from the
+//!   `empty_lines` and `function_length` analyzers
+
+use proc_macro::{Span, TokenStream, TokenTree};
+
+/// Analyzer names accepted by [`allow`].
+const RULES: &[&str] = &[
+    "allow_audit",
+    "api_types",
+    "argument_order",
+    "assert_message",
+    "async_blocking",
+    "bool_result",
+    "clone_in_loop",
+    "conversion_impls",
+    "data_integrity",
+    "debug_artifacts",
+    "default_impl",
+    "derive_order",
+    "discarded_result",
+    "doc_width",
+    "duplicate_literals",
+    "empty_lines",
+    "error_convention",
+    "error_display",
+    "error_sources",
+    "file_length",
+    "format_args",
+    "function_length",
+    "glob_import",
+    "global_state",
+    "hardcoded_paths",
+    "hardcoded_secrets",
+    "impl_location",
+    "import_order",
+    "inline_comments",
+    "item_order",
+    "license_header",
+    "line_length",
+    "mergeable_impls",
+    "missing_debug",
+    "missing_docs",
+    "missing_errors_doc",
+    "missing_inline",
+    "missing_instrument",
+    "missing_must_use",
+    "missing_panics_doc",
+    "naming",
+    "needless_allocation",
+    "nested_closures",
+    "nesting_depth",
+    "non_exhaustive_errors",
+    "numeric_cast",
+    "numeric_overflow",
+    "path_import",
+    "placeholder_docs",
+    "predicate_naming",
+    "print_logging",
+    "pub_fields",
+    "redundant_imports",
+    "redundant_syntax",
+    "required_debug",
+    "sensitive_debug",
+    "shadowing",
+    "sharing_complexity",
+    "sql_injection",
+    "stringly_typed",
+    "super_imports",
+    "test_assertions",
+    "test_modules",
+    "time_source",
+    "todo_comments",
+    "trait_bounds",
+    "type_size",
+    "unbounded_queue",
+    "unbounded_recursion",
+    "unsafe_docs",
+    "unwrap",
+    "variant_names",
+    "whitespace",
+    "wildcard_reexport"
+];
+
+/// Suppresses analyzers inside an item.
+///
+/// Takes a comma-separated list of analyzer names. Issues and fixes of those
+/// analyzers located inside the annotated function, impl block, module or
+/// other item are dropped. Unknown names are compile errors, so a typo
+/// cannot silently disable a suppression.
+///
+/// # Examples
+///
+/// ```
+/// use cargo_quality_attributes as quality;
+///
+/// #[quality::allow(unwrap, empty_lines)]
+/// fn parse(input: &str) -> u32 {
+///     let value = input.parse().unwrap();
+///
+///     value
+/// }
+///
+/// assert_eq!(parse("7"), 7);
+/// ```
+///
+/// ```compile_fail
+/// use cargo_quality_attributes as quality;
+///
+/// #[quality::allow(unwarp)]
+/// fn parse(input: &str) -> u32 {
+///     input.parse().unwrap()
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn allow(args: TokenStream, item: TokenStream) -> TokenStream {
+    let mut output = TokenStream::new();
+    let mut expect_rule = true;
+    let mut rules = 0;
+
+    for token in args {
+        match token {
+            TokenTree::Ident(ident) if expect_rule => {
+                let rule = ident.to_string();
+                if !RULES.contains(&rule.as_str()) {
+                    output.extend(error(
+                        ident.span(),
+                        &format!("unknown cargo-quality rule `{rule}`")
+                    ));
+                }
+                expect_rule = false;
+                rules += 1;
+            }
+            TokenTree::Punct(punct) if !expect_rule && punct.as_char() == ',' => {
+                expect_rule = true;
+            }
+            other => {
+                output.extend(error(
+                    other.span(),
+                    "expected a comma-separated list of cargo-quality rule names"
+                ));
+                break;
+            }
+        }
+    }
+    if rules == 0 {
+        output.extend(error(
+            Span::call_site(),
+            "expected at least one cargo-quality rule name"
+        ));
+    }
+
+    output.extend(item);
+    output
+}
+
+/// Marks an item as generated code.
+///
+/// The `empty_lines` and `function_length` analyzers skip functions, impl
+/// blocks and modules annotated with `#[quality::generated]`, e.g. large
+/// state machine `match`es emitted by a code generator.
+///
+/// # Examples
+///
+/// ```
+/// use cargo_quality_attributes as quality;
+///
+/// #[quality::generated]
+/// fn step(state: u8) -> u8 {
+///     match state {
+///         0 => 1,
+///
+///         _ => 0
+///     }
+/// }
+///
+/// assert_eq!(step(0), 1);
+/// ```
+#[proc_macro_attribute]
+pub fn generated(_args: TokenStream, item: TokenStream) -> TokenStream {
+    item
+}
+
+/// Build a `compile_error!` invocation pointing at a span.
+fn error(span: Span, message: &str) -> TokenStream {
+    format!("::core::compile_error!({message:?});")
+        .parse::<TokenStream>()
+        .unwrap_or_default()
+        .into_iter()
+        .map(|mut token| {
+            token.set_span(span);
+            token
+        })
+        .collect()
+}
@@ -0,0 +1,69 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use std::{fs, hint::black_box};
+
+use cargo_quality::{
+    analyzers::get_analyzers,
+    differ::{DiffResult, generate_diff, generate_diffs}
+};
+use criterion::{Criterion, criterion_group, criterion_main};
+use tempfile::TempDir;
+
+const FILES: usize = 1000;
+
+fn write_fixtures() -> (TempDir, Vec<String>) {
+    let temp_dir = TempDir::new().unwrap();
+    let files = (0..FILES)
+        .map(|index| {
+            let path = temp_dir.path().join(format!("module_{index}.rs"));
+            let code = format!(
+                r#"
+                use std::collections::HashMap;
+
+                pub fn load_{index}(path: &str) -> HashMap<String, String> {{
+                    let content = std::fs::read_to_string(path).unwrap();
+                    let mut map = HashMap::new();
+                    for line in content.lines() {{
+                        let parts: Vec<&str> = line.split('=').collect();
+                        println!("{{}} = {{}}", parts[0], parts[1]);
+                        map.insert(parts[0].to_string(), parts[1].to_string());
+                    }}
+                    map
+                }}
+                "#
+            );
+            fs::write(&path, code).unwrap();
+            path.to_str().unwrap().to_string()
+        })
+        .collect();
+    (temp_dir, files)
+}
+
+fn bench_diff_generation(c: &mut Criterion) {
+    let (_temp_dir, files) = write_fixtures();
+    let paths: Vec<&str> = files.iter().map(String::as_str).collect();
+    let analyzers = get_analyzers();
+
+    let mut group = c.benchmark_group("diff_1000_files");
+    group.sample_size(10);
+
+    group.bench_function("sequential", |b| {
+        b.iter(|| {
+            let mut result = DiffResult::new();
+            for path in &paths {
+                result.add_file(generate_diff(black_box(path), &analyzers).unwrap());
+            }
+            result
+        })
+    });
+
+    group.bench_function("parallel", |b| {
+        b.iter(|| generate_diffs(black_box(&paths), &analyzers, &[]).unwrap())
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_diff_generation);
+criterion_main!(benches);
@@ -8,8 +8,16 @@
 //! - `Analyzer` trait that all analyzers must implement
 //! - `Issue` struct representing detected problems
 //! - `AnalysisResult` struct containing analysis outcomes
+//! - `Severity` of the issues an analyzer reports
+//! - `Confidence` that the issues of an analyzer are real problems
+//! - `SourceFile` handed to the crate-wide stage of an analyzer
 
-use std::ops::Range;
+use std::{
+    fmt,
+    ops::Range,
+    path::{Path, PathBuf},
+    str::FromStr
+};
 
 use masterror::AppResult;
 use syn::File;
@@ -34,6 +42,7 @@ use syn::File;
 /// assert_eq!(edit.range.len(), 9);
 /// ```
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TextEdit {
     /// Byte range in the original source to replace
     pub range:       Range<usize>,
@@ -83,16 +92,16 @@ pub struct Suggestion {
 /// );
 /// ```
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
 pub enum Fix {
     /// No automatic fix available
     None,
 
-    /// Simple line replacement
+    /// Simple replacement
     ///
-    /// Replace the entire line with the provided string.
-    ///
-    /// Note: Reserved for future analyzers that need simple line replacements.
-    #[allow(dead_code)]
+    /// Replace the flagged code with the provided string (e.g. a rewritten
+    /// macro invocation or a suggested path). An empty string removes it.
     Simple(String),
 
     /// Fix requiring import addition
@@ -150,6 +159,122 @@ impl Fix {
     }
 }
 
+/// Severity of the issues reported by an analyzer.
+///
+/// Style and design findings are warnings; findings that are likely to be
+/// bugs or security problems, such as hardcoded credentials, are errors.
+///
+/// # Examples
+///
+/// ```
+/// use cargo_quality::analyzer::Severity;
+///
+/// assert_eq!(Severity::default(), Severity::Warning);
+/// assert_eq!(Severity::Error.to_string(), "error");
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+#[non_exhaustive]
+pub enum Severity {
+    /// Quality issue worth fixing
+    #[default]
+    Warning,
+    /// Likely bug or security problem
+    Error
+}
+
+impl Severity {
+    /// Returns the lowercase label used in reports.
+    ///
+    /// # Returns
+    ///
+    /// `"warning"` or `"error"`
+    #[inline]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Warning => "warning",
+            Self::Error => "error"
+        }
+    }
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Confidence that the issues of an analyzer are real problems.
+///
+/// Rules that match exact syntax report with [`Confidence::High`]. Heuristic
+/// rules, which guess from names or compare code across files without type
+/// information, report with a lower level, so strict CI gates can keep only
+/// precise rules with `--min-confidence high`.
+///
+/// The level is set per analyzer by [`Analyzer::confidence`] and applies to
+/// every issue it reports.
+///
+/// # Examples
+///
+/// ```
+/// use cargo_quality::analyzer::Confidence;
+///
+/// assert_eq!(Confidence::default(), Confidence::High);
+/// assert!(Confidence::Low < Confidence::Medium);
+/// assert_eq!("medium".parse::<Confidence>(), Ok(Confidence::Medium));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+#[non_exhaustive]
+pub enum Confidence {
+    /// Heuristic with frequent false positives
+    Low,
+    /// Heuristic that is usually right
+    Medium,
+    /// Exact match of the reported pattern
+    #[default]
+    High
+}
+
+impl Confidence {
+    /// Returns the lowercase label used in reports and on the command line.
+    ///
+    /// # Returns
+    ///
+    /// `"low"`, `"medium"` or `"high"`
+    #[inline]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Low => "low",
+            Self::Medium => "medium",
+            Self::High => "high"
+        }
+    }
+}
+
+impl fmt::Display for Confidence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Confidence {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "low" => Ok(Self::Low),
+            "medium" => Ok(Self::Medium),
+            "high" => Ok(Self::High),
+            _ => Err(format!(
+                "unknown confidence `{value}`, expected `high`, `medium` or `low`"
+            ))
+        }
+    }
+}
+
 /// Analysis issue found in code.
 ///
 /// Represents a single quality issue detected by an analyzer, including
@@ -173,6 +298,7 @@ impl Fix {
 /// assert!(issue.fix.is_available());
 /// ```
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Issue {
     /// Line number where issue was found
     pub line:    usize,
@@ -200,6 +326,7 @@ pub struct Issue {
 /// assert_eq!(result.issues.len(), 0);
 /// ```
 #[derive(Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AnalysisResult {
     /// Issues found
     pub issues:        Vec<Issue>,
@@ -207,11 +334,23 @@ pub struct AnalysisResult {
     pub fixable_count: usize
 }
 
+/// Parsed file handed to [`Analyzer::analyze_crate`].
+#[derive(Debug)]
+pub struct SourceFile {
+    /// Path of the file
+    pub path: PathBuf,
+    /// Parsed syntax tree
+    pub ast:  File
+}
+
 /// Trait for code analyzers.
 ///
 /// Implement this trait to create custom quality analyzers. Each analyzer
 /// must provide a unique name, analysis logic, and optional fix capability.
 ///
+/// Analyzers are shared between the worker threads of `diff`, so they must
+/// be `Send + Sync`.
+///
 /// # Examples
 ///
 /// ```
@@ -231,7 +370,7 @@ pub struct AnalysisResult {
 ///     }
 /// }
 /// ```
-pub trait Analyzer {
+pub trait Analyzer: Send + Sync {
     /// Returns unique analyzer identifier.
     ///
     /// Used for reporting and configuration. Must be lowercase snake_case.
@@ -249,6 +388,25 @@ pub trait Analyzer {
     /// `AppResult<AnalysisResult>` - Analysis results or error
     fn analyze(&self, ast: &File, content: &str) -> AppResult<AnalysisResult>;
 
+    /// Analyze a file whose location is known.
+    ///
+    /// Analyzers whose results depend on where the file lives, such as its
+    /// module path, override this. The default implementation ignores `path`
+    /// and calls [`Analyzer::analyze`].
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path of the analyzed file
+    /// * `ast` - Parsed Rust syntax tree to analyze
+    /// * `content` - Source code content for analyzers that need raw text
+    ///
+    /// # Returns
+    ///
+    /// `AppResult<AnalysisResult>` - Analysis results or error
+    fn analyze_file(&self, _path: &Path, ast: &File, content: &str) -> AppResult<AnalysisResult> {
+        self.analyze(ast, content)
+    }
+
     /// Produce fixable suggestions for the detected issues.
     ///
     /// Each suggestion is a byte-range edit (plus an optional import) applied
@@ -267,12 +425,160 @@ pub trait Analyzer {
     fn suggestions(&self, _ast: &File, _content: &str) -> AppResult<Vec<Suggestion>> {
         Ok(Vec::new())
     }
+
+    /// Produce fixable suggestions for a file whose location is known.
+    ///
+    /// Counterpart of [`Analyzer::analyze_file`]. The default implementation
+    /// ignores `path` and calls [`Analyzer::suggestions`].
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path of the file to fix
+    /// * `ast` - Parsed Rust syntax tree to fix
+    /// * `content` - Original source code the edits apply to
+    ///
+    /// # Returns
+    ///
+    /// `AppResult<Vec<Suggestion>>` - Non-overlapping suggestions, or error
+    fn file_suggestions(
+        &self,
+        _path: &Path,
+        ast: &File,
+        content: &str
+    ) -> AppResult<Vec<Suggestion>> {
+        self.suggestions(ast, content)
+    }
+
+    /// Whether the analyzer only runs when explicitly enabled.
+    ///
+    /// Opt-in analyzers are skipped by a default run. They run when listed in
+    /// `enable` under `[package.metadata.quality]` (see
+    /// [`crate::project::ProjectContext::is_active`]) or when selected with
+    /// `--analyzer`. The default implementation returns `false`.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the analyzer is opt-in
+    fn opt_in(&self) -> bool {
+        false
+    }
+
+    /// Whether the analyzer checks a given file.
+    ///
+    /// Lets an analyzer restrict itself to some kinds of files, e.g. library
+    /// modules only. The default implementation accepts every file.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path of the file about to be analyzed
+    ///
+    /// # Returns
+    ///
+    /// `true` if the file should be analyzed
+    fn applies_to(&self, _path: &Path) -> bool {
+        true
+    }
+
+    /// Severity of the issues this analyzer reports.
+    ///
+    /// The default implementation returns [`Severity::Warning`].
+    ///
+    /// # Returns
+    ///
+    /// Severity shown in reports
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    /// Confidence that the analyzer's issues are real problems.
+    ///
+    /// Analyzers below `--min-confidence` are skipped. The default
+    /// implementation returns [`Confidence::High`].
+    ///
+    /// # Returns
+    ///
+    /// Confidence shown in rule documentation and the run plan
+    fn confidence(&self) -> Confidence {
+        Confidence::High
+    }
+
+    /// Whether `fix` can resolve the analyzer's issues.
+    ///
+    /// Analyzers that override [`Analyzer::suggestions`] return `true`. Used
+    /// for rule documentation; the default implementation returns `false`.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the analyzer produces fix suggestions
+    fn fixable(&self) -> bool {
+        false
+    }
+
+    /// Whether the analyzer compares files with each other.
+    ///
+    /// Crate-wide analyzers get every file of a `check` run at once in
+    /// [`Analyzer::analyze_crate`], after each file went through
+    /// [`Analyzer::analyze_file`]. The files are only kept in memory when
+    /// such an analyzer runs. The default implementation returns `false`.
+    ///
+    /// # Returns
+    ///
+    /// `true` if [`Analyzer::analyze_crate`] should be called
+    fn crate_wide(&self) -> bool {
+        false
+    }
+
+    /// Analyze all files of a run together.
+    ///
+    /// Called once per `check` run for analyzers whose
+    /// [`Analyzer::crate_wide`] returns `true`, e.g. to find code repeated
+    /// across files. The default implementation reports nothing.
+    ///
+    /// # Arguments
+    ///
+    /// * `files` - Every file of the run the analyzer applies to
+    ///
+    /// # Returns
+    ///
+    /// `AppResult<Vec<(PathBuf, AnalysisResult)>>` - Results per file, or
+    /// error
+    fn analyze_crate(&self, _files: &[&SourceFile]) -> AppResult<Vec<(PathBuf, AnalysisResult)>> {
+        Ok(Vec::new())
+    }
+
+    /// Keys of `[package.metadata.quality]` that configure the analyzer.
+    ///
+    /// Used for rule documentation. The default implementation returns none.
+    ///
+    /// # Returns
+    ///
+    /// Configuration keys read for this analyzer
+    fn options(&self) -> &'static [&'static str] {
+        &[]
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_severity_order_and_labels() {
+        assert!(Severity::Error > Severity::Warning);
+        assert_eq!(Severity::Warning.as_str(), "warning");
+        assert_eq!(format!("{}", Severity::Error), "error");
+    }
+
+    #[test]
+    fn test_confidence_order_and_labels() {
+        assert!(Confidence::Medium < Confidence::High);
+        assert_eq!(Confidence::Low.to_string(), "low");
+        for level in [Confidence::Low, Confidence::Medium, Confidence::High] {
+            assert_eq!(level.as_str().parse::<Confidence>(), Ok(level));
+        }
+        assert!("strict".parse::<Confidence>().is_err());
+    }
+
     #[test]
     fn test_fix_none() {
         let fix = Fix::None;
@@ -12,9 +12,79 @@
 //! | Analyzer | Issue Detected | Auto-fix |
 //! |----------|---------------|----------|
 //! | [`PathImportAnalyzer`] | `std::fs::read()` paths | Yes |
-//! | [`FormatArgsAnalyzer`] | `println!("{}", x)` positional args | No |
+//! | [`FormatArgsAnalyzer`] | `println!("{}", x)` positional args | Yes |
 //! | [`EmptyLinesAnalyzer`] | Empty lines in functions | Yes |
-//! | [`InlineCommentsAnalyzer`] | `//` comments in code | No |
+//! | [`InlineCommentsAnalyzer`] | `//` comments in code | Yes |
+//! | [`NeedlessAllocationAnalyzer`] | `fn f(name: String)` that only reads `name` | No |
+//! | [`SharingComplexityAnalyzer`] | `Arc<Mutex<Vec<T>>>` stacks, `Rc` with threads | No |
+//! | [`PrintLoggingAnalyzer`] | `println!` logging when `tracing`/`log` is a dependency | Yes |
+//! | [`MissingInstrumentAnalyzer`] | Public async fns without `#[instrument]` or events (opt-in) | No |
+//! | [`TimeSourceAnalyzer`] | Clock reads in pure logic, `chrono::Local` in servers (opt-in) | No |
+//! | [`UnwrapAnalyzer`] | `.unwrap()`/`.expect()` outside tests | No |
+//! | [`UnboundedQueueAnalyzer`] | Unbounded channels and buffers growing in service loops | No |
+//! | [`HardcodedSecretsAnalyzer`] | AWS keys, high-entropy tokens, passwords in literals (error) | No |
+//! | [`DebugArtifactsAnalyzer`] | `dbg!`, `println!`, `eprintln!` left in library code | Yes |
+//! | [`HardcodedPathsAnalyzer`] | Absolute paths (`/home/...`, `C:\...`) and `http://` URLs | No |
+//! | [`FunctionLengthAnalyzer`] | Function bodies longer than 60 lines | No |
+//! | [`SqlInjectionAnalyzer`] | `format!`/`+` built SQL passed to `query`/`execute` | No |
+//! | [`NumericOverflowAnalyzer`] | Unchecked integer `+`/`*` in loops and on parameters (opt-in) | No |
+//! | [`NestingDepthAnalyzer`] | Functions nesting `if`/`match`/loops/closures deeper than 4 | No |
+//! | [`ErrorConventionAnalyzer`] | Public fns returning `anyhow::Error`/`Box<dyn Error>` (opt-in) | No |
+//! | [`BoolResultAnalyzer`] | Fallible calls collapsed into `bool` with `.is_ok()` | No |
+//! | [`GlobImportAnalyzer`] | Glob imports `use path::*;` outside tests and preludes | Yes |
+//! | [`NestedClosuresAnalyzer`] | Closures nested more than two levels deep | No |
+//! | [`ImportOrderAnalyzer`] | `use` items out of std/external/crate group order | No |
+//! | [`TraitBoundsAnalyzer`] | Functions and impls with too many or too long trait bounds | No |
+//! | [`RedundantImportsAnalyzer`] | Duplicated, overlapping and unused `use` items | Yes |
+//! | [`MissingInlineAnalyzer`] | Trivial public functions without `#[inline]` | Yes |
+//! | [`MissingDocsAnalyzer`] | Public items without a `///` doc comment | No |
+//! | [`SensitiveDebugAnalyzer`] | Derived `Debug` on types with secret-like fields | No |
+//! | [`MissingErrorsDocAnalyzer`] | Documented fallible pub fns without `# Errors` | No |
+//! | [`VariantNamesAnalyzer`] | Enum variants repeating the enum name or a shared prefix | Yes |
+//! | [`MissingPanicsDocAnalyzer`] | Documented panicking pub fns without `# Panics` | No |
+//! | [`LicenseHeaderAnalyzer`] | Files without SPDX license header tags | Yes |
+//! | [`MergeableImplsAnalyzer`] | Repeated inherent impl blocks in one module | Yes |
+//! | [`TodoCommentsAnalyzer`] | TODO/FIXME/HACK/XXX markers in comments (opt-in) | No |
+//! | [`TestModulesAnalyzer`] | Test modules without `use super::*` or before code | No |
+//! | [`ItemOrderAnalyzer`] | Items out of the configured group order | Yes |
+//! | [`LineLengthAnalyzer`] | Lines wider than 99 columns | No |
+//! | [`PubFieldsAnalyzer`] | Public fields on exported structs | No |
+//! | [`WhitespaceAnalyzer`] | Trailing whitespace, tab indentation, missing final newline | Yes |
+//! | [`StringlyTypedAnalyzer`] | Public fns with 3+ string parameters | No |
+//! | [`FileLengthAnalyzer`] | Source files over 600 lines | No |
+//! | [`ConversionImplsAnalyzer`] | Missing fallible reverse conversions, manual `to_x` methods | No |
+//! | [`TypeSizeAnalyzer`] | Enums over 20 variants, structs over 12 fields | No |
+//! | [`ShadowingAnalyzer`] | Names rebound with `let` several times in one function | No |
+//! | [`UnsafeDocsAnalyzer`] | Unsafe code without a `// SAFETY:` comment or `# Safety` section | No |
+//! | [`CloneInLoopAnalyzer`] | `.clone()` / `.to_owned()` inside loop bodies | No |
+//! | [`MissingMustUseAnalyzer`] | Builder-like public methods without `#[must_use]` | Yes |
+//! | [`DefaultImplAnalyzer`] | `pub fn new()` without a `Default` implementation | Yes |
+//! | [`MissingDebugAnalyzer`] | Public types without `Debug` | Yes |
+//! | [`NumericCastAnalyzer`] | Lossy integer `as` casts | No |
+//! | [`TestAssertionsAnalyzer`] | `#[test]` functions without assertions | No |
+//! | [`AssertMessageAnalyzer`] | Assertions without a message in non-test code | No |
+//! | [`SuperImportsAnalyzer`] | `use super::super::...` imports | Yes |
+//! | [`WildcardReexportAnalyzer`] | `pub use path::*` re-exports | Yes |
+//! | [`DocWidthAnalyzer`] | Doc comment lines wider than the comment width of 80 | Yes |
+//! | [`PlaceholderDocsAnalyzer`] | Empty, TODO-only or name-only doc comments | No |
+//! | [`DuplicateLiteralsAnalyzer`] | String literals repeated across the crate | No |
+//! | [`DeriveOrderAnalyzer`] | Derive lists out of canonical order (opt-in) | Yes |
+//! | [`ArgumentOrderAnalyzer`] | Related functions taking shared parameters in different orders | No |
+//! | [`AllowAuditAnalyzer`] | `#[allow(..)]` without a reason or comment | No |
+//! | [`RequiredDebugAnalyzer`] | Error, config and options types without `Debug` | Yes |
+//! | [`NamingAnalyzer`] | Single-letter and placeholder `let` binding names | No |
+//! | [`PredicateNamingAnalyzer`] | Public `bool` functions without a predicate name | No |
+//! | [`DiscardedResultAnalyzer`] | `Result`s silently dropped with `let _ =` | No |
+//! | [`GlobalStateAnalyzer`] | `static mut` items and `unsafe impl Send`/`Sync` | No |
+//! | [`ApiTypesAnalyzer`] | `&String`, `&Vec<T>`, `&Box<T>` and `Box<Vec<T>>` parameters | Yes |
+//! | [`ErrorSourcesAnalyzer`] | Error conversions that discard the source error | No |
+//! | [`AsyncBlockingAnalyzer`] | Blocking calls inside async functions and blocks | No |
+//! | [`DataIntegrityAnalyzer`] | Lossy `as u8` / `as char` casts and `from_utf8_unchecked` | No |
+//! | [`NonExhaustiveErrorsAnalyzer`] | Public error enums without `#[non_exhaustive]` | Yes |
+//! | [`UnboundedRecursionAnalyzer`] | Self-recursive functions without a depth limit | No |
+//! | [`ErrorDisplayAnalyzer`] | Error types without a `Display` implementation | No |
+//! | [`RedundantSyntaxAnalyzer`] | Trailing `return` and semicolons after block expressions | Yes |
+//! | [`ImplLocationAnalyzer`] | Inherent impls in another file than their type | No |
 //!
 //! # Usage
 //!
@@ -24,7 +94,7 @@
 //! use cargo_quality::analyzers::get_analyzers;
 //!
 //! let analyzers = get_analyzers();
-//! assert_eq!(analyzers.len(), 4);
+//! assert_eq!(analyzers.len(), 74);
 //! ```
 //!
 //! Use a specific analyzer:
@@ -68,7 +138,9 @@
 //! ## Format Args Analyzer
 //!
 //! Detects positional format arguments that should use named arguments
-//! for better readability (3+ placeholders triggers a warning).
+//! for better readability (3+ placeholders triggers a warning). Positional
+//! arguments that are plain identifiers are fixable by inlining them:
+//! `println!("{}", name)` becomes `println!("{name}")`.
 //!
 //! ```rust
 //! # use cargo_quality::{analyzer::Analyzer, analyzers::FormatArgsAnalyzer};
@@ -76,10 +148,440 @@
 //! let code = r#"
 //!     fn main() {
 //!         // Flagged: 3+ positional args
-//!         println!("{} {} {}", a, b, c);
+//!         println!("{} {} {}", x.a, x.b, x.c);
 //!
-//!         // NOT flagged: only 2 args
+//!         // Flagged and fixable: identifiers can be inlined
 //!         println!("{} {}", a, b);
+//!
+//!         // NOT flagged: only 2 args, not identifiers
+//!         println!("{} {}", x.a, x.b);
+//!     }
+//! "#;
+//! let ast = syn::parse_file(code).unwrap();
+//! let result = analyzer.analyze(&ast, code).unwrap();
+//! assert_eq!(result.issues.len(), 2);
+//! assert_eq!(result.fixable_count, 1);
+//! ```
+//!
+//! ## Empty Lines Analyzer
+//!
+//! Detects empty lines inside function bodies that indicate the function
+//! is doing multiple things and should be refactored.
+//!
+//! ```rust
+//! # use cargo_quality::{analyzer::Analyzer, analyzers::EmptyLinesAnalyzer};
+//! let analyzer = EmptyLinesAnalyzer::new();
+//! let code = "fn main() {\n    let x = 1;\n\n    let y = 2;\n}";
+//! let ast = syn::parse_file(code).unwrap();
+//! let result = analyzer.analyze(&ast, code).unwrap();
+//! assert_eq!(result.issues.len(), 1);
+//! ```
+//!
+//! ## Inline Comments Analyzer
+//!
+//! Detects `//` comments inside function bodies. These should be moved
+//! to doc comments (`///`) in the `# Notes` section, which the fix does.
+//!
+//! ```rust
+//! # use cargo_quality::{analyzer::Analyzer, analyzers::InlineCommentsAnalyzer};
+//! let analyzer = InlineCommentsAnalyzer::new();
+//! let code = "fn main() {\n    // This is flagged\n    let x = 1;\n}";
+//! let ast = syn::parse_file(code).unwrap();
+//! let result = analyzer.analyze(&ast, code).unwrap();
+//! assert_eq!(result.issues.len(), 1);
+//! ```
+//!
+//! ## Needless Allocation Analyzer
+//!
+//! Detects owned `String`, `Vec<T>` and `PathBuf` parameters that a function
+//! only reads, and `&String`/`&Vec<T>`/`&PathBuf` return types. Trait
+//! implementations are skipped because their signatures are fixed.
+//!
+//! ```rust
+//! # use cargo_quality::{analyzer::Analyzer, analyzers::NeedlessAllocationAnalyzer};
+//! let analyzer = NeedlessAllocationAnalyzer::new();
+//! let code = r#"
+//!     // Flagged: `name` is only read, take `&str`
+//!     fn greet(name: String) {
+//!         println!("Hello {name}");
+//!     }
+//!
+//!     // NOT flagged: `name` is moved into the vector
+//!     fn store(names: &mut Vec<String>, name: String) {
+//!         names.push(name);
+//!     }
+//! "#;
+//! let ast = syn::parse_file(code).unwrap();
+//! let result = analyzer.analyze(&ast, code).unwrap();
+//! assert_eq!(result.issues.len(), 1);
+//! ```
+//!
+//! ## Sharing Complexity Analyzer
+//!
+//! Detects stacks of sharing and interior-mutability wrappers deeper than two
+//! layers in struct fields and function signatures, and `Rc` used in files that
+//! spawn threads. The depth is configurable with
+//! [`SharingComplexityAnalyzer::with_max_depth`].
+//!
+//! ```rust
+//! # use cargo_quality::{analyzer::Analyzer, analyzers::SharingComplexityAnalyzer};
+//! let analyzer = SharingComplexityAnalyzer::new();
+//! let code = r#"
+//!     struct Cache {
+//!         // Flagged: three nested wrappers
+//!         entries: Arc<Mutex<Vec<Entry>>>,
+//!
+//!         // NOT flagged: two layers
+//!         state: Arc<Mutex<State>>,
+//!     }
+//! "#;
+//! let ast = syn::parse_file(code).unwrap();
+//! let result = analyzer.analyze(&ast, code).unwrap();
+//! assert_eq!(result.issues.len(), 1);
+//! ```
+//!
+//! ## Print Logging Analyzer
+//!
+//! Detects `println!`, `eprintln!`, `print!` and `eprint!` outside test code in
+//! projects that depend on `tracing` or `log`, as detected from `Cargo.toml` by
+//! [`crate::project::ProjectContext`]. `println!`/`eprintln!` with a format
+//! string are rewritten to `info!`/`error!`.
+//!
+//! ```rust
+//! # use cargo_quality::{analyzer::Analyzer, analyzers::PrintLoggingAnalyzer, project::LoggingCrate};
+//! let analyzer = PrintLoggingAnalyzer::with_logger(Some(LoggingCrate::Tracing));
+//! let code = r#"
+//!     fn serve() {
+//!         // Flagged and fixable: becomes `info!("listening")`
+//!         println!("listening");
+//!     }
+//! "#;
+//! let ast = syn::parse_file(code).unwrap();
+//! let result = analyzer.analyze(&ast, code).unwrap();
+//! assert_eq!(result.fixable_count, 1);
+//! ```
+//!
+//! ## Missing Instrument Analyzer
+//!
+//! Detects public async functions and inherent methods that have neither
+//! `#[instrument]` nor any `tracing` event or span in their body. Only active
+//! in projects depending on `tracing`, and opt-in: it runs when listed in
+//! `enable` under `[package.metadata.quality]` or selected with `--analyzer`.
+//!
+//! ```rust
+//! # use cargo_quality::{analyzer::Analyzer, analyzers::MissingInstrumentAnalyzer};
+//! let analyzer = MissingInstrumentAnalyzer::new();
+//! let code = r#"
+//!     // Flagged: no span, no events
+//!     pub async fn fetch(id: u64) {}
+//!
+//!     // Fine: instrumented
+//!     #[tracing::instrument]
+//!     pub async fn store(id: u64) {}
+//! "#;
+//! let ast = syn::parse_file(code).unwrap();
+//! let result = analyzer.analyze(&ast, code).unwrap();
+//! assert_eq!(result.issues.len(), 1);
+//! assert!(analyzer.opt_in());
+//! ```
+//!
+//! ## Time Source Analyzer
+//!
+//! Detects `SystemTime::now()`/`Instant::now()` inside synchronous functions
+//! other than `main`, where injecting the time keeps logic testable, and
+//! `chrono::Local` in server projects (depending on `axum`, `actix-web`,
+//! `tonic`, ...), where UTC is the standard. Opt-in: it runs when listed in
+//! `enable` under `[package.metadata.quality]` or selected with `--analyzer`.
+//!
+//! ```rust
+//! # use cargo_quality::{analyzer::Analyzer, analyzers::TimeSourceAnalyzer};
+//! let analyzer = TimeSourceAnalyzer::new();
+//! let code = r#"
+//!     // Flagged: the deadline check reads the clock itself
+//!     fn is_expired(deadline: SystemTime) -> bool {
+//!         SystemTime::now() > deadline
+//!     }
+//! "#;
+//! let ast = syn::parse_file(code).unwrap();
+//! let result = analyzer.analyze(&ast, code).unwrap();
+//! assert_eq!(result.issues.len(), 1);
+//! ```
+//!
+//! ## Unwrap Analyzer
+//!
+//! Detects `.unwrap()` and `.expect(...)` calls outside `#[cfg(test)]` modules
+//! and `#[test]` functions. Errors should be propagated with `?`, e.g. into a
+//! masterror `AppResult`. Non-panicking variants such as `unwrap_or` are
+//! ignored.
+//!
+//! ```rust
+//! # use cargo_quality::{analyzer::Analyzer, analyzers::UnwrapAnalyzer};
+//! let analyzer = UnwrapAnalyzer::new();
+//! let code = r#"
+//!     fn port() -> u16 {
+//!         // Flagged: panics if PORT is not a number
+//!         std::env::var("PORT").unwrap().parse().expect("numeric port")
+//!     }
+//! "#;
+//! let ast = syn::parse_file(code).unwrap();
+//! let result = analyzer.analyze(&ast, code).unwrap();
+//! assert_eq!(result.issues.len(), 2);
+//! ```
+//!
+//! ## Unbounded Queue Analyzer
+//!
+//! Detects unbounded channel constructors (`mpsc::unbounded_channel()`,
+//! `crossbeam::channel::unbounded()`, `std::sync::mpsc::channel()`, ...) and
+//! `Vec`/`VecDeque` buffers pushed to inside long-running loops (`loop` without
+//! `break`, `while let` over `recv()`/`accept()`/`next()`) that are never
+//! drained in the same function.
+//!
+//! ```rust
+//! # use cargo_quality::{analyzer::Analyzer, analyzers::UnboundedQueueAnalyzer};
+//! let analyzer = UnboundedQueueAnalyzer::new();
+//! let code = r#"
+//!     async fn serve() {
+//!         // Flagged: no backpressure
+//!         let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<Job>();
+//!         // Fine: bounded
+//!         let (jobs, mut queue) = tokio::sync::mpsc::channel::<Job>(1024);
+//!     }
+//! "#;
+//! let ast = syn::parse_file(code).unwrap();
+//! let result = analyzer.analyze(&ast, code).unwrap();
+//! assert_eq!(result.issues.len(), 1);
+//! ```
+//!
+//! ## Hardcoded Secrets Analyzer
+//!
+//! Scans string literals outside test code for AWS access key identifiers,
+//! high-entropy tokens and non-empty values bound to password-like names
+//! (`password`, `secret`, `token`, `api_key`, ...), including `Password=` in
+//! connection strings. Issues are reported with
+//! [`Severity::Error`](crate::analyzer::Severity::Error) and the secret is
+//! redacted in the message.
+//!
+//! ```rust
+//! # use cargo_quality::{analyzer::{Analyzer, Severity}, analyzers::HardcodedSecretsAnalyzer};
+//! let analyzer = HardcodedSecretsAnalyzer::new();
+//! let code = r#"
+//!     fn connect() {
+//!         // Flagged: credential in source
+//!         let db_password = "hunter2";
+//!     }
+//! "#;
+//! let ast = syn::parse_file(code).unwrap();
+//! let result = analyzer.analyze(&ast, code).unwrap();
+//! assert_eq!(result.issues.len(), 1);
+//! assert_eq!(analyzer.severity(), Severity::Error);
+//! ```
+//!
+//! ## Debug Artifacts Analyzer
+//!
+//! Flags `dbg!`, `println!` and `eprintln!` outside test code in library
+//! modules. `main.rs`, `build.rs` and files under `bin/`, `examples/`,
+//! `benches/` or `tests/` are skipped. Standalone statements are removed by
+//! `fix`; invocations used as values are reported only.
+//!
+//! ```rust
+//! # use cargo_quality::{analyzer::Analyzer, analyzers::DebugArtifactsAnalyzer};
+//! let analyzer = DebugArtifactsAnalyzer::new();
+//! let code = r#"
+//!     fn parse(input: &str) -> usize {
+//!         // Flagged and removable: standalone statement
+//!         dbg!(input);
+//!         input.len()
+//!     }
+//! "#;
+//! let ast = syn::parse_file(code).unwrap();
+//! let result = analyzer.analyze(&ast, code).unwrap();
+//! assert_eq!(result.issues.len(), 1);
+//! assert_eq!(result.fixable_count, 1);
+//! ```
+//!
+//! ## Hardcoded Paths Analyzer
+//!
+//! Flags string literals outside test code that hold absolute paths under
+//! machine-specific roots (`/home/`, `/Users/`, `/tmp/`, `C:\`, ...) or
+//! `http://` URLs, including literals inside macros such as `format!`. Doc
+//! comments, plain `const`/`static` values, loopback and example hosts, and
+//! files under `tests/`, `examples/`, `benches/` and `fixtures/` are allowed.
+//!
+//! ```rust
+//! # use cargo_quality::{analyzer::Analyzer, analyzers::HardcodedPathsAnalyzer};
+//! let analyzer = HardcodedPathsAnalyzer::new();
+//! let code = r#"
+//!     fn load() {
+//!         // Flagged: only exists on one machine
+//!         let raw = std::fs::read("/home/alice/config.toml");
+//!         // Allowed: loopback host
+//!         let health = "http://localhost:8080/health";
+//!     }
+//! "#;
+//! let ast = syn::parse_file(code).unwrap();
+//! let result = analyzer.analyze(&ast, code).unwrap();
+//! assert_eq!(result.issues.len(), 1);
+//! ```
+//!
+//! ## Function Length Analyzer
+//!
+//! Flags functions, methods and default trait methods outside test code whose
+//! body spans more than 60 lines, counted from the opening to the closing
+//! brace.
+//!
+//! ```rust
+//! # use cargo_quality::{analyzer::Analyzer, analyzers::FunctionLengthAnalyzer};
+//! let analyzer = FunctionLengthAnalyzer::new();
+//! let code = format!("fn handle() {{\n{}}}\n", "    step();\n".repeat(70));
+//! let ast = syn::parse_file(&code).unwrap();
+//! let result = analyzer.analyze(&ast, &code).unwrap();
+//! assert_eq!(result.issues.len(), 1);
+//! ```
+//!
+//! ## SQL Injection Analyzer
+//!
+//! Flags strings built with interpolating `format!` or `+` concatenation that
+//! are passed, directly or through a local binding, as the first argument of
+//! functions and methods named `query`, `query_as`, `query_scalar`,
+//! `query_with`, `execute` or `sql_query`. Test code is skipped.
+//!
+//! ```rust
+//! # use cargo_quality::{analyzer::Analyzer, analyzers::SqlInjectionAnalyzer};
+//! let analyzer = SqlInjectionAnalyzer::new();
+//! let code = r#"
+//!     async fn find(pool: &PgPool, name: &str) {
+//!         // Flagged: value spliced into SQL
+//!         let sql = format!("SELECT * FROM users WHERE name = '{name}'");
+//!         sqlx::query(&sql).fetch_all(pool).await;
+//!     }
+//! "#;
+//! let ast = syn::parse_file(code).unwrap();
+//! let result = analyzer.analyze(&ast, code).unwrap();
+//! assert_eq!(result.issues.len(), 1);
+//! ```
+//!
+//! ## Numeric Overflow Analyzer
+//!
+//! Flags `+`, `*`, `+=` and `*=` outside test code and `const` contexts when
+//! an operand is an integer-typed function parameter, or when integer
+//! arithmetic runs inside a `for`, `while` or `loop`. Integers are recognized
+//! from parameter and `let` type annotations and integer literals. Opt-in:
+//! enable it with `enable = ["numeric_overflow"]`.
+//!
+//! ```rust
+//! # use cargo_quality::{analyzer::Analyzer, analyzers::NumericOverflowAnalyzer};
+//! let analyzer = NumericOverflowAnalyzer::new();
+//! let code = r#"
+//!     fn area(width: u32, height: u32) -> u32 {
+//!         // Flagged: caller-controlled values multiplied unchecked
+//!         width * height
+//!     }
+//! "#;
+//! let ast = syn::parse_file(code).unwrap();
+//! let result = analyzer.analyze(&ast, code).unwrap();
+//! assert_eq!(result.issues.len(), 1);
+//! assert!(analyzer.opt_in());
+//! ```
+//!
+//! ## Nesting Depth Analyzer
+//!
+//! Reports functions outside test code whose `if`/`match`/loop/closure nesting
+//! exceeds 4 levels. The issue points at the first statement of the deepest
+//! level. `else if` chains count as a single level and nested functions are
+//! measured separately.
+//!
+//! ```rust
+//! # use cargo_quality::{analyzer::Analyzer, analyzers::NestingDepthAnalyzer};
+//! let analyzer = NestingDepthAnalyzer::new();
+//! let code = r#"
+//!     fn deep() {
+//!         if a { if b { if c { if d { if e {
+//!             step();
+//!         } } } } }
+//!     }
+//! "#;
+//! let ast = syn::parse_file(code).unwrap();
+//! let result = analyzer.analyze(&ast, code).unwrap();
+//! assert_eq!(result.issues.len(), 1);
+//! assert_eq!(result.issues[0].line, 4);
+//! ```
+//!
+//! ## Error Convention Analyzer
+//!
+//! Flags `pub` functions and inherent methods outside test code whose return
+//! type is `anyhow::Result<T>`, `Result<T, anyhow::Error>` (including names
+//! imported from `anyhow`) or `Result<T, Box<dyn Error>>`, and suggests the
+//! project's standard error type: `metadata.quality.error_type`, defaulting to
+//! `masterror::AppError`. Opt-in: enable it with `enable =
+//! ["error_convention"]`.
+//!
+//! ```rust
+//! # use cargo_quality::{analyzer::Analyzer, analyzers::ErrorConventionAnalyzer};
+//! let analyzer = ErrorConventionAnalyzer::new();
+//! let code = r#"
+//!     // Flagged: opaque error in a public API
+//!     pub fn load() -> anyhow::Result<String> { todo!() }
+//! "#;
+//! let ast = syn::parse_file(code).unwrap();
+//! let result = analyzer.analyze(&ast, code).unwrap();
+//! assert_eq!(result.issues.len(), 1);
+//! ```
+//!
+//! ## Bool Result Analyzer
+//!
+//! Flags functions and inherent methods outside test code that return `bool`
+//! and apply `.is_ok()`/`.is_err()` directly to a call such as
+//! `fs::write(..)` or `.parse(..)`. Predicates named `is_*`, `has_*`, `can_*`,
+//! `should_*` or `contains_*` are skipped, as are closures and trait impls.
+//!
+//! ```rust
+//! # use cargo_quality::{analyzer::Analyzer, analyzers::BoolResultAnalyzer};
+//! let analyzer = BoolResultAnalyzer::new();
+//! let code = r#"
+//!     // Flagged: the io::Error is discarded
+//!     fn save(path: &str, data: &[u8]) -> bool {
+//!         std::fs::write(path, data).is_ok()
+//!     }
+//! "#;
+//! let ast = syn::parse_file(code).unwrap();
+//! let result = analyzer.analyze(&ast, code).unwrap();
+//! assert_eq!(result.issues.len(), 1);
+//! ```
+//!
+//! ## Glob Import Analyzer
+//!
+//! Flags `use path::*;` anywhere outside test code, skipping paths through a
+//! `prelude` module and re-exports inside a module named `prelude`. Globs over
+//! an enum or inline module defined in the same file are fixed by replacing
+//! `*` with the variants or public items the file uses (traits are kept since
+//! they may only be used through method calls); other globs are report-only.
+//!
+//! ```rust
+//! # use cargo_quality::{analyzer::Analyzer, analyzers::GlobImportAnalyzer};
+//! let analyzer = GlobImportAnalyzer::new();
+//! let code = r#"
+//!     // Flagged: where do the names come from?
+//!     use std::collections::*;
+//! "#;
+//! let ast = syn::parse_file(code).unwrap();
+//! let result = analyzer.analyze(&ast, code).unwrap();
+//! assert_eq!(result.issues.len(), 1);
+//! ```
+//!
+//! ## Nested Closures Analyzer
+//!
+//! Counts closures nested inside each other and reports, once per outermost
+//! closure, the innermost closure deeper than
+//! [`nested_closures::MAX_CLOSURE_DEPTH`] levels. Sibling closures in one chain
+//! do not add up, and nested items start from zero. Test code is skipped.
+//!
+//! ```rust
+//! # use cargo_quality::{analyzer::Analyzer, analyzers::NestedClosuresAnalyzer};
+//! let analyzer = NestedClosuresAnalyzer::new();
+//! let code = r#"
+//!     fn run(groups: Vec<Vec<Vec<u8>>>) {
+//!         // Flagged: three closures deep
+//!         groups.iter().for_each(|g| g.iter().for_each(|row| row.iter().for_each(|b| drop(b))));
 //!     }
 //! "#;
 //! let ast = syn::parse_file(code).unwrap();
@@ -87,48 +589,1097 @@
 //! assert_eq!(result.issues.len(), 1);
 //! ```
 //!
-//! ## Empty Lines Analyzer
+//! ## Import Order Analyzer
+//!
+//! Checks each block of consecutive `use` items (in files, inline modules and
+//! function bodies) against rustfmt's `group_imports = "StdExternalCrate"`:
+//! `std`/`core`/`alloc` first, external crates next, `self`/`super`/`crate`
+//! last, a blank line between groups and imports sorted within a group.
+//! Attributed imports such as `#[cfg(test)] use` end a block.
+//!
+//! ```rust
+//! # use cargo_quality::{analyzer::Analyzer, analyzers::ImportOrderAnalyzer};
+//! let analyzer = ImportOrderAnalyzer::new();
+//! let code = r#"
+//! use serde::Deserialize;
+//! use std::fs;
+//! "#;
+//! let ast = syn::parse_file(code).unwrap();
+//! let result = analyzer.analyze(&ast, code).unwrap();
+//! assert_eq!(result.issues.len(), 1);
+//! ```
+//!
+//! ## Trait Bounds Analyzer
+//!
+//! Reports functions, methods (including trait method signatures) and impl
+//! blocks with more than [`trait_bounds::MAX_TRAIT_BOUNDS`] trait bounds across
+//! their generic parameters and where-clause, or whose generics and
+//! where-predicates are longer than [`trait_bounds::MAX_BOUNDS_LENGTH`]
+//! characters. Lifetime bounds are not counted. Test code is skipped.
+//!
+//! ```rust
+//! # use cargo_quality::{analyzer::Analyzer, analyzers::TraitBoundsAnalyzer};
+//! let analyzer = TraitBoundsAnalyzer::new();
+//! let code = r#"
+//!     // Flagged: 6 trait bounds
+//!     fn run<T>(task: T) where T: Clone + Send + Sync + Unpin + std::fmt::Debug + Default {}
+//! "#;
+//! let ast = syn::parse_file(code).unwrap();
+//! let result = analyzer.analyze(&ast, code).unwrap();
+//! assert_eq!(result.issues.len(), 1);
+//! ```
+//!
+//! ## Redundant Imports Analyzer
+//!
+//! Checks the `use` statements of each scope (file, inline module, block):
+//! a path imported twice, an item imported from a module that is itself
+//! imported and used as `module::item`, and names that appear nowhere outside
+//! `use` statements (including macro and attribute tokens). The unused check is
+//! conservative: re-exports, attributed imports, `as _` and globs are never
+//! reported, and CamelCase names, which may be traits needed only for their
+//! methods, are reported only in files without any `.member` access.
+//! Duplicated and unused statements importing a single name are removed.
+//!
+//! ```rust
+//! # use cargo_quality::{analyzer::Analyzer, analyzers::RedundantImportsAnalyzer};
+//! let analyzer = RedundantImportsAnalyzer::new();
+//! let code = r#"
+//! use std::path::PathBuf;
+//!
+//! fn main() {}
+//! "#;
+//! let ast = syn::parse_file(code).unwrap();
+//! let result = analyzer.analyze(&ast, code).unwrap();
+//! assert_eq!(result.issues.len(), 1);
+//! ```
+//!
+//! ## Missing Inline Analyzer
+//!
+//! Flags `pub` free functions and inherent methods in library files whose body
+//! is a single short expression without `if`, `match`, loops, closures or
+//! nested blocks, and that carry no `#[inline]` attribute. Generic and `async`
+//! functions, trait impls, test code and binaries (see
+//! [`Analyzer::applies_to`](crate::analyzer::Analyzer::applies_to)) are
+//! skipped. The fix inserts `#[inline]` above the function, after its docs.
+//!
+//! ```rust
+//! # use cargo_quality::{analyzer::Analyzer, analyzers::MissingInlineAnalyzer};
+//! let analyzer = MissingInlineAnalyzer::new();
+//! let code = r#"
+//!     pub fn answer() -> u32 {
+//!         42
+//!     }
+//! "#;
+//! let ast = syn::parse_file(code).unwrap();
+//! let result = analyzer.analyze(&ast, code).unwrap();
+//! assert_eq!(result.issues.len(), 1);
+//! ```
+//!
+//! ## Missing Docs Analyzer
+//!
+//! Flags `pub` functions, inherent methods, structs, enums, traits and inline
+//! modules without a `///` (or `#[doc = ..]`) comment. Items marked
+//! `#[doc(hidden)]` and everything inside a hidden module or impl are skipped,
+//! as are trait impls and test code. `mod name;` declarations are skipped since
+//! such modules are documented with `//!` in their own file.
+//!
+//! ```rust
+//! # use cargo_quality::{analyzer::Analyzer, analyzers::MissingDocsAnalyzer};
+//! let analyzer = MissingDocsAnalyzer::new();
+//! let code = r#"
+//!     pub fn undocumented() {}
+//!
+//!     /// Documented.
+//!     pub fn documented() {}
+//! "#;
+//! let ast = syn::parse_file(code).unwrap();
+//! let result = analyzer.analyze(&ast, code).unwrap();
+//! assert_eq!(result.issues.len(), 1);
+//! ```
+//!
+//! ## Sensitive Debug Analyzer
+//!
+//! Flags structs and enums with `#[derive(Debug)]` that have a named field
+//! whose name contains `password`, `token`, `secret`, `api_key`, `credential`
+//! or a similar fragment. Fields whose type already redacts itself (names
+//! containing `Secret`, `Redact` or `Sensitive`) are skipped. Extra patterns
+//! come from `metadata.quality.sensitive_fields`, and `profile = "strict"`
+//! raises the findings to errors.
+//!
+//! ```rust
+//! # use cargo_quality::{analyzer::Analyzer, analyzers::SensitiveDebugAnalyzer};
+//! let analyzer = SensitiveDebugAnalyzer::new();
+//! let code = r#"
+//!     #[derive(Debug)]
+//!     struct Login {
+//!         user: String,
+//!         password: String
+//!     }
+//! "#;
+//! let ast = syn::parse_file(code).unwrap();
+//! let result = analyzer.analyze(&ast, code).unwrap();
+//! assert_eq!(result.issues.len(), 1);
+//! ```
+//!
+//! ## Missing Errors Doc Analyzer
+//!
+//! Flags documented `pub` functions and inherent methods returning `Result`
+//! or an alias ending in `Result` (such as `AppResult` or `io::Result`) whose
+//! doc comment has no `# Errors` heading. Undocumented functions, trait
+//! impls, `#[doc(hidden)]` items and test code are skipped.
+//!
+//! ```rust
+//! # use cargo_quality::{analyzer::Analyzer, analyzers::MissingErrorsDocAnalyzer};
+//! let analyzer = MissingErrorsDocAnalyzer::new();
+//! let code = r#"
+//!     /// Load configuration.
+//!     pub fn load() -> AppResult<Config> { todo!() }
+//! "#;
+//! let ast = syn::parse_file(code).unwrap();
+//! let result = analyzer.analyze(&ast, code).unwrap();
+//! assert_eq!(result.issues.len(), 1);
+//! ```
+//!
+//! ## Variant Names Analyzer
+//!
+//! Flags variants whose leading words repeat the enum name
+//! (`ErrorKind::ErrorNotFound`, `Color::ColorRed`), and variants of enums
+//! with at least three variants that all start with the same word
+//! (`OnClick`, `OnHover`, `OnScroll`). The fix is limited to private enums
+//! in files without `mod child;` declarations or imports from the enum, so
+//! that all uses are qualified paths in the same file: the variants and
+//! every `Enum::Variant` and `Self::Variant` path are renamed in one edit,
+//! unless a trimmed name would clash with another variant.
+//!
+//! ```rust
+//! # use cargo_quality::{analyzer::Analyzer, analyzers::VariantNamesAnalyzer};
+//! let analyzer = VariantNamesAnalyzer::new();
+//! let code = r#"
+//!     enum ErrorKind {
+//!         ErrorNotFound,
+//!         Timeout
+//!     }
+//! "#;
+//! let ast = syn::parse_file(code).unwrap();
+//! let result = analyzer.analyze(&ast, code).unwrap();
+//! assert_eq!(result.issues.len(), 1);
+//! assert_eq!(result.fixable_count, 1);
+//! ```
+//!
+//! ## Missing Panics Doc Analyzer
+//!
+//! Flags documented `pub` functions and inherent methods whose body calls
+//! `unwrap()` or `expect()`, indexes with `[]`, or invokes `panic!`,
+//! `unreachable!`, `todo!` or `unimplemented!`, when the doc comment has no
+//! `# Panics` heading. Nested items, undocumented functions, trait impls,
+//! `#[doc(hidden)]` items and test code are skipped.
+//!
+//! ```rust
+//! # use cargo_quality::{analyzer::Analyzer, analyzers::MissingPanicsDocAnalyzer};
+//! let analyzer = MissingPanicsDocAnalyzer::new();
+//! let code = r#"
+//!     /// Parse the port.
+//!     pub fn port(s: &str) -> u16 { s.parse().unwrap() }
+//! "#;
+//! let ast = syn::parse_file(code).unwrap();
+//! let result = analyzer.analyze(&ast, code).unwrap();
+//! assert_eq!(result.issues.len(), 1);
+//! ```
+//!
+//! ## License Header Analyzer
+//!
+//! Checks that the leading comment block of every file (after an optional
+//! shebang) contains both `SPDX-FileCopyrightText` and
+//! `SPDX-License-Identifier`. The analyzer is opt-in unless a
+//! `license_header` template is configured in `metadata.quality`; with a
+//! template it runs by default and inserts the template at the top of files
+//! missing both tags. Files with only one tag are reported without a fix.
+//!
+//! ```rust
+//! # use cargo_quality::{analyzer::Analyzer, analyzers::LicenseHeaderAnalyzer};
+//! let analyzer = LicenseHeaderAnalyzer::with_template(
+//!     "// SPDX-FileCopyrightText: 2025 Jane Doe\n// SPDX-License-Identifier: MIT"
+//! );
+//! let code = "fn main() {}\n";
+//! let ast = syn::parse_file(code).unwrap();
+//! let result = analyzer.analyze(&ast, code).unwrap();
+//! assert_eq!(result.issues.len(), 1);
+//! assert_eq!(result.fixable_count, 1);
+//! ```
+//!
+//! ## Mergeable Impls Analyzer
+//!
+//! Reports inherent `impl` blocks that repeat an earlier block of the same
+//! module with the same type, generics, where-clause and attributes. Blocks
+//! with their own doc comment or a different `#[cfg]` are treated as split on
+//! purpose, and trait impls are never merged. The fix moves the items of the
+//! later blocks, comments included, to the end of the first block; it is
+//! applied when both blocks end with `}` on its own line.
+//!
+//! ```rust
+//! # use cargo_quality::{analyzer::Analyzer, analyzers::MergeableImplsAnalyzer};
+//! let analyzer = MergeableImplsAnalyzer::new();
+//! let code = "impl Config {\n    fn a() {}\n}\n\nimpl Config {\n    fn b() {}\n}\n";
+//! let ast = syn::parse_file(code).unwrap();
+//! let result = analyzer.analyze(&ast, code).unwrap();
+//! assert_eq!(result.issues.len(), 1);
+//! assert_eq!(result.fixable_count, 1);
+//! ```
+//!
+//! ## TODO Comments Analyzer
+//!
+//! Reports every `TODO`, `FIXME`, `HACK` and `XXX` written as a whole word in
+//! a line, block or doc comment, with the text that follows it (an `(owner)`
+//! tag is dropped). Markers inside string literals are ignored. The analyzer
+//! is opt-in; when enabled, `check` also prints the markers grouped by file.
+//!
+//! ```rust
+//! # use cargo_quality::{analyzer::Analyzer, analyzers::TodoCommentsAnalyzer};
+//! let analyzer = TodoCommentsAnalyzer::new();
+//! let code = "// TODO: retry on timeout\nfn fetch() {}\n";
+//! let ast = syn::parse_file(code).unwrap();
+//! let result = analyzer.analyze(&ast, code).unwrap();
+//! assert!(
+//!     result.issues[0]
+//!         .message
+//!         .starts_with("TODO: retry on timeout")
+//! );
+//! ```
+//!
+//! ## Test Modules Analyzer
+//!
+//! Checks inline `#[cfg(test)]` modules: their leading `use` items must
+//! include `use super::*;` (it may follow external imports, matching the
+//! import order convention), and no non-test item may follow them in the
+//! enclosing module. Out-of-line `mod tests;` declarations are only checked
+//! for their position.
+//!
+//! ```rust
+//! # use cargo_quality::{analyzer::Analyzer, analyzers::TestModulesAnalyzer};
+//! let analyzer = TestModulesAnalyzer::new();
+//! let code = r#"
+//!     #[cfg(test)]
+//!     mod tests {
+//!         #[test]
+//!         fn works() {}
+//!     }
+//!
+//!     fn helper() {}
+//! "#;
+//! let ast = syn::parse_file(code).unwrap();
+//! let result = analyzer.analyze(&ast, code).unwrap();
+//! assert_eq!(result.issues.len(), 2);
+//! ```
+//!
+//! ## Item Order Analyzer
+//!
+//! Checks the items of each module against a group order, by default
+//! constants, type definitions, impl blocks, free functions and test code,
+//! and reports items placed after a group that should follow them. `use`,
+//! `mod` and macro items are not ordered. The order is configured with
+//! `metadata.quality.item_order`, which also activates the analyzer; without
+//! it the analyzer is opt-in. Top-level items are reordered automatically,
+//! together with the comments above them, unless the file invokes macros at
+//! item level.
+//!
+//! ```rust
+//! # use cargo_quality::{analyzer::Analyzer, analyzers::ItemOrderAnalyzer};
+//! let analyzer = ItemOrderAnalyzer::new();
+//! let code = r#"
+//!     fn helper() {}
+//!
+//!     const LIMIT: usize = 10;
+//! "#;
+//! let ast = syn::parse_file(code).unwrap();
+//! let result = analyzer.analyze(&ast, code).unwrap();
+//! assert_eq!(result.issues.len(), 1);
+//! ```
+//!
+//! ## Line Length Analyzer
+//!
+//! Reports source lines wider than 99 columns, the `max_width` the formatter
+//! is configured with. Width is measured visually: wide Unicode characters
+//! count as two columns, ANSI escape sequences as none and tabs as four.
+//! Catches the string literals and comments rustfmt leaves untouched without
+//! running it.
+//!
+//! ```rust
+//! # use cargo_quality::{analyzer::Analyzer, analyzers::LineLengthAnalyzer};
+//! let analyzer = LineLengthAnalyzer::new();
+//! let code = format!("// {}\n", "x".repeat(100));
+//! let ast = syn::parse_file(&code).unwrap();
+//! let result = analyzer.analyze(&ast, &code).unwrap();
+//! assert_eq!(result.issues.len(), 1);
+//! ```
+//!
+//! ## Pub Fields Analyzer
+//!
+//! Flags `pub` named fields of `pub` structs in library code, since every
+//! such field is part of the API and cannot change without a breaking
+//! release. Structs deriving `Serialize`, `Deserialize` or clap's `Parser` /
+//! `Args` are treated as plain data and skipped, as are `#[doc(hidden)]`
+//! structs and type names matching a `metadata.quality.pub_fields_allow`
+//! pattern (`*` matches any text).
+//!
+//! ```rust
+//! # use cargo_quality::{analyzer::Analyzer, analyzers::PubFieldsAnalyzer};
+//! let analyzer = PubFieldsAnalyzer::new();
+//! let code = "pub struct Connection { pub host: String, port: u16 }";
+//! let ast = syn::parse_file(code).unwrap();
+//! let result = analyzer.analyze(&ast, code).unwrap();
+//! assert_eq!(result.issues.len(), 1);
+//! ```
+//!
+//! ## Whitespace Analyzer
+//!
+//! Reports trailing spaces and tabs, lines indented with tabs and files that
+//! do not end with a newline. The fix strips trailing whitespace, replaces
+//! each indentation tab with four spaces and appends the final newline.
+//! Whitespace inside multi-line string literals is left alone.
+//!
+//! ```rust
+//! # use cargo_quality::{analyzer::Analyzer, analyzers::WhitespaceAnalyzer};
+//! let analyzer = WhitespaceAnalyzer::new();
+//! let code = "fn main() {}  ";
+//! let ast = syn::parse_file(code).unwrap();
+//! let result = analyzer.analyze(&ast, code).unwrap();
+//! assert_eq!(result.issues.len(), 2);
+//! ```
+//!
+//! ## Stringly Typed Analyzer
+//!
+//! Flags public functions and inherent methods taking three or more string
+//! parameters (`&str`, `String`, `Cow<str>`, `impl AsRef<str>`,
+//! `impl Into<String>`) and reports the signature. Several strings standing
+//! for different concepts are easy to pass in the wrong order; newtypes let
+//! the compiler catch the mix-up.
+//!
+//! ```rust
+//! # use cargo_quality::{analyzer::Analyzer, analyzers::StringlyTypedAnalyzer};
+//! let analyzer = StringlyTypedAnalyzer::new();
+//! let code = "pub fn connect(host: &str, user: &str, database: &str) {}";
+//! let ast = syn::parse_file(code).unwrap();
+//! let result = analyzer.analyze(&ast, code).unwrap();
+//! assert_eq!(result.issues.len(), 1);
+//! ```
+//!
+//! ## File Length Analyzer
+//!
+//! Reports source files with more than 600 lines, counting comments and
+//! tests too. The issue is reported at line 1 and suggests extracting
+//! cohesive parts into submodules.
+//!
+//! ```rust
+//! # use cargo_quality::{analyzer::Analyzer, analyzers::FileLengthAnalyzer};
+//! let analyzer = FileLengthAnalyzer::new();
+//! let code = "// line\n".repeat(601);
+//! let ast = syn::parse_file(&code).unwrap();
+//! let result = analyzer.analyze(&ast, &code).unwrap();
+//! assert_eq!(result.issues.len(), 1);
+//! ```
+//!
+//! ## Conversion Impls Analyzer
+//!
+//! Flags `impl From<A> for B` between two structs of the file without a
+//! `From` or `TryFrom` impl back, when a field required in `A` is an
+//! `Option` in `B`: the reverse conversion is fallible and belongs in
+//! `impl TryFrom<B> for A`. Also flags inherent `to_b(&self)` and
+//! `into_b(self)` methods returning a type `B` named like the method, which
+//! should be `From` impls. Messages include an example impl.
+//!
+//! ```rust
+//! # use cargo_quality::{analyzer::Analyzer, analyzers::ConversionImplsAnalyzer};
+//! let analyzer = ConversionImplsAnalyzer::new();
+//! let code = "impl Settings { pub fn to_config(&self) -> Config { todo!() } }";
+//! let ast = syn::parse_file(code).unwrap();
+//! let result = analyzer.analyze(&ast, code).unwrap();
+//! assert_eq!(result.issues.len(), 1);
+//! ```
+//!
+//! ## Type Size Analyzer
+//!
+//! Reports enums with more than 20 variants and structs (named or tuple)
+//! with more than 12 fields, with the count in the message. Large types
+//! usually bundle several concepts that read better as nested types.
+//!
+//! ```rust
+//! # use cargo_quality::{analyzer::Analyzer, analyzers::TypeSizeAnalyzer};
+//! let analyzer = TypeSizeAnalyzer::new();
+//! let code = "struct Wide(u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8);";
+//! let ast = syn::parse_file(code).unwrap();
+//! let result = analyzer.analyze(&ast, code).unwrap();
+//! assert_eq!(result.issues.len(), 1);
+//! ```
+//!
+//! ## Shadowing Analyzer
+//!
+//! Follows `let` bindings through the block scopes of every function and
+//! reports each name bound more than once, listing the line of every
+//! binding. Bindings in sibling blocks and nested functions are separate.
+//! A single rebinding whose initializer uses the previous value, such as
+//! `let name = name.trim();`, is accepted.
+//!
+//! ```rust
+//! # use cargo_quality::{analyzer::Analyzer, analyzers::ShadowingAnalyzer};
+//! let analyzer = ShadowingAnalyzer::new();
+//! let code = "fn f() { let x = 1; let x = 2; }";
+//! let ast = syn::parse_file(code).unwrap();
+//! let result = analyzer.analyze(&ast, code).unwrap();
+//! assert_eq!(result.issues.len(), 1);
+//! ```
+//!
+//! ## Unsafe Docs Analyzer
+//!
+//! Reports `unsafe` blocks and `unsafe impl`s that are not preceded by a
+//! `// SAFETY:` comment, and `unsafe fn`s that have neither a `# Safety`
+//! doc section nor such a comment. The comment may sit right above the
+//! block or above the statement containing it.
+//!
+//! ```rust
+//! # use cargo_quality::{analyzer::Analyzer, analyzers::UnsafeDocsAnalyzer};
+//! let analyzer = UnsafeDocsAnalyzer::new();
+//! let code = "fn f(p: *const u8) -> u8 { unsafe { *p } }";
+//! let ast = syn::parse_file(code).unwrap();
+//! let result = analyzer.analyze(&ast, code).unwrap();
+//! assert_eq!(result.issues.len(), 1);
+//! ```
+//!
+//! ## Clone In Loop Analyzer
+//!
+//! Reports `.clone()` and `.to_owned()` calls inside `for`, `while` and
+//! `loop` bodies, with the line the enclosing loop starts on. The iterated
+//! expression and the `while` condition are not part of the body, and
+//! functions nested in a loop start fresh.
+//!
+//! ```rust
+//! # use cargo_quality::{analyzer::Analyzer, analyzers::CloneInLoopAnalyzer};
+//! let analyzer = CloneInLoopAnalyzer::new();
+//! let code = "fn f(v: &[u8], s: String) { for _ in v { take(s.clone()); } }";
+//! let ast = syn::parse_file(code).unwrap();
+//! let result = analyzer.analyze(&ast, code).unwrap();
+//! assert_eq!(result.issues.len(), 1);
+//! ```
+//!
+//! ## Missing Must Use Analyzer
+//!
+//! Reports public inherent methods in library files that take `self` or
+//! `&self`, receive nothing by `&mut` and return `Self` (or the impl's own
+//! type), and methods consuming `self` that return another owned value.
+//! Methods returning `Result`, references or `impl Trait` are skipped.
+//! The fix inserts `#[must_use]` after the doc comments.
+//!
+//! ```rust
+//! # use cargo_quality::{analyzer::Analyzer, analyzers::MissingMustUseAnalyzer};
+//! let analyzer = MissingMustUseAnalyzer::new();
+//! let code = "impl B { pub fn port(self, port: u16) -> Self { self } }";
+//! let ast = syn::parse_file(code).unwrap();
+//! let result = analyzer.analyze(&ast, code).unwrap();
+//! assert_eq!(result.issues.len(), 1);
+//! ```
+//!
+//! ## Default Impl Analyzer
+//!
+//! Reports types with a zero-argument `pub fn new() -> Self` when the file
+//! neither derives nor implements `Default` for them. The fix adds an
+//! `impl Default` block with the same generics, delegating to `new()`.
+//!
+//! ```rust
+//! # use cargo_quality::{analyzer::Analyzer, analyzers::DefaultImplAnalyzer};
+//! let analyzer = DefaultImplAnalyzer::new();
+//! let code = "impl Pool { pub fn new() -> Self { Pool } }";
+//! let ast = syn::parse_file(code).unwrap();
+//! let result = analyzer.analyze(&ast, code).unwrap();
+//! assert_eq!(result.issues.len(), 1);
+//! ```
+//!
+//! ## Missing Debug Analyzer
+//!
+//! Reports public structs and enums in library files that neither derive
+//! nor implement `Debug` in the same file. The fix adds `Debug` to the
+//! first `#[derive(..)]` or inserts `#[derive(Debug)]`. Types with
+//! credential-like fields get no fix, since they need a redacting manual
+//! implementation.
+//!
+//! ```rust
+//! # use cargo_quality::{analyzer::Analyzer, analyzers::MissingDebugAnalyzer};
+//! let analyzer = MissingDebugAnalyzer::new();
+//! let code = "#[derive(Clone)] pub struct Endpoint { pub url: String }";
+//! let ast = syn::parse_file(code).unwrap();
+//! let result = analyzer.analyze(&ast, code).unwrap();
+//! assert_eq!(result.issues.len(), 1);
+//! ```
+//!
+//! ## Numeric Cast Analyzer
+//!
+//! Reports integer `as` casts that can truncate or change sign, such as
+//! `u64 as u32`, `i32 as u32` or `len() as u16`, when the source type is
+//! known from a typed parameter or `let`, a suffixed literal, a nested cast
+//! or a `len()`/`count()` call. Widening casts and float casts are ignored.
+//!
+//! ```rust
+//! # use cargo_quality::{analyzer::Analyzer, analyzers::NumericCastAnalyzer};
+//! let analyzer = NumericCastAnalyzer::new();
+//! let code = "fn f(id: u64) -> u32 { id as u32 }";
+//! let ast = syn::parse_file(code).unwrap();
+//! let result = analyzer.analyze(&ast, code).unwrap();
+//! assert_eq!(result.issues.len(), 1);
+//! ```
+//!
+//! ## Test Assertions Analyzer
+//!
+//! Reports `#[test]` functions whose body contains no `assert!`-family or
+//! `panic!` macro, no `.unwrap()`/`.expect(..)` and no `?`. Calls to
+//! asserting functions of the same file count as assertions, and
+//! `#[should_panic]` tests are skipped.
+//!
+//! ```rust
+//! # use cargo_quality::{analyzer::Analyzer, analyzers::TestAssertionsAnalyzer};
+//! let analyzer = TestAssertionsAnalyzer::new();
+//! let code = "#[test] fn test_parse() { parse(\"1\"); }";
+//! let ast = syn::parse_file(code).unwrap();
+//! let result = analyzer.analyze(&ast, code).unwrap();
+//! assert_eq!(result.issues.len(), 1);
+//! ```
+//!
+//! ## Assert Message Analyzer
+//!
+//! Reports `assert!`, `assert_eq!`, `assert_ne!` and their `debug_`
+//! variants without a message argument outside of test code, including
+//! `tests/` and `benches/`.
+//!
+//! ```rust
+//! # use cargo_quality::{analyzer::Analyzer, analyzers::AssertMessageAnalyzer};
+//! let analyzer = AssertMessageAnalyzer::new();
+//! let code = "fn f(n: usize) { assert!(n < 8); }";
+//! let ast = syn::parse_file(code).unwrap();
+//! let result = analyzer.analyze(&ast, code).unwrap();
+//! assert_eq!(result.issues.len(), 1);
+//! ```
+//!
+//! ## Super Imports Analyzer
 //!
-//! Detects empty lines inside function bodies that indicate the function
-//! is doing multiple things and should be refactored.
+//! Reports `use` items starting with two or more `super` segments. For files
+//! under `src/` the module path is derived from the file location, and `fix`
+//! rewrites the chain to the equivalent `crate::` path.
 //!
 //! ```rust
-//! # use cargo_quality::{analyzer::Analyzer, analyzers::EmptyLinesAnalyzer};
-//! let analyzer = EmptyLinesAnalyzer::new();
-//! let code = "fn main() {\n    let x = 1;\n\n    let y = 2;\n}";
+//! # use cargo_quality::{analyzer::Analyzer, analyzers::SuperImportsAnalyzer};
+//! let analyzer = SuperImportsAnalyzer::new();
+//! let code = "use super::super::config::Limits;";
 //! let ast = syn::parse_file(code).unwrap();
 //! let result = analyzer.analyze(&ast, code).unwrap();
 //! assert_eq!(result.issues.len(), 1);
 //! ```
 //!
-//! ## Inline Comments Analyzer
+//! ## Wildcard Re-export Analyzer
 //!
-//! Detects `//` comments inside function bodies. These should be moved
-//! to doc comments (`///`) in the `# Notes` section.
+//! Reports `pub use path::*` outside test code and `prelude` modules. When
+//! the target is an enum or a module whose public items can be read, inline
+//! or through `mod name;` next to the file, `fix` lists them instead of `*`.
 //!
 //! ```rust
-//! # use cargo_quality::{analyzer::Analyzer, analyzers::InlineCommentsAnalyzer};
-//! let analyzer = InlineCommentsAnalyzer::new();
-//! let code = "fn main() {\n    // This is flagged\n    let x = 1;\n}";
+//! # use cargo_quality::{analyzer::Analyzer, analyzers::WildcardReexportAnalyzer};
+//! let analyzer = WildcardReexportAnalyzer::new();
+//! let code = "pub mod shapes { pub struct Circle; }\npub use shapes::*;";
+//! let ast = syn::parse_file(code).unwrap();
+//! let result = analyzer.analyze(&ast, code).unwrap();
+//! assert_eq!(result.issues.len(), 1);
+//! assert_eq!(result.fixable_count, 1);
+//! ```
+//!
+//! ## Doc Width Analyzer
+//!
+//! Reports `///` and `//!` paragraphs with a line wider than rustfmt's
+//! `comment_width` of 80 columns, which `wrap_comments = true` only enforces
+//! on nightly. `fix` rewraps the paragraph; code blocks, tables, headings and
+//! single words too long to break are left alone.
+//!
+//! ```rust
+//! # use cargo_quality::{analyzer::Analyzer, analyzers::DocWidthAnalyzer};
+//! let analyzer = DocWidthAnalyzer::new();
+//! let code = format!("/// {}\nfn run() {{}}", "word ".repeat(20));
+//! let ast = syn::parse_file(&code).unwrap();
+//! let result = analyzer.analyze(&ast, &code).unwrap();
+//! assert_eq!(result.issues.len(), 1);
+//! assert_eq!(result.fixable_count, 1);
+//! ```
+//!
+//! ## Placeholder Docs Analyzer
+//!
+//! Reports `///` comments that document nothing: blank lines only, a lone
+//! `TODO`/`FIXME`/`TBD`/`XXX` marker, or the item name restated, such as
+//! `/// Parse config.` on `parse_config`. Such comments satisfy
+//! `missing_docs` while leaving the reader with no information.
+//!
+//! ```rust
+//! # use cargo_quality::{analyzer::Analyzer, analyzers::PlaceholderDocsAnalyzer};
+//! let analyzer = PlaceholderDocsAnalyzer::new();
+//! let code = "/// TODO\npub fn run() {}\n/// Run.\npub fn run_again() {}\n/// Starts the worker pool.\npub fn start() {}";
+//! let ast = syn::parse_file(code).unwrap();
+//! let result = analyzer.analyze(&ast, code).unwrap();
+//! assert_eq!(result.issues.len(), 1);
+//! ```
+//!
+//! ## Duplicate Literals Analyzer
+//!
+//! Crate-wide: indexes the string literals of every file in a `check` run and
+//! reports each one that appears three times or more, at every occurrence, with
+//! the locations of the other copies. Short literals, literals without letters,
+//! constant initializers, attributes, macro arguments and test code are
+//! skipped.
+//!
+//! ```rust
+//! # use std::path::PathBuf;
+//! # use cargo_quality::{analyzer::{Analyzer, SourceFile}, analyzers::DuplicateLiteralsAnalyzer};
+//! let code = "fn a() { f(\"application/json\"); f(\"application/json\"); f(\"application/json\"); }";
+//! let file = SourceFile {
+//!     path: PathBuf::from("src/lib.rs"),
+//!     ast:  syn::parse_file(code).unwrap()
+//! };
+//! let results = DuplicateLiteralsAnalyzer::new().analyze_crate(&[&file]).unwrap();
+//! assert_eq!(results[0].1.issues.len(), 3);
+//! ```
+//!
+//! ## Derive Order Analyzer
+//!
+//! Opt-in: checks every `#[derive(..)]` list against the order `Debug, Clone,
+//! Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default`, then other derives in
+//! their written order, then `Serialize` and `Deserialize`. The fix reorders
+//! the traits in place.
+//!
+//! ```rust
+//! # use cargo_quality::{analyzer::Analyzer, analyzers::DeriveOrderAnalyzer};
+//! let code = "#[derive(Serialize, Clone, Debug)] struct Config;";
+//! let ast = syn::parse_file(code).unwrap();
+//! let result = DeriveOrderAnalyzer::new().analyze(&ast, code).unwrap();
+//! assert_eq!(result.fixable_count, 1);
+//! ```
+//!
+//! ## Argument Order Analyzer
+//!
+//! Groups the functions and inherent methods of a file by the first word of
+//! their name (`render_header`, `render_footer` form the `render_*` family) and
+//! flags functions whose shared parameters, matched by name, are ordered
+//! differently from the majority of the family.
+//!
+//! ```rust
+//! # use cargo_quality::{analyzer::Analyzer, analyzers::ArgumentOrderAnalyzer};
+//! let code = "fn render_a(lines: u8, width: u8) {} fn render_b(lines: u8, width: u8) {} \
+//!             fn render_c(width: u8, lines: u8) {}";
+//! let ast = syn::parse_file(code).unwrap();
+//! let result = ArgumentOrderAnalyzer::new().analyze(&ast, code).unwrap();
+//! assert_eq!(result.issues.len(), 1);
+//! ```
+//!
+//! ## Allow Audit Analyzer
+//!
+//! Reports every `#[allow(..)]` and `#![allow(..)]` outside test code that has
+//! no `reason = ".."` argument and no comment on the line above it or after it
+//! on the same line.
+//!
+//! ```rust
+//! # use cargo_quality::{analyzer::Analyzer, analyzers::AllowAuditAnalyzer};
+//! let code = "#[allow(dead_code)]\nfn legacy() {}";
+//! let ast = syn::parse_file(code).unwrap();
+//! let result = AllowAuditAnalyzer::new().analyze(&ast, code).unwrap();
+//! assert_eq!(result.issues.len(), 1);
+//! ```
+//!
+//! ## Required Debug Analyzer
+//!
+//! Flags structs and enums of any visibility whose name ends in `Error`,
+//! `Config` or `Options` that neither derive nor implement `Debug`. The
+//! suffixes are configured with `metadata.quality.debug_suffixes`. Types with
+//! credential-like fields are reported without a fix.
+//!
+//! ```rust
+//! # use cargo_quality::{analyzer::Analyzer, analyzers::RequiredDebugAnalyzer};
+//! let code = "struct RetryConfig { attempts: u32 }";
+//! let ast = syn::parse_file(code).unwrap();
+//! let result = RequiredDebugAnalyzer::new().analyze(&ast, code).unwrap();
+//! assert_eq!(result.fixable_count, 1);
+//! ```
+//!
+//! ## Naming Analyzer
+//!
+//! Flags `let` bindings named with a single letter, a placeholder such as `tmp`
+//! or `foo`, or a generic word with a number such as `data2`. Loop counters
+//! `i`, `j` and `k`, closure bodies and test code are not checked.
+//!
+//! ```rust
+//! # use cargo_quality::{analyzer::Analyzer, analyzers::NamingAnalyzer};
+//! let code = "fn f() { let tmp = 1; let count = tmp; }";
+//! let ast = syn::parse_file(code).unwrap();
+//! let result = NamingAnalyzer::new().analyze(&ast, code).unwrap();
+//! assert_eq!(result.issues.len(), 1);
+//! ```
+//!
+//! ## Predicate Naming Analyzer
+//!
+//! Flags public functions and inherent methods returning `bool` whose name has
+//! no predicate prefix (`is_`, `has_`, `can_`, `should_`, ...), is not a
+//! standard predicate such as `eq` or `insert`, and does not start with a
+//! third-person verb such as `contains` or `applies`.
+//!
+//! ```rust
+//! # use cargo_quality::{analyzer::Analyzer, analyzers::PredicateNamingAnalyzer};
+//! let code = "pub fn valid(name: &str) -> bool { !name.is_empty() }";
+//! let ast = syn::parse_file(code).unwrap();
+//! let result = PredicateNamingAnalyzer::new().analyze(&ast, code).unwrap();
+//! assert_eq!(result.issues.len(), 1);
+//! ```
+//!
+//! ## Discarded Result Analyzer
+//!
+//! Flags `let _ = expr;` where `expr` is a call known to return `Result`:
+//! functions of the same file declared to return `Result`, `std::fs`
+//! functions, `write!`/`writeln!` and common fallible methods such as
+//! `flush`, `send` or `join`.
+//!
+//! ```rust
+//! # use cargo_quality::{analyzer::Analyzer, analyzers::DiscardedResultAnalyzer};
+//! let code = "fn f() { let _ = fs::remove_file(\"lock\"); }";
+//! let ast = syn::parse_file(code).unwrap();
+//! let result = DiscardedResultAnalyzer::new().analyze(&ast, code).unwrap();
+//! assert_eq!(result.issues.len(), 1);
+//! ```
+//!
+//! ## Global State Analyzer
+//!
+//! Flags `static mut` items, including those declared in `extern` blocks, and
+//! `unsafe impl Send` / `unsafe impl Sync`. Both bypass the compiler's data
+//! race checks and are almost always correctness hazards, so issues are
+//! reported with [`Severity::Error`](crate::analyzer::Severity::Error). Test
+//! code is not checked.
+//!
+//! ```rust
+//! # use cargo_quality::{analyzer::{Analyzer, Severity}, analyzers::GlobalStateAnalyzer};
+//! let analyzer = GlobalStateAnalyzer::new();
+//! let code = "static mut COUNTER: u64 = 0; unsafe impl Send for Handle {}";
 //! let ast = syn::parse_file(code).unwrap();
 //! let result = analyzer.analyze(&ast, code).unwrap();
+//! assert_eq!(result.issues.len(), 2);
+//! assert_eq!(analyzer.severity(), Severity::Error);
+//! ```
+//!
+//! ## API Types Analyzer
+//!
+//! Flags parameters typed `&String`, `&Vec<T>`, `&Box<T>` or `Box<Vec<T>>` and
+//! suggests `&str`, `&[T]`, `&T` and `Vec<T>`. The borrowed forms are fixed in
+//! free functions and inherent methods, where callers keep compiling through
+//! deref coercion; trait methods and `Box<Vec<T>>` are reported only.
+//!
+//! ```rust
+//! # use cargo_quality::{analyzer::Analyzer, analyzers::ApiTypesAnalyzer};
+//! let code = "fn greet(name: &String, scores: &Vec<u32>) {}";
+//! let ast = syn::parse_file(code).unwrap();
+//! let result = ApiTypesAnalyzer::new().analyze(&ast, code).unwrap();
+//! assert_eq!(result.issues.len(), 2);
+//! assert_eq!(result.fixable_count, 2);
+//! ```
+//!
+//! ## Error Sources Analyzer
+//!
+//! Flags functions converting errors before `?` in a way that drops the
+//! original error, with `map_err` closures ignoring their argument
+//! (`|_| ..`, `|_e| ..`) or `.ok().ok_or(..)`, and `From` impls whose `from`
+//! ignores the converted error. One issue per function lists the lines of its
+//! conversions. The rule works on the shape of expressions, without type
+//! information, so its confidence is medium.
+//!
+//! ```rust
+//! # use cargo_quality::{analyzer::Analyzer, analyzers::ErrorSourcesAnalyzer};
+//! let code = r#"
+//!     fn load(path: &Path) -> Result<String, LoadError> {
+//!         fs::read_to_string(path).map_err(|_| LoadError)?;
+//!         Ok(String::new())
+//!     }
+//! "#;
+//! let ast = syn::parse_file(code).unwrap();
+//! let result = ErrorSourcesAnalyzer::new().analyze(&ast, code).unwrap();
+//! assert_eq!(result.issues.len(), 1);
+//! ```
+//!
+//! ## Async Blocking Analyzer
+//!
+//! Flags `thread::sleep`, `std::fs` functions and blocking channel receives
+//! (`recv`, `recv_timeout`, `blocking_recv`) inside `async fn` bodies, async
+//! blocks and async closures. Awaited calls are async counterparts and are
+//! not reported, and neither are calls in plain closures, which usually run
+//! through `spawn_blocking`.
+//!
+//! ```rust
+//! # use cargo_quality::{analyzer::Analyzer, analyzers::AsyncBlockingAnalyzer};
+//! let code = "async fn poll() { std::thread::sleep(DELAY); tokio::time::sleep(DELAY).await; }";
+//! let ast = syn::parse_file(code).unwrap();
+//! let result = AsyncBlockingAnalyzer::new().analyze(&ast, code).unwrap();
+//! assert_eq!(result.issues.len(), 1);
+//! ```
+//!
+//! ## Data Integrity Analyzer
+//!
+//! Flags `as u8` casts of values known to be `char`, `as char` casts of bytes
+//! read from input, and `from_utf8_unchecked` calls. Casts of literals,
+//! constants and arithmetic on literals are ignored.
+//!
+//! ```rust
+//! # use cargo_quality::{analyzer::Analyzer, analyzers::DataIntegrityAnalyzer};
+//! let code = "fn f(s: &str) -> Vec<u8> { s.chars().map(|c| c as u8).collect() }";
+//! let ast = syn::parse_file(code).unwrap();
+//! let result = DataIntegrityAnalyzer::new().analyze(&ast, code).unwrap();
+//! assert_eq!(result.issues.len(), 1);
+//! ```
+//!
+//! ## Non-Exhaustive Errors Analyzer
+//!
+//! Flags public enums named `*Error`, deriving `Error` or implementing
+//! `std::error::Error` in the same file that lack `#[non_exhaustive]`, and
+//! inserts the attribute after their other attributes. Only library files
+//! are checked.
+//!
+//! ```rust
+//! # use cargo_quality::{analyzer::Analyzer, analyzers::NonExhaustiveErrorsAnalyzer};
+//! let code = "pub enum ParseError { Empty }";
+//! let ast = syn::parse_file(code).unwrap();
+//! let result = NonExhaustiveErrorsAnalyzer::new()
+//!     .analyze(&ast, code)
+//!     .unwrap();
+//! assert_eq!(result.issues.len(), 1);
+//! ```
+//!
+//! ## Unbounded Recursion Analyzer
+//!
+//! Flags functions that call themselves directly, as `name(..)`,
+//! `Self::name(..)` or `self.name(..)`, and take no parameter named like
+//! `depth`, `level` or `limit`. The issue points at the first recursive call.
+//!
+//! ```rust
+//! # use cargo_quality::{analyzer::Analyzer, analyzers::UnboundedRecursionAnalyzer};
+//! let code = "fn walk(node: &Node) { walk(&node.next); }";
+//! let ast = syn::parse_file(code).unwrap();
+//! let result = UnboundedRecursionAnalyzer::new()
+//!     .analyze(&ast, code)
+//!     .unwrap();
 //! assert_eq!(result.issues.len(), 1);
 //! ```
+//!
+//! ## Error Display Analyzer
+//!
+//! Crate-wide: collects the `Display` implementations of every file in a
+//! `check` run and reports structs and enums named `*Error`, or implementing
+//! `std::error::Error`, that have none. Types deriving `Error` or `Display`
+//! and test code are skipped.
+//!
+//! ```rust
+//! # use std::path::PathBuf;
+//! # use cargo_quality::{analyzer::{Analyzer, SourceFile}, analyzers::ErrorDisplayAnalyzer};
+//! let file = SourceFile {
+//!     path: PathBuf::from("src/error.rs"),
+//!     ast:  syn::parse_file("pub struct ConfigError;").unwrap()
+//! };
+//! let results = ErrorDisplayAnalyzer::new().analyze_crate(&[&file]).unwrap();
+//! assert_eq!(results[0].1.issues.len(), 1);
+//! ```
+//!
+//! ## Redundant Syntax Analyzer
+//!
+//! Flags `return expr;` as the last statement of a function and semicolons
+//! after block-like expressions known to be `()`, and removes them.
+//!
+//! ```rust
+//! # use cargo_quality::{analyzer::Analyzer, analyzers::RedundantSyntaxAnalyzer};
+//! let code = "fn f(x: u32) -> u32 { for _ in 0..x {}; return x; }";
+//! let ast = syn::parse_file(code).unwrap();
+//! let result = RedundantSyntaxAnalyzer::new().analyze(&ast, code).unwrap();
+//! assert_eq!(result.issues.len(), 2);
+//! ```
+//!
+//! ## Impl Location Analyzer
+//!
+//! Runs over the whole crate at once: indexes the structs, enums and unions
+//! of every file and flags inherent impls in another file than their type.
+//! Trait impls, including extension traits, are not reported.
+//!
+//! ```rust
+//! # use cargo_quality::{analyzer::{Analyzer, SourceFile}, analyzers::ImplLocationAnalyzer};
+//! # use std::path::PathBuf;
+//! let config = SourceFile {
+//!     path: PathBuf::from("src/config.rs"),
+//!     ast:  syn::parse_file("pub struct Config;").unwrap()
+//! };
+//! let loader = SourceFile {
+//!     path: PathBuf::from("src/loader.rs"),
+//!     ast:  syn::parse_file("impl Config { fn load(&self) {} }").unwrap()
+//! };
+//! let results = ImplLocationAnalyzer::new()
+//!     .analyze_crate(&[&config, &loader])
+//!     .unwrap();
+//! assert_eq!(results[0].0, PathBuf::from("src/loader.rs"));
+//! ```
 
+pub mod allow_audit;
+pub mod api_types;
+pub mod argument_order;
+pub mod assert_message;
+pub mod async_blocking;
+pub mod bool_result;
+pub mod clone_in_loop;
+pub mod conversion_impls;
+pub mod data_integrity;
+pub mod debug_artifacts;
+pub mod default_impl;
+pub mod derive_order;
+pub mod discarded_result;
+pub mod doc_width;
+pub mod duplicate_literals;
 pub mod empty_lines;
+pub mod error_convention;
+pub mod error_display;
+pub mod error_sources;
+pub mod file_length;
 pub mod format_args;
+pub mod function_length;
+pub mod glob_import;
+pub mod global_state;
+pub mod hardcoded_paths;
+pub mod hardcoded_secrets;
+pub mod impl_location;
+pub mod import_order;
 pub mod inline_comments;
+pub mod item_order;
+pub mod license_header;
+pub mod line_length;
+pub mod mergeable_impls;
+pub mod missing_debug;
+pub mod missing_docs;
+pub mod missing_errors_doc;
+pub mod missing_inline;
+pub mod missing_instrument;
+pub mod missing_must_use;
+pub mod missing_panics_doc;
+pub mod naming;
+pub mod needless_allocation;
+pub mod nested_closures;
+pub mod nesting_depth;
+pub mod non_exhaustive_errors;
+pub mod numeric_cast;
+pub mod numeric_overflow;
 pub mod path_import;
+pub mod placeholder_docs;
+pub mod predicate_naming;
+pub mod print_logging;
+pub mod pub_fields;
+pub mod redundant_imports;
+pub mod redundant_syntax;
+pub mod required_debug;
+pub mod sensitive_debug;
+pub mod shadowing;
+pub mod sharing_complexity;
+pub mod sql_injection;
+pub mod stringly_typed;
+pub mod super_imports;
+pub mod test_assertions;
+pub mod test_modules;
+pub mod time_source;
+pub mod todo_comments;
+pub mod trait_bounds;
+pub mod type_size;
+pub mod unbounded_queue;
+pub mod unbounded_recursion;
+pub mod unsafe_docs;
+pub mod unwrap;
+pub mod variant_names;
+pub mod whitespace;
+pub mod wildcard_reexport;
 
-use std::collections::HashSet;
+use std::{
+    collections::HashSet,
+    ops::Range,
+    path::{Component, Path}
+};
 
+pub use allow_audit::AllowAuditAnalyzer;
+pub use api_types::ApiTypesAnalyzer;
+pub use argument_order::ArgumentOrderAnalyzer;
+pub use assert_message::AssertMessageAnalyzer;
+pub use async_blocking::AsyncBlockingAnalyzer;
+pub use bool_result::BoolResultAnalyzer;
+pub use clone_in_loop::CloneInLoopAnalyzer;
+use console::measure_text_width;
+pub use conversion_impls::ConversionImplsAnalyzer;
+pub use data_integrity::DataIntegrityAnalyzer;
+pub use debug_artifacts::DebugArtifactsAnalyzer;
+pub use default_impl::DefaultImplAnalyzer;
+pub use derive_order::DeriveOrderAnalyzer;
+pub use discarded_result::DiscardedResultAnalyzer;
+pub use doc_width::DocWidthAnalyzer;
+pub use duplicate_literals::DuplicateLiteralsAnalyzer;
 pub use empty_lines::EmptyLinesAnalyzer;
+pub use error_convention::ErrorConventionAnalyzer;
+pub use error_display::ErrorDisplayAnalyzer;
+pub use error_sources::ErrorSourcesAnalyzer;
+pub use file_length::FileLengthAnalyzer;
 pub use format_args::FormatArgsAnalyzer;
+pub use function_length::FunctionLengthAnalyzer;
+pub use glob_import::GlobImportAnalyzer;
+pub use global_state::GlobalStateAnalyzer;
+pub use hardcoded_paths::HardcodedPathsAnalyzer;
+pub use hardcoded_secrets::HardcodedSecretsAnalyzer;
+pub use impl_location::ImplLocationAnalyzer;
+pub use import_order::ImportOrderAnalyzer;
 pub use inline_comments::InlineCommentsAnalyzer;
+pub use item_order::ItemOrderAnalyzer;
+pub use license_header::LicenseHeaderAnalyzer;
+pub use line_length::LineLengthAnalyzer;
+pub use mergeable_impls::MergeableImplsAnalyzer;
+pub use missing_debug::MissingDebugAnalyzer;
+pub use missing_docs::MissingDocsAnalyzer;
+pub use missing_errors_doc::MissingErrorsDocAnalyzer;
+pub use missing_inline::MissingInlineAnalyzer;
+pub use missing_instrument::MissingInstrumentAnalyzer;
+pub use missing_must_use::MissingMustUseAnalyzer;
+pub use missing_panics_doc::MissingPanicsDocAnalyzer;
+pub use naming::NamingAnalyzer;
+pub use needless_allocation::NeedlessAllocationAnalyzer;
+pub use nested_closures::NestedClosuresAnalyzer;
+pub use nesting_depth::NestingDepthAnalyzer;
+pub use non_exhaustive_errors::NonExhaustiveErrorsAnalyzer;
+pub use numeric_cast::NumericCastAnalyzer;
+pub use numeric_overflow::NumericOverflowAnalyzer;
 pub use path_import::PathImportAnalyzer;
-use syn::{File, Lit, visit::Visit};
+pub use placeholder_docs::PlaceholderDocsAnalyzer;
+pub use predicate_naming::PredicateNamingAnalyzer;
+pub use print_logging::PrintLoggingAnalyzer;
+use proc_macro2::{TokenStream, TokenTree};
+pub use pub_fields::PubFieldsAnalyzer;
+pub use redundant_imports::RedundantImportsAnalyzer;
+pub use redundant_syntax::RedundantSyntaxAnalyzer;
+pub use required_debug::RequiredDebugAnalyzer;
+pub use sensitive_debug::SensitiveDebugAnalyzer;
+pub use shadowing::ShadowingAnalyzer;
+pub use sharing_complexity::SharingComplexityAnalyzer;
+pub use sql_injection::SqlInjectionAnalyzer;
+pub use stringly_typed::StringlyTypedAnalyzer;
+pub use super_imports::SuperImportsAnalyzer;
+use syn::{
+    Attribute, Expr, File, GenericArgument, Lit, PathArguments, ReturnType, Type,
+    punctuated::Punctuated, visit::Visit
+};
+pub use test_assertions::TestAssertionsAnalyzer;
+pub use test_modules::TestModulesAnalyzer;
+pub use time_source::TimeSourceAnalyzer;
+pub use todo_comments::TodoCommentsAnalyzer;
+pub use trait_bounds::TraitBoundsAnalyzer;
+pub use type_size::TypeSizeAnalyzer;
+pub use unbounded_queue::UnboundedQueueAnalyzer;
+pub use unbounded_recursion::UnboundedRecursionAnalyzer;
+pub use unsafe_docs::UnsafeDocsAnalyzer;
+pub use unwrap::UnwrapAnalyzer;
+pub use variant_names::VariantNamesAnalyzer;
+pub use whitespace::WhitespaceAnalyzer;
+pub use wildcard_reexport::WildcardReexportAnalyzer;
 
-use crate::analyzer::Analyzer;
+use crate::{analyzer::Analyzer, project::ProjectContext};
 
 /// Collects line numbers that lie inside multi-line literals.
 ///
@@ -173,6 +1724,248 @@ pub(crate) fn multiline_literal_lines(ast: &File) -> HashSet<usize> {
     visitor.lines
 }
 
+/// Default marker attribute for generated items.
+///
+/// Large generated functions such as state machine `match`es legitimately
+/// contain blank separators and exceed length limits. The `empty_lines` and
+/// `function_length` analyzers skip items annotated with the marker; the
+/// `cargo-quality-attributes` crate provides it as a no-op attribute.
+pub const GENERATED_MARKER: &str = "quality::generated";
+
+/// Checks whether attributes include a marker attribute.
+///
+/// # Arguments
+///
+/// * `attrs` - Attributes of the item
+/// * `marker` - Attribute path, e.g. `"quality::generated"`
+///
+/// # Returns
+///
+/// `true` if an attribute's path equals the marker
+pub(crate) fn has_marker(attrs: &[Attribute], marker: &str) -> bool {
+    attrs.iter().any(|attr| {
+        let path: Vec<String> = attr
+            .path()
+            .segments
+            .iter()
+            .map(|segment| segment.ident.to_string())
+            .collect();
+        path.join("::") == marker
+    })
+}
+
+/// Check whether attributes mark an item as test-only.
+pub(crate) fn is_test_code(attrs: &[Attribute]) -> bool {
+    attrs.iter().any(|attr| {
+        attr.path().is_ident("test")
+            || (attr.path().is_ident("cfg")
+                && attr
+                    .meta
+                    .require_list()
+                    .is_ok_and(|list| list.tokens.to_string() == "test"))
+    })
+}
+
+/// Directories whose files are binaries, examples or tests.
+const NON_LIBRARY_DIRS: &[&str] = &["bin", "examples", "benches", "tests"];
+
+/// Check whether a file belongs to a library rather than a binary.
+///
+/// `main.rs`, `build.rs` and files under `bin/`, `examples/`, `benches/` or
+/// `tests/` are excluded.
+///
+/// # Examples
+///
+/// ```
+/// use std::path::Path;
+///
+/// use cargo_quality::{analyzer::Analyzer, analyzers::DebugArtifactsAnalyzer};
+///
+/// let analyzer = DebugArtifactsAnalyzer::new();
+/// assert!(analyzer.applies_to(Path::new("src/parser.rs")));
+/// assert!(!analyzer.applies_to(Path::new("src/main.rs")));
+/// assert!(!analyzer.applies_to(Path::new("src/bin/tool.rs")));
+/// ```
+pub(crate) fn is_library_path(path: &Path) -> bool {
+    let file_name = path.file_name().and_then(|name| name.to_str());
+    if matches!(file_name, Some("main.rs" | "build.rs")) {
+        return false;
+    }
+
+    !path.components().any(|component| {
+        matches!(component, Component::Normal(dir) if NON_LIBRARY_DIRS.iter().any(|name| dir == *name))
+    })
+}
+
+/// Find a trait in the `#[derive(..)]` attributes of an item.
+///
+/// # Arguments
+///
+/// * `attrs` - Outer attributes of the item
+/// * `name` - Trait name, matched against the last path segment
+///
+/// # Returns
+///
+/// Path of the derived trait, e.g. `Debug` or `std::fmt::Debug`
+pub(crate) fn find_derive(attrs: &[Attribute], name: &str) -> Option<syn::Path> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("derive"))
+        .filter_map(|attr| {
+            attr.parse_args_with(Punctuated::<syn::Path, syn::Token![,]>::parse_terminated)
+                .ok()
+        })
+        .flatten()
+        .find(|path| path.segments.last().is_some_and(|s| s.ident == name))
+}
+
+/// Name of a type, looking through references and ignoring generics.
+pub(crate) fn type_name(ty: &Type) -> Option<String> {
+    match ty {
+        Type::Reference(reference) => type_name(&reference.elem),
+        Type::Path(path) => path.path.segments.last().map(|s| s.ident.to_string()),
+        _ => None
+    }
+}
+
+/// First type argument of a path segment, e.g. `A` in `From<A>`.
+pub(crate) fn first_type_argument(arguments: &PathArguments) -> Option<&Type> {
+    let PathArguments::AngleBracketed(arguments) = arguments else {
+        return None;
+    };
+    arguments.args.iter().find_map(|argument| match argument {
+        GenericArgument::Type(ty) => Some(ty),
+        _ => None
+    })
+}
+
+/// Check whether a return type is `Result` or an alias like `AppResult`.
+pub(crate) fn returns_result(output: &ReturnType) -> bool {
+    let ReturnType::Type(_, ty) = output else {
+        return false;
+    };
+    let Type::Path(path) = ty.as_ref() else {
+        return false;
+    };
+
+    path.path
+        .segments
+        .last()
+        .is_some_and(|segment| segment.ident.to_string().ends_with("Result"))
+}
+
+/// Describe the fallible call an outcome check is applied to.
+///
+/// # Returns
+///
+/// `Some("fs::write(..)")` or `Some("parse(..)")` for call receivers, `None`
+/// for other expressions such as plain variables
+pub(crate) fn describe_call(receiver: &Expr) -> Option<String> {
+    match receiver {
+        Expr::Call(call) => match call.func.as_ref() {
+            Expr::Path(path) => {
+                let segments: Vec<_> = path
+                    .path
+                    .segments
+                    .iter()
+                    .map(|segment| segment.ident.to_string())
+                    .collect();
+                Some(format!("{}(..)", segments.join("::")))
+            }
+            _ => None
+        },
+        Expr::MethodCall(call) => Some(format!("{}(..)", call.method)),
+        Expr::Await(await_expr) => describe_call(&await_expr.base),
+        Expr::Paren(paren) => describe_call(&paren.expr),
+        _ => None
+    }
+}
+
+/// Widen a statement range to its whole lines when nothing else shares them.
+///
+/// # Arguments
+///
+/// * `content` - Source code
+/// * `range` - Byte range of the statement
+///
+/// # Returns
+///
+/// Range covering the lines including their newline, or `range` unchanged
+pub(crate) fn whole_lines(content: &str, range: Range<usize>) -> Range<usize> {
+    let line_start = content[..range.start].rfind('\n').map_or(0, |idx| idx + 1);
+    let line_end = content[range.end..]
+        .find('\n')
+        .map_or(content.len(), |idx| range.end + idx + 1);
+
+    let before = &content[line_start..range.start];
+    let after = &content[range.end..line_end];
+    if before.trim().is_empty() && after.trim().is_empty() {
+        line_start..line_end
+    } else {
+        range
+    }
+}
+
+/// Line ending of a file, for fixes inserting whole lines.
+///
+/// # Returns
+///
+/// `"\r\n"` if the first line ends with it, `"\n"` otherwise
+pub(crate) fn line_ending(content: &str) -> &'static str {
+    match content.find('\n') {
+        Some(idx) if content[..idx].ends_with('\r') => "\r\n",
+        _ => "\n"
+    }
+}
+
+/// Byte ranges of the string, byte string and char literals of a file.
+///
+/// # Returns
+///
+/// Ranges sorted by start, empty if the file cannot be tokenized
+pub(crate) fn literal_ranges(content: &str) -> Vec<Range<usize>> {
+    let mut literals = Vec::new();
+    if let Ok(tokens) = content.parse::<TokenStream>() {
+        collect_literals(tokens, content, &mut literals);
+    }
+    literals.sort_by_key(|range| range.start);
+    literals
+}
+
+/// Collect byte ranges of string, byte string and char literals.
+///
+/// Doc comments are lexed as `#[doc = "..."]` literals whose span covers the
+/// comment itself; those are kept out so their markers are still found.
+fn collect_literals(tokens: TokenStream, content: &str, literals: &mut Vec<Range<usize>>) {
+    for token in tokens {
+        match token {
+            TokenTree::Group(group) => collect_literals(group.stream(), content, literals),
+            TokenTree::Literal(literal) => {
+                let range = literal.span().byte_range();
+                let is_comment = content
+                    .get(range.clone())
+                    .is_some_and(|text| text.starts_with("//") || text.starts_with("/*"));
+                if !is_comment {
+                    literals.push(range);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Columns a tab occupies, matching rustfmt's default `tab_spaces`.
+const TAB_WIDTH: usize = 4;
+
+/// Visual width of a line, with tabs expanded.
+pub(crate) fn line_width(line: &str) -> usize {
+    if line.contains('\t') {
+        measure_text_width(&line.replace('\t', &" ".repeat(TAB_WIDTH)))
+    } else {
+        measure_text_width(line)
+    }
+}
+
 /// Returns all built-in analyzers.
 ///
 /// This function creates new instances of all available analyzers.
@@ -185,6 +1978,79 @@ pub(crate) fn multiline_literal_lines(ast: &File) -> HashSet<usize> {
 /// 2. [`FormatArgsAnalyzer`] - format argument detection
 /// 3. [`EmptyLinesAnalyzer`] - empty line detection
 /// 4. [`InlineCommentsAnalyzer`] - inline comment detection
+/// 5. [`NeedlessAllocationAnalyzer`] - owned parameter detection
+/// 6. [`SharingComplexityAnalyzer`] - shared ownership stack detection
+/// 7. [`PrintLoggingAnalyzer`] - print logging detection
+/// 8. [`MissingInstrumentAnalyzer`] - instrumentation detection (opt-in)
+/// 9. [`TimeSourceAnalyzer`] - clock and timezone usage detection (opt-in)
+/// 10. [`UnwrapAnalyzer`] - unwrap/expect detection
+/// 11. [`UnboundedQueueAnalyzer`] - unbounded channel and queue detection
+/// 12. [`HardcodedSecretsAnalyzer`] - hardcoded credential detection
+/// 13. [`DebugArtifactsAnalyzer`] - leftover debug macro detection
+/// 14. [`HardcodedPathsAnalyzer`] - hardcoded path and URL detection
+/// 15. [`FunctionLengthAnalyzer`] - long function detection
+/// 16. [`SqlInjectionAnalyzer`] - SQL string construction detection
+/// 17. [`NumericOverflowAnalyzer`] - overflow-prone arithmetic (opt-in)
+/// 18. [`NestingDepthAnalyzer`] - deep nesting detection
+/// 19. [`ErrorConventionAnalyzer`] - error type convention check (opt-in)
+/// 20. [`BoolResultAnalyzer`] - bool-collapsed error detection
+/// 21. [`GlobImportAnalyzer`] - glob import detection
+/// 22. [`NestedClosuresAnalyzer`] - nested closure detection
+/// 23. [`ImportOrderAnalyzer`] - import grouping and ordering
+/// 24. [`TraitBoundsAnalyzer`] - overly long trait bound detection
+/// 25. [`RedundantImportsAnalyzer`] - redundant import detection
+/// 26. [`MissingInlineAnalyzer`] - missing `#[inline]` detection
+/// 27. [`MissingDocsAnalyzer`] - missing documentation detection
+/// 28. [`SensitiveDebugAnalyzer`] - secret-leaking `Debug` detection
+/// 29. [`MissingErrorsDocAnalyzer`] - missing `# Errors` section detection
+/// 30. [`VariantNamesAnalyzer`] - redundant variant prefix detection
+/// 31. [`MissingPanicsDocAnalyzer`] - missing `# Panics` section detection
+/// 32. [`LicenseHeaderAnalyzer`] - SPDX license header check
+/// 33. [`MergeableImplsAnalyzer`] - mergeable impl block detection
+/// 34. [`TodoCommentsAnalyzer`] - TODO marker tracking (opt-in)
+/// 35. [`TestModulesAnalyzer`] - test module layout check
+/// 36. [`ItemOrderAnalyzer`] - configurable item order check (opt-in)
+/// 37. [`LineLengthAnalyzer`] - line width check
+/// 38. [`PubFieldsAnalyzer`] - public struct field check
+/// 39. [`WhitespaceAnalyzer`] - whitespace hygiene check
+/// 40. [`StringlyTypedAnalyzer`] - stringly-typed signature check
+/// 41. [`FileLengthAnalyzer`] - oversized file check
+/// 42. [`ConversionImplsAnalyzer`] - conversion trait completeness check
+/// 43. [`TypeSizeAnalyzer`] - enum variant and struct field count check
+/// 44. [`ShadowingAnalyzer`] - let rebinding chain check
+/// 45. [`UnsafeDocsAnalyzer`] - SAFETY comment check for unsafe code
+/// 46. [`CloneInLoopAnalyzer`] - clone and to_owned inside loop check
+/// 47. [`MissingMustUseAnalyzer`] - must_use attribute check for builder-like
+///     methods
+/// 48. [`DefaultImplAnalyzer`] - Default implementation check for zero-argument
+///     constructors
+/// 49. [`MissingDebugAnalyzer`] - Debug implementation check for public types
+/// 50. [`NumericCastAnalyzer`] - lossy integer cast detection
+/// 51. [`TestAssertionsAnalyzer`] - vacuous test detection
+/// 52. [`AssertMessageAnalyzer`] - bare assertion detection
+/// 53. [`SuperImportsAnalyzer`] - deep relative import detection
+/// 54. [`WildcardReexportAnalyzer`] - wildcard re-export detection
+/// 55. [`DocWidthAnalyzer`] - doc comment width detection
+/// 56. [`PlaceholderDocsAnalyzer`] - placeholder doc comment detection
+/// 57. [`DuplicateLiteralsAnalyzer`] - crate-wide duplicate string literal
+///     detection
+/// 58. [`DeriveOrderAnalyzer`] - canonical derive list ordering
+/// 59. [`ArgumentOrderAnalyzer`] - function family parameter order consistency
+/// 60. [`AllowAuditAnalyzer`] - unjustified lint suppression detection
+/// 61. [`RequiredDebugAnalyzer`] - required `Debug` on error and config types
+/// 62. [`NamingAnalyzer`] - non-descriptive variable name detection
+/// 63. [`PredicateNamingAnalyzer`] - predicate function naming detection
+/// 64. [`DiscardedResultAnalyzer`] - discarded Result detection
+/// 65. [`GlobalStateAnalyzer`] - static mut and unsafe Send/Sync detection
+/// 66. [`ApiTypesAnalyzer`] - borrowed container parameter detection
+/// 67. [`ErrorSourcesAnalyzer`] - discarded error source detection
+/// 68. [`AsyncBlockingAnalyzer`] - blocking call in async code detection
+/// 69. [`DataIntegrityAnalyzer`] - lossy byte and character conversion
+/// 70. [`NonExhaustiveErrorsAnalyzer`] - non_exhaustive error enum detection
+/// 71. [`UnboundedRecursionAnalyzer`] - unbounded self-recursion detection
+/// 72. [`ErrorDisplayAnalyzer`] - error type without Display detection
+/// 73. [`RedundantSyntaxAnalyzer`] - redundant return and semicolon detection
+/// 74. [`ImplLocationAnalyzer`] - inherent impl location detection
 ///
 /// # Examples
 ///
@@ -192,29 +2058,167 @@ pub(crate) fn multiline_literal_lines(ast: &File) -> HashSet<usize> {
 /// use cargo_quality::{analyzer::Analyzer, analyzers::get_analyzers};
 ///
 /// let analyzers = get_analyzers();
-/// assert_eq!(analyzers.len(), 4);
+/// assert_eq!(analyzers.len(), 74);
 ///
 /// for analyzer in &analyzers {
 ///     println!("Analyzer: {}", analyzer.name());
 /// }
 /// ```
 pub fn get_analyzers() -> Vec<Box<dyn Analyzer>> {
+    get_analyzers_for(&ProjectContext::default())
+}
+
+/// Returns all built-in analyzers configured for a project.
+///
+/// Analyzers that depend on the project's dependencies (such as
+/// [`PrintLoggingAnalyzer`]) are configured from `project`; all others are
+/// identical to [`get_analyzers`]. Opt-in analyzers are included; filter
+/// them with [`ProjectContext::is_active`] for a default run.
+///
+/// # Arguments
+///
+/// * `project` - Context discovered from the project's `Cargo.toml`
+///
+/// # Returns
+///
+/// Vector of boxed analyzer trait objects, in the same order as
+/// [`get_analyzers`]
+///
+/// # Examples
+///
+/// ```rust
+/// use cargo_quality::{analyzers::get_analyzers_for, project::ProjectContext};
+///
+/// let project = ProjectContext::from_manifest_str("[dependencies]\ntracing = \"0.1\"").unwrap();
+/// let analyzers = get_analyzers_for(&project);
+/// assert!(analyzers.iter().any(|a| a.name() == "print_logging"));
+/// ```
+pub fn get_analyzers_for(project: &ProjectContext) -> Vec<Box<dyn Analyzer>> {
     vec![
         Box::new(PathImportAnalyzer::new()),
         Box::new(FormatArgsAnalyzer::new()),
-        Box::new(EmptyLinesAnalyzer::new()),
+        Box::new(
+            EmptyLinesAnalyzer::with_policy(project.empty_lines())
+                .with_generated_marker(project.generated_marker())
+        ),
         Box::new(InlineCommentsAnalyzer::new()),
+        Box::new(NeedlessAllocationAnalyzer::new()),
+        Box::new(SharingComplexityAnalyzer::new()),
+        Box::new(PrintLoggingAnalyzer::with_logger(project.logging_crate())),
+        Box::new(MissingInstrumentAnalyzer::with_tracing(
+            project.has_dependency("tracing")
+        )),
+        Box::new(TimeSourceAnalyzer::with_server(project.is_server())),
+        Box::new(UnwrapAnalyzer::new()),
+        Box::new(UnboundedQueueAnalyzer::new()),
+        Box::new(HardcodedSecretsAnalyzer::new()),
+        Box::new(DebugArtifactsAnalyzer::new()),
+        Box::new(HardcodedPathsAnalyzer::new()),
+        Box::new(FunctionLengthAnalyzer::new().with_generated_marker(project.generated_marker())),
+        Box::new(SqlInjectionAnalyzer::new()),
+        Box::new(NumericOverflowAnalyzer::new()),
+        Box::new(NestingDepthAnalyzer::new()),
+        Box::new(project.error_type().map_or_else(
+            ErrorConventionAnalyzer::new,
+            ErrorConventionAnalyzer::with_error_type
+        )),
+        Box::new(BoolResultAnalyzer::new()),
+        Box::new(GlobImportAnalyzer::new()),
+        Box::new(NestedClosuresAnalyzer::new()),
+        Box::new(ImportOrderAnalyzer::new()),
+        Box::new(TraitBoundsAnalyzer::new()),
+        Box::new(RedundantImportsAnalyzer::new()),
+        Box::new(MissingInlineAnalyzer::new()),
+        Box::new(MissingDocsAnalyzer::new()),
+        Box::new(SensitiveDebugAnalyzer::with_config(
+            project.sensitive_fields().map(str::to_string).collect(),
+            project.is_strict()
+        )),
+        Box::new(MissingErrorsDocAnalyzer::new()),
+        Box::new(VariantNamesAnalyzer::new()),
+        Box::new(MissingPanicsDocAnalyzer::new()),
+        Box::new(project.license_header().map_or_else(
+            LicenseHeaderAnalyzer::new,
+            LicenseHeaderAnalyzer::with_template
+        )),
+        Box::new(MergeableImplsAnalyzer::new()),
+        Box::new(TodoCommentsAnalyzer::new()),
+        Box::new(TestModulesAnalyzer::new()),
+        Box::new(
+            project
+                .item_order()
+                .map_or_else(ItemOrderAnalyzer::new, ItemOrderAnalyzer::with_order)
+        ),
+        Box::new(LineLengthAnalyzer::new()),
+        Box::new(PubFieldsAnalyzer::with_allow(
+            project.pub_fields_allow().map(str::to_string).collect()
+        )),
+        Box::new(WhitespaceAnalyzer::new()),
+        Box::new(StringlyTypedAnalyzer::new()),
+        Box::new(FileLengthAnalyzer::new()),
+        Box::new(ConversionImplsAnalyzer::new()),
+        Box::new(TypeSizeAnalyzer::new()),
+        Box::new(ShadowingAnalyzer::new()),
+        Box::new(UnsafeDocsAnalyzer::new()),
+        Box::new(CloneInLoopAnalyzer::new()),
+        Box::new(MissingMustUseAnalyzer::new()),
+        Box::new(DefaultImplAnalyzer::new()),
+        Box::new(MissingDebugAnalyzer::new()),
+        Box::new(NumericCastAnalyzer::new()),
+        Box::new(TestAssertionsAnalyzer::new()),
+        Box::new(AssertMessageAnalyzer::new()),
+        Box::new(SuperImportsAnalyzer::new()),
+        Box::new(WildcardReexportAnalyzer::new()),
+        Box::new(DocWidthAnalyzer::new()),
+        Box::new(PlaceholderDocsAnalyzer::new()),
+        Box::new(DuplicateLiteralsAnalyzer::new()),
+        Box::new(DeriveOrderAnalyzer::new()),
+        Box::new(ArgumentOrderAnalyzer::new()),
+        Box::new(AllowAuditAnalyzer::new()),
+        Box::new(project.debug_suffixes().map_or_else(
+            RequiredDebugAnalyzer::new,
+            RequiredDebugAnalyzer::with_suffixes
+        )),
+        Box::new(NamingAnalyzer::new()),
+        Box::new(PredicateNamingAnalyzer::new()),
+        Box::new(DiscardedResultAnalyzer::new()),
+        Box::new(GlobalStateAnalyzer::new()),
+        Box::new(ApiTypesAnalyzer::new()),
+        Box::new(ErrorSourcesAnalyzer::new()),
+        Box::new(AsyncBlockingAnalyzer::new()),
+        Box::new(DataIntegrityAnalyzer::new()),
+        Box::new(NonExhaustiveErrorsAnalyzer::new()),
+        Box::new(UnboundedRecursionAnalyzer::new()),
+        Box::new(ErrorDisplayAnalyzer::new()),
+        Box::new(RedundantSyntaxAnalyzer::new()),
+        Box::new(ImplLocationAnalyzer::new()),
     ]
 }
 
 #[cfg(test)]
 mod tests {
+    use std::{fs, path::Path};
+
     use super::*;
 
     #[test]
     fn test_get_analyzers() {
         let analyzers = get_analyzers();
-        assert_eq!(analyzers.len(), 4);
+        assert_eq!(analyzers.len(), 74);
+    }
+
+    #[test]
+    fn test_opt_in_analyzers_inactive_by_default() {
+        let project = ProjectContext::default();
+        let active: Vec<_> = get_analyzers_for(&project)
+            .into_iter()
+            .filter(|a| project.is_active(a.as_ref()))
+            .map(|a| a.name())
+            .collect();
+
+        assert_eq!(active.len(), 66);
+        assert!(!active.contains(&"time_source"));
+        assert!(!active.contains(&"missing_instrument"));
     }
 
     #[test]
@@ -226,5 +2230,88 @@ mod tests {
         assert!(names.contains(&"format_args"));
         assert!(names.contains(&"empty_lines"));
         assert!(names.contains(&"inline_comments"));
+        assert!(names.contains(&"needless_allocation"));
+        assert!(names.contains(&"sharing_complexity"));
+        assert!(names.contains(&"print_logging"));
+        assert!(names.contains(&"missing_instrument"));
+        assert!(names.contains(&"time_source"));
+        assert!(names.contains(&"unwrap"));
+        assert!(names.contains(&"unbounded_queue"));
+        assert!(names.contains(&"hardcoded_secrets"));
+        assert!(names.contains(&"debug_artifacts"));
+        assert!(names.contains(&"hardcoded_paths"));
+        assert!(names.contains(&"function_length"));
+        assert!(names.contains(&"sql_injection"));
+        assert!(names.contains(&"numeric_overflow"));
+        assert!(names.contains(&"nesting_depth"));
+        assert!(names.contains(&"error_convention"));
+        assert!(names.contains(&"bool_result"));
+        assert!(names.contains(&"glob_import"));
+        assert!(names.contains(&"nested_closures"));
+        assert!(names.contains(&"import_order"));
+        assert!(names.contains(&"trait_bounds"));
+        assert!(names.contains(&"redundant_imports"));
+        assert!(names.contains(&"missing_inline"));
+        assert!(names.contains(&"missing_docs"));
+        assert!(names.contains(&"sensitive_debug"));
+        assert!(names.contains(&"missing_errors_doc"));
+        assert!(names.contains(&"variant_names"));
+        assert!(names.contains(&"missing_panics_doc"));
+        assert!(names.contains(&"license_header"));
+        assert!(names.contains(&"mergeable_impls"));
+        assert!(names.contains(&"todo_comments"));
+        assert!(names.contains(&"test_modules"));
+        assert!(names.contains(&"item_order"));
+        assert!(names.contains(&"line_length"));
+        assert!(names.contains(&"pub_fields"));
+        assert!(names.contains(&"whitespace"));
+        assert!(names.contains(&"stringly_typed"));
+        assert!(names.contains(&"file_length"));
+        assert!(names.contains(&"conversion_impls"));
+        assert!(names.contains(&"type_size"));
+        assert!(names.contains(&"shadowing"));
+        assert!(names.contains(&"unsafe_docs"));
+        assert!(names.contains(&"clone_in_loop"));
+        assert!(names.contains(&"missing_must_use"));
+        assert!(names.contains(&"default_impl"));
+        assert!(names.contains(&"missing_debug"));
+        assert!(names.contains(&"numeric_cast"));
+        assert!(names.contains(&"test_assertions"));
+        assert!(names.contains(&"assert_message"));
+        assert!(names.contains(&"super_imports"));
+        assert!(names.contains(&"wildcard_reexport"));
+        assert!(names.contains(&"doc_width"));
+        assert!(names.contains(&"placeholder_docs"));
+        assert!(names.contains(&"duplicate_literals"));
+        assert!(names.contains(&"derive_order"));
+        assert!(names.contains(&"argument_order"));
+        assert!(names.contains(&"allow_audit"));
+        assert!(names.contains(&"required_debug"));
+        assert!(names.contains(&"naming"));
+        assert!(names.contains(&"predicate_naming"));
+        assert!(names.contains(&"discarded_result"));
+        assert!(names.contains(&"global_state"));
+        assert!(names.contains(&"api_types"));
+        assert!(names.contains(&"error_sources"));
+        assert!(names.contains(&"async_blocking"));
+        assert!(names.contains(&"data_integrity"));
+        assert!(names.contains(&"non_exhaustive_errors"));
+        assert!(names.contains(&"unbounded_recursion"));
+        assert!(names.contains(&"error_display"));
+        assert!(names.contains(&"redundant_syntax"));
+        assert!(names.contains(&"impl_location"));
+    }
+
+    #[test]
+    fn test_attributes_crate_knows_all_analyzers() {
+        let source = fs::read_to_string(
+            Path::new(env!("CARGO_MANIFEST_DIR")).join("attributes/src/lib.rs")
+        )
+        .unwrap();
+
+        for analyzer in get_analyzers() {
+            let rule = format!("\"{}\"", analyzer.name());
+            assert!(source.contains(&rule), "{rule} missing from RULES");
+        }
     }
 }
@@ -0,0 +1,253 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Allow audit analyzer for lint suppressions without a justification.
+//!
+//! An `#[allow(..)]` silences a lint for good: once the code around it
+//! changes, nobody remembers whether the suppression still applies. This
+//! analyzer reports every `#[allow(..)]` and `#![allow(..)]` that neither
+//! states a `reason = ".."` nor has a comment directly above it or at the
+//! end of its line, so each suppression carries its explanation.
+
+use masterror::AppResult;
+use proc_macro2::LineColumn;
+use syn::{
+    AttrStyle, Attribute, File, ItemMod, Meta, Path, punctuated::Punctuated, spanned::Spanned,
+    visit::Visit
+};
+
+use super::is_test_code;
+use crate::analyzer::{AnalysisResult, Analyzer, Fix, Issue};
+
+/// Analyzer for `#[allow(..)]` attributes without a justification.
+///
+/// # Examples
+///
+/// Detects this pattern:
+/// ```ignore
+/// #[allow(dead_code)]
+/// fn legacy() {}
+/// ```
+///
+/// Suggests stating why the lint does not apply:
+/// ```ignore
+/// #[allow(dead_code, reason = "called from the FFI layer")]
+/// fn legacy() {}
+/// ```
+pub struct AllowAuditAnalyzer;
+
+impl AllowAuditAnalyzer {
+    /// Create new allow audit analyzer instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Analyzer for AllowAuditAnalyzer {
+    fn name(&self) -> &'static str {
+        "allow_audit"
+    }
+
+    fn analyze(&self, ast: &File, content: &str) -> AppResult<AnalysisResult> {
+        let mut visitor = AllowVisitor {
+            lines:  content.lines().collect(),
+            issues: Vec::new()
+        };
+        visitor.visit_file(ast);
+
+        Ok(AnalysisResult {
+            issues:        visitor.issues,
+            fixable_count: 0
+        })
+    }
+}
+
+impl Default for AllowAuditAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct AllowVisitor<'a> {
+    lines:  Vec<&'a str>,
+    issues: Vec<Issue>
+}
+
+impl AllowVisitor<'_> {
+    /// Flag an `allow` attribute without a reason or adjacent comment.
+    fn check(&mut self, attr: &Attribute) {
+        if !attr.path().is_ident("allow") {
+            return;
+        }
+        let Ok(args) = attr.parse_args_with(Punctuated::<Meta, syn::Token![,]>::parse_terminated)
+        else {
+            return;
+        };
+        if args
+            .iter()
+            .any(|meta| matches!(meta, Meta::NameValue(nv) if nv.path.is_ident("reason")))
+        {
+            return;
+        }
+
+        let start = attr.pound_token.span.start();
+        let end = attr.span().end();
+        if self.has_comment(start.line, end) {
+            return;
+        }
+
+        let lints: Vec<String> = args.iter().map(|meta| path_text(meta.path())).collect();
+        let bang = match attr.style {
+            AttrStyle::Inner(_) => "!",
+            AttrStyle::Outer => ""
+        };
+        self.issues.push(Issue {
+            line:    start.line,
+            column:  start.column,
+            message: format!(
+                "`#{bang}[allow({})]` has no justification\n\
+                 Add `reason = \"..\"` to the attribute, or a comment on the line above, \
+                 explaining why the lint does not apply here",
+                lints.join(", ")
+            ),
+            fix:     Fix::None
+        });
+    }
+
+    /// Check for a comment on the line above the attribute or after its end.
+    ///
+    /// # Arguments
+    ///
+    /// * `first` - 1-based line of the `#`
+    /// * `end` - Position after the closing `]`
+    fn has_comment(&self, first: usize, end: LineColumn) -> bool {
+        let above = first
+            .checked_sub(2)
+            .and_then(|index| self.lines.get(index))
+            .is_some_and(|line| line.trim_start().starts_with("//"));
+        let trailing = end
+            .line
+            .checked_sub(1)
+            .and_then(|index| self.lines.get(index))
+            .is_some_and(|line| {
+                let rest: String = line.chars().skip(end.column).collect();
+                rest.trim_start().starts_with("//")
+            });
+        above || trailing
+    }
+}
+
+impl<'ast> Visit<'ast> for AllowVisitor<'_> {
+    fn visit_attribute(&mut self, node: &'ast Attribute) {
+        self.check(node);
+    }
+
+    fn visit_item_mod(&mut self, node: &'ast ItemMod) {
+        if !is_test_code(&node.attrs) {
+            syn::visit::visit_item_mod(self, node);
+        }
+    }
+}
+
+/// Lint path as written, e.g. `clippy::enum_variant_names`.
+fn path_text(path: &Path) -> String {
+    path.segments
+        .iter()
+        .map(|segment| segment.ident.to_string())
+        .collect::<Vec<_>>()
+        .join("::")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analyze(code: &str) -> AnalysisResult {
+        let ast = syn::parse_file(code).unwrap();
+        AllowAuditAnalyzer::new().analyze(&ast, code).unwrap()
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        let analyzer = AllowAuditAnalyzer::new();
+        assert_eq!(analyzer.name(), "allow_audit");
+    }
+
+    #[test]
+    fn test_detect_unjustified_allow() {
+        let result = analyze(
+            r#"#![allow(unused_imports)]
+
+/// Supported shells.
+#[derive(Debug)]
+#[allow(clippy::enum_variant_names, dead_code)]
+pub enum Shell { Bash }
+
+fn f() {
+    #[allow(unused_variables)]
+    let x = 1;
+}
+"#
+        );
+
+        assert_eq!(result.issues.len(), 3);
+        assert_eq!(result.fixable_count, 0);
+        assert_eq!(result.issues[0].line, 1);
+        assert!(
+            result.issues[0]
+                .message
+                .contains("`#![allow(unused_imports)]` has no justification")
+        );
+        assert_eq!(result.issues[1].line, 5);
+        assert!(
+            result.issues[1]
+                .message
+                .contains("`#[allow(clippy::enum_variant_names, dead_code)]`")
+        );
+        assert_eq!(result.issues[2].line, 9);
+        assert_eq!(result.issues[2].column, 4);
+    }
+
+    #[test]
+    fn test_ignore_justified_allow() {
+        let result = analyze(
+            r#"
+#[allow(
+    dead_code,
+    reason = "library API"
+)]
+pub struct Issue;
+
+// SAFETY: the handle is only accessed behind a mutex
+#[allow(unused)]
+unsafe impl Send for Handle {}
+
+/// Kept for the FFI layer.
+#[allow(dead_code)]
+fn legacy() {}
+
+#[allow(non_snake_case)] // generated names, see [docs]
+fn Legacy() {}
+
+#[deny(unsafe_code)]
+#[cfg_attr(test, allow(dead_code))]
+fn other() {}
+
+#[cfg(test)]
+mod tests {
+    #[allow(clippy::unwrap_used)]
+    fn fixture() {}
+}
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_default_implementation() {
+        let analyzer = AllowAuditAnalyzer;
+        assert_eq!(analyzer.name(), "allow_audit");
+    }
+}
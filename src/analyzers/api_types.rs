@@ -0,0 +1,600 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! API types analyzer for parameters that borrow owned containers.
+//!
+//! A `&String` parameter only accepts borrowed `String`s, while `&str`
+//! accepts them through deref coercion and string literals and slices on
+//! top; the same holds for `&Vec<T>` against `&[T]` and `&Box<T>` against
+//! `&T`. `Box<Vec<T>>` boxes a value that already keeps its items on the
+//! heap. This analyzer reports such parameters and fixes the borrowed
+//! forms in free functions and inherent methods, where callers keep
+//! compiling. Trait methods are reported only, since their implementations
+//! must change along, and `Box<Vec<T>>` is reported only, since its callers
+//! must.
+//!
+//! The body must keep compiling too: a parameter is fixed only when every
+//! use of it also works on the borrowed form, that is indexing, `for`
+//! loops, format arguments and calls of methods slices or `str` have as
+//! well. Passing it on, comparing it or calling e.g. `capacity` leaves the
+//! issue without a fix.
+
+use std::ops::Range;
+
+use masterror::AppResult;
+use proc_macro2::{TokenStream, TokenTree};
+use syn::{
+    Block, Expr, File, FnArg, GenericArgument, Ident, ImplItemFn, ItemFn, ItemImpl, ItemMod,
+    Macro, Pat, PathArguments, Signature, TraitItemFn, Type, TypePath, punctuated::Punctuated,
+    spanned::Spanned, visit::Visit
+};
+
+use super::is_test_code;
+use crate::analyzer::{AnalysisResult, Analyzer, Fix, Issue, Suggestion, TextEdit};
+
+/// Methods of `String` that `str` has as well.
+const STR_METHODS: &[&str] = &[
+    "as_bytes",
+    "as_ptr",
+    "bytes",
+    "char_indices",
+    "chars",
+    "contains",
+    "ends_with",
+    "eq_ignore_ascii_case",
+    "find",
+    "get",
+    "is_char_boundary",
+    "is_empty",
+    "len",
+    "lines",
+    "matches",
+    "parse",
+    "repeat",
+    "replace",
+    "rfind",
+    "rsplit",
+    "rsplit_once",
+    "split",
+    "split_once",
+    "split_whitespace",
+    "splitn",
+    "starts_with",
+    "strip_prefix",
+    "strip_suffix",
+    "to_lowercase",
+    "to_owned",
+    "to_string",
+    "to_uppercase",
+    "trim",
+    "trim_end",
+    "trim_start"
+];
+
+/// Methods of `Vec<T>` that `[T]` has as well.
+const SLICE_METHODS: &[&str] = &[
+    "as_ptr",
+    "binary_search",
+    "chunks",
+    "concat",
+    "contains",
+    "ends_with",
+    "first",
+    "get",
+    "into_iter",
+    "is_empty",
+    "iter",
+    "join",
+    "last",
+    "len",
+    "split_first",
+    "split_last",
+    "starts_with",
+    "to_owned",
+    "to_vec",
+    "windows"
+];
+
+/// Analyzer for `&String`, `&Vec<T>`, `&Box<T>` and `Box<Vec<T>>`
+/// parameters.
+///
+/// # Examples
+///
+/// Detects this pattern:
+/// ```ignore
+/// fn greet(name: &String, scores: &Vec<u32>) {}
+/// ```
+///
+/// Fixes to:
+/// ```ignore
+/// fn greet(name: &str, scores: &[u32]) {}
+/// ```
+pub struct ApiTypesAnalyzer;
+
+/// Parameter whose type should be replaced.
+struct Finding {
+    line:      usize,
+    column:    usize,
+    /// Parameter name, or `_` for patterns
+    name:      String,
+    /// Type as written
+    found:     String,
+    /// Type to use instead
+    suggested: String,
+    /// Whether the parameter type is a box around a vector
+    boxed_vec: bool,
+    /// Edit replacing the type, for the cases callers are unaffected by
+    edit:      Option<TextEdit>
+}
+
+impl ApiTypesAnalyzer {
+    /// Create new API types analyzer instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Collect the parameters with a replaceable type.
+    fn collect(ast: &File, content: &str) -> Vec<Finding> {
+        let mut visitor = ParamVisitor {
+            content,
+            findings: Vec::new()
+        };
+        visitor.visit_file(ast);
+        visitor.findings
+    }
+}
+
+impl Analyzer for ApiTypesAnalyzer {
+    fn name(&self) -> &'static str {
+        "api_types"
+    }
+
+    fn analyze(&self, ast: &File, content: &str) -> AppResult<AnalysisResult> {
+        let issues: Vec<Issue> = Self::collect(ast, content)
+            .into_iter()
+            .map(|finding| {
+                let hint = if finding.boxed_vec {
+                    format!(
+                        "Take `{}` instead: the vector already keeps its items on the heap, the \
+                         box only adds an allocation",
+                        finding.suggested
+                    )
+                } else {
+                    format!(
+                        "Take `{}` instead: callers can still pass `{}`, and other borrowed forms \
+                         work too",
+                        finding.suggested, finding.found
+                    )
+                };
+                Issue {
+                    line:    finding.line,
+                    column:  finding.column,
+                    message: format!(
+                        "Parameter `{}` has type `{}`\n{hint}",
+                        finding.name, finding.found
+                    ),
+                    fix:     finding
+                        .edit
+                        .map_or(Fix::None, |edit| Fix::Simple(edit.replacement))
+                }
+            })
+            .collect();
+
+        Ok(AnalysisResult {
+            fixable_count: issues.iter().filter(|i| i.fix.is_available()).count(),
+            issues
+        })
+    }
+
+    fn suggestions(&self, ast: &File, content: &str) -> AppResult<Vec<Suggestion>> {
+        Ok(Self::collect(ast, content)
+            .into_iter()
+            .filter_map(|finding| finding.edit)
+            .map(|edit| Suggestion {
+                edit,
+                import: None
+            })
+            .collect())
+    }
+
+    fn fixable(&self) -> bool {
+        true
+    }
+}
+
+impl Default for ApiTypesAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Visitor checking the parameters of every function.
+struct ParamVisitor<'a> {
+    content:  &'a str,
+    findings: Vec<Finding>
+}
+
+impl ParamVisitor<'_> {
+    /// Check the parameters of a function.
+    ///
+    /// # Arguments
+    ///
+    /// * `sig` - Function signature
+    /// * `body` - Function body, `None` when the parameters must not be fixed
+    fn check(&mut self, sig: &Signature, body: Option<&Block>) {
+        for input in &sig.inputs {
+            let FnArg::Typed(param) = input else {
+                continue;
+            };
+            let ident = match param.pat.as_ref() {
+                Pat::Ident(ident) => Some(&ident.ident),
+                _ => None
+            };
+            self.check_type(&param.ty, ident, body);
+        }
+    }
+
+    fn check_type(&mut self, ty: &Type, ident: Option<&Ident>, body: Option<&Block>) {
+        let (replaced, replacement, fixable) = match ty {
+            Type::Reference(reference) if reference.mutability.is_none() => {
+                let Type::Path(path) = reference.elem.as_ref() else {
+                    return;
+                };
+                let Some((borrowed, methods)) = self.borrowed_form(path) else {
+                    return;
+                };
+                let fixable = body.is_some_and(|body| {
+                    ident.is_none_or(|ident| uses_fit_borrowed(body, ident, methods))
+                });
+                (reference.elem.as_ref(), borrowed, fixable)
+            }
+            Type::Path(path) => {
+                let Some(vec) = single_argument(path, "Box") else {
+                    return;
+                };
+                let Type::Path(vec_path) = vec else {
+                    return;
+                };
+                let (Some(_), Some(vec)) = (single_argument(vec_path, "Vec"), self.text(vec))
+                else {
+                    return;
+                };
+                (ty, vec, false)
+            }
+            _ => return
+        };
+        let boxed_vec = matches!(ty, Type::Path(_));
+
+        let (Some(outer), Some(inner)) = (self.range(ty), self.range(replaced)) else {
+            return;
+        };
+        let start = ty.span().start();
+        self.findings.push(Finding {
+            line: start.line,
+            column: start.column,
+            name: ident.map_or_else(|| "_".to_string(), ToString::to_string),
+            found: self.content[outer.clone()].to_string(),
+            suggested: format!(
+                "{}{replacement}{}",
+                &self.content[outer.start..inner.start],
+                &self.content[inner.end..outer.end]
+            ),
+            boxed_vec,
+            edit: fixable.then_some(TextEdit {
+                range: inner,
+                replacement
+            })
+        });
+    }
+
+    /// Borrowed form of an owned container, e.g. `str` for `String`.
+    ///
+    /// # Returns
+    ///
+    /// Borrowed type with the methods it shares with the container, `None`
+    /// for all methods
+    fn borrowed_form(&self, path: &TypePath) -> Option<(String, Option<&'static [&'static str]>)> {
+        let segment = path.path.segments.last()?;
+        if path.qself.is_some() {
+            return None;
+        }
+        match segment.ident.to_string().as_str() {
+            "String" if segment.arguments.is_none() => {
+                Some(("str".to_string(), Some(STR_METHODS)))
+            }
+            "Vec" => Some((
+                format!("[{}]", self.text(single_argument(path, "Vec")?)?),
+                Some(SLICE_METHODS)
+            )),
+            "Box" => Some((self.text(single_argument(path, "Box")?)?, None)),
+            _ => None
+        }
+    }
+
+    /// Source text of a type.
+    fn text(&self, ty: &Type) -> Option<String> {
+        self.range(ty).map(|range| self.content[range].to_string())
+    }
+
+    /// Byte range of a type in the source.
+    fn range(&self, ty: &Type) -> Option<Range<usize>> {
+        let range = ty.span().byte_range();
+        (range.start < range.end && self.content.get(range.clone()).is_some()).then_some(range)
+    }
+}
+
+impl<'ast> Visit<'ast> for ParamVisitor<'_> {
+    fn visit_item_fn(&mut self, node: &'ast ItemFn) {
+        if !is_test_code(&node.attrs) {
+            self.check(&node.sig, Some(&node.block));
+            syn::visit::visit_item_fn(self, node);
+        }
+    }
+
+    fn visit_item_impl(&mut self, node: &'ast ItemImpl) {
+        if node.trait_.is_none() && !is_test_code(&node.attrs) {
+            syn::visit::visit_item_impl(self, node);
+        }
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'ast ImplItemFn) {
+        if !is_test_code(&node.attrs) {
+            self.check(&node.sig, Some(&node.block));
+            syn::visit::visit_impl_item_fn(self, node);
+        }
+    }
+
+    fn visit_trait_item_fn(&mut self, node: &'ast TraitItemFn) {
+        self.check(&node.sig, None);
+        syn::visit::visit_trait_item_fn(self, node);
+    }
+
+    fn visit_item_mod(&mut self, node: &'ast ItemMod) {
+        if !is_test_code(&node.attrs) {
+            syn::visit::visit_item_mod(self, node);
+        }
+    }
+}
+
+/// Check whether every use of a parameter works on its borrowed form.
+///
+/// # Arguments
+///
+/// * `body` - Function body
+/// * `ident` - Parameter name
+/// * `methods` - Methods the borrowed form shares, `None` for all
+fn uses_fit_borrowed(body: &Block, ident: &Ident, methods: Option<&[&str]>) -> bool {
+    let mut visitor = UseVisitor {
+        ident,
+        methods,
+        fits: true
+    };
+    visitor.visit_block(body);
+    visitor.fits
+}
+
+/// Visitor checking the uses of a parameter.
+struct UseVisitor<'a> {
+    ident:   &'a Ident,
+    /// Methods the borrowed form shares, `None` for all
+    methods: Option<&'a [&'a str]>,
+    /// Whether every use seen so far works on the borrowed form
+    fits:    bool
+}
+
+impl UseVisitor<'_> {
+    fn is_param(&self, expr: &Expr) -> bool {
+        matches!(expr, Expr::Path(path) if path.qself.is_none() && path.path.is_ident(self.ident))
+    }
+}
+
+impl<'ast> Visit<'ast> for UseVisitor<'_> {
+    fn visit_expr(&mut self, node: &'ast Expr) {
+        match node {
+            Expr::MethodCall(call) if self.is_param(&call.receiver) => {
+                let shared = self
+                    .methods
+                    .is_none_or(|methods| methods.iter().any(|method| call.method == method));
+                self.fits &= shared;
+                for arg in &call.args {
+                    self.visit_expr(arg);
+                }
+            }
+            Expr::Index(index) if self.is_param(&index.expr) => self.visit_expr(&index.index),
+            Expr::ForLoop(for_loop) if self.is_param(&for_loop.expr) => {
+                self.visit_block(&for_loop.body);
+            }
+            _ if self.is_param(node) => self.fits = false,
+            _ => syn::visit::visit_expr(self, node)
+        }
+    }
+
+    fn visit_macro(&mut self, node: &'ast Macro) {
+        let args = node.parse_body_with(Punctuated::<Expr, syn::Token![,]>::parse_terminated);
+        match args {
+            Ok(args) => args.iter().for_each(|arg| self.visit_expr(arg)),
+            Err(_) => self.fits &= !mentions(node.tokens.clone(), self.ident)
+        }
+    }
+}
+
+/// Check whether a token stream mentions an identifier.
+fn mentions(tokens: TokenStream, ident: &Ident) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(found) => found == *ident,
+        TokenTree::Group(group) => mentions(group.stream(), ident),
+        _ => false
+    })
+}
+
+/// The single type argument of a path ending in `name`, e.g. `T` of
+/// `Vec<T>`.
+fn single_argument<'a>(path: &'a TypePath, name: &str) -> Option<&'a Type> {
+    let segment = path.path.segments.last()?;
+    if segment.ident != name {
+        return None;
+    }
+    let PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+        return None;
+    };
+    let mut arguments = arguments.args.iter();
+    match (arguments.next(), arguments.next()) {
+        (Some(GenericArgument::Type(ty)), None) => Some(ty),
+        _ => None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analyze(code: &str) -> AnalysisResult {
+        let ast = syn::parse_file(code).unwrap();
+        ApiTypesAnalyzer::new().analyze(&ast, code).unwrap()
+    }
+
+    fn fix(code: &str) -> String {
+        let ast = syn::parse_file(code).unwrap();
+        let suggestions = ApiTypesAnalyzer::new().suggestions(&ast, code).unwrap();
+        crate::fixer::apply_suggestions(code, &suggestions).source
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        let analyzer = ApiTypesAnalyzer::new();
+        assert_eq!(analyzer.name(), "api_types");
+        assert!(analyzer.fixable());
+    }
+
+    #[test]
+    fn test_detect_parameters() {
+        let result = analyze(
+            r#"
+fn greet(name: &String, scores: &Vec<u32>, handler: &Box<dyn Fn()>, items: Box<Vec<u8>>) {}
+"#
+        );
+
+        assert_eq!(result.issues.len(), 4);
+        assert_eq!(result.fixable_count, 3);
+        assert_eq!(result.issues[0].line, 2);
+        assert_eq!(result.issues[0].column, 15);
+        assert!(result.issues[0].message.starts_with(
+            "Parameter `name` has type `&String`\nTake `&str` instead: callers can still pass \
+             `&String`"
+        ));
+        assert!(result.issues[1].message.contains("Take `&[u32]` instead"));
+        assert!(
+            result.issues[2]
+                .message
+                .contains("Take `&dyn Fn()` instead")
+        );
+        assert!(result.issues[3].message.starts_with(
+            "Parameter `items` has type `Box<Vec<u8>>`\nTake `Vec<u8>` instead: the vector"
+        ));
+        assert_eq!(result.issues[3].fix, Fix::None);
+    }
+
+    #[test]
+    fn test_fix_borrowed_forms() {
+        let code = r#"
+struct Store;
+
+impl Store {
+    fn insert<'a>(&self, key: &'a String, values: &Vec<Option<u8>>) {}
+}
+
+fn keep(items: Box<Vec<u8>>) {}
+"#;
+
+        assert_eq!(
+            fix(code),
+            r#"
+struct Store;
+
+impl Store {
+    fn insert<'a>(&self, key: &'a str, values: &[Option<u8>]) {}
+}
+
+fn keep(items: Box<Vec<u8>>) {}
+"#
+        );
+    }
+
+    #[test]
+    fn test_no_fix_when_body_needs_container() {
+        let code = r#"
+fn reserve(values: &Vec<u8>, name: &String) -> usize {
+    values.capacity() + name.len()
+}
+
+fn forward(values: &Vec<u8>) {
+    store(values);
+}
+
+fn show(values: &Vec<u8>, name: &String) -> String {
+    let mut out = format!("{}: {:?}", name.trim(), &values[1..]);
+    for value in values {
+        out.push_str(&value.to_string());
+    }
+    out
+}
+"#;
+        let result = analyze(code);
+
+        assert_eq!(result.issues.len(), 5);
+        assert_eq!(result.fixable_count, 3);
+        assert_eq!(result.issues[0].fix, Fix::None);
+        assert_eq!(result.issues[2].fix, Fix::None);
+        assert_eq!(
+            fix(code),
+            code.replace("name: &String) -> usize", "name: &str) -> usize")
+                .replace(
+                    "show(values: &Vec<u8>, name: &String)",
+                    "show(values: &[u8], name: &str)"
+                )
+        );
+    }
+
+    #[test]
+    fn test_trait_methods_reported_only() {
+        let code = r#"
+trait Render {
+    fn render(&self, out: &String);
+}
+
+impl Render for Page {
+    fn render(&self, out: &String) {}
+}
+"#;
+        let result = analyze(code);
+
+        assert_eq!(result.issues.len(), 1);
+        assert_eq!(result.issues[0].line, 3);
+        assert_eq!(result.fixable_count, 0);
+        assert_eq!(fix(code), code);
+    }
+
+    #[test]
+    fn test_ignore_other_types() {
+        let result = analyze(
+            r#"
+fn run(name: &str, items: &[u8], buffer: &mut String, list: &mut Vec<u8>, owned: Vec<u8>) {}
+fn wrap(value: &Option<String>, boxed: Box<[u8]>, map: &HashMap<String, u8>) {}
+
+#[cfg(test)]
+mod tests {
+    fn fixture(name: &String) {}
+}
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_default_implementation() {
+        let analyzer = ApiTypesAnalyzer;
+        assert_eq!(analyzer.name(), "api_types");
+    }
+}
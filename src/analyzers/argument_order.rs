@@ -0,0 +1,309 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Argument order analyzer for related functions taking shared parameters in
+//! different orders.
+//!
+//! Helpers of one family, such as `render_header(lines, width, file)` and
+//! `render_footer(file, lines, width)`, are called side by side. When their
+//! shared parameters are listed in different orders, arguments of the same
+//! type are easily swapped at a call site without any compiler error. This
+//! analyzer groups the functions of a file by the first word of their name
+//! and flags every function whose shared parameters are ordered differently
+//! from the rest of its group.
+
+use std::collections::BTreeMap;
+
+use masterror::AppResult;
+use proc_macro2::Span;
+use syn::{File, FnArg, ImplItemFn, ItemFn, ItemImpl, ItemMod, Pat, Signature, visit::Visit};
+
+use super::is_test_code;
+use crate::analyzer::{AnalysisResult, Analyzer, Confidence, Fix, Issue};
+
+/// Analyzer for inconsistent parameter order within a function family.
+///
+/// # Examples
+///
+/// Detects this pattern:
+/// ```ignore
+/// fn render_header(lines: &mut Vec<String>, width: usize, title: &str) {}
+/// fn render_body(lines: &mut Vec<String>, width: usize, body: &str) {}
+/// fn render_footer(width: usize, lines: &mut Vec<String>) {}
+/// ```
+///
+/// Suggests the order used by the rest of the family:
+/// ```ignore
+/// fn render_footer(lines: &mut Vec<String>, width: usize) {}
+/// ```
+pub struct ArgumentOrderAnalyzer;
+
+/// Function signature reduced to its named parameters.
+struct Function {
+    name:   String,
+    line:   usize,
+    params: Vec<(String, Span)>
+}
+
+impl Function {
+    /// Position of a parameter by name.
+    fn position(&self, param: &str) -> Option<usize> {
+        self.params.iter().position(|(name, _)| name == param)
+    }
+}
+
+impl ArgumentOrderAnalyzer {
+    /// Create new argument order analyzer instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Check the functions of one family against each other.
+    ///
+    /// Every pair of parameters shared by several functions has a majority
+    /// order; on a tie the order of the first function wins. A function is
+    /// reported once, for its first pair in the minority order.
+    fn check_family(prefix: &str, functions: &[Function], issues: &mut Vec<Issue>) {
+        for function in functions {
+            let conflict = pairs(function).find_map(|(first, span, second)| {
+                let mut agree = Vec::new();
+                let mut disagree = Vec::new();
+                for other in functions {
+                    if let (Some(a), Some(b)) = (other.position(first), other.position(second)) {
+                        if a < b {
+                            agree.push(other);
+                        } else {
+                            disagree.push(other);
+                        }
+                    }
+                }
+                let minority = disagree.len() > agree.len()
+                    || (disagree.len() == agree.len() && disagree[0].line < agree[0].line);
+                minority.then(|| (first, span, second, disagree[0]))
+            });
+
+            let Some((first, span, second, reference)) = conflict else {
+                continue;
+            };
+            let start = span.start();
+            issues.push(Issue {
+                line:    start.line,
+                column:  start.column,
+                message: format!(
+                    "Function `{}` takes `{first}` before `{second}`, unlike `{}` at line {}\n\
+                     Keep the parameters shared by `{prefix}_*` functions in one order, so \
+                     arguments cannot be swapped at call sites",
+                    function.name, reference.name, reference.line
+                ),
+                fix:     Fix::None
+            });
+        }
+    }
+}
+
+impl Analyzer for ArgumentOrderAnalyzer {
+    fn name(&self) -> &'static str {
+        "argument_order"
+    }
+
+    fn analyze(&self, ast: &File, _content: &str) -> AppResult<AnalysisResult> {
+        let mut visitor = FunctionVisitor {
+            families: BTreeMap::new()
+        };
+        visitor.visit_file(ast);
+
+        let mut issues = Vec::new();
+        for (prefix, functions) in &visitor.families {
+            if functions.len() > 1 {
+                Self::check_family(prefix, functions, &mut issues);
+            }
+        }
+        issues.sort_by_key(|issue| (issue.line, issue.column));
+
+        Ok(AnalysisResult {
+            issues,
+            fixable_count: 0
+        })
+    }
+
+    fn confidence(&self) -> Confidence {
+        Confidence::Low
+    }
+}
+
+impl Default for ArgumentOrderAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Collects functions grouped by the first word of their name.
+struct FunctionVisitor {
+    families: BTreeMap<String, Vec<Function>>
+}
+
+impl FunctionVisitor {
+    fn add(&mut self, sig: &Signature) {
+        let name = sig.ident.to_string();
+        let Some((prefix, _)) = name.trim_start_matches('_').split_once('_') else {
+            return;
+        };
+
+        let params: Vec<(String, Span)> = sig
+            .inputs
+            .iter()
+            .filter_map(|input| match input {
+                FnArg::Typed(typed) => match typed.pat.as_ref() {
+                    Pat::Ident(ident) if ident.ident != "_" => {
+                        Some((ident.ident.to_string(), ident.ident.span()))
+                    }
+                    _ => None
+                },
+                FnArg::Receiver(_) => None
+            })
+            .collect();
+        if params.len() < 2 {
+            return;
+        }
+
+        let prefix = prefix.to_string();
+        self.families.entry(prefix).or_default().push(Function {
+            line: sig.ident.span().start().line,
+            name,
+            params
+        });
+    }
+}
+
+impl<'ast> Visit<'ast> for FunctionVisitor {
+    fn visit_item_fn(&mut self, node: &'ast ItemFn) {
+        if is_test_code(&node.attrs) {
+            return;
+        }
+        self.add(&node.sig);
+        syn::visit::visit_item_fn(self, node);
+    }
+
+    fn visit_item_impl(&mut self, node: &'ast ItemImpl) {
+        if node.trait_.is_none() {
+            syn::visit::visit_item_impl(self, node);
+        }
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'ast ImplItemFn) {
+        if is_test_code(&node.attrs) {
+            return;
+        }
+        self.add(&node.sig);
+        syn::visit::visit_impl_item_fn(self, node);
+    }
+
+    fn visit_item_mod(&mut self, node: &'ast ItemMod) {
+        if !is_test_code(&node.attrs) {
+            syn::visit::visit_item_mod(self, node);
+        }
+    }
+}
+
+/// Parameter pairs of a function in their declared order, with the span of
+/// the first parameter.
+fn pairs(function: &Function) -> impl Iterator<Item = (&str, Span, &str)> {
+    function
+        .params
+        .iter()
+        .enumerate()
+        .flat_map(|(index, (first, span))| {
+            function.params[index + 1..]
+                .iter()
+                .map(move |(second, _)| (first.as_str(), *span, second.as_str()))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analyze(code: &str) -> AnalysisResult {
+        let ast = syn::parse_file(code).unwrap();
+        ArgumentOrderAnalyzer::new().analyze(&ast, code).unwrap()
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        let analyzer = ArgumentOrderAnalyzer::new();
+        assert_eq!(analyzer.name(), "argument_order");
+        assert_eq!(analyzer.confidence(), Confidence::Low);
+    }
+
+    #[test]
+    fn test_detect_minority_order() {
+        let result = analyze(
+            r#"
+fn render_header(lines: &mut Vec<String>, width: usize, title: &str) {}
+fn render_body(lines: &mut Vec<String>, width: usize, body: &str) {}
+fn render_footer(width: usize, lines: &mut Vec<String>) {}
+"#
+        );
+
+        assert_eq!(result.issues.len(), 1);
+        assert_eq!(result.fixable_count, 0);
+        assert_eq!(result.issues[0].line, 4);
+        assert_eq!(result.issues[0].column, 17);
+        assert!(result.issues[0].message.contains(
+            "Function `render_footer` takes `width` before `lines`, unlike `render_header` at \
+             line 2"
+        ));
+        assert!(result.issues[0].message.contains("`render_*` functions"));
+    }
+
+    #[test]
+    fn test_tie_keeps_first_order() {
+        let result = analyze(
+            r#"
+struct Canvas;
+
+impl Canvas {
+    fn draw_line(&mut self, from: Point, to: Point) {}
+    fn draw_arrow(&mut self, to: Point, from: Point) {}
+}
+"#
+        );
+
+        assert_eq!(result.issues.len(), 1);
+        assert!(
+            result.issues[0]
+                .message
+                .contains("`draw_arrow` takes `to` before `from`, unlike `draw_line`")
+        );
+    }
+
+    #[test]
+    fn test_ignore_consistent_and_unrelated() {
+        let result = analyze(
+            r#"
+fn render_header(lines: &mut Vec<String>, width: usize) {}
+fn render_footer(lines: &mut Vec<String>, note: &str, width: usize) {}
+fn parse_header(width: usize, lines: &[String]) {}
+fn render(width: usize, lines: &[String]) {}
+
+impl Display for Header {
+    fn fmt_with(width: usize, lines: &[String]) {}
+}
+
+#[cfg(test)]
+mod tests {
+    fn render_case(width: usize, lines: &[String]) {}
+}
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_default_implementation() {
+        let analyzer = ArgumentOrderAnalyzer;
+        assert_eq!(analyzer.name(), "argument_order");
+    }
+}
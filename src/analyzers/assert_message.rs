@@ -0,0 +1,241 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Assert message analyzer for bare assertions in production code.
+//!
+//! A failing `assert!(buffer.len() <= MAX)` panics with the stringified
+//! condition and nothing else, leaving whoever reads the log to guess which
+//! invariant broke and why it matters. This analyzer flags `assert!`,
+//! `assert_eq!`, `assert_ne!` and their `debug_` variants without a message
+//! argument outside of test code. Conditions that can fail on user input are
+//! better reported as errors than asserted.
+
+use std::path::{Component, Path};
+
+use masterror::AppResult;
+use syn::{
+    Expr, File, ImplItemFn, ItemFn, ItemMod, Macro, Token, punctuated::Punctuated, visit::Visit
+};
+
+use super::is_test_code;
+use crate::analyzer::{AnalysisResult, Analyzer, Fix, Issue};
+
+/// Assertion macros with the number of arguments before the message.
+const ASSERT_MACROS: &[(&str, usize)] = &[
+    ("assert", 1),
+    ("assert_eq", 2),
+    ("assert_ne", 2),
+    ("debug_assert", 1),
+    ("debug_assert_eq", 2),
+    ("debug_assert_ne", 2)
+];
+
+/// Directories whose files only hold tests.
+const TEST_DIRS: &[&str] = &["tests", "benches"];
+
+/// Analyzer for assertions without an explanatory message.
+///
+/// # Examples
+///
+/// Detects this pattern:
+/// ```ignore
+/// fn push(&mut self, frame: Frame) {
+///     assert!(self.frames.len() < MAX_FRAMES);
+///     self.frames.push(frame);
+/// }
+/// ```
+///
+/// Suggests:
+/// ```ignore
+/// fn push(&mut self, frame: Frame) {
+///     assert!(
+///         self.frames.len() < MAX_FRAMES,
+///         "frame queue is bounded by the window size"
+///     );
+///     self.frames.push(frame);
+/// }
+/// ```
+pub struct AssertMessageAnalyzer;
+
+impl AssertMessageAnalyzer {
+    /// Create new assert message analyzer instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Analyzer for AssertMessageAnalyzer {
+    fn name(&self) -> &'static str {
+        "assert_message"
+    }
+
+    fn analyze(&self, ast: &File, _content: &str) -> AppResult<AnalysisResult> {
+        let mut visitor = AssertVisitor {
+            issues: Vec::new()
+        };
+        visitor.visit_file(ast);
+
+        Ok(AnalysisResult {
+            issues:        visitor.issues,
+            fixable_count: 0
+        })
+    }
+
+    fn applies_to(&self, path: &Path) -> bool {
+        !path.components().any(|component| {
+            matches!(component, Component::Normal(dir) if TEST_DIRS.iter().any(|name| dir == *name))
+        })
+    }
+}
+
+impl Default for AssertMessageAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct AssertVisitor {
+    issues: Vec<Issue>
+}
+
+impl<'ast> Visit<'ast> for AssertVisitor {
+    fn visit_item_fn(&mut self, node: &'ast ItemFn) {
+        if !is_test_code(&node.attrs) {
+            syn::visit::visit_item_fn(self, node);
+        }
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'ast ImplItemFn) {
+        if !is_test_code(&node.attrs) {
+            syn::visit::visit_impl_item_fn(self, node);
+        }
+    }
+
+    fn visit_item_mod(&mut self, node: &'ast ItemMod) {
+        if !is_test_code(&node.attrs) {
+            syn::visit::visit_item_mod(self, node);
+        }
+    }
+
+    fn visit_macro(&mut self, node: &'ast Macro) {
+        let Some(name) = node.path.segments.last().map(|seg| seg.ident.to_string()) else {
+            return;
+        };
+        let Some((_, required)) = ASSERT_MACROS.iter().find(|(mac, _)| name == *mac) else {
+            return;
+        };
+        let Ok(args) = node.parse_body_with(Punctuated::<Expr, Token![,]>::parse_terminated)
+        else {
+            return;
+        };
+
+        if args.len() <= *required {
+            let start = node.path.segments[0].ident.span().start();
+            self.issues.push(Issue {
+                line:    start.line,
+                column:  start.column,
+                message: format!(
+                    "`{name}!` without a message\n\
+                     Add a message explaining the invariant, or return an error if the \
+                     condition can fail at runtime"
+                ),
+                fix:     Fix::None
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analyze(code: &str) -> AnalysisResult {
+        let ast = syn::parse_file(code).unwrap();
+        AssertMessageAnalyzer::new().analyze(&ast, code).unwrap()
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        let analyzer = AssertMessageAnalyzer::new();
+        assert_eq!(analyzer.name(), "assert_message");
+    }
+
+    #[test]
+    fn test_detect_assertions_without_message() {
+        let result = analyze(
+            r#"
+struct Queue;
+
+impl Queue {
+    fn push(&mut self, frame: Frame) {
+        assert!(self.frames.len() < MAX_FRAMES);
+        debug_assert_eq!(frame.window, self.window);
+        let checked = { std::assert_ne!(frame.id, 0,); frame };
+    }
+}
+"#
+        );
+
+        assert_eq!(result.issues.len(), 3);
+        assert_eq!(result.issues[0].line, 6);
+        assert_eq!(result.issues[0].column, 8);
+        assert!(
+            result.issues[0]
+                .message
+                .starts_with("`assert!` without a message")
+        );
+        assert!(result.issues[1].message.contains("`debug_assert_eq!`"));
+        assert!(result.issues[2].message.contains("`assert_ne!`"));
+    }
+
+    #[test]
+    fn test_ignore_assertions_with_message() {
+        let result = analyze(
+            r#"
+fn push(frames: &[Frame], frame: &Frame) {
+    assert!(frames.len() < MAX_FRAMES, "queue is bounded");
+    debug_assert!(frame.valid(), "frame {} is corrupt", frame.id);
+    assert_eq!(frame.window, 4, "window mismatch");
+}
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_ignore_test_code() {
+        let result = analyze(
+            r#"
+#[test]
+fn test_push() {
+    assert!(push());
+}
+
+#[cfg(test)]
+mod tests {
+    fn check(value: u8) {
+        assert_eq!(value, 1);
+    }
+}
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_applies_to_production_files() {
+        let analyzer = AssertMessageAnalyzer::new();
+        assert!(analyzer.applies_to(Path::new("src/queue.rs")));
+        assert!(!analyzer.applies_to(Path::new("tests/queue.rs")));
+        assert!(!analyzer.applies_to(Path::new("benches/queue.rs")));
+    }
+
+    #[test]
+    fn test_default_implementation() {
+        let analyzer = AssertMessageAnalyzer;
+        assert_eq!(analyzer.name(), "assert_message");
+    }
+}
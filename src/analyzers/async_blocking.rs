@@ -0,0 +1,358 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Async blocking analyzer for blocking calls inside async code.
+//!
+//! An async task runs on an executor thread shared with other tasks. A call
+//! that blocks the thread, sleeping, waiting on the file system or on a
+//! channel, stalls every task scheduled on it until the call returns. This
+//! analyzer flags `thread::sleep`, `std::fs` functions and blocking channel
+//! receives inside `async fn` bodies and async blocks. Calls that are
+//! awaited are async counterparts and are not reported, and neither are
+//! calls inside plain closures, which usually run on a blocking thread via
+//! `spawn_blocking`.
+
+use std::mem;
+
+use masterror::AppResult;
+use syn::{
+    Expr, ExprAsync, ExprAwait, ExprClosure, File, ImplItemFn, ItemFn, ItemMod, spanned::Spanned,
+    visit::Visit
+};
+
+use super::{describe_call, is_test_code};
+use crate::analyzer::{AnalysisResult, Analyzer, Fix, Issue};
+
+/// Channel methods that block the thread until a message arrives.
+const BLOCKING_RECV: &[&str] = &["recv", "recv_timeout", "blocking_recv"];
+
+/// Crates whose `fs` module is async.
+const ASYNC_FS_CRATES: &[&str] = &["tokio", "async_std", "smol", "async_fs"];
+
+/// Analyzer for blocking calls in async functions and blocks.
+///
+/// # Examples
+///
+/// Detects this pattern:
+/// ```ignore
+/// async fn poll(path: &Path) -> String {
+///     std::thread::sleep(Duration::from_secs(1));
+///     std::fs::read_to_string(path).unwrap()
+/// }
+/// ```
+///
+/// Suggests the async counterparts:
+/// ```ignore
+/// async fn poll(path: &Path) -> String {
+///     tokio::time::sleep(Duration::from_secs(1)).await;
+///     tokio::fs::read_to_string(path).await.unwrap()
+/// }
+/// ```
+pub struct AsyncBlockingAnalyzer;
+
+impl AsyncBlockingAnalyzer {
+    /// Create new async blocking analyzer instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Analyzer for AsyncBlockingAnalyzer {
+    fn name(&self) -> &'static str {
+        "async_blocking"
+    }
+
+    fn analyze(&self, ast: &File, _content: &str) -> AppResult<AnalysisResult> {
+        let mut visitor = BlockingVisitor {
+            context: None,
+            issues:  Vec::new()
+        };
+        visitor.visit_file(ast);
+
+        Ok(AnalysisResult {
+            issues:        visitor.issues,
+            fixable_count: 0
+        })
+    }
+}
+
+impl Default for AsyncBlockingAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Kind of blocking call, with the async counterpart to suggest.
+#[derive(Clone, Copy)]
+enum Blocking {
+    Sleep,
+    FileSystem,
+    Receive
+}
+
+impl Blocking {
+    fn hint(self) -> &'static str {
+        match self {
+            Self::Sleep => "Use the runtime's timer instead, e.g. `tokio::time::sleep(..).await`",
+            Self::FileSystem => {
+                "Use the runtime's file system API, e.g. `tokio::fs`, or move the call into \
+                 `spawn_blocking`"
+            }
+            Self::Receive => {
+                "Use an async channel such as `tokio::sync::mpsc` and `.recv().await`, or move \
+                 the call into `spawn_blocking`"
+            }
+        }
+    }
+}
+
+struct BlockingVisitor {
+    /// Async code being visited, e.g. "async fn load"; `None` outside
+    context: Option<String>,
+    issues:  Vec<Issue>
+}
+
+impl BlockingVisitor {
+    /// Visit code in a context, restoring the outer one afterwards.
+    fn within(&mut self, context: Option<String>, visit: impl FnOnce(&mut Self)) {
+        let outer = mem::replace(&mut self.context, context);
+        visit(self);
+        self.context = outer;
+    }
+
+    /// Report a blocking call inside async code.
+    fn check(&mut self, expr: &Expr) {
+        let Some(context) = &self.context else {
+            return;
+        };
+        let Some(kind) = blocking_kind(expr) else {
+            return;
+        };
+        let Some(call) = describe_call(expr) else {
+            return;
+        };
+
+        let start = expr.span().start();
+        self.issues.push(Issue {
+            line:    start.line,
+            column:  start.column,
+            message: format!("Blocking call `{call}` inside {context}\n{}", kind.hint()),
+            fix:     Fix::None
+        });
+    }
+}
+
+impl<'ast> Visit<'ast> for BlockingVisitor {
+    fn visit_item_fn(&mut self, node: &'ast ItemFn) {
+        if is_test_code(&node.attrs) {
+            return;
+        }
+        let context = node
+            .sig
+            .asyncness
+            .map(|_| format!("`async fn {}`", node.sig.ident));
+        self.within(context, |visitor| syn::visit::visit_item_fn(visitor, node));
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'ast ImplItemFn) {
+        if is_test_code(&node.attrs) {
+            return;
+        }
+        let context = node
+            .sig
+            .asyncness
+            .map(|_| format!("`async fn {}`", node.sig.ident));
+        self.within(context, |visitor| {
+            syn::visit::visit_impl_item_fn(visitor, node);
+        });
+    }
+
+    fn visit_item_mod(&mut self, node: &'ast ItemMod) {
+        if !is_test_code(&node.attrs) {
+            syn::visit::visit_item_mod(self, node);
+        }
+    }
+
+    fn visit_expr_async(&mut self, node: &'ast ExprAsync) {
+        self.within(Some("an async block".to_string()), |visitor| {
+            syn::visit::visit_expr_async(visitor, node);
+        });
+    }
+
+    fn visit_expr_closure(&mut self, node: &'ast ExprClosure) {
+        let context = node.asyncness.map(|_| "an async closure".to_string());
+        self.within(context, |visitor| {
+            syn::visit::visit_expr_closure(visitor, node);
+        });
+    }
+
+    fn visit_expr_await(&mut self, node: &'ast ExprAwait) {
+        match node.base.as_ref() {
+            Expr::Call(call) => {
+                call.args.iter().for_each(|arg| self.visit_expr(arg));
+            }
+            Expr::MethodCall(call) => {
+                self.visit_expr(&call.receiver);
+                call.args.iter().for_each(|arg| self.visit_expr(arg));
+            }
+            base => self.visit_expr(base)
+        }
+    }
+
+    fn visit_expr(&mut self, node: &'ast Expr) {
+        self.check(node);
+        syn::visit::visit_expr(self, node);
+    }
+}
+
+/// Kind of blocking call an expression is, if any.
+///
+/// Path calls into an `fs` module count unless the path starts with a crate
+/// of [`ASYNC_FS_CRATES`]; `File::open`/`File::create` count only spelled
+/// with `fs`, as `tokio::fs::File` is imported as `File` too.
+fn blocking_kind(expr: &Expr) -> Option<Blocking> {
+    match expr {
+        Expr::Call(call) => {
+            let Expr::Path(path) = call.func.as_ref() else {
+                return None;
+            };
+            let segments: Vec<String> = path
+                .path
+                .segments
+                .iter()
+                .map(|segment| segment.ident.to_string())
+                .collect();
+            if let [.., module, name] = segments.as_slice()
+                && module == "thread"
+                && name == "sleep"
+            {
+                return Some(Blocking::Sleep);
+            }
+            let in_fs = segments[..segments.len().saturating_sub(1)]
+                .iter()
+                .any(|segment| segment == "fs");
+            let async_crate = segments
+                .first()
+                .is_some_and(|first| ASYNC_FS_CRATES.contains(&first.as_str()));
+            (in_fs && !async_crate).then_some(Blocking::FileSystem)
+        }
+        Expr::MethodCall(call) => BLOCKING_RECV
+            .contains(&call.method.to_string().as_str())
+            .then_some(Blocking::Receive),
+        _ => None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analyze(code: &str) -> AnalysisResult {
+        let ast = syn::parse_file(code).unwrap();
+        AsyncBlockingAnalyzer::new().analyze(&ast, code).unwrap()
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        let analyzer = AsyncBlockingAnalyzer::new();
+        assert_eq!(analyzer.name(), "async_blocking");
+    }
+
+    #[test]
+    fn test_detect_blocking_calls() {
+        let result = analyze(
+            r#"
+async fn poll(path: &Path, rx: Receiver<u8>) {
+    std::thread::sleep(Duration::from_secs(1));
+    let text = fs::read_to_string(path).unwrap();
+    let file = std::fs::File::open(path);
+    let value = rx.recv().unwrap();
+}
+
+impl Worker {
+    fn start(&self) {
+        spawn(async move {
+            thread::sleep(DELAY);
+        });
+    }
+}
+"#
+        );
+
+        assert_eq!(result.issues.len(), 5);
+        assert_eq!(result.fixable_count, 0);
+        assert_eq!(result.issues[0].line, 3);
+        assert_eq!(result.issues[0].column, 4);
+        assert!(result.issues[0].message.starts_with(
+            "Blocking call `std::thread::sleep(..)` inside `async fn poll`\nUse the runtime's \
+             timer"
+        ));
+        assert!(
+            result.issues[1]
+                .message
+                .contains("`fs::read_to_string(..)`")
+        );
+        assert!(result.issues[1].message.contains("`tokio::fs`"));
+        assert!(
+            result.issues[2]
+                .message
+                .contains("`std::fs::File::open(..)`")
+        );
+        assert!(result.issues[3].message.contains("`recv(..)`"));
+        assert!(
+            result.issues[4]
+                .message
+                .starts_with("Blocking call `thread::sleep(..)` inside an async block")
+        );
+    }
+
+    #[test]
+    fn test_ignore_async_and_sync_code() {
+        let result = analyze(
+            r#"
+async fn poll(path: &Path, mut rx: Receiver<u8>) {
+    tokio::time::sleep(DELAY).await;
+    let text = tokio::fs::read_to_string(path).await.unwrap();
+    let bytes = fs::read(path).await.unwrap();
+    let value = rx.recv().await;
+    let loaded = spawn_blocking(move || std::fs::read(path)).await;
+}
+
+fn sync(path: &Path) {
+    std::thread::sleep(DELAY);
+    std::fs::read(path).unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+    async fn fixture() {
+        std::thread::sleep(DELAY);
+    }
+}
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_awaited_call_arguments_checked() {
+        let result = analyze(
+            r#"
+async fn save(path: &Path) {
+    write(path, std::fs::read(path).unwrap()).await;
+}
+"#
+        );
+
+        assert_eq!(result.issues.len(), 1);
+        assert!(result.issues[0].message.contains("`std::fs::read(..)`"));
+    }
+
+    #[test]
+    fn test_default_implementation() {
+        let analyzer = AsyncBlockingAnalyzer;
+        assert_eq!(analyzer.name(), "async_blocking");
+    }
+}
@@ -0,0 +1,299 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Bool result analyzer for fallible work collapsed into `bool`.
+//!
+//! A function like `fn save(..) -> bool { fs::write(..).is_ok() }` throws the
+//! error away: callers learn that something failed but not why, and cannot
+//! tell a missing file from a permission problem. This analyzer flags
+//! functions returning `bool` that turn fallible calls into booleans with
+//! `.is_ok()`/`.is_err()`. A `bool` function cannot use `?`, so the error
+//! never leaves it.
+//!
+//! Predicates named `is_*`, `has_*` and the like are skipped, but a probe
+//! with another name whose caller only needs the yes/no answer, e.g.
+//! `fn port_free(..) -> bool`, is reported too, so the confidence is medium.
+
+use masterror::AppResult;
+use syn::{
+    Block, ExprClosure, ExprMethodCall, File, ImplItemFn, Item, ItemFn, ItemImpl, ItemMod,
+    ReturnType, Signature, Type, visit::Visit
+};
+
+use super::{describe_call, is_test_code, predicate_naming::has_predicate_prefix};
+use crate::analyzer::{AnalysisResult, Analyzer, Confidence, Fix, Issue};
+
+/// Analyzer for functions returning `bool` from `.is_ok()`/`.is_err()`.
+///
+/// # Examples
+///
+/// Detects this pattern:
+/// ```ignore
+/// fn save(path: &Path, data: &[u8]) -> bool {
+///     fs::write(path, data).is_ok()
+/// }
+/// ```
+///
+/// Suggests returning the error:
+/// ```ignore
+/// fn save(path: &Path, data: &[u8]) -> io::Result<()> {
+///     fs::write(path, data)
+/// }
+/// ```
+pub struct BoolResultAnalyzer;
+
+impl BoolResultAnalyzer {
+    /// Create new bool result analyzer instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Analyzer for BoolResultAnalyzer {
+    fn name(&self) -> &'static str {
+        "bool_result"
+    }
+
+    fn analyze(&self, ast: &File, _content: &str) -> AppResult<AnalysisResult> {
+        let mut visitor = BoolVisitor {
+            issues: Vec::new()
+        };
+        visitor.visit_file(ast);
+
+        Ok(AnalysisResult {
+            issues:        visitor.issues,
+            fixable_count: 0
+        })
+    }
+
+    fn confidence(&self) -> Confidence {
+        Confidence::Medium
+    }
+}
+
+impl Default for BoolResultAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct BoolVisitor {
+    issues: Vec<Issue>
+}
+
+impl BoolVisitor {
+    /// Flag a `bool` function that collapses a fallible call.
+    fn check_function(&mut self, sig: &Signature, block: &Block) {
+        let name = sig.ident.to_string();
+        if !returns_bool(&sig.output) || has_predicate_prefix(&name) {
+            return;
+        }
+
+        let mut body = OutcomeVisitor {
+            collapsed: None
+        };
+        body.visit_block(block);
+
+        let Some((call, check)) = body.collapsed else {
+            return;
+        };
+
+        let start = sig.ident.span().start();
+        self.issues.push(Issue {
+            line:    start.line,
+            column:  start.column,
+            message: format!(
+                "Function `{name}` returns `bool` but collapses `{call}` with `.{check}()`\n\
+                 The error is discarded, so callers cannot tell why it failed. Return a `Result` \
+                 (e.g. `Result<(), E>`) and let callers decide how to handle the cause"
+            ),
+            fix:     Fix::None
+        });
+    }
+}
+
+impl<'ast> Visit<'ast> for BoolVisitor {
+    fn visit_item_fn(&mut self, node: &'ast ItemFn) {
+        if is_test_code(&node.attrs) {
+            return;
+        }
+        self.check_function(&node.sig, &node.block);
+        syn::visit::visit_item_fn(self, node);
+    }
+
+    fn visit_item_impl(&mut self, node: &'ast ItemImpl) {
+        if node.trait_.is_none() {
+            syn::visit::visit_item_impl(self, node);
+        }
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'ast ImplItemFn) {
+        if is_test_code(&node.attrs) {
+            return;
+        }
+        self.check_function(&node.sig, &node.block);
+        syn::visit::visit_impl_item_fn(self, node);
+    }
+
+    fn visit_item_mod(&mut self, node: &'ast ItemMod) {
+        if !is_test_code(&node.attrs) {
+            syn::visit::visit_item_mod(self, node);
+        }
+    }
+}
+
+/// Looks for `.is_ok()`/`.is_err()` on calls in one function body.
+///
+/// Nested items and closures are skipped; their results are not the
+/// function's outcome.
+struct OutcomeVisitor {
+    /// First collapsed call and the check applied to it
+    collapsed: Option<(String, &'static str)>
+}
+
+impl<'ast> Visit<'ast> for OutcomeVisitor {
+    fn visit_item(&mut self, _node: &'ast Item) {}
+
+    fn visit_expr_closure(&mut self, _node: &'ast ExprClosure) {}
+
+    fn visit_expr_method_call(&mut self, node: &'ast ExprMethodCall) {
+        let check = match node.method.to_string().as_str() {
+            "is_ok" => Some("is_ok"),
+            "is_err" => Some("is_err"),
+            _ => None
+        };
+
+        if let Some(check) = check
+            && node.args.is_empty()
+            && self.collapsed.is_none()
+            && let Some(call) = describe_call(&node.receiver)
+        {
+            self.collapsed = Some((call, check));
+        }
+        syn::visit::visit_expr_method_call(self, node);
+    }
+}
+
+/// Check whether a return type is `bool`.
+pub(crate) fn returns_bool(output: &ReturnType) -> bool {
+    let ReturnType::Type(_, ty) = output else {
+        return false;
+    };
+    matches!(ty.as_ref(), Type::Path(path) if path.path.is_ident("bool"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analyze(code: &str) -> AnalysisResult {
+        let ast = syn::parse_file(code).unwrap();
+        BoolResultAnalyzer::new().analyze(&ast, code).unwrap()
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        let analyzer = BoolResultAnalyzer::new();
+        assert_eq!(analyzer.name(), "bool_result");
+        assert_eq!(analyzer.confidence(), Confidence::Medium);
+    }
+
+    #[test]
+    fn test_detect_io_collapsed_to_bool() {
+        let result = analyze(
+            r#"
+fn save(path: &Path, data: &[u8]) -> bool {
+    fs::write(path, data).is_ok()
+}
+"#
+        );
+
+        assert_eq!(result.issues.len(), 1);
+        assert_eq!(result.issues[0].line, 2);
+        assert!(
+            result.issues[0]
+                .message
+                .contains("collapses `fs::write(..)` with `.is_ok()`")
+        );
+    }
+
+    #[test]
+    fn test_detect_parse_and_async_methods() {
+        let result = analyze(
+            r#"
+impl Client {
+    async fn ping(&self) -> bool {
+        !self.http.get(&self.url).send().await.is_err()
+    }
+
+    fn load_port(&mut self, raw: &str) -> bool {
+        let ok = raw.parse::<u16>().is_ok();
+        ok
+    }
+}
+"#
+        );
+
+        assert_eq!(result.issues.len(), 2);
+        assert!(
+            result.issues[0]
+                .message
+                .contains("`send(..)` with `.is_err()`")
+        );
+        assert!(result.issues[1].message.contains("`parse(..)`"));
+    }
+
+    #[test]
+    fn test_ignore_predicates_and_non_bool_returns() {
+        let result = analyze(
+            r#"
+fn is_number(s: &str) -> bool {
+    s.parse::<f64>().is_ok()
+}
+
+fn refresh(cache: &Cache) -> Result<bool, Error> {
+    Ok(cache.reload().is_ok())
+}
+
+fn any_ready(jobs: &[Job]) -> bool {
+    jobs.iter().any(|job| job.poll().is_ok())
+}
+
+fn check(result: Result<(), Error>) -> bool {
+    result.is_ok()
+}
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_ignore_trait_impls_and_tests() {
+        let result = analyze(
+            r#"
+impl Validator for Config {
+    fn validate(&self) -> bool {
+        Url::parse(&self.url).is_ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    fn writes() -> bool {
+        fs::write("x", "y").is_ok()
+    }
+}
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_default_implementation() {
+        let analyzer = BoolResultAnalyzer;
+        assert_eq!(analyzer.name(), "bool_result");
+    }
+}
@@ -0,0 +1,227 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Clone in loop analyzer for copies made on every iteration.
+//!
+//! A `.clone()` or `.to_owned()` inside a loop body runs once per iteration,
+//! and for strings, vectors and maps every run is an allocation. Often the
+//! value does not change between iterations and can be cloned once before
+//! the loop, or borrowed instead. This analyzer reports such calls in `for`,
+//! `while` and `loop` bodies together with the line the loop starts on.
+
+use std::mem;
+
+use masterror::AppResult;
+use syn::{
+    Block, ExprForLoop, ExprLoop, ExprMethodCall, ExprWhile, File, Item, ItemFn, ItemMod,
+    visit::Visit
+};
+
+use super::is_test_code;
+use crate::analyzer::{AnalysisResult, Analyzer, Fix, Issue};
+
+/// Methods that copy their receiver.
+const COPY_METHODS: &[&str] = &["clone", "to_owned"];
+
+/// Analyzer for clones inside loop bodies.
+///
+/// # Examples
+///
+/// Detects this pattern:
+/// ```ignore
+/// for user in users {
+///     send(user, config.clone());
+/// }
+/// ```
+///
+/// Suggests borrowing instead:
+/// ```ignore
+/// for user in users {
+///     send(user, &config);
+/// }
+/// ```
+pub struct CloneInLoopAnalyzer;
+
+impl CloneInLoopAnalyzer {
+    /// Create new clone in loop analyzer instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Analyzer for CloneInLoopAnalyzer {
+    fn name(&self) -> &'static str {
+        "clone_in_loop"
+    }
+
+    fn analyze(&self, ast: &File, _content: &str) -> AppResult<AnalysisResult> {
+        let mut visitor = CloneVisitor {
+            loops:  Vec::new(),
+            issues: Vec::new()
+        };
+        visitor.visit_file(ast);
+
+        Ok(AnalysisResult {
+            issues:        visitor.issues,
+            fixable_count: 0
+        })
+    }
+}
+
+impl Default for CloneInLoopAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct CloneVisitor {
+    /// Start lines of the loops enclosing the current expression
+    loops:  Vec<usize>,
+    issues: Vec<Issue>
+}
+
+impl CloneVisitor {
+    /// Visit a loop body with the loop on the stack.
+    fn visit_loop_body(&mut self, line: usize, body: &Block) {
+        self.loops.push(line);
+        self.visit_block(body);
+        self.loops.pop();
+    }
+}
+
+impl<'ast> Visit<'ast> for CloneVisitor {
+    fn visit_expr_for_loop(&mut self, node: &'ast ExprForLoop) {
+        self.visit_pat(&node.pat);
+        self.visit_expr(&node.expr);
+        self.visit_loop_body(node.for_token.span.start().line, &node.body);
+    }
+
+    fn visit_expr_while(&mut self, node: &'ast ExprWhile) {
+        self.visit_expr(&node.cond);
+        self.visit_loop_body(node.while_token.span.start().line, &node.body);
+    }
+
+    fn visit_expr_loop(&mut self, node: &'ast ExprLoop) {
+        self.visit_loop_body(node.loop_token.span.start().line, &node.body);
+    }
+
+    fn visit_expr_method_call(&mut self, node: &'ast ExprMethodCall) {
+        let method = node.method.to_string();
+        if let Some(&loop_line) = self.loops.last()
+            && node.args.is_empty()
+            && COPY_METHODS.contains(&method.as_str())
+        {
+            let start = node.method.span().start();
+            self.issues.push(Issue {
+                line:    start.line,
+                column:  start.column,
+                message: format!(
+                    "`.{method}()` runs on every iteration of the loop starting on line \
+                     {loop_line}\n\
+                     Clone once before the loop, or borrow the value if the callee accepts a \
+                     reference"
+                ),
+                fix:     Fix::None
+            });
+        }
+        syn::visit::visit_expr_method_call(self, node);
+    }
+
+    fn visit_item(&mut self, node: &'ast Item) {
+        let loops = mem::take(&mut self.loops);
+        syn::visit::visit_item(self, node);
+        self.loops = loops;
+    }
+
+    fn visit_item_fn(&mut self, node: &'ast ItemFn) {
+        if !is_test_code(&node.attrs) {
+            syn::visit::visit_item_fn(self, node);
+        }
+    }
+
+    fn visit_item_mod(&mut self, node: &'ast ItemMod) {
+        if !is_test_code(&node.attrs) {
+            syn::visit::visit_item_mod(self, node);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analyze(code: &str) -> AnalysisResult {
+        let ast = syn::parse_file(code).unwrap();
+        CloneInLoopAnalyzer::new().analyze(&ast, code).unwrap()
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        let analyzer = CloneInLoopAnalyzer::new();
+        assert_eq!(analyzer.name(), "clone_in_loop");
+    }
+
+    #[test]
+    fn test_detect_clone_in_loops() {
+        let result = analyze(
+            r#"
+fn send_all(users: Vec<User>, config: Config, name: &str) {
+    for user in users {
+        send(user, config.clone());
+    }
+    while ready() {
+        push(name.to_owned());
+    }
+    loop {
+        for _ in 0..3 {
+            log(config.clone());
+        }
+    }
+}
+"#
+        );
+
+        assert_eq!(result.issues.len(), 3);
+        assert_eq!(result.issues[0].line, 4);
+        assert!(
+            result.issues[0]
+                .message
+                .contains("`.clone()` runs on every iteration of the loop starting on line 3")
+        );
+        assert!(result.issues[1].message.contains("`.to_owned()`"));
+        assert!(result.issues[2].message.contains("starting on line 10"));
+    }
+
+    #[test]
+    fn test_ignore_clones_outside_loop_bodies() {
+        let result = analyze(
+            r#"
+fn run(items: &[Item], config: &Config) {
+    let config = config.clone();
+    for item in items.to_owned() {
+        fn helper(config: &Config) -> Config { config.clone() }
+        item.clone_from(&config);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    fn fixture(items: &[Item]) {
+        for item in items {
+            keep(item.clone());
+        }
+    }
+}
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_default_implementation() {
+        let analyzer = CloneInLoopAnalyzer;
+        assert_eq!(analyzer.name(), "clone_in_loop");
+    }
+}
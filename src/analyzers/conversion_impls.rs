@@ -0,0 +1,369 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Conversion impls analyzer for incomplete or hand-rolled conversions.
+//!
+//! Conversions between types read best through the standard traits:
+//! `B::from(a)`, `a.into()` and `A::try_from(b)?` work with generic code
+//! and `?`. This analyzer flags two gaps. An `impl From<A> for B` without a
+//! way back, where a field required in `A` is optional in `B`, so the reverse
+//! conversion needs a `TryFrom` impl. And inherent `to_b(&self)` /
+//! `into_b(self)` methods converting into a type `B`, which belong in a
+//! `From` impl.
+
+use std::collections::{HashMap, HashSet};
+
+use masterror::AppResult;
+use proc_macro2::Span;
+use syn::{Fields, File, FnArg, ImplItem, Item, ItemImpl, ReturnType, Type};
+
+use super::{first_type_argument, is_test_code, type_name, variant_names::camel_words};
+use crate::analyzer::{AnalysisResult, Analyzer, Fix, Issue};
+
+/// Method name prefixes of conversions.
+const CONVERSION_PREFIXES: &[&str] = &["to_", "into_"];
+
+/// Analyzer for missing reverse conversions and manual conversion methods.
+///
+/// # Examples
+///
+/// Detects this pattern:
+/// ```ignore
+/// impl Settings {
+///     pub fn to_config(&self) -> Config { ... }
+/// }
+/// ```
+///
+/// Suggests:
+/// ```ignore
+/// impl From<&Settings> for Config {
+///     fn from(settings: &Settings) -> Self { ... }
+/// }
+/// ```
+pub struct ConversionImplsAnalyzer;
+
+/// Types, conversion impls and conversion methods of a file.
+#[derive(Default)]
+struct Conversions<'a> {
+    /// Named fields of the structs, by struct name
+    structs: HashMap<String, HashMap<String, &'a Type>>,
+    /// `impl From<source> for target` as (source, target, location)
+    from:    Vec<(String, String, Span)>,
+    /// (source, target) of every `From` and `TryFrom` impl
+    pairs:   HashSet<(String, String)>,
+    /// Conversion methods as (self type, method, return type, location)
+    methods: Vec<(String, String, String, Span)>
+}
+
+impl ConversionImplsAnalyzer {
+    /// Create new conversion impls analyzer instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Analyzer for ConversionImplsAnalyzer {
+    fn name(&self) -> &'static str {
+        "conversion_impls"
+    }
+
+    fn analyze(&self, ast: &File, _content: &str) -> AppResult<AnalysisResult> {
+        let mut conversions = Conversions::default();
+        conversions.collect(&ast.items);
+
+        let mut issues = conversions.missing_reverse();
+        issues.extend(conversions.manual_methods());
+        issues.sort_by_key(|issue| (issue.line, issue.column));
+
+        Ok(AnalysisResult {
+            issues,
+            fixable_count: 0
+        })
+    }
+}
+
+impl Default for ConversionImplsAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> Conversions<'a> {
+    /// Collect structs, conversion impls and methods, inline modules
+    /// included.
+    fn collect(&mut self, items: &'a [Item]) {
+        for item in items {
+            match item {
+                Item::Struct(node) if !is_test_code(&node.attrs) => {
+                    if let Fields::Named(fields) = &node.fields {
+                        let fields = fields
+                            .named
+                            .iter()
+                            .filter_map(|field| {
+                                Some((field.ident.as_ref()?.to_string(), &field.ty))
+                            })
+                            .collect();
+                        self.structs.insert(node.ident.to_string(), fields);
+                    }
+                }
+                Item::Impl(node) if !is_test_code(&node.attrs) => self.collect_impl(node),
+                Item::Mod(node) if !is_test_code(&node.attrs) => {
+                    if let Some((_, items)) = &node.content {
+                        self.collect(items);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Record a conversion trait impl or the conversion methods of an
+    /// inherent impl.
+    fn collect_impl(&mut self, node: &ItemImpl) {
+        let Some(self_ty) = type_name(&node.self_ty) else {
+            return;
+        };
+
+        if let Some((_, path, _)) = &node.trait_ {
+            let Some(segment) = path.segments.last() else {
+                return;
+            };
+            if segment.ident != "From" && segment.ident != "TryFrom" {
+                return;
+            }
+            let Some(source) = first_type_argument(&segment.arguments).and_then(type_name) else {
+                return;
+            };
+            if segment.ident == "From" {
+                self.from
+                    .push((source.clone(), self_ty.clone(), node.impl_token.span));
+            }
+            self.pairs.insert((source, self_ty));
+            return;
+        }
+
+        for item in &node.items {
+            let ImplItem::Fn(method) = item else {
+                continue;
+            };
+            let sig = &method.sig;
+            let ReturnType::Type(_, output) = &sig.output else {
+                continue;
+            };
+            let name = sig.ident.to_string();
+            if is_test_code(&method.attrs)
+                || sig.inputs.len() != 1
+                || !matches!(sig.inputs.first(), Some(FnArg::Receiver(_)))
+                || !CONVERSION_PREFIXES
+                    .iter()
+                    .any(|prefix| name.starts_with(prefix))
+            {
+                continue;
+            }
+            if let Some(target) = type_name(output) {
+                self.methods
+                    .push((self_ty.clone(), name, target, sig.ident.span()));
+            }
+        }
+    }
+
+    /// Report `From` impls whose reverse conversion is fallible but missing.
+    fn missing_reverse(&self) -> Vec<Issue> {
+        let mut issues = Vec::new();
+
+        for (source, target, span) in &self.from {
+            if self.pairs.contains(&(target.clone(), source.clone())) {
+                continue;
+            }
+            let (Some(source_fields), Some(target_fields)) =
+                (self.structs.get(source), self.structs.get(target))
+            else {
+                continue;
+            };
+
+            let mut optional: Vec<&String> = source_fields
+                .iter()
+                .filter(|(name, ty)| {
+                    !is_option(ty) && target_fields.get(*name).is_some_and(|ty| is_option(ty))
+                })
+                .map(|(name, _)| name)
+                .collect();
+            if optional.is_empty() {
+                continue;
+            }
+            optional.sort();
+            let fields: Vec<String> = optional.iter().map(|name| format!("`{name}`")).collect();
+            let fields = if fields.len() == 1 {
+                format!("field {} is", fields[0])
+            } else {
+                format!("fields {} are", fields.join(", "))
+            };
+
+            let start = span.start();
+            issues.push(Issue {
+                line:    start.line,
+                column:  start.column,
+                message: format!(
+                    "`From<{source}> for {target}` has no reverse conversion, and {fields} \
+                     optional in `{target}` but required in `{source}`\n\
+                     Add a fallible conversion back, e.g. `impl TryFrom<{target}> for {source} \
+                     {{ type Error = MissingField; fn try_from(value: {target}) -> \
+                     Result<Self, Self::Error> {{ .. }} }}`"
+                ),
+                fix:     Fix::None
+            });
+        }
+
+        issues
+    }
+
+    /// Report `to_b` / `into_b` methods that should be `From` impls.
+    fn manual_methods(&self) -> Vec<Issue> {
+        self.methods
+            .iter()
+            .filter(|(self_ty, method, target, _)| {
+                let suffix = CONVERSION_PREFIXES
+                    .iter()
+                    .find_map(|prefix| method.strip_prefix(prefix))
+                    .unwrap_or_default();
+                target != self_ty
+                    && target != "Self"
+                    && target != "String"
+                    && suffix == snake_case(target)
+                    && !self.pairs.contains(&(self_ty.clone(), target.clone()))
+            })
+            .map(|(self_ty, method, target, span)| {
+                let start = span.start();
+                let source = if method.starts_with("into_") {
+                    self_ty.clone()
+                } else {
+                    format!("&{self_ty}")
+                };
+                Issue {
+                    line:    start.line,
+                    column:  start.column,
+                    message: format!(
+                        "Method `{method}` converts `{self_ty}` into `{target}` by hand\n\
+                         Implement `From` instead, e.g. `impl From<{source}> for {target} {{ fn \
+                         from(value: {source}) -> Self {{ .. }} }}`, so callers can write \
+                         `{target}::from(..)` or `.into()`"
+                    ),
+                    fix:     Fix::None
+                }
+            })
+            .collect()
+    }
+}
+
+/// Check whether a field type is an `Option`.
+fn is_option(ty: &Type) -> bool {
+    type_name(ty).is_some_and(|name| name == "Option")
+}
+
+/// Convert a CamelCase type name to snake_case.
+fn snake_case(name: &str) -> String {
+    camel_words(name)
+        .iter()
+        .map(|word| word.to_lowercase())
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analyze(code: &str) -> AnalysisResult {
+        let ast = syn::parse_file(code).unwrap();
+        ConversionImplsAnalyzer::new().analyze(&ast, code).unwrap()
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        let analyzer = ConversionImplsAnalyzer::new();
+        assert_eq!(analyzer.name(), "conversion_impls");
+    }
+
+    #[test]
+    fn test_detect_missing_fallible_reverse() {
+        let result = analyze(
+            r#"
+struct User { name: String, email: String, age: Option<u8> }
+struct UserRow { name: Option<String>, email: Option<String>, age: Option<u8> }
+
+impl From<User> for UserRow {
+    fn from(user: User) -> Self { todo!() }
+}
+"#
+        );
+
+        assert_eq!(result.issues.len(), 1);
+        assert_eq!(result.issues[0].line, 5);
+        assert!(result.issues[0].message.contains(
+            "`From<User> for UserRow` has no reverse conversion, and fields `email`, `name` are \
+             optional"
+        ));
+        assert!(
+            result.issues[0]
+                .message
+                .contains("impl TryFrom<UserRow> for User")
+        );
+    }
+
+    #[test]
+    fn test_accept_existing_reverse() {
+        let result = analyze(
+            r#"
+struct User { name: String }
+struct UserRow { name: Option<String> }
+struct Plain { name: String }
+
+impl From<User> for UserRow { fn from(user: User) -> Self { todo!() } }
+impl TryFrom<UserRow> for User { type Error = (); fn try_from(row: UserRow) -> Result<Self, ()> { todo!() } }
+impl From<&User> for Plain { fn from(user: &User) -> Self { todo!() } }
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_detect_manual_conversion_methods() {
+        let result = analyze(
+            r#"
+impl Settings {
+    pub fn to_app_config(&self) -> AppConfig { todo!() }
+    pub fn into_config(self) -> Config { todo!() }
+    pub fn to_string(&self) -> String { todo!() }
+    pub fn to_other(&self) -> Config { todo!() }
+    pub fn to_config_with(&self, base: Config) -> Config { todo!() }
+}
+"#
+        );
+
+        assert_eq!(result.issues.len(), 2);
+        assert_eq!(result.issues[0].line, 3);
+        assert!(
+            result.issues[0]
+                .message
+                .contains("`to_app_config` converts `Settings` into `AppConfig`")
+        );
+        assert!(
+            result.issues[0]
+                .message
+                .contains("impl From<&Settings> for AppConfig")
+        );
+        assert!(
+            result.issues[1]
+                .message
+                .contains("impl From<Settings> for Config")
+        );
+    }
+
+    #[test]
+    fn test_default_implementation() {
+        let analyzer = ConversionImplsAnalyzer;
+        assert_eq!(analyzer.name(), "conversion_impls");
+    }
+}
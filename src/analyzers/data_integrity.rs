@@ -0,0 +1,535 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Data integrity analyzer for lossy byte and character handling.
+//!
+//! Parsing code moves between bytes and characters all the time, and the
+//! shortcuts corrupt text outside ASCII without a trace: `'é' as u8` is
+//! `0xE9` and `'€' as u8` is `0xAC`, `byte as char` reads each byte of a
+//! UTF-8 sequence as a separate Latin-1 character, and
+//! `from_utf8_unchecked` on invalid input is undefined behavior. This
+//! analyzer flags these conversions.
+//!
+//! Only `u8` can be cast to `char`, so every `as char` on a value from
+//! outside is a byte read as Latin-1; casts of literals, constants and
+//! arithmetic on literals such as `(b'a' + n) as char` are ignored. A cast
+//! `as u8` is flagged when its operand is known to be a `char`: a char
+//! literal outside ASCII, a `char` parameter or `let`, an item of `chars()`
+//! or `char_indices()` in a `for` loop or iterator closure, or a character
+//! taken with `.chars().next()`.
+
+use std::{collections::HashSet, mem, ptr};
+
+use masterror::AppResult;
+use syn::{
+    Expr, ExprCall, ExprCast, ExprClosure, ExprForLoop, ExprMethodCall, File, FnArg, ImplItemFn,
+    ItemFn, ItemMod, Lit, Local, Pat, Signature, Type, UnOp, spanned::Spanned, visit::Visit
+};
+
+use super::is_test_code;
+use crate::analyzer::{AnalysisResult, Analyzer, Confidence, Fix, Issue};
+
+/// Iterator adapters that keep the item type.
+const ADAPTERS: &[&str] = &[
+    "filter",
+    "skip",
+    "take",
+    "rev",
+    "skip_while",
+    "take_while",
+    "inspect",
+    "peekable",
+    "step_by",
+    "by_ref"
+];
+
+/// Methods taking a character out of a `chars()` iterator.
+const TAKE_CHAR: &[&str] = &[
+    "next",
+    "next_back",
+    "last",
+    "nth",
+    "unwrap",
+    "expect",
+    "unwrap_or",
+    "unwrap_or_default"
+];
+
+/// Analyzer for lossy `as u8` / `as char` casts and unchecked UTF-8.
+///
+/// # Examples
+///
+/// Detects this pattern:
+/// ```ignore
+/// fn decode(bytes: &[u8]) -> String {
+///     bytes.iter().map(|&b| b as char).collect()
+/// }
+/// ```
+///
+/// Suggests decoding the input:
+/// ```ignore
+/// fn decode(bytes: &[u8]) -> String {
+///     String::from_utf8_lossy(bytes).into_owned()
+/// }
+/// ```
+pub struct DataIntegrityAnalyzer;
+
+impl DataIntegrityAnalyzer {
+    /// Create new data integrity analyzer instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Analyzer for DataIntegrityAnalyzer {
+    fn name(&self) -> &'static str {
+        "data_integrity"
+    }
+
+    fn analyze(&self, ast: &File, content: &str) -> AppResult<AnalysisResult> {
+        let mut visitor = IntegrityVisitor {
+            content,
+            chars: HashSet::new(),
+            issues: Vec::new()
+        };
+        visitor.visit_file(ast);
+
+        Ok(AnalysisResult {
+            issues:        visitor.issues,
+            fixable_count: 0
+        })
+    }
+
+    fn confidence(&self) -> Confidence {
+        Confidence::Medium
+    }
+}
+
+impl Default for DataIntegrityAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct IntegrityVisitor<'a> {
+    content: &'a str,
+    /// Bindings of the current function known to hold a `char`
+    chars:   HashSet<String>,
+    issues:  Vec<Issue>
+}
+
+impl IntegrityVisitor<'_> {
+    /// Visit a function with its `char` parameters as the known bindings.
+    fn visit_function(&mut self, sig: &Signature, visit: impl FnOnce(&mut Self)) {
+        let params = sig
+            .inputs
+            .iter()
+            .filter_map(|arg| match arg {
+                FnArg::Typed(typed) if is_char_type(&typed.ty) => binding_name(&typed.pat),
+                _ => None
+            })
+            .collect();
+
+        let outer = mem::replace(&mut self.chars, params);
+        visit(self);
+        self.chars = outer;
+    }
+
+    /// Visit code with extra `char` bindings, dropping them afterwards.
+    fn with_chars(&mut self, names: Vec<String>, visit: impl FnOnce(&mut Self)) {
+        let outer = self.chars.clone();
+        self.chars.extend(names);
+        visit(self);
+        self.chars = outer;
+    }
+
+    /// Check whether an expression is known to be a `char`.
+    fn is_char(&self, expr: &Expr) -> bool {
+        match expr {
+            Expr::Lit(lit) => matches!(&lit.lit, Lit::Char(c) if !c.value().is_ascii()),
+            Expr::Path(path) => path
+                .path
+                .get_ident()
+                .is_some_and(|ident| self.chars.contains(&ident.to_string())),
+            Expr::Paren(paren) => self.is_char(&paren.expr),
+            Expr::Unary(unary) if matches!(unary.op, UnOp::Deref(_)) => self.is_char(&unary.expr),
+            Expr::MethodCall(_) => {
+                let mut receiver = expr;
+                while let Expr::MethodCall(call) = receiver
+                    && TAKE_CHAR.iter().any(|method| call.method == method)
+                {
+                    receiver = &call.receiver;
+                }
+                !ptr::eq(receiver, expr) && item_kind(receiver) == Some(Items::Chars)
+            }
+            _ => false
+        }
+    }
+
+    fn check_cast(&mut self, node: &ExprCast) {
+        let message = if is_named(&node.ty, "u8") && self.is_char(&node.expr) {
+            format!(
+                "`{}` truncates characters above U+00FF\nUse `u8::try_from(..)` or check \
+                 `is_ascii()` first; to store text as bytes, encode it with `encode_utf8`",
+                self.text(node)
+            )
+        } else if is_named(&node.ty, "char") && !is_fixed(&node.expr) {
+            format!(
+                "`{}` reads the byte as Latin-1 and corrupts UTF-8 input\nDecode the bytes with \
+                 `str::from_utf8` or `String::from_utf8_lossy`, or check `is_ascii()` first",
+                self.text(node)
+            )
+        } else {
+            return;
+        };
+
+        let start = node.span().start();
+        self.issues.push(Issue {
+            line: start.line,
+            column: start.column,
+            message,
+            fix: Fix::None
+        });
+    }
+
+    fn check_unchecked(&mut self, func: &Expr) {
+        let Expr::Path(path) = func else {
+            return;
+        };
+        let Some(last) = path.path.segments.last() else {
+            return;
+        };
+        if last.ident != "from_utf8_unchecked" && last.ident != "from_utf8_unchecked_mut" {
+            return;
+        }
+
+        let start = func.span().start();
+        self.issues.push(Issue {
+            line:    start.line,
+            column:  start.column,
+            message: format!(
+                "`{}` skips UTF-8 validation\nInvalid input is undefined behavior: use \
+                 `from_utf8` and handle the error, or `String::from_utf8_lossy`",
+                last.ident
+            ),
+            fix:     Fix::None
+        });
+    }
+
+    /// Source text of a cast, e.g. `c as u8`.
+    fn text(&self, node: &ExprCast) -> String {
+        let range = node.span().byte_range();
+        self.content
+            .get(range)
+            .filter(|text| !text.is_empty() && !text.contains('\n'))
+            .map_or_else(|| "as".to_string(), str::to_string)
+    }
+}
+
+impl<'ast> Visit<'ast> for IntegrityVisitor<'_> {
+    fn visit_item_fn(&mut self, node: &'ast ItemFn) {
+        if !is_test_code(&node.attrs) {
+            self.visit_function(&node.sig, |visitor| {
+                syn::visit::visit_item_fn(visitor, node);
+            });
+        }
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'ast ImplItemFn) {
+        if !is_test_code(&node.attrs) {
+            self.visit_function(&node.sig, |visitor| {
+                syn::visit::visit_impl_item_fn(visitor, node);
+            });
+        }
+    }
+
+    fn visit_item_mod(&mut self, node: &'ast ItemMod) {
+        if !is_test_code(&node.attrs) {
+            syn::visit::visit_item_mod(self, node);
+        }
+    }
+
+    fn visit_local(&mut self, node: &'ast Local) {
+        syn::visit::visit_local(self, node);
+        let Some(name) = binding_name(&node.pat) else {
+            return;
+        };
+        if matches!(&node.pat, Pat::Type(typed) if is_char_type(&typed.ty)) {
+            self.chars.insert(name);
+        } else {
+            self.chars.remove(&name);
+        }
+    }
+
+    fn visit_expr_for_loop(&mut self, node: &'ast ExprForLoop) {
+        self.visit_expr(&node.expr);
+        let names = item_kind(&node.expr)
+            .and_then(|items| items.binding(&node.pat))
+            .into_iter()
+            .collect();
+        self.with_chars(names, |visitor| {
+            visitor.visit_pat(&node.pat);
+            visitor.visit_block(&node.body);
+        });
+    }
+
+    fn visit_expr_method_call(&mut self, node: &'ast ExprMethodCall) {
+        let Some(items) = item_kind(&node.receiver) else {
+            syn::visit::visit_expr_method_call(self, node);
+            return;
+        };
+        let names = node
+            .args
+            .iter()
+            .filter_map(|arg| match arg {
+                Expr::Closure(ExprClosure {
+                    inputs, ..
+                }) if inputs.len() == 1 => items.binding(&inputs[0]),
+                _ => None
+            })
+            .collect();
+        self.with_chars(names, |visitor| {
+            syn::visit::visit_expr_method_call(visitor, node);
+        });
+    }
+
+    fn visit_expr_cast(&mut self, node: &'ast ExprCast) {
+        self.check_cast(node);
+        syn::visit::visit_expr_cast(self, node);
+    }
+
+    fn visit_expr_call(&mut self, node: &'ast ExprCall) {
+        self.check_unchecked(&node.func);
+        syn::visit::visit_expr_call(self, node);
+    }
+}
+
+/// Items of a character iterator.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Items {
+    /// `chars()`: the items are characters
+    Chars,
+    /// `char_indices()` or `chars().enumerate()`: the characters come second
+    Indexed
+}
+
+impl Items {
+    /// Name the character is bound to in an item pattern.
+    fn binding(self, pat: &Pat) -> Option<String> {
+        match (self, pat) {
+            (_, Pat::Reference(reference)) => self.binding(&reference.pat),
+            (_, Pat::Type(typed)) => self.binding(&typed.pat),
+            (Self::Chars, pat) => binding_name(pat),
+            (Self::Indexed, Pat::Tuple(tuple)) if tuple.elems.len() == 2 => {
+                binding_name(&tuple.elems[1])
+            }
+            _ => None
+        }
+    }
+}
+
+/// Kind of character iterator an expression is, if any.
+fn item_kind(expr: &Expr) -> Option<Items> {
+    let Expr::MethodCall(call) = expr else {
+        return None;
+    };
+    let method = call.method.to_string();
+    match method.as_str() {
+        "chars" => Some(Items::Chars),
+        "char_indices" => Some(Items::Indexed),
+        "enumerate" => (item_kind(&call.receiver) == Some(Items::Chars)).then_some(Items::Indexed),
+        _ if ADAPTERS.contains(&method.as_str()) => item_kind(&call.receiver),
+        _ => None
+    }
+}
+
+/// Check whether a cast operand is fixed by the code rather than read from
+/// input: a literal, a constant, or arithmetic with a literal.
+fn is_fixed(expr: &Expr) -> bool {
+    match expr {
+        Expr::Lit(_) => true,
+        Expr::Path(path) => path.path.segments.last().is_some_and(|segment| {
+            let name = segment.ident.to_string();
+            name.chars().any(|c| c.is_ascii_uppercase())
+                && !name.chars().any(|c| c.is_ascii_lowercase())
+        }),
+        Expr::Paren(paren) => is_fixed(&paren.expr),
+        Expr::Binary(binary) => is_fixed(&binary.left) || is_fixed(&binary.right),
+        _ => false
+    }
+}
+
+/// Check whether a type is `char`.
+fn is_char_type(ty: &Type) -> bool {
+    is_named(ty, "char")
+}
+
+/// Check whether a type is the single-segment path `name`.
+fn is_named(ty: &Type, name: &str) -> bool {
+    matches!(ty, Type::Path(path) if path.qself.is_none() && path.path.is_ident(name))
+}
+
+/// Name bound by a simple pattern.
+fn binding_name(pat: &Pat) -> Option<String> {
+    match pat {
+        Pat::Ident(ident) => Some(ident.ident.to_string()),
+        Pat::Type(typed) => binding_name(&typed.pat),
+        Pat::Reference(reference) => binding_name(&reference.pat),
+        _ => None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analyze(code: &str) -> AnalysisResult {
+        let ast = syn::parse_file(code).unwrap();
+        DataIntegrityAnalyzer::new().analyze(&ast, code).unwrap()
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        let analyzer = DataIntegrityAnalyzer::new();
+        assert_eq!(analyzer.name(), "data_integrity");
+        assert_eq!(analyzer.confidence(), Confidence::Medium);
+    }
+
+    #[test]
+    fn test_detect_char_to_byte_casts() {
+        let result = analyze(
+            r#"
+fn encode(input: &str, sep: char) -> Vec<u8> {
+    let mut out = Vec::from([sep as u8]);
+    for c in input.chars() {
+        out.push(c as u8);
+    }
+    for (i, c) in input.char_indices() {
+        out.push(c as u8 + i as u8);
+    }
+    let first = input.chars().next().unwrap() as u8;
+    let bytes: Vec<u8> = input.chars().filter(|c| !c.is_whitespace()).map(|c| c as u8).collect();
+    let euro = '€' as u8;
+    out
+}
+"#
+        );
+
+        assert_eq!(result.issues.len(), 6);
+        assert_eq!(result.fixable_count, 0);
+        assert_eq!(result.issues[0].line, 3);
+        assert_eq!(result.issues[0].column, 29);
+        assert!(
+            result.issues[0].message.starts_with(
+                "`sep as u8` truncates characters above U+00FF\nUse `u8::try_from(..)`"
+            )
+        );
+        assert!(result.issues[1].message.starts_with("`c as u8`"));
+        assert!(result.issues[2].message.starts_with("`c as u8`"));
+        assert!(
+            result.issues[3]
+                .message
+                .starts_with("`input.chars().next().unwrap() as u8`")
+        );
+        assert!(result.issues[4].message.starts_with("`c as u8`"));
+        assert!(result.issues[5].message.starts_with("`'€' as u8`"));
+    }
+
+    #[test]
+    fn test_detect_byte_to_char_casts() {
+        let result = analyze(
+            r#"
+fn decode(bytes: &[u8], index: usize) -> String {
+    let first = bytes[index] as char;
+    bytes.iter().map(|&b| b as char).collect()
+}
+"#
+        );
+
+        assert_eq!(result.issues.len(), 2);
+        assert_eq!(result.issues[0].line, 3);
+        assert!(result.issues[0].message.starts_with(
+            "`bytes[index] as char` reads the byte as Latin-1 and corrupts UTF-8 input\nDecode"
+        ));
+        assert!(result.issues[1].message.starts_with("`b as char`"));
+    }
+
+    #[test]
+    fn test_detect_unchecked_utf8() {
+        let result = analyze(
+            r#"
+fn text(bytes: Vec<u8>) -> String {
+    unsafe { String::from_utf8_unchecked(bytes) }
+}
+
+fn view(bytes: &mut [u8]) -> &mut str {
+    unsafe { std::str::from_utf8_unchecked_mut(bytes) }
+}
+"#
+        );
+
+        assert_eq!(result.issues.len(), 2);
+        assert_eq!(result.issues[0].line, 3);
+        assert_eq!(result.issues[0].column, 13);
+        assert!(
+            result.issues[0]
+                .message
+                .starts_with("`from_utf8_unchecked` skips UTF-8 validation")
+        );
+        assert!(
+            result.issues[1]
+                .message
+                .starts_with("`from_utf8_unchecked_mut`")
+        );
+    }
+
+    #[test]
+    fn test_ignore_safe_conversions() {
+        let result = analyze(
+            r#"
+const SEPARATOR: u8 = b',';
+
+fn convert(input: &str, count: u32, digit: u8) -> Vec<u8> {
+    let sep = SEPARATOR as char;
+    let letter = (b'a' + digit) as char;
+    let newline = 10 as char;
+    let dash = '-' as u8;
+    let small = count as u8;
+    let parsed = std::str::from_utf8(input.as_bytes());
+    input.bytes().map(|b| b as u16 as u8).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    fn fixture(c: char) -> u8 {
+        c as u8
+    }
+}
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_char_bindings_scoped() {
+        let result = analyze(
+            r#"
+fn scoped(input: &str, n: u32) {
+    for c in input.chars() {}
+    let c = n;
+    let byte = c as u8;
+}
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_default_implementation() {
+        let analyzer = DataIntegrityAnalyzer;
+        assert_eq!(analyzer.name(), "data_integrity");
+    }
+}
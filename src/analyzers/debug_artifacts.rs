@@ -0,0 +1,279 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Debug artifacts analyzer for leftover `dbg!` and print debugging.
+//!
+//! `dbg!`, `println!` and `eprintln!` added while debugging a library end up
+//! on the terminal of every downstream user. This analyzer flags them in
+//! library modules (binaries, `build.rs`, examples, benches and integration
+//! tests print on purpose) and removes the standalone statements.
+
+use std::path::Path;
+
+use masterror::AppResult;
+use syn::{
+    Expr, ExprMacro, File, ImplItemFn, ItemFn, ItemMod, Macro, Stmt, spanned::Spanned,
+    visit::Visit
+};
+
+use super::{is_library_path, is_test_code, whole_lines};
+use crate::analyzer::{AnalysisResult, Analyzer, Fix, Issue, Suggestion, TextEdit};
+
+/// Macros used for ad-hoc debugging.
+const DEBUG_MACROS: &[&str] = &["dbg", "println", "eprintln"];
+
+/// Analyzer for `dbg!`, `println!` and `eprintln!` left in library code.
+///
+/// # Examples
+///
+/// Detects this pattern:
+/// ```ignore
+/// pub fn parse(input: &str) -> Ast {
+///     dbg!(input);
+///     eprintln!("parsing");
+///     Parser::new(input).parse()
+/// }
+/// ```
+///
+/// Fixes to:
+/// ```ignore
+/// pub fn parse(input: &str) -> Ast {
+///     Parser::new(input).parse()
+/// }
+/// ```
+pub struct DebugArtifactsAnalyzer;
+
+/// Debug macro found in library code.
+struct Artifact<'ast> {
+    mac:   &'ast Macro,
+    /// Statement span for standalone invocations that can be removed
+    range: Option<std::ops::Range<usize>>
+}
+
+impl DebugArtifactsAnalyzer {
+    /// Create new debug artifacts analyzer instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Collect debug macros outside test code.
+    fn collect(ast: &File) -> Vec<Artifact<'_>> {
+        let mut visitor = ArtifactVisitor {
+            artifacts: Vec::new()
+        };
+        visitor.visit_file(ast);
+        visitor.artifacts
+    }
+}
+
+impl Analyzer for DebugArtifactsAnalyzer {
+    fn name(&self) -> &'static str {
+        "debug_artifacts"
+    }
+
+    fn analyze(&self, ast: &File, _content: &str) -> AppResult<AnalysisResult> {
+        let mut issues = Vec::new();
+        let mut fixable_count = 0;
+
+        for artifact in Self::collect(ast) {
+            let name = macro_name(artifact.mac).unwrap_or_default();
+            let start = artifact.mac.span().start();
+            let fix = if artifact.range.is_some() {
+                fixable_count += 1;
+                Fix::Simple(String::new())
+            } else {
+                Fix::None
+            };
+
+            issues.push(Issue {
+                line: start.line,
+                column: start.column,
+                message: format!(
+                    "Debug artifact: `{name}!` left in library code\n\
+                     Remove it, or use `tracing`/`log` for output that should stay"
+                ),
+                fix
+            });
+        }
+
+        Ok(AnalysisResult {
+            issues,
+            fixable_count
+        })
+    }
+
+    fn suggestions(&self, ast: &File, content: &str) -> AppResult<Vec<Suggestion>> {
+        Ok(Self::collect(ast)
+            .into_iter()
+            .filter_map(|artifact| artifact.range)
+            .map(|range| Suggestion {
+                edit:   TextEdit {
+                    range:       whole_lines(content, range),
+                    replacement: String::new()
+                },
+                import: None
+            })
+            .collect())
+    }
+
+    fn fixable(&self) -> bool {
+        true
+    }
+
+    fn applies_to(&self, path: &Path) -> bool {
+        is_library_path(path)
+    }
+}
+
+impl Default for DebugArtifactsAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct ArtifactVisitor<'ast> {
+    artifacts: Vec<Artifact<'ast>>
+}
+
+impl<'ast> Visit<'ast> for ArtifactVisitor<'ast> {
+    fn visit_item_fn(&mut self, node: &'ast ItemFn) {
+        if !is_test_code(&node.attrs) {
+            syn::visit::visit_item_fn(self, node);
+        }
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'ast ImplItemFn) {
+        if !is_test_code(&node.attrs) {
+            syn::visit::visit_impl_item_fn(self, node);
+        }
+    }
+
+    fn visit_item_mod(&mut self, node: &'ast ItemMod) {
+        if !is_test_code(&node.attrs) {
+            syn::visit::visit_item_mod(self, node);
+        }
+    }
+
+    fn visit_stmt(&mut self, node: &'ast Stmt) {
+        let standalone = match node {
+            Stmt::Macro(stmt) => Some(&stmt.mac),
+            Stmt::Expr(Expr::Macro(expr), Some(_)) => Some(&expr.mac),
+            _ => None
+        };
+
+        match standalone {
+            Some(mac) if macro_name(mac).is_some() => self.artifacts.push(Artifact {
+                mac,
+                range: Some(node.span().byte_range())
+            }),
+            _ => syn::visit::visit_stmt(self, node)
+        }
+    }
+
+    fn visit_expr_macro(&mut self, node: &'ast ExprMacro) {
+        if macro_name(&node.mac).is_some() {
+            self.artifacts.push(Artifact {
+                mac:   &node.mac,
+                range: None
+            });
+        }
+        syn::visit::visit_expr_macro(self, node);
+    }
+}
+
+/// Get the name of a debug macro.
+///
+/// # Returns
+///
+/// `Some(name)` for `dbg`, `println` and `eprintln`
+fn macro_name(mac: &Macro) -> Option<&'static str> {
+    let ident = mac.path.get_ident()?;
+    DEBUG_MACROS.iter().copied().find(|name| ident == name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analyze(code: &str) -> AnalysisResult {
+        let ast = syn::parse_file(code).unwrap();
+        DebugArtifactsAnalyzer::new().analyze(&ast, code).unwrap()
+    }
+
+    fn fix(code: &str) -> String {
+        let ast = syn::parse_file(code).unwrap();
+        let suggestions = DebugArtifactsAnalyzer::new()
+            .suggestions(&ast, code)
+            .unwrap();
+        crate::fixer::apply_suggestions(code, &suggestions).source
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        let analyzer = DebugArtifactsAnalyzer::new();
+        assert_eq!(analyzer.name(), "debug_artifacts");
+    }
+
+    #[test]
+    fn test_detect_debug_macros() {
+        let result = analyze(
+            "fn parse(input: &str) -> usize {\n    dbg!(input);\n    println!(\"x\");\n    \
+             eprintln!(\"y\");\n    print!(\"kept\");\n    input.len()\n}\n"
+        );
+
+        assert_eq!(result.issues.len(), 3);
+        assert_eq!(result.fixable_count, 3);
+        assert!(result.issues[0].message.contains("`dbg!`"));
+    }
+
+    #[test]
+    fn test_expression_position_not_fixable() {
+        let result = analyze("fn f(x: u8) -> u8 {\n    let y = dbg!(x) + 1;\n    dbg!(y)\n}\n");
+
+        assert_eq!(result.issues.len(), 2);
+        assert_eq!(result.fixable_count, 0);
+    }
+
+    #[test]
+    fn test_fix_removes_statement_lines() {
+        let code = "fn f(x: u8) -> u8 {\n    dbg!(x);\n    eprintln!(\n        \"x = {}\",\n        \
+                    x\n    );\n    x\n}\n";
+
+        assert_eq!(fix(code), "fn f(x: u8) -> u8 {\n    x\n}\n");
+    }
+
+    #[test]
+    fn test_fix_keeps_code_sharing_the_line() {
+        let code = "fn f(x: u8) {\n    let y = x; dbg!(y);\n}\n";
+
+        assert_eq!(fix(code), "fn f(x: u8) {\n    let y = x; \n}\n");
+    }
+
+    #[test]
+    fn test_ignore_test_code() {
+        let result = analyze(
+            "#[cfg(test)]\nmod tests {\n    fn helper() {\n        dbg!(1);\n    }\n}\n\n#[test]\nfn \
+             t() {\n    println!(\"ok\");\n}\n"
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_library_paths() {
+        assert!(is_library_path(Path::new("src/lib.rs")));
+        assert!(is_library_path(Path::new("crates/core/src/parser/mod.rs")));
+        assert!(!is_library_path(Path::new("src/main.rs")));
+        assert!(!is_library_path(Path::new("build.rs")));
+        assert!(!is_library_path(Path::new("src/bin/cli.rs")));
+        assert!(!is_library_path(Path::new("examples/demo.rs")));
+        assert!(!is_library_path(Path::new("tests/integration.rs")));
+    }
+
+    #[test]
+    fn test_default_implementation() {
+        let analyzer = DebugArtifactsAnalyzer;
+        assert_eq!(analyzer.name(), "debug_artifacts");
+    }
+}
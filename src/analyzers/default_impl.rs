@@ -0,0 +1,419 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Default impl analyzer for constructors without a `Default` counterpart.
+//!
+//! A type with a zero-argument `pub fn new()` can be created without any
+//! input, so generic code expects `T::default()`, `unwrap_or_default()` and
+//! `#[derive(Default)]` on containing types to work for it too. This
+//! analyzer flags such constructors when the file neither derives nor
+//! implements `Default` for the type, and generates an impl delegating to
+//! `new()`.
+
+use std::collections::HashSet;
+
+use masterror::AppResult;
+use syn::{
+    File, ImplItem, ImplItemFn, ItemEnum, ItemImpl, ItemMod, ItemStruct, ReturnType, Type,
+    Visibility, spanned::Spanned, visit::Visit
+};
+
+use super::{find_derive, is_test_code};
+use crate::analyzer::{AnalysisResult, Analyzer, Fix, Issue, Suggestion, TextEdit};
+
+/// Analyzer for types with `pub fn new()` but no `Default` implementation.
+///
+/// # Examples
+///
+/// Detects this pattern:
+/// ```ignore
+/// impl Registry {
+///     pub fn new() -> Self {
+///         Self { entries: Vec::new() }
+///     }
+/// }
+/// ```
+///
+/// Fixes to:
+/// ```ignore
+/// impl Registry {
+///     pub fn new() -> Self {
+///         Self { entries: Vec::new() }
+///     }
+/// }
+///
+/// impl Default for Registry {
+///     fn default() -> Self {
+///         Self::new()
+///     }
+/// }
+/// ```
+pub struct DefaultImplAnalyzer;
+
+/// Zero-argument constructor of a type without `Default`.
+struct Candidate {
+    type_name: String,
+    line:      usize,
+    column:    usize,
+    /// Generated `impl Default` block, unindented
+    impl_text: String,
+    /// Byte offset just past the closing brace of the inherent impl
+    offset:    usize
+}
+
+impl DefaultImplAnalyzer {
+    /// Create new default impl analyzer instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Collect constructors of types lacking a `Default` implementation.
+    fn collect(ast: &File, content: &str) -> Vec<Candidate> {
+        let mut defaults = DefaultVisitor {
+            types: HashSet::new()
+        };
+        defaults.visit_file(ast);
+
+        let mut visitor = ConstructorVisitor {
+            content,
+            defaults: defaults.types,
+            candidates: Vec::new()
+        };
+        visitor.visit_file(ast);
+        visitor.candidates
+    }
+}
+
+impl Analyzer for DefaultImplAnalyzer {
+    fn name(&self) -> &'static str {
+        "default_impl"
+    }
+
+    fn analyze(&self, ast: &File, content: &str) -> AppResult<AnalysisResult> {
+        let issues: Vec<Issue> = Self::collect(ast, content)
+            .into_iter()
+            .map(|candidate| Issue {
+                line:    candidate.line,
+                column:  candidate.column,
+                message: format!(
+                    "`{}` has `pub fn new()` without arguments but no `Default` implementation\n\
+                     Implement `Default` by delegating to `new()`",
+                    candidate.type_name
+                ),
+                fix:     Fix::Simple(candidate.impl_text)
+            })
+            .collect();
+
+        Ok(AnalysisResult {
+            fixable_count: issues.len(),
+            issues
+        })
+    }
+
+    fn suggestions(&self, ast: &File, content: &str) -> AppResult<Vec<Suggestion>> {
+        Ok(Self::collect(ast, content)
+            .into_iter()
+            .map(|candidate| {
+                let line_start = content[..candidate.offset]
+                    .rfind('\n')
+                    .map_or(0, |idx| idx + 1);
+                let indent: String = content[line_start..]
+                    .chars()
+                    .take_while(|c| *c == ' ' || *c == '\t')
+                    .collect();
+                let block: Vec<String> = candidate
+                    .impl_text
+                    .lines()
+                    .map(|line| format!("{indent}{line}"))
+                    .collect();
+
+                Suggestion {
+                    edit:   TextEdit {
+                        range:       candidate.offset..candidate.offset,
+                        replacement: format!("\n\n{}", block.join("\n"))
+                    },
+                    import: None
+                }
+            })
+            .collect())
+    }
+
+    fn fixable(&self) -> bool {
+        true
+    }
+}
+
+impl Default for DefaultImplAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Collects names of types deriving or implementing `Default`.
+struct DefaultVisitor {
+    types: HashSet<String>
+}
+
+impl<'ast> Visit<'ast> for DefaultVisitor {
+    fn visit_item_struct(&mut self, node: &'ast ItemStruct) {
+        if find_derive(&node.attrs, "Default").is_some() {
+            self.types.insert(node.ident.to_string());
+        }
+    }
+
+    fn visit_item_enum(&mut self, node: &'ast ItemEnum) {
+        if find_derive(&node.attrs, "Default").is_some() {
+            self.types.insert(node.ident.to_string());
+        }
+    }
+
+    fn visit_item_impl(&mut self, node: &'ast ItemImpl) {
+        let implements_default = node
+            .trait_
+            .as_ref()
+            .and_then(|(_, path, _)| path.segments.last())
+            .is_some_and(|segment| segment.ident == "Default");
+        if implements_default && let Some(name) = type_name(&node.self_ty) {
+            self.types.insert(name);
+        }
+        syn::visit::visit_item_impl(self, node);
+    }
+}
+
+struct ConstructorVisitor<'a> {
+    content:    &'a str,
+    /// Types that already derive or implement `Default`
+    defaults:   HashSet<String>,
+    candidates: Vec<Candidate>
+}
+
+impl ConstructorVisitor<'_> {
+    /// Source text of a syntax node on a single line.
+    fn text(&self, node: &impl Spanned) -> String {
+        single_line(&self.content[node.span().byte_range()])
+    }
+
+    /// Build the `impl Default` block matching an inherent impl's header.
+    fn default_impl(&self, node: &ItemImpl) -> String {
+        let generics = match (&node.generics.lt_token, &node.generics.gt_token) {
+            (Some(lt), Some(gt)) => {
+                single_line(&self.content[lt.span.byte_range().start..gt.span.byte_range().end])
+            }
+            _ => String::new()
+        };
+        let where_clause = node
+            .generics
+            .where_clause
+            .as_ref()
+            .map(|clause| format!(" {}", self.text(clause)))
+            .unwrap_or_default();
+
+        format!(
+            "impl{generics} Default for {}{where_clause} {{\n    fn default() -> Self {{\n        \
+             Self::new()\n    }}\n}}",
+            self.text(&node.self_ty)
+        )
+    }
+}
+
+impl<'ast> Visit<'ast> for ConstructorVisitor<'_> {
+    fn visit_item_impl(&mut self, node: &'ast ItemImpl) {
+        if node.trait_.is_some() || is_test_code(&node.attrs) {
+            return;
+        }
+        let Some(name) = type_name(&node.self_ty) else {
+            return;
+        };
+        if self.defaults.contains(&name) {
+            return;
+        }
+
+        let constructor = node.items.iter().find_map(|item| match item {
+            ImplItem::Fn(method) if is_plain_constructor(method, &name) => Some(method),
+            _ => None
+        });
+        let Some(constructor) = constructor else {
+            return;
+        };
+
+        let start = constructor.sig.ident.span().start();
+        self.candidates.push(Candidate {
+            type_name: name.clone(),
+            line:      start.line,
+            column:    start.column,
+            impl_text: self.default_impl(node),
+            offset:    node.brace_token.span.close().byte_range().end
+        });
+        self.defaults.insert(name);
+    }
+
+    fn visit_item_mod(&mut self, node: &'ast ItemMod) {
+        if !is_test_code(&node.attrs) {
+            syn::visit::visit_item_mod(self, node);
+        }
+    }
+}
+
+/// Check whether a method is `pub fn new() -> Self` without arguments.
+fn is_plain_constructor(method: &ImplItemFn, self_name: &str) -> bool {
+    let sig = &method.sig;
+    let ReturnType::Type(_, output) = &sig.output else {
+        return false;
+    };
+
+    matches!(method.vis, Visibility::Public(_))
+        && sig.ident == "new"
+        && sig.inputs.is_empty()
+        && sig.generics.params.is_empty()
+        && sig.asyncness.is_none()
+        && sig.unsafety.is_none()
+        && type_name(output).is_some_and(|ret| ret == "Self" || ret == self_name)
+}
+
+/// Name of a path type, e.g. the type an impl block targets.
+fn type_name(ty: &Type) -> Option<String> {
+    match ty {
+        Type::Path(path) => path.path.segments.last().map(|s| s.ident.to_string()),
+        _ => None
+    }
+}
+
+/// Collapse source text spanning several lines into one.
+fn single_line(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analyze(code: &str) -> AnalysisResult {
+        let ast = syn::parse_file(code).unwrap();
+        DefaultImplAnalyzer::new().analyze(&ast, code).unwrap()
+    }
+
+    fn fix(code: &str) -> String {
+        let ast = syn::parse_file(code).unwrap();
+        let suggestions = DefaultImplAnalyzer::new().suggestions(&ast, code).unwrap();
+        crate::fixer::apply_suggestions(code, &suggestions).source
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        let analyzer = DefaultImplAnalyzer::new();
+        assert_eq!(analyzer.name(), "default_impl");
+    }
+
+    #[test]
+    fn test_detect_new_without_default() {
+        let result = analyze(
+            r#"
+pub struct Registry { entries: Vec<String> }
+
+impl Registry {
+    pub fn new() -> Self { Self { entries: Vec::new() } }
+}
+
+impl<T: Clone> Cache<T> {
+    pub fn new() -> Cache<T> { todo!() }
+}
+"#
+        );
+
+        assert_eq!(result.issues.len(), 2);
+        assert_eq!(result.fixable_count, 2);
+        assert_eq!(result.issues[0].line, 5);
+        assert!(result.issues[0].message.contains(
+            "`Registry` has `pub fn new()` without arguments but no `Default` implementation"
+        ));
+        assert!(matches!(
+            &result.issues[1].fix,
+            Fix::Simple(text) if text.starts_with("impl<T: Clone> Default for Cache<T> {")
+        ));
+    }
+
+    #[test]
+    fn test_ignore_types_with_default_or_arguments() {
+        let result = analyze(
+            r#"
+#[derive(Debug, Default)]
+pub struct Derived;
+impl Derived {
+    pub fn new() -> Self { Self }
+}
+
+pub struct Manual;
+impl Manual {
+    pub fn new() -> Self { Self }
+}
+impl Default for Manual {
+    fn default() -> Self { Self::new() }
+}
+
+pub struct Configured;
+impl Configured {
+    pub fn new(size: usize) -> Self { Self }
+    fn private() -> Self { Self }
+}
+
+pub struct Fallible;
+impl Fallible {
+    pub fn new() -> Result<Self, Error> { Ok(Self) }
+}
+
+#[cfg(test)]
+mod tests {
+    struct Fixture;
+    impl Fixture {
+        pub fn new() -> Self { Self }
+    }
+}
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_fix_generates_default_impl() {
+        let code = r#"mod store {
+    impl<K> Store<K>
+    where
+        K: Ord
+    {
+        pub fn new() -> Self {
+            Self { items: BTreeMap::new() }
+        }
+    }
+}
+"#;
+
+        assert_eq!(
+            fix(code),
+            r#"mod store {
+    impl<K> Store<K>
+    where
+        K: Ord
+    {
+        pub fn new() -> Self {
+            Self { items: BTreeMap::new() }
+        }
+    }
+
+    impl<K> Default for Store<K> where K: Ord {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+}
+"#
+        );
+    }
+
+    #[test]
+    fn test_default_implementation() {
+        let analyzer = DefaultImplAnalyzer;
+        assert_eq!(analyzer.name(), "default_impl");
+    }
+}
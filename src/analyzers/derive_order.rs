@@ -0,0 +1,365 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Derive order analyzer for `#[derive(..)]` lists in a canonical order.
+//!
+//! Derive lists written in arbitrary order are harder to scan and produce
+//! noisy diffs when a trait is added. This opt-in analyzer checks every
+//! derive list against a canonical order: the standard traits from `Debug`
+//! to `Default`, then other derives in their written order, then the serde
+//! traits. The fix reorders the traits in place and keeps the separators,
+//! comments and line breaks of the list.
+
+use std::ops::Range;
+
+use masterror::AppResult;
+use syn::{
+    Attribute, File, MacroDelimiter, Meta, Path, punctuated::Punctuated, spanned::Spanned,
+    visit::Visit
+};
+
+use crate::analyzer::{AnalysisResult, Analyzer, Fix, Issue, Suggestion, TextEdit};
+
+/// Standard derives in their canonical order.
+///
+/// Derives not listed here follow them in their written order, except the
+/// serde traits in [`TRAILING_DERIVES`].
+pub const LEADING_DERIVES: &[&str] = &[
+    "Debug",
+    "Clone",
+    "Copy",
+    "PartialEq",
+    "Eq",
+    "PartialOrd",
+    "Ord",
+    "Hash",
+    "Default"
+];
+
+/// Derives placed at the end of the list.
+pub const TRAILING_DERIVES: &[&str] = &["Serialize", "Deserialize"];
+
+/// Analyzer for derive lists out of the canonical order.
+///
+/// # Examples
+///
+/// Detects this pattern:
+/// ```ignore
+/// #[derive(Serialize, Clone, Debug)]
+/// pub struct Config;
+/// ```
+///
+/// Fixes to:
+/// ```ignore
+/// #[derive(Debug, Clone, Serialize)]
+/// pub struct Config;
+/// ```
+pub struct DeriveOrderAnalyzer;
+
+/// Derive list whose traits are out of order.
+struct Unordered {
+    line:    usize,
+    column:  usize,
+    /// Traits as written
+    current: Vec<String>,
+    /// Traits in canonical order
+    sorted:  Vec<String>,
+    /// Edit reordering the list in place
+    edit:    TextEdit
+}
+
+impl DeriveOrderAnalyzer {
+    /// Create new opt-in derive order analyzer.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Collect the derive lists out of order.
+    fn collect(ast: &File, content: &str) -> Vec<Unordered> {
+        let mut visitor = DeriveVisitor {
+            content,
+            unordered: Vec::new()
+        };
+        visitor.visit_file(ast);
+        visitor.unordered
+    }
+}
+
+impl Analyzer for DeriveOrderAnalyzer {
+    fn name(&self) -> &'static str {
+        "derive_order"
+    }
+
+    fn analyze(&self, ast: &File, content: &str) -> AppResult<AnalysisResult> {
+        let issues: Vec<Issue> = Self::collect(ast, content)
+            .into_iter()
+            .map(|unordered| {
+                let sorted = unordered.sorted.join(", ");
+                Issue {
+                    line:    unordered.line,
+                    column:  unordered.column,
+                    message: format!(
+                        "Derives `{}` are out of order\n\
+                         List them as `{sorted}`: {}, then other derives, then serde traits",
+                        unordered.current.join(", "),
+                        LEADING_DERIVES.join(", ")
+                    ),
+                    fix:     Fix::Simple(format!("#[derive({sorted})]"))
+                }
+            })
+            .collect();
+
+        Ok(AnalysisResult {
+            fixable_count: issues.iter().filter(|i| i.fix.is_available()).count(),
+            issues
+        })
+    }
+
+    fn suggestions(&self, ast: &File, content: &str) -> AppResult<Vec<Suggestion>> {
+        Ok(Self::collect(ast, content)
+            .into_iter()
+            .map(|unordered| Suggestion {
+                edit:   unordered.edit,
+                import: None
+            })
+            .collect())
+    }
+
+    fn fixable(&self) -> bool {
+        true
+    }
+
+    fn opt_in(&self) -> bool {
+        true
+    }
+}
+
+impl Default for DeriveOrderAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Visitor checking every derive attribute of a file.
+struct DeriveVisitor<'a> {
+    content:   &'a str,
+    unordered: Vec<Unordered>
+}
+
+impl DeriveVisitor<'_> {
+    fn check(&mut self, attr: &Attribute) {
+        let Meta::List(list) = &attr.meta else {
+            return;
+        };
+        if !attr.path().is_ident("derive") || !matches!(list.delimiter, MacroDelimiter::Paren(_)) {
+            return;
+        }
+        let Ok(paths) = attr.parse_args_with(Punctuated::<Path, syn::Token![,]>::parse_terminated)
+        else {
+            return;
+        };
+
+        let paths: Vec<&Path> = paths.iter().collect();
+        let mut order: Vec<usize> = (0..paths.len()).collect();
+        order.sort_by_key(|&index| rank(paths[index]));
+        if order.iter().enumerate().all(|(slot, &index)| slot == index) {
+            return;
+        }
+
+        let Some(ranges) = paths
+            .iter()
+            .map(|path| path_range(path, self.content))
+            .collect::<Option<Vec<Range<usize>>>>()
+        else {
+            return;
+        };
+        let texts: Vec<String> = ranges
+            .iter()
+            .map(|range| self.content[range.clone()].to_string())
+            .collect();
+
+        let start = list.path.span().start();
+        self.unordered.push(Unordered {
+            line:    start.line,
+            column:  start.column,
+            current: texts.clone(),
+            sorted:  order.iter().map(|&index| texts[index].clone()).collect(),
+            edit:    reorder_edit(&ranges, &order, self.content)
+        });
+    }
+}
+
+impl<'ast> Visit<'ast> for DeriveVisitor<'_> {
+    fn visit_attribute(&mut self, node: &'ast Attribute) {
+        self.check(node);
+    }
+}
+
+/// Position of a derive in the canonical order.
+///
+/// Paths are compared by their last segment, so `serde::Serialize` sorts
+/// like `Serialize`. Equal ranks keep their written order.
+fn rank(path: &Path) -> usize {
+    let Some(name) = path.segments.last().map(|s| s.ident.to_string()) else {
+        return LEADING_DERIVES.len();
+    };
+    if let Some(position) = LEADING_DERIVES.iter().position(|d| *d == name) {
+        return position;
+    }
+    match TRAILING_DERIVES.iter().position(|d| *d == name) {
+        Some(position) => LEADING_DERIVES.len() + 1 + position,
+        None => LEADING_DERIVES.len()
+    }
+}
+
+/// Byte range of a derive path in the source text.
+fn path_range(path: &Path, content: &str) -> Option<Range<usize>> {
+    let first = match &path.leading_colon {
+        Some(colon) => colon.spans[0],
+        None => path.segments.first()?.ident.span()
+    };
+    let last = path.segments.last()?.ident.span();
+    let range = first.byte_range().start..last.byte_range().end;
+    (range.start < range.end && content.get(range.clone()).is_some()).then_some(range)
+}
+
+/// Edit rewriting the list from the first to the last derive.
+///
+/// Each slot receives the derive sorted into it, while the text between
+/// slots (separators, comments, line breaks) stays where it is.
+fn reorder_edit(ranges: &[Range<usize>], order: &[usize], content: &str) -> TextEdit {
+    let mut replacement = String::new();
+    for (slot, &index) in order.iter().enumerate() {
+        if slot > 0 {
+            replacement.push_str(&content[ranges[slot - 1].end..ranges[slot].start]);
+        }
+        replacement.push_str(&content[ranges[index].clone()]);
+    }
+    TextEdit {
+        range: ranges[0].start..ranges[ranges.len() - 1].end,
+        replacement
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analyze(code: &str) -> AnalysisResult {
+        let ast = syn::parse_file(code).unwrap();
+        DeriveOrderAnalyzer::new().analyze(&ast, code).unwrap()
+    }
+
+    fn fix(code: &str) -> String {
+        let ast = syn::parse_file(code).unwrap();
+        let suggestions = DeriveOrderAnalyzer::new().suggestions(&ast, code).unwrap();
+        crate::fixer::apply_suggestions(code, &suggestions).source
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        let analyzer = DeriveOrderAnalyzer::new();
+        assert_eq!(analyzer.name(), "derive_order");
+        assert!(analyzer.opt_in());
+        assert!(analyzer.fixable());
+    }
+
+    #[test]
+    fn test_detect_unordered_derives() {
+        let result = analyze(
+            r#"
+#[derive(Serialize, Clone, Debug)]
+pub struct Config;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Ordered;
+
+#[derive(Debug, Error, Clone)]
+pub enum Failure {}
+"#
+        );
+
+        assert_eq!(result.issues.len(), 2);
+        assert_eq!(result.fixable_count, 2);
+        assert_eq!(result.issues[0].line, 2);
+        assert!(
+            result.issues[0]
+                .message
+                .contains("Derives `Serialize, Clone, Debug` are out of order")
+        );
+        assert!(
+            result.issues[0]
+                .message
+                .contains("List them as `Debug, Clone, Serialize`")
+        );
+        assert_eq!(
+            result.issues[1].fix,
+            Fix::Simple("#[derive(Debug, Clone, Error)]".to_string())
+        );
+    }
+
+    #[test]
+    fn test_other_derives_keep_written_order() {
+        let result = analyze(
+            r#"
+#[derive(Debug, Clone, Error, Display, serde::Serialize, Deserialize)]
+pub struct Ordered;
+
+#[derive(Debug)]
+#[serde(rename_all = "snake_case")]
+pub struct Other;
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_fix_reorders_in_place() {
+        let code = r#"mod model {
+    #[derive(serde::Deserialize, Clone, Debug)]
+    pub struct Config;
+
+    #[derive(
+        Hash, // keys
+        Eq,
+        PartialEq,
+    )]
+    pub struct Key;
+}
+"#;
+
+        assert_eq!(
+            fix(code),
+            r#"mod model {
+    #[derive(Debug, Clone, serde::Deserialize)]
+    pub struct Config;
+
+    #[derive(
+        PartialEq, // keys
+        Eq,
+        Hash,
+    )]
+    pub struct Key;
+}
+"#
+        );
+    }
+
+    #[test]
+    fn test_rank() {
+        let rank_of = |code: &str| rank(&syn::parse_str::<Path>(code).unwrap());
+        assert_eq!(rank_of("Debug"), 0);
+        assert_eq!(rank_of("std::fmt::Debug"), 0);
+        assert!(rank_of("Default") < rank_of("Error"));
+        assert!(rank_of("Error") < rank_of("serde::Serialize"));
+        assert!(rank_of("Serialize") < rank_of("Deserialize"));
+    }
+
+    #[test]
+    fn test_default_implementation() {
+        let analyzer = DeriveOrderAnalyzer;
+        assert_eq!(analyzer.name(), "derive_order");
+    }
+}
@@ -0,0 +1,325 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Discarded result analyzer for errors dropped with `let _ =`.
+//!
+//! `let _ = fs::remove_file(path);` compiles without a warning, and the
+//! error is gone: a failed cleanup, write or send leaves no trace. This
+//! analyzer flags `let _ =` bindings of calls known to return a `Result`:
+//! functions and methods of the same file declared to return `Result` (or
+//! an alias such as `AppResult`), `std::fs` functions, `write!`/`writeln!`
+//! and common fallible methods such as `flush`, `send` or `join()`. Without
+//! type information other calls are not checked, and a method of another
+//! type sharing one of those names is reported too, so the confidence is
+//! medium.
+
+use std::collections::HashSet;
+
+use masterror::AppResult;
+use syn::{
+    Expr, File, ImplItemFn, ItemFn, ItemMod, Local, Pat, Signature, TraitItemFn, Type,
+    visit::Visit
+};
+
+use super::{describe_call, is_test_code, returns_result};
+use crate::analyzer::{AnalysisResult, Analyzer, Confidence, Fix, Issue};
+
+/// Methods of the standard library that return `Result`.
+const FALLIBLE_METHODS: &[&str] = &[
+    "write",
+    "write_all",
+    "write_fmt",
+    "flush",
+    "send",
+    "try_send",
+    "recv",
+    "try_recv",
+    "join",
+    "sync_all",
+    "sync_data",
+    "set_len",
+    "shutdown",
+    "read_to_string",
+    "read_to_end",
+    "read_exact",
+    "parse",
+    "kill",
+    "wait",
+    "seek",
+    "try_into",
+    "set_nonblocking"
+];
+
+/// Methods of [`FALLIBLE_METHODS`] returning `Result` only when called
+/// without arguments: `handle.join()` fails, `parts.join(",")` and
+/// `path.join("src")` do not.
+const NULLARY_METHODS: &[&str] = &["join"];
+
+/// Analyzer for `Result`s discarded with `let _ =`.
+///
+/// # Examples
+///
+/// Detects this pattern:
+/// ```ignore
+/// let _ = fs::remove_file(&lock_path);
+/// ```
+///
+/// Suggests handling the error:
+/// ```ignore
+/// fs::remove_file(&lock_path)?;
+/// // or, when the failure really does not matter:
+/// // The lock file may already be gone after a crash.
+/// fs::remove_file(&lock_path).ok();
+/// ```
+pub struct DiscardedResultAnalyzer;
+
+impl DiscardedResultAnalyzer {
+    /// Create new discarded result analyzer instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Analyzer for DiscardedResultAnalyzer {
+    fn name(&self) -> &'static str {
+        "discarded_result"
+    }
+
+    fn analyze(&self, ast: &File, _content: &str) -> AppResult<AnalysisResult> {
+        let mut fallible = FallibleVisitor {
+            names: HashSet::new()
+        };
+        fallible.visit_file(ast);
+
+        let mut visitor = DiscardVisitor {
+            fallible: fallible.names,
+            issues:   Vec::new()
+        };
+        visitor.visit_file(ast);
+
+        Ok(AnalysisResult {
+            issues:        visitor.issues,
+            fixable_count: 0
+        })
+    }
+
+    fn confidence(&self) -> Confidence {
+        Confidence::Medium
+    }
+}
+
+impl Default for DiscardedResultAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Collects names of functions of the file that return `Result`.
+struct FallibleVisitor {
+    names: HashSet<String>
+}
+
+impl FallibleVisitor {
+    fn add(&mut self, sig: &Signature) {
+        if returns_result(&sig.output) {
+            self.names.insert(sig.ident.to_string());
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for FallibleVisitor {
+    fn visit_signature(&mut self, node: &'ast Signature) {
+        self.add(node);
+    }
+}
+
+struct DiscardVisitor {
+    /// Functions of the file that return `Result`
+    fallible: HashSet<String>,
+    issues:   Vec<Issue>
+}
+
+impl DiscardVisitor {
+    /// Describe a call returning `Result`, e.g. `fs::remove_file(..)`.
+    fn fallible_call(&self, expr: &Expr) -> Option<String> {
+        match expr {
+            Expr::Call(call) => {
+                let Expr::Path(path) = call.func.as_ref() else {
+                    return None;
+                };
+                let segments = &path.path.segments;
+                let name = segments.last()?.ident.to_string();
+                (self.fallible.contains(&name) || segments.iter().any(|s| s.ident == "fs"))
+                    .then(|| describe_call(expr))
+                    .flatten()
+            }
+            Expr::MethodCall(call) => {
+                let name = call.method.to_string();
+                let known = FALLIBLE_METHODS.contains(&name.as_str())
+                    && (call.args.is_empty() || !NULLARY_METHODS.contains(&name.as_str()));
+                (self.fallible.contains(&name) || known)
+                    .then(|| describe_call(expr))
+                    .flatten()
+            }
+            Expr::Macro(mac) => {
+                let name = mac.mac.path.segments.last()?.ident.to_string();
+                matches!(name.as_str(), "write" | "writeln").then(|| format!("{name}!(..)"))
+            }
+            Expr::Await(await_expr) => self.fallible_call(&await_expr.base),
+            Expr::Paren(paren) => self.fallible_call(&paren.expr),
+            _ => None
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for DiscardVisitor {
+    fn visit_item_fn(&mut self, node: &'ast ItemFn) {
+        if !is_test_code(&node.attrs) {
+            syn::visit::visit_item_fn(self, node);
+        }
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'ast ImplItemFn) {
+        if !is_test_code(&node.attrs) {
+            syn::visit::visit_impl_item_fn(self, node);
+        }
+    }
+
+    fn visit_trait_item_fn(&mut self, node: &'ast TraitItemFn) {
+        if !is_test_code(&node.attrs) {
+            syn::visit::visit_trait_item_fn(self, node);
+        }
+    }
+
+    fn visit_item_mod(&mut self, node: &'ast ItemMod) {
+        if !is_test_code(&node.attrs) {
+            syn::visit::visit_item_mod(self, node);
+        }
+    }
+
+    fn visit_local(&mut self, node: &'ast Local) {
+        let (wildcard, typed_result) = match &node.pat {
+            Pat::Wild(_) => (true, false),
+            Pat::Type(typed) => (
+                matches!(typed.pat.as_ref(), Pat::Wild(_)),
+                is_result_type(&typed.ty)
+            ),
+            _ => (false, false)
+        };
+
+        if wildcard && let Some(init) = &node.init {
+            let call = self.fallible_call(&init.expr).or_else(|| {
+                typed_result.then(|| describe_call(&init.expr).unwrap_or("the value".into()))
+            });
+            if let Some(call) = call {
+                let start = node.let_token.span.start();
+                self.issues.push(Issue {
+                    line:    start.line,
+                    column:  start.column,
+                    message: format!(
+                        "`let _ =` discards the `Result` of `{call}`\n\
+                         Propagate the error with `?`, log it, or call `.ok()` with a comment \
+                         explaining why the failure does not matter"
+                    ),
+                    fix:     Fix::None
+                });
+            }
+        }
+        syn::visit::visit_local(self, node);
+    }
+}
+
+/// Check whether a type annotation names `Result` or an alias of it.
+fn is_result_type(ty: &Type) -> bool {
+    let Type::Path(path) = ty else {
+        return false;
+    };
+    path.path
+        .segments
+        .last()
+        .is_some_and(|segment| segment.ident.to_string().ends_with("Result"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analyze(code: &str) -> AnalysisResult {
+        let ast = syn::parse_file(code).unwrap();
+        DiscardedResultAnalyzer::new().analyze(&ast, code).unwrap()
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        let analyzer = DiscardedResultAnalyzer::new();
+        assert_eq!(analyzer.name(), "discarded_result");
+        assert_eq!(analyzer.confidence(), Confidence::Medium);
+    }
+
+    #[test]
+    fn test_detect_discarded_results() {
+        let result = analyze(
+            r#"
+fn save(path: &Path) -> AppResult<()> { Ok(()) }
+
+async fn cleanup(path: &Path, out: &mut String, tx: Sender<u8>) {
+    let _ = fs::remove_file(path);
+    let _ = save(path);
+    let _ = tx.send(1).await;
+    let _ = writeln!(out, "done");
+    let _: io::Result<()> = other();
+    let _ = worker.join();
+}
+"#
+        );
+
+        assert_eq!(result.issues.len(), 6);
+        assert_eq!(result.fixable_count, 0);
+        assert_eq!(result.issues[0].line, 5);
+        assert_eq!(result.issues[0].column, 4);
+        assert!(
+            result.issues[0]
+                .message
+                .contains("`let _ =` discards the `Result` of `fs::remove_file(..)`")
+        );
+        assert!(result.issues[1].message.contains("`save(..)`"));
+        assert!(result.issues[2].message.contains("`send(..)`"));
+        assert!(result.issues[3].message.contains("`writeln!(..)`"));
+        assert!(result.issues[4].message.contains("`other(..)`"));
+        assert!(result.issues[5].message.contains("`join(..)`"));
+    }
+
+    #[test]
+    fn test_ignore_handled_and_unknown_values() {
+        let result = analyze(
+            r#"
+fn run(path: &Path, values: &[u8]) -> AppResult<()> {
+    let _ = values.len();
+    let _ = parts.join(",");
+    let _ = path.join("src");
+    let _ = fs::remove_file(path).ok();
+    let _ = fs::read(path)?;
+    let _guard = fs::remove_file(path);
+    let _ = render();
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    fn fixture() {
+        let _ = fs::remove_file("x");
+    }
+}
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_default_implementation() {
+        let analyzer = DiscardedResultAnalyzer;
+        assert_eq!(analyzer.name(), "discarded_result");
+    }
+}
@@ -0,0 +1,450 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Doc width analyzer for doc comments wider than the comment width.
+//!
+//! The formatter sets `wrap_comments = true`, which wraps comments at
+//! rustfmt's `comment_width` of 80 columns while code may reach `max_width`
+//! of 99. That option is nightly-only, so on stable doc comments grow past
+//! the width unnoticed. This analyzer reports `///` and `//!` paragraphs with
+//! a line wider than 80 columns and rewraps the paragraph. Code blocks,
+//! tables, headings and indented code are left alone, as are lines that only
+//! hold a single word too long to break.
+
+use std::ops::Range;
+
+use masterror::AppResult;
+use syn::File;
+
+use super::{line_width, literal_ranges};
+use crate::analyzer::{AnalysisResult, Analyzer, Fix, Issue, Suggestion, TextEdit};
+
+/// Maximum visual width of a doc comment line, matching rustfmt's
+/// `comment_width` used by `wrap_comments`.
+const COMMENT_WIDTH: usize = 80;
+
+/// Analyzer for doc comment lines exceeding the comment width.
+///
+/// # Examples
+///
+/// Detects this pattern:
+/// ```ignore
+/// /// Loads the configuration from the given path, falling back to the defaults when the file is missing.
+/// fn load(path: &Path) -> Config;
+/// ```
+///
+/// Fixes to:
+/// ```ignore
+/// /// Loads the configuration from the given path, falling back to the
+/// /// defaults when the file is missing.
+/// fn load(path: &Path) -> Config;
+/// ```
+pub struct DocWidthAnalyzer;
+
+/// Doc paragraph with a line over the comment width.
+struct Finding {
+    /// Position of the first line over the width
+    line:      usize,
+    width:     usize,
+    /// Byte range of the paragraph's lines, without the final line break
+    range:     Range<usize>,
+    /// The paragraph wrapped at the comment width
+    rewrapped: String
+}
+
+/// Consecutive doc lines forming one paragraph.
+struct Paragraph {
+    /// Prefix of the first line, e.g. `    /// - `
+    first_prefix: String,
+    /// Prefix of the following lines, e.g. `    ///   `
+    rest_prefix:  String,
+    /// Whether the paragraph is a list item
+    list_item:    bool,
+    start_line:   usize,
+    range:        Range<usize>,
+    words:        Vec<String>,
+    /// Visual width of each line that holds more than one word
+    widths:       Vec<Option<usize>>
+}
+
+impl Paragraph {
+    /// Wrap the words greedily at the comment width.
+    fn rewrap(&self) -> String {
+        let mut lines: Vec<String> = Vec::new();
+        let mut current = self.first_prefix.clone();
+        let mut empty = true;
+
+        for word in &self.words {
+            if !empty && line_width(&current) + 1 + line_width(word) > COMMENT_WIDTH {
+                lines.push(current);
+                current = self.rest_prefix.clone();
+                empty = true;
+            }
+            if !empty {
+                current.push(' ');
+            }
+            current.push_str(word);
+            empty = false;
+        }
+        lines.push(current);
+        lines.join("\n")
+    }
+
+    /// Report the paragraph if it is too wide and rewrapping helps.
+    fn finding(&self, content: &str) -> Option<Finding> {
+        let (index, width) = self.widths.iter().enumerate().find_map(|(index, width)| {
+            width
+                .filter(|width| *width > COMMENT_WIDTH)
+                .map(|width| (index, width))
+        })?;
+
+        let rewrapped = self.rewrap();
+        if rewrapped == content[self.range.clone()] {
+            return None;
+        }
+
+        Some(Finding {
+            line: self.start_line + index,
+            width,
+            range: self.range.clone(),
+            rewrapped
+        })
+    }
+}
+
+impl DocWidthAnalyzer {
+    /// Create new doc width analyzer instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Find doc paragraphs wider than the comment width.
+    fn collect(content: &str) -> Vec<Finding> {
+        let literals = literal_ranges(content);
+        let mut findings = Vec::new();
+        let mut paragraph: Option<Paragraph> = None;
+        let mut in_fence = false;
+        let mut offset = 0;
+
+        let mut flush = |paragraph: &mut Option<Paragraph>| {
+            if let Some(finding) = paragraph.take().and_then(|p| p.finding(content)) {
+                findings.push(finding);
+            }
+        };
+
+        for (index, raw) in content.split_inclusive('\n').enumerate() {
+            let line = raw.trim_end_matches('\n').trim_end_matches('\r');
+            let start = offset;
+            offset += raw.len();
+
+            let in_literal = literals
+                .iter()
+                .any(|literal| literal.start < start && start < literal.end);
+            let Some((prefix, text)) = doc_text(line).filter(|_| !in_literal) else {
+                flush(&mut paragraph);
+                in_fence = false;
+                continue;
+            };
+
+            if paragraph
+                .as_ref()
+                .is_some_and(|p| !p.rest_prefix.starts_with(prefix))
+            {
+                flush(&mut paragraph);
+            }
+
+            let trimmed = text.trim_start();
+            if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+                flush(&mut paragraph);
+                in_fence = !in_fence;
+                continue;
+            }
+            if in_fence || trimmed.is_empty() || !is_prose(text) {
+                flush(&mut paragraph);
+                continue;
+            }
+
+            let indent = text.len() - trimmed.len();
+            let marker = list_marker(text);
+            let continues = marker.is_none()
+                && paragraph.as_ref().is_some_and(|p| {
+                    let expected = p.rest_prefix.len() - prefix.len();
+                    if p.list_item {
+                        indent == expected
+                    } else {
+                        indent == 0
+                    }
+                });
+
+            if !continues {
+                flush(&mut paragraph);
+                if indent > 0 && marker.is_none() {
+                    continue;
+                }
+                let marker_len = marker.map_or(0, |marker| marker.len() + 1);
+                paragraph = Some(Paragraph {
+                    first_prefix: format!("{prefix}{}", &text[..indent + marker_len]),
+                    rest_prefix:  format!("{prefix}{}", " ".repeat(indent + marker_len)),
+                    list_item:    marker.is_some(),
+                    start_line:   index + 1,
+                    range:        start..start,
+                    words:        Vec::new(),
+                    widths:       Vec::new()
+                });
+            }
+
+            if let Some(current) = paragraph.as_mut() {
+                let words = if current.words.is_empty() {
+                    &text[current.first_prefix.len() - prefix.len()..]
+                } else {
+                    trimmed
+                };
+                let count = current.words.len();
+                current
+                    .words
+                    .extend(words.split_whitespace().map(str::to_string));
+                let breakable = current.words.len() - count > 1;
+                current.widths.push(breakable.then(|| line_width(line)));
+                current.range.end = start + line.len();
+            }
+        }
+        flush(&mut paragraph);
+
+        findings
+    }
+}
+
+impl Analyzer for DocWidthAnalyzer {
+    fn name(&self) -> &'static str {
+        "doc_width"
+    }
+
+    fn analyze(&self, _ast: &File, content: &str) -> AppResult<AnalysisResult> {
+        let issues: Vec<Issue> = Self::collect(content)
+            .into_iter()
+            .map(|finding| Issue {
+                line:    finding.line,
+                column:  COMMENT_WIDTH,
+                message: format!(
+                    "Doc comment line is {} characters wide, over the comment width of \
+                     {COMMENT_WIDTH}\nRewrap the paragraph",
+                    finding.width
+                ),
+                fix:     Fix::Simple(finding.rewrapped)
+            })
+            .collect();
+
+        Ok(AnalysisResult {
+            fixable_count: issues.len(),
+            issues
+        })
+    }
+
+    fn suggestions(&self, _ast: &File, content: &str) -> AppResult<Vec<Suggestion>> {
+        Ok(Self::collect(content)
+            .into_iter()
+            .map(|finding| Suggestion {
+                edit:   TextEdit {
+                    range:       finding.range,
+                    replacement: finding.rewrapped
+                },
+                import: None
+            })
+            .collect())
+    }
+
+    fn fixable(&self) -> bool {
+        true
+    }
+}
+
+impl Default for DocWidthAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Split a doc comment line into its prefix and text.
+///
+/// # Arguments
+///
+/// * `line` - Source line
+///
+/// # Returns
+///
+/// Prefix up to and including the space after `///` or `//!`, and the text
+/// after it; `None` for other lines
+fn doc_text(line: &str) -> Option<(&str, &str)> {
+    let trimmed = line.trim_start();
+    let is_doc =
+        (trimmed.starts_with("///") && !trimmed.starts_with("////")) || trimmed.starts_with("//!");
+    if !is_doc {
+        return None;
+    }
+
+    let prefix_len = line.len() - trimmed.len() + 3;
+    match &line[prefix_len..] {
+        "" => Some((&line[..prefix_len], "")),
+        text if text.starts_with(' ') => Some((&line[..=prefix_len], &text[1..])),
+        _ => None
+    }
+}
+
+/// Checks whether a doc line is wrappable text.
+///
+/// Tables, headings, HTML, link reference definitions and lines ending in a
+/// hard line break keep their lines.
+fn is_prose(text: &str) -> bool {
+    let text = text.trim_start();
+    !(text.ends_with("  ")
+        || text.ends_with('\\')
+        || text.starts_with('|')
+        || text.starts_with('#')
+        || text.starts_with('<')
+        || (text.starts_with('[') && text.contains("]:")))
+}
+
+/// Returns the list marker a line starts with, e.g. `-` or `1.`.
+fn list_marker(text: &str) -> Option<&str> {
+    let trimmed = text.trim_start();
+    let marker = trimmed.split(' ').next()?;
+    let is_marker = matches!(marker, "-" | "*" | "+")
+        || (marker.len() > 1
+            && (marker.ends_with('.') || marker.ends_with(')'))
+            && marker[..marker.len() - 1]
+                .bytes()
+                .all(|b| b.is_ascii_digit()));
+
+    (is_marker && trimmed.len() > marker.len()).then_some(marker)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixer::apply_suggestions;
+
+    fn analyze(code: &str) -> AnalysisResult {
+        let ast = syn::parse_file(code).unwrap();
+        DocWidthAnalyzer::new().analyze(&ast, code).unwrap()
+    }
+
+    fn fix(code: &str) -> String {
+        let ast = syn::parse_file(code).unwrap();
+        let suggestions = DocWidthAnalyzer::new().suggestions(&ast, code).unwrap();
+        apply_suggestions(code, &suggestions).source
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        let analyzer = DocWidthAnalyzer::new();
+        assert_eq!(analyzer.name(), "doc_width");
+    }
+
+    #[test]
+    fn test_detect_and_rewrap_paragraph() {
+        let code = "/// Loads the configuration from the given path, falling back to the defaults \
+                    when the file is missing.\n\
+                    /// Errors are logged.\n\
+                    fn load() {}\n";
+
+        let result = analyze(code);
+        assert_eq!(result.issues.len(), 1);
+        assert_eq!(result.issues[0].line, 1);
+        assert_eq!(result.issues[0].column, 80);
+        assert!(result.issues[0].message.contains("103 characters wide"));
+        assert_eq!(result.fixable_count, 1);
+
+        assert_eq!(
+            fix(code),
+            "/// Loads the configuration from the given path, falling back to the defaults\n\
+             /// when the file is missing. Errors are logged.\n\
+             fn load() {}\n"
+        );
+    }
+
+    #[test]
+    fn test_rewrap_indented_module_docs_and_lists() {
+        let code = "mod net {\n    //! Networking for the crate, including the client, the server and the shared codec types.\n    //!\n    //! - Retries with exponential backoff when the connection to the remote peer drops unexpectedly.\n    //! - Short item.\n}\n";
+
+        assert_eq!(
+            fix(code),
+            "mod net {\n    //! Networking for the crate, including the client, the server and the\n    //! shared codec types.\n    //!\n    //! - Retries with exponential backoff when the connection to the remote\n    //!   peer drops unexpectedly.\n    //! - Short item.\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_list_after_prose_keeps_items() {
+        let code = "/// Loads the configuration from the given path, falling back to the defaults when needed:\n\
+                    /// - the user file\n\
+                    /// - the system file\n\
+                    ///   - nested\n\
+                    fn load() {}\n";
+
+        assert_eq!(
+            fix(code),
+            "/// Loads the configuration from the given path, falling back to the defaults\n\
+             /// when needed:\n\
+             /// - the user file\n\
+             /// - the system file\n\
+             ///   - nested\n\
+             fn load() {}\n"
+        );
+    }
+
+    #[test]
+    fn test_ignore_code_tables_and_long_words() {
+        let long = "x".repeat(90);
+        let code = format!(
+            "// Plain comments are not docs and can be as long as the line length allows.\n\
+             /// ```\n/// let value = compute_something_long(first_argument, second_argument, third);\n/// ```\n\
+             /// | Column | Description of the column that is rather long and keeps going on |\n\
+             /// https://example.com/{long}\n\
+             /// # Heading that is long enough to pass the comment width of eighty columns\n\
+             fn run() {{}}\n"
+        );
+
+        assert!(analyze(&code).issues.is_empty());
+    }
+
+    #[test]
+    fn test_ignore_long_word_inside_paragraph() {
+        let code = format!(
+            "/// See the\n/// [`Report::display`](crate::report::{}::display)\n/// or the CLI.\nfn \
+             run() {{}}\n",
+            "Nested".repeat(10)
+        );
+
+        assert!(analyze(&code).issues.is_empty());
+    }
+
+    #[test]
+    fn test_ignore_docs_in_string_literals() {
+        let code = "const DOC: &str = \"\n/// Inside a literal this is not a comment, so its width does not matter here at all.\n\";\n";
+
+        assert!(analyze(code).issues.is_empty());
+    }
+
+    #[test]
+    fn test_short_docs_untouched() {
+        let code = "/// Short line.\n/// Another short line.\nfn run() {}\n";
+
+        assert!(analyze(code).issues.is_empty());
+        assert_eq!(fix(code), code);
+    }
+
+    #[test]
+    fn test_list_marker() {
+        assert_eq!(list_marker("- item"), Some("-"));
+        assert_eq!(list_marker("12. item"), Some("12."));
+        assert_eq!(list_marker("1) item"), Some("1)"));
+        assert_eq!(list_marker("-"), None);
+        assert_eq!(list_marker("e.g. item"), None);
+    }
+
+    #[test]
+    fn test_default_implementation() {
+        let analyzer = DocWidthAnalyzer;
+        assert_eq!(analyzer.name(), "doc_width");
+    }
+}
@@ -0,0 +1,409 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Duplicate literals analyzer for strings repeated across a crate.
+//!
+//! The same message, header name or key spelled out in several places drifts
+//! apart as soon as one copy is edited. This analyzer builds an index of the
+//! string literals of every file in the run and reports each literal that
+//! appears [`MIN_OCCURRENCES`] times or more, at every occurrence, with the
+//! locations of the other copies. Short literals, literals without letters,
+//! attribute arguments, constant and static initializers, test code and
+//! macro arguments are left out.
+
+use std::{
+    collections::BTreeMap,
+    path::{Component, Path, PathBuf}
+};
+
+use masterror::AppResult;
+use syn::{
+    Attribute, Expr, ExprLit, File, ImplItemConst, ImplItemFn, ItemConst, ItemFn, ItemMod,
+    ItemStatic, Lit, LitStr, visit::Visit
+};
+
+use super::is_test_code;
+use crate::analyzer::{AnalysisResult, Analyzer, Confidence, Fix, Issue, SourceFile};
+
+/// Occurrences from which a literal is reported.
+pub const MIN_OCCURRENCES: usize = 3;
+
+/// Shortest literal considered, in characters after trimming.
+const MIN_LENGTH: usize = 6;
+
+/// Other locations listed in a message before the rest is counted.
+const MAX_LOCATIONS: usize = 5;
+
+/// Longest literal quoted in a message, in characters.
+const MAX_QUOTED: usize = 40;
+
+/// Words of a literal used for the suggested constant name.
+const MAX_NAME_WORDS: usize = 4;
+
+/// Directories whose files only hold tests.
+const TEST_DIRS: &[&str] = &["tests", "benches"];
+
+/// Analyzer for string literals repeated across the files of a crate.
+///
+/// # Examples
+///
+/// Detects this pattern:
+/// ```ignore
+/// // src/client.rs
+/// request.header("Content-Type", "application/json");
+/// // src/server.rs
+/// response.header("Content-Type", "application/json");
+/// // src/webhook.rs
+/// if content_type == "application/json" {}
+/// ```
+///
+/// Suggests:
+/// ```ignore
+/// pub const APPLICATION_JSON: &str = "application/json";
+///
+/// request.header("Content-Type", APPLICATION_JSON);
+/// ```
+pub struct DuplicateLiteralsAnalyzer;
+
+impl DuplicateLiteralsAnalyzer {
+    /// Create new duplicate literals analyzer instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Analyzer for DuplicateLiteralsAnalyzer {
+    fn name(&self) -> &'static str {
+        "duplicate_literals"
+    }
+
+    fn analyze(&self, _ast: &File, _content: &str) -> AppResult<AnalysisResult> {
+        Ok(AnalysisResult::default())
+    }
+
+    fn crate_wide(&self) -> bool {
+        true
+    }
+
+    fn analyze_crate(&self, files: &[&SourceFile]) -> AppResult<Vec<(PathBuf, AnalysisResult)>> {
+        let mut index: BTreeMap<String, Vec<Location>> = BTreeMap::new();
+        for (file, source) in files.iter().enumerate() {
+            let mut visitor = LiteralVisitor {
+                literals: Vec::new()
+            };
+            visitor.visit_file(&source.ast);
+            for (value, line, column) in visitor.literals {
+                index.entry(value).or_default().push(Location {
+                    file,
+                    line,
+                    column
+                });
+            }
+        }
+
+        let mut issues: BTreeMap<usize, Vec<Issue>> = BTreeMap::new();
+        for (value, locations) in index
+            .iter()
+            .filter(|(_, locations)| locations.len() >= MIN_OCCURRENCES)
+        {
+            for (position, location) in locations.iter().enumerate() {
+                let others: Vec<&Location> = locations
+                    .iter()
+                    .enumerate()
+                    .filter(|(other, _)| *other != position)
+                    .map(|(_, other)| other)
+                    .collect();
+                issues.entry(location.file).or_default().push(Issue {
+                    line:    location.line,
+                    column:  location.column,
+                    message: message(value, locations.len(), &others, files),
+                    fix:     Fix::None
+                });
+            }
+        }
+
+        Ok(issues
+            .into_iter()
+            .map(|(file, mut issues)| {
+                issues.sort_by_key(|issue| (issue.line, issue.column));
+                (
+                    files[file].path.clone(),
+                    AnalysisResult {
+                        issues,
+                        fixable_count: 0
+                    }
+                )
+            })
+            .collect())
+    }
+
+    fn applies_to(&self, path: &Path) -> bool {
+        !path.components().any(|component| {
+            matches!(component, Component::Normal(dir) if TEST_DIRS.iter().any(|name| dir == *name))
+        })
+    }
+
+    fn confidence(&self) -> Confidence {
+        Confidence::Low
+    }
+}
+
+impl Default for DuplicateLiteralsAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Position of a literal in the files of a run.
+struct Location {
+    /// Index of the file
+    file:   usize,
+    /// Line of the literal, 1-based
+    line:   usize,
+    /// Column of the literal, 1-based
+    column: usize
+}
+
+/// Build the message of one occurrence of a repeated literal.
+///
+/// # Arguments
+///
+/// * `value` - Value of the literal
+/// * `count` - Number of occurrences in the crate
+/// * `others` - Locations of the other occurrences
+/// * `files` - Files of the run the locations point into
+fn message(value: &str, count: usize, others: &[&Location], files: &[&SourceFile]) -> String {
+    let mut locations: Vec<String> = others
+        .iter()
+        .take(MAX_LOCATIONS)
+        .map(|location| format!("{}:{}", files[location.file].path.display(), location.line))
+        .collect();
+    if others.len() > MAX_LOCATIONS {
+        locations.push(format!("{} more", others.len() - MAX_LOCATIONS));
+    }
+
+    let literal = quoted(value);
+    let locations = locations.join(", ");
+    let constant = constant_name(value);
+    format!(
+        "String literal \"{literal}\" appears {count} times in the crate, also at {locations}\n\
+         Extract a named constant, e.g. `const {constant}: &str = \"{literal}\";`, and use it \
+         everywhere"
+    )
+}
+
+/// Escape a literal for a message, shortening long ones.
+fn quoted(value: &str) -> String {
+    let escaped: String = value.escape_debug().collect();
+    if escaped.chars().count() <= MAX_QUOTED {
+        return escaped;
+    }
+    let short: String = escaped.chars().take(MAX_QUOTED).collect();
+    format!("{short}...")
+}
+
+/// Suggest a constant name from the first words of a literal.
+///
+/// # Examples
+///
+/// `"application/json"` becomes `APPLICATION_JSON`, a literal without a
+/// leading letter falls back to `VALUE`.
+fn constant_name(value: &str) -> String {
+    let words: Vec<String> = value
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .take(MAX_NAME_WORDS)
+        .map(str::to_ascii_uppercase)
+        .collect();
+    match words.first() {
+        Some(first) if first.starts_with(|c: char| c.is_ascii_alphabetic()) => words.join("_"),
+        _ => "VALUE".to_string()
+    }
+}
+
+/// Check whether a literal is worth a named constant.
+fn is_significant(value: &str) -> bool {
+    value.trim().chars().count() >= MIN_LENGTH && value.chars().any(char::is_alphabetic)
+}
+
+/// Check whether an expression is a plain string literal.
+fn is_string_literal(expr: &Expr) -> bool {
+    matches!(
+        expr,
+        Expr::Lit(ExprLit {
+            lit: Lit::Str(_),
+            ..
+        })
+    )
+}
+
+struct LiteralVisitor {
+    literals: Vec<(String, usize, usize)>
+}
+
+impl<'ast> Visit<'ast> for LiteralVisitor {
+    fn visit_attribute(&mut self, _node: &'ast Attribute) {}
+
+    fn visit_item_fn(&mut self, node: &'ast ItemFn) {
+        if is_test_code(&node.attrs) {
+            return;
+        }
+        syn::visit::visit_item_fn(self, node);
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'ast ImplItemFn) {
+        if is_test_code(&node.attrs) {
+            return;
+        }
+        syn::visit::visit_impl_item_fn(self, node);
+    }
+
+    fn visit_item_mod(&mut self, node: &'ast ItemMod) {
+        if is_test_code(&node.attrs) {
+            return;
+        }
+        syn::visit::visit_item_mod(self, node);
+    }
+
+    fn visit_item_const(&mut self, node: &'ast ItemConst) {
+        if !is_string_literal(&node.expr) {
+            syn::visit::visit_item_const(self, node);
+        }
+    }
+
+    fn visit_impl_item_const(&mut self, node: &'ast ImplItemConst) {
+        if !is_string_literal(&node.expr) {
+            syn::visit::visit_impl_item_const(self, node);
+        }
+    }
+
+    fn visit_item_static(&mut self, node: &'ast ItemStatic) {
+        if !is_string_literal(&node.expr) {
+            syn::visit::visit_item_static(self, node);
+        }
+    }
+
+    fn visit_lit_str(&mut self, node: &'ast LitStr) {
+        let value = node.value();
+        if is_significant(&value) {
+            let start = node.span().start();
+            self.literals.push((value, start.line, start.column + 1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn source(path: &str, code: &str) -> SourceFile {
+        SourceFile {
+            path: PathBuf::from(path),
+            ast:  syn::parse_file(code).unwrap()
+        }
+    }
+
+    fn analyze(sources: &[SourceFile]) -> Vec<(PathBuf, AnalysisResult)> {
+        let files: Vec<&SourceFile> = sources.iter().collect();
+        DuplicateLiteralsAnalyzer::new()
+            .analyze_crate(&files)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_literal_repeated_across_files() {
+        let sources = [
+            source(
+                "src/client.rs",
+                "fn a() { send(\"application/json\"); }\nfn b() { send(\"application/json\"); }"
+            ),
+            source(
+                "src/server.rs",
+                "fn c() { if kind == \"application/json\" {} }"
+            )
+        ];
+        let results = analyze(&sources);
+
+        assert_eq!(results.len(), 2);
+        let (path, client) = &results[0];
+        assert_eq!(path, &PathBuf::from("src/client.rs"));
+        assert_eq!(client.issues.len(), 2);
+        assert_eq!(client.issues[1].line, 2);
+        assert_eq!(
+            client.issues[0].message,
+            "String literal \"application/json\" appears 3 times in the crate, also at \
+             src/client.rs:2, src/server.rs:1\nExtract a named constant, e.g. `const \
+             APPLICATION_JSON: &str = \"application/json\";`, and use it everywhere"
+        );
+        assert_eq!(results[1].1.issues.len(), 1);
+    }
+
+    #[test]
+    fn test_two_occurrences_ignored() {
+        let sources = [
+            source("src/a.rs", "fn a() { send(\"application/json\"); }"),
+            source("src/b.rs", "fn b() { send(\"application/json\"); }")
+        ];
+        assert!(analyze(&sources).is_empty());
+    }
+
+    #[test]
+    fn test_trivial_literals_ignored() {
+        let code = "fn a() { f(\"ok\"); f(\"ok\"); f(\"ok\"); f(\"------\"); f(\"------\"); \
+                    f(\"------\"); }";
+        assert!(analyze(&[source("src/lib.rs", code)]).is_empty());
+    }
+
+    #[test]
+    fn test_constants_attributes_and_tests_ignored() {
+        let code = r#"
+            const MIME: &str = "application/json";
+            static OTHER: &str = "application/json";
+            #[serde(rename = "application/json")]
+            struct Body;
+            fn a() { send(MIME); send("application/json"); }
+            #[cfg(test)]
+            mod tests {
+                fn b() { send("application/json"); send("application/json"); }
+            }
+        "#;
+        assert!(analyze(&[source("src/lib.rs", code)]).is_empty());
+    }
+
+    #[test]
+    fn test_many_locations_shortened() {
+        let code = "fn a() { f(\"connection refused\"); }\n".repeat(8);
+        let results = analyze(&[source("src/lib.rs", &code)]);
+
+        let message = &results[0].1.issues[0].message;
+        assert!(message.starts_with("String literal \"connection refused\" appears 8 times"));
+        assert!(message.contains("src/lib.rs:6, 2 more\n"));
+    }
+
+    #[test]
+    fn test_quoted_and_constant_name() {
+        assert_eq!(quoted("line\none"), "line\\none");
+        assert_eq!(quoted(&"x".repeat(50)), format!("{}...", "x".repeat(40)));
+        assert_eq!(constant_name("Content-Type header"), "CONTENT_TYPE_HEADER");
+        assert_eq!(constant_name("42 is the answer"), "VALUE");
+    }
+
+    #[test]
+    fn test_applies_to() {
+        let analyzer = DuplicateLiteralsAnalyzer::new();
+        assert!(analyzer.applies_to(Path::new("src/lib.rs")));
+        assert!(!analyzer.applies_to(Path::new("tests/api.rs")));
+    }
+
+    #[test]
+    fn test_analyze_single_file_reports_nothing() {
+        let code = "fn a() { f(\"repeated text\"); f(\"repeated text\"); f(\"repeated text\"); }";
+        let ast = syn::parse_file(code).unwrap();
+        let analyzer = DuplicateLiteralsAnalyzer::new();
+
+        assert!(analyzer.crate_wide());
+        assert_eq!(analyzer.confidence(), Confidence::Low);
+        assert!(analyzer.analyze(&ast, code).unwrap().issues.is_empty());
+    }
+}
@@ -5,13 +5,17 @@
 //!
 //! This analyzer identifies empty lines within function and method bodies,
 //! which violate the Single Responsibility Principle by suggesting the
-//! function does multiple things.
+//! function does multiple things. Teams that use single blank lines as
+//! paragraph separators can relax the rule with an [`EmptyLinesPolicy`].
+//! Items annotated with the generated code marker, `#[quality::generated]` by
+//! default, are skipped.
 
 use std::collections::HashSet;
 
 use masterror::AppResult;
 use syn::{File, ImplItem, Item, ItemFn, ItemImpl, spanned::Spanned, visit::Visit};
 
+use super::{GENERATED_MARKER, has_marker, test_modules::item_attrs};
 use crate::analyzer::{AnalysisResult, Analyzer, Fix, Issue};
 
 /// Analyzer for detecting empty lines inside functions and methods.
@@ -31,13 +35,80 @@ use crate::analyzer::{AnalysisResult, Analyzer, Fix, Issue};
 /// ```
 ///
 /// Suggests removing the empty line or refactoring into separate functions.
-pub struct EmptyLinesAnalyzer;
+pub struct EmptyLinesAnalyzer {
+    policy:           EmptyLinesPolicy,
+    generated_marker: String
+}
+
+/// Empty lines accepted inside function bodies.
+///
+/// The default accepts none, see
+/// [`ProjectContext::empty_lines`](crate::project::ProjectContext::empty_lines)
+/// for the configuration keys.
+///
+/// # Examples
+///
+/// ```
+/// use cargo_quality::analyzers::empty_lines::EmptyLinesPolicy;
+///
+/// let policy = EmptyLinesPolicy::default();
+/// assert_eq!(policy.max_consecutive, 0);
+/// assert!(!policy.allow_before_comment);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EmptyLinesPolicy {
+    /// Number of consecutive empty lines accepted as a paragraph separator
+    pub max_consecutive:      usize,
+    /// Accept an empty line directly followed by a comment line
+    pub allow_before_comment: bool
+}
 
 impl EmptyLinesAnalyzer {
     /// Create new empty lines analyzer instance.
     #[inline]
     pub fn new() -> Self {
-        Self
+        Self::with_policy(EmptyLinesPolicy::default())
+    }
+
+    /// Create analyzer accepting the empty lines a policy allows.
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - Empty lines accepted inside function bodies
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cargo_quality::{
+    ///     analyzer::Analyzer,
+    ///     analyzers::{EmptyLinesAnalyzer, empty_lines::EmptyLinesPolicy}
+    /// };
+    ///
+    /// let analyzer = EmptyLinesAnalyzer::with_policy(EmptyLinesPolicy {
+    ///     max_consecutive:      1,
+    ///     allow_before_comment: false
+    /// });
+    /// let code = "fn f() {\n    a();\n\n    b();\n}";
+    /// let ast = syn::parse_file(code).unwrap();
+    /// assert!(analyzer.analyze(&ast, code).unwrap().issues.is_empty());
+    /// ```
+    #[inline]
+    pub fn with_policy(policy: EmptyLinesPolicy) -> Self {
+        Self {
+            policy,
+            generated_marker: GENERATED_MARKER.to_string()
+        }
+    }
+
+    /// Use another marker attribute for generated items.
+    ///
+    /// # Arguments
+    ///
+    /// * `marker` - Attribute path, e.g. `"codegen::generated"`
+    #[must_use]
+    pub fn with_generated_marker(mut self, marker: &str) -> Self {
+        self.generated_marker = marker.to_string();
+        self
     }
 
     /// Check function body for empty lines.
@@ -48,6 +119,7 @@ impl EmptyLinesAnalyzer {
     ///
     /// * `func` - Function item to analyze
     /// * `lines` - Source code split into lines
+    /// * `policy` - Empty lines to accept
     ///
     /// # Returns
     ///
@@ -56,7 +128,8 @@ impl EmptyLinesAnalyzer {
         start_line: usize,
         end_line: usize,
         lines: &[&str],
-        excluded: &HashSet<usize>
+        excluded: &HashSet<usize>,
+        policy: EmptyLinesPolicy
     ) -> Vec<Issue> {
         let mut issues = Vec::new();
 
@@ -64,8 +137,10 @@ impl EmptyLinesAnalyzer {
             return issues;
         }
 
+        let mut run = 0;
         for line_num in start_line..end_line {
             if excluded.contains(&line_num) {
+                run = 0;
                 continue;
             }
 
@@ -75,28 +150,45 @@ impl EmptyLinesAnalyzer {
                 continue;
             };
 
-            if line.trim().is_empty() {
-                let is_first = line_num == start_line;
-                let is_last = line_num == end_line.saturating_sub(1);
-
-                if is_first || is_last {
-                    continue;
-                }
-
-                if Self::is_after_opening_brace(lines, idx)
-                    || Self::is_before_closing_brace(lines, idx)
-                {
-                    continue;
-                }
-
-                issues.push(Issue {
-                    line:    line_num,
-                    column:  1,
-                    message: "Empty line in function body indicates untamed complexity"
-                        .to_string(),
-                    fix:     Fix::None
-                });
+            if !line.trim().is_empty() {
+                run = 0;
+                continue;
             }
+            run += 1;
+
+            let is_first = line_num == start_line;
+            let is_last = line_num == end_line.saturating_sub(1);
+
+            if is_first || is_last {
+                continue;
+            }
+
+            if Self::is_after_opening_brace(lines, idx)
+                || Self::is_before_closing_brace(lines, idx)
+            {
+                continue;
+            }
+
+            if run <= policy.max_consecutive
+                || (policy.allow_before_comment && Self::is_before_comment(lines, idx))
+            {
+                continue;
+            }
+
+            let message = if policy.max_consecutive == 0 {
+                "Empty line in function body indicates untamed complexity".to_string()
+            } else {
+                format!(
+                    "More than {} consecutive empty lines in function body",
+                    policy.max_consecutive
+                )
+            };
+            issues.push(Issue {
+                line: line_num,
+                column: 1,
+                message,
+                fix: Fix::None
+            });
         }
 
         issues
@@ -150,18 +242,38 @@ impl EmptyLinesAnalyzer {
         false
     }
 
+    /// Check if empty line is right before a comment line.
+    ///
+    /// # Arguments
+    ///
+    /// * `lines` - Source code lines
+    /// * `idx` - Index of empty line (0-based)
+    #[inline]
+    fn is_before_comment(lines: &[&str], idx: usize) -> bool {
+        lines.get(idx + 1).is_some_and(|next| {
+            let trimmed = next.trim_start();
+            trimmed.starts_with("//") || trimmed.starts_with("/*")
+        })
+    }
+
     /// Check standalone function for empty lines.
     ///
     /// # Arguments
     ///
     /// * `func` - Function item to analyze
     /// * `lines` - Source code split into lines
-    fn check_function(func: &ItemFn, lines: &[&str], excluded: &HashSet<usize>) -> Vec<Issue> {
+    /// * `policy` - Empty lines to accept
+    fn check_function(
+        func: &ItemFn,
+        lines: &[&str],
+        excluded: &HashSet<usize>,
+        policy: EmptyLinesPolicy
+    ) -> Vec<Issue> {
         let span = func.block.span();
         let start_line = span.start().line;
         let end_line = span.end().line;
 
-        Self::check_block(start_line, end_line, lines, excluded)
+        Self::check_block(start_line, end_line, lines, excluded, policy)
     }
 
     /// Check impl block methods for empty lines.
@@ -170,20 +282,28 @@ impl EmptyLinesAnalyzer {
     ///
     /// * `impl_block` - Impl block to analyze
     /// * `lines` - Source code split into lines
+    /// * `policy` - Empty lines to accept
+    /// * `marker` - Marker attribute of generated methods to skip
     fn check_impl_block(
         impl_block: &ItemImpl,
         lines: &[&str],
-        excluded: &HashSet<usize>
+        excluded: &HashSet<usize>,
+        policy: EmptyLinesPolicy,
+        marker: &str
     ) -> Vec<Issue> {
         let mut issues = Vec::new();
 
         for item in &impl_block.items {
-            if let ImplItem::Fn(method) = item {
+            if let ImplItem::Fn(method) = item
+                && !has_marker(&method.attrs, marker)
+            {
                 let span = method.block.span();
                 let start_line = span.start().line;
                 let end_line = span.end().line;
 
-                issues.extend(Self::check_block(start_line, end_line, lines, excluded));
+                issues.extend(Self::check_block(
+                    start_line, end_line, lines, excluded, policy
+                ));
             }
         }
 
@@ -202,7 +322,9 @@ impl Analyzer for EmptyLinesAnalyzer {
         let mut visitor = FunctionVisitor {
             issues:   Vec::new(),
             lines:    &lines,
-            excluded: &excluded
+            excluded: &excluded,
+            policy:   self.policy,
+            marker:   &self.generated_marker
         };
         visitor.visit_file(ast);
 
@@ -211,25 +333,43 @@ impl Analyzer for EmptyLinesAnalyzer {
             fixable_count: 0
         })
     }
+
+    fn options(&self) -> &'static [&'static str] {
+        &["empty_lines", "generated_marker"]
+    }
 }
 
 struct FunctionVisitor<'a> {
     issues:   Vec<Issue>,
     lines:    &'a [&'a str],
-    excluded: &'a HashSet<usize>
+    excluded: &'a HashSet<usize>,
+    policy:   EmptyLinesPolicy,
+    marker:   &'a str
 }
 
 impl<'ast, 'a> Visit<'ast> for FunctionVisitor<'a> {
     fn visit_item(&mut self, node: &'ast Item) {
+        if has_marker(item_attrs(node), self.marker) {
+            return;
+        }
         match node {
             Item::Fn(func) => {
-                let func_issues =
-                    EmptyLinesAnalyzer::check_function(func, self.lines, self.excluded);
+                let func_issues = EmptyLinesAnalyzer::check_function(
+                    func,
+                    self.lines,
+                    self.excluded,
+                    self.policy
+                );
                 self.issues.extend(func_issues);
             }
             Item::Impl(impl_block) => {
-                let impl_issues =
-                    EmptyLinesAnalyzer::check_impl_block(impl_block, self.lines, self.excluded);
+                let impl_issues = EmptyLinesAnalyzer::check_impl_block(
+                    impl_block,
+                    self.lines,
+                    self.excluded,
+                    self.policy,
+                    self.marker
+                );
                 self.issues.extend(impl_issues);
             }
             _ => {}
@@ -385,7 +525,7 @@ mod tests {
 
     #[test]
     fn test_default_implementation() {
-        let analyzer = EmptyLinesAnalyzer;
+        let analyzer = EmptyLinesAnalyzer::default();
         assert_eq!(analyzer.name(), "empty_lines");
     }
 
@@ -534,4 +674,92 @@ impl Foo {
         let result = analyzer.analyze(&code, content).unwrap();
         assert_eq!(result.issues.len(), 0);
     }
+
+    #[test]
+    fn test_policy_allows_single_separators() {
+        let analyzer = EmptyLinesAnalyzer::with_policy(EmptyLinesPolicy {
+            max_consecutive:      1,
+            allow_before_comment: false
+        });
+        let content = r#"fn process() {
+    let x = read();
+
+    let y = transform(x);
+
+
+    write(y);
+}"#;
+        let code = syn::parse_str(content).unwrap();
+
+        let result = analyzer.analyze(&code, content).unwrap();
+        assert_eq!(result.issues.len(), 1);
+        assert_eq!(result.issues[0].line, 6);
+        assert!(
+            result.issues[0]
+                .message
+                .contains("More than 1 consecutive empty lines")
+        );
+    }
+
+    #[test]
+    fn test_policy_allows_empty_line_before_comment() {
+        let analyzer = EmptyLinesAnalyzer::with_policy(EmptyLinesPolicy {
+            max_consecutive:      0,
+            allow_before_comment: true
+        });
+        let content = r#"fn process() {
+    let x = read();
+
+    // Transform the input
+    let y = transform(x);
+
+    write(y);
+}"#;
+        let code = syn::parse_str(content).unwrap();
+
+        let result = analyzer.analyze(&code, content).unwrap();
+        assert_eq!(result.issues.len(), 1);
+        assert_eq!(result.issues[0].line, 6);
+    }
+
+    #[test]
+    fn test_skip_generated_items() {
+        let analyzer = EmptyLinesAnalyzer::new();
+        let content = r#"#[quality::generated]
+fn step(state: u8) -> u8 {
+    let next = state + 1;
+
+    next
+}
+
+impl Machine {
+    #[quality::generated]
+    fn table(&self) {
+        a();
+
+        b();
+    }
+
+    fn kept(&self) {
+        a();
+
+        b();
+    }
+}"#;
+        let code = syn::parse_str(content).unwrap();
+
+        let result = analyzer.analyze(&code, content).unwrap();
+        assert_eq!(result.issues.len(), 1);
+        assert_eq!(result.issues[0].line, 18);
+    }
+
+    #[test]
+    fn test_custom_generated_marker() {
+        let analyzer = EmptyLinesAnalyzer::new().with_generated_marker("codegen::generated");
+        let content = "#[codegen::generated]\nfn f() {\n    a();\n\n    b();\n}";
+        let code = syn::parse_str(content).unwrap();
+
+        let result = analyzer.analyze(&code, content).unwrap();
+        assert!(result.issues.is_empty());
+    }
 }
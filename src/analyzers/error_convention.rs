@@ -0,0 +1,394 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Error convention analyzer for public APIs with ad-hoc error types.
+//!
+//! Public functions returning `anyhow::Error` or `Box<dyn Error>` hand
+//! callers an opaque error they cannot match on, and mix error styles across
+//! crates that standardize on one type such as `masterror::AppError`. This
+//! analyzer flags such signatures and suggests the project's standard error
+//! type. It is opt-in: enable it per project with
+//! `enable = ["error_convention"]` under `[package.metadata.quality]`, and
+//! set the expected type with `error_type = "path::To::Error"`.
+
+use std::collections::HashMap;
+
+use masterror::AppResult;
+use syn::{
+    File, GenericArgument, ImplItemFn, Item, ItemFn, ItemImpl, ItemMod, PathArguments, ReturnType,
+    Signature, Type, TypeParamBound, UseTree, Visibility, visit::Visit
+};
+
+use super::is_test_code;
+use crate::analyzer::{AnalysisResult, Analyzer, Fix, Issue};
+
+/// Error type suggested when the project does not configure one.
+pub const DEFAULT_ERROR_TYPE: &str = "masterror::AppError";
+
+/// Analyzer for public functions returning `anyhow` or boxed errors.
+///
+/// # Examples
+///
+/// Detects this pattern:
+/// ```ignore
+/// pub fn load(path: &Path) -> anyhow::Result<Config> { ... }
+/// pub fn parse(input: &str) -> Result<Ast, Box<dyn std::error::Error>> { ... }
+/// ```
+///
+/// Suggests the project's standard error type:
+/// ```ignore
+/// pub fn load(path: &Path) -> AppResult<Config> { ... }
+/// pub fn parse(input: &str) -> Result<Ast, AppError> { ... }
+/// ```
+pub struct ErrorConventionAnalyzer {
+    error_type: String
+}
+
+impl ErrorConventionAnalyzer {
+    /// Create new error convention analyzer expecting [`DEFAULT_ERROR_TYPE`].
+    #[inline]
+    pub fn new() -> Self {
+        Self::with_error_type(DEFAULT_ERROR_TYPE)
+    }
+
+    /// Create analyzer expecting a project-specific error type.
+    ///
+    /// # Arguments
+    ///
+    /// * `error_type` - Type path suggested in issues, see
+    ///   [`crate::project::ProjectContext::error_type`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cargo_quality::{analyzer::Analyzer, analyzers::ErrorConventionAnalyzer};
+    ///
+    /// let analyzer = ErrorConventionAnalyzer::with_error_type("crate::Error");
+    /// let code = "pub fn run() -> anyhow::Result<()> { Ok(()) }";
+    /// let ast = syn::parse_file(code).unwrap();
+    /// let result = analyzer.analyze(&ast, code).unwrap();
+    /// assert!(result.issues[0].message.contains("`crate::Error`"));
+    /// ```
+    #[inline]
+    pub fn with_error_type(error_type: impl Into<String>) -> Self {
+        Self {
+            error_type: error_type.into()
+        }
+    }
+}
+
+impl Analyzer for ErrorConventionAnalyzer {
+    fn name(&self) -> &'static str {
+        "error_convention"
+    }
+
+    fn analyze(&self, ast: &File, _content: &str) -> AppResult<AnalysisResult> {
+        let mut visitor = ConventionVisitor {
+            issues:   Vec::new(),
+            imports:  anyhow_imports(ast),
+            expected: &self.error_type
+        };
+        visitor.visit_file(ast);
+
+        Ok(AnalysisResult {
+            issues:        visitor.issues,
+            fixable_count: 0
+        })
+    }
+
+    fn opt_in(&self) -> bool {
+        true
+    }
+
+    fn options(&self) -> &'static [&'static str] {
+        &["error_type"]
+    }
+}
+
+impl Default for ErrorConventionAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct ConventionVisitor<'a> {
+    issues:   Vec<Issue>,
+    /// Local names imported from `anyhow`, mapped to the original name
+    imports:  HashMap<String, String>,
+    expected: &'a str
+}
+
+impl ConventionVisitor<'_> {
+    /// Flag a public function whose return type uses a non-standard error.
+    fn check_function(&mut self, vis: &Visibility, sig: &Signature) {
+        if !matches!(vis, Visibility::Public(_)) {
+            return;
+        }
+        let ReturnType::Type(_, ty) = &sig.output else {
+            return;
+        };
+        let Some(found) = self.non_standard_error(ty) else {
+            return;
+        };
+        if normalize(found) == normalize(self.expected) {
+            return;
+        }
+
+        let start = sig.ident.span().start();
+        self.issues.push(Issue {
+            line:    start.line,
+            column:  start.column,
+            message: format!(
+                "Public function `{}` returns `{found}`\n\
+                 Use the project's standard error type `{}` so callers can match on errors",
+                sig.ident, self.expected
+            ),
+            fix:     Fix::None
+        });
+    }
+
+    /// Find an `anyhow` or boxed error in a `Result` return type.
+    ///
+    /// # Returns
+    ///
+    /// `"anyhow::Error"` or `"Box<dyn Error>"` if the type uses one
+    fn non_standard_error(&self, ty: &Type) -> Option<&'static str> {
+        let Type::Path(path) = ty else {
+            return None;
+        };
+        let segments = &path.path.segments;
+        let last = segments.last()?;
+        let anyhow_result = match segments.len() {
+            1 => self
+                .imports
+                .get(&last.ident.to_string())
+                .is_some_and(|orig| orig == "Result"),
+            _ => segments[0].ident == "anyhow" && last.ident == "Result"
+        };
+
+        if anyhow_result {
+            return Some("anyhow::Error");
+        }
+        if last.ident != "Result" {
+            return None;
+        }
+
+        let PathArguments::AngleBracketed(args) = &last.arguments else {
+            return None;
+        };
+        let error = args.args.iter().nth(1).and_then(|arg| match arg {
+            GenericArgument::Type(ty) => Some(ty),
+            _ => None
+        })?;
+        self.classify_error(error)
+    }
+
+    /// Classify the error parameter of a `Result`.
+    fn classify_error(&self, ty: &Type) -> Option<&'static str> {
+        let Type::Path(path) = ty else {
+            return None;
+        };
+        let segments = &path.path.segments;
+        let last = segments.last()?;
+
+        let anyhow = match segments.len() {
+            1 => self
+                .imports
+                .get(&last.ident.to_string())
+                .is_some_and(|orig| orig == "Error"),
+            _ => segments[0].ident == "anyhow" && last.ident == "Error"
+        };
+        if anyhow {
+            return Some("anyhow::Error");
+        }
+
+        (last.ident == "Box" && is_dyn_error(&last.arguments)).then_some("Box<dyn Error>")
+    }
+}
+
+impl<'ast> Visit<'ast> for ConventionVisitor<'_> {
+    fn visit_item_fn(&mut self, node: &'ast ItemFn) {
+        if is_test_code(&node.attrs) {
+            return;
+        }
+        self.check_function(&node.vis, &node.sig);
+        syn::visit::visit_item_fn(self, node);
+    }
+
+    fn visit_item_impl(&mut self, node: &'ast ItemImpl) {
+        if node.trait_.is_none() {
+            syn::visit::visit_item_impl(self, node);
+        }
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'ast ImplItemFn) {
+        if is_test_code(&node.attrs) {
+            return;
+        }
+        self.check_function(&node.vis, &node.sig);
+        syn::visit::visit_impl_item_fn(self, node);
+    }
+
+    fn visit_item_mod(&mut self, node: &'ast ItemMod) {
+        if !is_test_code(&node.attrs) {
+            syn::visit::visit_item_mod(self, node);
+        }
+    }
+}
+
+/// Check whether `Box` arguments are a `dyn Error` trait object.
+fn is_dyn_error(arguments: &PathArguments) -> bool {
+    let PathArguments::AngleBracketed(args) = arguments else {
+        return false;
+    };
+    let Some(GenericArgument::Type(Type::TraitObject(object))) = args.args.first() else {
+        return false;
+    };
+
+    object.bounds.iter().any(|bound| match bound {
+        TypeParamBound::Trait(bound) => bound
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "Error"),
+        _ => false
+    })
+}
+
+/// Collect names imported from `anyhow` at the top of a file.
+///
+/// # Returns
+///
+/// Map from the local name (after `as` renames) to the name in `anyhow`
+fn anyhow_imports(ast: &File) -> HashMap<String, String> {
+    fn walk(tree: &UseTree, in_anyhow: bool, imports: &mut HashMap<String, String>) {
+        match tree {
+            UseTree::Path(path) => walk(&path.tree, in_anyhow || path.ident == "anyhow", imports),
+            UseTree::Name(name) if in_anyhow => {
+                imports.insert(name.ident.to_string(), name.ident.to_string());
+            }
+            UseTree::Rename(rename) if in_anyhow => {
+                imports.insert(rename.rename.to_string(), rename.ident.to_string());
+            }
+            UseTree::Group(group) => {
+                for item in &group.items {
+                    walk(item, in_anyhow, imports);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut imports = HashMap::new();
+    for item in &ast.items {
+        if let Item::Use(item_use) = item {
+            walk(&item_use.tree, false, &mut imports);
+        }
+    }
+    imports
+}
+
+/// Remove whitespace so type paths compare independent of formatting.
+fn normalize(path: &str) -> String {
+    path.chars().filter(|c| !c.is_whitespace()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analyze(code: &str) -> AnalysisResult {
+        let ast = syn::parse_file(code).unwrap();
+        ErrorConventionAnalyzer::new().analyze(&ast, code).unwrap()
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        let analyzer = ErrorConventionAnalyzer::new();
+        assert_eq!(analyzer.name(), "error_convention");
+        assert!(analyzer.opt_in());
+    }
+
+    #[test]
+    fn test_detect_anyhow_results() {
+        let result = analyze(
+            r#"
+pub fn load() -> anyhow::Result<Config> { todo!() }
+pub fn parse() -> Result<Ast, anyhow::Error> { todo!() }
+"#
+        );
+
+        assert_eq!(result.issues.len(), 2);
+        assert_eq!(result.issues[1].line, 3);
+        assert!(result.issues[0].message.contains("returns `anyhow::Error`"));
+        assert!(result.issues[0].message.contains("`masterror::AppError`"));
+    }
+
+    #[test]
+    fn test_detect_imported_anyhow_names() {
+        let result = analyze(
+            r#"
+use anyhow::{Error as AnyError, Result};
+
+pub fn load() -> Result<Config> { todo!() }
+pub fn parse() -> std::result::Result<Ast, AnyError> { todo!() }
+"#
+        );
+
+        assert_eq!(result.issues.len(), 2);
+    }
+
+    #[test]
+    fn test_detect_boxed_error() {
+        let result = analyze(
+            r#"
+pub struct Service;
+
+impl Service {
+    pub fn start(&self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> { todo!() }
+}
+"#
+        );
+
+        assert_eq!(result.issues.len(), 1);
+        assert!(result.issues[0].message.contains("`Box<dyn Error>`"));
+    }
+
+    #[test]
+    fn test_ignore_private_trait_and_standard_errors() {
+        let result = analyze(
+            r#"
+fn private() -> anyhow::Result<()> { todo!() }
+pub(crate) fn internal() -> anyhow::Result<()> { todo!() }
+pub fn standard() -> AppResult<()> { todo!() }
+pub fn io() -> Result<(), std::io::Error> { todo!() }
+
+impl Runner for Task {
+    fn run(&self) -> anyhow::Result<()> { todo!() }
+}
+
+#[cfg(test)]
+mod tests {
+    pub fn helper() -> anyhow::Result<()> { todo!() }
+}
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_configured_anyhow_is_standard() {
+        let analyzer = ErrorConventionAnalyzer::with_error_type("anyhow::Error");
+        let code = "pub fn load() -> anyhow::Result<()> { todo!() }";
+        let ast = syn::parse_file(code).unwrap();
+
+        assert!(analyzer.analyze(&ast, code).unwrap().issues.is_empty());
+    }
+
+    #[test]
+    fn test_default_implementation() {
+        let analyzer = ErrorConventionAnalyzer::default();
+        assert_eq!(analyzer.error_type, DEFAULT_ERROR_TYPE);
+    }
+}
@@ -0,0 +1,338 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Error display analyzer for error types without a `Display`
+//! implementation.
+//!
+//! An error is shown to someone sooner or later: `main` returning it, a log
+//! line, a `?` into `Box<dyn Error>`. Without `Display` the type cannot
+//! implement `std::error::Error` at all, and callers fall back to `{:?}`,
+//! printing struct syntax instead of a message. This analyzer collects the
+//! `Display` implementations of every file in the run and reports structs
+//! and enums named `*Error`, or implementing `std::error::Error`, that have
+//! none. Types deriving `Error` (e.g. `thiserror::Error`) or `Display`
+//! (e.g. `derive_more::Display`) get their implementation from the derive.
+//!
+//! `Display` implementations written by other macros are not seen, so the
+//! confidence is medium.
+
+use std::{
+    collections::{BTreeMap, HashSet},
+    path::PathBuf
+};
+
+use masterror::AppResult;
+use syn::{
+    Attribute, File, Ident, ItemEnum, ItemImpl, ItemMod, ItemStruct, Meta, Path as SynPath, Type,
+    punctuated::Punctuated, visit::Visit
+};
+
+use super::is_test_code;
+use crate::analyzer::{AnalysisResult, Analyzer, Confidence, Fix, Issue, SourceFile};
+
+/// Derives implementing `Display`.
+const DISPLAY_DERIVES: &[&str] = &["Error", "Display"];
+
+/// Analyzer for error types without a `Display` implementation.
+///
+/// # Examples
+///
+/// Detects this pattern:
+/// ```ignore
+/// #[derive(Debug)]
+/// pub struct ConfigError {
+///     pub key: String
+/// }
+/// ```
+///
+/// Suggests:
+/// ```ignore
+/// impl fmt::Display for ConfigError {
+///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         write!(f, "invalid configuration key `{}`", self.key)
+///     }
+/// }
+/// ```
+pub struct ErrorDisplayAnalyzer;
+
+impl ErrorDisplayAnalyzer {
+    /// Create new error display analyzer instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Analyzer for ErrorDisplayAnalyzer {
+    fn name(&self) -> &'static str {
+        "error_display"
+    }
+
+    fn analyze(&self, _ast: &File, _content: &str) -> AppResult<AnalysisResult> {
+        Ok(AnalysisResult::default())
+    }
+
+    fn crate_wide(&self) -> bool {
+        true
+    }
+
+    fn analyze_crate(&self, files: &[&SourceFile]) -> AppResult<Vec<(PathBuf, AnalysisResult)>> {
+        let mut visitor = ErrorVisitor {
+            file:          0,
+            types:         Vec::new(),
+            error_impls:   HashSet::new(),
+            display_impls: HashSet::new()
+        };
+        for (file, source) in files.iter().enumerate() {
+            visitor.file = file;
+            visitor.visit_file(&source.ast);
+        }
+
+        let ErrorVisitor {
+            types,
+            error_impls,
+            display_impls,
+            ..
+        } = visitor;
+        let mut issues: BTreeMap<usize, Vec<Issue>> = BTreeMap::new();
+        for candidate in types {
+            let name = candidate.ident.to_string();
+            let is_error = name.ends_with("Error") || error_impls.contains(&name);
+            if !is_error || display_impls.contains(&name) {
+                continue;
+            }
+
+            let start = candidate.ident.span().start();
+            issues.entry(candidate.file).or_default().push(Issue {
+                line:    start.line,
+                column:  start.column,
+                message: format!(
+                    "Error type `{name}` has no `Display` implementation\nImplement \
+                     `fmt::Display` with a message for users, or derive it with \
+                     `thiserror::Error`"
+                ),
+                fix:     Fix::None
+            });
+        }
+
+        Ok(issues
+            .into_iter()
+            .map(|(file, issues)| {
+                (
+                    files[file].path.clone(),
+                    AnalysisResult {
+                        issues,
+                        fixable_count: 0
+                    }
+                )
+            })
+            .collect())
+    }
+
+    fn confidence(&self) -> Confidence {
+        Confidence::Medium
+    }
+}
+
+impl Default for ErrorDisplayAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Struct or enum that may be an error type.
+struct Candidate {
+    /// Index of the file
+    file:  usize,
+    ident: Ident
+}
+
+struct ErrorVisitor {
+    /// Index of the file being visited
+    file:          usize,
+    /// Structs and enums without a `Display` derive
+    types:         Vec<Candidate>,
+    /// Types implementing `std::error::Error` by hand
+    error_impls:   HashSet<String>,
+    /// Types implementing `Display` by hand
+    display_impls: HashSet<String>
+}
+
+impl ErrorVisitor {
+    fn record_type(&mut self, ident: &Ident, attrs: &[Attribute]) {
+        if !is_test_code(attrs) && !derives_display(attrs) {
+            self.types.push(Candidate {
+                file:  self.file,
+                ident: ident.clone()
+            });
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for ErrorVisitor {
+    fn visit_item_struct(&mut self, node: &'ast ItemStruct) {
+        self.record_type(&node.ident, &node.attrs);
+    }
+
+    fn visit_item_enum(&mut self, node: &'ast ItemEnum) {
+        self.record_type(&node.ident, &node.attrs);
+    }
+
+    fn visit_item_impl(&mut self, node: &'ast ItemImpl) {
+        let Some((_, path, _)) = &node.trait_ else {
+            return;
+        };
+        let Type::Path(self_ty) = node.self_ty.as_ref() else {
+            return;
+        };
+        let (Some(name), Some(ty)) = (path.segments.last(), self_ty.path.segments.last()) else {
+            return;
+        };
+
+        if name.ident == "Display" {
+            self.display_impls.insert(ty.ident.to_string());
+        } else if name.ident == "Error" {
+            self.error_impls.insert(ty.ident.to_string());
+        }
+    }
+
+    fn visit_item_mod(&mut self, node: &'ast ItemMod) {
+        if !is_test_code(&node.attrs) {
+            syn::visit::visit_item_mod(self, node);
+        }
+    }
+}
+
+/// Check whether attributes derive an implementation of `Display`.
+fn derives_display(attrs: &[Attribute]) -> bool {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("derive"))
+        .filter_map(|attr| match &attr.meta {
+            Meta::List(list) => list
+                .parse_args_with(Punctuated::<SynPath, syn::Token![,]>::parse_terminated)
+                .ok(),
+            _ => None
+        })
+        .flatten()
+        .any(|path| {
+            path.segments.last().is_some_and(|segment| {
+                DISPLAY_DERIVES.iter().any(|derive| segment.ident == derive)
+            })
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn source(path: &str, code: &str) -> SourceFile {
+        SourceFile {
+            path: PathBuf::from(path),
+            ast:  syn::parse_file(code).unwrap()
+        }
+    }
+
+    fn analyze(sources: &[SourceFile]) -> Vec<(PathBuf, AnalysisResult)> {
+        let files: Vec<&SourceFile> = sources.iter().collect();
+        ErrorDisplayAnalyzer::new().analyze_crate(&files).unwrap()
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        let analyzer = ErrorDisplayAnalyzer::new();
+        assert_eq!(analyzer.name(), "error_display");
+        assert!(analyzer.crate_wide());
+        assert_eq!(analyzer.confidence(), Confidence::Medium);
+    }
+
+    #[test]
+    fn test_detect_error_types_without_display() {
+        let results = analyze(&[
+            source(
+                "src/error.rs",
+                "#[derive(Debug)]\npub struct ConfigError {\n    key: String\n}\n\npub enum \
+                 LoadError { Missing }"
+            ),
+            source(
+                "src/load.rs",
+                "pub struct Failure;\nimpl std::error::Error for Failure {}"
+            )
+        ]);
+
+        assert_eq!(results.len(), 2);
+        let (path, error) = &results[0];
+        assert_eq!(path, &PathBuf::from("src/error.rs"));
+        assert_eq!(error.issues.len(), 2);
+        assert_eq!(error.fixable_count, 0);
+        assert_eq!(error.issues[0].line, 2);
+        assert_eq!(error.issues[0].column, 11);
+        assert_eq!(
+            error.issues[0].message,
+            "Error type `ConfigError` has no `Display` implementation\nImplement `fmt::Display` \
+             with a message for users, or derive it with `thiserror::Error`"
+        );
+        assert!(error.issues[1].message.contains("`LoadError`"));
+        assert!(results[1].1.issues[0].message.contains("`Failure`"));
+    }
+
+    #[test]
+    fn test_display_in_other_file_counts() {
+        let results = analyze(&[
+            source("src/error.rs", "pub struct ConfigError;"),
+            source(
+                "src/display.rs",
+                "impl fmt::Display for ConfigError {\n    fn fmt(&self, f: &mut fmt::Formatter) \
+                 -> fmt::Result { Ok(()) }\n}"
+            )
+        ]);
+
+        assert!(results.is_empty());
+    }
+
+    #[test]
+    fn test_ignore_complete_and_other_types() {
+        let results = analyze(&[source(
+            "src/lib.rs",
+            r#"
+#[derive(Debug, thiserror::Error)]
+pub enum ParseError {
+    #[error("empty input")]
+    Empty
+}
+
+#[derive(derive_more::Display)]
+pub struct IoError;
+
+pub struct Config;
+
+impl<T> std::fmt::Display for WrapError<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result { Ok(()) }
+}
+
+pub struct WrapError<T>(T);
+
+#[cfg(test)]
+mod tests {
+    struct FixtureError;
+}
+"#
+        )]);
+
+        assert!(results.is_empty());
+    }
+
+    #[test]
+    fn test_analyze_reports_nothing_per_file() {
+        let analyzer = ErrorDisplayAnalyzer;
+        let code = "pub struct ConfigError;";
+        let ast = syn::parse_file(code).unwrap();
+        assert!(analyzer.analyze(&ast, code).unwrap().issues.is_empty());
+    }
+
+    #[test]
+    fn test_default_implementation() {
+        let analyzer = ErrorDisplayAnalyzer;
+        assert_eq!(analyzer.name(), "error_display");
+    }
+}
@@ -0,0 +1,373 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Error sources analyzer for conversions that drop the original error.
+//!
+//! `read(path).map_err(|_| Error::Config)?` compiles into a tidy error type,
+//! and the reason the read failed is gone: the caller sees "config error"
+//! for a missing file, a permission problem and invalid UTF-8 alike. This
+//! analyzer flags functions that convert errors this way before `?`, with
+//! `map_err` closures ignoring their argument or `.ok().ok_or(..)`, and
+//! `From` impls that ignore the error they convert. Without type
+//! information the analyzer works on the shape of the expressions, so its
+//! confidence is medium.
+
+use std::mem;
+
+use masterror::AppResult;
+use proc_macro2::Span;
+use syn::{
+    Expr, ExprClosure, ExprMethodCall, ExprTry, File, FnArg, Ident, ImplItem, ImplItemFn, ItemFn,
+    ItemImpl, ItemMod, Pat, Type, visit::Visit
+};
+
+use super::{first_type_argument, is_test_code, type_name};
+use crate::analyzer::{AnalysisResult, Analyzer, Confidence, Fix, Issue};
+
+/// Analyzer for error conversions that discard the source error.
+///
+/// # Examples
+///
+/// Detects this pattern:
+/// ```ignore
+/// fn load(path: &Path) -> Result<Config, ConfigError> {
+///     let text = fs::read_to_string(path).map_err(|_| ConfigError)?;
+///     toml::from_str(&text).map_err(|_| ConfigError)
+/// }
+/// ```
+///
+/// Suggests keeping the source:
+/// ```ignore
+/// let text = fs::read_to_string(path).map_err(ConfigError::Read)?;
+/// ```
+pub struct ErrorSourcesAnalyzer;
+
+impl ErrorSourcesAnalyzer {
+    /// Create new error sources analyzer instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Analyzer for ErrorSourcesAnalyzer {
+    fn name(&self) -> &'static str {
+        "error_sources"
+    }
+
+    fn analyze(&self, ast: &File, _content: &str) -> AppResult<AnalysisResult> {
+        let mut visitor = SourceVisitor {
+            discards: Vec::new(),
+            issues:   Vec::new()
+        };
+        visitor.visit_file(ast);
+
+        Ok(AnalysisResult {
+            issues:        visitor.issues,
+            fixable_count: 0
+        })
+    }
+
+    fn confidence(&self) -> Confidence {
+        Confidence::Medium
+    }
+}
+
+impl Default for ErrorSourcesAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct SourceVisitor {
+    /// Lines of the conversions before `?` that discard the error, in the
+    /// function being visited
+    discards: Vec<usize>,
+    issues:   Vec<Issue>
+}
+
+impl SourceVisitor {
+    /// Visit a function body and report its discarding conversions.
+    fn check_fn(&mut self, ident: &Ident, visit: impl FnOnce(&mut Self)) {
+        let outer = mem::take(&mut self.discards);
+        visit(self);
+        let discards = mem::replace(&mut self.discards, outer);
+
+        if discards.is_empty() {
+            return;
+        }
+        let lines: Vec<String> = discards.iter().map(ToString::to_string).collect();
+        let start = ident.span().start();
+        self.issues.push(Issue {
+            line:    start.line,
+            column:  start.column,
+            message: format!(
+                "Function `{ident}` discards the source of {} converted with `?` ({} {})\n\
+                 Keep the original error: wrap it in a variant such as `map_err(Error::Io)` or \
+                 store it as the `source` of the new error",
+                if discards.len() == 1 {
+                    "1 error".to_string()
+                } else {
+                    format!("{} errors", discards.len())
+                },
+                if discards.len() == 1 { "line" } else { "lines" },
+                lines.join(", ")
+            ),
+            fix:     Fix::None
+        });
+    }
+
+    /// Check a `From` impl whose `from` ignores the converted error.
+    fn check_from(&mut self, node: &ItemImpl) {
+        let Some((_, path, _)) = &node.trait_ else {
+            return;
+        };
+        let Some(segment) = path.segments.last() else {
+            return;
+        };
+        if segment.ident != "From" {
+            return;
+        }
+        let Some(source) = first_type_argument(&segment.arguments) else {
+            return;
+        };
+        let ignored = node.items.iter().any(|item| {
+            let ImplItem::Fn(method) = item else {
+                return false;
+            };
+            method.sig.ident == "from"
+                && method.sig.inputs.first().is_some_and(
+                    |input| matches!(input, FnArg::Typed(param) if is_ignored(&param.pat))
+                )
+        });
+        if !ignored {
+            return;
+        }
+
+        let start = node.impl_token.span.start();
+        self.issues.push(Issue {
+            line:    start.line,
+            column:  start.column,
+            message: format!(
+                "`From<{}>` for `{}` discards the converted error\n\
+                 Store the error in the target type so callers can reach it through \
+                 `Error::source`",
+                type_text(source),
+                type_name(&node.self_ty).unwrap_or_else(|| "_".to_string())
+            ),
+            fix:     Fix::None
+        });
+    }
+}
+
+impl<'ast> Visit<'ast> for SourceVisitor {
+    fn visit_item_fn(&mut self, node: &'ast ItemFn) {
+        if !is_test_code(&node.attrs) {
+            self.check_fn(&node.sig.ident, |visitor| {
+                syn::visit::visit_item_fn(visitor, node);
+            });
+        }
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'ast ImplItemFn) {
+        if !is_test_code(&node.attrs) {
+            self.check_fn(&node.sig.ident, |visitor| {
+                syn::visit::visit_impl_item_fn(visitor, node);
+            });
+        }
+    }
+
+    fn visit_item_impl(&mut self, node: &'ast ItemImpl) {
+        if !is_test_code(&node.attrs) {
+            self.check_from(node);
+            syn::visit::visit_item_impl(self, node);
+        }
+    }
+
+    fn visit_item_mod(&mut self, node: &'ast ItemMod) {
+        if !is_test_code(&node.attrs) {
+            syn::visit::visit_item_mod(self, node);
+        }
+    }
+
+    fn visit_expr_try(&mut self, node: &'ast ExprTry) {
+        if let Expr::MethodCall(call) = node.expr.as_ref()
+            && let Some(span) = discarding_conversion(call)
+        {
+            self.discards.push(span.start().line);
+        }
+        syn::visit::visit_expr_try(self, node);
+    }
+}
+
+/// Location of a conversion that drops the error it converts.
+///
+/// Matches `.map_err(|_| ..)`, `.map_err(|_e| ..)` and `.ok().ok_or(..)` /
+/// `.ok().ok_or_else(..)`.
+fn discarding_conversion(call: &ExprMethodCall) -> Option<Span> {
+    match call.method.to_string().as_str() {
+        "map_err" => match call.args.first()? {
+            Expr::Closure(ExprClosure {
+                inputs, ..
+            }) if inputs.len() == 1 && is_ignored(&inputs[0]) => Some(call.method.span()),
+            _ => None
+        },
+        "ok_or" | "ok_or_else" => match call.receiver.as_ref() {
+            Expr::MethodCall(ok) if ok.method == "ok" && ok.args.is_empty() => {
+                Some(ok.method.span())
+            }
+            _ => None
+        },
+        _ => None
+    }
+}
+
+/// Check whether a parameter pattern ignores its value: `_`, `_name` or a
+/// typed form of these.
+fn is_ignored(pat: &Pat) -> bool {
+    match pat {
+        Pat::Wild(_) => true,
+        Pat::Ident(ident) => ident.ident.to_string().starts_with('_'),
+        Pat::Type(typed) => is_ignored(&typed.pat),
+        _ => false
+    }
+}
+
+/// Path of a type as written, without generics, e.g. `io::Error`.
+fn type_text(ty: &Type) -> String {
+    match ty {
+        Type::Path(path) => path
+            .path
+            .segments
+            .iter()
+            .map(|segment| segment.ident.to_string())
+            .collect::<Vec<_>>()
+            .join("::"),
+        _ => type_name(ty).unwrap_or_else(|| "_".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analyze(code: &str) -> AnalysisResult {
+        let ast = syn::parse_file(code).unwrap();
+        ErrorSourcesAnalyzer::new().analyze(&ast, code).unwrap()
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        let analyzer = ErrorSourcesAnalyzer::new();
+        assert_eq!(analyzer.name(), "error_sources");
+        assert_eq!(analyzer.confidence(), Confidence::Medium);
+    }
+
+    #[test]
+    fn test_detect_discarding_functions() {
+        let result = analyze(
+            r#"
+fn load(path: &Path) -> Result<Config, ConfigError> {
+    let text = fs::read_to_string(path).map_err(|_| ConfigError)?;
+    let port = text.parse::<u16>().ok().ok_or(ConfigError)?;
+    let value = toml::from_str(&text).map_err(|_err: toml::de::Error| ConfigError)?;
+    Ok(Config { port, value })
+}
+
+impl Store {
+    fn open(&self) -> Result<(), StoreError> {
+        self.file().map_err(|_e| StoreError::Open)?;
+        Ok(())
+    }
+}
+"#
+        );
+
+        assert_eq!(result.issues.len(), 2);
+        assert_eq!(result.fixable_count, 0);
+        assert_eq!(result.issues[0].line, 2);
+        assert_eq!(result.issues[0].column, 3);
+        assert!(result.issues[0].message.starts_with(
+            "Function `load` discards the source of 3 errors converted with `?` (lines 3, 4, 5)"
+        ));
+        assert!(result.issues[1].message.starts_with(
+            "Function `open` discards the source of 1 error converted with `?` (line 11)"
+        ));
+    }
+
+    #[test]
+    fn test_detect_discarding_from_impl() {
+        let result = analyze(
+            r#"
+impl From<io::Error> for LoadError {
+    fn from(_: io::Error) -> Self {
+        LoadError
+    }
+}
+
+impl From<ParseIntError> for LoadError {
+    fn from(error: ParseIntError) -> Self {
+        LoadError::Parse(error)
+    }
+}
+"#
+        );
+
+        assert_eq!(result.issues.len(), 1);
+        assert_eq!(result.issues[0].line, 2);
+        assert!(
+            result.issues[0]
+                .message
+                .starts_with("`From<io::Error>` for `LoadError` discards the converted error")
+        );
+    }
+
+    #[test]
+    fn test_ignore_preserved_sources() {
+        let result = analyze(
+            r#"
+fn load(path: &Path) -> Result<Config, ConfigError> {
+    let text = fs::read_to_string(path).map_err(ConfigError::Read)?;
+    let port = text.parse::<u16>().map_err(|e| ConfigError::Port(e))?;
+    let fallback = text.parse::<u8>().map_err(|_| ConfigError::Port);
+    let name = lookup(&text).ok_or(ConfigError::Missing)?;
+    Ok(Config { port, name })
+}
+
+#[cfg(test)]
+mod tests {
+    fn fixture() -> Result<(), ()> {
+        run().map_err(|_| ())?;
+        Ok(())
+    }
+}
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_nested_functions_reported_separately() {
+        let result = analyze(
+            r#"
+fn outer() -> Result<(), E> {
+    fn inner() -> Result<(), E> {
+        step().map_err(|_| E)?;
+        Ok(())
+    }
+    inner()
+}
+"#
+        );
+
+        assert_eq!(result.issues.len(), 1);
+        assert!(result.issues[0].message.starts_with("Function `inner`"));
+    }
+
+    #[test]
+    fn test_default_implementation() {
+        let analyzer = ErrorSourcesAnalyzer;
+        assert_eq!(analyzer.name(), "error_sources");
+    }
+}
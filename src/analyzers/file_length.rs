@@ -0,0 +1,106 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! File length analyzer for oversized source files.
+//!
+//! A file that keeps growing usually holds several concerns that would read
+//! better as separate modules, and long files make reviews and merges
+//! harder. This analyzer reports files with more than 600 lines, counting
+//! everything including comments and tests.
+
+use masterror::AppResult;
+use syn::File;
+
+use crate::analyzer::{AnalysisResult, Analyzer, Fix, Issue};
+
+/// Maximum number of lines in a source file.
+const MAX_LINES: usize = 600;
+
+/// Analyzer for source files exceeding the line limit.
+///
+/// # Examples
+///
+/// A 900-line `parser.rs` holding the lexer, the AST and the parser is
+/// reported; splitting it into `parser/lexer.rs`, `parser/ast.rs` and
+/// `parser.rs` resolves the issue.
+pub struct FileLengthAnalyzer;
+
+impl FileLengthAnalyzer {
+    /// Create new file length analyzer instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Analyzer for FileLengthAnalyzer {
+    fn name(&self) -> &'static str {
+        "file_length"
+    }
+
+    fn analyze(&self, _ast: &File, content: &str) -> AppResult<AnalysisResult> {
+        let lines = content.lines().count();
+        if lines <= MAX_LINES {
+            return Ok(AnalysisResult::default());
+        }
+
+        Ok(AnalysisResult {
+            issues:        vec![Issue {
+                line:    1,
+                column:  0,
+                message: format!(
+                    "File has {lines} lines, over the limit of {MAX_LINES}\n\
+                     Extract cohesive parts into submodules"
+                ),
+                fix:     Fix::None
+            }],
+            fixable_count: 0
+        })
+    }
+}
+
+impl Default for FileLengthAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analyze(code: &str) -> AnalysisResult {
+        let ast = syn::parse_file(code).unwrap();
+        FileLengthAnalyzer::new().analyze(&ast, code).unwrap()
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        let analyzer = FileLengthAnalyzer::new();
+        assert_eq!(analyzer.name(), "file_length");
+    }
+
+    #[test]
+    fn test_detect_long_file() {
+        let result = analyze(&"// line\n".repeat(MAX_LINES + 1));
+
+        assert_eq!(result.issues.len(), 1);
+        assert_eq!(result.issues[0].line, 1);
+        assert!(
+            result.issues[0]
+                .message
+                .contains("File has 601 lines, over the limit of 600")
+        );
+    }
+
+    #[test]
+    fn test_accept_file_at_limit() {
+        assert!(analyze(&"// line\n".repeat(MAX_LINES)).issues.is_empty());
+    }
+
+    #[test]
+    fn test_default_implementation() {
+        let analyzer = FileLengthAnalyzer;
+        assert_eq!(analyzer.name(), "file_length");
+    }
+}
@@ -1,15 +1,31 @@
 // SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
 // SPDX-License-Identifier: MIT
 
+use std::ops::Range;
+
 use masterror::AppResult;
-use proc_macro2::TokenTree;
+use proc_macro2::{Spacing, TokenStream, TokenTree};
 use syn::{ExprMacro, File, LitStr, Macro, spanned::Spanned};
 
-use crate::analyzer::{AnalysisResult, Analyzer, Fix, Issue};
+use crate::analyzer::{AnalysisResult, Analyzer, Fix, Issue, Suggestion, TextEdit};
 
 /// Analyzer for format macro arguments
 pub struct FormatArgsAnalyzer;
 
+/// Rewrite of positional format arguments into inline named arguments.
+///
+/// Produced only when every positional argument is a plain identifier, so the
+/// rewrite `println!("{}", name)` → `println!("{name}")` cannot change
+/// behavior.
+struct InlineRewrite {
+    /// Byte range from the format string to the last positional argument
+    range:      Range<usize>,
+    /// New format string literal source
+    literal:    String,
+    /// Rewritten macro invocation, for display
+    invocation: String
+}
+
 impl FormatArgsAnalyzer {
     #[inline]
     pub fn new() -> Self {
@@ -19,23 +35,103 @@ impl FormatArgsAnalyzer {
     fn analyze_format_macro(mac: &Macro) -> Option<Issue> {
         let format = Self::extract_format_string(mac)?;
         let placeholder_count = Self::count_positional_placeholders(&format);
+        let rewrite = Self::inline_rewrite(mac);
 
-        if placeholder_count >= 3 {
-            let span = mac.span();
-            let start = span.start();
-
-            return Some(Issue {
-                line:    start.line,
-                column:  start.column,
-                message: format!(
-                    "Use named format arguments for better readability ({} placeholders)",
-                    placeholder_count
-                ),
-                fix:     Fix::None
-            });
+        if placeholder_count < 3 && rewrite.is_none() {
+            return None;
         }
 
-        None
+        let start = mac.span().start();
+        let message = if placeholder_count >= 3 {
+            format!(
+                "Use named format arguments for better readability ({} placeholders)",
+                placeholder_count
+            )
+        } else {
+            "Use inline format arguments instead of positional ones".to_string()
+        };
+
+        Some(Issue {
+            line: start.line,
+            column: start.column,
+            message,
+            fix: rewrite.map_or(Fix::None, |r| Fix::Simple(r.invocation))
+        })
+    }
+
+    /// Build the inline-argument rewrite for a format macro.
+    ///
+    /// The rewrite is produced only when all of these hold:
+    /// - every positional placeholder is implicit (`{}` or `{:spec}`), with no
+    ///   indexed (`{0}`) placeholders and no `$`/`*` width or precision
+    ///   references
+    /// - there is exactly one positional argument per placeholder
+    /// - every positional argument is a single identifier other than `self` and
+    ///   raw identifiers (`r#type`), which format strings cannot name
+    /// - the literal has no `\u{...}` escapes, whose braces would be mistaken
+    ///   for placeholders
+    ///
+    /// Named arguments after the positional ones are left untouched.
+    ///
+    /// # Arguments
+    ///
+    /// * `mac` - Format macro invocation
+    ///
+    /// # Returns
+    ///
+    /// `Some(InlineRewrite)` if the macro can be rewritten safely
+    fn inline_rewrite(mac: &Macro) -> Option<InlineRewrite> {
+        let args = split_args(&mac.tokens);
+        let literal_index = args.iter().position(|arg| {
+            matches!(arg.as_slice(), [TokenTree::Literal(lit)]
+                if syn::parse_str::<LitStr>(&lit.to_string()).is_ok())
+        })?;
+        let TokenTree::Literal(literal) = &args[literal_index][0] else {
+            return None;
+        };
+
+        let source = literal.to_string();
+        if source.contains("\\u{") {
+            return None;
+        }
+
+        let positional: Vec<String> = args[literal_index + 1..]
+            .iter()
+            .take_while(|arg| !is_named_arg(arg))
+            .map(|arg| match arg.as_slice() {
+                [TokenTree::Ident(ident)] if ident != "self" => {
+                    Some(ident.to_string()).filter(|name| !name.starts_with("r#"))
+                }
+                _ => None
+            })
+            .collect::<Option<_>>()?;
+
+        if positional.is_empty() {
+            return None;
+        }
+
+        let new_literal = inline_placeholders(&source, &positional)?;
+        let last_arg = args[literal_index + positional.len()].last()?;
+
+        let mut rendered: Vec<String> = args[..literal_index]
+            .iter()
+            .map(|arg| arg.iter().cloned().collect::<TokenStream>().to_string())
+            .collect();
+        rendered.push(new_literal.clone());
+        rendered.extend(
+            args[literal_index + positional.len() + 1..]
+                .iter()
+                .filter(|arg| !arg.is_empty())
+                .map(|arg| arg.iter().cloned().collect::<TokenStream>().to_string())
+        );
+
+        let name = mac.path.segments.last()?.ident.to_string();
+
+        Some(InlineRewrite {
+            range:      literal.span().byte_range().start..last_arg.span().byte_range().end,
+            literal:    new_literal,
+            invocation: format!("{}!({})", name, rendered.join(", "))
+        })
     }
 
     /// Extract the format string literal from macro tokens.
@@ -130,19 +226,41 @@ impl Analyzer for FormatArgsAnalyzer {
 
     fn analyze(&self, ast: &File, _content: &str) -> AppResult<AnalysisResult> {
         let mut visitor = FormatVisitor {
-            issues: Vec::new()
+            issues:      Vec::new(),
+            suggestions: Vec::new()
         };
         syn::visit::visit_file(&mut visitor, ast);
 
+        let fixable_count = visitor
+            .issues
+            .iter()
+            .filter(|issue| issue.fix.is_available())
+            .count();
+
         Ok(AnalysisResult {
-            issues:        visitor.issues,
-            fixable_count: 0
+            issues: visitor.issues,
+            fixable_count
         })
     }
+
+    fn suggestions(&self, ast: &File, _content: &str) -> AppResult<Vec<Suggestion>> {
+        let mut visitor = FormatVisitor {
+            issues:      Vec::new(),
+            suggestions: Vec::new()
+        };
+        syn::visit::visit_file(&mut visitor, ast);
+
+        Ok(visitor.suggestions)
+    }
+
+    fn fixable(&self) -> bool {
+        true
+    }
 }
 
 struct FormatVisitor {
-    issues: Vec<Issue>
+    issues:      Vec<Issue>,
+    suggestions: Vec<Suggestion>
 }
 
 impl<'ast> syn::visit::Visit<'ast> for FormatVisitor {
@@ -168,11 +286,116 @@ impl FormatVisitor {
             || path.is_ident("writeln"))
             && let Some(issue) = FormatArgsAnalyzer::analyze_format_macro(mac)
         {
+            if issue.fix.is_available()
+                && let Some(rewrite) = FormatArgsAnalyzer::inline_rewrite(mac)
+            {
+                self.suggestions.push(Suggestion {
+                    edit:   TextEdit {
+                        range:       rewrite.range,
+                        replacement: rewrite.literal
+                    },
+                    import: None
+                });
+            }
             self.issues.push(issue);
         }
     }
 }
 
+/// Split macro tokens into comma-separated arguments.
+///
+/// Commas inside groups (parentheses, brackets, braces) belong to nested
+/// expressions and do not split. A trailing comma yields a final empty
+/// argument.
+///
+/// # Arguments
+///
+/// * `tokens` - Macro input tokens
+///
+/// # Returns
+///
+/// Token trees of each top-level argument
+fn split_args(tokens: &TokenStream) -> Vec<Vec<TokenTree>> {
+    let mut args = vec![Vec::new()];
+
+    for token in tokens.clone() {
+        match &token {
+            TokenTree::Punct(punct) if punct.as_char() == ',' => args.push(Vec::new()),
+            _ => {
+                if let Some(current) = args.last_mut() {
+                    current.push(token);
+                }
+            }
+        }
+    }
+
+    args
+}
+
+/// Check whether a macro argument is a named argument (`name = expr`).
+fn is_named_arg(arg: &[TokenTree]) -> bool {
+    matches!(
+        arg,
+        [TokenTree::Ident(_), TokenTree::Punct(punct), ..]
+            if punct.as_char() == '=' && punct.spacing() == Spacing::Alone
+    )
+}
+
+/// Insert argument names into the implicit placeholders of a literal.
+///
+/// Works on the literal's source text so escapes and raw-string delimiters are
+/// preserved.
+///
+/// # Arguments
+///
+/// * `source` - Format string literal as written in source
+/// * `names` - Identifier for each implicit placeholder, in order
+///
+/// # Returns
+///
+/// `Some(String)` with the rewritten literal, `None` if the placeholders do not
+/// match the arguments one-to-one
+fn inline_placeholders(source: &str, names: &[String]) -> Option<String> {
+    let bytes = source.as_bytes();
+    let mut output = String::with_capacity(source.len() + names.len() * 8);
+    let mut names = names.iter();
+    let mut index = 0;
+    let mut copied = 0;
+
+    while index < bytes.len() {
+        match bytes[index] {
+            b'{' if bytes.get(index + 1) == Some(&b'{') => index += 2,
+            b'{' => {
+                let close = index + source[index..].find('}')?;
+                let inner = &source[index + 1..close];
+                let (argument, spec) = inner.split_once(':').unwrap_or((inner, ""));
+
+                if !argument.is_empty() && argument.bytes().all(|b| b.is_ascii_digit()) {
+                    return None;
+                }
+                if spec.contains('$') || spec.contains('*') {
+                    return None;
+                }
+
+                if argument.is_empty() {
+                    output.push_str(&source[copied..=index]);
+                    output.push_str(names.next()?);
+                    copied = index + 1;
+                }
+                index = close + 1;
+            }
+            _ => index += 1
+        }
+    }
+
+    if names.next().is_some() {
+        return None;
+    }
+
+    output.push_str(&source[copied..]);
+    Some(output)
+}
+
 impl Default for FormatArgsAnalyzer {
     fn default() -> Self {
         Self::new()
@@ -204,6 +427,86 @@ mod tests {
         assert!(!result.issues.is_empty());
     }
 
+    fn fix(content: &str) -> String {
+        let ast = syn::parse_file(content).unwrap();
+        let suggestions = FormatArgsAnalyzer::new()
+            .suggestions(&ast, content)
+            .unwrap();
+        crate::fixer::apply_suggestions(content, &suggestions).source
+    }
+
+    #[test]
+    fn test_inline_single_identifier() {
+        let output = fix("fn main() {\n    println!(\"Hello {}\", name);\n}\n");
+        assert_eq!(output, "fn main() {\n    println!(\"Hello {name}\");\n}\n");
+    }
+
+    #[test]
+    fn test_inline_keeps_spec_and_named_args() {
+        let output = fix("fn f() {\n    let s = format!(\"{:>8} {x} {:?}\", a, b, x = 1);\n}\n");
+        assert_eq!(
+            output,
+            "fn f() {\n    let s = format!(\"{a:>8} {x} {b:?}\", x = 1);\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_inline_write_macro() {
+        let output = fix("fn f() {\n    writeln!(out, \"{}: {}\", key, value)?;\n}\n");
+        assert_eq!(
+            output,
+            "fn f() {\n    writeln!(out, \"{key}: {value}\")?;\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_leave_complex_expressions() {
+        let content =
+            "fn f() {\n    println!(\"{} {}\", a, b.len());\n    println!(\"{}\", self);\n}\n";
+        assert_eq!(fix(content), content);
+    }
+
+    #[test]
+    fn test_leave_raw_identifiers() {
+        let content = "fn f() {\n    println!(\"{} {}\", name, r#type);\n}\n";
+        assert_eq!(fix(content), content);
+    }
+
+    #[test]
+    fn test_leave_indexed_and_star_precision() {
+        let content =
+            "fn f() {\n    println!(\"{0} {0}\", a);\n    println!(\"{:.*}\", p, v);\n}\n";
+        assert_eq!(fix(content), content);
+    }
+
+    #[test]
+    fn test_inline_issue_carries_simple_fix() {
+        let analyzer = FormatArgsAnalyzer::new();
+        let code: File = parse_quote! {
+            fn main() {
+                println!("{} {} {:?}", a, b, c);
+            }
+        };
+
+        let result = analyzer.analyze(&code, "").unwrap();
+        assert_eq!(result.fixable_count, 1);
+        assert_eq!(
+            result.issues[0].fix.as_simple(),
+            Some("println!(\"{a} {b} {c:?}\")")
+        );
+    }
+
+    #[test]
+    fn test_inline_placeholders_helper() {
+        let names = vec!["a".to_string(), "b".to_string()];
+        assert_eq!(
+            inline_placeholders("\"{{}} {} {:#?}\"", &names),
+            Some("\"{{}} {a} {b:#?}\"".to_string())
+        );
+        assert_eq!(inline_placeholders("\"{}\"", &names), None);
+        assert_eq!(inline_placeholders("\"{:1$}\"", &names[..1]), None);
+    }
+
     #[test]
     fn test_advisory_only_not_fixable() {
         let analyzer = FormatArgsAnalyzer::new();
@@ -367,7 +670,7 @@ mod tests {
         let analyzer = FormatArgsAnalyzer::new();
         let code: File = parse_quote! {
             fn main() {
-                println!("{{}} {{}} {{}} {}", x);
+                println!("{{}} {{}} {{}} {}", x.len());
             }
         };
 
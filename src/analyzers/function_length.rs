@@ -0,0 +1,278 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Function length analyzer for oversized function bodies.
+//!
+//! Long functions mix several responsibilities and are hard to read, test
+//! and review. This analyzer flags functions and methods whose body spans
+//! more than [`MAX_FUNCTION_LINES`] lines, counted from the opening to the
+//! closing brace of the block. Items annotated with the generated code
+//! marker, `#[quality::generated]` by default, are skipped.
+
+use masterror::AppResult;
+use syn::{
+    Block, File, ImplItemFn, Item, ItemFn, ItemMod, Signature, TraitItemFn, spanned::Spanned,
+    visit::Visit
+};
+
+use super::{GENERATED_MARKER, has_marker, is_test_code, test_modules::item_attrs};
+use crate::analyzer::{AnalysisResult, Analyzer, Fix, Issue};
+
+/// Maximum number of lines a function body may span.
+pub const MAX_FUNCTION_LINES: usize = 60;
+
+/// Analyzer for functions and methods longer than [`MAX_FUNCTION_LINES`].
+///
+/// # Examples
+///
+/// Detects this pattern:
+/// ```ignore
+/// fn handle(request: Request) -> Response {
+///     // validation, parsing, storage and rendering
+///     // ... 80 lines ...
+/// }
+/// ```
+///
+/// Suggests splitting it into focused helpers:
+/// ```ignore
+/// fn handle(request: Request) -> Response {
+///     let input = validate(&request)?;
+///     let record = store(parse(input)?)?;
+///     render(record)
+/// }
+/// ```
+pub struct FunctionLengthAnalyzer {
+    generated_marker: String
+}
+
+impl FunctionLengthAnalyzer {
+    /// Create new function length analyzer instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            generated_marker: GENERATED_MARKER.to_string()
+        }
+    }
+
+    /// Use another marker attribute for generated items.
+    ///
+    /// # Arguments
+    ///
+    /// * `marker` - Attribute path, e.g. `"codegen::generated"`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cargo_quality::{analyzer::Analyzer, analyzers::FunctionLengthAnalyzer};
+    ///
+    /// let analyzer = FunctionLengthAnalyzer::new().with_generated_marker("codegen::generated");
+    /// let body = "    step();\n".repeat(70);
+    /// let code = format!("#[codegen::generated]\nfn machine() {{\n{body}}}");
+    /// let ast = syn::parse_file(&code).unwrap();
+    /// assert!(analyzer.analyze(&ast, &code).unwrap().issues.is_empty());
+    /// ```
+    #[must_use]
+    pub fn with_generated_marker(mut self, marker: &str) -> Self {
+        self.generated_marker = marker.to_string();
+        self
+    }
+}
+
+impl Analyzer for FunctionLengthAnalyzer {
+    fn name(&self) -> &'static str {
+        "function_length"
+    }
+
+    fn analyze(&self, ast: &File, _content: &str) -> AppResult<AnalysisResult> {
+        let mut visitor = LengthVisitor {
+            marker: &self.generated_marker,
+            issues: Vec::new()
+        };
+        visitor.visit_file(ast);
+
+        Ok(AnalysisResult {
+            issues:        visitor.issues,
+            fixable_count: 0
+        })
+    }
+
+    fn options(&self) -> &'static [&'static str] {
+        &["generated_marker"]
+    }
+}
+
+impl Default for FunctionLengthAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct LengthVisitor<'a> {
+    /// Marker attribute of generated items
+    marker: &'a str,
+    issues: Vec<Issue>
+}
+
+impl LengthVisitor<'_> {
+    /// Flag a function whose body exceeds [`MAX_FUNCTION_LINES`].
+    fn check_function(&mut self, sig: &Signature, block: &Block) {
+        let span = block.span();
+        let lines = span.end().line - span.start().line + 1;
+        if lines <= MAX_FUNCTION_LINES {
+            return;
+        }
+
+        let start = sig.ident.span().start();
+        self.issues.push(Issue {
+            line:    start.line,
+            column:  start.column,
+            message: format!(
+                "Function `{}` is {lines} lines long (max {MAX_FUNCTION_LINES})\n\
+                 Split it into smaller functions with a single responsibility each",
+                sig.ident
+            ),
+            fix:     Fix::None
+        });
+    }
+}
+
+impl<'ast> Visit<'ast> for LengthVisitor<'_> {
+    fn visit_item(&mut self, node: &'ast Item) {
+        if !has_marker(item_attrs(node), self.marker) {
+            syn::visit::visit_item(self, node);
+        }
+    }
+
+    fn visit_item_fn(&mut self, node: &'ast ItemFn) {
+        if is_test_code(&node.attrs) {
+            return;
+        }
+        self.check_function(&node.sig, &node.block);
+        syn::visit::visit_item_fn(self, node);
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'ast ImplItemFn) {
+        if is_test_code(&node.attrs) || has_marker(&node.attrs, self.marker) {
+            return;
+        }
+        self.check_function(&node.sig, &node.block);
+        syn::visit::visit_impl_item_fn(self, node);
+    }
+
+    fn visit_trait_item_fn(&mut self, node: &'ast TraitItemFn) {
+        if has_marker(&node.attrs, self.marker) {
+            return;
+        }
+        if let Some(block) = &node.default {
+            self.check_function(&node.sig, block);
+        }
+        syn::visit::visit_trait_item_fn(self, node);
+    }
+
+    fn visit_item_mod(&mut self, node: &'ast ItemMod) {
+        if !is_test_code(&node.attrs) {
+            syn::visit::visit_item_mod(self, node);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Build a function whose body spans `lines` lines including braces.
+    fn function(header: &str, lines: usize) -> String {
+        let body = "    let _ = 1;\n".repeat(lines - 2);
+        format!("{header} {{\n{body}}}\n")
+    }
+
+    fn analyze(code: &str) -> AnalysisResult {
+        let ast = syn::parse_file(code).unwrap();
+        FunctionLengthAnalyzer::new().analyze(&ast, code).unwrap()
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        let analyzer = FunctionLengthAnalyzer::new();
+        assert_eq!(analyzer.name(), "function_length");
+    }
+
+    #[test]
+    fn test_detect_long_function() {
+        let code = function("fn long()", MAX_FUNCTION_LINES + 1);
+        let result = analyze(&code);
+
+        assert_eq!(result.issues.len(), 1);
+        assert_eq!(result.issues[0].line, 1);
+        assert!(result.issues[0].message.contains("`long` is 61 lines long"));
+    }
+
+    #[test]
+    fn test_ignore_function_at_threshold() {
+        let code = function("fn short()", MAX_FUNCTION_LINES);
+        let result = analyze(&code);
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_detect_long_methods() {
+        let method = function("    fn run(&self)", MAX_FUNCTION_LINES + 5);
+        let code = format!(
+            "impl Worker {{\n{method}}}\n\ntrait Job {{\n{method}}}\n\nimpl Job for Worker {{\n{}}}\n",
+            function("    fn run(&self)", 3)
+        );
+        let result = analyze(&code);
+
+        assert_eq!(result.issues.len(), 2);
+        assert!(result.issues[0].message.contains("65 lines"));
+    }
+
+    #[test]
+    fn test_ignore_test_code() {
+        let code = format!(
+            "#[cfg(test)]\nmod tests {{\n{}}}\n\n#[test]\n{}",
+            function("fn helper()", MAX_FUNCTION_LINES * 2),
+            function("fn long_test()", MAX_FUNCTION_LINES * 2)
+        );
+        let result = analyze(&code);
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_skip_generated_items() {
+        let long = MAX_FUNCTION_LINES * 2;
+        let code = format!(
+            "#[quality::generated]\n{}\n#[quality::generated]\nimpl Machine {{\n{}}}\n\n\
+             impl Parser {{\n    #[quality::generated]\n{}}}\n\n#[other::generated]\n{}",
+            function("fn step(state: u8)", long),
+            function("    fn run(&self)", long),
+            function("    fn table(&self)", long),
+            function("fn kept()", long)
+        );
+        let result = analyze(&code);
+
+        assert_eq!(result.issues.len(), 1);
+        assert!(result.issues[0].message.contains("`kept`"));
+    }
+
+    #[test]
+    fn test_custom_generated_marker() {
+        let code = format!(
+            "#[codegen::generated]\n{}",
+            function("fn step()", MAX_FUNCTION_LINES * 2)
+        );
+        let ast = syn::parse_file(&code).unwrap();
+        let analyzer = FunctionLengthAnalyzer::new().with_generated_marker("codegen::generated");
+
+        assert!(analyzer.analyze(&ast, &code).unwrap().issues.is_empty());
+        assert_eq!(analyze(&code).issues.len(), 1);
+    }
+
+    #[test]
+    fn test_default_implementation() {
+        let analyzer = FunctionLengthAnalyzer::default();
+        assert_eq!(analyzer.name(), "function_length");
+    }
+}
@@ -0,0 +1,524 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Glob import analyzer for `use path::*;` outside tests and preludes.
+//!
+//! A glob import hides where names come from and can silently pick up new
+//! items when the imported module grows. This analyzer flags globs outside
+//! test code and prelude modules. When the glob targets an enum or inline
+//! module defined in the same file, the fix replaces `*` with the items the
+//! file actually uses.
+//!
+//! Public re-exports (`pub use path::*`) are left to the `wildcard_reexport`
+//! analyzer, as they expose every item rather than import the used ones.
+
+use std::{collections::BTreeSet, ops::Range};
+
+use masterror::AppResult;
+use proc_macro2::{Spacing, TokenStream, TokenTree};
+use syn::{
+    File, Ident, ImplItemFn, Item, ItemEnum, ItemFn, ItemMod, ItemUse, Macro, Pat, PatIdent, Path,
+    UseTree, Visibility, visit::Visit
+};
+
+use super::is_test_code;
+use crate::analyzer::{AnalysisResult, Analyzer, Fix, Issue, Suggestion, TextEdit};
+
+/// Analyzer for glob imports such as `use module::*;`.
+///
+/// # Examples
+///
+/// Detects this pattern:
+/// ```ignore
+/// use Direction::*;
+///
+/// fn turn(d: Direction) -> Direction {
+///     match d { North => East, _ => North }
+/// }
+/// ```
+///
+/// Fixes to:
+/// ```ignore
+/// use Direction::{East, North};
+/// ```
+pub struct GlobImportAnalyzer;
+
+/// Glob import found outside test and prelude code.
+struct Glob {
+    /// Path before the `*`, e.g. `std::collections`
+    path:        String,
+    line:        usize,
+    column:      usize,
+    /// Byte range of the `*` token
+    range:       Range<usize>,
+    /// Name of a file-level item the glob may target (`Name::*` or
+    /// `self::Name::*` outside inline modules)
+    target:      Option<String>,
+    /// Explicit item list replacing `*`, when the target is in this file
+    replacement: Option<String>
+}
+
+impl GlobImportAnalyzer {
+    /// Create new glob import analyzer instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Collect glob imports and resolve the ones targeting local items.
+    fn collect(ast: &File) -> Vec<Glob> {
+        let mut visitor = GlobVisitor {
+            globs:     Vec::new(),
+            mod_depth: 0,
+            prelude:   false
+        };
+        visitor.visit_file(ast);
+
+        for glob in &mut visitor.globs {
+            glob.replacement = glob
+                .target
+                .as_deref()
+                .and_then(|target| explicit_items(ast, target));
+        }
+        visitor.globs
+    }
+}
+
+impl Analyzer for GlobImportAnalyzer {
+    fn name(&self) -> &'static str {
+        "glob_import"
+    }
+
+    fn analyze(&self, ast: &File, _content: &str) -> AppResult<AnalysisResult> {
+        let mut issues = Vec::new();
+        let mut fixable_count = 0;
+
+        for glob in Self::collect(ast) {
+            let path = &glob.path;
+            let (hint, fix) = match glob.replacement {
+                Some(items) => {
+                    fixable_count += 1;
+                    (format!("use `{path}::{items}`"), Fix::Simple(items))
+                }
+                None => (
+                    format!("list the items used from `{path}` explicitly"),
+                    Fix::None
+                )
+            };
+
+            issues.push(Issue {
+                line: glob.line,
+                column: glob.column,
+                message: format!(
+                    "Glob import `use {path}::*` hides where names come from\n\
+                     Enumerate the imported items: {hint}"
+                ),
+                fix
+            });
+        }
+
+        Ok(AnalysisResult {
+            issues,
+            fixable_count
+        })
+    }
+
+    fn suggestions(&self, ast: &File, _content: &str) -> AppResult<Vec<Suggestion>> {
+        Ok(Self::collect(ast)
+            .into_iter()
+            .filter_map(|glob| {
+                Some(Suggestion {
+                    edit:   TextEdit {
+                        range:       glob.range,
+                        replacement: glob.replacement?
+                    },
+                    import: None
+                })
+            })
+            .collect())
+    }
+
+    fn fixable(&self) -> bool {
+        true
+    }
+}
+
+impl Default for GlobImportAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct GlobVisitor {
+    globs:     Vec<Glob>,
+    /// Number of inline modules around the current item
+    mod_depth: usize,
+    /// Inside a module named `prelude`
+    prelude:   bool
+}
+
+impl GlobVisitor {
+    /// Record globs in a use tree below the given path segments.
+    fn walk(&mut self, tree: &UseTree, segments: &mut Vec<String>) {
+        match tree {
+            UseTree::Path(path) => {
+                segments.push(path.ident.to_string());
+                self.walk(&path.tree, segments);
+                segments.pop();
+            }
+            UseTree::Group(group) => {
+                for item in &group.items {
+                    self.walk(item, segments);
+                }
+            }
+            UseTree::Glob(glob) if !segments.iter().any(|s| s == "prelude") => {
+                let start = glob.star_token.span.start();
+                let target = match segments.as_slice() {
+                    [name] => Some(name),
+                    [prefix, name] if prefix == "self" => Some(name),
+                    _ => None
+                }
+                .filter(|_| self.mod_depth == 0)
+                .cloned();
+
+                self.globs.push(Glob {
+                    path: segments.join("::"),
+                    line: start.line,
+                    column: start.column,
+                    range: glob.star_token.span.byte_range(),
+                    target,
+                    replacement: None
+                });
+            }
+            _ => {}
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for GlobVisitor {
+    fn visit_item_fn(&mut self, node: &'ast ItemFn) {
+        if !is_test_code(&node.attrs) {
+            syn::visit::visit_item_fn(self, node);
+        }
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'ast ImplItemFn) {
+        if !is_test_code(&node.attrs) {
+            syn::visit::visit_impl_item_fn(self, node);
+        }
+    }
+
+    fn visit_item_mod(&mut self, node: &'ast ItemMod) {
+        if is_test_code(&node.attrs) {
+            return;
+        }
+
+        let outer = self.prelude;
+        self.prelude |= node.ident == "prelude";
+        self.mod_depth += 1;
+        syn::visit::visit_item_mod(self, node);
+        self.mod_depth -= 1;
+        self.prelude = outer;
+    }
+
+    fn visit_item_use(&mut self, node: &'ast ItemUse) {
+        if !self.prelude && !matches!(node.vis, Visibility::Public(_)) {
+            self.walk(&node.tree, &mut Vec::new());
+        }
+    }
+}
+
+/// Build the explicit item list for a glob over a file-level enum or module.
+///
+/// Traits exported by a module are always kept, since they may only be used
+/// through method calls.
+///
+/// # Returns
+///
+/// `{A, B}` or `A` for the items the file uses, `None` if the target is not
+/// defined in the file or none of its items are used
+fn explicit_items(ast: &File, target: &str) -> Option<String> {
+    let (candidates, traits) = ast.items.iter().find_map(|item| match item {
+        Item::Enum(item) if item.ident == target => Some(enum_variants(item)),
+        Item::Mod(item) if item.ident == target => module_items(item),
+        _ => None
+    })?;
+
+    let mut used = UsageVisitor {
+        target,
+        names: BTreeSet::new()
+    };
+    used.visit_file(ast);
+
+    let items: Vec<_> = candidates
+        .into_iter()
+        .filter(|name| used.names.contains(name) || traits.contains(name))
+        .collect();
+
+    match items.as_slice() {
+        [] => None,
+        [single] => Some(single.clone()),
+        _ => Some(format!("{{{}}}", items.join(", ")))
+    }
+}
+
+/// Variant names of an enum; enums export no traits.
+fn enum_variants(item: &ItemEnum) -> (BTreeSet<String>, BTreeSet<String>) {
+    let variants = item.variants.iter().map(|v| v.ident.to_string()).collect();
+    (variants, BTreeSet::new())
+}
+
+/// Public item names of an inline module, and the traits among them.
+///
+/// # Returns
+///
+/// `None` for `mod name;` declarations whose items live in another file
+fn module_items(item: &ItemMod) -> Option<(BTreeSet<String>, BTreeSet<String>)> {
+    let (_, content) = item.content.as_ref()?;
+    let mut names = BTreeSet::new();
+    let mut traits = BTreeSet::new();
+
+    for item in content {
+        let (vis, ident) = match item {
+            Item::Const(item) => (&item.vis, &item.ident),
+            Item::Enum(item) => (&item.vis, &item.ident),
+            Item::Fn(item) => (&item.vis, &item.sig.ident),
+            Item::Mod(item) => (&item.vis, &item.ident),
+            Item::Static(item) => (&item.vis, &item.ident),
+            Item::Struct(item) => (&item.vis, &item.ident),
+            Item::Type(item) => (&item.vis, &item.ident),
+            Item::Union(item) => (&item.vis, &item.ident),
+            Item::Trait(item) => {
+                if !matches!(item.vis, Visibility::Inherited) {
+                    traits.insert(item.ident.to_string());
+                }
+                (&item.vis, &item.ident)
+            }
+            _ => continue
+        };
+        if !matches!(vis, Visibility::Inherited) {
+            names.insert(ident.to_string());
+        }
+    }
+    Some((names, traits))
+}
+
+/// Collects names used unqualified, which a glob import may provide.
+///
+/// Use statements and the glob's target definition are skipped so the
+/// import and the declarations do not count as uses.
+struct UsageVisitor<'a> {
+    target: &'a str,
+    names:  BTreeSet<String>
+}
+
+impl UsageVisitor<'_> {
+    /// Record identifiers in macro tokens that are not preceded by `::`.
+    fn record_tokens(&mut self, tokens: TokenStream) {
+        let mut after_path_sep = false;
+        for token in tokens {
+            match token {
+                TokenTree::Ident(ident) => {
+                    if !after_path_sep {
+                        self.names.insert(ident.to_string());
+                    }
+                    after_path_sep = false;
+                }
+                TokenTree::Group(group) => {
+                    self.record_tokens(group.stream());
+                    after_path_sep = false;
+                }
+                TokenTree::Punct(punct) => {
+                    after_path_sep = punct.as_char() == ':'
+                        && (after_path_sep || punct.spacing() == Spacing::Joint);
+                }
+                TokenTree::Literal(_) => after_path_sep = false
+            }
+        }
+    }
+
+    fn record(&mut self, ident: &Ident) {
+        self.names.insert(ident.to_string());
+    }
+}
+
+impl<'ast> Visit<'ast> for UsageVisitor<'_> {
+    fn visit_item_use(&mut self, _node: &'ast ItemUse) {}
+
+    fn visit_item_enum(&mut self, node: &'ast ItemEnum) {
+        if node.ident != self.target {
+            syn::visit::visit_item_enum(self, node);
+        }
+    }
+
+    fn visit_item_mod(&mut self, node: &'ast ItemMod) {
+        if node.ident != self.target {
+            syn::visit::visit_item_mod(self, node);
+        }
+    }
+
+    fn visit_path(&mut self, node: &'ast Path) {
+        if node.leading_colon.is_none()
+            && let Some(first) = node.segments.first()
+        {
+            self.record(&first.ident);
+        }
+        syn::visit::visit_path(self, node);
+    }
+
+    fn visit_pat(&mut self, node: &'ast Pat) {
+        if let Pat::Ident(PatIdent {
+            ident,
+            subpat: None,
+            ..
+        }) = node
+        {
+            self.record(ident);
+        }
+        syn::visit::visit_pat(self, node);
+    }
+
+    fn visit_macro(&mut self, node: &'ast Macro) {
+        self.record_tokens(node.tokens.clone());
+        syn::visit::visit_macro(self, node);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analyze(code: &str) -> AnalysisResult {
+        let ast = syn::parse_file(code).unwrap();
+        GlobImportAnalyzer::new().analyze(&ast, code).unwrap()
+    }
+
+    fn fix(code: &str) -> String {
+        let ast = syn::parse_file(code).unwrap();
+        let suggestions = GlobImportAnalyzer::new().suggestions(&ast, code).unwrap();
+        crate::fixer::apply_suggestions(code, &suggestions).source
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        let analyzer = GlobImportAnalyzer::new();
+        assert_eq!(analyzer.name(), "glob_import");
+    }
+
+    #[test]
+    fn test_detect_external_glob() {
+        let result = analyze(
+            r#"
+use std::collections::*;
+use serde::{Deserialize, de::*};
+
+fn run() {
+    use std::io::*;
+}
+
+pub use crate::types::*;
+"#
+        );
+
+        assert_eq!(result.issues.len(), 3);
+        assert_eq!(result.issues[0].line, 2);
+        assert_eq!(result.issues[0].column, 22);
+        assert!(
+            result.issues[0]
+                .message
+                .contains("`use std::collections::*`")
+        );
+        assert!(result.issues[1].message.contains("`use serde::de::*`"));
+        assert_eq!(result.issues[2].fix, Fix::None);
+        assert_eq!(result.fixable_count, 0);
+    }
+
+    #[test]
+    fn test_fix_local_enum_glob() {
+        let code = r#"
+enum Direction { North, East, South, West }
+
+use Direction::*;
+
+fn turn(d: Direction) -> Direction {
+    match d {
+        North => East,
+        _ => { println!("{:?}", South); North }
+    }
+}
+"#;
+        let result = analyze(code);
+
+        assert_eq!(result.fixable_count, 1);
+        assert_eq!(
+            result.issues[0].fix,
+            Fix::Simple("{East, North, South}".to_string())
+        );
+        assert!(fix(code).contains("use Direction::{East, North, South};"));
+    }
+
+    #[test]
+    fn test_fix_local_module_glob_keeps_traits() {
+        let code = r#"
+mod shapes {
+    pub struct Circle;
+    pub struct Square;
+    pub trait Area {}
+    fn private() {}
+}
+
+use self::shapes::*;
+
+fn make() -> Circle {
+    Circle
+}
+"#;
+
+        assert!(fix(code).contains("use self::shapes::{Area, Circle};"));
+    }
+
+    #[test]
+    fn test_fix_single_item_without_braces() {
+        let code = r#"
+enum Mode { Fast, Slow }
+
+fn pick() -> Mode {
+    use Mode::*;
+    Fast
+}
+"#;
+
+        assert!(fix(code).contains("use Mode::Fast;"));
+    }
+
+    #[test]
+    fn test_ignore_preludes_and_tests() {
+        let result = analyze(
+            r#"
+use rayon::prelude::*;
+
+pub mod prelude {
+    pub use crate::analyzers::*;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+}
+
+#[test]
+fn check() {
+    use std::io::*;
+}
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_default_implementation() {
+        let analyzer = GlobImportAnalyzer;
+        assert_eq!(analyzer.name(), "glob_import");
+    }
+}
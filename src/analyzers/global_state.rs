@@ -0,0 +1,236 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Global state analyzer for `static mut` and unsafe `Send`/`Sync` impls.
+//!
+//! Every access to a `static mut` is a potential data race the compiler no
+//! longer rejects, and references to one are undefined behavior as soon as
+//! two of them overlap. An `unsafe impl Send` or `unsafe impl Sync` asserts
+//! thread safety for a type the compiler proved unsafe to share, usually
+//! because it holds raw pointers or `Cell`s. Both are almost always
+//! correctness hazards, so issues are reported with
+//! [`Severity::Error`](crate::analyzer::Severity::Error) and never fixed
+//! automatically.
+
+use masterror::AppResult;
+use proc_macro2::Span;
+use syn::{
+    File, ForeignItemStatic, ImplItemFn, ItemFn, ItemImpl, ItemMod, ItemStatic, StaticMutability,
+    visit::Visit
+};
+
+use super::is_test_code;
+use crate::analyzer::{AnalysisResult, Analyzer, Fix, Issue, Severity};
+
+/// Analyzer for mutable statics and unsafe thread-safety impls.
+///
+/// # Examples
+///
+/// Detects these patterns:
+/// ```ignore
+/// static mut COUNTER: u64 = 0;
+///
+/// unsafe impl Send for Handle {}
+/// ```
+///
+/// Suggests synchronized or thread-local state instead:
+/// ```ignore
+/// static COUNTER: AtomicU64 = AtomicU64::new(0);
+/// ```
+pub struct GlobalStateAnalyzer;
+
+impl GlobalStateAnalyzer {
+    /// Create new global state analyzer instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Analyzer for GlobalStateAnalyzer {
+    fn name(&self) -> &'static str {
+        "global_state"
+    }
+
+    fn analyze(&self, ast: &File, _content: &str) -> AppResult<AnalysisResult> {
+        let mut visitor = GlobalStateVisitor {
+            issues: Vec::new()
+        };
+        visitor.visit_file(ast);
+
+        Ok(AnalysisResult {
+            issues:        visitor.issues,
+            fixable_count: 0
+        })
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
+}
+
+impl Default for GlobalStateAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct GlobalStateVisitor {
+    issues: Vec<Issue>
+}
+
+impl GlobalStateVisitor {
+    fn push(&mut self, span: Span, message: String) {
+        let start = span.start();
+        self.issues.push(Issue {
+            line: start.line,
+            column: start.column,
+            message,
+            fix: Fix::None
+        });
+    }
+
+    fn check_static(&mut self, mutability: &StaticMutability, name: &syn::Ident) {
+        if let StaticMutability::Mut(token) = mutability {
+            self.push(
+                token.span,
+                format!(
+                    "`static mut {name}` can be accessed from several threads without \
+                     synchronization\n\
+                     Use an atomic, a `Mutex`/`RwLock` in a `static`, `OnceLock` or \
+                     `thread_local!` instead"
+                )
+            );
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for GlobalStateVisitor {
+    fn visit_item_static(&mut self, node: &'ast ItemStatic) {
+        self.check_static(&node.mutability, &node.ident);
+        syn::visit::visit_item_static(self, node);
+    }
+
+    fn visit_foreign_item_static(&mut self, node: &'ast ForeignItemStatic) {
+        self.check_static(&node.mutability, &node.ident);
+        syn::visit::visit_foreign_item_static(self, node);
+    }
+
+    fn visit_item_impl(&mut self, node: &'ast ItemImpl) {
+        if is_test_code(&node.attrs) {
+            return;
+        }
+        if let (Some(unsafety), Some((None, path, _))) = (node.unsafety, &node.trait_)
+            && let Some(segment) = path.segments.last()
+            && (segment.ident == "Send" || segment.ident == "Sync")
+        {
+            self.push(
+                unsafety.span,
+                format!(
+                    "`unsafe impl {}` asserts thread safety the compiler could not prove\n\
+                     Wrap the non-thread-safe fields in synchronized types so the trait is \
+                     derived automatically, or document why every access is synchronized",
+                    segment.ident
+                )
+            );
+        }
+        syn::visit::visit_item_impl(self, node);
+    }
+
+    fn visit_item_fn(&mut self, node: &'ast ItemFn) {
+        if !is_test_code(&node.attrs) {
+            syn::visit::visit_item_fn(self, node);
+        }
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'ast ImplItemFn) {
+        if !is_test_code(&node.attrs) {
+            syn::visit::visit_impl_item_fn(self, node);
+        }
+    }
+
+    fn visit_item_mod(&mut self, node: &'ast ItemMod) {
+        if !is_test_code(&node.attrs) {
+            syn::visit::visit_item_mod(self, node);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analyze(code: &str) -> AnalysisResult {
+        let ast = syn::parse_file(code).unwrap();
+        GlobalStateAnalyzer::new().analyze(&ast, code).unwrap()
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        let analyzer = GlobalStateAnalyzer::new();
+        assert_eq!(analyzer.name(), "global_state");
+        assert_eq!(analyzer.severity(), Severity::Error);
+    }
+
+    #[test]
+    fn test_detect_global_state() {
+        let result = analyze(
+            r#"
+static mut COUNTER: u64 = 0;
+
+extern "C" {
+    static mut errno: i32;
+}
+
+struct Handle(*mut u8);
+
+unsafe impl Send for Handle {}
+unsafe impl std::marker::Sync for Handle {}
+
+fn bump() {
+    static mut CALLS: u32 = 0;
+}
+"#
+        );
+
+        assert_eq!(result.issues.len(), 5);
+        assert_eq!(result.fixable_count, 0);
+        assert_eq!(result.issues[0].line, 2);
+        assert_eq!(result.issues[0].column, 7);
+        assert!(
+            result.issues[0]
+                .message
+                .starts_with("`static mut COUNTER` can be accessed from several threads")
+        );
+        assert!(result.issues[1].message.contains("`static mut errno`"));
+        assert!(result.issues[2].message.starts_with("`unsafe impl Send`"));
+        assert!(result.issues[3].message.starts_with("`unsafe impl Sync`"));
+        assert!(result.issues[4].message.contains("`static mut CALLS`"));
+    }
+
+    #[test]
+    fn test_ignore_safe_state() {
+        let result = analyze(
+            r#"
+static COUNTER: AtomicU64 = AtomicU64::new(0);
+static NAMES: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+unsafe impl GlobalAlloc for Allocator {}
+impl Send for Marker {}
+
+#[cfg(test)]
+mod tests {
+    static mut FIXTURE: u8 = 0;
+}
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_default_implementation() {
+        let analyzer = GlobalStateAnalyzer;
+        assert_eq!(analyzer.name(), "global_state");
+    }
+}
@@ -0,0 +1,395 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Hardcoded paths analyzer for machine-specific paths and plain HTTP URLs.
+//!
+//! Absolute paths such as `/home/alice/data` or `C:\Users\...` only exist on
+//! the developer's machine, and hardcoded `http://` endpoints pin deployments
+//! to one unencrypted host. This analyzer flags both in string literals
+//! outside test code, including literals inside macro invocations like
+//! `format!`. Doc comments, test code and files under `tests/`, `examples/`,
+//! `benches/` and `fixtures/` are allowed, as are example and loopback hosts.
+
+use std::path::{Component, Path};
+
+use masterror::AppResult;
+use proc_macro2::{TokenStream, TokenTree};
+use syn::{
+    Attribute, Expr, File, ImplItemFn, ItemConst, ItemFn, ItemMod, ItemStatic, Lit, LitStr, Macro,
+    visit::Visit
+};
+
+use super::is_test_code;
+use crate::analyzer::{AnalysisResult, Analyzer, Fix, Issue};
+
+/// Top-level directories that mark a Unix path as machine-specific.
+const UNIX_ROOTS: &[&str] = &[
+    "/home/",
+    "/Users/",
+    "/root/",
+    "/tmp/",
+    "/var/",
+    "/opt/",
+    "/mnt/",
+    "/media/",
+    "/srv/",
+    "/Volumes/"
+];
+
+/// Hosts that are placeholders or loopback rather than real endpoints.
+const ALLOWED_HOSTS: &[&str] = &[
+    "localhost",
+    "127.0.0.1",
+    "0.0.0.0",
+    "[::1]",
+    "example.com",
+    "example.org",
+    "example.net",
+    "www.w3.org"
+];
+
+/// Directories holding tests, examples and fixtures.
+const ALLOWED_DIRS: &[&str] = &["tests", "examples", "benches", "fixtures"];
+
+/// Analyzer for hardcoded absolute paths and `http://` URLs.
+///
+/// # Examples
+///
+/// Detects this pattern:
+/// ```ignore
+/// fn load() -> Config {
+///     let raw = fs::read_to_string("/home/alice/app/config.toml")?;
+///     let client = Client::new("http://api.internal.corp");
+/// }
+/// ```
+///
+/// Suggests configuration or named constants:
+/// ```ignore
+/// const CONFIG_FILE: &str = "config.toml";
+///
+/// fn load(settings: &Settings) -> Config {
+///     let raw = fs::read_to_string(settings.dir.join(CONFIG_FILE))?;
+///     let client = Client::new(&settings.api_url);
+/// }
+/// ```
+pub struct HardcodedPathsAnalyzer;
+
+impl HardcodedPathsAnalyzer {
+    /// Create new hardcoded paths analyzer instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Analyzer for HardcodedPathsAnalyzer {
+    fn name(&self) -> &'static str {
+        "hardcoded_paths"
+    }
+
+    fn analyze(&self, ast: &File, _content: &str) -> AppResult<AnalysisResult> {
+        let mut visitor = PathVisitor {
+            issues: Vec::new()
+        };
+        visitor.visit_file(ast);
+
+        Ok(AnalysisResult {
+            issues:        visitor.issues,
+            fixable_count: 0
+        })
+    }
+
+    fn applies_to(&self, path: &Path) -> bool {
+        !path.components().any(|component| {
+            matches!(component, Component::Normal(dir) if ALLOWED_DIRS.iter().any(|name| dir == *name))
+        })
+    }
+}
+
+impl Default for HardcodedPathsAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct PathVisitor {
+    issues: Vec<Issue>
+}
+
+impl PathVisitor {
+    /// Record a literal if it holds an absolute path or plain HTTP URL.
+    fn check(&mut self, lit: &LitStr) {
+        let value = lit.value();
+        let message = if let Some(url) = find_http_url(&value) {
+            format!(
+                "Hardcoded URL `{url}`\n\
+                 Read the endpoint from configuration, and prefer `https://`"
+            )
+        } else if is_absolute_path(&value) {
+            format!(
+                "Hardcoded absolute path `{value}`\n\
+                 Read it from configuration or build it from a named constant relative to a \
+                 configurable base directory"
+            )
+        } else {
+            return;
+        };
+
+        let start = lit.span().start();
+        self.issues.push(Issue {
+            line: start.line,
+            column: start.column,
+            message,
+            fix: Fix::None
+        });
+    }
+
+    /// Check string literals inside macro tokens, such as `format!` input.
+    fn check_tokens(&mut self, tokens: TokenStream) {
+        for tree in tokens {
+            match tree {
+                TokenTree::Group(group) => self.check_tokens(group.stream()),
+                TokenTree::Literal(literal) => {
+                    if let Ok(lit) = syn::parse2::<LitStr>(TokenTree::Literal(literal).into()) {
+                        self.check(&lit);
+                    }
+                }
+                TokenTree::Ident(_) | TokenTree::Punct(_) => {}
+            }
+        }
+    }
+
+    /// Check a constant's initializer, allowing a plain literal value.
+    ///
+    /// A named constant is the suggested home for a fixed path, so only
+    /// literals nested deeper in the initializer are checked.
+    fn visit_const_expr(&mut self, expr: &Expr) {
+        if !matches!(expr, Expr::Lit(lit) if matches!(lit.lit, Lit::Str(_))) {
+            self.visit_expr(expr);
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for PathVisitor {
+    fn visit_attribute(&mut self, _node: &'ast Attribute) {}
+
+    fn visit_item_fn(&mut self, node: &'ast ItemFn) {
+        if !is_test_code(&node.attrs) {
+            syn::visit::visit_item_fn(self, node);
+        }
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'ast ImplItemFn) {
+        if !is_test_code(&node.attrs) {
+            syn::visit::visit_impl_item_fn(self, node);
+        }
+    }
+
+    fn visit_item_mod(&mut self, node: &'ast ItemMod) {
+        if !is_test_code(&node.attrs) {
+            syn::visit::visit_item_mod(self, node);
+        }
+    }
+
+    fn visit_item_const(&mut self, node: &'ast ItemConst) {
+        self.visit_const_expr(&node.expr);
+    }
+
+    fn visit_item_static(&mut self, node: &'ast ItemStatic) {
+        self.visit_const_expr(&node.expr);
+    }
+
+    fn visit_macro(&mut self, node: &'ast Macro) {
+        self.check_tokens(node.tokens.clone());
+    }
+
+    fn visit_lit_str(&mut self, node: &'ast LitStr) {
+        self.check(node);
+    }
+}
+
+/// Check whether a literal is an absolute, machine-specific path.
+///
+/// Matches Unix paths under well-known top-level directories (`/home/`,
+/// `/Users/`, `/tmp/`, ...) and Windows drive paths (`C:\`, `D:/`). Other
+/// leading slashes are left alone since they are usually URL routes.
+///
+/// # Examples
+///
+/// ```ignore
+/// assert!(is_absolute_path("/home/alice/data.csv"));
+/// assert!(is_absolute_path(r"C:\Users\alice"));
+/// assert!(!is_absolute_path("/api/users"));
+/// ```
+fn is_absolute_path(value: &str) -> bool {
+    let bytes = value.as_bytes();
+    let windows = bytes.len() > 3
+        && bytes[0].is_ascii_alphabetic()
+        && bytes[1] == b':'
+        && matches!(bytes[2], b'\\' | b'/');
+
+    windows || UNIX_ROOTS.iter().any(|root| value.starts_with(root))
+}
+
+/// Find the first `http://` URL whose host is not allow-listed.
+///
+/// Hosts built from format placeholders (`http://{host}`) come from
+/// configuration and are not reported.
+///
+/// # Returns
+///
+/// The URL up to the first whitespace or quote, if one is found
+fn find_http_url(value: &str) -> Option<&str> {
+    value.match_indices("http://").find_map(|(start, scheme)| {
+        let rest = &value[start + scheme.len()..];
+        let host_end = rest.find(['/', ':', '?', '#', ' ']).unwrap_or(rest.len());
+        let host = &rest[..host_end];
+
+        if host.is_empty() || host.contains('{') || is_allowed_host(host) {
+            return None;
+        }
+
+        let url_len = rest
+            .find(|c: char| c.is_whitespace() || c == '"' || c == '\'')
+            .unwrap_or(rest.len());
+        Some(&value[start..start + scheme.len() + url_len])
+    })
+}
+
+/// Check whether a host, or a domain it belongs to, is allow-listed.
+fn is_allowed_host(host: &str) -> bool {
+    let host = host.to_ascii_lowercase();
+    host.ends_with(".localhost")
+        || ALLOWED_HOSTS.iter().any(|allowed| {
+            host == *allowed
+                || host
+                    .strip_suffix(allowed)
+                    .is_some_and(|sub| sub.ends_with('.'))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analyze(code: &str) -> AnalysisResult {
+        let ast = syn::parse_file(code).unwrap();
+        HardcodedPathsAnalyzer::new().analyze(&ast, code).unwrap()
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        let analyzer = HardcodedPathsAnalyzer::new();
+        assert_eq!(analyzer.name(), "hardcoded_paths");
+    }
+
+    #[test]
+    fn test_detect_absolute_paths() {
+        let result = analyze(
+            r#"
+fn load() {
+    let unix = fs::read("/home/alice/app/config.toml");
+    let windows = fs::read(r"C:\Users\alice\config.toml");
+}
+"#
+        );
+
+        assert_eq!(result.issues.len(), 2);
+        assert_eq!(result.issues[0].line, 3);
+        assert!(result.issues[0].message.contains("/home/alice"));
+        assert!(result.issues[1].message.contains("absolute path"));
+    }
+
+    #[test]
+    fn test_detect_http_url_in_macro() {
+        let result = analyze(
+            r#"
+fn endpoint(id: u32) -> String {
+    format!("http://api.internal.corp/users/{id}")
+}
+"#
+        );
+
+        assert_eq!(result.issues.len(), 1);
+        assert!(
+            result.issues[0]
+                .message
+                .contains("`http://api.internal.corp/users/{id}`")
+        );
+    }
+
+    #[test]
+    fn test_ignore_routes_https_and_allowed_hosts() {
+        let result = analyze(
+            r#"
+fn routes() {
+    let route = "/api/users";
+    let secure = "https://api.example.io";
+    let local = "http://localhost:8080/health";
+    let docs = "http://docs.example.com/guide";
+    let ns = "http://www.w3.org/2000/svg";
+    let templated = format!("http://{host}:{port}", host = h, port = p);
+}
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_allow_plain_constants() {
+        let result = analyze(
+            r#"
+const DATA_DIR: &str = "/var/lib/app";
+static PATHS: &[&str] = &["/opt/app/bin"];
+"#
+        );
+
+        assert_eq!(result.issues.len(), 1);
+        assert!(result.issues[0].message.contains("/opt/app/bin"));
+    }
+
+    #[test]
+    fn test_ignore_docs_and_test_code() {
+        let result = analyze(
+            r#"
+/// Reads `/home/user/.config/app.toml` from http://intranet.corp.
+fn documented() {}
+
+#[cfg(test)]
+mod tests {
+    fn fixture() -> &'static str {
+        "/tmp/fixture.json"
+    }
+}
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_applies_to_skips_tests_and_examples() {
+        let analyzer = HardcodedPathsAnalyzer::new();
+        assert!(analyzer.applies_to(Path::new("src/config.rs")));
+        assert!(analyzer.applies_to(Path::new("src/main.rs")));
+        assert!(!analyzer.applies_to(Path::new("tests/integration.rs")));
+        assert!(!analyzer.applies_to(Path::new("examples/demo.rs")));
+        assert!(!analyzer.applies_to(Path::new("src/fixtures/paths.rs")));
+    }
+
+    #[test]
+    fn test_is_allowed_host() {
+        assert!(is_allowed_host("localhost"));
+        assert!(is_allowed_host("api.localhost"));
+        assert!(is_allowed_host("docs.EXAMPLE.com"));
+        assert!(!is_allowed_host("notexample.com"));
+        assert!(!is_allowed_host("api.internal.corp"));
+    }
+
+    #[test]
+    fn test_default_implementation() {
+        let analyzer = HardcodedPathsAnalyzer;
+        assert_eq!(analyzer.name(), "hardcoded_paths");
+    }
+}
@@ -0,0 +1,299 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Impl location analyzer for inherent impls far from their type.
+//!
+//! Reading a type means reading its methods: with the definition in
+//! `src/config.rs` and an `impl Config` block in `src/loader.rs`, neither
+//! file tells the whole story and the second impl is easily missed. This
+//! analyzer indexes the structs, enums and unions of every file in the run
+//! and reports inherent impls living in another file than the type.
+//!
+//! Trait impls are not reported: implementing a trait, including an
+//! extension trait, for a type defined elsewhere is the usual way to extend
+//! it. Types are matched by name, and names defined in several files are
+//! skipped, so the confidence is medium.
+
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    path::PathBuf
+};
+
+use masterror::AppResult;
+use syn::{File, Ident, ItemEnum, ItemImpl, ItemMod, ItemStruct, ItemUnion, Type, visit::Visit};
+
+use super::is_test_code;
+use crate::analyzer::{AnalysisResult, Analyzer, Confidence, Fix, Issue, SourceFile};
+
+/// Analyzer for inherent impls in another file than their type.
+///
+/// # Examples
+///
+/// Detects this pattern:
+/// ```ignore
+/// // src/config.rs
+/// pub struct Config {
+///     pub path: PathBuf
+/// }
+///
+/// // src/loader.rs
+/// impl Config {
+///     pub fn load(&self) -> AppResult<String> {
+///         Ok(fs::read_to_string(&self.path)?)
+///     }
+/// }
+/// ```
+///
+/// Suggests moving the impl to `src/config.rs`, or an extension trait when
+/// the methods belong to the other module.
+pub struct ImplLocationAnalyzer;
+
+impl ImplLocationAnalyzer {
+    /// Create new impl location analyzer instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Analyzer for ImplLocationAnalyzer {
+    fn name(&self) -> &'static str {
+        "impl_location"
+    }
+
+    fn analyze(&self, _ast: &File, _content: &str) -> AppResult<AnalysisResult> {
+        Ok(AnalysisResult::default())
+    }
+
+    fn crate_wide(&self) -> bool {
+        true
+    }
+
+    fn analyze_crate(&self, files: &[&SourceFile]) -> AppResult<Vec<(PathBuf, AnalysisResult)>> {
+        let mut visitor = ImplVisitor {
+            file:        0,
+            definitions: HashMap::new(),
+            impls:       Vec::new()
+        };
+        for (file, source) in files.iter().enumerate() {
+            visitor.file = file;
+            visitor.visit_file(&source.ast);
+        }
+
+        let ImplVisitor {
+            definitions,
+            impls,
+            ..
+        } = visitor;
+        let mut issues: BTreeMap<usize, Vec<Issue>> = BTreeMap::new();
+        for candidate in impls {
+            let name = candidate.ident.to_string();
+            let Some(defined_in) = definitions.get(&name) else {
+                continue;
+            };
+            if defined_in.len() != 1 || defined_in.contains(&candidate.file) {
+                continue;
+            }
+            let Some(&definition) = defined_in.iter().next() else {
+                continue;
+            };
+
+            let start = candidate.ident.span().start();
+            issues.entry(candidate.file).or_default().push(Issue {
+                line:    start.line,
+                column:  start.column,
+                message: format!(
+                    "Inherent impl of `{name}` is far from its definition in {}\nMove the impl \
+                     next to the type, or turn it into an extension trait",
+                    files[definition].path.display()
+                ),
+                fix:     Fix::None
+            });
+        }
+
+        Ok(issues
+            .into_iter()
+            .map(|(file, issues)| {
+                (
+                    files[file].path.clone(),
+                    AnalysisResult {
+                        issues,
+                        fixable_count: 0
+                    }
+                )
+            })
+            .collect())
+    }
+
+    fn confidence(&self) -> Confidence {
+        Confidence::Medium
+    }
+}
+
+impl Default for ImplLocationAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Inherent impl of a type.
+struct Candidate {
+    /// Index of the file
+    file:  usize,
+    /// Name of the implemented type
+    ident: Ident
+}
+
+struct ImplVisitor {
+    /// Index of the file being visited
+    file:        usize,
+    /// Files defining a struct, enum or union of each name
+    definitions: HashMap<String, HashSet<usize>>,
+    /// Inherent impls outside test code
+    impls:       Vec<Candidate>
+}
+
+impl ImplVisitor {
+    fn record_type(&mut self, ident: &Ident) {
+        self.definitions
+            .entry(ident.to_string())
+            .or_default()
+            .insert(self.file);
+    }
+}
+
+impl<'ast> Visit<'ast> for ImplVisitor {
+    fn visit_item_struct(&mut self, node: &'ast ItemStruct) {
+        self.record_type(&node.ident);
+    }
+
+    fn visit_item_enum(&mut self, node: &'ast ItemEnum) {
+        self.record_type(&node.ident);
+    }
+
+    fn visit_item_union(&mut self, node: &'ast ItemUnion) {
+        self.record_type(&node.ident);
+    }
+
+    fn visit_item_impl(&mut self, node: &'ast ItemImpl) {
+        if node.trait_.is_some() || is_test_code(&node.attrs) {
+            return;
+        }
+        if let Type::Path(self_ty) = node.self_ty.as_ref()
+            && self_ty.qself.is_none()
+            && let Some(segment) = self_ty.path.segments.last()
+        {
+            self.impls.push(Candidate {
+                file:  self.file,
+                ident: segment.ident.clone()
+            });
+        }
+    }
+
+    fn visit_item_mod(&mut self, node: &'ast ItemMod) {
+        if !is_test_code(&node.attrs) {
+            syn::visit::visit_item_mod(self, node);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn source(path: &str, code: &str) -> SourceFile {
+        SourceFile {
+            path: PathBuf::from(path),
+            ast:  syn::parse_file(code).unwrap()
+        }
+    }
+
+    fn analyze(sources: &[SourceFile]) -> Vec<(PathBuf, AnalysisResult)> {
+        let files: Vec<&SourceFile> = sources.iter().collect();
+        ImplLocationAnalyzer::new().analyze_crate(&files).unwrap()
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        let analyzer = ImplLocationAnalyzer::new();
+        assert_eq!(analyzer.name(), "impl_location");
+        assert!(analyzer.crate_wide());
+        assert_eq!(analyzer.confidence(), Confidence::Medium);
+    }
+
+    #[test]
+    fn test_detect_impl_in_other_file() {
+        let results = analyze(&[
+            source(
+                "src/config.rs",
+                "pub struct Config;\nimpl Config {\n    fn new() -> Self { Self }\n}"
+            ),
+            source(
+                "src/loader.rs",
+                "use crate::config::Config;\n\nimpl Config {\n    fn load(&self) {}\n}\n\nimpl \
+                 crate::config::Config {}"
+            )
+        ]);
+
+        assert_eq!(results.len(), 1);
+        let (path, loader) = &results[0];
+        assert_eq!(path, &PathBuf::from("src/loader.rs"));
+        assert_eq!(loader.issues.len(), 2);
+        assert_eq!(loader.fixable_count, 0);
+        assert_eq!(loader.issues[0].line, 3);
+        assert_eq!(loader.issues[0].column, 5);
+        assert_eq!(
+            loader.issues[0].message,
+            "Inherent impl of `Config` is far from its definition in src/config.rs\nMove the \
+             impl next to the type, or turn it into an extension trait"
+        );
+        assert_eq!(loader.issues[1].line, 7);
+    }
+
+    #[test]
+    fn test_ignore_trait_impls_and_ambiguous_names() {
+        let results = analyze(&[
+            source("src/config.rs", "pub struct Config;\npub struct Error;"),
+            source("src/io.rs", "pub struct Error;"),
+            source(
+                "src/loader.rs",
+                r#"
+impl fmt::Display for Config {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { Ok(()) }
+}
+
+impl ConfigExt for Config {}
+
+impl Error {
+    fn code(&self) -> u16 { 0 }
+}
+
+impl Unknown {}
+
+#[cfg(test)]
+mod tests {
+    impl super::Config {
+        fn fixture() -> Self { Self }
+    }
+}
+"#
+            )
+        ]);
+
+        assert!(results.is_empty());
+    }
+
+    #[test]
+    fn test_analyze_reports_nothing_per_file() {
+        let analyzer = ImplLocationAnalyzer;
+        let code = "impl Config {}";
+        let ast = syn::parse_file(code).unwrap();
+        assert!(analyzer.analyze(&ast, code).unwrap().issues.is_empty());
+    }
+
+    #[test]
+    fn test_default_implementation() {
+        let analyzer = ImplLocationAnalyzer;
+        assert_eq!(analyzer.name(), "impl_location");
+    }
+}
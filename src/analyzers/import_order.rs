@@ -0,0 +1,377 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Import order analyzer for `use` grouping and sorting.
+//!
+//! The formatter config uses `group_imports = "StdExternalCrate"`, which only
+//! nightly rustfmt applies. This analyzer checks the same layout on stable:
+//! within each block of consecutive `use` items, `std`/`core`/`alloc`
+//! imports come first, then external crates, then `self`/`super`/`crate`,
+//! with a blank line between groups and each group sorted.
+
+use std::cmp::Ordering;
+
+use masterror::AppResult;
+use syn::{Block, File, Item, ItemMod, ItemUse, Stmt, UseTree, spanned::Spanned, visit::Visit};
+
+use crate::analyzer::{AnalysisResult, Analyzer, Fix, Issue};
+
+/// Crates forming the standard library group.
+const STD_CRATES: &[&str] = &["std", "core", "alloc"];
+
+/// Analyzer for `use` items out of `StdExternalCrate` order.
+///
+/// # Examples
+///
+/// Detects this pattern:
+/// ```ignore
+/// use serde::Deserialize;
+/// use std::fs;
+/// use crate::config::Config;
+/// ```
+///
+/// Suggests:
+/// ```ignore
+/// use std::fs;
+///
+/// use serde::Deserialize;
+///
+/// use crate::config::Config;
+/// ```
+pub struct ImportOrderAnalyzer;
+
+impl ImportOrderAnalyzer {
+    /// Create new import order analyzer instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Analyzer for ImportOrderAnalyzer {
+    fn name(&self) -> &'static str {
+        "import_order"
+    }
+
+    fn analyze(&self, ast: &File, content: &str) -> AppResult<AnalysisResult> {
+        let mut visitor = OrderVisitor {
+            issues: Vec::new(),
+            lines:  content.lines().collect()
+        };
+        visitor.visit_file(ast);
+
+        Ok(AnalysisResult {
+            issues:        visitor.issues,
+            fixable_count: 0
+        })
+    }
+}
+
+impl Default for ImportOrderAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Import group in `StdExternalCrate` order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum Group {
+    Std,
+    External,
+    Crate
+}
+
+impl Group {
+    fn label(self) -> &'static str {
+        match self {
+            Self::Std => "std",
+            Self::External => "external",
+            Self::Crate => "crate"
+        }
+    }
+}
+
+/// One `use` item reduced to what ordering needs.
+pub(crate) struct Import {
+    pub(crate) group: Group,
+    /// Path segments, the last one being a name, `*` or `{..}`
+    segments:         Vec<String>,
+    line:             usize,
+    column:           usize,
+    end_line:         usize
+}
+
+impl Import {
+    pub(crate) fn new(item: &ItemUse) -> Self {
+        let mut segments = Vec::new();
+        let mut tree = &item.tree;
+        loop {
+            match tree {
+                UseTree::Path(path) => {
+                    segments.push(path.ident.to_string());
+                    tree = &path.tree;
+                }
+                UseTree::Name(name) => break segments.push(name.ident.to_string()),
+                UseTree::Rename(rename) => break segments.push(rename.ident.to_string()),
+                UseTree::Glob(_) => break segments.push("*".to_string()),
+                UseTree::Group(_) => break segments.push("{..}".to_string())
+            }
+        }
+
+        let root = segments[0].as_str();
+        let group = match root {
+            "self" | "super" | "crate" => Group::Crate,
+            _ if STD_CRATES.contains(&root) => Group::Std,
+            _ => Group::External
+        };
+        let start = item.span().start();
+
+        Self {
+            group,
+            segments,
+            line: start.line,
+            column: start.column,
+            end_line: item.span().end().line
+        }
+    }
+
+    /// Compare like rustfmt: `self`, `super` and `crate` lead, then by path.
+    pub(crate) fn sort_cmp(&self, other: &Self) -> Ordering {
+        let rank = |import: &Self| match import.segments[0].as_str() {
+            "self" => 0,
+            "super" => 1,
+            "crate" => 2,
+            _ => 3
+        };
+        rank(self)
+            .cmp(&rank(other))
+            .then_with(|| self.segments.cmp(&other.segments))
+    }
+
+    fn path(&self) -> String {
+        self.segments.join("::")
+    }
+}
+
+struct OrderVisitor<'a> {
+    issues: Vec<Issue>,
+    lines:  Vec<&'a str>
+}
+
+impl OrderVisitor<'_> {
+    /// Split a list of items into blocks of consecutive `use` items.
+    ///
+    /// Attributed imports (e.g. `#[cfg(..)] use`) end a block, since their
+    /// placement is usually deliberate.
+    fn check_items<'ast>(&mut self, items: impl IntoIterator<Item = Option<&'ast ItemUse>>) {
+        let mut block = Vec::new();
+        for item in items {
+            match item {
+                Some(item) if item.attrs.is_empty() => block.push(Import::new(item)),
+                _ => self.check_block(std::mem::take(&mut block))
+            }
+        }
+        self.check_block(block);
+    }
+
+    /// Report the first ordering problem of each import in a block.
+    fn check_block(&mut self, block: Vec<Import>) {
+        let mut highest = Group::Std;
+        for pair in block.windows(2) {
+            let (prev, import) = (&pair[0], &pair[1]);
+            highest = highest.max(prev.group);
+
+            let message = if import.group < highest {
+                format!(
+                    "Import `{}` ({}) comes after {} imports\n\
+                     Order import groups as std, external, then crate (`self`/`super`/`crate`)",
+                    import.path(),
+                    import.group.label(),
+                    highest.label()
+                )
+            } else if import.group > prev.group && !self.blank_between(prev, import) {
+                format!(
+                    "Missing blank line between {} and {} imports before `{}`\n\
+                     Separate import groups with an empty line",
+                    prev.group.label(),
+                    import.group.label(),
+                    import.path()
+                )
+            } else if import.group == prev.group && import.sort_cmp(prev) == Ordering::Less {
+                format!(
+                    "Import `{}` should come before `{}`\n\
+                     Sort imports alphabetically within each group",
+                    import.path(),
+                    prev.path()
+                )
+            } else {
+                continue;
+            };
+
+            self.issues.push(Issue {
+                line: import.line,
+                column: import.column,
+                message,
+                fix: Fix::None
+            });
+        }
+    }
+
+    /// Check whether an empty line separates two imports.
+    fn blank_between(&self, prev: &Import, next: &Import) -> bool {
+        self.lines
+            .get(prev.end_line..next.line.saturating_sub(1))
+            .is_some_and(|lines| lines.iter().any(|line| line.trim().is_empty()))
+    }
+}
+
+impl<'ast> Visit<'ast> for OrderVisitor<'_> {
+    fn visit_file(&mut self, node: &'ast File) {
+        self.check_items(node.items.iter().map(use_item));
+        syn::visit::visit_file(self, node);
+    }
+
+    fn visit_item_mod(&mut self, node: &'ast ItemMod) {
+        if let Some((_, items)) = &node.content {
+            self.check_items(items.iter().map(use_item));
+        }
+        syn::visit::visit_item_mod(self, node);
+    }
+
+    fn visit_block(&mut self, node: &'ast Block) {
+        self.check_items(node.stmts.iter().map(|stmt| match stmt {
+            Stmt::Item(item) => use_item(item),
+            _ => None
+        }));
+        syn::visit::visit_block(self, node);
+    }
+}
+
+/// Get the `use` item an item is, if any.
+fn use_item(item: &Item) -> Option<&ItemUse> {
+    match item {
+        Item::Use(item) => Some(item),
+        _ => None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analyze(code: &str) -> AnalysisResult {
+        let ast = syn::parse_file(code).unwrap();
+        ImportOrderAnalyzer::new().analyze(&ast, code).unwrap()
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        let analyzer = ImportOrderAnalyzer::new();
+        assert_eq!(analyzer.name(), "import_order");
+    }
+
+    #[test]
+    fn test_accept_grouped_and_sorted() {
+        let result = analyze(
+            r#"
+use core::fmt;
+use std::{collections::HashMap, fs};
+
+use masterror::AppResult;
+use syn::{File, visit::Visit};
+
+use super::is_test_code;
+use crate::analyzer::Issue;
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_detect_group_out_of_order() {
+        let result = analyze(
+            r#"
+use serde::Deserialize;
+
+use std::fs;
+
+use crate::config::Config;
+"#
+        );
+
+        assert_eq!(result.issues.len(), 1);
+        assert_eq!(result.issues[0].line, 4);
+        assert!(
+            result.issues[0]
+                .message
+                .contains("`std::fs` (std) comes after external imports")
+        );
+    }
+
+    #[test]
+    fn test_detect_missing_blank_line() {
+        let result = analyze(
+            r#"
+mod config {
+    use std::path::PathBuf;
+    use toml::Table;
+
+    use crate::error::Error;
+}
+"#
+        );
+
+        assert_eq!(result.issues.len(), 1);
+        assert_eq!(result.issues[0].line, 4);
+        assert!(
+            result.issues[0]
+                .message
+                .contains("between std and external imports before `toml::Table`")
+        );
+    }
+
+    #[test]
+    fn test_detect_unsorted_in_function() {
+        let result = analyze(
+            r#"
+fn run() {
+    use syn::File;
+    use quote::quote;
+}
+"#
+        );
+
+        assert_eq!(result.issues.len(), 1);
+        assert!(
+            result.issues[0]
+                .message
+                .contains("`quote::quote` should come before `syn::File`")
+        );
+    }
+
+    #[test]
+    fn test_blocks_split_by_items_and_attributes() {
+        let result = analyze(
+            r#"
+use crate::a::A;
+
+mod inner {}
+
+use std::fs;
+
+#[cfg(test)]
+use proptest::prelude::*;
+use anyhow::Result;
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_default_implementation() {
+        let analyzer = ImportOrderAnalyzer;
+        assert_eq!(analyzer.name(), "import_order");
+    }
+}
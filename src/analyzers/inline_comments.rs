@@ -10,14 +10,17 @@
 use std::collections::HashSet;
 
 use masterror::AppResult;
-use syn::{File, ImplItem, Item, ItemFn, ItemImpl, spanned::Spanned, visit::Visit};
+use syn::{Attribute, File, ImplItemFn, ItemFn, spanned::Spanned, visit::Visit};
 
-use crate::analyzer::{AnalysisResult, Analyzer, Fix, Issue};
+use super::{line_ending, literal_ranges};
+use crate::analyzer::{AnalysisResult, Analyzer, Fix, Issue, Suggestion, TextEdit};
 
 /// Analyzer for detecting inline comments inside functions and methods.
 ///
 /// Finds non-doc comments within function bodies and suggests moving them
-/// to the function's doc block `# Notes` section with code context.
+/// to the function's doc block `# Notes` section with code context. The fix
+/// removes each comment line and appends the notes to an existing `# Notes`
+/// section, or creates one at the end of the doc block.
 ///
 /// # Examples
 ///
@@ -30,13 +33,13 @@ use crate::analyzer::{AnalysisResult, Analyzer, Fix, Issue};
 /// }
 /// ```
 ///
-/// Suggests adding to doc block:
+/// Fixes to:
 /// ```ignore
 /// /// Calculate something
 /// ///
 /// /// # Notes
 /// ///
-/// /// - Line 3: `let y = transform(x);` - Process the data
+/// /// - Process the data - `let y = transform(x);`
 /// fn calculate() {
 ///     let x = read_data();
 ///     let y = transform(x);
@@ -44,6 +47,31 @@ use crate::analyzer::{AnalysisResult, Analyzer, Fix, Issue};
 /// ```
 pub struct InlineCommentsAnalyzer;
 
+/// Inline comment found inside a function body.
+struct InlineComment<'a> {
+    line: usize,
+    text: &'a str,
+    code: Option<&'a str>
+}
+
+impl InlineComment<'_> {
+    /// Render the comment as a `# Notes` list item, without the `///` prefix.
+    fn note(&self) -> String {
+        match self.code {
+            Some(code) => format!("- {} - `{}`", self.text, code.trim()),
+            None => format!("- {}", self.text)
+        }
+    }
+}
+
+/// Function or method whose body is scanned for inline comments.
+struct FunctionBody<'ast> {
+    attrs:      &'ast [Attribute],
+    item_line:  usize,
+    start_line: usize,
+    end_line:   usize
+}
+
 impl InlineCommentsAnalyzer {
     /// Create new inline comments analyzer instance.
     #[inline]
@@ -51,34 +79,29 @@ impl InlineCommentsAnalyzer {
         Self
     }
 
-    /// Check function body for inline comments.
-    ///
-    /// Analyzes source code to find inline comments within function boundaries
-    /// and creates issues with suggestions to move them to doc blocks.
+    /// Collect inline comments within function boundaries.
     ///
     /// # Arguments
     ///
     /// * `start_line` - First line of function body
     /// * `end_line` - Last line of function body
     /// * `lines` - Source code split into lines
+    /// * `claimed` - Lines already attributed to another function, extended
+    ///   with the lines returned
     ///
     /// # Returns
     ///
-    /// Vector of issues found
-    fn check_block(
+    /// Comments found, in source order
+    fn collect_comments<'a>(
         start_line: usize,
         end_line: usize,
-        lines: &[&str],
-        excluded: &HashSet<usize>
-    ) -> Vec<Issue> {
-        let mut issues = Vec::new();
-
-        if start_line >= end_line {
-            return issues;
-        }
+        lines: &[&'a str],
+        claimed: &mut HashSet<usize>
+    ) -> Vec<InlineComment<'a>> {
+        let mut comments = Vec::new();
 
         for line_num in start_line..end_line {
-            if excluded.contains(&line_num) {
+            if claimed.contains(&line_num) {
                 continue;
             }
 
@@ -91,30 +114,16 @@ impl InlineCommentsAnalyzer {
             let trimmed = line.trim();
 
             if trimmed.starts_with("//") && !trimmed.starts_with("///") {
-                let comment_text = trimmed.trim_start_matches("//").trim();
-
-                let code_line = Self::find_related_code_line(lines, idx);
-
-                let suggestion = if let Some((_code_idx, code)) = code_line {
-                    format!(
-                        "Move to doc block # Notes section:\n/// - {} - `{}`",
-                        comment_text,
-                        code.trim()
-                    )
-                } else {
-                    format!("Move to doc block # Notes section:\n/// - {}", comment_text)
-                };
-
-                issues.push(Issue {
-                    line:    line_num,
-                    column:  1,
-                    message: format!("Inline comment found: \"{}\"\n{}", comment_text, suggestion),
-                    fix:     Fix::None
+                claimed.insert(line_num);
+                comments.push(InlineComment {
+                    line: line_num,
+                    text: trimmed.trim_start_matches("//").trim(),
+                    code: Self::find_related_code_line(lines, idx).map(|(_, code)| code)
                 });
             }
         }
 
-        issues
+        comments
     }
 
     /// Find the code line that this comment describes.
@@ -148,44 +157,42 @@ impl InlineCommentsAnalyzer {
         None
     }
 
-    /// Check standalone function for inline comments.
+    /// Collect functions and methods with the comments found in each.
+    ///
+    /// Nested functions own the comments in their own bodies, so a comment
+    /// is reported and moved once.
     ///
     /// # Arguments
     ///
-    /// * `func` - Function item to analyze
+    /// * `ast` - Parsed file
+    /// * `content` - Source code
     /// * `lines` - Source code split into lines
-    fn check_function(func: &ItemFn, lines: &[&str], excluded: &HashSet<usize>) -> Vec<Issue> {
-        let span = func.block.span();
-        let start_line = span.start().line;
-        let end_line = span.end().line;
-
-        Self::check_block(start_line, end_line, lines, excluded)
-    }
-
-    /// Check impl block methods for inline comments.
     ///
-    /// # Arguments
+    /// # Returns
     ///
-    /// * `impl_block` - Impl block to analyze
-    /// * `lines` - Source code split into lines
-    fn check_impl_block(
-        impl_block: &ItemImpl,
-        lines: &[&str],
-        excluded: &HashSet<usize>
-    ) -> Vec<Issue> {
-        let mut issues = Vec::new();
-
-        for item in &impl_block.items {
-            if let ImplItem::Fn(method) = item {
-                let span = method.block.span();
-                let start_line = span.start().line;
-                let end_line = span.end().line;
-
-                issues.extend(Self::check_block(start_line, end_line, lines, excluded));
-            }
-        }
+    /// Pairs of function and its comments, innermost functions first
+    fn scan<'ast, 'a>(
+        ast: &'ast File,
+        content: &str,
+        lines: &[&'a str]
+    ) -> Vec<(FunctionBody<'ast>, Vec<InlineComment<'a>>)> {
+        let mut visitor = FunctionVisitor {
+            functions: Vec::new()
+        };
+        visitor.visit_file(ast);
 
-        issues
+        let mut claimed = literal_lines(content);
+        visitor
+            .functions
+            .into_iter()
+            .rev()
+            .map(|func| {
+                let comments =
+                    Self::collect_comments(func.start_line, func.end_line, lines, &mut claimed);
+                (func, comments)
+            })
+            .filter(|(_, comments)| !comments.is_empty())
+            .collect()
     }
 }
 
@@ -196,46 +203,109 @@ impl Analyzer for InlineCommentsAnalyzer {
 
     fn analyze(&self, ast: &File, content: &str) -> AppResult<AnalysisResult> {
         let lines: Vec<&str> = content.lines().collect();
-        let excluded = crate::analyzers::multiline_literal_lines(ast);
-        let mut visitor = FunctionVisitor {
-            issues:   Vec::new(),
-            lines:    &lines,
-            excluded: &excluded
-        };
-        visitor.visit_file(ast);
+        let mut issues = Vec::new();
+        let mut fixable_count = 0;
+
+        for (func, comments) in Self::scan(ast, content, &lines) {
+            let fixable = doc_lines(func.attrs, &lines).is_some();
+
+            for comment in comments {
+                let note = comment.note();
+                let fix = if fixable {
+                    fixable_count += 1;
+                    Fix::Simple(format!("/// {}", note))
+                } else {
+                    Fix::None
+                };
+
+                issues.push(Issue {
+                    line: comment.line,
+                    column: 1,
+                    message: format!(
+                        "Inline comment found: \"{}\"\nMove to doc block # Notes section:\n/// {}",
+                        comment.text, note
+                    ),
+                    fix
+                });
+            }
+        }
+
+        issues.sort_by_key(|issue| issue.line);
 
         Ok(AnalysisResult {
-            issues:        visitor.issues,
-            fixable_count: 0
+            issues,
+            fixable_count
         })
     }
-}
 
-struct FunctionVisitor<'a> {
-    issues:   Vec<Issue>,
-    lines:    &'a [&'a str],
-    excluded: &'a HashSet<usize>
-}
+    fn suggestions(&self, ast: &File, content: &str) -> AppResult<Vec<Suggestion>> {
+        let lines: Vec<&str> = content.lines().collect();
+        let starts = line_starts(content);
+        let newline = line_ending(content);
+        let mut suggestions = Vec::new();
+
+        for (func, comments) in Self::scan(ast, content, &lines) {
+            let Some(docs) = doc_lines(func.attrs, &lines) else {
+                continue;
+            };
 
-impl<'ast, 'a> Visit<'ast> for FunctionVisitor<'a> {
-    fn visit_item(&mut self, node: &'ast Item) {
-        match node {
-            Item::Fn(func) => {
-                let func_issues =
-                    InlineCommentsAnalyzer::check_function(func, self.lines, self.excluded);
-                self.issues.extend(func_issues);
+            let notes: Vec<String> = comments
+                .iter()
+                .filter(|comment| !comment.text.is_empty())
+                .map(InlineComment::note)
+                .collect();
+            if !notes.is_empty() {
+                suggestions.push(Suggestion {
+                    edit:   notes_edit(&docs, func.item_line, &notes, &lines, &starts, newline),
+                    import: None
+                });
             }
-            Item::Impl(impl_block) => {
-                let impl_issues = InlineCommentsAnalyzer::check_impl_block(
-                    impl_block,
-                    self.lines,
-                    self.excluded
-                );
-                self.issues.extend(impl_issues);
+
+            for comment in &comments {
+                suggestions.push(Suggestion {
+                    edit:   TextEdit {
+                        range:       starts[comment.line - 1]..starts[comment.line],
+                        replacement: String::new()
+                    },
+                    import: None
+                });
             }
-            _ => {}
         }
-        syn::visit::visit_item(self, node);
+
+        Ok(suggestions)
+    }
+
+    fn fixable(&self) -> bool {
+        true
+    }
+}
+
+struct FunctionVisitor<'ast> {
+    functions: Vec<FunctionBody<'ast>>
+}
+
+impl<'ast> FunctionVisitor<'ast> {
+    /// Record a function body.
+    fn push(&mut self, attrs: &'ast [Attribute], item_line: usize, block: &syn::Block) {
+        let span = block.span();
+        self.functions.push(FunctionBody {
+            attrs,
+            item_line,
+            start_line: span.start().line,
+            end_line: span.end().line
+        });
+    }
+}
+
+impl<'ast> Visit<'ast> for FunctionVisitor<'ast> {
+    fn visit_item_fn(&mut self, node: &'ast ItemFn) {
+        self.push(&node.attrs, node.span().start().line, &node.block);
+        syn::visit::visit_item_fn(self, node);
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'ast ImplItemFn) {
+        self.push(&node.attrs, node.span().start().line, &node.block);
+        syn::visit::visit_impl_item_fn(self, node);
     }
 }
 
@@ -245,6 +315,139 @@ impl Default for InlineCommentsAnalyzer {
     }
 }
 
+/// Get the lines of a function's `///` doc comments.
+///
+/// # Arguments
+///
+/// * `attrs` - Function attributes
+/// * `lines` - Source code split into lines
+///
+/// # Returns
+///
+/// Line numbers of the doc comments in order, or `None` if the docs use
+/// `#[doc = ...]` or block comments and cannot be edited line by line
+fn doc_lines(attrs: &[Attribute], lines: &[&str]) -> Option<Vec<usize>> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .map(|attr| {
+            let line = attr.span().start().line;
+            let text = lines.get(line.checked_sub(1)?)?;
+            text.trim_start().starts_with("///").then_some(line)
+        })
+        .collect()
+}
+
+/// Get the text of a `///` doc line without the marker.
+fn doc_text<'a>(lines: &[&'a str], line: usize) -> &'a str {
+    lines[line - 1]
+        .trim_start()
+        .trim_start_matches("///")
+        .trim()
+}
+
+/// Lines continuing a string literal, including literals inside macros.
+///
+/// # Returns
+///
+/// Set of 1-based line numbers after the first line of a literal
+fn literal_lines(content: &str) -> HashSet<usize> {
+    let starts = line_starts(content);
+    let line_of = |offset: usize| starts.partition_point(|&start| start <= offset);
+
+    literal_ranges(content)
+        .into_iter()
+        .flat_map(|range| line_of(range.start) + 1..=line_of(range.end))
+        .collect()
+}
+
+/// Compute the byte offset of the start of every line.
+///
+/// # Returns
+///
+/// Offsets of each line start, followed by the content length
+fn line_starts(content: &str) -> Vec<usize> {
+    let mut starts = vec![0];
+    starts.extend(
+        content
+            .match_indices('\n')
+            .map(|(idx, _)| idx + 1)
+            .filter(|&idx| idx < content.len())
+    );
+    starts.push(content.len());
+    starts
+}
+
+/// Build the edit adding notes to a function's doc block.
+///
+/// Notes are appended to the last non-empty line of an existing `# Notes`
+/// section; otherwise a `# Notes` section is added after the last doc line,
+/// or above the function when it has no docs.
+///
+/// # Arguments
+///
+/// * `docs` - Lines of the function's `///` comments
+/// * `item_line` - First line of the function item, including attributes
+/// * `notes` - Notes to add, without the `///` prefix
+/// * `lines` - Source code split into lines
+/// * `starts` - Line start offsets from [`line_starts`]
+/// * `newline` - Line ending of the file, from [`line_ending`]
+///
+/// # Returns
+///
+/// Insertion edit
+fn notes_edit(
+    docs: &[usize],
+    item_line: usize,
+    notes: &[String],
+    lines: &[&str],
+    starts: &[usize],
+    newline: &str
+) -> TextEdit {
+    let anchor = docs.first().copied().unwrap_or(item_line);
+    let line = lines[anchor - 1];
+    let indent = &line[..line.len() - line.trim_start().len()];
+    let bullets: String = notes
+        .iter()
+        .map(|note| format!("{indent}/// {note}{newline}"))
+        .collect();
+
+    let heading = docs
+        .iter()
+        .position(|&line| doc_text(lines, line) == "# Notes");
+
+    let (after, replacement) = match (heading, docs.last()) {
+        (Some(heading), _) => {
+            let section = docs[heading + 1..]
+                .iter()
+                .take_while(|&&line| !doc_text(lines, line).starts_with('#'));
+            match section
+                .filter(|&&line| !doc_text(lines, line).is_empty())
+                .last()
+            {
+                Some(&last) => (last, bullets),
+                None => (docs[heading], format!("{indent}///{newline}{bullets}"))
+            }
+        }
+        (None, Some(&last)) => (
+            last,
+            format!(
+                "{indent}///{newline}{indent}/// # Notes{newline}{indent}///{newline}{bullets}"
+            )
+        ),
+        (None, None) => (
+            item_line - 1,
+            format!("{indent}/// # Notes{newline}{indent}///{newline}{bullets}")
+        )
+    };
+
+    let offset = starts[after];
+    TextEdit {
+        range: offset..offset,
+        replacement
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -266,6 +469,19 @@ mod tests {
         assert_eq!(result.issues.len(), 0);
     }
 
+    #[test]
+    fn test_ignore_double_slash_inside_macro_and_raw_string() {
+        let content = "/// Render.\nfn f() -> String {\n    let s = r#\"\n// Accessors\n\"#;\n    \
+                       format!(\"a\\n\\\n// not a comment\\n{s}\")\n}\n";
+        let code = syn::parse_str(content).unwrap();
+
+        let result = InlineCommentsAnalyzer::new()
+            .analyze(&code, content)
+            .unwrap();
+        assert!(result.issues.is_empty());
+        assert_eq!(fix(content), content);
+    }
+
     #[test]
     fn test_detect_inline_comment_in_function() {
         let analyzer = InlineCommentsAnalyzer::new();
@@ -381,26 +597,137 @@ impl Foo {
     }
 
     #[test]
-    fn test_fixable_count_is_zero() {
+    fn test_fixable_count() {
         let analyzer = InlineCommentsAnalyzer::new();
         let content = r#"fn main() {
     // Comment
     let x = 1;
+}
+
+#[doc = "Generated docs"]
+fn generated() {
+    // Comment
+    let y = 2;
 }"#;
         let code = syn::parse_str(content).unwrap();
 
         let result = analyzer.analyze(&code, content).unwrap();
-        assert_eq!(result.fixable_count, 0);
+        assert_eq!(result.issues.len(), 2);
+        assert_eq!(result.fixable_count, 1);
+        assert!(matches!(
+            &result.issues[0].fix,
+            Fix::Simple(note) if note == "/// - Comment - `let x = 1;`"
+        ));
+    }
+
+    fn fix(content: &str) -> String {
+        let analyzer = InlineCommentsAnalyzer::new();
+        let ast = syn::parse_file(content).unwrap();
+        let suggestions = analyzer.suggestions(&ast, content).unwrap();
+        crate::fixer::apply_suggestions(content, &suggestions).source
+    }
+
+    #[test]
+    fn test_fix_creates_notes_without_docs() {
+        let content = "#[inline]\nfn main() {\n    // Comment\n    let x = 1;\n}\n";
+
+        assert_eq!(
+            fix(content),
+            "/// # Notes\n///\n/// - Comment - `let x = 1;`\n#[inline]\nfn main() {\n    let x = \
+             1;\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_fix_keeps_crlf_line_endings() {
+        let content = "/// Sum values\r\nfn sum() {\r\n    // Read input\r\n    let a = \
+                       read();\r\n    a + 1\r\n}\r\n";
+
+        assert_eq!(
+            fix(content),
+            "/// Sum values\r\n///\r\n/// # Notes\r\n///\r\n/// - Read input - `let a = \
+             read();`\r\nfn sum() {\r\n    let a = read();\r\n    a + 1\r\n}\r\n"
+        );
+    }
+
+    #[test]
+    fn test_fix_appends_notes_section_to_docs() {
+        let content = "/// Sum values\nfn sum() {\n    // Read input\n    let a = read();\n    // \
+                       Add\n    a + 1\n}\n";
+
+        assert_eq!(
+            fix(content),
+            "/// Sum values\n///\n/// # Notes\n///\n/// - Read input - `let a = read();`\n/// - \
+             Add - `a + 1`\nfn sum() {\n    let a = read();\n    a + 1\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_fix_extends_existing_notes_section() {
+        let content = r#"impl Foo {
+    /// Run
+    ///
+    /// # Notes
+    ///
+    /// - Existing
+    ///
+    /// # Examples
+    fn run(&self) {
+        // New
+        go();
+    }
+}
+"#;
+
+        assert_eq!(
+            fix(content),
+            r#"impl Foo {
+    /// Run
+    ///
+    /// # Notes
+    ///
+    /// - Existing
+    /// - New - `go();`
+    ///
+    /// # Examples
+    fn run(&self) {
+        go();
+    }
+}
+"#
+        );
+    }
+
+    #[test]
+    fn test_fix_nested_function_owns_its_comments() {
+        let content = r#"/// Outer
+fn outer() {
+    /// Inner
+    fn inner() {
+        // Inside
+        a();
+    }
+    // Outside
+    b();
+}
+"#;
+        let analyzer = InlineCommentsAnalyzer::new();
+        let ast = syn::parse_file(content).unwrap();
+        assert_eq!(analyzer.analyze(&ast, content).unwrap().issues.len(), 2);
+
+        let fixed = fix(content);
+        assert!(
+            fixed.contains("    /// Inner\n    ///\n    /// # Notes\n    ///\n    /// - Inside")
+        );
+        assert!(fixed.contains("/// Outer\n///\n/// # Notes\n///\n/// - Outside - `b();`\n"));
+        assert!(!fixed.contains("// Inside\n"));
     }
 
     #[test]
-    fn test_no_edits() {
+    fn test_no_edits_for_attribute_docs() {
         let analyzer = InlineCommentsAnalyzer::new();
-        let content = r#"fn main() {
-    // Comment
-    let x = 1;
-}"#;
-        let code = syn::parse_str(content).unwrap();
+        let content = "#[doc = \"Docs\"]\nfn main() {\n    // Comment\n    let x = 1;\n}";
+        let code = syn::parse_file(content).unwrap();
 
         let edits = analyzer.suggestions(&code, content).unwrap();
         assert!(edits.is_empty());
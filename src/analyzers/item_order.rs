@@ -0,0 +1,552 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Item order analyzer for the layout of items within a module.
+//!
+//! Files are easier to navigate when every module lays out its items in the
+//! same order. This opt-in analyzer checks the items of each module against
+//! a group order, by default constants, type definitions, impl blocks, free
+//! functions and finally test code, and reports every item placed after a
+//! group that should follow it. The top-level items of a file are reordered
+//! automatically unless the file invokes macros at item level, whose
+//! expansion may depend on where they are written.
+
+use masterror::AppResult;
+use proc_macro2::Span;
+use quote::ToTokens;
+use syn::{File, Item, spanned::Spanned};
+
+use super::{is_test_code, test_modules::item_attrs};
+use crate::analyzer::{AnalysisResult, Analyzer, Fix, Issue, Suggestion, TextEdit};
+
+/// Item groups in their default order.
+///
+/// * `const` - Constants and statics
+/// * `type` - Structs, enums, unions, type aliases and traits
+/// * `impl` - Impl blocks
+/// * `fn` - Free functions
+/// * `test` - Items marked `#[cfg(test)]` or `#[test]`
+///
+/// Other items (`use`, `mod`, `extern crate`, macros) are not ordered.
+pub const ITEM_GROUPS: &[&str] = &["const", "type", "impl", "fn", "test"];
+
+/// Analyzer for items placed out of the configured group order.
+///
+/// # Examples
+///
+/// Detects this pattern:
+/// ```ignore
+/// fn helper() {}
+///
+/// const LIMIT: usize = 10;
+/// ```
+///
+/// Fixes to:
+/// ```ignore
+/// const LIMIT: usize = 10;
+///
+/// fn helper() {}
+/// ```
+pub struct ItemOrderAnalyzer {
+    order:      Vec<String>,
+    configured: bool
+}
+
+/// Item placed after a group that should follow it.
+struct Misplaced {
+    name:       String,
+    group:      &'static str,
+    line:       usize,
+    column:     usize,
+    after:      &'static str,
+    after_line: usize
+}
+
+impl ItemOrderAnalyzer {
+    /// Create new opt-in item order analyzer with the default order.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            order:      ITEM_GROUPS.iter().map(|group| group.to_string()).collect(),
+            configured: false
+        }
+    }
+
+    /// Create analyzer enforcing a configured group order.
+    ///
+    /// Groups missing from `order` are not ordered. A configured analyzer
+    /// runs by default.
+    ///
+    /// # Arguments
+    ///
+    /// * `order` - Group names from [`ITEM_GROUPS`], see
+    ///   [`ProjectContext::item_order`](crate::project::ProjectContext::item_order)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cargo_quality::{analyzer::Analyzer, analyzers::ItemOrderAnalyzer};
+    ///
+    /// let analyzer = ItemOrderAnalyzer::with_order(&["type".to_string(), "fn".to_string()]);
+    /// assert!(!analyzer.opt_in());
+    /// ```
+    pub fn with_order(order: &[String]) -> Self {
+        Self {
+            order:      order.to_vec(),
+            configured: true
+        }
+    }
+
+    /// Position of an item's group in the order, if it is ordered.
+    fn rank(&self, item: &Item) -> Option<usize> {
+        let group = item_group(item)?;
+        self.order.iter().position(|name| name == group)
+    }
+
+    /// Find the misplaced items among the items of one module.
+    fn check_items(&self, items: &[Item], misplaced: &mut Vec<Misplaced>) {
+        let mut latest: Option<(usize, &'static str, usize)> = None;
+
+        for item in items {
+            let (Some(rank), Some(group), Some((name, span))) =
+                (self.rank(item), item_group(item), item_name(item))
+            else {
+                continue;
+            };
+            let start = span.start();
+
+            match latest {
+                Some((latest_rank, after, after_line)) if rank < latest_rank => {
+                    misplaced.push(Misplaced {
+                        name,
+                        group,
+                        line: start.line,
+                        column: start.column,
+                        after,
+                        after_line
+                    });
+                }
+                Some((latest_rank, ..)) if rank == latest_rank => {}
+                _ => latest = Some((rank, group, start.line))
+            }
+        }
+    }
+
+    /// Check the items of nested inline modules, recursively.
+    fn check_modules(&self, items: &[Item], misplaced: &mut Vec<Misplaced>) {
+        for item in items {
+            if let Item::Mod(node) = item
+                && !is_test_code(&node.attrs)
+                && let Some((_, content)) = &node.content
+            {
+                self.check_items(content, misplaced);
+                self.check_modules(content, misplaced);
+            }
+        }
+    }
+
+    /// Build the edit reordering the top-level items of a file.
+    ///
+    /// Items from the first ordered one to the end of the file are sorted by
+    /// group, each together with the comments and blank lines above it.
+    ///
+    /// # Returns
+    ///
+    /// `None` if the items are in order, or if the file invokes macros at
+    /// item level, interleaves unordered items with ordered ones, or puts
+    /// several items on one line
+    fn reorder(&self, ast: &File, content: &str) -> Option<TextEdit> {
+        if ast
+            .items
+            .iter()
+            .any(|item| matches!(item, Item::Macro(_) | Item::Verbatim(_)))
+        {
+            return None;
+        }
+
+        let first = ast
+            .items
+            .iter()
+            .position(|item| self.rank(item).is_some())?;
+        let region = &ast.items[first..];
+        let ranks: Vec<usize> = region
+            .iter()
+            .map(|item| self.rank(item))
+            .collect::<Option<_>>()?;
+        if ranks.windows(2).all(|pair| pair[0] <= pair[1]) {
+            return None;
+        }
+
+        let region_start = match first.checked_sub(1) {
+            Some(previous) => line_end(content, ast.items[previous].span().byte_range().end),
+            None => match ast.attrs.last() {
+                Some(attr) => line_end(content, attr.span().byte_range().end),
+                None => line_start(content, region[0].span().byte_range().start)
+            }
+        };
+
+        let mut chunks = Vec::new();
+        let mut start = region_start;
+        for (item, rank) in region.iter().zip(ranks) {
+            let range = item.span().byte_range();
+            if line_start(content, range.start) < start {
+                return None;
+            }
+            let end = line_end(content, range.end);
+            chunks.push((rank, split_leading_blank_lines(&content[start..end])));
+            start = end;
+        }
+        if !content[..start].ends_with('\n') {
+            return None;
+        }
+
+        let first_leading = chunks[0].1.0;
+        chunks.sort_by_key(|(rank, _)| *rank);
+
+        let mut replacement = String::new();
+        let mut previous = None;
+        for (index, (rank, (leading, body))) in chunks.iter().enumerate() {
+            let leading = if index == 0 {
+                first_leading
+            } else if leading.is_empty() && previous != Some(rank) {
+                "\n"
+            } else {
+                leading
+            };
+            replacement.push_str(leading);
+            replacement.push_str(body);
+            previous = Some(rank);
+        }
+
+        Some(TextEdit {
+            range: region_start..start,
+            replacement
+        })
+    }
+}
+
+impl Analyzer for ItemOrderAnalyzer {
+    fn name(&self) -> &'static str {
+        "item_order"
+    }
+
+    fn analyze(&self, ast: &File, content: &str) -> AppResult<AnalysisResult> {
+        let mut top_level = Vec::new();
+        self.check_items(&ast.items, &mut top_level);
+        let mut nested = Vec::new();
+        self.check_modules(&ast.items, &mut nested);
+
+        let fixable = self.reorder(ast, content).is_some();
+        let fixable_count = if fixable { top_level.len() } else { 0 };
+        let order = self.order.join(" → ");
+
+        let issues = top_level
+            .into_iter()
+            .map(|misplaced| (misplaced, fixable))
+            .chain(nested.into_iter().map(|misplaced| (misplaced, false)))
+            .map(|(misplaced, fixable)| Issue {
+                line:    misplaced.line,
+                column:  misplaced.column,
+                message: format!(
+                    "`{}` ({}) is placed after {} at line {}\n\
+                     Order items as {order}",
+                    misplaced.name,
+                    misplaced.group,
+                    group_label(misplaced.after),
+                    misplaced.after_line
+                ),
+                fix:     if fixable {
+                    Fix::Simple("reorder top-level items".to_string())
+                } else {
+                    Fix::None
+                }
+            })
+            .collect();
+
+        Ok(AnalysisResult {
+            issues,
+            fixable_count
+        })
+    }
+
+    fn suggestions(&self, ast: &File, content: &str) -> AppResult<Vec<Suggestion>> {
+        Ok(self
+            .reorder(ast, content)
+            .map(|edit| Suggestion {
+                edit,
+                import: None
+            })
+            .into_iter()
+            .collect())
+    }
+
+    fn fixable(&self) -> bool {
+        true
+    }
+
+    fn opt_in(&self) -> bool {
+        !self.configured
+    }
+
+    fn options(&self) -> &'static [&'static str] {
+        &["item_order"]
+    }
+}
+
+impl Default for ItemOrderAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Group of an item, `None` for items that are not ordered.
+fn item_group(item: &Item) -> Option<&'static str> {
+    if is_test_code(item_attrs(item)) {
+        return Some("test");
+    }
+
+    match item {
+        Item::Const(_) | Item::Static(_) => Some("const"),
+        Item::Struct(_)
+        | Item::Enum(_)
+        | Item::Union(_)
+        | Item::Type(_)
+        | Item::Trait(_)
+        | Item::TraitAlias(_) => Some("type"),
+        Item::Impl(_) => Some("impl"),
+        Item::Fn(_) => Some("fn"),
+        _ => None
+    }
+}
+
+/// Name an item is reported by, and where.
+fn item_name(item: &Item) -> Option<(String, Span)> {
+    let ident = match item {
+        Item::Const(node) => &node.ident,
+        Item::Static(node) => &node.ident,
+        Item::Struct(node) => &node.ident,
+        Item::Enum(node) => &node.ident,
+        Item::Union(node) => &node.ident,
+        Item::Type(node) => &node.ident,
+        Item::Trait(node) => &node.ident,
+        Item::TraitAlias(node) => &node.ident,
+        Item::Fn(node) => &node.sig.ident,
+        Item::Mod(node) => &node.ident,
+        Item::Impl(node) => {
+            let name = format!("impl {}", node.self_ty.to_token_stream());
+            return Some((name, node.impl_token.span));
+        }
+        _ => return None
+    };
+
+    Some((ident.to_string(), ident.span()))
+}
+
+/// Plural description of a group for messages.
+fn group_label(group: &str) -> &'static str {
+    match group {
+        "const" => "constants",
+        "type" => "type definitions",
+        "impl" => "impl blocks",
+        "fn" => "free functions",
+        _ => "test code"
+    }
+}
+
+/// Split off the blank lines at the start of a chunk.
+///
+/// # Returns
+///
+/// The blank lines and the rest of the chunk
+fn split_leading_blank_lines(chunk: &str) -> (&str, &str) {
+    let whitespace = &chunk[..chunk.len() - chunk.trim_start().len()];
+    chunk.split_at(whitespace.rfind('\n').map_or(0, |idx| idx + 1))
+}
+
+/// Byte offset of the start of the line containing `offset`.
+fn line_start(content: &str, offset: usize) -> usize {
+    content[..offset].rfind('\n').map_or(0, |idx| idx + 1)
+}
+
+/// Byte offset just past the newline ending the line containing `offset`.
+fn line_end(content: &str, offset: usize) -> usize {
+    content[offset..]
+        .find('\n')
+        .map_or(content.len(), |idx| offset + idx + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analyze(analyzer: &ItemOrderAnalyzer, code: &str) -> AnalysisResult {
+        let ast = syn::parse_file(code).unwrap();
+        analyzer.analyze(&ast, code).unwrap()
+    }
+
+    fn fix(code: &str) -> String {
+        let ast = syn::parse_file(code).unwrap();
+        let suggestions = ItemOrderAnalyzer::new().suggestions(&ast, code).unwrap();
+        crate::fixer::apply_suggestions(code, &suggestions).source
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        let analyzer = ItemOrderAnalyzer::new();
+        assert_eq!(analyzer.name(), "item_order");
+        assert!(analyzer.opt_in());
+    }
+
+    #[test]
+    fn test_detect_out_of_order_items() {
+        let result = analyze(
+            &ItemOrderAnalyzer::new(),
+            r#"
+struct Config;
+
+impl Config {}
+
+fn helper() {}
+
+const LIMIT: usize = 10;
+
+enum Mode {}
+"#
+        );
+
+        assert_eq!(result.issues.len(), 2);
+        assert_eq!(result.fixable_count, 2);
+        assert_eq!(result.issues[0].line, 8);
+        assert!(
+            result.issues[0]
+                .message
+                .contains("`LIMIT` (const) is placed after free functions at line 6")
+        );
+        assert!(
+            result.issues[1]
+                .message
+                .contains("`Mode` (type) is placed after free functions at line 6")
+        );
+        assert!(
+            result.issues[1]
+                .message
+                .contains("Order items as const → type → impl → fn → test")
+        );
+    }
+
+    #[test]
+    fn test_accept_ordered_items() {
+        let result = analyze(
+            &ItemOrderAnalyzer::new(),
+            r#"
+use std::fmt;
+
+mod helpers;
+
+const LIMIT: usize = 10;
+static NAME: &str = "x";
+
+struct Config;
+trait Named {}
+
+impl Config {}
+impl fmt::Display for Config {}
+
+fn helper() {}
+
+macro_rules! noop { () => {} }
+
+#[cfg(test)]
+mod tests {}
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_configured_order() {
+        let analyzer = ItemOrderAnalyzer::with_order(&["fn".to_string(), "type".to_string()]);
+        let result = analyze(
+            &analyzer,
+            "struct Config;\nconst LIMIT: u8 = 1;\nfn helper() {}\nimpl Config {}\n"
+        );
+
+        assert!(!analyzer.opt_in());
+        assert_eq!(result.issues.len(), 1);
+        assert!(
+            result.issues[0]
+                .message
+                .contains("`helper` (fn) is placed after type definitions at line 1")
+        );
+    }
+
+    #[test]
+    fn test_nested_modules_reported_not_fixed() {
+        let result = analyze(
+            &ItemOrderAnalyzer::new(),
+            "mod inner {\n    fn helper() {}\n    struct Config;\n}\n"
+        );
+
+        assert_eq!(result.issues.len(), 1);
+        assert_eq!(result.issues[0].line, 3);
+        assert_eq!(result.fixable_count, 0);
+    }
+
+    #[test]
+    fn test_fix_reorders_items_with_comments() {
+        let code = r#"//! Docs.
+
+use std::fmt;
+
+fn helper() {}
+
+// Accessors
+impl Config {
+    fn get(&self) {}
+}
+
+/// Configuration.
+struct Config;
+const A: u8 = 1;
+const B: u8 = 2;
+"#;
+
+        assert_eq!(
+            fix(code),
+            r#"//! Docs.
+
+use std::fmt;
+
+const A: u8 = 1;
+const B: u8 = 2;
+
+/// Configuration.
+struct Config;
+
+// Accessors
+impl Config {
+    fn get(&self) {}
+}
+
+fn helper() {}
+"#
+        );
+    }
+
+    #[test]
+    fn test_macro_invocations_prevent_fix() {
+        let code = "fn helper() {}\nmacro_rules! noop { () => {} }\nconst LIMIT: u8 = 1;\n";
+        let result = analyze(&ItemOrderAnalyzer::new(), code);
+
+        assert_eq!(result.issues.len(), 1);
+        assert_eq!(result.fixable_count, 0);
+        assert_eq!(fix(code), code);
+    }
+
+    #[test]
+    fn test_default_implementation() {
+        let analyzer = ItemOrderAnalyzer::default();
+        assert_eq!(analyzer.name(), "item_order");
+    }
+}
@@ -0,0 +1,313 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! License header analyzer for files without SPDX tags.
+//!
+//! REUSE-compliant projects start every source file with
+//! `SPDX-FileCopyrightText` and `SPDX-License-Identifier` comments. This
+//! analyzer checks the leading comment block of each file for both tags.
+//! With a `license_header` template configured in `metadata.quality` it
+//! runs by default and inserts the template into files lacking both tags;
+//! without one it is opt-in and report-only.
+
+use std::ops::Range;
+
+use masterror::AppResult;
+use syn::File;
+
+use crate::analyzer::{AnalysisResult, Analyzer, Fix, Issue, Suggestion, TextEdit};
+
+/// SPDX tags every file header must contain.
+const SPDX_TAGS: &[&str] = &["SPDX-FileCopyrightText", "SPDX-License-Identifier"];
+
+/// Analyzer for files whose header lacks SPDX license tags.
+///
+/// # Examples
+///
+/// Detects a file starting directly with code or docs:
+/// ```ignore
+/// //! Configuration loading.
+///
+/// use std::fs;
+/// ```
+///
+/// Fixes to:
+/// ```ignore
+/// // SPDX-FileCopyrightText: 2025 Jane Doe <jane@example.com>
+/// // SPDX-License-Identifier: MIT
+///
+/// //! Configuration loading.
+///
+/// use std::fs;
+/// ```
+pub struct LicenseHeaderAnalyzer {
+    template: Option<String>
+}
+
+impl LicenseHeaderAnalyzer {
+    /// Create new report-only license header analyzer.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            template: None
+        }
+    }
+
+    /// Create analyzer that inserts a header template.
+    ///
+    /// Template lines not already written as `//` comments are prefixed
+    /// with `// `.
+    ///
+    /// # Arguments
+    ///
+    /// * `template` - Header text, see
+    ///   [`ProjectContext::license_header`](crate::project::ProjectContext::license_header)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cargo_quality::{analyzer::Analyzer, analyzers::LicenseHeaderAnalyzer};
+    ///
+    /// let analyzer = LicenseHeaderAnalyzer::with_template("SPDX-License-Identifier: MIT");
+    /// assert!(!analyzer.opt_in());
+    /// ```
+    pub fn with_template(template: &str) -> Self {
+        let lines: Vec<String> = template
+            .trim_matches('\n')
+            .lines()
+            .map(|line| {
+                if line.trim_start().starts_with("//") {
+                    line.to_string()
+                } else {
+                    format!("// {line}").trim_end().to_string()
+                }
+            })
+            .collect();
+
+        Self {
+            template: Some(lines.join("\n"))
+        }
+    }
+
+    /// Find the SPDX tags missing from the header of a file.
+    fn missing_tags(content: &str) -> Vec<&'static str> {
+        let header = &content[header_range(content)];
+        SPDX_TAGS
+            .iter()
+            .copied()
+            .filter(|tag| !header.contains(tag))
+            .collect()
+    }
+}
+
+impl Analyzer for LicenseHeaderAnalyzer {
+    fn name(&self) -> &'static str {
+        "license_header"
+    }
+
+    fn analyze(&self, _ast: &File, content: &str) -> AppResult<AnalysisResult> {
+        let missing = Self::missing_tags(content);
+        if missing.is_empty() {
+            return Ok(AnalysisResult::default());
+        }
+
+        let fix = match &self.template {
+            Some(template) if missing.len() == SPDX_TAGS.len() => Fix::Simple(template.clone()),
+            _ => Fix::None
+        };
+        let fixable_count = usize::from(fix.is_available());
+        let tags: Vec<String> = missing.iter().map(|tag| format!("`{tag}`")).collect();
+
+        Ok(AnalysisResult {
+            issues: vec![Issue {
+                line: 1,
+                column: 0,
+                message: format!(
+                    "File header has no {} tag\n\
+                     Start the file with SPDX comments declaring its copyright and license",
+                    tags.join(" or ")
+                ),
+                fix
+            }],
+            fixable_count
+        })
+    }
+
+    fn suggestions(&self, _ast: &File, content: &str) -> AppResult<Vec<Suggestion>> {
+        let Some(template) = &self.template else {
+            return Ok(Vec::new());
+        };
+        if Self::missing_tags(content).len() < SPDX_TAGS.len() {
+            return Ok(Vec::new());
+        }
+
+        let offset = header_range(content).start;
+        Ok(vec![Suggestion {
+            edit:   TextEdit {
+                range:       offset..offset,
+                replacement: format!("{template}\n\n")
+            },
+            import: None
+        }])
+    }
+
+    fn fixable(&self) -> bool {
+        true
+    }
+
+    fn opt_in(&self) -> bool {
+        self.template.is_none()
+    }
+
+    fn options(&self) -> &'static [&'static str] {
+        &["license_header"]
+    }
+}
+
+impl Default for LicenseHeaderAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Byte range of the leading comment block of a file.
+///
+/// The block starts after a `#!` shebang line, if any, and runs over blank
+/// lines and comments up to the first line of code.
+fn header_range(content: &str) -> Range<usize> {
+    let start = if content.starts_with("#!") && !content.starts_with("#![") {
+        content.find('\n').map_or(content.len(), |idx| idx + 1)
+    } else {
+        0
+    };
+
+    let mut end = start;
+    for line in content[start..].split_inclusive('\n') {
+        let trimmed = line.trim();
+        let comment = trimmed.is_empty()
+            || trimmed.starts_with("//")
+            || trimmed.starts_with("/*")
+            || trimmed.starts_with('*');
+        if !comment {
+            break;
+        }
+        end += line.len();
+    }
+
+    start..end
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEMPLATE: &str = "// SPDX-FileCopyrightText: 2025 Jane Doe\n\
+                            // SPDX-License-Identifier: MIT";
+
+    fn analyze(analyzer: &LicenseHeaderAnalyzer, code: &str) -> AnalysisResult {
+        let ast = syn::parse_file(code).unwrap();
+        analyzer.analyze(&ast, code).unwrap()
+    }
+
+    fn fix(code: &str) -> String {
+        let ast = syn::parse_file(code).unwrap();
+        let suggestions = LicenseHeaderAnalyzer::with_template(TEMPLATE)
+            .suggestions(&ast, code)
+            .unwrap();
+        crate::fixer::apply_suggestions(code, &suggestions).source
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        let analyzer = LicenseHeaderAnalyzer::new();
+        assert_eq!(analyzer.name(), "license_header");
+    }
+
+    #[test]
+    fn test_accept_spdx_header() {
+        let code = "// SPDX-FileCopyrightText: 2025 Jane Doe\n\
+                    // SPDX-License-Identifier: MIT\n\n\
+                    //! Docs.\n\nfn main() {}\n";
+        assert!(
+            analyze(&LicenseHeaderAnalyzer::new(), code)
+                .issues
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_detect_missing_header() {
+        let code = "//! Docs.\n\nfn main() {}\n";
+        let result = analyze(&LicenseHeaderAnalyzer::new(), code);
+
+        assert_eq!(result.issues.len(), 1);
+        assert_eq!(result.issues[0].line, 1);
+        assert_eq!(result.fixable_count, 0);
+        assert!(
+            result.issues[0]
+                .message
+                .contains("no `SPDX-FileCopyrightText` or `SPDX-License-Identifier` tag")
+        );
+    }
+
+    #[test]
+    fn test_tags_after_code_do_not_count() {
+        let code = "fn main() {}\n// SPDX-FileCopyrightText: 2025 Jane Doe\n";
+        let result = analyze(&LicenseHeaderAnalyzer::with_template(TEMPLATE), code);
+
+        assert_eq!(result.issues.len(), 1);
+        assert_eq!(result.fixable_count, 1);
+    }
+
+    #[test]
+    fn test_partial_header_not_fixable() {
+        let code = "// SPDX-License-Identifier: MIT\n\nfn main() {}\n";
+        let result = analyze(&LicenseHeaderAnalyzer::with_template(TEMPLATE), code);
+
+        assert_eq!(result.issues.len(), 1);
+        assert_eq!(result.fixable_count, 0);
+        assert!(
+            result.issues[0]
+                .message
+                .contains("no `SPDX-FileCopyrightText` tag")
+        );
+        assert_eq!(fix(code), code);
+    }
+
+    #[test]
+    fn test_fix_inserts_template() {
+        assert_eq!(
+            fix("//! Docs.\n\nfn main() {}\n"),
+            "// SPDX-FileCopyrightText: 2025 Jane Doe\n\
+             // SPDX-License-Identifier: MIT\n\n\
+             //! Docs.\n\nfn main() {}\n"
+        );
+        assert_eq!(
+            fix("#!/usr/bin/env run-cargo-script\nfn main() {}\n"),
+            "#!/usr/bin/env run-cargo-script\n\
+             // SPDX-FileCopyrightText: 2025 Jane Doe\n\
+             // SPDX-License-Identifier: MIT\n\n\
+             fn main() {}\n"
+        );
+    }
+
+    #[test]
+    fn test_template_lines_become_comments() {
+        let analyzer = LicenseHeaderAnalyzer::with_template(
+            "\nSPDX-FileCopyrightText: 2025 Jane Doe\nSPDX-License-Identifier: MIT\n"
+        );
+        assert_eq!(analyzer.template.as_deref(), Some(TEMPLATE));
+    }
+
+    #[test]
+    fn test_opt_in_without_template() {
+        assert!(LicenseHeaderAnalyzer::new().opt_in());
+        assert!(!LicenseHeaderAnalyzer::with_template(TEMPLATE).opt_in());
+    }
+
+    #[test]
+    fn test_default_implementation() {
+        let analyzer = LicenseHeaderAnalyzer::default();
+        assert_eq!(analyzer.name(), "license_header");
+    }
+}
@@ -0,0 +1,126 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Line length analyzer for lines wider than the formatter's `max_width`.
+//!
+//! The formatter is configured with `max_width = 99`, but it leaves long
+//! string literals, comments and macro bodies it cannot break untouched, and
+//! `check` never runs it. This analyzer measures every source line by its
+//! visual width, so wide Unicode characters count double and ANSI escape
+//! sequences not at all, and reports the lines over the limit.
+
+use masterror::AppResult;
+use syn::File;
+
+use super::line_width;
+use crate::analyzer::{AnalysisResult, Analyzer, Fix, Issue};
+
+/// Maximum visual width of a line, matching `max_width` in `rustfmt.toml`.
+const MAX_WIDTH: usize = 99;
+
+/// Analyzer for source lines exceeding the maximum width.
+///
+/// # Examples
+///
+/// Detects this pattern, where the literal pushes the line past column 99:
+/// ```ignore
+/// const HELP: &str = "Runs every analyzer on the given path and prints a report per file";
+/// ```
+///
+/// Suggests splitting the literal with a `\` line continuation.
+pub struct LineLengthAnalyzer;
+
+impl LineLengthAnalyzer {
+    /// Create new line length analyzer instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Analyzer for LineLengthAnalyzer {
+    fn name(&self) -> &'static str {
+        "line_length"
+    }
+
+    fn analyze(&self, _ast: &File, content: &str) -> AppResult<AnalysisResult> {
+        let issues = content
+            .lines()
+            .enumerate()
+            .filter_map(|(index, line)| {
+                let width = line_width(line);
+                (width > MAX_WIDTH).then(|| Issue {
+                    line:    index + 1,
+                    column:  MAX_WIDTH,
+                    message: format!(
+                        "Line is {width} characters wide, over the limit of {MAX_WIDTH}\n\
+                         Break the line or move long literals into constants"
+                    ),
+                    fix:     Fix::None
+                })
+            })
+            .collect();
+
+        Ok(AnalysisResult {
+            issues,
+            fixable_count: 0
+        })
+    }
+}
+
+impl Default for LineLengthAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analyze(code: &str) -> AnalysisResult {
+        let ast = syn::parse_file(code).unwrap();
+        LineLengthAnalyzer::new().analyze(&ast, code).unwrap()
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        let analyzer = LineLengthAnalyzer::new();
+        assert_eq!(analyzer.name(), "line_length");
+    }
+
+    #[test]
+    fn test_detect_long_lines() {
+        let code = format!(
+            "fn main() {{}}\n// {}\nconst S: &str = \"{}\";\n",
+            "x".repeat(97),
+            "y".repeat(80)
+        );
+        let result = analyze(&code);
+
+        assert_eq!(result.issues.len(), 1);
+        assert_eq!(result.issues[0].line, 2);
+        assert!(
+            result.issues[0]
+                .message
+                .contains("Line is 100 characters wide, over the limit of 99")
+        );
+    }
+
+    #[test]
+    fn test_visual_width() {
+        assert_eq!(line_width(&"═".repeat(99)), 99);
+        assert_eq!(line_width("\x1b[31mred\x1b[0m"), 3);
+        assert_eq!(line_width("界"), 2);
+        assert_eq!(line_width("\tx"), 5);
+
+        let result = analyze(&format!("// {}\n", "═".repeat(96)));
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_default_implementation() {
+        let analyzer = LineLengthAnalyzer;
+        assert_eq!(analyzer.name(), "line_length");
+    }
+}
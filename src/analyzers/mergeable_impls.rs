@@ -0,0 +1,465 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Mergeable impls analyzer for split inherent impl blocks.
+//!
+//! Several `impl Foo { ... }` blocks for the same type in one module make
+//! readers hunt for the full API. Blocks with different `#[cfg]`s or their
+//! own doc comments are often split on purpose, but identical blocks are
+//! not. This analyzer reports inherent impls that repeat an earlier block's
+//! type, generics and attributes in the same module, and merges their items
+//! into the first block, comments included.
+
+use std::{collections::HashMap, ops::Range};
+
+use masterror::AppResult;
+use quote::ToTokens;
+use syn::{File, Item, ItemImpl, spanned::Spanned};
+
+use super::{is_test_code, whole_lines};
+use crate::analyzer::{AnalysisResult, Analyzer, Fix, Issue, Suggestion, TextEdit};
+
+/// Analyzer for inherent impl blocks that could be merged.
+///
+/// # Examples
+///
+/// Detects this pattern:
+/// ```ignore
+/// impl Config {
+///     pub fn new() -> Self { ... }
+/// }
+///
+/// impl Config {
+///     pub fn path(&self) -> &Path { ... }
+/// }
+/// ```
+///
+/// Fixes to:
+/// ```ignore
+/// impl Config {
+///     pub fn new() -> Self { ... }
+///
+///     pub fn path(&self) -> &Path { ... }
+/// }
+/// ```
+pub struct MergeableImplsAnalyzer;
+
+/// Impl block that repeats an earlier block of the same module.
+struct Duplicate {
+    self_ty:    String,
+    line:       usize,
+    column:     usize,
+    first_line: usize,
+    /// Byte offset of the first block's closing brace
+    target:     usize,
+    /// Items of this block as written, without the surrounding braces
+    body:       String,
+    /// Byte range of this block, widened to whole lines
+    removal:    Range<usize>,
+    /// Whether both blocks close their brace on its own line
+    mergeable:  bool
+}
+
+impl MergeableImplsAnalyzer {
+    /// Create new mergeable impls analyzer instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Collect impl blocks repeating an earlier block in the same module.
+    fn collect(ast: &File, content: &str) -> Vec<Duplicate> {
+        let mut duplicates = Vec::new();
+        collect_items(&ast.items, content, &mut duplicates);
+        duplicates
+    }
+}
+
+impl Analyzer for MergeableImplsAnalyzer {
+    fn name(&self) -> &'static str {
+        "mergeable_impls"
+    }
+
+    fn analyze(&self, ast: &File, content: &str) -> AppResult<AnalysisResult> {
+        let duplicates = Self::collect(ast, content);
+        let fixable_count = duplicates.iter().filter(|d| d.mergeable).count();
+
+        let issues = duplicates
+            .into_iter()
+            .map(|duplicate| Issue {
+                line:    duplicate.line,
+                column:  duplicate.column,
+                message: format!(
+                    "Impl block for `{}` repeats the one at line {}\n\
+                     Merge the blocks to keep the type's API in one place",
+                    duplicate.self_ty, duplicate.first_line
+                ),
+                fix:     if duplicate.mergeable {
+                    Fix::Simple(format!("merge into impl at line {}", duplicate.first_line))
+                } else {
+                    Fix::None
+                }
+            })
+            .collect();
+
+        Ok(AnalysisResult {
+            issues,
+            fixable_count
+        })
+    }
+
+    /// Build the edits merging duplicate impl blocks.
+    ///
+    /// # Notes
+    ///
+    /// - All blocks merged into the same block form one edit, from its closing
+    ///   brace to the end of the last removed block, so the fixer applies or
+    ///   drops the merge as a whole
+    fn suggestions(&self, ast: &File, content: &str) -> AppResult<Vec<Suggestion>> {
+        let mut merges: Vec<(usize, Vec<Duplicate>)> = Vec::new();
+        for duplicate in Self::collect(ast, content) {
+            if !duplicate.mergeable {
+                continue;
+            }
+            match merges
+                .iter_mut()
+                .find(|(target, _)| *target == duplicate.target)
+            {
+                Some((_, group)) => group.push(duplicate),
+                None => merges.push((duplicate.target, vec![duplicate]))
+            }
+        }
+
+        Ok(merges
+            .into_iter()
+            .map(|(target, group)| Suggestion {
+                edit:   merge_edit(content, target, &group),
+                import: None
+            })
+            .collect())
+    }
+
+    fn fixable(&self) -> bool {
+        true
+    }
+}
+
+impl Default for MergeableImplsAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Find duplicate impl blocks among the items of one module.
+///
+/// Inline modules are checked as separate scopes.
+///
+/// # Arguments
+///
+/// * `items` - Items of the module
+/// * `content` - Source code of the file
+/// * `duplicates` - Receives the repeated blocks
+fn collect_items(items: &[Item], content: &str, duplicates: &mut Vec<Duplicate>) {
+    let mut first_blocks: HashMap<String, &ItemImpl> = HashMap::new();
+
+    for item in items {
+        match item {
+            Item::Impl(node) if node.trait_.is_none() && !is_documented(node) => {
+                let key = impl_key(node);
+                let Some(first) = first_blocks.get(&key) else {
+                    first_blocks.insert(key, node);
+                    continue;
+                };
+                duplicates.push(duplicate(first, node, content));
+            }
+            Item::Mod(node) if !is_test_code(&node.attrs) => {
+                if let Some((_, items)) = &node.content {
+                    collect_items(items, content, duplicates);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Describe a block repeating `first`.
+fn duplicate(first: &ItemImpl, node: &ItemImpl, content: &str) -> Duplicate {
+    let first_close = first.brace_token.span.close().byte_range().start;
+    let open = node.brace_token.span.open().byte_range().end;
+    let close = node.brace_token.span.close().byte_range().start;
+
+    let mut removal = whole_lines(content, node.span().byte_range());
+    if content[..removal.start].ends_with("\n\n") {
+        removal.start -= 1;
+    }
+
+    let start = node.impl_token.span.start();
+    Duplicate {
+        self_ty: node.self_ty.to_token_stream().to_string(),
+        line: start.line,
+        column: start.column,
+        first_line: first.impl_token.span.start().line,
+        target: first_close,
+        body: content[open..close].to_string(),
+        removal,
+        mergeable: closes_on_own_line(content, first_close) && closes_on_own_line(content, close)
+    }
+}
+
+/// Build the edit merging blocks into the block closing at `target`.
+///
+/// # Arguments
+///
+/// * `content` - Source code of the file
+/// * `target` - Byte offset of the first block's closing brace
+/// * `group` - Blocks repeating the first one, in source order
+///
+/// # Returns
+///
+/// Edit replacing everything from the closing brace to the end of the last
+/// repeated block: the merged items, then the code in between without the
+/// repeated blocks
+fn merge_edit(content: &str, target: usize, group: &[Duplicate]) -> TextEdit {
+    let end = group
+        .iter()
+        .map(|duplicate| duplicate.removal.end)
+        .max()
+        .unwrap_or(target);
+
+    let mut replacement = String::new();
+    for duplicate in group {
+        let body = duplicate.body.trim_start_matches('\n');
+        if !body.trim().is_empty() {
+            replacement.push('\n');
+            replacement.push_str(body);
+        }
+    }
+    let mut kept = target;
+    for duplicate in group {
+        replacement.push_str(&content[kept..duplicate.removal.start]);
+        kept = duplicate.removal.end;
+    }
+    replacement.push_str(&content[kept..end]);
+
+    TextEdit {
+        range: target..end,
+        replacement
+    }
+}
+
+/// Key identifying blocks that can be merged: attributes, generics and type.
+fn impl_key(node: &ItemImpl) -> String {
+    let attrs: Vec<String> = node
+        .attrs
+        .iter()
+        .map(|attr| attr.to_token_stream().to_string())
+        .collect();
+
+    format!(
+        "{} {} {} {} {}",
+        attrs.join(" "),
+        node.unsafety.is_some(),
+        node.generics.to_token_stream(),
+        node.generics.where_clause.to_token_stream(),
+        node.self_ty.to_token_stream()
+    )
+}
+
+/// Check whether an impl block has its own doc comment.
+fn is_documented(node: &ItemImpl) -> bool {
+    node.attrs.iter().any(|attr| attr.path().is_ident("doc"))
+}
+
+/// Check whether only whitespace precedes a closing brace on its line.
+fn closes_on_own_line(content: &str, close: usize) -> bool {
+    let line_start = content[..close].rfind('\n').map_or(0, |idx| idx + 1);
+    content[line_start..close].trim().is_empty()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analyze(code: &str) -> AnalysisResult {
+        let ast = syn::parse_file(code).unwrap();
+        MergeableImplsAnalyzer::new().analyze(&ast, code).unwrap()
+    }
+
+    fn fix(code: &str) -> String {
+        let ast = syn::parse_file(code).unwrap();
+        let suggestions = MergeableImplsAnalyzer::new()
+            .suggestions(&ast, code)
+            .unwrap();
+        crate::fixer::apply_suggestions(code, &suggestions).source
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        let analyzer = MergeableImplsAnalyzer::new();
+        assert_eq!(analyzer.name(), "mergeable_impls");
+    }
+
+    #[test]
+    fn test_detect_repeated_impl_blocks() {
+        let result = analyze(
+            r#"
+struct Config;
+
+impl Config {
+    fn new() -> Self { Self }
+}
+
+impl Config {
+    fn path(&self) {}
+}
+
+impl<T> Wrapper<T> {}
+impl<T> Wrapper<T> where T: Clone {}
+"#
+        );
+
+        assert_eq!(result.issues.len(), 1);
+        assert_eq!(result.fixable_count, 1);
+        assert_eq!(result.issues[0].line, 8);
+        assert!(
+            result.issues[0]
+                .message
+                .contains("Impl block for `Config` repeats the one at line 4")
+        );
+    }
+
+    #[test]
+    fn test_ignore_distinct_blocks() {
+        let result = analyze(
+            r#"
+impl Config {
+    fn new() -> Self { Self }
+}
+
+#[cfg(feature = "serde")]
+impl Config {
+    fn to_json(&self) {}
+}
+
+/// Accessors.
+impl Config {
+    fn path(&self) {}
+}
+
+impl Display for Config {}
+impl Display for Config {}
+
+mod inner {
+    impl Config {
+        fn extra(&self) {}
+    }
+}
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_fix_merges_blocks_with_comments() {
+        let code = r#"impl Config {
+    fn new() -> Self {
+        Self
+    }
+}
+
+fn helper() {}
+
+impl Config {
+    // Accessors
+    fn path(&self) -> &str {
+        "config.toml"
+    }
+}
+"#;
+
+        assert_eq!(
+            fix(code),
+            r#"impl Config {
+    fn new() -> Self {
+        Self
+    }
+
+    // Accessors
+    fn path(&self) -> &str {
+        "config.toml"
+    }
+}
+
+fn helper() {}
+"#
+        );
+    }
+
+    #[test]
+    fn test_fix_merges_three_blocks() {
+        let code = "impl Config {\n    fn a() {}\n}\n\nimpl Config {\n    fn b() {}\n}\n\n\
+                    impl Config {\n    fn c() {}\n}\n";
+
+        assert_eq!(
+            fix(code),
+            "impl Config {\n    fn a() {}\n\n    fn b() {}\n\n    fn c() {}\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_merge_applied_as_a_whole_with_missing_inline() {
+        use crate::analyzers::MissingInlineAnalyzer;
+
+        let code = r#"struct Holder;
+
+impl Holder {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Holder {
+    pub fn value(&self) -> u8 {
+        1
+    }
+}
+"#;
+        let ast = syn::parse_file(code).unwrap();
+        let inline = MissingInlineAnalyzer::new()
+            .suggestions(&ast, code)
+            .unwrap();
+        let merge = MergeableImplsAnalyzer::new()
+            .suggestions(&ast, code)
+            .unwrap();
+        assert_eq!(merge.len(), 1);
+
+        let inline_first: Vec<Suggestion> = inline.iter().chain(&merge).cloned().collect();
+        let outcome = crate::fixer::apply_suggestions(code, &inline_first);
+        assert_eq!(outcome.conflicts, 1);
+        assert_eq!(outcome.source.matches("impl Holder").count(), 2);
+        assert_eq!(outcome.source.matches("pub fn value").count(), 1);
+        assert_eq!(outcome.source.matches("#[inline]").count(), 2);
+        syn::parse_file(&outcome.source).unwrap();
+
+        let merge_first: Vec<Suggestion> = merge.iter().chain(&inline).cloned().collect();
+        let outcome = crate::fixer::apply_suggestions(code, &merge_first);
+        assert_eq!(outcome.source.matches("impl Holder").count(), 1);
+        assert_eq!(outcome.source.matches("pub fn value").count(), 1);
+        syn::parse_file(&outcome.source).unwrap();
+    }
+
+    #[test]
+    fn test_single_line_blocks_not_fixable() {
+        let result = analyze("impl Config { fn a() {} }\nimpl Config { fn b() {} }\n");
+
+        assert_eq!(result.issues.len(), 1);
+        assert_eq!(result.fixable_count, 0);
+    }
+
+    #[test]
+    fn test_default_implementation() {
+        let analyzer = MergeableImplsAnalyzer;
+        assert_eq!(analyzer.name(), "mergeable_impls");
+    }
+}
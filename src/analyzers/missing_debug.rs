@@ -0,0 +1,608 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Missing debug analyzer for public types without a `Debug` implementation.
+//!
+//! A public type without `Debug` cannot be printed with `{:?}`, used in
+//! `assert_eq!`, or derive `Debug` in any type that contains it, so the gap
+//! spreads to every downstream crate. This analyzer flags public structs and
+//! enums of library files that neither derive nor implement `Debug` in the
+//! same file, and adds `Debug` to their derives. Types with credential-like
+//! fields are reported without a fix, since they need a redacting manual
+//! implementation instead.
+//!
+//! The derive only compiles when every field type implements `Debug`. The
+//! fix is offered when each field is a type parameter, a standard type known
+//! to implement it (`String`, `Vec<T>`, `Option<T>`, ...) or a type of the
+//! same file with `Debug`. Trait objects such as `Box<dyn Fn()>` and types
+//! of other modules or crates leave the issue without a fix.
+
+use std::{collections::HashSet, path::Path};
+
+use masterror::AppResult;
+use proc_macro2::{Ident, Span};
+use syn::{
+    Attribute, Field, File, GenericArgument, GenericParam, Generics, ItemEnum, ItemImpl, ItemMod,
+    ItemStruct, MacroDelimiter, Meta, PathArguments, Type, Visibility, visit::Visit
+};
+
+use super::{find_derive, is_library_path, is_test_code, sensitive_debug::has_sensitive_field};
+use crate::analyzer::{AnalysisResult, Analyzer, Fix, Issue, Suggestion, TextEdit};
+
+/// Standard types implementing `Debug` when their type arguments do.
+const DEBUG_TYPES: &[&str] = &[
+    "bool",
+    "char",
+    "str",
+    "u8",
+    "u16",
+    "u32",
+    "u64",
+    "u128",
+    "usize",
+    "i8",
+    "i16",
+    "i32",
+    "i64",
+    "i128",
+    "isize",
+    "f32",
+    "f64",
+    "String",
+    "Vec",
+    "VecDeque",
+    "Option",
+    "Result",
+    "Box",
+    "Rc",
+    "Arc",
+    "Weak",
+    "Cow",
+    "Cell",
+    "RefCell",
+    "Mutex",
+    "RwLock",
+    "HashMap",
+    "HashSet",
+    "BTreeMap",
+    "BTreeSet",
+    "BinaryHeap",
+    "PathBuf",
+    "Path",
+    "OsString",
+    "OsStr",
+    "Duration",
+    "Instant",
+    "SystemTime",
+    "PhantomData",
+    "IpAddr",
+    "Ipv4Addr",
+    "Ipv6Addr",
+    "SocketAddr"
+];
+
+/// Analyzer for public types without `Debug`.
+///
+/// # Examples
+///
+/// Detects this pattern:
+/// ```ignore
+/// #[derive(Clone)]
+/// pub struct Endpoint {
+///     pub url: String
+/// }
+/// ```
+///
+/// Fixes to:
+/// ```ignore
+/// #[derive(Debug, Clone)]
+/// pub struct Endpoint {
+///     pub url: String
+/// }
+/// ```
+pub struct MissingDebugAnalyzer;
+
+/// Public type lacking `Debug`.
+struct Candidate {
+    kind:      &'static str,
+    name:      String,
+    line:      usize,
+    column:    usize,
+    /// Whether a field holds a secret
+    sensitive: bool,
+    /// Edit adding `Debug`, `None` for types holding secrets or fields that
+    /// may lack `Debug`
+    edit:      Option<TextEdit>
+}
+
+impl MissingDebugAnalyzer {
+    /// Create new missing debug analyzer instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Collect public types that neither derive nor implement `Debug`.
+    fn collect(ast: &File) -> Vec<Candidate> {
+        let implemented = debug_impls(ast);
+        let mut debug_types = derived_debug(ast);
+        debug_types.extend(implemented.iter().cloned());
+        let mut visitor = TypeVisitor {
+            implemented,
+            debug_types,
+            candidates: Vec::new()
+        };
+        visitor.visit_file(ast);
+        visitor.candidates
+    }
+}
+
+impl Analyzer for MissingDebugAnalyzer {
+    fn name(&self) -> &'static str {
+        "missing_debug"
+    }
+
+    fn analyze(&self, ast: &File, _content: &str) -> AppResult<AnalysisResult> {
+        let issues: Vec<Issue> = Self::collect(ast)
+            .into_iter()
+            .map(|candidate| {
+                let (hint, fix) = match candidate.edit {
+                    Some(_) => (
+                        "Derive `Debug` so the type can be inspected and embedded in other \
+                         `Debug` types",
+                        Fix::Simple("#[derive(Debug)]".to_string())
+                    ),
+                    None if candidate.sensitive => (
+                        "Implement `Debug` manually and print credential fields as \
+                         \"[REDACTED]\"",
+                        Fix::None
+                    ),
+                    None => (
+                        "Implement `Debug` manually, or derive it once every field type \
+                         implements `Debug`",
+                        Fix::None
+                    )
+                };
+                Issue {
+                    line: candidate.line,
+                    column: candidate.column,
+                    message: format!(
+                        "Public {} `{}` does not implement `Debug`\n{hint}",
+                        candidate.kind, candidate.name
+                    ),
+                    fix
+                }
+            })
+            .collect();
+
+        Ok(AnalysisResult {
+            fixable_count: issues.iter().filter(|i| i.fix.is_available()).count(),
+            issues
+        })
+    }
+
+    fn suggestions(&self, ast: &File, content: &str) -> AppResult<Vec<Suggestion>> {
+        Ok(Self::collect(ast)
+            .into_iter()
+            .filter_map(|candidate| candidate.edit)
+            .map(|edit| derive_suggestion(edit, content))
+            .collect())
+    }
+
+    fn fixable(&self) -> bool {
+        true
+    }
+
+    fn applies_to(&self, path: &Path) -> bool {
+        is_library_path(path)
+    }
+}
+
+impl Default for MissingDebugAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Collect names of types with a manual `Debug` implementation in a file.
+pub(crate) fn debug_impls(ast: &File) -> HashSet<String> {
+    let mut visitor = DebugImplVisitor {
+        types: HashSet::new()
+    };
+    visitor.visit_file(ast);
+    visitor.types
+}
+
+/// Collects names of types with a manual `Debug` implementation.
+struct DebugImplVisitor {
+    types: HashSet<String>
+}
+
+impl<'ast> Visit<'ast> for DebugImplVisitor {
+    fn visit_item_impl(&mut self, node: &'ast ItemImpl) {
+        let implements_debug = node
+            .trait_
+            .as_ref()
+            .and_then(|(_, path, _)| path.segments.last())
+            .is_some_and(|segment| segment.ident == "Debug");
+        if implements_debug && let Type::Path(path) = &*node.self_ty {
+            self.types.extend(
+                path.path
+                    .segments
+                    .last()
+                    .map(|segment| segment.ident.to_string())
+            );
+        }
+        syn::visit::visit_item_impl(self, node);
+    }
+}
+
+/// Collect names of types deriving `Debug` in a file.
+fn derived_debug(ast: &File) -> HashSet<String> {
+    let mut visitor = DerivedDebugVisitor {
+        types: HashSet::new()
+    };
+    visitor.visit_file(ast);
+    visitor.types
+}
+
+/// Collects names of types deriving `Debug`.
+struct DerivedDebugVisitor {
+    types: HashSet<String>
+}
+
+impl<'ast> Visit<'ast> for DerivedDebugVisitor {
+    fn visit_item_struct(&mut self, node: &'ast ItemStruct) {
+        if find_derive(&node.attrs, "Debug").is_some() {
+            self.types.insert(node.ident.to_string());
+        }
+    }
+
+    fn visit_item_enum(&mut self, node: &'ast ItemEnum) {
+        if find_derive(&node.attrs, "Debug").is_some() {
+            self.types.insert(node.ident.to_string());
+        }
+    }
+}
+
+struct TypeVisitor {
+    /// Types with a manual `Debug` implementation
+    implemented: HashSet<String>,
+    /// Types of the file implementing `Debug`, by hand or through a derive
+    debug_types: HashSet<String>,
+    candidates:  Vec<Candidate>
+}
+
+impl TypeVisitor {
+    /// Record a public type that lacks `Debug`.
+    fn check(
+        &mut self,
+        kind: &'static str,
+        vis: &Visibility,
+        ident: &Ident,
+        attrs: &[Attribute],
+        generics: &Generics,
+        fields: &[&Field]
+    ) {
+        let Visibility::Public(token) = vis else {
+            return;
+        };
+        if find_derive(attrs, "Debug").is_some()
+            || self.implemented.contains(&ident.to_string())
+            || is_test_code(attrs)
+        {
+            return;
+        }
+
+        let params: HashSet<String> = generics
+            .params
+            .iter()
+            .filter_map(|param| match param {
+                GenericParam::Type(param) => Some(param.ident.to_string()),
+                _ => None
+            })
+            .collect();
+        let sensitive = has_sensitive_field(fields.iter().copied());
+        let derivable = fields
+            .iter()
+            .all(|field| self.implements_debug(&field.ty, &params));
+        let edit = (!sensitive && derivable).then(|| derive_edit(attrs, token.span));
+        let start = ident.span().start();
+        self.candidates.push(Candidate {
+            kind,
+            name: ident.to_string(),
+            line: start.line,
+            column: start.column,
+            sensitive,
+            edit
+        });
+    }
+
+    /// Check whether a field type is known to implement `Debug`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ty` - Field type
+    /// * `params` - Type parameters of the item, bounded by the derive
+    fn implements_debug(&self, ty: &Type, params: &HashSet<String>) -> bool {
+        match ty {
+            Type::Path(path) if path.qself.is_none() => {
+                path.path.segments.last().is_some_and(|segment| {
+                    let name = segment.ident.to_string();
+                    let known = (path.path.segments.len() == 1 && params.contains(&name))
+                        || DEBUG_TYPES.contains(&name.as_str())
+                        || self.debug_types.contains(&name);
+                    known
+                        && match &segment.arguments {
+                            PathArguments::None => true,
+                            PathArguments::AngleBracketed(args) => {
+                                args.args.iter().all(|arg| match arg {
+                                    GenericArgument::Type(ty) => self.implements_debug(ty, params),
+                                    GenericArgument::Lifetime(_) | GenericArgument::Const(_) => {
+                                        true
+                                    }
+                                    _ => false
+                                })
+                            }
+                            PathArguments::Parenthesized(_) => false
+                        }
+                })
+            }
+            Type::Reference(reference) => self.implements_debug(&reference.elem, params),
+            Type::Array(array) => self.implements_debug(&array.elem, params),
+            Type::Slice(slice) => self.implements_debug(&slice.elem, params),
+            Type::Paren(paren) => self.implements_debug(&paren.elem, params),
+            Type::Group(group) => self.implements_debug(&group.elem, params),
+            Type::Tuple(tuple) => tuple
+                .elems
+                .iter()
+                .all(|elem| self.implements_debug(elem, params)),
+            Type::Ptr(_) | Type::BareFn(_) | Type::Never(_) => true,
+            _ => false
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for TypeVisitor {
+    fn visit_item_struct(&mut self, node: &'ast ItemStruct) {
+        let fields: Vec<&Field> = node.fields.iter().collect();
+        self.check(
+            "struct",
+            &node.vis,
+            &node.ident,
+            &node.attrs,
+            &node.generics,
+            &fields
+        );
+    }
+
+    fn visit_item_enum(&mut self, node: &'ast ItemEnum) {
+        let fields: Vec<&Field> = node
+            .variants
+            .iter()
+            .flat_map(|variant| variant.fields.iter())
+            .collect();
+        self.check(
+            "enum",
+            &node.vis,
+            &node.ident,
+            &node.attrs,
+            &node.generics,
+            &fields
+        );
+    }
+
+    fn visit_item_mod(&mut self, node: &'ast ItemMod) {
+        if !is_test_code(&node.attrs) {
+            syn::visit::visit_item_mod(self, node);
+        }
+    }
+}
+
+/// Edit adding `Debug` to the first `#[derive(..)]`, or a new derive
+/// attribute before the item.
+///
+/// A new attribute is returned without the trailing indentation, which
+/// depends on the source text, see [`derive_suggestion`].
+///
+/// # Arguments
+///
+/// * `attrs` - Attributes of the type
+/// * `item` - Span of the first token after the attributes
+pub(crate) fn derive_edit(attrs: &[Attribute], item: Span) -> TextEdit {
+    let derive_list = attrs.iter().find_map(|attr| match &attr.meta {
+        Meta::List(list) if attr.path().is_ident("derive") => match &list.delimiter {
+            MacroDelimiter::Paren(paren) => Some((paren.span.open(), list.tokens.is_empty())),
+            _ => None
+        },
+        _ => None
+    });
+
+    match derive_list {
+        Some((open, empty)) => {
+            let offset = open.byte_range().end;
+            TextEdit {
+                range:       offset..offset,
+                replacement: if empty { "Debug" } else { "Debug, " }.to_string()
+            }
+        }
+        None => {
+            let offset = item.byte_range().start;
+            TextEdit {
+                range:       offset..offset,
+                replacement: "#[derive(Debug)]\n".to_string()
+            }
+        }
+    }
+}
+
+/// Suggestion for a [`derive_edit`], indenting a new attribute like the
+/// item it is inserted before.
+pub(crate) fn derive_suggestion(mut edit: TextEdit, content: &str) -> Suggestion {
+    if edit.replacement.starts_with('#') {
+        let line_start = content[..edit.range.start]
+            .rfind('\n')
+            .map_or(0, |idx| idx + 1);
+        edit.replacement
+            .push_str(&content[line_start..edit.range.start]);
+    }
+    Suggestion {
+        edit,
+        import: None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analyze(code: &str) -> AnalysisResult {
+        let ast = syn::parse_file(code).unwrap();
+        MissingDebugAnalyzer::new().analyze(&ast, code).unwrap()
+    }
+
+    fn fix(code: &str) -> String {
+        let ast = syn::parse_file(code).unwrap();
+        let suggestions = MissingDebugAnalyzer::new().suggestions(&ast, code).unwrap();
+        crate::fixer::apply_suggestions(code, &suggestions).source
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        let analyzer = MissingDebugAnalyzer::new();
+        assert_eq!(analyzer.name(), "missing_debug");
+    }
+
+    #[test]
+    fn test_detect_public_types_without_debug() {
+        let result = analyze(
+            r#"
+#[derive(Clone)]
+pub struct Endpoint { pub url: String }
+
+pub enum Mode { Fast, Slow }
+
+pub struct Login { pub user: String, pub password: String }
+"#
+        );
+
+        assert_eq!(result.issues.len(), 3);
+        assert_eq!(result.fixable_count, 2);
+        assert_eq!(result.issues[0].line, 3);
+        assert!(
+            result.issues[0]
+                .message
+                .contains("Public struct `Endpoint` does not implement `Debug`")
+        );
+        assert!(result.issues[1].message.contains("Public enum `Mode`"));
+        assert!(result.issues[2].message.contains("[REDACTED]"));
+        assert_eq!(result.issues[2].fix, Fix::None);
+    }
+
+    #[test]
+    fn test_no_fix_when_fields_may_lack_debug() {
+        let code = r#"
+pub struct Holder { callback: Box<dyn Fn()> }
+
+pub struct Client(reqwest::Client);
+
+pub enum Source { Reader(Box<dyn Read>) }
+
+pub struct Wrapper<'a, T>(T, &'a [Option<u8>], HashMap<String, Vec<Mode>>);
+
+#[derive(Debug)]
+pub enum Mode { Fast }
+"#;
+        let result = analyze(code);
+
+        assert_eq!(result.issues.len(), 4);
+        assert_eq!(result.fixable_count, 1);
+        assert_eq!(result.issues[0].fix, Fix::None);
+        assert!(result.issues[0].message.ends_with(
+            "Implement `Debug` manually, or derive it once every field type implements `Debug`"
+        ));
+        assert_eq!(result.issues[1].fix, Fix::None);
+        assert_eq!(result.issues[2].fix, Fix::None);
+        assert!(result.issues[3].message.contains("`Wrapper`"));
+        assert_eq!(
+            fix(code),
+            code.replace("pub struct Wrapper", "#[derive(Debug)]\npub struct Wrapper")
+        );
+    }
+
+    #[test]
+    fn test_ignore_types_with_debug_or_private() {
+        let result = analyze(
+            r#"
+#[derive(Debug, Clone)]
+pub struct Derived;
+
+#[derive(std::fmt::Debug)]
+pub enum Qualified { A }
+
+pub struct Manual;
+impl fmt::Debug for Manual {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.write_str("Manual") }
+}
+
+struct Private;
+pub(crate) struct Internal;
+
+#[cfg(test)]
+mod tests {
+    pub struct Fixture;
+}
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_fix_adds_debug_derive() {
+        let code = r#"mod model {
+    /// An endpoint.
+    pub struct Endpoint;
+
+    #[derive(Clone, PartialEq)]
+    pub enum Mode {
+        Fast
+    }
+
+    #[derive()]
+    pub struct Empty;
+}
+"#;
+
+        assert_eq!(
+            fix(code),
+            r#"mod model {
+    /// An endpoint.
+    #[derive(Debug)]
+    pub struct Endpoint;
+
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum Mode {
+        Fast
+    }
+
+    #[derive(Debug)]
+    pub struct Empty;
+}
+"#
+        );
+    }
+
+    #[test]
+    fn test_only_library_files() {
+        let analyzer = MissingDebugAnalyzer::new();
+        assert!(analyzer.applies_to(Path::new("src/model.rs")));
+        assert!(!analyzer.applies_to(Path::new("src/main.rs")));
+    }
+
+    #[test]
+    fn test_default_implementation() {
+        let analyzer = MissingDebugAnalyzer;
+        assert_eq!(analyzer.name(), "missing_debug");
+    }
+}
@@ -0,0 +1,342 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Missing docs analyzer for undocumented public items.
+//!
+//! RustManifest asks for every public API to explain itself with a `///`
+//! doc comment. This analyzer flags `pub` functions, methods, structs,
+//! enums, traits and inline modules without one. Items marked
+//! `#[doc(hidden)]`, or nested in a hidden module or impl, are skipped.
+
+use masterror::AppResult;
+use proc_macro2::Ident;
+use syn::{
+    Attribute, Expr, ExprLit, File, ImplItemFn, ItemEnum, ItemFn, ItemImpl, ItemMod, ItemStruct,
+    ItemTrait, Lit, Meta, MetaNameValue, Visibility, visit::Visit
+};
+
+use super::is_test_code;
+use crate::analyzer::{AnalysisResult, Analyzer, Fix, Issue};
+
+/// Analyzer for public items without a `///` doc comment.
+///
+/// # Examples
+///
+/// Detects this pattern:
+/// ```ignore
+/// pub struct Config {
+///     pub path: PathBuf
+/// }
+/// ```
+///
+/// Suggests:
+/// ```ignore
+/// /// Settings loaded from `quality.toml`.
+/// pub struct Config {
+///     pub path: PathBuf
+/// }
+/// ```
+pub struct MissingDocsAnalyzer;
+
+impl MissingDocsAnalyzer {
+    /// Create new missing docs analyzer instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Analyzer for MissingDocsAnalyzer {
+    fn name(&self) -> &'static str {
+        "missing_docs"
+    }
+
+    fn analyze(&self, ast: &File, _content: &str) -> AppResult<AnalysisResult> {
+        let mut visitor = DocsVisitor {
+            issues: Vec::new()
+        };
+        visitor.visit_file(ast);
+
+        Ok(AnalysisResult {
+            issues:        visitor.issues,
+            fixable_count: 0
+        })
+    }
+}
+
+impl Default for MissingDocsAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct DocsVisitor {
+    issues: Vec<Issue>
+}
+
+impl DocsVisitor {
+    /// Report a public item without documentation.
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - Item kind shown in the message, e.g. `function`
+    /// * `attrs` - Outer attributes of the item
+    /// * `vis` - Visibility of the item
+    /// * `ident` - Name of the item
+    fn check(&mut self, kind: &str, attrs: &[Attribute], vis: &Visibility, ident: &Ident) {
+        if !matches!(vis, Visibility::Public(_)) || has_docs(attrs) {
+            return;
+        }
+
+        let start = ident.span().start();
+        self.issues.push(Issue {
+            line:    start.line,
+            column:  start.column,
+            message: format!(
+                "Public {kind} `{ident}` has no doc comment\n\
+                 Add a `///` comment explaining what it is for, or mark it `#[doc(hidden)]`"
+            ),
+            fix:     Fix::None
+        });
+    }
+}
+
+impl<'ast> Visit<'ast> for DocsVisitor {
+    fn visit_item_fn(&mut self, node: &'ast ItemFn) {
+        if !is_test_code(&node.attrs) && !is_hidden(&node.attrs) {
+            self.check("function", &node.attrs, &node.vis, &node.sig.ident);
+        }
+    }
+
+    fn visit_item_struct(&mut self, node: &'ast ItemStruct) {
+        if !is_hidden(&node.attrs) {
+            self.check("struct", &node.attrs, &node.vis, &node.ident);
+        }
+    }
+
+    fn visit_item_enum(&mut self, node: &'ast ItemEnum) {
+        if !is_hidden(&node.attrs) {
+            self.check("enum", &node.attrs, &node.vis, &node.ident);
+        }
+    }
+
+    fn visit_item_trait(&mut self, node: &'ast ItemTrait) {
+        if !is_hidden(&node.attrs) {
+            self.check("trait", &node.attrs, &node.vis, &node.ident);
+        }
+    }
+
+    fn visit_item_impl(&mut self, node: &'ast ItemImpl) {
+        if node.trait_.is_none() && !is_hidden(&node.attrs) {
+            syn::visit::visit_item_impl(self, node);
+        }
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'ast ImplItemFn) {
+        if !is_test_code(&node.attrs) && !is_hidden(&node.attrs) {
+            self.check("method", &node.attrs, &node.vis, &node.sig.ident);
+        }
+    }
+
+    /// Check inline modules and their items.
+    ///
+    /// # Notes
+    ///
+    /// - `mod name;` is documented with `//!` in its own file; inline modules
+    ///   carry both `///` and `//!` docs in their attributes
+    fn visit_item_mod(&mut self, node: &'ast ItemMod) {
+        if is_test_code(&node.attrs) || is_hidden(&node.attrs) {
+            return;
+        }
+
+        if node.content.is_some() {
+            self.check("module", &node.attrs, &node.vis, &node.ident);
+            syn::visit::visit_item_mod(self, node);
+        }
+    }
+}
+
+/// Check whether attributes include a doc comment (`///` or `#[doc = ..]`).
+pub(crate) fn has_docs(attrs: &[Attribute]) -> bool {
+    attrs
+        .iter()
+        .any(|attr| attr.path().is_ident("doc") && matches!(attr.meta, Meta::NameValue(_)))
+}
+
+/// Check whether a doc comment has a `# Heading` section.
+///
+/// # Arguments
+///
+/// * `attrs` - Outer attributes of the item
+/// * `heading` - Section name without the `#`, e.g. `Errors`
+///
+/// # Returns
+///
+/// `true` if any doc line is a level-one heading with that name
+pub(crate) fn has_doc_section(attrs: &[Attribute], heading: &str) -> bool {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .filter_map(|attr| match &attr.meta {
+            Meta::NameValue(MetaNameValue {
+                value:
+                    Expr::Lit(ExprLit {
+                        lit: Lit::Str(text),
+                        ..
+                    }),
+                ..
+            }) => Some(text.value()),
+            _ => None
+        })
+        .any(|text| {
+            text.lines()
+                .any(|line| line.trim().strip_prefix("# ") == Some(heading))
+        })
+}
+
+/// Check whether an item is marked `#[doc(hidden)]`.
+pub(crate) fn is_hidden(attrs: &[Attribute]) -> bool {
+    attrs.iter().any(|attr| {
+        attr.path().is_ident("doc")
+            && attr
+                .meta
+                .require_list()
+                .is_ok_and(|list| list.tokens.to_string() == "hidden")
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analyze(code: &str) -> AnalysisResult {
+        let ast = syn::parse_file(code).unwrap();
+        MissingDocsAnalyzer::new().analyze(&ast, code).unwrap()
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        let analyzer = MissingDocsAnalyzer::new();
+        assert_eq!(analyzer.name(), "missing_docs");
+    }
+
+    #[test]
+    fn test_detect_undocumented_public_items() {
+        let result = analyze(
+            r#"
+pub struct Config;
+pub enum Mode { Fast }
+pub trait Load {}
+pub fn run() {}
+
+impl Config {
+    pub fn new() -> Self { Self }
+}
+
+pub mod util {}
+"#
+        );
+
+        let kinds: Vec<_> = result
+            .issues
+            .iter()
+            .map(|issue| issue.message.lines().next().unwrap().to_string())
+            .collect();
+        assert_eq!(
+            kinds,
+            [
+                "Public struct `Config` has no doc comment",
+                "Public enum `Mode` has no doc comment",
+                "Public trait `Load` has no doc comment",
+                "Public function `run` has no doc comment",
+                "Public method `new` has no doc comment",
+                "Public module `util` has no doc comment"
+            ]
+        );
+        assert_eq!(result.issues[0].line, 2);
+    }
+
+    #[test]
+    fn test_accept_documented_and_private_items() {
+        let result = analyze(
+            r#"
+/// Settings.
+pub struct Config;
+
+#[doc = "Run mode."]
+pub enum Mode { Fast }
+
+pub mod util {
+    //! Helpers.
+
+    fn private() {}
+    pub(crate) fn internal() {}
+}
+
+pub mod analyzers;
+
+impl Display for Config {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result { Ok(()) }
+}
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_honor_doc_hidden() {
+        let result = analyze(
+            r#"
+#[doc(hidden)]
+pub fn internal() {}
+
+#[doc(hidden)]
+pub mod private {
+    pub fn helper() {}
+}
+
+#[doc(hidden)]
+impl Config {
+    pub fn raw(&self) {}
+}
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_ignore_test_code() {
+        let result = analyze(
+            r#"
+#[cfg(test)]
+mod tests {
+    pub fn fixture() {}
+}
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_has_doc_section() {
+        let ast: ItemFn = syn::parse_quote! {
+            /// Load settings.
+            ///
+            /// # Errors
+            ///
+            /// Fails if the file is missing.
+            fn load() {}
+        };
+        assert!(has_doc_section(&ast.attrs, "Errors"));
+        assert!(!has_doc_section(&ast.attrs, "Panics"));
+    }
+
+    #[test]
+    fn test_default_implementation() {
+        let analyzer = MissingDocsAnalyzer;
+        assert_eq!(analyzer.name(), "missing_docs");
+    }
+}
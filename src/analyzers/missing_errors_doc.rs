@@ -0,0 +1,233 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Missing errors doc analyzer for fallible public functions.
+//!
+//! This crate documents every public function returning `AppResult` with an
+//! `# Errors` section describing when it fails. This analyzer flags
+//! documented `pub` functions and inherent methods returning a `Result`
+//! type whose doc comment has no such section. Undocumented functions are
+//! left to the missing docs analyzer.
+
+use masterror::AppResult;
+use syn::{
+    Attribute, File, ImplItemFn, ItemFn, ItemImpl, ItemMod, Signature, Visibility, visit::Visit
+};
+
+use super::{
+    is_test_code,
+    missing_docs::{has_doc_section, has_docs, is_hidden},
+    returns_result
+};
+use crate::analyzer::{AnalysisResult, Analyzer, Fix, Issue};
+
+/// Analyzer for documented fallible public functions without `# Errors`.
+///
+/// # Examples
+///
+/// Detects this pattern:
+/// ```ignore
+/// /// Load configuration from disk.
+/// pub fn load(path: &Path) -> AppResult<Config> { ... }
+/// ```
+///
+/// Suggests:
+/// ```ignore
+/// /// Load configuration from disk.
+/// ///
+/// /// # Errors
+/// ///
+/// /// Returns an error if the file cannot be read or parsed.
+/// pub fn load(path: &Path) -> AppResult<Config> { ... }
+/// ```
+pub struct MissingErrorsDocAnalyzer;
+
+impl MissingErrorsDocAnalyzer {
+    /// Create new missing errors doc analyzer instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Analyzer for MissingErrorsDocAnalyzer {
+    fn name(&self) -> &'static str {
+        "missing_errors_doc"
+    }
+
+    fn analyze(&self, ast: &File, _content: &str) -> AppResult<AnalysisResult> {
+        let mut visitor = ErrorsDocVisitor {
+            issues: Vec::new()
+        };
+        visitor.visit_file(ast);
+
+        Ok(AnalysisResult {
+            issues:        visitor.issues,
+            fixable_count: 0
+        })
+    }
+}
+
+impl Default for MissingErrorsDocAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct ErrorsDocVisitor {
+    issues: Vec<Issue>
+}
+
+impl ErrorsDocVisitor {
+    /// Report a documented fallible public function without `# Errors`.
+    ///
+    /// # Arguments
+    ///
+    /// * `attrs` - Outer attributes of the function
+    /// * `vis` - Visibility of the function
+    /// * `sig` - Signature of the function
+    fn check(&mut self, attrs: &[Attribute], vis: &Visibility, sig: &Signature) {
+        if !matches!(vis, Visibility::Public(_))
+            || is_test_code(attrs)
+            || is_hidden(attrs)
+            || !has_docs(attrs)
+            || !returns_result(&sig.output)
+            || has_doc_section(attrs, "Errors")
+        {
+            return;
+        }
+
+        let start = sig.ident.span().start();
+        self.issues.push(Issue {
+            line:    start.line,
+            column:  start.column,
+            message: format!(
+                "Public function `{}` returns `Result` but its docs have no `# Errors` \
+                 section\n\
+                 Add `# Errors` describing the conditions under which it fails",
+                sig.ident
+            ),
+            fix:     Fix::None
+        });
+    }
+}
+
+impl<'ast> Visit<'ast> for ErrorsDocVisitor {
+    fn visit_item_fn(&mut self, node: &'ast ItemFn) {
+        self.check(&node.attrs, &node.vis, &node.sig);
+    }
+
+    fn visit_item_impl(&mut self, node: &'ast ItemImpl) {
+        if node.trait_.is_none() && !is_hidden(&node.attrs) {
+            syn::visit::visit_item_impl(self, node);
+        }
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'ast ImplItemFn) {
+        self.check(&node.attrs, &node.vis, &node.sig);
+    }
+
+    fn visit_item_mod(&mut self, node: &'ast ItemMod) {
+        if !is_test_code(&node.attrs) && !is_hidden(&node.attrs) {
+            syn::visit::visit_item_mod(self, node);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analyze(code: &str) -> AnalysisResult {
+        let ast = syn::parse_file(code).unwrap();
+        MissingErrorsDocAnalyzer::new().analyze(&ast, code).unwrap()
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        let analyzer = MissingErrorsDocAnalyzer::new();
+        assert_eq!(analyzer.name(), "missing_errors_doc");
+    }
+
+    #[test]
+    fn test_detect_missing_errors_section() {
+        let result = analyze(
+            r#"
+/// Load configuration.
+pub fn load() -> AppResult<Config> { todo!() }
+
+impl Config {
+    /// Save configuration.
+    ///
+    /// # Returns
+    ///
+    /// Nothing on success
+    pub fn save(&self) -> std::io::Result<()> { Ok(()) }
+}
+"#
+        );
+
+        assert_eq!(result.issues.len(), 2);
+        assert_eq!(result.issues[0].line, 3);
+        assert!(
+            result.issues[0]
+                .message
+                .contains("`load` returns `Result` but its docs have no `# Errors`")
+        );
+        assert!(result.issues[1].message.contains("`save`"));
+    }
+
+    #[test]
+    fn test_accept_documented_errors() {
+        let result = analyze(
+            r#"
+/// Load configuration.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read.
+pub fn load() -> AppResult<Config> { todo!() }
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_ignore_other_functions() {
+        let result = analyze(
+            r#"
+pub fn undocumented() -> Result<(), Error> { Ok(()) }
+
+/// Private.
+fn private() -> Result<(), Error> { Ok(()) }
+
+/// Infallible.
+pub fn name() -> String { String::new() }
+
+impl Display for Config {
+    /// Format.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result { Ok(()) }
+}
+
+#[doc(hidden)]
+/// Hidden.
+pub fn internal() -> Result<(), Error> { Ok(()) }
+
+#[cfg(test)]
+mod tests {
+    /// Fixture.
+    pub fn fixture() -> Result<(), Error> { Ok(()) }
+}
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_default_implementation() {
+        let analyzer = MissingErrorsDocAnalyzer;
+        assert_eq!(analyzer.name(), "missing_errors_doc");
+    }
+}
@@ -0,0 +1,374 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Missing inline analyzer for trivial public functions.
+//!
+//! Non-generic functions are not available for inlining in other crates
+//! unless marked `#[inline]`, so small accessors and constructors in a
+//! library cost a call across crate boundaries. This crate marks such
+//! functions `#[inline]` throughout; this analyzer finds public functions
+//! whose body is a single expression without branching and inserts the
+//! attribute.
+
+use std::path::Path;
+
+use masterror::AppResult;
+use syn::{
+    Attribute, Block, Expr, ExprClosure, ExprForLoop, ExprIf, ExprLoop, ExprMatch, ExprWhile,
+    File, ImplItemFn, ItemFn, ItemImpl, ItemMod, Signature, Stmt, Visibility, spanned::Spanned,
+    visit::Visit
+};
+
+use super::{is_library_path, is_test_code};
+use crate::analyzer::{AnalysisResult, Analyzer, Fix, Issue, Suggestion, TextEdit};
+
+/// Maximum number of lines of a trivial function body, braces included.
+const MAX_BODY_LINES: usize = 5;
+
+/// Analyzer for trivial public functions without `#[inline]`.
+///
+/// # Examples
+///
+/// Detects this pattern:
+/// ```ignore
+/// impl Config {
+///     pub fn name(&self) -> &str {
+///         &self.name
+///     }
+/// }
+/// ```
+///
+/// Fixes to:
+/// ```ignore
+/// impl Config {
+///     #[inline]
+///     pub fn name(&self) -> &str {
+///         &self.name
+///     }
+/// }
+/// ```
+pub struct MissingInlineAnalyzer;
+
+/// Trivial public function without `#[inline]`.
+struct Candidate {
+    name:   String,
+    line:   usize,
+    column: usize,
+    /// Byte offset of the visibility, where the attribute is inserted
+    offset: usize
+}
+
+impl MissingInlineAnalyzer {
+    /// Create new missing inline analyzer instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Collect trivial public functions lacking an inline attribute.
+    fn collect(ast: &File) -> Vec<Candidate> {
+        let mut visitor = InlineVisitor {
+            candidates: Vec::new()
+        };
+        visitor.visit_file(ast);
+        visitor.candidates
+    }
+}
+
+impl Analyzer for MissingInlineAnalyzer {
+    fn name(&self) -> &'static str {
+        "missing_inline"
+    }
+
+    fn analyze(&self, ast: &File, _content: &str) -> AppResult<AnalysisResult> {
+        let issues: Vec<Issue> = Self::collect(ast)
+            .into_iter()
+            .map(|candidate| Issue {
+                line:    candidate.line,
+                column:  candidate.column,
+                message: format!(
+                    "Trivial public function `{}` is missing `#[inline]`\n\
+                     Mark small library functions `#[inline]` so other crates can inline them",
+                    candidate.name
+                ),
+                fix:     Fix::Simple("#[inline]".to_string())
+            })
+            .collect();
+
+        Ok(AnalysisResult {
+            fixable_count: issues.len(),
+            issues
+        })
+    }
+
+    fn suggestions(&self, ast: &File, content: &str) -> AppResult<Vec<Suggestion>> {
+        Ok(Self::collect(ast)
+            .into_iter()
+            .map(|candidate| {
+                let line_start = content[..candidate.offset]
+                    .rfind('\n')
+                    .map_or(0, |idx| idx + 1);
+                let indent = &content[line_start..candidate.offset];
+
+                Suggestion {
+                    edit:   TextEdit {
+                        range:       candidate.offset..candidate.offset,
+                        replacement: format!("#[inline]\n{indent}")
+                    },
+                    import: None
+                }
+            })
+            .collect())
+    }
+
+    fn fixable(&self) -> bool {
+        true
+    }
+
+    fn applies_to(&self, path: &Path) -> bool {
+        is_library_path(path)
+    }
+}
+
+impl Default for MissingInlineAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct InlineVisitor {
+    candidates: Vec<Candidate>
+}
+
+impl InlineVisitor {
+    /// Record a public, non-generic function with a trivial body.
+    fn check_function(
+        &mut self,
+        attrs: &[Attribute],
+        vis: &Visibility,
+        sig: &Signature,
+        block: &Block
+    ) {
+        let Visibility::Public(token) = vis else {
+            return;
+        };
+        if attrs.iter().any(|attr| attr.path().is_ident("inline"))
+            || sig.asyncness.is_some()
+            || sig.generics.type_params().next().is_some()
+            || !is_trivial(block)
+        {
+            return;
+        }
+
+        let start = sig.ident.span().start();
+        self.candidates.push(Candidate {
+            name:   sig.ident.to_string(),
+            line:   start.line,
+            column: start.column,
+            offset: token.span.byte_range().start
+        });
+    }
+}
+
+impl<'ast> Visit<'ast> for InlineVisitor {
+    fn visit_item_fn(&mut self, node: &'ast ItemFn) {
+        if !is_test_code(&node.attrs) {
+            self.check_function(&node.attrs, &node.vis, &node.sig, &node.block);
+        }
+    }
+
+    fn visit_item_impl(&mut self, node: &'ast ItemImpl) {
+        if node.trait_.is_none() {
+            syn::visit::visit_item_impl(self, node);
+        }
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'ast ImplItemFn) {
+        if !is_test_code(&node.attrs) {
+            self.check_function(&node.attrs, &node.vis, &node.sig, &node.block);
+        }
+    }
+
+    fn visit_item_mod(&mut self, node: &'ast ItemMod) {
+        if !is_test_code(&node.attrs) {
+            syn::visit::visit_item_mod(self, node);
+        }
+    }
+}
+
+/// Check whether a body is one short expression without branching or loops.
+fn is_trivial(block: &Block) -> bool {
+    let [Stmt::Expr(expr, None)] = block.stmts.as_slice() else {
+        return false;
+    };
+    let span = block.span();
+    if span.end().line - span.start().line + 1 > MAX_BODY_LINES {
+        return false;
+    }
+
+    let mut branches = BranchVisitor {
+        found: false
+    };
+    branches.visit_expr(expr);
+    !branches.found
+}
+
+/// Detects control flow and closures inside an expression.
+struct BranchVisitor {
+    found: bool
+}
+
+impl<'ast> Visit<'ast> for BranchVisitor {
+    fn visit_expr(&mut self, node: &'ast Expr) {
+        if matches!(node, Expr::Block(_) | Expr::Unsafe(_)) {
+            self.found = true;
+        }
+        syn::visit::visit_expr(self, node);
+    }
+
+    fn visit_expr_if(&mut self, _node: &'ast ExprIf) {
+        self.found = true;
+    }
+
+    fn visit_expr_match(&mut self, _node: &'ast ExprMatch) {
+        self.found = true;
+    }
+
+    fn visit_expr_for_loop(&mut self, _node: &'ast ExprForLoop) {
+        self.found = true;
+    }
+
+    fn visit_expr_while(&mut self, _node: &'ast ExprWhile) {
+        self.found = true;
+    }
+
+    fn visit_expr_loop(&mut self, _node: &'ast ExprLoop) {
+        self.found = true;
+    }
+
+    fn visit_expr_closure(&mut self, _node: &'ast ExprClosure) {
+        self.found = true;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analyze(code: &str) -> AnalysisResult {
+        let ast = syn::parse_file(code).unwrap();
+        MissingInlineAnalyzer::new().analyze(&ast, code).unwrap()
+    }
+
+    fn fix(code: &str) -> String {
+        let ast = syn::parse_file(code).unwrap();
+        let suggestions = MissingInlineAnalyzer::new()
+            .suggestions(&ast, code)
+            .unwrap();
+        crate::fixer::apply_suggestions(code, &suggestions).source
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        let analyzer = MissingInlineAnalyzer::new();
+        assert_eq!(analyzer.name(), "missing_inline");
+    }
+
+    #[test]
+    fn test_detect_trivial_public_functions() {
+        let result = analyze(
+            r#"
+pub fn version() -> u32 {
+    VERSION
+}
+
+impl Config {
+    /// Configured name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.len() == 0
+    }
+}
+"#
+        );
+
+        assert_eq!(result.issues.len(), 3);
+        assert_eq!(result.fixable_count, 3);
+        assert_eq!(result.issues[1].line, 8);
+        assert!(
+            result.issues[1]
+                .message
+                .contains("`name` is missing `#[inline]`")
+        );
+    }
+
+    #[test]
+    fn test_fix_inserts_attribute_after_docs() {
+        let code = r#"impl Config {
+    /// Configured name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+"#;
+
+        assert_eq!(
+            fix(code),
+            r#"impl Config {
+    /// Configured name.
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+"#
+        );
+    }
+
+    #[test]
+    fn test_ignore_non_trivial_and_non_public() {
+        let result = analyze(
+            r#"
+#[inline]
+pub fn id() -> u32 { 1 }
+
+fn private() -> u32 { 1 }
+pub(crate) fn internal() -> u32 { 1 }
+pub fn generic<T: Default>() -> T { T::default() }
+pub async fn fetch() -> u32 { 1 }
+pub fn sign(n: i32) -> i32 { if n < 0 { -1 } else { 1 } }
+pub fn evens(v: &[u32]) -> Vec<u32> { v.iter().copied().filter(|n| n % 2 == 0).collect() }
+pub fn setup() { init(); start() }
+pub fn all() -> Vec<u32> {
+    vec![
+        1,
+        2,
+        3
+    ]
+}
+
+impl Display for Config {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result { write!(f, "config") }
+}
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_only_library_files() {
+        let analyzer = MissingInlineAnalyzer::new();
+        assert!(analyzer.applies_to(Path::new("src/config.rs")));
+        assert!(!analyzer.applies_to(Path::new("src/main.rs")));
+    }
+
+    #[test]
+    fn test_default_implementation() {
+        let analyzer = MissingInlineAnalyzer;
+        assert_eq!(analyzer.name(), "missing_inline");
+    }
+}
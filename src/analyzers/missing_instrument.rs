@@ -0,0 +1,346 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Missing instrumentation analyzer for public async functions.
+//!
+//! In services built on `tracing`, public async entry points are where
+//! requests cross module boundaries. A function without `#[instrument]` and
+//! without a single event is invisible in traces. This analyzer is opt-in:
+//! enable it per project with `enable = ["missing_instrument"]` under
+//! `[package.metadata.quality]`.
+
+use masterror::AppResult;
+use syn::{
+    Attribute, Block, ExprMethodCall, File, ImplItemFn, ItemFn, ItemImpl, ItemMod, Macro,
+    Signature, Visibility, visit::Visit
+};
+
+use super::is_test_code;
+use crate::analyzer::{AnalysisResult, Analyzer, Fix, Issue};
+
+/// Macros that emit a `tracing` event or open a span.
+const EVENT_MACROS: &[&str] = &[
+    "trace",
+    "debug",
+    "info",
+    "warn",
+    "error",
+    "event",
+    "span",
+    "trace_span",
+    "debug_span",
+    "info_span",
+    "warn_span",
+    "error_span"
+];
+
+/// Analyzer for public async functions that are invisible to `tracing`.
+///
+/// Without `tracing` in the project dependencies the analyzer reports
+/// nothing.
+///
+/// # Examples
+///
+/// Detects this pattern:
+/// ```ignore
+/// pub async fn fetch_user(id: u64) -> Result<User, Error> {
+///     db.load(id).await
+/// }
+/// ```
+///
+/// Suggests:
+/// ```ignore
+/// #[tracing::instrument(skip(db))]
+/// pub async fn fetch_user(id: u64) -> Result<User, Error> {
+///     db.load(id).await
+/// }
+/// ```
+pub struct MissingInstrumentAnalyzer {
+    tracing: bool
+}
+
+impl MissingInstrumentAnalyzer {
+    /// Create new missing instrumentation analyzer for a `tracing` project.
+    #[inline]
+    pub fn new() -> Self {
+        Self::with_tracing(true)
+    }
+
+    /// Create analyzer that is active only if the project uses `tracing`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tracing` - Whether `tracing` is a dependency of the project
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cargo_quality::{analyzer::Analyzer, analyzers::MissingInstrumentAnalyzer};
+    ///
+    /// let analyzer = MissingInstrumentAnalyzer::with_tracing(false);
+    /// let code = "pub async fn run() {}";
+    /// let ast = syn::parse_file(code).unwrap();
+    /// assert!(analyzer.analyze(&ast, code).unwrap().issues.is_empty());
+    /// ```
+    #[inline]
+    pub fn with_tracing(tracing: bool) -> Self {
+        Self {
+            tracing
+        }
+    }
+}
+
+impl Analyzer for MissingInstrumentAnalyzer {
+    fn name(&self) -> &'static str {
+        "missing_instrument"
+    }
+
+    fn analyze(&self, ast: &File, _content: &str) -> AppResult<AnalysisResult> {
+        if !self.tracing {
+            return Ok(AnalysisResult::default());
+        }
+
+        let mut visitor = InstrumentVisitor {
+            issues: Vec::new()
+        };
+        visitor.visit_file(ast);
+
+        Ok(AnalysisResult {
+            issues:        visitor.issues,
+            fixable_count: 0
+        })
+    }
+
+    fn opt_in(&self) -> bool {
+        true
+    }
+}
+
+impl Default for MissingInstrumentAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct InstrumentVisitor {
+    issues: Vec<Issue>
+}
+
+impl InstrumentVisitor {
+    /// Report a public async function that is neither instrumented nor emits
+    /// events.
+    fn check(&mut self, attrs: &[Attribute], vis: &Visibility, sig: &Signature, block: &Block) {
+        if !matches!(vis, Visibility::Public(_)) || sig.asyncness.is_none() {
+            return;
+        }
+        if attrs.iter().any(is_instrument) || emits_events(block) {
+            return;
+        }
+
+        let start = sig.ident.span().start();
+        self.issues.push(Issue {
+            line:    start.line,
+            column:  start.column,
+            message: format!(
+                "Public async fn `{}` has no `#[instrument]` and emits no tracing events\n\
+                 Add `#[tracing::instrument]` so calls appear in traces",
+                sig.ident
+            ),
+            fix:     Fix::None
+        });
+    }
+}
+
+impl<'ast> Visit<'ast> for InstrumentVisitor {
+    fn visit_item_fn(&mut self, node: &'ast ItemFn) {
+        if !is_test_code(&node.attrs) {
+            self.check(&node.attrs, &node.vis, &node.sig, &node.block);
+        }
+    }
+
+    fn visit_item_impl(&mut self, node: &'ast ItemImpl) {
+        if node.trait_.is_none() {
+            syn::visit::visit_item_impl(self, node);
+        }
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'ast ImplItemFn) {
+        if !is_test_code(&node.attrs) {
+            self.check(&node.attrs, &node.vis, &node.sig, &node.block);
+        }
+    }
+
+    fn visit_item_mod(&mut self, node: &'ast ItemMod) {
+        if !is_test_code(&node.attrs) {
+            syn::visit::visit_item_mod(self, node);
+        }
+    }
+}
+
+/// Check whether an attribute is `#[instrument]` or `#[tracing::instrument]`.
+fn is_instrument(attr: &Attribute) -> bool {
+    attr.path()
+        .segments
+        .last()
+        .is_some_and(|seg| seg.ident == "instrument")
+}
+
+/// Check whether a function body emits an event, opens a span or attaches
+/// one with `.instrument(...)`.
+fn emits_events(block: &Block) -> bool {
+    struct EventVisitor {
+        found: bool
+    }
+
+    impl<'ast> Visit<'ast> for EventVisitor {
+        fn visit_macro(&mut self, node: &'ast Macro) {
+            if node
+                .path
+                .segments
+                .last()
+                .is_some_and(|seg| EVENT_MACROS.contains(&seg.ident.to_string().as_str()))
+            {
+                self.found = true;
+            }
+            syn::visit::visit_macro(self, node);
+        }
+
+        fn visit_expr_method_call(&mut self, node: &'ast ExprMethodCall) {
+            if node.method == "instrument" {
+                self.found = true;
+            }
+            syn::visit::visit_expr_method_call(self, node);
+        }
+    }
+
+    let mut visitor = EventVisitor {
+        found: false
+    };
+    visitor.visit_block(block);
+    visitor.found
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::parse_quote;
+
+    use super::*;
+
+    fn analyze(code: File) -> AnalysisResult {
+        MissingInstrumentAnalyzer::new().analyze(&code, "").unwrap()
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        let analyzer = MissingInstrumentAnalyzer::new();
+        assert_eq!(analyzer.name(), "missing_instrument");
+    }
+
+    #[test]
+    fn test_is_opt_in() {
+        assert!(MissingInstrumentAnalyzer::new().opt_in());
+    }
+
+    #[test]
+    fn test_detect_public_async_fn() {
+        let result = analyze(parse_quote! {
+            pub async fn fetch(id: u64) -> User {
+                load(id).await
+            }
+        });
+
+        assert_eq!(result.issues.len(), 1);
+        assert!(result.issues[0].message.contains("`fetch`"));
+    }
+
+    #[test]
+    fn test_detect_inherent_method() {
+        let result = analyze(parse_quote! {
+            impl Service {
+                pub async fn handle(&self) {}
+                async fn private(&self) {}
+            }
+        });
+
+        assert_eq!(result.issues.len(), 1);
+        assert!(result.issues[0].message.contains("`handle`"));
+    }
+
+    #[test]
+    fn test_allow_instrumented() {
+        let result = analyze(parse_quote! {
+            #[instrument]
+            pub async fn a() {}
+
+            #[tracing::instrument(skip(db))]
+            pub async fn b(db: Db) {}
+        });
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_allow_event_emission() {
+        let result = analyze(parse_quote! {
+            pub async fn a() {
+                tracing::info!("start");
+            }
+
+            pub async fn b() {
+                if failed {
+                    warn!(code = 3, "failed");
+                }
+            }
+
+            pub async fn c() {
+                work().instrument(span).await
+            }
+        });
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_ignore_sync_private_and_trait_impls() {
+        let result = analyze(parse_quote! {
+            pub fn sync() {}
+            async fn private() {}
+            pub(crate) async fn internal() {}
+
+            impl Handler for Service {
+                async fn call(&self) {}
+            }
+        });
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_ignore_test_code() {
+        let result = analyze(parse_quote! {
+            #[cfg(test)]
+            mod tests {
+                pub async fn helper() {}
+            }
+        });
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_silent_without_tracing() {
+        let analyzer = MissingInstrumentAnalyzer::with_tracing(false);
+        let code: File = parse_quote! {
+            pub async fn fetch() {}
+        };
+
+        assert!(analyzer.analyze(&code, "").unwrap().issues.is_empty());
+    }
+
+    #[test]
+    fn test_default_implementation() {
+        let analyzer = MissingInstrumentAnalyzer::default();
+        assert_eq!(analyzer.name(), "missing_instrument");
+        assert!(analyzer.tracing);
+    }
+}
@@ -0,0 +1,360 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Missing must_use analyzer for builder-like public methods.
+//!
+//! A method such as `fn timeout(self, value: Duration) -> Self` does nothing
+//! but return a new value, so a call whose result is dropped,
+//! `builder.timeout(t);`, is always a mistake. `#[must_use]` turns that
+//! mistake into a compiler warning. This analyzer finds public inherent
+//! methods that take `self` or `&self`, receive nothing mutably and return
+//! `Self`, or consume `self` and return another owned value, and inserts the
+//! attribute.
+
+use std::path::Path;
+
+use masterror::AppResult;
+use syn::{
+    Attribute, File, FnArg, ImplItemFn, ItemImpl, ItemMod, ReturnType, Type, Visibility,
+    visit::Visit
+};
+
+use super::{is_library_path, is_test_code};
+use crate::analyzer::{AnalysisResult, Analyzer, Fix, Issue, Suggestion, TextEdit};
+
+/// Return types that are already `#[must_use]`.
+const MUST_USE_TYPES: &[&str] = &["Result"];
+
+/// Analyzer for builder-like public methods without `#[must_use]`.
+///
+/// # Examples
+///
+/// Detects this pattern:
+/// ```ignore
+/// impl RequestBuilder {
+///     pub fn timeout(mut self, timeout: Duration) -> Self {
+///         self.timeout = timeout;
+///         self
+///     }
+/// }
+/// ```
+///
+/// Fixes to:
+/// ```ignore
+/// impl RequestBuilder {
+///     #[must_use]
+///     pub fn timeout(mut self, timeout: Duration) -> Self {
+///         self.timeout = timeout;
+///         self
+///     }
+/// }
+/// ```
+pub struct MissingMustUseAnalyzer;
+
+/// Builder-like public method without `#[must_use]`.
+struct Candidate {
+    name:   String,
+    line:   usize,
+    column: usize,
+    /// Byte offset of the visibility, where the attribute is inserted
+    offset: usize
+}
+
+impl MissingMustUseAnalyzer {
+    /// Create new missing must_use analyzer instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Collect builder-like public methods lacking a must_use attribute.
+    fn collect(ast: &File) -> Vec<Candidate> {
+        let mut visitor = MustUseVisitor {
+            self_ty:    None,
+            candidates: Vec::new()
+        };
+        visitor.visit_file(ast);
+        visitor.candidates
+    }
+}
+
+impl Analyzer for MissingMustUseAnalyzer {
+    fn name(&self) -> &'static str {
+        "missing_must_use"
+    }
+
+    fn analyze(&self, ast: &File, _content: &str) -> AppResult<AnalysisResult> {
+        let issues: Vec<Issue> = Self::collect(ast)
+            .into_iter()
+            .map(|candidate| Issue {
+                line:    candidate.line,
+                column:  candidate.column,
+                message: format!(
+                    "Method `{}` only returns a new value but is missing `#[must_use]`\n\
+                     Mark it `#[must_use]` so a call whose result is dropped is a warning",
+                    candidate.name
+                ),
+                fix:     Fix::Simple("#[must_use]".to_string())
+            })
+            .collect();
+
+        Ok(AnalysisResult {
+            fixable_count: issues.len(),
+            issues
+        })
+    }
+
+    fn suggestions(&self, ast: &File, content: &str) -> AppResult<Vec<Suggestion>> {
+        Ok(Self::collect(ast)
+            .into_iter()
+            .map(|candidate| {
+                let line_start = content[..candidate.offset]
+                    .rfind('\n')
+                    .map_or(0, |idx| idx + 1);
+                let indent = &content[line_start..candidate.offset];
+
+                Suggestion {
+                    edit:   TextEdit {
+                        range:       candidate.offset..candidate.offset,
+                        replacement: format!("#[must_use]\n{indent}")
+                    },
+                    import: None
+                }
+            })
+            .collect())
+    }
+
+    fn fixable(&self) -> bool {
+        true
+    }
+
+    fn applies_to(&self, path: &Path) -> bool {
+        is_library_path(path)
+    }
+}
+
+impl Default for MissingMustUseAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct MustUseVisitor {
+    /// Name of the type of the impl block being visited
+    self_ty:    Option<String>,
+    candidates: Vec<Candidate>
+}
+
+impl MustUseVisitor {
+    /// Record a public method that only produces a new value.
+    fn check_method(&mut self, node: &ImplItemFn) {
+        let Visibility::Public(token) = &node.vis else {
+            return;
+        };
+        let sig = &node.sig;
+        let ReturnType::Type(_, output) = &sig.output else {
+            return;
+        };
+        if has_must_use(&node.attrs) || sig.asyncness.is_some() {
+            return;
+        }
+
+        let Some(FnArg::Receiver(receiver)) = sig.inputs.first() else {
+            return;
+        };
+        let mutable_input = sig.inputs.iter().any(|input| match input {
+            FnArg::Receiver(receiver) => {
+                receiver.reference.is_some() && receiver.mutability.is_some()
+            }
+            FnArg::Typed(pat) => {
+                matches!(&*pat.ty, Type::Reference(reference) if reference.mutability.is_some())
+            }
+        });
+        if mutable_input {
+            return;
+        }
+
+        let Some(returned) = owned_type_name(output) else {
+            return;
+        };
+        let returns_self =
+            returned == "Self" || self.self_ty.as_deref() == Some(returned.as_str());
+        let consumes_self = receiver.reference.is_none();
+        let converts_self = consumes_self && !MUST_USE_TYPES.contains(&returned.as_str());
+        if !returns_self && !converts_self {
+            return;
+        }
+
+        let start = sig.ident.span().start();
+        self.candidates.push(Candidate {
+            name:   sig.ident.to_string(),
+            line:   start.line,
+            column: start.column,
+            offset: token.span.byte_range().start
+        });
+    }
+}
+
+impl<'ast> Visit<'ast> for MustUseVisitor {
+    fn visit_item_impl(&mut self, node: &'ast ItemImpl) {
+        if node.trait_.is_some() || is_test_code(&node.attrs) {
+            return;
+        }
+        let Type::Path(self_ty) = &*node.self_ty else {
+            return;
+        };
+
+        self.self_ty = self_ty
+            .path
+            .segments
+            .last()
+            .map(|segment| segment.ident.to_string());
+        syn::visit::visit_item_impl(self, node);
+        self.self_ty = None;
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'ast ImplItemFn) {
+        if !is_test_code(&node.attrs) {
+            self.check_method(node);
+        }
+    }
+
+    fn visit_item_mod(&mut self, node: &'ast ItemMod) {
+        if !is_test_code(&node.attrs) {
+            syn::visit::visit_item_mod(self, node);
+        }
+    }
+}
+
+/// Check whether attributes include `#[must_use]`.
+fn has_must_use(attrs: &[Attribute]) -> bool {
+    attrs.iter().any(|attr| attr.path().is_ident("must_use"))
+}
+
+/// Name of an owned, named return type, `None` for references, tuples and
+/// `impl Trait`.
+fn owned_type_name(ty: &Type) -> Option<String> {
+    match ty {
+        Type::Path(path) if path.qself.is_none() => {
+            path.path.segments.last().map(|s| s.ident.to_string())
+        }
+        Type::Paren(paren) => owned_type_name(&paren.elem),
+        _ => None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analyze(code: &str) -> AnalysisResult {
+        let ast = syn::parse_file(code).unwrap();
+        MissingMustUseAnalyzer::new().analyze(&ast, code).unwrap()
+    }
+
+    fn fix(code: &str) -> String {
+        let ast = syn::parse_file(code).unwrap();
+        let suggestions = MissingMustUseAnalyzer::new()
+            .suggestions(&ast, code)
+            .unwrap();
+        crate::fixer::apply_suggestions(code, &suggestions).source
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        let analyzer = MissingMustUseAnalyzer::new();
+        assert_eq!(analyzer.name(), "missing_must_use");
+    }
+
+    #[test]
+    fn test_detect_builder_like_methods() {
+        let result = analyze(
+            r#"
+impl RequestBuilder {
+    pub fn timeout(mut self, timeout: Duration) -> Self { self }
+    pub fn with_header(&self, name: &str) -> RequestBuilder { todo!() }
+    pub fn build(self) -> Request { todo!() }
+}
+"#
+        );
+
+        assert_eq!(result.issues.len(), 3);
+        assert_eq!(result.fixable_count, 3);
+        assert_eq!(result.issues[0].line, 3);
+        assert!(
+            result.issues[0].message.contains(
+                "Method `timeout` only returns a new value but is missing `#[must_use]`"
+            )
+        );
+        assert!(result.issues[2].message.contains("`build`"));
+    }
+
+    #[test]
+    fn test_ignore_methods_with_side_effects_or_must_use() {
+        let result = analyze(
+            r#"
+impl Builder {
+    #[must_use]
+    pub fn timeout(self, timeout: Duration) -> Self { self }
+    pub fn reset(&mut self) -> Self { todo!() }
+    pub fn fill(&self, out: &mut Vec<u8>) -> Self { todo!() }
+    pub fn name(&self) -> String { todo!() }
+    pub fn inner(&self) -> &Config { &self.config }
+    pub fn send(self) -> Result<Response, Error> { todo!() }
+    pub async fn wait(self) -> Self { self }
+    pub fn new() -> Self { Self }
+    fn private(self) -> Self { self }
+}
+
+impl Clone for Builder {
+    fn clone(&self) -> Self { todo!() }
+}
+
+#[cfg(test)]
+mod tests {
+    impl Fixture {
+        pub fn with(self) -> Self { self }
+    }
+}
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_fix_inserts_attribute_after_docs() {
+        let code = r#"impl Builder {
+    /// Sets the timeout.
+    pub fn timeout(self, timeout: u64) -> Self {
+        Self { timeout }
+    }
+}
+"#;
+
+        assert_eq!(
+            fix(code),
+            r#"impl Builder {
+    /// Sets the timeout.
+    #[must_use]
+    pub fn timeout(self, timeout: u64) -> Self {
+        Self { timeout }
+    }
+}
+"#
+        );
+    }
+
+    #[test]
+    fn test_only_library_files() {
+        let analyzer = MissingMustUseAnalyzer::new();
+        assert!(analyzer.applies_to(Path::new("src/builder.rs")));
+        assert!(!analyzer.applies_to(Path::new("src/main.rs")));
+    }
+
+    #[test]
+    fn test_default_implementation() {
+        let analyzer = MissingMustUseAnalyzer;
+        assert_eq!(analyzer.name(), "missing_must_use");
+    }
+}
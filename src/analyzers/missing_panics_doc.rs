@@ -0,0 +1,294 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Missing panics doc analyzer for public functions that can panic.
+//!
+//! A caller reading the docs of a public function should learn when it
+//! panics. This analyzer flags documented `pub` functions and inherent
+//! methods whose body calls `unwrap`/`expect`, indexes a collection or
+//! invokes `panic!`, `unreachable!`, `todo!` or `unimplemented!`, but whose
+//! doc comment has no `# Panics` section.
+
+use masterror::AppResult;
+use syn::{
+    Attribute, Block, ExprIndex, ExprMethodCall, File, ImplItemFn, Item, ItemFn, ItemImpl,
+    ItemMod, Macro, Signature, Visibility, visit::Visit
+};
+
+use super::{
+    is_test_code,
+    missing_docs::{has_doc_section, has_docs, is_hidden}
+};
+use crate::analyzer::{AnalysisResult, Analyzer, Fix, Issue};
+
+/// Macros that panic unconditionally.
+const PANIC_MACROS: &[&str] = &["panic", "unreachable", "todo", "unimplemented"];
+
+/// Analyzer for documented panicking public functions without `# Panics`.
+///
+/// # Examples
+///
+/// Detects this pattern:
+/// ```ignore
+/// /// First configured path.
+/// pub fn first_path(&self) -> &Path {
+///     &self.paths[0]
+/// }
+/// ```
+///
+/// Suggests:
+/// ```ignore
+/// /// First configured path.
+/// ///
+/// /// # Panics
+/// ///
+/// /// Panics if no paths are configured.
+/// pub fn first_path(&self) -> &Path {
+///     &self.paths[0]
+/// }
+/// ```
+pub struct MissingPanicsDocAnalyzer;
+
+impl MissingPanicsDocAnalyzer {
+    /// Create new missing panics doc analyzer instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Analyzer for MissingPanicsDocAnalyzer {
+    fn name(&self) -> &'static str {
+        "missing_panics_doc"
+    }
+
+    fn analyze(&self, ast: &File, _content: &str) -> AppResult<AnalysisResult> {
+        let mut visitor = PanicsDocVisitor {
+            issues: Vec::new()
+        };
+        visitor.visit_file(ast);
+
+        Ok(AnalysisResult {
+            issues:        visitor.issues,
+            fixable_count: 0
+        })
+    }
+}
+
+impl Default for MissingPanicsDocAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct PanicsDocVisitor {
+    issues: Vec<Issue>
+}
+
+impl PanicsDocVisitor {
+    /// Report a documented public function that can panic without
+    /// `# Panics`.
+    ///
+    /// # Arguments
+    ///
+    /// * `attrs` - Outer attributes of the function
+    /// * `vis` - Visibility of the function
+    /// * `sig` - Signature of the function
+    /// * `block` - Body of the function
+    fn check(&mut self, attrs: &[Attribute], vis: &Visibility, sig: &Signature, block: &Block) {
+        if !matches!(vis, Visibility::Public(_))
+            || is_test_code(attrs)
+            || is_hidden(attrs)
+            || !has_docs(attrs)
+            || has_doc_section(attrs, "Panics")
+        {
+            return;
+        }
+
+        let mut finder = PanicFinder {
+            found: None
+        };
+        finder.visit_block(block);
+        let Some(cause) = finder.found else {
+            return;
+        };
+
+        let start = sig.ident.span().start();
+        self.issues.push(Issue {
+            line:    start.line,
+            column:  start.column,
+            message: format!(
+                "Public function `{}` can panic ({cause}) but its docs have no `# Panics` \
+                 section\n\
+                 Add `# Panics` describing when it panics, or return an error instead",
+                sig.ident
+            ),
+            fix:     Fix::None
+        });
+    }
+}
+
+impl<'ast> Visit<'ast> for PanicsDocVisitor {
+    fn visit_item_fn(&mut self, node: &'ast ItemFn) {
+        self.check(&node.attrs, &node.vis, &node.sig, &node.block);
+    }
+
+    fn visit_item_impl(&mut self, node: &'ast ItemImpl) {
+        if node.trait_.is_none() && !is_hidden(&node.attrs) {
+            syn::visit::visit_item_impl(self, node);
+        }
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'ast ImplItemFn) {
+        self.check(&node.attrs, &node.vis, &node.sig, &node.block);
+    }
+
+    fn visit_item_mod(&mut self, node: &'ast ItemMod) {
+        if !is_test_code(&node.attrs) && !is_hidden(&node.attrs) {
+            syn::visit::visit_item_mod(self, node);
+        }
+    }
+}
+
+/// Finds the first panicking expression in a function body.
+struct PanicFinder {
+    /// Description of the first panic site, e.g. ``calls `unwrap()` ``
+    found: Option<String>
+}
+
+impl PanicFinder {
+    fn record(&mut self, cause: String) {
+        self.found.get_or_insert(cause);
+    }
+}
+
+impl<'ast> Visit<'ast> for PanicFinder {
+    /// Skip nested items.
+    ///
+    /// # Notes
+    ///
+    /// - Nested items are separate functions with their own docs
+    fn visit_item(&mut self, _node: &'ast Item) {}
+
+    fn visit_expr_method_call(&mut self, node: &'ast ExprMethodCall) {
+        if node.method == "unwrap" || node.method == "expect" {
+            self.record(format!("calls `{}()`", node.method));
+        }
+        syn::visit::visit_expr_method_call(self, node);
+    }
+
+    fn visit_expr_index(&mut self, node: &'ast ExprIndex) {
+        self.record("indexes with `[]`".to_string());
+        syn::visit::visit_expr_index(self, node);
+    }
+
+    fn visit_macro(&mut self, node: &'ast Macro) {
+        if let Some(name) = node.path.get_ident()
+            && PANIC_MACROS.iter().any(|panic| name == panic)
+        {
+            self.record(format!("calls `{name}!`"));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analyze(code: &str) -> AnalysisResult {
+        let ast = syn::parse_file(code).unwrap();
+        MissingPanicsDocAnalyzer::new().analyze(&ast, code).unwrap()
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        let analyzer = MissingPanicsDocAnalyzer::new();
+        assert_eq!(analyzer.name(), "missing_panics_doc");
+    }
+
+    #[test]
+    fn test_detect_panicking_functions() {
+        let result = analyze(
+            r#"
+/// Parse the port.
+pub fn port(s: &str) -> u16 { s.parse().unwrap() }
+
+/// First path.
+pub fn first(paths: &[String]) -> &str { &paths[0] }
+
+impl Config {
+    /// Mode.
+    pub fn mode(&self) -> Mode {
+        match self.raw {
+            0 => Mode::Fast,
+            _ => unreachable!("validated on load")
+        }
+    }
+}
+"#
+        );
+
+        assert_eq!(result.issues.len(), 3);
+        assert_eq!(result.issues[0].line, 3);
+        assert!(
+            result.issues[0]
+                .message
+                .contains("`port` can panic (calls `unwrap()`)")
+        );
+        assert!(result.issues[1].message.contains("indexes with `[]`"));
+        assert!(result.issues[2].message.contains("calls `unreachable!`"));
+    }
+
+    #[test]
+    fn test_accept_documented_panics() {
+        let result = analyze(
+            r#"
+/// Parse the port.
+///
+/// # Panics
+///
+/// Panics if `s` is not a number.
+pub fn port(s: &str) -> u16 { s.parse().expect("port") }
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_ignore_other_functions() {
+        let result = analyze(
+            r#"
+pub fn undocumented(s: &str) -> u16 { s.parse().unwrap() }
+
+/// Private.
+fn private(s: &str) -> u16 { s.parse().unwrap() }
+
+/// Safe.
+pub fn safe(s: &str) -> u16 {
+    fn helper(s: &str) -> u16 { s.parse().unwrap() }
+    s.parse().unwrap_or_default()
+}
+
+impl Display for Config {
+    /// Format.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result { todo!() }
+}
+
+#[cfg(test)]
+mod tests {
+    /// Fixture.
+    pub fn fixture() -> u16 { "1".parse().unwrap() }
+}
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_default_implementation() {
+        let analyzer = MissingPanicsDocAnalyzer;
+        assert_eq!(analyzer.name(), "missing_panics_doc");
+    }
+}
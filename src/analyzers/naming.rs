@@ -0,0 +1,243 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Naming analyzer for single-letter and placeholder variable names.
+//!
+//! A binding called `x`, `tmp` or `data2` says nothing about what it holds,
+//! so every reader has to trace it back to its initializer. This analyzer
+//! flags `let` bindings with a single-letter name, a placeholder name such as
+//! `tmp` or `foo`, or a generic name with a number such as `data2`. Loop
+//! counters (`i`, `j`, `k`), closure bodies, where short names for iterator
+//! items are idiomatic, and test code are not checked.
+
+use masterror::AppResult;
+use syn::{ExprClosure, File, ImplItemFn, ItemFn, ItemMod, Local, visit::Visit};
+
+use super::{is_test_code, shadowing::BindingNames};
+use crate::analyzer::{AnalysisResult, Analyzer, Confidence, Fix, Issue};
+
+/// Single-letter names accepted as loop counters.
+const COUNTERS: &[&str] = &["i", "j", "k"];
+
+/// Names that never describe a value.
+const PLACEHOLDERS: &[&str] = &[
+    "tmp", "temp", "foo", "bar", "baz", "qux", "stuff", "thing", "whatever"
+];
+
+/// Generic names that are non-descriptive with a number appended, e.g.
+/// `data2`.
+const GENERIC_STEMS: &[&str] = &[
+    "data", "tmp", "temp", "val", "value", "var", "res", "result", "obj", "item", "foo", "bar"
+];
+
+/// Analyzer for non-descriptive `let` binding names.
+///
+/// # Examples
+///
+/// Detects this pattern:
+/// ```ignore
+/// let d = fs::read_to_string(path)?;
+/// let tmp = parse(&d)?;
+/// ```
+///
+/// Suggests naming the values:
+/// ```ignore
+/// let text = fs::read_to_string(path)?;
+/// let config = parse(&text)?;
+/// ```
+pub struct NamingAnalyzer;
+
+impl NamingAnalyzer {
+    /// Create new naming analyzer instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Analyzer for NamingAnalyzer {
+    fn name(&self) -> &'static str {
+        "naming"
+    }
+
+    fn analyze(&self, ast: &File, _content: &str) -> AppResult<AnalysisResult> {
+        let mut visitor = NamingVisitor {
+            issues: Vec::new()
+        };
+        visitor.visit_file(ast);
+
+        Ok(AnalysisResult {
+            issues:        visitor.issues,
+            fixable_count: 0
+        })
+    }
+
+    fn confidence(&self) -> Confidence {
+        Confidence::Medium
+    }
+}
+
+impl Default for NamingAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct NamingVisitor {
+    issues: Vec<Issue>
+}
+
+impl<'ast> Visit<'ast> for NamingVisitor {
+    fn visit_item_fn(&mut self, node: &'ast ItemFn) {
+        if !is_test_code(&node.attrs) {
+            syn::visit::visit_item_fn(self, node);
+        }
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'ast ImplItemFn) {
+        if !is_test_code(&node.attrs) {
+            syn::visit::visit_impl_item_fn(self, node);
+        }
+    }
+
+    fn visit_item_mod(&mut self, node: &'ast ItemMod) {
+        if !is_test_code(&node.attrs) {
+            syn::visit::visit_item_mod(self, node);
+        }
+    }
+
+    fn visit_expr_closure(&mut self, _node: &'ast ExprClosure) {}
+
+    fn visit_local(&mut self, node: &'ast Local) {
+        let mut names = BindingNames::default();
+        names.visit_pat(&node.pat);
+        for ident in names.idents {
+            let name = ident.to_string();
+            let Some(kind) = classify(&name) else {
+                continue;
+            };
+            let start = ident.span().start();
+            self.issues.push(Issue {
+                line:    start.line,
+                column:  start.column,
+                message: format!(
+                    "Variable `{name}` has a {kind} name\n\
+                     Name the binding after what it holds, e.g. `config` or `retry_count`"
+                ),
+                fix:     Fix::None
+            });
+        }
+        syn::visit::visit_local(self, node);
+    }
+}
+
+/// Describe why a binding name is non-descriptive.
+///
+/// # Returns
+///
+/// `Some("single-letter")`, `Some("placeholder")` or `Some("numbered")` for
+/// flagged names, `None` for descriptive names and loop counters
+fn classify(name: &str) -> Option<&'static str> {
+    if name.chars().count() == 1 {
+        return (!COUNTERS.contains(&name)).then_some("single-letter");
+    }
+    if PLACEHOLDERS.contains(&name) {
+        return Some("placeholder");
+    }
+    let stem = name.trim_end_matches(|c: char| c.is_ascii_digit());
+    let stem = stem.strip_suffix('_').unwrap_or(stem);
+    (stem.len() < name.len() && GENERIC_STEMS.contains(&stem)).then_some("numbered")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analyze(code: &str) -> AnalysisResult {
+        let ast = syn::parse_file(code).unwrap();
+        NamingAnalyzer::new().analyze(&ast, code).unwrap()
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        let analyzer = NamingAnalyzer::new();
+        assert_eq!(analyzer.name(), "naming");
+        assert_eq!(analyzer.confidence(), Confidence::Medium);
+    }
+
+    #[test]
+    fn test_detect_non_descriptive_names() {
+        let result = analyze(
+            r#"
+fn load(path: &Path) -> Config {
+    let d = fs::read_to_string(path)?;
+    let tmp = parse(&d)?;
+    let (data2, value_1) = split(tmp);
+    merge(data2, value_1)
+}
+"#
+        );
+
+        assert_eq!(result.issues.len(), 4);
+        assert_eq!(result.fixable_count, 0);
+        assert_eq!(result.issues[0].line, 3);
+        assert_eq!(result.issues[0].column, 8);
+        assert!(
+            result.issues[0]
+                .message
+                .contains("Variable `d` has a single-letter name")
+        );
+        assert!(
+            result.issues[1]
+                .message
+                .contains("Variable `tmp` has a placeholder name")
+        );
+        assert!(
+            result.issues[2]
+                .message
+                .contains("Variable `data2` has a numbered name")
+        );
+        assert!(result.issues[3].message.contains("`value_1`"));
+    }
+
+    #[test]
+    fn test_ignore_counters_closures_and_descriptive_names() {
+        let result = analyze(
+            r#"
+fn sum(values: &[u64]) -> u64 {
+    let mut i = 0;
+    let _ = values.len();
+    let sha256 = digest();
+    let total: u64 = values.iter().map(|v| { let x = v * 2; x }).sum();
+    for n in values {}
+    total
+}
+
+#[cfg(test)]
+mod tests {
+    fn fixture() {
+        let x = 1;
+    }
+}
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_classify() {
+        assert_eq!(classify("x"), Some("single-letter"));
+        assert_eq!(classify("k"), None);
+        assert_eq!(classify("foo"), Some("placeholder"));
+        assert_eq!(classify("result2"), Some("numbered"));
+        assert_eq!(classify("utf8"), None);
+        assert_eq!(classify("results"), None);
+    }
+
+    #[test]
+    fn test_default_implementation() {
+        let analyzer = NamingAnalyzer;
+        assert_eq!(analyzer.name(), "naming");
+    }
+}
@@ -0,0 +1,652 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Needless allocation analyzer for function signatures.
+//!
+//! This analyzer belongs to the API-ergonomics group. It flags owned
+//! parameters (`String`, `Vec<T>`, `PathBuf`) that the function only reads,
+//! and return types that hand out a reference to an owned container
+//! (`&String`, `&Vec<T>`, `&PathBuf`). Both force callers to allocate or
+//! expose an implementation detail where a borrowed slice type would do.
+
+use masterror::AppResult;
+use proc_macro2::{TokenStream, TokenTree};
+use syn::{
+    BinOp, Block, Expr, File, FnArg, GenericArgument, ImplItem, Item, ItemImpl, LitStr, Macro,
+    Pat, PathArguments, ReturnType, Signature, Type, TypeReference, spanned::Spanned,
+    visit::Visit
+};
+
+use crate::analyzer::{AnalysisResult, Analyzer, Fix, Issue};
+
+/// Methods that only borrow their receiver.
+///
+/// A parameter used exclusively as the receiver of these methods never needs
+/// to be owned.
+const READ_ONLY_METHODS: &[&str] = &[
+    "as_bytes",
+    "as_os_str",
+    "as_path",
+    "as_slice",
+    "as_str",
+    "bytes",
+    "chars",
+    "contains",
+    "display",
+    "ends_with",
+    "exists",
+    "extension",
+    "file_name",
+    "file_stem",
+    "first",
+    "get",
+    "is_dir",
+    "is_empty",
+    "is_file",
+    "iter",
+    "join",
+    "last",
+    "len",
+    "lines",
+    "parent",
+    "split",
+    "split_whitespace",
+    "starts_with",
+    "to_lowercase",
+    "to_owned",
+    "to_str",
+    "to_string",
+    "to_uppercase",
+    "to_vec",
+    "trim",
+    "windows"
+];
+
+/// Macros that take their arguments by reference.
+const BORROWING_MACROS: &[&str] = &[
+    "assert",
+    "assert_eq",
+    "assert_ne",
+    "debug",
+    "debug_assert",
+    "eprint",
+    "eprintln",
+    "error",
+    "format",
+    "info",
+    "panic",
+    "print",
+    "println",
+    "trace",
+    "warn",
+    "write",
+    "writeln"
+];
+
+/// Analyzer for owned types in signatures where a borrow suffices.
+///
+/// # Examples
+///
+/// Detects this pattern:
+/// ```ignore
+/// fn greet(name: String) {
+///     println!("Hello {name}");
+/// }
+///
+/// fn items(&self) -> &Vec<Item> {
+///     &self.items
+/// }
+/// ```
+///
+/// Suggests:
+/// ```ignore
+/// fn greet(name: &str) {
+///     println!("Hello {name}");
+/// }
+///
+/// fn items(&self) -> &[Item] {
+///     &self.items
+/// }
+/// ```
+pub struct NeedlessAllocationAnalyzer;
+
+impl NeedlessAllocationAnalyzer {
+    /// Create new needless allocation analyzer instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Check a function signature and body for needless allocations.
+    ///
+    /// # Arguments
+    ///
+    /// * `sig` - Function signature
+    /// * `block` - Function body, used to decide whether a parameter is only
+    ///   read
+    ///
+    /// # Returns
+    ///
+    /// Vector of issues found
+    fn check_fn(sig: &Signature, block: &Block) -> Vec<Issue> {
+        let mut issues = Vec::new();
+
+        for input in &sig.inputs {
+            let FnArg::Typed(pat_type) = input else {
+                continue;
+            };
+            let Pat::Ident(pat_ident) = pat_type.pat.as_ref() else {
+                continue;
+            };
+            if pat_ident.mutability.is_some() || pat_ident.by_ref.is_some() {
+                continue;
+            }
+            let Some(borrowed) = borrowed_form(&pat_type.ty) else {
+                continue;
+            };
+
+            let name = pat_ident.ident.to_string();
+            let mut usage = UsageVisitor::new(&name);
+            usage.visit_block(block);
+
+            if usage.read && !usage.consumed {
+                let owned = type_to_string(&pat_type.ty);
+                let start = pat_type.span().start();
+                issues.push(Issue {
+                    line:    start.line,
+                    column:  start.column,
+                    message: format!(
+                        "Needless allocation: parameter `{name}: {owned}` is only read\n\
+                         Take `{name}: {borrowed}` instead, e.g. `fn {fn_name}({name}: {borrowed})`",
+                        fn_name = sig.ident
+                    ),
+                    fix:     Fix::None
+                });
+            }
+        }
+
+        if let ReturnType::Type(_, ty) = &sig.output
+            && let Type::Reference(reference) = ty.as_ref()
+            && let Some(issue) = Self::check_return(sig, reference)
+        {
+            issues.push(issue);
+        }
+
+        issues
+    }
+
+    /// Check a returned reference to an owned container.
+    ///
+    /// # Arguments
+    ///
+    /// * `sig` - Function signature, used for the message
+    /// * `reference` - Returned reference type
+    ///
+    /// # Returns
+    ///
+    /// `Some(Issue)` if the reference points at `String`, `Vec<T>` or
+    /// `PathBuf`
+    fn check_return(sig: &Signature, reference: &TypeReference) -> Option<Issue> {
+        if reference.mutability.is_some() {
+            return None;
+        }
+
+        let borrowed = borrowed_form(&reference.elem)?;
+        let start = reference.span().start();
+
+        Some(Issue {
+            line:    start.line,
+            column:  start.column,
+            message: format!(
+                "Needless allocation: `{}` returns `&{}`\n\
+                 Return `{}` instead so the container type stays private",
+                sig.ident,
+                type_to_string(&reference.elem),
+                borrowed
+            ),
+            fix:     Fix::None
+        })
+    }
+
+    /// Check inherent impl methods.
+    ///
+    /// Trait implementations are skipped because their signatures are fixed by
+    /// the trait.
+    ///
+    /// # Arguments
+    ///
+    /// * `impl_block` - Impl block to analyze
+    ///
+    /// # Returns
+    ///
+    /// Vector of issues found
+    fn check_impl_block(impl_block: &ItemImpl) -> Vec<Issue> {
+        if impl_block.trait_.is_some() {
+            return Vec::new();
+        }
+
+        impl_block
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                ImplItem::Fn(method) => Some(Self::check_fn(&method.sig, &method.block)),
+                _ => None
+            })
+            .flatten()
+            .collect()
+    }
+}
+
+impl Analyzer for NeedlessAllocationAnalyzer {
+    fn name(&self) -> &'static str {
+        "needless_allocation"
+    }
+
+    fn analyze(&self, ast: &File, _content: &str) -> AppResult<AnalysisResult> {
+        let mut visitor = SignatureVisitor {
+            issues: Vec::new()
+        };
+        visitor.visit_file(ast);
+
+        Ok(AnalysisResult {
+            issues:        visitor.issues,
+            fixable_count: 0
+        })
+    }
+}
+
+impl Default for NeedlessAllocationAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct SignatureVisitor {
+    issues: Vec<Issue>
+}
+
+impl<'ast> Visit<'ast> for SignatureVisitor {
+    fn visit_item(&mut self, node: &'ast Item) {
+        match node {
+            Item::Fn(func) => {
+                self.issues
+                    .extend(NeedlessAllocationAnalyzer::check_fn(&func.sig, &func.block));
+            }
+            Item::Impl(impl_block) => {
+                self.issues
+                    .extend(NeedlessAllocationAnalyzer::check_impl_block(impl_block));
+            }
+            _ => {}
+        }
+        syn::visit::visit_item(self, node);
+    }
+}
+
+/// Tracks how a parameter is used inside a function body.
+///
+/// Any use that is not clearly a borrow (a read-only method call, `&name`,
+/// indexing, comparison or a formatting macro) counts as consuming the value.
+struct UsageVisitor<'a> {
+    name:     &'a str,
+    read:     bool,
+    consumed: bool
+}
+
+impl<'a> UsageVisitor<'a> {
+    fn new(name: &'a str) -> Self {
+        Self {
+            name,
+            read: false,
+            consumed: false
+        }
+    }
+
+    fn is_param(&self, expr: &Expr) -> bool {
+        matches!(expr, Expr::Path(path) if path.qself.is_none() && path.path.is_ident(self.name))
+    }
+
+    /// Check whether macro tokens refer to the parameter.
+    ///
+    /// Matches the bare identifier as well as inline format arguments such as
+    /// `{name}` or `{name:?}` inside string literals.
+    fn mentions(&self, tokens: &TokenStream) -> bool {
+        tokens.clone().into_iter().any(|token| match token {
+            TokenTree::Ident(ident) => ident == self.name,
+            TokenTree::Group(group) => self.mentions(&group.stream()),
+            TokenTree::Literal(literal) => syn::parse_str::<LitStr>(&literal.to_string())
+                .is_ok_and(|lit| {
+                    let value = lit.value();
+                    value.contains(&format!("{{{}}}", self.name))
+                        || value.contains(&format!("{{{}:", self.name))
+                }),
+            _ => false
+        })
+    }
+}
+
+impl<'ast> Visit<'ast> for UsageVisitor<'_> {
+    fn visit_expr(&mut self, node: &'ast Expr) {
+        match node {
+            Expr::Path(_) if self.is_param(node) => {
+                self.consumed = true;
+            }
+            Expr::MethodCall(call) if self.is_param(&call.receiver) => {
+                let method = call.method.to_string();
+                if READ_ONLY_METHODS.contains(&method.as_str()) {
+                    self.read = true;
+                } else {
+                    self.consumed = true;
+                }
+                for arg in &call.args {
+                    self.visit_expr(arg);
+                }
+            }
+            Expr::Reference(reference)
+                if reference.mutability.is_none() && self.is_param(&reference.expr) =>
+            {
+                self.read = true;
+            }
+            Expr::Index(index) if self.is_param(&index.expr) => {
+                self.read = true;
+                self.visit_expr(&index.index);
+            }
+            Expr::Binary(binary) if is_comparison(&binary.op) => {
+                for operand in [&binary.left, &binary.right] {
+                    if self.is_param(operand) {
+                        self.read = true;
+                    } else {
+                        self.visit_expr(operand);
+                    }
+                }
+            }
+            _ => syn::visit::visit_expr(self, node)
+        }
+    }
+
+    fn visit_macro(&mut self, node: &'ast Macro) {
+        if !self.mentions(&node.tokens) {
+            return;
+        }
+
+        let borrowing = node
+            .path
+            .segments
+            .last()
+            .is_some_and(|seg| BORROWING_MACROS.contains(&seg.ident.to_string().as_str()));
+
+        if borrowing {
+            self.read = true;
+        } else {
+            self.consumed = true;
+        }
+    }
+}
+
+/// Check whether a binary operator compares its operands by reference.
+#[inline]
+fn is_comparison(op: &BinOp) -> bool {
+    matches!(
+        op,
+        BinOp::Eq(_) | BinOp::Ne(_) | BinOp::Lt(_) | BinOp::Le(_) | BinOp::Gt(_) | BinOp::Ge(_)
+    )
+}
+
+/// Get the borrowed equivalent of an owned container type.
+///
+/// # Arguments
+///
+/// * `ty` - Type to inspect
+///
+/// # Returns
+///
+/// `Some("&str")`, `Some("&[T]")` or `Some("&Path")` for `String`, `Vec<T>`
+/// and `PathBuf`, `None` otherwise
+fn borrowed_form(ty: &Type) -> Option<String> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    if type_path.qself.is_some() {
+        return None;
+    }
+
+    let segment = type_path.path.segments.last()?;
+    match segment.ident.to_string().as_str() {
+        "String" => Some("&str".to_string()),
+        "PathBuf" => Some("&Path".to_string()),
+        "Vec" => {
+            let PathArguments::AngleBracketed(args) = &segment.arguments else {
+                return None;
+            };
+            match args.args.first()? {
+                GenericArgument::Type(inner) => Some(format!("&[{}]", type_to_string(inner))),
+                _ => None
+            }
+        }
+        _ => None
+    }
+}
+
+/// Render a type the way it is usually written in source.
+fn type_to_string(ty: &Type) -> String {
+    quote::quote!(#ty)
+        .to_string()
+        .replace(" < ", "<")
+        .replace(" <", "<")
+        .replace("< ", "<")
+        .replace(" >", ">")
+        .replace(" , ", ", ")
+        .replace(" :: ", "::")
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::parse_quote;
+
+    use super::*;
+
+    fn analyze(code: File) -> AnalysisResult {
+        NeedlessAllocationAnalyzer::new()
+            .analyze(&code, "")
+            .unwrap()
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        let analyzer = NeedlessAllocationAnalyzer::new();
+        assert_eq!(analyzer.name(), "needless_allocation");
+    }
+
+    #[test]
+    fn test_detect_read_only_string() {
+        let result = analyze(parse_quote! {
+            fn greet(name: String) {
+                println!("Hello {}", name);
+            }
+        });
+
+        assert_eq!(result.issues.len(), 1);
+        assert!(result.issues[0].message.contains("`name: &str`"));
+    }
+
+    #[test]
+    fn test_inline_format_argument_is_read() {
+        let result = analyze(parse_quote! {
+            fn greet(name: String) {
+                println!("Hello {name}");
+            }
+        });
+
+        assert_eq!(result.issues.len(), 1);
+    }
+
+    #[test]
+    fn test_detect_read_only_vec() {
+        let result = analyze(parse_quote! {
+            fn total(values: Vec<u32>) -> usize {
+                values.len()
+            }
+        });
+
+        assert_eq!(result.issues.len(), 1);
+        assert!(result.issues[0].message.contains("&[u32]"));
+    }
+
+    #[test]
+    fn test_detect_read_only_path_buf() {
+        let result = analyze(parse_quote! {
+            fn exists(path: PathBuf) -> bool {
+                path.exists()
+            }
+        });
+
+        assert_eq!(result.issues.len(), 1);
+        assert!(result.issues[0].message.contains("&Path"));
+    }
+
+    #[test]
+    fn test_ignore_consumed_parameter() {
+        let result = analyze(parse_quote! {
+            fn store(&mut self, name: String) {
+                self.names.push(name);
+            }
+        });
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_ignore_moved_into_struct() {
+        let result = analyze(parse_quote! {
+            fn new(name: String) -> Self {
+                Self { name }
+            }
+        });
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_ignore_consuming_method() {
+        let result = analyze(parse_quote! {
+            fn bytes(name: String) -> Vec<u8> {
+                name.into_bytes()
+            }
+        });
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_ignore_mutable_parameter() {
+        let result = analyze(parse_quote! {
+            fn shout(mut name: String) -> usize {
+                name.len()
+            }
+        });
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_ignore_unused_parameter() {
+        let result = analyze(parse_quote! {
+            fn noop(_name: String) {}
+        });
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_ignore_non_formatting_macro() {
+        let result = analyze(parse_quote! {
+            fn wrap(name: String) -> Vec<String> {
+                vec![name]
+            }
+        });
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_comparison_is_read() {
+        let result = analyze(parse_quote! {
+            fn is_admin(role: String) -> bool {
+                role == "admin"
+            }
+        });
+
+        assert_eq!(result.issues.len(), 1);
+    }
+
+    #[test]
+    fn test_detect_reference_to_string_return() {
+        let result = analyze(parse_quote! {
+            struct User { name: String }
+
+            impl User {
+                fn name(&self) -> &String {
+                    &self.name
+                }
+            }
+        });
+
+        assert_eq!(result.issues.len(), 1);
+        assert!(result.issues[0].message.contains("returns `&String`"));
+        assert!(result.issues[0].message.contains("`&str`"));
+    }
+
+    #[test]
+    fn test_ignore_trait_impl() {
+        let result = analyze(parse_quote! {
+            impl From<String> for Name {
+                fn from(value: String) -> Self {
+                    println!("{}", value);
+                    Self
+                }
+            }
+        });
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_ignore_borrowed_parameter() {
+        let result = analyze(parse_quote! {
+            fn greet(name: &str) -> usize {
+                name.len()
+            }
+        });
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_fixable_count_is_zero() {
+        let result = analyze(parse_quote! {
+            fn greet(name: String) -> usize {
+                name.len()
+            }
+        });
+
+        assert_eq!(result.fixable_count, 0);
+    }
+
+    #[test]
+    fn test_no_edits() {
+        let analyzer = NeedlessAllocationAnalyzer::new();
+        let code: File = parse_quote! {
+            fn greet(name: String) -> usize {
+                name.len()
+            }
+        };
+
+        let edits = analyzer.suggestions(&code, "").unwrap();
+        assert!(edits.is_empty());
+    }
+
+    #[test]
+    fn test_default_implementation() {
+        let analyzer = NeedlessAllocationAnalyzer;
+        assert_eq!(analyzer.name(), "needless_allocation");
+    }
+}
@@ -0,0 +1,266 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Nested closures analyzer for callback pyramids.
+//!
+//! Iterator chains, `spawn` calls and combinators each take a closure, and
+//! stacking them produces bodies indented three or four closures deep where
+//! captures and return types are hard to follow. This analyzer reports
+//! closures nested deeper than [`MAX_CLOSURE_DEPTH`] levels, pointing at the
+//! innermost one.
+
+use masterror::AppResult;
+use proc_macro2::LineColumn;
+use syn::{ExprClosure, File, ImplItemFn, Item, ItemFn, ItemMod, spanned::Spanned, visit::Visit};
+
+use super::is_test_code;
+use crate::analyzer::{AnalysisResult, Analyzer, Fix, Issue};
+
+/// Maximum number of closures nested inside each other.
+pub const MAX_CLOSURE_DEPTH: usize = 2;
+
+/// Analyzer for closures nested deeper than [`MAX_CLOSURE_DEPTH`] levels.
+///
+/// # Examples
+///
+/// Detects this pattern:
+/// ```ignore
+/// let handles = jobs.into_iter().map(|job| {
+///     thread::spawn(move || {
+///         job.items.iter().map(|item| item.run()).collect::<Vec<_>>()
+///     })
+/// });
+/// ```
+///
+/// Suggests extracting named functions:
+/// ```ignore
+/// fn run_job(job: Job) -> Vec<Output> {
+///     job.items.iter().map(|item| item.run()).collect()
+/// }
+///
+/// let handles = jobs.into_iter().map(|job| thread::spawn(move || run_job(job)));
+/// ```
+pub struct NestedClosuresAnalyzer;
+
+impl NestedClosuresAnalyzer {
+    /// Create new nested closures analyzer instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Analyzer for NestedClosuresAnalyzer {
+    fn name(&self) -> &'static str {
+        "nested_closures"
+    }
+
+    fn analyze(&self, ast: &File, _content: &str) -> AppResult<AnalysisResult> {
+        let mut visitor = ClosureVisitor {
+            issues:    Vec::new(),
+            depth:     0,
+            innermost: None
+        };
+        visitor.visit_file(ast);
+
+        Ok(AnalysisResult {
+            issues:        visitor.issues,
+            fixable_count: 0
+        })
+    }
+}
+
+impl Default for NestedClosuresAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct ClosureVisitor {
+    issues:    Vec<Issue>,
+    /// Closures enclosing the current expression
+    depth:     usize,
+    /// Deepest closure below the current outermost closure and its depth
+    innermost: Option<(usize, LineColumn)>
+}
+
+impl ClosureVisitor {
+    /// Visit a nested item with its own closure depth.
+    fn with_item(&mut self, visit: impl FnOnce(&mut Self)) {
+        let depth = std::mem::take(&mut self.depth);
+        let innermost = self.innermost.take();
+        visit(self);
+        self.depth = depth;
+        self.innermost = innermost;
+    }
+}
+
+impl<'ast> Visit<'ast> for ClosureVisitor {
+    fn visit_item(&mut self, node: &'ast Item) {
+        self.with_item(|visitor| syn::visit::visit_item(visitor, node));
+    }
+
+    fn visit_item_fn(&mut self, node: &'ast ItemFn) {
+        if !is_test_code(&node.attrs) {
+            syn::visit::visit_item_fn(self, node);
+        }
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'ast ImplItemFn) {
+        if !is_test_code(&node.attrs) {
+            self.with_item(|visitor| syn::visit::visit_impl_item_fn(visitor, node));
+        }
+    }
+
+    fn visit_item_mod(&mut self, node: &'ast ItemMod) {
+        if !is_test_code(&node.attrs) {
+            syn::visit::visit_item_mod(self, node);
+        }
+    }
+
+    fn visit_expr_closure(&mut self, node: &'ast ExprClosure) {
+        self.depth += 1;
+        if self.innermost.is_none_or(|(levels, _)| self.depth > levels) {
+            self.innermost = Some((self.depth, node.span().start()));
+        }
+        syn::visit::visit_expr_closure(self, node);
+        self.depth -= 1;
+
+        if self.depth > 0 {
+            return;
+        }
+        if let Some((levels, position)) = self.innermost.take()
+            && levels > MAX_CLOSURE_DEPTH
+        {
+            self.issues.push(Issue {
+                line:    position.line,
+                column:  position.column,
+                message: format!(
+                    "Closure nested {levels} levels deep (max {MAX_CLOSURE_DEPTH})\n\
+                     Extract the inner closures into named functions to flatten the callbacks"
+                ),
+                fix:     Fix::None
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analyze(code: &str) -> AnalysisResult {
+        let ast = syn::parse_file(code).unwrap();
+        NestedClosuresAnalyzer::new().analyze(&ast, code).unwrap()
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        let analyzer = NestedClosuresAnalyzer::new();
+        assert_eq!(analyzer.name(), "nested_closures");
+    }
+
+    #[test]
+    fn test_detect_innermost_closure() {
+        let result = analyze(
+            r#"
+fn run(jobs: Vec<Job>) {
+    let handles = jobs.into_iter().map(|job| {
+        thread::spawn(move || {
+            job.items.iter().map(|item| item.run()).collect::<Vec<_>>()
+        })
+    });
+}
+"#
+        );
+
+        assert_eq!(result.issues.len(), 1);
+        assert_eq!(result.issues[0].line, 5);
+        assert_eq!(result.issues[0].column, 33);
+        assert!(
+            result.issues[0]
+                .message
+                .contains("nested 3 levels deep (max 2)")
+        );
+    }
+
+    #[test]
+    fn test_one_issue_per_outer_closure() {
+        let result = analyze(
+            r#"
+impl Pool {
+    fn start(&self) {
+        self.a.iter().for_each(|x| x.b.iter().for_each(|y| y.c.iter().for_each(|z| {
+            z.d.iter().for_each(|w| w.run());
+        })));
+        self.e.iter().for_each(|x| x.f.iter().for_each(|y| y.g.iter().for_each(|z| z.run())));
+    }
+}
+"#
+        );
+
+        assert_eq!(result.issues.len(), 2);
+        assert!(result.issues[0].message.contains("4 levels"));
+        assert_eq!(result.issues[1].line, 7);
+    }
+
+    #[test]
+    fn test_allow_two_levels_and_siblings() {
+        let result = analyze(
+            r#"
+fn total(orders: &[Order]) -> u64 {
+    orders
+        .iter()
+        .map(|order| order.lines.iter().map(|line| line.price).sum::<u64>())
+        .filter(|sum| *sum > 0)
+        .fold(0, |acc, sum| acc + sum)
+}
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_nested_item_resets_depth() {
+        let result = analyze(
+            r#"
+fn outer() {
+    let f = |a| {
+        let g = |b| {
+            fn helper() {
+                let h = |c| c;
+            }
+            b
+        };
+        g(a)
+    };
+}
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_ignore_test_code() {
+        let result = analyze(
+            r#"
+#[cfg(test)]
+mod tests {
+    fn deep() {
+        let f = |a| |b| |c| a + b + c;
+    }
+}
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_default_implementation() {
+        let analyzer = NestedClosuresAnalyzer;
+        assert_eq!(analyzer.name(), "nested_closures");
+    }
+}
@@ -0,0 +1,399 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Nesting depth analyzer for deeply nested control flow.
+//!
+//! Every `if`, `match`, loop and closure adds a level the reader has to keep
+//! in mind. This analyzer reports functions that nest deeper than
+//! [`MAX_NESTING_DEPTH`] levels, pointing at the deepest statement so it is
+//! clear where to flatten the code.
+
+use masterror::AppResult;
+use proc_macro2::LineColumn;
+use syn::{
+    Arm, Block, Expr, ExprClosure, ExprForLoop, ExprIf, ExprLoop, ExprMatch, ExprWhile, File,
+    ImplItemFn, Item, ItemFn, ItemMod, Signature, Stmt, TraitItemFn, spanned::Spanned,
+    visit::Visit
+};
+
+use super::is_test_code;
+use crate::analyzer::{AnalysisResult, Analyzer, Fix, Issue};
+
+/// Maximum number of nested `if`/`match`/loop/closure levels.
+pub const MAX_NESTING_DEPTH: usize = 4;
+
+/// Analyzer for functions nested deeper than [`MAX_NESTING_DEPTH`] levels.
+///
+/// # Examples
+///
+/// Detects this pattern:
+/// ```ignore
+/// fn sync(users: &[User]) {
+///     for user in users {
+///         if user.active {
+///             match user.role {
+///                 Role::Admin => {
+///                     for group in &user.groups {
+///                         if group.enabled {
+///                             notify(group);
+///                         }
+///                     }
+///                 }
+///                 _ => {}
+///             }
+///         }
+///     }
+/// }
+/// ```
+///
+/// Suggests flattening with early exits and helpers:
+/// ```ignore
+/// fn sync(users: &[User]) {
+///     for user in users.iter().filter(|user| user.active) {
+///         if user.role == Role::Admin {
+///             notify_enabled(&user.groups);
+///         }
+///     }
+/// }
+/// ```
+pub struct NestingDepthAnalyzer;
+
+impl NestingDepthAnalyzer {
+    /// Create new nesting depth analyzer instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Analyzer for NestingDepthAnalyzer {
+    fn name(&self) -> &'static str {
+        "nesting_depth"
+    }
+
+    fn analyze(&self, ast: &File, _content: &str) -> AppResult<AnalysisResult> {
+        let mut visitor = FunctionVisitor {
+            issues: Vec::new()
+        };
+        visitor.visit_file(ast);
+
+        Ok(AnalysisResult {
+            issues:        visitor.issues,
+            fixable_count: 0
+        })
+    }
+}
+
+impl Default for NestingDepthAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Finds functions and measures the nesting of each body.
+struct FunctionVisitor {
+    issues: Vec<Issue>
+}
+
+impl FunctionVisitor {
+    /// Report a function whose body nests deeper than allowed.
+    fn check_function(&mut self, sig: &Signature, block: &Block) {
+        let mut depth = DepthVisitor {
+            depth:   0,
+            deepest: None
+        };
+        depth.visit_block(block);
+
+        if let Some((levels, position)) = depth.deepest
+            && levels > MAX_NESTING_DEPTH
+        {
+            self.issues.push(Issue {
+                line:    position.line,
+                column:  position.column,
+                message: format!(
+                    "Function `{}` nests {levels} levels deep (max {MAX_NESTING_DEPTH})\n\
+                     Flatten it with early returns, `?`, `let ... else`, or extract the inner \
+                     blocks into functions",
+                    sig.ident
+                ),
+                fix:     Fix::None
+            });
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for FunctionVisitor {
+    fn visit_item_fn(&mut self, node: &'ast ItemFn) {
+        if is_test_code(&node.attrs) {
+            return;
+        }
+        self.check_function(&node.sig, &node.block);
+        syn::visit::visit_item_fn(self, node);
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'ast ImplItemFn) {
+        if is_test_code(&node.attrs) {
+            return;
+        }
+        self.check_function(&node.sig, &node.block);
+        syn::visit::visit_impl_item_fn(self, node);
+    }
+
+    fn visit_trait_item_fn(&mut self, node: &'ast TraitItemFn) {
+        if let Some(block) = &node.default {
+            self.check_function(&node.sig, block);
+        }
+        syn::visit::visit_trait_item_fn(self, node);
+    }
+
+    fn visit_item_mod(&mut self, node: &'ast ItemMod) {
+        if !is_test_code(&node.attrs) {
+            syn::visit::visit_item_mod(self, node);
+        }
+    }
+}
+
+/// Tracks nesting inside one function body.
+///
+/// Nested items are skipped; they are measured as functions of their own.
+struct DepthVisitor {
+    depth:   usize,
+    /// Deepest level reached and the position of its first statement
+    deepest: Option<(usize, LineColumn)>
+}
+
+impl DepthVisitor {
+    /// Visit with one more level of nesting.
+    fn nested(&mut self, visit: impl FnOnce(&mut Self)) {
+        self.depth += 1;
+        visit(self);
+        self.depth -= 1;
+    }
+
+    /// Record a statement or expression at the current depth.
+    fn record(&mut self, node: &impl Spanned) {
+        if self.deepest.is_none_or(|(levels, _)| self.depth > levels) {
+            self.deepest = Some((self.depth, node.span().start()));
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for DepthVisitor {
+    fn visit_item(&mut self, _node: &'ast Item) {}
+
+    fn visit_stmt(&mut self, node: &'ast Stmt) {
+        self.record(node);
+        syn::visit::visit_stmt(self, node);
+    }
+
+    fn visit_expr_if(&mut self, node: &'ast ExprIf) {
+        self.visit_expr(&node.cond);
+        self.nested(|visitor| visitor.visit_block(&node.then_branch));
+
+        if let Some((_, else_branch)) = &node.else_branch {
+            match else_branch.as_ref() {
+                Expr::If(else_if) => self.visit_expr_if(else_if),
+                other => self.nested(|visitor| visitor.visit_expr(other))
+            }
+        }
+    }
+
+    fn visit_expr_match(&mut self, node: &'ast ExprMatch) {
+        self.visit_expr(&node.expr);
+        self.nested(|visitor| {
+            for arm in &node.arms {
+                visitor.visit_arm(arm);
+            }
+        });
+    }
+
+    fn visit_arm(&mut self, node: &'ast Arm) {
+        if !matches!(node.body.as_ref(), Expr::Block(_)) {
+            self.record(&node.body);
+        }
+        syn::visit::visit_arm(self, node);
+    }
+
+    fn visit_expr_for_loop(&mut self, node: &'ast ExprForLoop) {
+        self.visit_expr(&node.expr);
+        self.nested(|visitor| visitor.visit_block(&node.body));
+    }
+
+    fn visit_expr_while(&mut self, node: &'ast ExprWhile) {
+        self.visit_expr(&node.cond);
+        self.nested(|visitor| visitor.visit_block(&node.body));
+    }
+
+    fn visit_expr_loop(&mut self, node: &'ast ExprLoop) {
+        self.nested(|visitor| visitor.visit_block(&node.body));
+    }
+
+    fn visit_expr_closure(&mut self, node: &'ast ExprClosure) {
+        self.nested(|visitor| {
+            if !matches!(node.body.as_ref(), Expr::Block(_)) {
+                visitor.record(&node.body);
+            }
+            visitor.visit_expr(&node.body);
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analyze(code: &str) -> AnalysisResult {
+        let ast = syn::parse_file(code).unwrap();
+        NestingDepthAnalyzer::new().analyze(&ast, code).unwrap()
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        let analyzer = NestingDepthAnalyzer::new();
+        assert_eq!(analyzer.name(), "nesting_depth");
+    }
+
+    #[test]
+    fn test_detect_deep_nesting_at_deepest_statement() {
+        let result = analyze(
+            r#"
+fn sync(users: &[User]) {
+    for user in users {
+        if user.active {
+            match user.role {
+                Role::Admin => {
+                    for group in &user.groups {
+                        if group.enabled {
+                            notify(group);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+"#
+        );
+
+        assert_eq!(result.issues.len(), 1);
+        assert_eq!(result.issues[0].line, 9);
+        assert!(
+            result.issues[0]
+                .message
+                .contains("`sync` nests 5 levels deep")
+        );
+    }
+
+    #[test]
+    fn test_allow_depth_at_limit() {
+        let result = analyze(
+            r#"
+fn sync(users: &[User]) {
+    for user in users {
+        if user.active {
+            match user.role {
+                Role::Admin => users.iter().for_each(|u| notify(u)),
+                _ => {}
+            }
+        }
+    }
+}
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_else_if_chain_is_one_level() {
+        let result = analyze(
+            r#"
+fn classify(n: i32) -> &'static str {
+    if n < 0 {
+        "negative"
+    } else if n == 0 {
+        "zero"
+    } else if n < 10 {
+        "small"
+    } else if n < 100 {
+        "medium"
+    } else if n < 1000 {
+        "large"
+    } else {
+        "huge"
+    }
+}
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_closures_count_as_levels() {
+        let result = analyze(
+            r#"
+impl Scheduler {
+    fn run(&self) {
+        loop {
+            self.jobs.iter().for_each(|job| {
+                if job.ready() {
+                    while job.step() {
+                        if job.failed() {
+                            job.report();
+                        }
+                    }
+                }
+            });
+        }
+    }
+}
+"#
+        );
+
+        assert_eq!(result.issues.len(), 1);
+        assert_eq!(result.issues[0].line, 9);
+    }
+
+    #[test]
+    fn test_nested_function_measured_separately() {
+        let result = analyze(
+            r#"
+fn outer() {
+    if a {
+        if b {
+            fn inner() {
+                if c {
+                    step();
+                }
+            }
+        }
+    }
+}
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_ignore_test_code() {
+        let result = analyze(
+            r#"
+#[test]
+fn deep() {
+    if a { if b { if c { if d { if e { step(); } } } } }
+}
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_default_implementation() {
+        let analyzer = NestingDepthAnalyzer;
+        assert_eq!(analyzer.name(), "nesting_depth");
+    }
+}
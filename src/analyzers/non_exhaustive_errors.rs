@@ -0,0 +1,373 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Non-exhaustive errors analyzer for public error enums.
+//!
+//! Error enums grow: a new failure mode means a new variant. Without
+//! `#[non_exhaustive]`, every downstream `match` over the enum is exhaustive
+//! and breaks when the variant is added, turning a minor release into a
+//! major one. This analyzer flags public enums that are errors, named
+//! `*Error` or implementing `std::error::Error` in the same file (by hand or
+//! through `#[derive(Error)]`), and inserts the attribute.
+//!
+//! Adding the attribute is itself a breaking change for downstream crates
+//! that match exhaustively, so the fix is best applied before a release
+//! that breaks anyway. Only library files are checked.
+
+use std::{collections::HashSet, path::Path};
+
+use masterror::AppResult;
+use syn::{
+    Attribute, File, ItemEnum, ItemImpl, ItemMod, Meta, Type, Visibility, punctuated::Punctuated,
+    visit::Visit
+};
+
+use super::{is_library_path, is_test_code};
+use crate::analyzer::{AnalysisResult, Analyzer, Fix, Issue, Suggestion, TextEdit};
+
+/// Analyzer for public error enums without `#[non_exhaustive]`.
+///
+/// # Examples
+///
+/// Detects this pattern:
+/// ```ignore
+/// #[derive(Debug)]
+/// pub enum ParseError {
+///     Empty,
+///     InvalidDigit(usize)
+/// }
+/// ```
+///
+/// Fixes to:
+/// ```ignore
+/// #[derive(Debug)]
+/// #[non_exhaustive]
+/// pub enum ParseError {
+///     Empty,
+///     InvalidDigit(usize)
+/// }
+/// ```
+pub struct NonExhaustiveErrorsAnalyzer;
+
+/// Public error enum without `#[non_exhaustive]`.
+struct Candidate {
+    name:   String,
+    line:   usize,
+    column: usize,
+    /// Byte offset of the visibility, where the attribute is inserted
+    offset: usize
+}
+
+impl NonExhaustiveErrorsAnalyzer {
+    /// Create new non-exhaustive errors analyzer instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Collect public error enums lacking a non_exhaustive attribute.
+    fn collect(ast: &File) -> Vec<Candidate> {
+        let mut visitor = EnumVisitor {
+            error_impls: HashSet::new(),
+            enums:       Vec::new()
+        };
+        visitor.visit_file(ast);
+
+        let EnumVisitor {
+            error_impls,
+            enums
+        } = visitor;
+        enums
+            .into_iter()
+            .filter_map(|(candidate, derives_error)| {
+                let is_error = derives_error
+                    || candidate.name.ends_with("Error")
+                    || error_impls.contains(&candidate.name);
+                is_error.then_some(candidate)
+            })
+            .collect()
+    }
+}
+
+impl Analyzer for NonExhaustiveErrorsAnalyzer {
+    fn name(&self) -> &'static str {
+        "non_exhaustive_errors"
+    }
+
+    fn analyze(&self, ast: &File, _content: &str) -> AppResult<AnalysisResult> {
+        let issues: Vec<Issue> = Self::collect(ast)
+            .into_iter()
+            .map(|candidate| Issue {
+                line:    candidate.line,
+                column:  candidate.column,
+                message: format!(
+                    "Public error enum `{}` is missing `#[non_exhaustive]`\nMark it \
+                     `#[non_exhaustive]` so new variants are not breaking changes",
+                    candidate.name
+                ),
+                fix:     Fix::Simple("#[non_exhaustive]".to_string())
+            })
+            .collect();
+
+        Ok(AnalysisResult {
+            fixable_count: issues.len(),
+            issues
+        })
+    }
+
+    fn suggestions(&self, ast: &File, content: &str) -> AppResult<Vec<Suggestion>> {
+        Ok(Self::collect(ast)
+            .into_iter()
+            .map(|candidate| {
+                let line_start = content[..candidate.offset]
+                    .rfind('\n')
+                    .map_or(0, |idx| idx + 1);
+                let indent = &content[line_start..candidate.offset];
+
+                Suggestion {
+                    edit:   TextEdit {
+                        range:       candidate.offset..candidate.offset,
+                        replacement: format!("#[non_exhaustive]\n{indent}")
+                    },
+                    import: None
+                }
+            })
+            .collect())
+    }
+
+    fn fixable(&self) -> bool {
+        true
+    }
+
+    fn applies_to(&self, path: &Path) -> bool {
+        is_library_path(path)
+    }
+}
+
+impl Default for NonExhaustiveErrorsAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct EnumVisitor {
+    /// Types implementing `std::error::Error` by hand
+    error_impls: HashSet<String>,
+    /// Public enums without the attribute, with whether they derive `Error`
+    enums:       Vec<(Candidate, bool)>
+}
+
+impl<'ast> Visit<'ast> for EnumVisitor {
+    fn visit_item_enum(&mut self, node: &'ast ItemEnum) {
+        let Visibility::Public(token) = &node.vis else {
+            return;
+        };
+        if node.variants.is_empty()
+            || is_test_code(&node.attrs)
+            || node
+                .attrs
+                .iter()
+                .any(|attr| attr.path().is_ident("non_exhaustive"))
+        {
+            return;
+        }
+
+        let start = node.ident.span().start();
+        self.enums.push((
+            Candidate {
+                name:   node.ident.to_string(),
+                line:   start.line,
+                column: start.column,
+                offset: token.span.byte_range().start
+            },
+            derives_error(&node.attrs)
+        ));
+    }
+
+    fn visit_item_impl(&mut self, node: &'ast ItemImpl) {
+        let Some((_, path, _)) = &node.trait_ else {
+            return;
+        };
+        let is_error_trait = path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "Error");
+        if is_error_trait
+            && let Type::Path(self_ty) = node.self_ty.as_ref()
+            && let Some(segment) = self_ty.path.segments.last()
+        {
+            self.error_impls.insert(segment.ident.to_string());
+        }
+    }
+
+    fn visit_item_mod(&mut self, node: &'ast ItemMod) {
+        if !is_test_code(&node.attrs) {
+            syn::visit::visit_item_mod(self, node);
+        }
+    }
+}
+
+/// Check whether attributes derive `Error`, e.g. `thiserror::Error`.
+fn derives_error(attrs: &[Attribute]) -> bool {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("derive"))
+        .filter_map(|attr| match &attr.meta {
+            Meta::List(list) => list
+                .parse_args_with(Punctuated::<syn::Path, syn::Token![,]>::parse_terminated)
+                .ok(),
+            _ => None
+        })
+        .flatten()
+        .any(|path| {
+            path.segments
+                .last()
+                .is_some_and(|segment| segment.ident == "Error")
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analyze(code: &str) -> AnalysisResult {
+        let ast = syn::parse_file(code).unwrap();
+        NonExhaustiveErrorsAnalyzer::new()
+            .analyze(&ast, code)
+            .unwrap()
+    }
+
+    fn fix(code: &str) -> String {
+        let ast = syn::parse_file(code).unwrap();
+        let suggestions = NonExhaustiveErrorsAnalyzer::new()
+            .suggestions(&ast, code)
+            .unwrap();
+        crate::fixer::apply_suggestions(code, &suggestions).source
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        let analyzer = NonExhaustiveErrorsAnalyzer::new();
+        assert_eq!(analyzer.name(), "non_exhaustive_errors");
+        assert!(analyzer.fixable());
+    }
+
+    #[test]
+    fn test_detect_error_enums() {
+        let result = analyze(
+            r#"
+pub enum ParseError {
+    Empty
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum Failure {
+    #[error("io")]
+    Io
+}
+
+pub enum LoadProblem {
+    Missing
+}
+
+impl std::error::Error for LoadProblem {}
+"#
+        );
+
+        assert_eq!(result.issues.len(), 3);
+        assert_eq!(result.fixable_count, 3);
+        assert_eq!(result.issues[0].line, 2);
+        assert_eq!(result.issues[0].column, 9);
+        assert!(result.issues[0].message.starts_with(
+            "Public error enum `ParseError` is missing `#[non_exhaustive]`\nMark it"
+        ));
+        assert!(result.issues[1].message.contains("`Failure`"));
+        assert!(result.issues[2].message.contains("`LoadProblem`"));
+    }
+
+    #[test]
+    fn test_ignore_other_enums() {
+        let result = analyze(
+            r#"
+#[non_exhaustive]
+pub enum ParseError {
+    Empty
+}
+
+pub(crate) enum InternalError {
+    Broken
+}
+
+enum PrivateError {
+    Broken
+}
+
+pub enum Never {}
+
+pub enum NeverError {}
+
+pub enum Color {
+    Red
+}
+
+pub struct ConfigError;
+
+#[cfg(test)]
+mod tests {
+    pub enum FixtureError {
+        Broken
+    }
+}
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_fix_inserts_attribute_after_derives() {
+        let code = r#"/// Errors of the parser.
+#[derive(Debug)]
+pub enum ParseError {
+    Empty
+}
+
+mod inner {
+    pub enum IoError {
+        Closed
+    }
+}
+"#;
+
+        assert_eq!(
+            fix(code),
+            r#"/// Errors of the parser.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ParseError {
+    Empty
+}
+
+mod inner {
+    #[non_exhaustive]
+    pub enum IoError {
+        Closed
+    }
+}
+"#
+        );
+    }
+
+    #[test]
+    fn test_only_library_files() {
+        let analyzer = NonExhaustiveErrorsAnalyzer::new();
+        assert!(analyzer.applies_to(Path::new("src/error.rs")));
+        assert!(!analyzer.applies_to(Path::new("src/main.rs")));
+    }
+
+    #[test]
+    fn test_default_implementation() {
+        let analyzer = NonExhaustiveErrorsAnalyzer;
+        assert_eq!(analyzer.name(), "non_exhaustive_errors");
+    }
+}
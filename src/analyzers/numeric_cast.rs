@@ -0,0 +1,372 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Numeric cast analyzer for lossy `as` conversions between integers.
+//!
+//! `as` never fails: `300_u64 as u8` is `44` and `-1_i32 as u32` is
+//! `4294967295`, silently. `try_from` makes the out-of-range case explicit.
+//! This analyzer flags integer `as` casts that can truncate or change sign,
+//! such as `u64 as u32` or `usize as u16`, where the source type is known
+//! from a typed parameter or `let`, a suffixed literal, a nested cast, or a
+//! `len()`/`count()` call. Widening casts like `u32 as u64` are lossless and
+//! ignored. Float casts are out of scope, as there is no `try_from` for them.
+
+use std::{collections::HashMap, mem};
+
+use masterror::AppResult;
+use syn::{
+    Expr, ExprCast, File, FnArg, ImplItemFn, ItemFn, ItemMod, Lit, Local, Pat, Signature, Type,
+    UnOp, visit::Visit
+};
+
+use super::is_test_code;
+use crate::analyzer::{AnalysisResult, Analyzer, Fix, Issue};
+
+/// Primitive integer types with their width in bits and signedness.
+///
+/// `usize` and `isize` are 32 bits wide on some targets and 64 on others:
+/// casts into them are checked as 32 bits, casts out of them as 64.
+const INTEGERS: &[(&str, u32, bool)] = &[
+    ("u8", 8, false),
+    ("u16", 16, false),
+    ("u32", 32, false),
+    ("u64", 64, false),
+    ("u128", 128, false),
+    ("usize", 0, false),
+    ("i8", 8, true),
+    ("i16", 16, true),
+    ("i32", 32, true),
+    ("i64", 64, true),
+    ("i128", 128, true),
+    ("isize", 0, true)
+];
+
+/// Methods returning a `usize` count.
+const COUNT_METHODS: &[&str] = &["len", "count", "capacity"];
+
+/// Analyzer for lossy integer `as` casts.
+///
+/// # Examples
+///
+/// Detects this pattern:
+/// ```ignore
+/// fn header(payload: &[u8]) -> u16 {
+///     payload.len() as u16
+/// }
+/// ```
+///
+/// Suggests a checked conversion:
+/// ```ignore
+/// fn header(payload: &[u8]) -> Result<u16, TryFromIntError> {
+///     u16::try_from(payload.len())
+/// }
+/// ```
+pub struct NumericCastAnalyzer;
+
+impl NumericCastAnalyzer {
+    /// Create new numeric cast analyzer instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Analyzer for NumericCastAnalyzer {
+    fn name(&self) -> &'static str {
+        "numeric_cast"
+    }
+
+    fn analyze(&self, ast: &File, _content: &str) -> AppResult<AnalysisResult> {
+        let mut visitor = CastVisitor {
+            types:  HashMap::new(),
+            issues: Vec::new()
+        };
+        visitor.visit_file(ast);
+
+        Ok(AnalysisResult {
+            issues:        visitor.issues,
+            fixable_count: 0
+        })
+    }
+}
+
+impl Default for NumericCastAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct CastVisitor {
+    /// Integer types of the bindings of the current function
+    types:  HashMap<String, &'static str>,
+    issues: Vec<Issue>
+}
+
+impl CastVisitor {
+    /// Visit a function with its integer parameters as the known bindings.
+    fn visit_function(&mut self, sig: &Signature, visit: impl FnOnce(&mut Self)) {
+        let params = sig
+            .inputs
+            .iter()
+            .filter_map(|arg| match arg {
+                FnArg::Typed(typed) => Some((binding_name(&typed.pat)?, integer_type(&typed.ty)?)),
+                FnArg::Receiver(_) => None
+            })
+            .collect();
+
+        let outer = mem::replace(&mut self.types, params);
+        visit(self);
+        self.types = outer;
+    }
+
+    /// Integer type of an expression, if it can be told from the syntax.
+    fn source_type(&self, expr: &Expr) -> Option<&'static str> {
+        match expr {
+            Expr::Path(path) => {
+                let name = path.path.get_ident()?.to_string();
+                self.types.get(&name).copied()
+            }
+            Expr::Lit(lit) => match &lit.lit {
+                Lit::Int(int) => integer_name(int.suffix()),
+                _ => None
+            },
+            Expr::Cast(cast) => integer_type(&cast.ty),
+            Expr::Paren(paren) => self.source_type(&paren.expr),
+            Expr::Unary(unary) if matches!(unary.op, UnOp::Deref(_)) => {
+                self.source_type(&unary.expr)
+            }
+            Expr::MethodCall(call)
+                if call.args.is_empty()
+                    && COUNT_METHODS.iter().any(|method| call.method == method) =>
+            {
+                Some("usize")
+            }
+            _ => None
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for CastVisitor {
+    fn visit_item_fn(&mut self, node: &'ast ItemFn) {
+        if !is_test_code(&node.attrs) {
+            self.visit_function(&node.sig, |visitor| {
+                syn::visit::visit_item_fn(visitor, node)
+            });
+        }
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'ast ImplItemFn) {
+        if !is_test_code(&node.attrs) {
+            self.visit_function(&node.sig, |visitor| {
+                syn::visit::visit_impl_item_fn(visitor, node)
+            });
+        }
+    }
+
+    fn visit_item_mod(&mut self, node: &'ast ItemMod) {
+        if !is_test_code(&node.attrs) {
+            syn::visit::visit_item_mod(self, node);
+        }
+    }
+
+    fn visit_local(&mut self, node: &'ast Local) {
+        syn::visit::visit_local(self, node);
+
+        let (name, annotated) = match &node.pat {
+            Pat::Type(typed) => (binding_name(&typed.pat), integer_type(&typed.ty)),
+            pat => (binding_name(pat), None)
+        };
+        let Some(name) = name else {
+            return;
+        };
+
+        let inferred = node
+            .init
+            .as_ref()
+            .and_then(|init| self.source_type(&init.expr));
+        match annotated.or(inferred) {
+            Some(ty) => self.types.insert(name, ty),
+            None => self.types.remove(&name)
+        };
+    }
+
+    fn visit_expr_cast(&mut self, node: &'ast ExprCast) {
+        if let Some(target) = integer_type(&node.ty)
+            && let Some(source) = self.source_type(&node.expr)
+            && let Some(loss) = cast_loss(source, target)
+        {
+            let start = node.as_token.span.start();
+            self.issues.push(Issue {
+                line:    start.line,
+                column:  start.column,
+                message: format!(
+                    "`{source} as {target}` may {loss}\n\
+                     Use `{target}::try_from(..)` and handle the out-of-range case"
+                ),
+                fix:     Fix::None
+            });
+        }
+        syn::visit::visit_expr_cast(self, node);
+    }
+}
+
+/// Describe how a cast between integer types can lose information.
+///
+/// # Arguments
+///
+/// * `source` - Type of the cast expression
+/// * `target` - Type cast to
+///
+/// # Returns
+///
+/// `Some("truncate the value")` or `Some("change the sign")` for lossy
+/// casts, `None` for widening ones
+fn cast_loss(source: &str, target: &str) -> Option<&'static str> {
+    let (_, source_bits, source_signed) = integer(source)?;
+    let (_, target_bits, target_signed) = integer(target)?;
+    let source_bits = if source_bits == 0 { 64 } else { source_bits };
+    let target_bits = if target_bits == 0 { 32 } else { target_bits };
+
+    if source_signed && !target_signed {
+        return Some("change the sign");
+    }
+    let usable_bits = if !source_signed && target_signed {
+        target_bits - 1
+    } else {
+        target_bits
+    };
+    if usable_bits >= source_bits {
+        None
+    } else if source_bits == target_bits {
+        Some("change the sign")
+    } else {
+        Some("truncate the value")
+    }
+}
+
+/// Look up a primitive integer type by name.
+fn integer(name: &str) -> Option<(&'static str, u32, bool)> {
+    INTEGERS.iter().find(|(ty, ..)| *ty == name).copied()
+}
+
+/// Static name of a primitive integer type.
+fn integer_name(name: &str) -> Option<&'static str> {
+    integer(name).map(|(ty, ..)| ty)
+}
+
+/// Primitive integer a type refers to, possibly behind a reference.
+fn integer_type(ty: &Type) -> Option<&'static str> {
+    match ty {
+        Type::Path(path) => integer_name(&path.path.get_ident()?.to_string()),
+        Type::Reference(reference) => integer_type(&reference.elem),
+        Type::Paren(paren) => integer_type(&paren.elem),
+        _ => None
+    }
+}
+
+/// Get the identifier bound by a simple pattern.
+fn binding_name(pat: &Pat) -> Option<String> {
+    match pat {
+        Pat::Ident(ident) => Some(ident.ident.to_string()),
+        _ => None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analyze(code: &str) -> AnalysisResult {
+        let ast = syn::parse_file(code).unwrap();
+        NumericCastAnalyzer::new().analyze(&ast, code).unwrap()
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        let analyzer = NumericCastAnalyzer::new();
+        assert_eq!(analyzer.name(), "numeric_cast");
+    }
+
+    #[test]
+    fn test_detect_lossy_casts() {
+        let result = analyze(
+            r#"
+fn encode(id: u64, offset: i32, payload: &[u8]) {
+    let short = id as u32;
+    let length = payload.len() as u16;
+    let unsigned = offset as u32;
+    let total: usize = 10;
+    let signed = total as i64;
+    let byte = 300u16 as u8;
+}
+"#
+        );
+
+        assert_eq!(result.issues.len(), 5);
+        assert_eq!(result.issues[0].line, 3);
+        assert!(
+            result.issues[0]
+                .message
+                .contains("`u64 as u32` may truncate the value")
+        );
+        assert!(result.issues[0].message.contains("`u32::try_from(..)`"));
+        assert!(result.issues[1].message.contains("`usize as u16`"));
+        assert!(
+            result.issues[2]
+                .message
+                .contains("`i32 as u32` may change the sign")
+        );
+        assert!(result.issues[3].message.contains("`usize as i64`"));
+        assert!(result.issues[4].message.contains("`u16 as u8`"));
+    }
+
+    #[test]
+    fn test_ignore_widening_and_unknown_casts() {
+        let result = analyze(
+            r#"
+fn widen(small: u32, signed: i16, ratio: f64, items: &[Item]) {
+    let a = small as u64;
+    let b = small as i64;
+    let c = signed as i32;
+    let d = small as usize;
+    let e = ratio as u8;
+    let f = items[0].size as u8;
+    let g = 200 as u8;
+    let h = small as f32;
+}
+
+#[cfg(test)]
+mod tests {
+    fn fixture(big: u64) -> u8 {
+        big as u8
+    }
+}
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_shadowed_binding_uses_new_type() {
+        let result = analyze(
+            r#"
+fn parse(value: u64) {
+    let value = value as u8;
+    let wide = value as u16;
+    let value = compute();
+    let other = value as u8;
+}
+"#
+        );
+
+        assert_eq!(result.issues.len(), 1);
+        assert_eq!(result.issues[0].line, 3);
+    }
+
+    #[test]
+    fn test_default_implementation() {
+        let analyzer = NumericCastAnalyzer;
+        assert_eq!(analyzer.name(), "numeric_cast");
+    }
+}
@@ -0,0 +1,397 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Numeric overflow analyzer for unchecked integer arithmetic.
+//!
+//! Integer `+` and `*` panic on overflow in debug builds and silently wrap in
+//! release builds. This analyzer flags unchecked addition and multiplication
+//! on integers inside loops, where values accumulate, and on integer function
+//! parameters, which come from callers and external input. It is opt-in:
+//! enable it per project with `enable = ["numeric_overflow"]` under
+//! `[package.metadata.quality]`.
+
+use std::collections::HashSet;
+
+use masterror::AppResult;
+use syn::{
+    BinOp, Expr, ExprBinary, ExprForLoop, ExprLoop, ExprWhile, File, FnArg, ImplItemFn, ItemConst,
+    ItemFn, ItemMod, Lit, Local, Pat, Signature, Type, spanned::Spanned, visit::Visit
+};
+
+use super::is_test_code;
+use crate::analyzer::{AnalysisResult, Analyzer, Fix, Issue};
+
+/// Primitive integer type names.
+const INTEGER_TYPES: &[&str] = &[
+    "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize"
+];
+
+/// Analyzer for unchecked integer `+`/`*` in loops and on parameters.
+///
+/// # Examples
+///
+/// Detects this pattern:
+/// ```ignore
+/// fn total(prices: &[u64], quantity: u64) -> u64 {
+///     let mut sum = 0;
+///     for price in prices {
+///         sum += price * quantity;
+///     }
+///     sum
+/// }
+/// ```
+///
+/// Suggests explicit overflow handling:
+/// ```ignore
+/// fn total(prices: &[u64], quantity: u64) -> Option<u64> {
+///     prices
+///         .iter()
+///         .try_fold(0u64, |sum, price| sum.checked_add(price.checked_mul(quantity)?))
+/// }
+/// ```
+pub struct NumericOverflowAnalyzer;
+
+impl NumericOverflowAnalyzer {
+    /// Create new numeric overflow analyzer instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Analyzer for NumericOverflowAnalyzer {
+    fn name(&self) -> &'static str {
+        "numeric_overflow"
+    }
+
+    fn analyze(&self, ast: &File, _content: &str) -> AppResult<AnalysisResult> {
+        let mut visitor = OverflowVisitor {
+            issues:     Vec::new(),
+            params:     HashSet::new(),
+            integers:   HashSet::new(),
+            loop_depth: 0
+        };
+        visitor.visit_file(ast);
+
+        Ok(AnalysisResult {
+            issues:        visitor.issues,
+            fixable_count: 0
+        })
+    }
+
+    fn opt_in(&self) -> bool {
+        true
+    }
+}
+
+impl Default for NumericOverflowAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct OverflowVisitor {
+    issues:     Vec<Issue>,
+    /// Integer parameters of the current function
+    params:     HashSet<String>,
+    /// Integer locals and parameters of the current function
+    integers:   HashSet<String>,
+    loop_depth: usize
+}
+
+impl OverflowVisitor {
+    /// Visit a function with its own integer bindings and loop depth.
+    fn visit_function(&mut self, sig: &Signature, visit: impl FnOnce(&mut Self)) {
+        let params: HashSet<_> = sig
+            .inputs
+            .iter()
+            .filter_map(|arg| match arg {
+                FnArg::Typed(typed) if is_integer_type(&typed.ty) => binding_name(&typed.pat),
+                _ => None
+            })
+            .collect();
+
+        let outer_params = std::mem::replace(&mut self.params, params.clone());
+        let outer_integers = std::mem::replace(&mut self.integers, params);
+        let outer_depth = std::mem::take(&mut self.loop_depth);
+        visit(self);
+        self.params = outer_params;
+        self.integers = outer_integers;
+        self.loop_depth = outer_depth;
+    }
+
+    /// Describe why an addition or multiplication may overflow.
+    ///
+    /// # Returns
+    ///
+    /// `on parameter `x`` when an operand is an integer parameter, `inside a
+    /// loop` for other integer arithmetic in a loop, `None` otherwise
+    fn overflow_context(&self, left: &Expr, right: &Expr) -> Option<String> {
+        let operands = [left, right];
+        if operands.iter().all(|operand| is_int_literal(operand)) {
+            return None;
+        }
+
+        if let Some(param) = operands
+            .iter()
+            .filter_map(|operand| path_name(operand))
+            .find(|name| self.params.contains(name))
+        {
+            return Some(format!("on parameter `{param}`"));
+        }
+
+        let integer = operands.iter().any(|operand| {
+            is_int_literal(operand)
+                || path_name(operand).is_some_and(|name| self.integers.contains(&name))
+        });
+        (self.loop_depth > 0 && integer).then(|| "inside a loop".to_string())
+    }
+
+    /// Visit a loop body with the loop depth increased.
+    fn with_loop(&mut self, visit: impl FnOnce(&mut Self)) {
+        self.loop_depth += 1;
+        visit(self);
+        self.loop_depth -= 1;
+    }
+}
+
+impl<'ast> Visit<'ast> for OverflowVisitor {
+    fn visit_item_fn(&mut self, node: &'ast ItemFn) {
+        if !is_test_code(&node.attrs) && node.sig.constness.is_none() {
+            self.visit_function(&node.sig, |visitor| {
+                syn::visit::visit_item_fn(visitor, node)
+            });
+        }
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'ast ImplItemFn) {
+        if !is_test_code(&node.attrs) && node.sig.constness.is_none() {
+            self.visit_function(&node.sig, |visitor| {
+                syn::visit::visit_impl_item_fn(visitor, node)
+            });
+        }
+    }
+
+    fn visit_item_mod(&mut self, node: &'ast ItemMod) {
+        if !is_test_code(&node.attrs) {
+            syn::visit::visit_item_mod(self, node);
+        }
+    }
+
+    fn visit_item_const(&mut self, _node: &'ast ItemConst) {}
+
+    fn visit_local(&mut self, node: &'ast Local) {
+        syn::visit::visit_local(self, node);
+
+        let (name, ty) = match &node.pat {
+            Pat::Type(typed) => (binding_name(&typed.pat), Some(typed.ty.as_ref())),
+            pat => (binding_name(pat), None)
+        };
+
+        if let Some(name) = name {
+            let integer = ty.is_some_and(is_integer_type)
+                || node
+                    .init
+                    .as_ref()
+                    .is_some_and(|init| is_int_literal(&init.expr));
+            if integer {
+                self.integers.insert(name.clone());
+            } else {
+                self.integers.remove(&name);
+            }
+            self.params.remove(&name);
+        }
+    }
+
+    fn visit_expr_for_loop(&mut self, node: &'ast ExprForLoop) {
+        self.visit_expr(&node.expr);
+        self.with_loop(|visitor| {
+            visitor.visit_pat(&node.pat);
+            visitor.visit_block(&node.body);
+        });
+    }
+
+    fn visit_expr_while(&mut self, node: &'ast ExprWhile) {
+        self.with_loop(|visitor| syn::visit::visit_expr_while(visitor, node));
+    }
+
+    fn visit_expr_loop(&mut self, node: &'ast ExprLoop) {
+        self.with_loop(|visitor| syn::visit::visit_expr_loop(visitor, node));
+    }
+
+    fn visit_expr_binary(&mut self, node: &'ast ExprBinary) {
+        let method = match node.op {
+            BinOp::Add(_) | BinOp::AddAssign(_) => Some("add"),
+            BinOp::Mul(_) | BinOp::MulAssign(_) => Some("mul"),
+            _ => None
+        };
+
+        if let Some(method) = method
+            && let Some(context) = self.overflow_context(&node.left, &node.right)
+        {
+            let start = node.op.span().start();
+            self.issues.push(Issue {
+                line:    start.line,
+                column:  start.column,
+                message: format!(
+                    "Unchecked integer `{method}` {context} may overflow\n\
+                     Use `checked_{method}` or `saturating_{method}` to handle overflow \
+                     explicitly"
+                ),
+                fix:     Fix::None
+            });
+        }
+        syn::visit::visit_expr_binary(self, node);
+    }
+}
+
+/// Get the identifier bound by a simple pattern.
+fn binding_name(pat: &Pat) -> Option<String> {
+    match pat {
+        Pat::Ident(ident) => Some(ident.ident.to_string()),
+        _ => None
+    }
+}
+
+/// Get the name of a single-identifier path expression, through derefs.
+fn path_name(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::Path(path) => path.path.get_ident().map(ToString::to_string),
+        Expr::Unary(unary) => path_name(&unary.expr),
+        Expr::Paren(paren) => path_name(&paren.expr),
+        _ => None
+    }
+}
+
+/// Check whether an expression is an integer literal.
+fn is_int_literal(expr: &Expr) -> bool {
+    matches!(expr, Expr::Lit(lit) if matches!(lit.lit, Lit::Int(_)))
+}
+
+/// Check whether a type is a primitive integer, possibly behind a reference.
+fn is_integer_type(ty: &Type) -> bool {
+    match ty {
+        Type::Path(path) => path
+            .path
+            .get_ident()
+            .is_some_and(|ident| INTEGER_TYPES.iter().any(|name| ident == name)),
+        Type::Reference(reference) => is_integer_type(&reference.elem),
+        _ => false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analyze(code: &str) -> AnalysisResult {
+        let ast = syn::parse_file(code).unwrap();
+        NumericOverflowAnalyzer::new().analyze(&ast, code).unwrap()
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        let analyzer = NumericOverflowAnalyzer::new();
+        assert_eq!(analyzer.name(), "numeric_overflow");
+        assert!(analyzer.opt_in());
+    }
+
+    #[test]
+    fn test_detect_arithmetic_on_parameters() {
+        let result = analyze(
+            r#"
+fn area(width: u32, height: u32, label: &str) -> u32 {
+    let name = label.to_string() + "!";
+    width * height
+}
+"#
+        );
+
+        assert_eq!(result.issues.len(), 1);
+        assert_eq!(result.issues[0].line, 4);
+        assert!(
+            result.issues[0]
+                .message
+                .contains("`mul` on parameter `width`")
+        );
+        assert!(result.issues[0].message.contains("checked_mul"));
+    }
+
+    #[test]
+    fn test_detect_accumulation_in_loop() {
+        let result = analyze(
+            r#"
+fn count(items: &[Item]) -> u64 {
+    let mut total = 0;
+    let mut ratio = 1.0;
+    for item in items {
+        total += 1;
+        ratio *= 0.5;
+    }
+    total + 1
+}
+"#
+        );
+
+        assert_eq!(result.issues.len(), 1);
+        assert_eq!(result.issues[0].line, 6);
+        assert!(result.issues[0].message.contains("`add` inside a loop"));
+    }
+
+    #[test]
+    fn test_ignore_literals_and_const_contexts() {
+        let result = analyze(
+            r#"
+const SIZE: usize = 4 * 1024;
+
+const fn double(n: usize) -> usize {
+    n * 2
+}
+
+fn buffer() -> usize {
+    loop {
+        return 2 * 8;
+    }
+}
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_shadowed_parameter_not_reported() {
+        let result = analyze(
+            r#"
+fn scale(factor: u32) -> f64 {
+    let factor = factor as f64 + 0.5;
+    factor * 2.5
+}
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_ignore_test_code() {
+        let result = analyze(
+            r#"
+#[cfg(test)]
+mod tests {
+    fn sum(a: u8, b: u8) -> u8 {
+        a + b
+    }
+}
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_default_implementation() {
+        let analyzer = NumericOverflowAnalyzer;
+        assert_eq!(analyzer.name(), "numeric_overflow");
+    }
+}
@@ -15,7 +15,7 @@ use std::collections::{HashMap, HashSet};
 use masterror::AppResult;
 use syn::{ExprPath, File, Path, spanned::Spanned, visit::Visit};
 
-use crate::analyzer::{AnalysisResult, Analyzer, Fix, Issue, Suggestion, TextEdit};
+use crate::analyzer::{AnalysisResult, Analyzer, Confidence, Fix, Issue, Suggestion, TextEdit};
 
 /// Analyzer for detecting path separators that should be imports.
 ///
@@ -176,6 +176,14 @@ impl Analyzer for PathImportAnalyzer {
 
         Ok(visitor.suggestions)
     }
+
+    fn fixable(&self) -> bool {
+        true
+    }
+
+    fn confidence(&self) -> Confidence {
+        Confidence::Medium
+    }
 }
 
 impl PathImportAnalyzer {
@@ -348,6 +356,7 @@ mod tests {
     fn test_analyzer_name() {
         let analyzer = PathImportAnalyzer::new();
         assert_eq!(analyzer.name(), "path_import");
+        assert_eq!(analyzer.confidence(), Confidence::Medium);
     }
 
     #[test]
@@ -472,9 +481,8 @@ mod tests {
         let analyzer = PathImportAnalyzer::new();
         let ast = syn::parse_file(content).unwrap();
         let suggestions = analyzer.suggestions(&ast, content).unwrap();
-        let fixed = suggestions.len();
-        let output = crate::fixer::apply_suggestions(content, &suggestions);
-        (fixed, output)
+        let outcome = crate::fixer::apply_suggestions(content, &suggestions);
+        (outcome.applied, outcome.source)
     }
 
     #[test]
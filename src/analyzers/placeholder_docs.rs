@@ -0,0 +1,457 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Placeholder docs analyzer for doc comments that document nothing.
+//!
+//! `missing_docs` is satisfied by any `///` comment, so a blank line, a lone
+//! `TODO` or a comment that only restates the item name pass the check while
+//! telling the reader nothing. This analyzer flags such `///` comments on
+//! functions, methods, types, traits, constants, statics and modules. Items
+//! marked `#[doc(hidden)]` and test code are skipped.
+
+use masterror::AppResult;
+use proc_macro2::Ident;
+use syn::{
+    AttrStyle, Attribute, Expr, ExprLit, File, ImplItemFn, ItemConst, ItemEnum, ItemFn, ItemMod,
+    ItemStatic, ItemStruct, ItemTrait, ItemType, Lit, Meta, MetaNameValue, TraitItemFn,
+    ext::IdentExt, spanned::Spanned, visit::Visit
+};
+
+use super::{is_test_code, missing_docs::is_hidden};
+use crate::analyzer::{AnalysisResult, Analyzer, Fix, Issue};
+
+/// Markers that stand in for documentation still to be written.
+const PLACEHOLDERS: &[&str] = &["todo", "fixme", "tbd", "xxx"];
+
+/// Words skipped when comparing a doc comment with the item name.
+const FILLER_WORDS: &[&str] = &["a", "an", "the"];
+
+/// Analyzer for empty or placeholder doc comments.
+///
+/// # Examples
+///
+/// Detects this pattern:
+/// ```ignore
+/// /// TODO
+/// pub fn parse_config(path: &Path) -> Config;
+///
+/// /// Parse config.
+/// pub fn parse_config(path: &Path) -> Config;
+/// ```
+///
+/// Suggests:
+/// ```ignore
+/// /// Reads `quality.toml` at `path`, falling back to defaults for missing
+/// /// keys.
+/// pub fn parse_config(path: &Path) -> Config;
+/// ```
+pub struct PlaceholderDocsAnalyzer;
+
+impl PlaceholderDocsAnalyzer {
+    /// Create new placeholder docs analyzer instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Analyzer for PlaceholderDocsAnalyzer {
+    fn name(&self) -> &'static str {
+        "placeholder_docs"
+    }
+
+    fn analyze(&self, ast: &File, _content: &str) -> AppResult<AnalysisResult> {
+        let mut visitor = PlaceholderVisitor {
+            issues: Vec::new()
+        };
+        visitor.visit_file(ast);
+
+        Ok(AnalysisResult {
+            issues:        visitor.issues,
+            fixable_count: 0
+        })
+    }
+}
+
+impl Default for PlaceholderDocsAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Why a doc comment documents nothing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Placeholder {
+    /// Only blank lines
+    Empty,
+    /// Only a marker such as `TODO`
+    Marker,
+    /// Only the item name, e.g. `/// Parse config.` on `parse_config`
+    Name
+}
+
+struct PlaceholderVisitor {
+    issues: Vec<Issue>
+}
+
+impl PlaceholderVisitor {
+    /// Report an item whose doc comment is a placeholder.
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - Item kind shown in the message, e.g. `function`
+    /// * `attrs` - Outer attributes of the item
+    /// * `ident` - Name of the item
+    fn check(&mut self, kind: &str, attrs: &[Attribute], ident: &Ident) {
+        if is_hidden(attrs) {
+            return;
+        }
+        let docs: Vec<&Attribute> = attrs
+            .iter()
+            .filter(|attr| matches!(attr.style, AttrStyle::Outer))
+            .filter(|attr| doc_text(attr).is_some())
+            .collect();
+        let Some(first) = docs.first() else {
+            return;
+        };
+        let text: Vec<String> = docs.iter().filter_map(|attr| doc_text(attr)).collect();
+        let Some(placeholder) = classify(&text.join("\n"), &ident.unraw().to_string(), kind)
+        else {
+            return;
+        };
+
+        let message = match placeholder {
+            Placeholder::Empty => format!(
+                "Doc comment of {kind} `{ident}` is empty\n\
+                 Describe what the {kind} is for, or remove the empty `///` lines"
+            ),
+            Placeholder::Marker => format!(
+                "Doc comment of {kind} `{ident}` is only a placeholder\n\
+                 Write the documentation, or track the missing docs in an issue"
+            ),
+            Placeholder::Name => format!(
+                "Doc comment of {kind} `{ident}` only repeats its name\n\
+                 Explain what the {kind} does and when to use it, not what it is called"
+            )
+        };
+
+        let start = first.span().start();
+        self.issues.push(Issue {
+            line: start.line,
+            column: start.column,
+            message,
+            fix: Fix::None
+        });
+    }
+}
+
+impl<'ast> Visit<'ast> for PlaceholderVisitor {
+    fn visit_item_fn(&mut self, node: &'ast ItemFn) {
+        if is_test_code(&node.attrs) {
+            return;
+        }
+        self.check("function", &node.attrs, &node.sig.ident);
+        syn::visit::visit_item_fn(self, node);
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'ast ImplItemFn) {
+        if is_test_code(&node.attrs) {
+            return;
+        }
+        self.check("method", &node.attrs, &node.sig.ident);
+        syn::visit::visit_impl_item_fn(self, node);
+    }
+
+    fn visit_trait_item_fn(&mut self, node: &'ast TraitItemFn) {
+        self.check("method", &node.attrs, &node.sig.ident);
+        syn::visit::visit_trait_item_fn(self, node);
+    }
+
+    fn visit_item_struct(&mut self, node: &'ast ItemStruct) {
+        self.check("struct", &node.attrs, &node.ident);
+    }
+
+    fn visit_item_enum(&mut self, node: &'ast ItemEnum) {
+        self.check("enum", &node.attrs, &node.ident);
+    }
+
+    fn visit_item_trait(&mut self, node: &'ast ItemTrait) {
+        self.check("trait", &node.attrs, &node.ident);
+        syn::visit::visit_item_trait(self, node);
+    }
+
+    fn visit_item_type(&mut self, node: &'ast ItemType) {
+        self.check("type", &node.attrs, &node.ident);
+    }
+
+    fn visit_item_const(&mut self, node: &'ast ItemConst) {
+        self.check("constant", &node.attrs, &node.ident);
+    }
+
+    fn visit_item_static(&mut self, node: &'ast ItemStatic) {
+        self.check("static", &node.attrs, &node.ident);
+    }
+
+    fn visit_item_mod(&mut self, node: &'ast ItemMod) {
+        if is_test_code(&node.attrs) || is_hidden(&node.attrs) {
+            return;
+        }
+        self.check("module", &node.attrs, &node.ident);
+        syn::visit::visit_item_mod(self, node);
+    }
+}
+
+/// Returns the text of a `///` or `#[doc = "..."]` attribute.
+fn doc_text(attr: &Attribute) -> Option<String> {
+    if !attr.path().is_ident("doc") {
+        return None;
+    }
+    match &attr.meta {
+        Meta::NameValue(MetaNameValue {
+            value:
+                Expr::Lit(ExprLit {
+                    lit: Lit::Str(text),
+                    ..
+                }),
+            ..
+        }) => Some(text.value()),
+        _ => None
+    }
+}
+
+/// Decides whether doc text documents nothing.
+///
+/// # Arguments
+///
+/// * `text` - Doc comment text, one line per `///`
+/// * `name` - Item name without `r#`
+/// * `kind` - Item kind, ignored in the text, e.g. `/// The config struct.`
+///
+/// # Returns
+///
+/// The kind of placeholder, or `None` for real documentation
+///
+/// # Notes
+///
+/// - Words are compared joined, so `HttpClient` matches `/// Http client` and
+///   `/// httpclient`
+/// - A trailing `s` of the first word is dropped for third-person docs like
+///   `/// Parses config.` on `parse_config`
+fn classify(text: &str, name: &str, kind: &str) -> Option<Placeholder> {
+    let words = words(text);
+    if words.is_empty() {
+        return Some(Placeholder::Empty);
+    }
+    if let [word] = words.as_slice()
+        && PLACEHOLDERS.contains(&word.as_str())
+    {
+        return Some(Placeholder::Marker);
+    }
+
+    let mut content: Vec<String> = words
+        .into_iter()
+        .filter(|word| !FILLER_WORDS.contains(&word.as_str()) && word != kind)
+        .collect();
+    let name = name_words(name).concat();
+    if content.concat() == name {
+        return Some(Placeholder::Name);
+    }
+
+    if let Some(first) = content.first_mut()
+        && let Some(stem) = first.strip_suffix('s')
+    {
+        *first = stem.to_string();
+    }
+    (content.concat() == name).then_some(Placeholder::Name)
+}
+
+/// Splits doc text into lowercase words, dropping punctuation and markup.
+fn words(text: &str) -> Vec<String> {
+    text.split(|ch: char| !ch.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// Splits a `snake_case` or `CamelCase` name into lowercase words.
+fn name_words(name: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut current = String::new();
+    let mut previous_lower = false;
+
+    for ch in name.chars() {
+        if ch == '_' {
+            if !current.is_empty() {
+                words.push(current.to_lowercase());
+                current.clear();
+            }
+            previous_lower = false;
+            continue;
+        }
+        if ch.is_uppercase() && previous_lower && !current.is_empty() {
+            words.push(current.to_lowercase());
+            current.clear();
+        }
+        previous_lower = ch.is_lowercase() || ch.is_ascii_digit();
+        current.push(ch);
+    }
+    if !current.is_empty() {
+        words.push(current.to_lowercase());
+    }
+
+    words
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analyze(code: &str) -> AnalysisResult {
+        let ast = syn::parse_file(code).unwrap();
+        PlaceholderDocsAnalyzer::new().analyze(&ast, code).unwrap()
+    }
+
+    fn first_lines(result: &AnalysisResult) -> Vec<&str> {
+        result
+            .issues
+            .iter()
+            .map(|issue| issue.message.lines().next().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        let analyzer = PlaceholderDocsAnalyzer::new();
+        assert_eq!(analyzer.name(), "placeholder_docs");
+    }
+
+    #[test]
+    fn test_detect_empty_and_marker_docs() {
+        let result = analyze(
+            r#"
+///
+///
+pub fn run() {}
+
+/// TODO
+pub struct Config;
+
+/// FIXME!
+pub enum Mode { Fast }
+
+#[doc = ""]
+pub const LIMIT: usize = 1;
+"#
+        );
+
+        assert_eq!(
+            first_lines(&result),
+            [
+                "Doc comment of function `run` is empty",
+                "Doc comment of struct `Config` is only a placeholder",
+                "Doc comment of enum `Mode` is only a placeholder",
+                "Doc comment of constant `LIMIT` is empty"
+            ]
+        );
+        assert_eq!(result.issues[0].line, 2);
+        assert_eq!(result.issues[0].column, 0);
+        assert_eq!(result.fixable_count, 0);
+    }
+
+    #[test]
+    fn test_detect_docs_repeating_the_name() {
+        let result = analyze(
+            r#"
+/// Parse config.
+fn parse_config() {}
+
+/// Parses the config
+fn parse_config_again() {}
+
+/// `HttpClient`
+struct HttpClient;
+
+/// The retry policy trait.
+trait RetryPolicy {
+    /// max_attempts
+    fn max_attempts(&self) -> u32;
+}
+
+impl HttpClient {
+    /// New.
+    fn new() -> Self { Self }
+}
+
+/// Type.
+fn r#type() {}
+"#
+        );
+
+        assert_eq!(
+            first_lines(&result),
+            [
+                "Doc comment of function `parse_config` only repeats its name",
+                "Doc comment of struct `HttpClient` only repeats its name",
+                "Doc comment of trait `RetryPolicy` only repeats its name",
+                "Doc comment of method `max_attempts` only repeats its name",
+                "Doc comment of method `new` only repeats its name",
+                "Doc comment of function `r#type` only repeats its name"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_real_docs_pass() {
+        let result = analyze(
+            r#"
+/// Parses `quality.toml`, falling back to defaults.
+pub fn parse_config() {}
+
+/// TODO: describe the retry rules once they settle.
+pub struct Retry;
+
+/// Creates a client with the default timeout.
+pub fn new() {}
+
+pub fn undocumented() {}
+
+mod inner {
+    //! Inner docs are not `///` comments.
+}
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_skip_hidden_and_test_code() {
+        let result = analyze(
+            r#"
+/// TODO
+#[doc(hidden)]
+pub fn internal() {}
+
+#[cfg(test)]
+mod tests {
+    /// TODO
+    fn helper() {}
+}
+
+/// run
+#[test]
+fn run() {}
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_name_words() {
+        assert_eq!(name_words("parse_config"), ["parse", "config"]);
+        assert_eq!(name_words("HttpClient"), ["http", "client"]);
+        assert_eq!(name_words("MAX_RETRIES"), ["max", "retries"]);
+        assert_eq!(name_words("parse2Json"), ["parse2", "json"]);
+    }
+}
@@ -0,0 +1,261 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Predicate naming analyzer for public `bool` functions named like actions.
+//!
+//! A call such as `if config.valid()` or `if file.cached(key)` leaves the
+//! reader guessing whether it checks something or does something, while
+//! `is_valid()` and `has_cached(key)` read as questions. This analyzer flags
+//! public functions and inherent methods returning `bool` whose name has no
+//! predicate prefix such as `is_` or `has_`, following the standard library
+//! (`is_empty`, `contains`, `starts_with`). Names starting with a
+//! third-person verb (`contains_key`, `applies_to`) are accepted as well.
+
+use masterror::AppResult;
+use syn::{File, ImplItemFn, ItemFn, ItemImpl, ItemMod, Signature, Visibility, visit::Visit};
+
+use super::{bool_result::returns_bool, is_test_code};
+use crate::analyzer::{AnalysisResult, Analyzer, Confidence, Fix, Issue};
+
+/// Name prefixes of predicates, where a `bool` answer is the intent.
+const PREDICATE_PREFIXES: &[&str] = &[
+    "is_",
+    "has_",
+    "can_",
+    "should_",
+    "contains_",
+    "was_",
+    "will_",
+    "needs_",
+    "must_"
+];
+
+/// Complete names that read as questions without a prefix.
+const PREDICATE_NAMES: &[&str] = &[
+    "all", "any", "eq", "ne", "lt", "le", "gt", "ge", "insert", "remove", "equals"
+];
+
+/// Analyzer for public `bool` functions without a predicate name.
+///
+/// # Examples
+///
+/// Detects this pattern:
+/// ```ignore
+/// pub fn valid(&self) -> bool {
+///     !self.name.is_empty()
+/// }
+/// ```
+///
+/// Suggests a predicate name:
+/// ```ignore
+/// pub fn is_valid(&self) -> bool {
+///     !self.name.is_empty()
+/// }
+/// ```
+pub struct PredicateNamingAnalyzer;
+
+impl PredicateNamingAnalyzer {
+    /// Create new predicate naming analyzer instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Analyzer for PredicateNamingAnalyzer {
+    fn name(&self) -> &'static str {
+        "predicate_naming"
+    }
+
+    fn analyze(&self, ast: &File, _content: &str) -> AppResult<AnalysisResult> {
+        let mut visitor = PredicateVisitor {
+            issues: Vec::new()
+        };
+        visitor.visit_file(ast);
+
+        Ok(AnalysisResult {
+            issues:        visitor.issues,
+            fixable_count: 0
+        })
+    }
+
+    fn confidence(&self) -> Confidence {
+        Confidence::Medium
+    }
+}
+
+impl Default for PredicateNamingAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct PredicateVisitor {
+    issues: Vec<Issue>
+}
+
+impl PredicateVisitor {
+    /// Flag a public `bool` function whose name is not a predicate.
+    fn check(&mut self, vis: &Visibility, sig: &Signature) {
+        let name = sig.ident.to_string();
+        if !matches!(vis, Visibility::Public(_))
+            || !returns_bool(&sig.output)
+            || is_predicate_name(&name)
+        {
+            return;
+        }
+
+        let start = sig.ident.span().start();
+        self.issues.push(Issue {
+            line:    start.line,
+            column:  start.column,
+            message: format!(
+                "Public function `{name}` returns `bool` but is not named as a question\n\
+                 Rename it with a predicate prefix, e.g. `is_{name}` or `has_{name}`, so call \
+                 sites read as conditions"
+            ),
+            fix:     Fix::None
+        });
+    }
+}
+
+impl<'ast> Visit<'ast> for PredicateVisitor {
+    fn visit_item_fn(&mut self, node: &'ast ItemFn) {
+        if is_test_code(&node.attrs) {
+            return;
+        }
+        self.check(&node.vis, &node.sig);
+        syn::visit::visit_item_fn(self, node);
+    }
+
+    fn visit_item_impl(&mut self, node: &'ast ItemImpl) {
+        if node.trait_.is_none() {
+            syn::visit::visit_item_impl(self, node);
+        }
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'ast ImplItemFn) {
+        if is_test_code(&node.attrs) {
+            return;
+        }
+        self.check(&node.vis, &node.sig);
+        syn::visit::visit_impl_item_fn(self, node);
+    }
+
+    fn visit_item_mod(&mut self, node: &'ast ItemMod) {
+        if !is_test_code(&node.attrs) {
+            syn::visit::visit_item_mod(self, node);
+        }
+    }
+}
+
+/// Check whether a function name reads as a question.
+///
+/// # Returns
+///
+/// `true` for names with a [`PREDICATE_PREFIXES`] prefix, names in
+/// `PREDICATE_NAMES`, and names whose first word is a third-person verb
+/// such as `contains`, `matches` or `applies`
+fn is_predicate_name(name: &str) -> bool {
+    let name = name.trim_start_matches('_');
+    let first = name.split('_').next().unwrap_or(name);
+    has_predicate_prefix(name)
+        || PREDICATE_NAMES.contains(&name)
+        || (first.len() > 3 && first.ends_with('s') && !first.ends_with("ss"))
+}
+
+/// Check whether a function name starts with a [`PREDICATE_PREFIXES`]
+/// prefix, such as `is_` or `has_`.
+pub(crate) fn has_predicate_prefix(name: &str) -> bool {
+    PREDICATE_PREFIXES
+        .iter()
+        .any(|prefix| name.starts_with(prefix))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analyze(code: &str) -> AnalysisResult {
+        let ast = syn::parse_file(code).unwrap();
+        PredicateNamingAnalyzer::new().analyze(&ast, code).unwrap()
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        let analyzer = PredicateNamingAnalyzer::new();
+        assert_eq!(analyzer.name(), "predicate_naming");
+        assert_eq!(analyzer.confidence(), Confidence::Medium);
+    }
+
+    #[test]
+    fn test_detect_public_bool_without_prefix() {
+        let result = analyze(
+            r#"
+pub fn valid(name: &str) -> bool { !name.is_empty() }
+
+pub struct Cache;
+
+impl Cache {
+    pub fn cached(&self, key: &str) -> bool { false }
+}
+"#
+        );
+
+        assert_eq!(result.issues.len(), 2);
+        assert_eq!(result.fixable_count, 0);
+        assert_eq!(result.issues[0].line, 2);
+        assert_eq!(result.issues[0].column, 7);
+        assert!(
+            result.issues[0]
+                .message
+                .contains("Public function `valid` returns `bool` but is not named as a question")
+        );
+        assert!(result.issues[0].message.contains("`is_valid`"));
+        assert!(result.issues[1].message.contains("`cached`"));
+    }
+
+    #[test]
+    fn test_ignore_predicates_private_and_trait_impls() {
+        let result = analyze(
+            r#"
+pub fn is_available(&self) -> bool { true }
+pub fn has_errors(&self) -> bool { true }
+pub fn contains(&self, key: &str) -> bool { true }
+pub fn applies_to(&self, path: &Path) -> bool { true }
+pub fn insert(&mut self, key: String) -> bool { true }
+pub fn validate(name: &str) -> Result<(), Error> { Ok(()) }
+fn valid(name: &str) -> bool { true }
+pub(crate) fn cached(key: &str) -> bool { true }
+
+impl Analyzer for Custom {
+    fn fixable(&self) -> bool { true }
+}
+
+#[cfg(test)]
+mod tests {
+    pub fn fixture() -> bool { true }
+}
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_is_predicate_name() {
+        assert!(is_predicate_name("is_empty"));
+        assert!(is_predicate_name("starts_with"));
+        assert!(is_predicate_name("matches"));
+        assert!(is_predicate_name("eq"));
+        assert!(!is_predicate_name("valid"));
+        assert!(!is_predicate_name("process"));
+        assert!(!is_predicate_name("fixable"));
+    }
+
+    #[test]
+    fn test_default_implementation() {
+        let analyzer = PredicateNamingAnalyzer;
+        assert_eq!(analyzer.name(), "predicate_naming");
+    }
+}
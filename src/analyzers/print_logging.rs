@@ -0,0 +1,407 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Print logging analyzer for projects with structured logging.
+//!
+//! When a project depends on `tracing` or `log`, output written with
+//! `println!`/`eprintln!` bypasses levels, filtering and structured fields.
+//! This analyzer flags those macros outside test code and rewrites the simple
+//! cases to the logging crate's `info!`/`error!` equivalents.
+
+use std::collections::HashSet;
+
+use masterror::AppResult;
+use proc_macro2::TokenTree;
+use syn::{File, ItemFn, ItemMod, Macro, UseTree, spanned::Spanned, visit::Visit};
+
+use super::is_test_code;
+use crate::{
+    analyzer::{AnalysisResult, Analyzer, Fix, Issue, Suggestion, TextEdit},
+    project::LoggingCrate
+};
+
+/// Analyzer for `println!`-style logging in projects using `tracing` or `log`.
+///
+/// Without a known logging crate the analyzer reports nothing, so it is safe
+/// to run on CLI tools where printing is the intended output.
+///
+/// # Examples
+///
+/// Detects this pattern:
+/// ```ignore
+/// fn handle(request: Request) {
+///     println!("handling {}", request.id);
+/// }
+/// ```
+///
+/// Fixes to:
+/// ```ignore
+/// use tracing::info;
+///
+/// fn handle(request: Request) {
+///     info!("handling {}", request.id);
+/// }
+/// ```
+pub struct PrintLoggingAnalyzer {
+    logger: Option<LoggingCrate>
+}
+
+impl PrintLoggingAnalyzer {
+    /// Create new print logging analyzer without a known logging crate.
+    ///
+    /// Such an analyzer never reports issues; use [`Self::with_logger`] or
+    /// [`crate::analyzers::get_analyzers_for`] to enable it.
+    #[inline]
+    pub fn new() -> Self {
+        Self::with_logger(None)
+    }
+
+    /// Create analyzer for a project using the given logging crate.
+    ///
+    /// # Arguments
+    ///
+    /// * `logger` - Logging crate detected from `Cargo.toml`, if any
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cargo_quality::{
+    ///     analyzer::Analyzer, analyzers::PrintLoggingAnalyzer, project::LoggingCrate
+    /// };
+    ///
+    /// let analyzer = PrintLoggingAnalyzer::with_logger(Some(LoggingCrate::Log));
+    /// let code = "fn f() { println!(\"ready\"); }";
+    /// let ast = syn::parse_file(code).unwrap();
+    /// assert_eq!(analyzer.analyze(&ast, code).unwrap().issues.len(), 1);
+    /// ```
+    #[inline]
+    pub fn with_logger(logger: Option<LoggingCrate>) -> Self {
+        Self {
+            logger
+        }
+    }
+
+    /// Collect print macro call sites outside test code.
+    ///
+    /// # Arguments
+    ///
+    /// * `ast` - Parsed file
+    ///
+    /// # Returns
+    ///
+    /// Print macro invocations in source order
+    fn collect(ast: &File) -> Vec<&Macro> {
+        let mut visitor = PrintVisitor {
+            macros: Vec::new()
+        };
+        visitor.visit_file(ast);
+        visitor.macros
+    }
+}
+
+impl Analyzer for PrintLoggingAnalyzer {
+    fn name(&self) -> &'static str {
+        "print_logging"
+    }
+
+    fn analyze(&self, ast: &File, _content: &str) -> AppResult<AnalysisResult> {
+        let Some(logger) = self.logger else {
+            return Ok(AnalysisResult::default());
+        };
+
+        let mut issues = Vec::new();
+        let mut fixable_count = 0;
+
+        for mac in Self::collect(ast) {
+            let Some(name) = macro_name(mac) else {
+                continue;
+            };
+            let level = log_level(&name);
+            let start = mac.span().start();
+
+            let fix = if is_simple(mac, &name) {
+                fixable_count += 1;
+                Fix::WithImport {
+                    import:      format!("use {}::{};", logger.crate_name(), level),
+                    pattern:     format!("{}!", name),
+                    replacement: format!("{}!", level)
+                }
+            } else {
+                Fix::None
+            };
+
+            issues.push(Issue {
+                line: start.line,
+                column: start.column,
+                message: format!(
+                    "`{name}!` used for logging in a project that depends on `{krate}`\n\
+                     Use `{krate}::{level}!` so output respects levels and filters",
+                    krate = logger.crate_name()
+                ),
+                fix
+            });
+        }
+
+        Ok(AnalysisResult {
+            issues,
+            fixable_count
+        })
+    }
+
+    fn suggestions(&self, ast: &File, _content: &str) -> AppResult<Vec<Suggestion>> {
+        let Some(logger) = self.logger else {
+            return Ok(Vec::new());
+        };
+
+        let imported = imported_names(ast);
+        let mut suggestions = Vec::new();
+
+        for mac in Self::collect(ast) {
+            let Some(name) = macro_name(mac) else {
+                continue;
+            };
+            if !is_simple(mac, &name) {
+                continue;
+            }
+
+            let level = log_level(&name);
+            let import = if imported.contains(level) || imported.contains(logger.crate_name()) {
+                None
+            } else {
+                Some(format!("use {}::{};", logger.crate_name(), level))
+            };
+
+            suggestions.push(Suggestion {
+                edit: TextEdit {
+                    range:       mac.path.span().byte_range(),
+                    replacement: level.to_string()
+                },
+                import
+            });
+        }
+
+        Ok(suggestions)
+    }
+
+    fn fixable(&self) -> bool {
+        true
+    }
+}
+
+impl Default for PrintLoggingAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct PrintVisitor<'ast> {
+    macros: Vec<&'ast Macro>
+}
+
+impl<'ast> Visit<'ast> for PrintVisitor<'ast> {
+    fn visit_item_fn(&mut self, node: &'ast ItemFn) {
+        if !is_test_code(&node.attrs) {
+            syn::visit::visit_item_fn(self, node);
+        }
+    }
+
+    fn visit_item_mod(&mut self, node: &'ast ItemMod) {
+        if !is_test_code(&node.attrs) {
+            syn::visit::visit_item_mod(self, node);
+        }
+    }
+
+    fn visit_macro(&mut self, node: &'ast Macro) {
+        if macro_name(node).is_some() {
+            self.macros.push(node);
+        }
+        syn::visit::visit_macro(self, node);
+    }
+}
+
+/// Get the name of a print macro.
+///
+/// # Returns
+///
+/// `Some(name)` for `println`, `print`, `eprintln` and `eprint`
+fn macro_name(mac: &Macro) -> Option<String> {
+    let ident = mac.path.get_ident()?.to_string();
+    matches!(ident.as_str(), "println" | "print" | "eprintln" | "eprint").then_some(ident)
+}
+
+/// Map a print macro to the matching log level macro.
+#[inline]
+fn log_level(name: &str) -> &'static str {
+    if name.starts_with('e') {
+        "error"
+    } else {
+        "info"
+    }
+}
+
+/// Check whether a print macro can be rewritten mechanically.
+///
+/// Only line-printing macros whose first argument is a format string qualify;
+/// `print!`/`eprint!` build partial lines and `println!()` prints a blank
+/// line, neither of which maps to a log event.
+fn is_simple(mac: &Macro, name: &str) -> bool {
+    if !name.ends_with("ln") {
+        return false;
+    }
+
+    matches!(
+        mac.tokens.clone().into_iter().next(),
+        Some(TokenTree::Literal(literal)) if literal.to_string().starts_with(['"', 'r'])
+    )
+}
+
+/// Collect the names a file already brings into scope with `use`.
+///
+/// Glob imports contribute the name of their parent module, so
+/// `use tracing::*;` registers `tracing`.
+fn imported_names(ast: &File) -> HashSet<String> {
+    fn walk(tree: &UseTree, parent: Option<&str>, names: &mut HashSet<String>) {
+        match tree {
+            UseTree::Path(path) => walk(&path.tree, Some(&path.ident.to_string()), names),
+            UseTree::Name(name) => {
+                names.insert(name.ident.to_string());
+            }
+            UseTree::Rename(rename) => {
+                names.insert(rename.rename.to_string());
+            }
+            UseTree::Glob(_) => {
+                if let Some(parent) = parent {
+                    names.insert(parent.to_string());
+                }
+            }
+            UseTree::Group(group) => {
+                for item in &group.items {
+                    walk(item, parent, names);
+                }
+            }
+        }
+    }
+
+    let mut names = HashSet::new();
+    for item in &ast.items {
+        if let syn::Item::Use(item_use) = item {
+            walk(&item_use.tree, None, &mut names);
+        }
+    }
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::parse_quote;
+
+    use super::*;
+
+    fn tracing() -> PrintLoggingAnalyzer {
+        PrintLoggingAnalyzer::with_logger(Some(LoggingCrate::Tracing))
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        let analyzer = PrintLoggingAnalyzer::new();
+        assert_eq!(analyzer.name(), "print_logging");
+    }
+
+    #[test]
+    fn test_silent_without_logging_crate() {
+        let analyzer = PrintLoggingAnalyzer::new();
+        let code: File = parse_quote! {
+            fn main() {
+                println!("hello");
+            }
+        };
+
+        let result = analyzer.analyze(&code, "").unwrap();
+        assert!(result.issues.is_empty());
+        assert!(analyzer.suggestions(&code, "").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_detect_println_and_eprintln() {
+        let code: File = parse_quote! {
+            fn serve() {
+                println!("started");
+                eprintln!("failed: {}", err);
+            }
+        };
+
+        let result = tracing().analyze(&code, "").unwrap();
+        assert_eq!(result.issues.len(), 2);
+        assert_eq!(result.fixable_count, 2);
+        assert!(result.issues[0].message.contains("tracing::info!"));
+        assert!(result.issues[1].message.contains("tracing::error!"));
+    }
+
+    #[test]
+    fn test_print_without_newline_not_fixable() {
+        let code: File = parse_quote! {
+            fn serve() {
+                print!("progress");
+                println!();
+            }
+        };
+
+        let result = tracing().analyze(&code, "").unwrap();
+        assert_eq!(result.issues.len(), 2);
+        assert_eq!(result.fixable_count, 0);
+    }
+
+    #[test]
+    fn test_ignore_test_code() {
+        let code: File = parse_quote! {
+            #[cfg(test)]
+            mod tests {
+                fn helper() {
+                    println!("debug");
+                }
+            }
+
+            #[test]
+            fn standalone() {
+                println!("debug");
+            }
+        };
+
+        let result = tracing().analyze(&code, "").unwrap();
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_fix_rewrites_macro_and_adds_import() {
+        let content = "fn serve() {\n    println!(\"started {}\", port);\n}\n";
+        let ast = syn::parse_file(content).unwrap();
+
+        let suggestions = tracing().suggestions(&ast, content).unwrap();
+        let outcome = crate::fixer::apply_suggestions(content, &suggestions);
+
+        assert_eq!(
+            outcome.source,
+            "use tracing::info;\nfn serve() {\n    info!(\"started {}\", port);\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_fix_skips_existing_import() {
+        let content = "use log::{error, info};\n\nfn serve() {\n    eprintln!(\"boom\");\n}\n";
+        let ast = syn::parse_file(content).unwrap();
+
+        let analyzer = PrintLoggingAnalyzer::with_logger(Some(LoggingCrate::Log));
+        let suggestions = analyzer.suggestions(&ast, content).unwrap();
+
+        assert_eq!(suggestions.len(), 1);
+        assert!(suggestions[0].import.is_none());
+        assert_eq!(suggestions[0].edit.replacement, "error");
+    }
+
+    #[test]
+    fn test_default_implementation() {
+        let analyzer = PrintLoggingAnalyzer::default();
+        assert_eq!(analyzer.name(), "print_logging");
+        assert!(analyzer.logger.is_none());
+    }
+}
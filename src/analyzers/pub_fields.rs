@@ -0,0 +1,305 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Pub fields analyzer for exported structs exposing their representation.
+//!
+//! A `pub` field on a `pub` struct is part of the crate's API: renaming it,
+//! changing its type or deriving it from other state becomes a breaking
+//! change. This analyzer flags public named fields of public structs in
+//! library code and suggests accessor methods instead. Plain data types are
+//! exempt: structs deriving `Serialize`, `Deserialize` or clap's `Parser` /
+//! `Args`, and type names matching `metadata.quality.pub_fields_allow`.
+
+use std::path::Path;
+
+use masterror::AppResult;
+use syn::{Fields, File, ItemFn, ItemMod, ItemStruct, Visibility, visit::Visit};
+
+use super::{find_derive, is_library_path, is_test_code, missing_docs::is_hidden};
+use crate::analyzer::{AnalysisResult, Analyzer, Fix, Issue};
+
+/// Derives marking a struct as a data transfer or configuration type.
+const DATA_DERIVES: &[&str] = &["Serialize", "Deserialize", "Parser", "Args"];
+
+/// Analyzer for public fields on exported structs.
+///
+/// # Examples
+///
+/// Detects this pattern:
+/// ```ignore
+/// pub struct Connection {
+///     pub host: String,
+///     pub port: u16
+/// }
+/// ```
+///
+/// Suggests:
+/// ```ignore
+/// pub struct Connection {
+///     host: String,
+///     port: u16
+/// }
+///
+/// impl Connection {
+///     pub fn host(&self) -> &str {
+///         &self.host
+///     }
+///
+///     pub fn port(&self) -> u16 {
+///         self.port
+///     }
+/// }
+/// ```
+pub struct PubFieldsAnalyzer {
+    allow: Vec<String>
+}
+
+impl PubFieldsAnalyzer {
+    /// Create new pub fields analyzer without an allow-list.
+    #[inline]
+    pub fn new() -> Self {
+        Self::with_allow(Vec::new())
+    }
+
+    /// Create analyzer skipping types whose name matches a pattern.
+    ///
+    /// # Arguments
+    ///
+    /// * `allow` - Type name patterns where `*` matches any text, e.g.
+    ///   `*Config`, see
+    ///   [`ProjectContext::pub_fields_allow`](crate::project::ProjectContext::pub_fields_allow)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cargo_quality::{analyzer::Analyzer, analyzers::PubFieldsAnalyzer};
+    ///
+    /// let analyzer = PubFieldsAnalyzer::with_allow(vec!["*Config".to_string()]);
+    /// let code = "pub struct AppConfig { pub port: u16 }";
+    /// let ast = syn::parse_file(code).unwrap();
+    /// assert!(analyzer.analyze(&ast, code).unwrap().issues.is_empty());
+    /// ```
+    pub fn with_allow(allow: Vec<String>) -> Self {
+        Self {
+            allow
+        }
+    }
+}
+
+impl Analyzer for PubFieldsAnalyzer {
+    fn name(&self) -> &'static str {
+        "pub_fields"
+    }
+
+    fn analyze(&self, ast: &File, _content: &str) -> AppResult<AnalysisResult> {
+        let mut visitor = PubFieldsVisitor {
+            allow:  &self.allow,
+            issues: Vec::new()
+        };
+        visitor.visit_file(ast);
+
+        Ok(AnalysisResult {
+            issues:        visitor.issues,
+            fixable_count: 0
+        })
+    }
+
+    fn applies_to(&self, path: &Path) -> bool {
+        is_library_path(path)
+    }
+
+    fn options(&self) -> &'static [&'static str] {
+        &["pub_fields_allow"]
+    }
+}
+
+impl Default for PubFieldsAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct PubFieldsVisitor<'a> {
+    allow:  &'a [String],
+    issues: Vec<Issue>
+}
+
+impl<'ast> Visit<'ast> for PubFieldsVisitor<'_> {
+    fn visit_item_struct(&mut self, node: &'ast ItemStruct) {
+        let Fields::Named(fields) = &node.fields else {
+            return;
+        };
+        let name = node.ident.to_string();
+        if !matches!(node.vis, Visibility::Public(_))
+            || is_hidden(&node.attrs)
+            || DATA_DERIVES
+                .iter()
+                .any(|derive| find_derive(&node.attrs, derive).is_some())
+            || self
+                .allow
+                .iter()
+                .any(|pattern| matches_pattern(pattern, &name))
+        {
+            return;
+        }
+
+        let public: Vec<String> = fields
+            .named
+            .iter()
+            .filter(|field| matches!(field.vis, Visibility::Public(_)))
+            .filter_map(|field| field.ident.as_ref().map(|ident| format!("`{ident}`")))
+            .collect();
+        if public.is_empty() {
+            return;
+        }
+
+        let start = node.ident.span().start();
+        self.issues.push(Issue {
+            line:    start.line,
+            column:  start.column,
+            message: format!(
+                "Struct `{name}` exposes public field{} {}\n\
+                 Make the fields private and add accessor methods, so the representation \
+                 can change without breaking callers",
+                if public.len() == 1 { "" } else { "s" },
+                public.join(", ")
+            ),
+            fix:     Fix::None
+        });
+    }
+
+    /// Skip function bodies.
+    ///
+    /// # Notes
+    ///
+    /// - Types declared inside functions cannot be exported
+    fn visit_item_fn(&mut self, _node: &'ast ItemFn) {}
+
+    fn visit_item_mod(&mut self, node: &'ast ItemMod) {
+        if !is_test_code(&node.attrs) {
+            syn::visit::visit_item_mod(self, node);
+        }
+    }
+}
+
+/// Check whether a type name matches a pattern where `*` matches any text.
+fn matches_pattern(pattern: &str, name: &str) -> bool {
+    let mut parts: Vec<&str> = pattern.split('*').collect();
+    let Some(mut rest) = name.strip_prefix(parts.remove(0)) else {
+        return false;
+    };
+    let Some(last) = parts.pop() else {
+        return rest.is_empty();
+    };
+
+    for part in parts {
+        let Some(index) = rest.find(part) else {
+            return false;
+        };
+        rest = &rest[index + part.len()..];
+    }
+    rest.ends_with(last)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analyze(code: &str) -> AnalysisResult {
+        let ast = syn::parse_file(code).unwrap();
+        PubFieldsAnalyzer::new().analyze(&ast, code).unwrap()
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        let analyzer = PubFieldsAnalyzer::new();
+        assert_eq!(analyzer.name(), "pub_fields");
+    }
+
+    #[test]
+    fn test_detect_pub_fields() {
+        let result = analyze(
+            r#"
+pub struct Connection {
+    pub host: String,
+    pub(crate) retries: u8,
+    pub port: u16
+}
+"#
+        );
+
+        assert_eq!(result.issues.len(), 1);
+        assert_eq!(result.issues[0].line, 2);
+        assert!(
+            result.issues[0]
+                .message
+                .contains("`Connection` exposes public fields `host`, `port`")
+        );
+    }
+
+    #[test]
+    fn test_ignore_exempt_structs() {
+        let result = analyze(
+            r#"
+struct Private { pub a: u8 }
+pub(crate) struct Internal { pub a: u8 }
+pub struct Wrapper(pub u8);
+pub struct Accessors { a: u8 }
+
+#[derive(Debug, serde::Deserialize)]
+pub struct Settings { pub a: u8 }
+
+#[derive(clap::Parser)]
+pub struct Cli { pub a: u8 }
+
+#[doc(hidden)]
+pub struct Hidden { pub a: u8 }
+
+fn build() {
+    pub struct Local { pub a: u8 }
+}
+
+#[cfg(test)]
+mod tests {
+    pub struct Fixture { pub a: u8 }
+}
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_allow_list_patterns() {
+        let analyzer =
+            PubFieldsAnalyzer::with_allow(vec!["*Config".to_string(), "Dto*Row".to_string()]);
+        let code = "pub struct AppConfig { pub a: u8 }\npub struct DtoUserRow { pub a: u8 }\n\
+                    pub struct ConfigLoader { pub a: u8 }\n";
+        let ast = syn::parse_file(code).unwrap();
+        let result = analyzer.analyze(&ast, code).unwrap();
+
+        assert_eq!(result.issues.len(), 1);
+        assert!(
+            result.issues[0]
+                .message
+                .contains("`ConfigLoader` exposes public field `a`\n")
+        );
+        assert!(matches_pattern("Exact", "Exact"));
+        assert!(!matches_pattern("Exact", "Exactly"));
+        assert!(matches_pattern("*", "Anything"));
+        assert!(!matches_pattern("AB*BA", "ABA"));
+    }
+
+    #[test]
+    fn test_applies_to_library_code() {
+        let analyzer = PubFieldsAnalyzer::new();
+        assert!(analyzer.applies_to(Path::new("src/config.rs")));
+        assert!(!analyzer.applies_to(Path::new("src/main.rs")));
+    }
+
+    #[test]
+    fn test_default_implementation() {
+        let analyzer = PubFieldsAnalyzer::default();
+        assert_eq!(analyzer.name(), "pub_fields");
+    }
+}
@@ -0,0 +1,509 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Redundant imports analyzer for duplicated, overlapping and unused `use`.
+//!
+//! Imports accumulate as code moves around: the same path ends up imported
+//! twice, an item is imported although the code reaches it through its
+//! imported module, or nothing uses the name at all. This analyzer finds all
+//! three with a conservative scan of the identifiers in the file, and
+//! removes duplicated and unused single-item `use` statements.
+
+use std::{
+    collections::{HashMap, HashSet},
+    ops::Range
+};
+
+use masterror::AppResult;
+use proc_macro2::{Span, TokenStream, TokenTree};
+use quote::ToTokens;
+use syn::{
+    Block, File, Item, ItemMod, ItemUse, Stmt, UseTree, Visibility, spanned::Spanned, visit::Visit
+};
+
+use super::whole_lines;
+use crate::analyzer::{AnalysisResult, Analyzer, Fix, Issue, Suggestion, TextEdit};
+
+/// Analyzer for duplicated, overlapping and unused imports.
+///
+/// # Examples
+///
+/// Detects this pattern:
+/// ```ignore
+/// use std::env;
+/// use std::fs;
+/// use std::fs::read;
+/// use std::fs;
+///
+/// fn load() -> Vec<u8> {
+///     fs::read("config.toml").unwrap()
+/// }
+/// ```
+///
+/// Fixes the duplicated and unused imports:
+/// ```ignore
+/// use std::fs;
+/// use std::fs::read;
+///
+/// fn load() -> Vec<u8> {
+///     fs::read("config.toml").unwrap()
+/// }
+/// ```
+pub struct RedundantImportsAnalyzer;
+
+/// Problem found with one imported name.
+struct Finding {
+    span:    Span,
+    message: String,
+    /// Range removing the whole `use` statement, when that fixes it
+    removal: Option<Range<usize>>
+}
+
+impl RedundantImportsAnalyzer {
+    /// Create new redundant imports analyzer instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Check every scope of the file against the identifiers it uses.
+    fn collect(ast: &File) -> Vec<Finding> {
+        let mut usage = Usage::default();
+        usage.scan(ast.to_token_stream());
+
+        let mut visitor = ScopeVisitor {
+            findings: Vec::new(),
+            usage
+        };
+        visitor.visit_file(ast);
+        visitor.findings
+    }
+}
+
+impl Analyzer for RedundantImportsAnalyzer {
+    fn name(&self) -> &'static str {
+        "redundant_imports"
+    }
+
+    fn analyze(&self, ast: &File, _content: &str) -> AppResult<AnalysisResult> {
+        let mut issues = Vec::new();
+        let mut fixable_count = 0;
+
+        for finding in Self::collect(ast) {
+            let fix = if finding.removal.is_some() {
+                fixable_count += 1;
+                Fix::Simple(String::new())
+            } else {
+                Fix::None
+            };
+
+            let start = finding.span.start();
+            issues.push(Issue {
+                line: start.line,
+                column: start.column,
+                message: finding.message,
+                fix
+            });
+        }
+
+        Ok(AnalysisResult {
+            issues,
+            fixable_count
+        })
+    }
+
+    fn suggestions(&self, ast: &File, content: &str) -> AppResult<Vec<Suggestion>> {
+        Ok(Self::collect(ast)
+            .into_iter()
+            .filter_map(|finding| finding.removal)
+            .map(|range| Suggestion {
+                edit:   TextEdit {
+                    range:       whole_lines(content, range),
+                    replacement: String::new()
+                },
+                import: None
+            })
+            .collect())
+    }
+
+    fn fixable(&self) -> bool {
+        true
+    }
+}
+
+impl Default for RedundantImportsAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Identifiers used outside `use` statements.
+#[derive(Default)]
+struct Usage {
+    names:     HashSet<String>,
+    /// `parent::name` pairs written as paths
+    qualified: HashSet<(String, String)>,
+    /// Whether any `.member` access appears, which may need a trait import
+    members:   bool
+}
+
+impl Usage {
+    /// Record identifiers in tokens, skipping `use ...;` statements.
+    fn scan(&mut self, tokens: TokenStream) {
+        let mut tokens = tokens.into_iter();
+        let mut previous: Option<String> = None;
+        let mut colons = 0;
+        let mut dot = false;
+
+        while let Some(token) = tokens.next() {
+            let after_dot = std::mem::take(&mut dot);
+            match token {
+                TokenTree::Ident(ident) if ident == "use" => {
+                    for token in tokens.by_ref() {
+                        if matches!(&token, TokenTree::Punct(punct) if punct.as_char() == ';') {
+                            break;
+                        }
+                    }
+                    previous = None;
+                }
+                TokenTree::Ident(ident) => {
+                    self.members |= after_dot;
+                    let name = ident.to_string();
+                    if colons == 2
+                        && let Some(parent) = previous.take()
+                    {
+                        self.qualified.insert((parent, name.clone()));
+                    }
+                    self.names.insert(name.clone());
+                    previous = Some(name);
+                    colons = 0;
+                }
+                TokenTree::Punct(punct) if punct.as_char() == ':' => colons += 1,
+                TokenTree::Group(group) => {
+                    self.scan(group.stream());
+                    previous = None;
+                    colons = 0;
+                }
+                other => {
+                    dot = matches!(other, TokenTree::Punct(punct) if punct.as_char() == '.');
+                    previous = None;
+                    colons = 0;
+                }
+            }
+        }
+    }
+}
+
+/// Name brought into scope by one leaf of a use tree.
+struct Leaf {
+    /// Full path, with ` as name` for renames
+    path:      String,
+    /// Path of the module the name is imported from
+    module:    String,
+    name:      String,
+    span:      Span,
+    /// Statement is `pub use` or carries attributes such as `#[cfg]`
+    exempt:    bool,
+    /// Range of the whole statement when this is its only leaf
+    statement: Option<Range<usize>>
+}
+
+struct ScopeVisitor {
+    findings: Vec<Finding>,
+    usage:    Usage
+}
+
+impl ScopeVisitor {
+    /// Check the `use` statements declared directly in one scope.
+    fn check_scope<'ast>(&mut self, items: impl IntoIterator<Item = &'ast ItemUse>) {
+        let leaves: Vec<Leaf> = items.into_iter().flat_map(leaves).collect();
+        let paths: HashSet<&str> = leaves.iter().map(|leaf| leaf.path.as_str()).collect();
+        let mut seen = HashMap::new();
+
+        for leaf in &leaves {
+            let first = *seen.entry(leaf.path.as_str()).or_insert(leaf.span.start());
+
+            let (message, removal) = if first != leaf.span.start() && !leaf.exempt {
+                let message = format!(
+                    "Import `{}` is duplicated (first imported on line {})\n\
+                     Remove the repeated import",
+                    leaf.path, first.line
+                );
+                (message, leaf.statement.clone())
+            } else if !leaf.exempt && self.is_unused(&leaf.name) {
+                let message = format!(
+                    "Import `{}` is never used\n\
+                     Remove the unused import",
+                    leaf.path
+                );
+                (message, leaf.statement.clone())
+            } else if let Some(parent) = leaf.module.rsplit("::").next()
+                && paths.contains(leaf.module.as_str())
+                && self
+                    .usage
+                    .qualified
+                    .contains(&(parent.to_string(), leaf.name.clone()))
+            {
+                let message = format!(
+                    "`{name}` is imported from `{}`, which is also imported and used as \
+                     `{parent}::{name}`\n\
+                     Use one form consistently: drop the item import or the path prefix",
+                    leaf.module,
+                    name = leaf.name
+                );
+                (message, None)
+            } else {
+                continue;
+            };
+
+            self.findings.push(Finding {
+                span: leaf.span,
+                message,
+                removal
+            });
+        }
+    }
+
+    /// Conservative unused check: the name appears nowhere outside imports.
+    ///
+    /// CamelCase names may be traits needed only for their methods, so they
+    /// are reported only in files without any `.member` access.
+    fn is_unused(&self, name: &str) -> bool {
+        let maybe_trait = name.starts_with(char::is_uppercase)
+            && name.chars().any(char::is_lowercase)
+            && self.usage.members;
+        name != "_" && !maybe_trait && !self.usage.names.contains(name)
+    }
+}
+
+impl<'ast> Visit<'ast> for ScopeVisitor {
+    fn visit_file(&mut self, node: &'ast File) {
+        self.check_scope(node.items.iter().filter_map(use_item));
+        syn::visit::visit_file(self, node);
+    }
+
+    fn visit_item_mod(&mut self, node: &'ast ItemMod) {
+        if let Some((_, items)) = &node.content {
+            self.check_scope(items.iter().filter_map(use_item));
+        }
+        syn::visit::visit_item_mod(self, node);
+    }
+
+    fn visit_block(&mut self, node: &'ast Block) {
+        self.check_scope(node.stmts.iter().filter_map(|stmt| match stmt {
+            Stmt::Item(item) => use_item(item),
+            _ => None
+        }));
+        syn::visit::visit_block(self, node);
+    }
+}
+
+/// Get the `use` item an item is, if any.
+fn use_item(item: &Item) -> Option<&ItemUse> {
+    match item {
+        Item::Use(item) => Some(item),
+        _ => None
+    }
+}
+
+/// Flatten a `use` statement into the names it imports.
+///
+/// Globs are skipped since they do not name what they import.
+fn leaves(item: &ItemUse) -> Vec<Leaf> {
+    fn walk(tree: &UseTree, prefix: &mut Vec<String>, leaves: &mut Vec<Leaf>) {
+        let (path, module, name, span) = match tree {
+            UseTree::Path(path) => {
+                prefix.push(path.ident.to_string());
+                walk(&path.tree, prefix, leaves);
+                prefix.pop();
+                return;
+            }
+            UseTree::Group(group) => {
+                for tree in &group.items {
+                    walk(tree, prefix, leaves);
+                }
+                return;
+            }
+            UseTree::Glob(_) => return,
+            UseTree::Name(name) if name.ident == "self" => {
+                let Some((last, module)) = prefix.split_last() else {
+                    return;
+                };
+                (
+                    prefix.join("::"),
+                    module.join("::"),
+                    last.clone(),
+                    name.ident.span()
+                )
+            }
+            UseTree::Name(name) => {
+                let module = prefix.join("::");
+                let path = format!("{module}::{}", name.ident);
+                (path, module, name.ident.to_string(), name.ident.span())
+            }
+            UseTree::Rename(rename) => {
+                let module = prefix.join("::");
+                let path = format!("{module}::{} as {}", rename.ident, rename.rename);
+                (
+                    path,
+                    module,
+                    rename.rename.to_string(),
+                    rename.rename.span()
+                )
+            }
+        };
+
+        leaves.push(Leaf {
+            path: path.trim_start_matches("::").to_string(),
+            module,
+            name,
+            span,
+            exempt: false,
+            statement: None
+        });
+    }
+
+    let mut found = Vec::new();
+    walk(&item.tree, &mut Vec::new(), &mut found);
+
+    let exempt = !matches!(item.vis, Visibility::Inherited) || !item.attrs.is_empty();
+    let single = found.len() == 1;
+    for leaf in &mut found {
+        leaf.exempt = exempt;
+        if single {
+            leaf.statement = Some(item.span().byte_range());
+        }
+    }
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analyze(code: &str) -> AnalysisResult {
+        let ast = syn::parse_file(code).unwrap();
+        RedundantImportsAnalyzer::new().analyze(&ast, code).unwrap()
+    }
+
+    fn fix(code: &str) -> String {
+        let ast = syn::parse_file(code).unwrap();
+        let suggestions = RedundantImportsAnalyzer::new()
+            .suggestions(&ast, code)
+            .unwrap();
+        crate::fixer::apply_suggestions(code, &suggestions).source
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        let analyzer = RedundantImportsAnalyzer::new();
+        assert_eq!(analyzer.name(), "redundant_imports");
+    }
+
+    #[test]
+    fn test_detect_duplicate_import() {
+        let code = r#"
+use std::collections::HashMap;
+use std::{collections::HashMap, fs};
+
+fn build() -> HashMap<String, Vec<u8>> {
+    fs::read_dir(".");
+    HashMap::new()
+}
+"#;
+        let result = analyze(code);
+
+        assert_eq!(result.issues.len(), 1);
+        assert_eq!(result.issues[0].line, 3);
+        assert!(
+            result.issues[0]
+                .message
+                .contains("`std::collections::HashMap` is duplicated (first imported on line 2)")
+        );
+        assert_eq!(result.fixable_count, 0);
+    }
+
+    #[test]
+    fn test_detect_and_remove_unused_imports() {
+        let code = r#"use std::path::PathBuf;
+use std::io::{self, Write};
+use serde::Serialize;
+use tracing::info;
+
+#[derive(Serialize)]
+struct Report;
+
+fn log(out: &mut impl Write) {
+    info!("writing");
+}
+"#;
+        let result = analyze(code);
+
+        assert_eq!(result.issues.len(), 2);
+        assert!(
+            result.issues[0]
+                .message
+                .contains("`std::path::PathBuf` is never used")
+        );
+        assert!(result.issues[1].message.contains("`std::io` is never used"));
+        assert_eq!(result.fixable_count, 1);
+        assert!(fix(code).starts_with("use std::io::{self, Write};\n"));
+    }
+
+    #[test]
+    fn test_detect_item_also_used_through_module() {
+        let result = analyze(
+            r#"
+use std::fs;
+use std::fs::read;
+
+fn load() -> Vec<u8> {
+    let a = read("a").unwrap();
+    fs::read("b").unwrap()
+}
+"#
+        );
+
+        assert_eq!(result.issues.len(), 1);
+        assert_eq!(result.issues[0].line, 3);
+        assert!(
+            result.issues[0]
+                .message
+                .contains("also imported and used as `fs::read`")
+        );
+    }
+
+    #[test]
+    fn test_scopes_reexports_and_method_traits() {
+        let result = analyze(
+            r#"
+pub use crate::config::Config;
+use syn::spanned::Spanned;
+use std::io::Write as _;
+
+mod inner {
+    use std::fs;
+
+    pub fn run() {
+        fs::remove_file("x");
+    }
+}
+
+fn span_of(node: &syn::Expr) {
+    use std::fs;
+    use std::path::PathBuf;
+    node.span();
+    fs::write("y", "z");
+}
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_default_implementation() {
+        let analyzer = RedundantImportsAnalyzer;
+        assert_eq!(analyzer.name(), "redundant_imports");
+    }
+}
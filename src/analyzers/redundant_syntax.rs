@@ -0,0 +1,438 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Redundant syntax analyzer for `return` and `;` the compiler does not need.
+//!
+//! A function evaluates to its last expression, so `return value;` as its
+//! last statement says the same as `value`, and idiomatic Rust keeps
+//! `return` for early exits. Block-like expressions such as `if`, `for` and
+//! `match` end statements on their own, so `if ready { start(); };` carries
+//! a stray semicolon. This analyzer flags both and removes them.
+//!
+//! Removing the semicolon after a block expression is only safe when the
+//! expression is `()`. Without types, the analyzer only removes it after
+//! `for` and `while` loops, loops without `break value`, `if` without `else`,
+//! and blocks, `if`/`else` chains and `match`es whose every branch is a block
+//! ending in a statement.
+
+use std::ops::Range;
+
+use masterror::AppResult;
+use proc_macro2::TokenTree;
+use quote::ToTokens;
+use syn::{
+    Block, Expr, File, ImplItemFn, ItemFn, ItemMod, Stmt, TraitItemFn, spanned::Spanned,
+    visit::Visit
+};
+
+use super::is_test_code;
+use crate::analyzer::{AnalysisResult, Analyzer, Fix, Issue, Suggestion, TextEdit};
+
+/// Analyzer for a trailing `return` and semicolons after block expressions.
+///
+/// # Examples
+///
+/// Detects this pattern:
+/// ```ignore
+/// fn total(items: &[u32]) -> u32 {
+///     for item in items {
+///         log(item);
+///     };
+///     return items.iter().sum();
+/// }
+/// ```
+///
+/// Fixes to:
+/// ```ignore
+/// fn total(items: &[u32]) -> u32 {
+///     for item in items {
+///         log(item);
+///     }
+///     items.iter().sum()
+/// }
+/// ```
+pub struct RedundantSyntaxAnalyzer;
+
+/// Redundant piece of syntax with the edit removing it.
+struct Finding {
+    line:    usize,
+    column:  usize,
+    message: String,
+    edit:    TextEdit
+}
+
+impl RedundantSyntaxAnalyzer {
+    /// Create new redundant syntax analyzer instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Collect the redundant `return`s and semicolons.
+    fn collect(ast: &File, content: &str) -> Vec<Finding> {
+        let mut visitor = SyntaxVisitor {
+            content,
+            findings: Vec::new()
+        };
+        visitor.visit_file(ast);
+        visitor.findings
+    }
+}
+
+impl Analyzer for RedundantSyntaxAnalyzer {
+    fn name(&self) -> &'static str {
+        "redundant_syntax"
+    }
+
+    fn analyze(&self, ast: &File, content: &str) -> AppResult<AnalysisResult> {
+        let issues: Vec<Issue> = Self::collect(ast, content)
+            .into_iter()
+            .map(|finding| Issue {
+                line:    finding.line,
+                column:  finding.column,
+                message: finding.message,
+                fix:     Fix::Simple(finding.edit.replacement)
+            })
+            .collect();
+
+        Ok(AnalysisResult {
+            fixable_count: issues.len(),
+            issues
+        })
+    }
+
+    fn suggestions(&self, ast: &File, content: &str) -> AppResult<Vec<Suggestion>> {
+        Ok(Self::collect(ast, content)
+            .into_iter()
+            .map(|finding| Suggestion {
+                edit:   finding.edit,
+                import: None
+            })
+            .collect())
+    }
+
+    fn fixable(&self) -> bool {
+        true
+    }
+}
+
+impl Default for RedundantSyntaxAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct SyntaxVisitor<'a> {
+    content:  &'a str,
+    findings: Vec<Finding>
+}
+
+impl SyntaxVisitor<'_> {
+    /// Check the last statement of a function body for a `return`.
+    fn check_return(&mut self, name: &str, body: &Block) {
+        let Some(Stmt::Expr(Expr::Return(ret), semi)) = body.stmts.last() else {
+            return;
+        };
+        let Some(value) = &ret.expr else {
+            return;
+        };
+        let Some(text) = self.text(value.span().byte_range()) else {
+            return;
+        };
+        let start = ret.return_token.span.byte_range().start;
+        let end = semi.map_or_else(
+            || ret.span().byte_range().end,
+            |semi| semi.span.byte_range().end
+        );
+
+        let position = ret.return_token.span.start();
+        self.findings.push(Finding {
+            line:    position.line,
+            column:  position.column,
+            message: format!(
+                "Redundant `return` in the last statement of `{name}`\nEnd the function with \
+                 the expression itself: `{}`",
+                first_line(&text)
+            ),
+            edit:    TextEdit {
+                range:       start..end,
+                replacement: text
+            }
+        });
+    }
+
+    /// Source text of a byte range.
+    fn text(&self, range: Range<usize>) -> Option<String> {
+        (range.start < range.end)
+            .then(|| self.content.get(range))
+            .flatten()
+            .map(str::to_string)
+    }
+}
+
+impl<'ast> Visit<'ast> for SyntaxVisitor<'_> {
+    fn visit_item_fn(&mut self, node: &'ast ItemFn) {
+        if !is_test_code(&node.attrs) {
+            self.check_return(&node.sig.ident.to_string(), &node.block);
+            syn::visit::visit_item_fn(self, node);
+        }
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'ast ImplItemFn) {
+        if !is_test_code(&node.attrs) {
+            self.check_return(&node.sig.ident.to_string(), &node.block);
+            syn::visit::visit_impl_item_fn(self, node);
+        }
+    }
+
+    fn visit_trait_item_fn(&mut self, node: &'ast TraitItemFn) {
+        if let Some(block) = &node.default {
+            self.check_return(&node.sig.ident.to_string(), block);
+        }
+        syn::visit::visit_trait_item_fn(self, node);
+    }
+
+    fn visit_item_mod(&mut self, node: &'ast ItemMod) {
+        if !is_test_code(&node.attrs) {
+            syn::visit::visit_item_mod(self, node);
+        }
+    }
+
+    fn visit_block(&mut self, node: &'ast Block) {
+        for stmt in &node.stmts {
+            let Stmt::Expr(expr, Some(semi)) = stmt else {
+                continue;
+            };
+            let Some(kind) = unit_block_kind(expr) else {
+                continue;
+            };
+            let range = semi.span.byte_range();
+            if self.content.get(range.clone()) != Some(";") {
+                continue;
+            }
+
+            let position = semi.span.start();
+            self.findings.push(Finding {
+                line:    position.line,
+                column:  position.column,
+                message: format!(
+                    "Redundant `;` after {kind}\nBlock-like expressions end the statement on \
+                     their own"
+                ),
+                edit:    TextEdit {
+                    range,
+                    replacement: String::new()
+                }
+            });
+        }
+        syn::visit::visit_block(self, node);
+    }
+}
+
+/// Description of a block-like expression known to be `()`, e.g. "a `for`
+/// loop".
+fn unit_block_kind(expr: &Expr) -> Option<&'static str> {
+    match expr {
+        Expr::ForLoop(_) => Some("a `for` loop"),
+        Expr::While(_) => Some("a `while` loop"),
+        Expr::Loop(looped) if !breaks_with_value(&looped.body) => Some("a `loop`"),
+        Expr::If(_) if is_unit(expr) => Some("an `if`"),
+        Expr::Match(_) if is_unit(expr) => Some("a `match`"),
+        Expr::Block(block) if block.label.is_none() && is_unit(expr) => Some("a block"),
+        Expr::Unsafe(_) if is_unit(expr) => Some("an `unsafe` block"),
+        _ => None
+    }
+}
+
+/// Check whether a block-like expression evaluates to `()`: every branch is
+/// a block without a tail expression, and `if`s without `else` count too.
+fn is_unit(expr: &Expr) -> bool {
+    match expr {
+        Expr::If(branch) => {
+            is_unit_block(&branch.then_branch)
+                && branch
+                    .else_branch
+                    .as_ref()
+                    .is_none_or(|(_, otherwise)| is_unit(otherwise))
+        }
+        Expr::Match(matched) => matched.arms.iter().all(|arm| is_unit(&arm.body)),
+        Expr::Block(block) => is_unit_block(&block.block),
+        Expr::Unsafe(block) => is_unit_block(&block.block),
+        Expr::Tuple(tuple) => tuple.elems.is_empty(),
+        _ => false
+    }
+}
+
+/// Check whether a block has no tail expression.
+fn is_unit_block(block: &Block) -> bool {
+    !matches!(block.stmts.last(), Some(Stmt::Expr(_, None)))
+}
+
+/// Check whether the body of a `loop` contains `break value`.
+fn breaks_with_value(body: &Block) -> bool {
+    let tokens: Vec<TokenTree> = body
+        .stmts
+        .iter()
+        .flat_map(ToTokens::to_token_stream)
+        .collect();
+    breaks_in(&tokens)
+}
+
+/// Check whether tokens contain `break` followed by a value.
+fn breaks_in(tokens: &[TokenTree]) -> bool {
+    tokens.iter().enumerate().any(|(index, token)| match token {
+        TokenTree::Ident(ident) if ident == "break" => match tokens.get(index + 1) {
+            None => false,
+            Some(TokenTree::Punct(punct)) => punct.as_char() != ';' && punct.as_char() != '\'',
+            Some(TokenTree::Ident(_) | TokenTree::Literal(_) | TokenTree::Group(_)) => true
+        },
+        TokenTree::Group(group) => breaks_in(&group.stream().into_iter().collect::<Vec<_>>()),
+        _ => false
+    })
+}
+
+/// First line of a text, with `..` if it continues.
+fn first_line(text: &str) -> String {
+    match text.split_once('\n') {
+        Some((first, _)) => format!("{} ..", first.trim_end()),
+        None => text.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analyze(code: &str) -> AnalysisResult {
+        let ast = syn::parse_file(code).unwrap();
+        RedundantSyntaxAnalyzer::new().analyze(&ast, code).unwrap()
+    }
+
+    fn fix(code: &str) -> String {
+        let ast = syn::parse_file(code).unwrap();
+        let suggestions = RedundantSyntaxAnalyzer::new()
+            .suggestions(&ast, code)
+            .unwrap();
+        crate::fixer::apply_suggestions(code, &suggestions).source
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        let analyzer = RedundantSyntaxAnalyzer::new();
+        assert_eq!(analyzer.name(), "redundant_syntax");
+        assert!(analyzer.fixable());
+    }
+
+    #[test]
+    fn test_detect_redundant_syntax() {
+        let result = analyze(
+            r#"
+fn total(items: &[u32]) -> u32 {
+    for item in items {
+        log(item);
+    };
+    if items.is_empty() {
+        warn();
+    };
+    return items.iter().sum();
+}
+"#
+        );
+
+        assert_eq!(result.issues.len(), 3);
+        assert_eq!(result.fixable_count, 3);
+        assert_eq!(result.issues[0].line, 9);
+        assert_eq!(result.issues[0].column, 4);
+        assert_eq!(
+            result.issues[0].message,
+            "Redundant `return` in the last statement of `total`\nEnd the function with the \
+             expression itself: `items.iter().sum()`"
+        );
+        assert_eq!(result.issues[1].line, 5);
+        assert_eq!(result.issues[1].column, 5);
+        assert!(
+            result.issues[1]
+                .message
+                .starts_with("Redundant `;` after a `for` loop\nBlock-like")
+        );
+        assert!(
+            result.issues[2]
+                .message
+                .starts_with("Redundant `;` after an `if`")
+        );
+    }
+
+    #[test]
+    fn test_fix_removes_redundant_syntax() {
+        let code = r#"impl Cache {
+    fn get(&self, key: &str) -> Option<&Entry> {
+        match self.mode {
+            Mode::Off => {}
+            Mode::On => {
+                self.hits.set(self.hits.get() + 1);
+            }
+        };
+        return self.entries.get(key)
+    }
+}
+"#;
+
+        assert_eq!(
+            fix(code),
+            r#"impl Cache {
+    fn get(&self, key: &str) -> Option<&Entry> {
+        match self.mode {
+            Mode::Off => {}
+            Mode::On => {
+                self.hits.set(self.hits.get() + 1);
+            }
+        }
+        self.entries.get(key)
+    }
+}
+"#
+        );
+    }
+
+    #[test]
+    fn test_ignore_needed_syntax() {
+        let result = analyze(
+            r#"
+fn run(flag: bool) -> u32 {
+    if flag {
+        return 1;
+    }
+    let value = if flag { 1 } else { 2 };
+    match flag { true => 1, false => 2 };
+    if flag { compute() } else { other() };
+    loop {
+        break 5;
+    };
+    loop {
+        break;
+    }
+    value
+}
+
+fn finish() {
+    return;
+}
+
+#[cfg(test)]
+mod tests {
+    fn fixture() -> u32 {
+        return 1;
+    }
+}
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_default_implementation() {
+        let analyzer = RedundantSyntaxAnalyzer;
+        assert_eq!(analyzer.name(), "redundant_syntax");
+    }
+}
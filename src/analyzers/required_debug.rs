@@ -0,0 +1,394 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Required debug analyzer for error and configuration types without
+//! `Debug`.
+//!
+//! Errors end up in logs, `unwrap` panics and `{:?}` reports; configuration
+//! and options are printed at startup and in bug reports. A type of this kind
+//! without `Debug` forces every caller to work around it. This analyzer
+//! flags structs and enums whose name ends in `Error`, `Config` or `Options`
+//! (configurable) that neither derive nor implement `Debug`, in any file and
+//! at any visibility, and adds `Debug` to their derives. Public types of
+//! library files are also covered by
+//! [`MissingDebugAnalyzer`](super::MissingDebugAnalyzer); `fix` applies the
+//! shared edit once.
+
+use std::collections::HashSet;
+
+use masterror::AppResult;
+use proc_macro2::{Ident, Span};
+use syn::{
+    Attribute, Field, Fields, File, ItemEnum, ItemMod, ItemStruct, Visibility, visit::Visit
+};
+
+use super::{
+    find_derive, is_test_code,
+    missing_debug::{debug_impls, derive_edit, derive_suggestion},
+    sensitive_debug::has_sensitive_field
+};
+use crate::analyzer::{AnalysisResult, Analyzer, Fix, Issue, Suggestion, TextEdit};
+
+/// Type name suffixes that require `Debug` by default.
+pub const DEBUG_SUFFIXES: &[&str] = &["Error", "Config", "Options"];
+
+/// Analyzer for error and configuration types without `Debug`.
+///
+/// # Examples
+///
+/// Detects this pattern:
+/// ```ignore
+/// #[derive(Clone)]
+/// struct RetryConfig {
+///     attempts: u32
+/// }
+/// ```
+///
+/// Fixes to:
+/// ```ignore
+/// #[derive(Debug, Clone)]
+/// struct RetryConfig {
+///     attempts: u32
+/// }
+/// ```
+pub struct RequiredDebugAnalyzer {
+    suffixes: Vec<String>
+}
+
+/// Type lacking `Debug`.
+struct Candidate {
+    kind:   &'static str,
+    name:   String,
+    suffix: String,
+    line:   usize,
+    column: usize,
+    /// Edit adding `Debug`, `None` for types holding secrets
+    edit:   Option<TextEdit>
+}
+
+impl RequiredDebugAnalyzer {
+    /// Create new required debug analyzer with the default suffixes.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            suffixes: DEBUG_SUFFIXES.iter().map(|s| s.to_string()).collect()
+        }
+    }
+
+    /// Create analyzer requiring `Debug` on types with configured suffixes.
+    ///
+    /// # Arguments
+    ///
+    /// * `suffixes` - Type name suffixes, replacing [`DEBUG_SUFFIXES`], see
+    ///   [`ProjectContext::debug_suffixes`](crate::project::ProjectContext::debug_suffixes)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cargo_quality::{analyzer::Analyzer, analyzers::RequiredDebugAnalyzer};
+    ///
+    /// let analyzer = RequiredDebugAnalyzer::with_suffixes(&["Settings".to_string()]);
+    /// let code = "struct AppSettings; struct AppConfig;";
+    /// let ast = syn::parse_file(code).unwrap();
+    /// assert_eq!(analyzer.analyze(&ast, code).unwrap().issues.len(), 1);
+    /// ```
+    pub fn with_suffixes(suffixes: &[String]) -> Self {
+        Self {
+            suffixes: suffixes.to_vec()
+        }
+    }
+
+    /// Collect matching types that neither derive nor implement `Debug`.
+    fn collect(&self, ast: &File) -> Vec<Candidate> {
+        let mut visitor = TypeVisitor {
+            suffixes:    &self.suffixes,
+            implemented: debug_impls(ast),
+            candidates:  Vec::new()
+        };
+        visitor.visit_file(ast);
+        visitor.candidates
+    }
+}
+
+impl Analyzer for RequiredDebugAnalyzer {
+    fn name(&self) -> &'static str {
+        "required_debug"
+    }
+
+    fn analyze(&self, ast: &File, _content: &str) -> AppResult<AnalysisResult> {
+        let issues: Vec<Issue> = self
+            .collect(ast)
+            .into_iter()
+            .map(|candidate| {
+                let (hint, fix) = match candidate.edit {
+                    Some(_) => (
+                        format!(
+                            "`*{}` types end up in logs and panic messages; derive `Debug` so \
+                             they can be printed",
+                            candidate.suffix
+                        ),
+                        Fix::Simple("#[derive(Debug)]".to_string())
+                    ),
+                    None => (
+                        "Implement `Debug` manually and print credential fields as \
+                         \"[REDACTED]\""
+                            .to_string(),
+                        Fix::None
+                    )
+                };
+                Issue {
+                    line: candidate.line,
+                    column: candidate.column,
+                    message: format!(
+                        "{} `{}` does not implement `Debug`\n{hint}",
+                        candidate.kind, candidate.name
+                    ),
+                    fix
+                }
+            })
+            .collect();
+
+        Ok(AnalysisResult {
+            fixable_count: issues.iter().filter(|i| i.fix.is_available()).count(),
+            issues
+        })
+    }
+
+    fn suggestions(&self, ast: &File, content: &str) -> AppResult<Vec<Suggestion>> {
+        Ok(self
+            .collect(ast)
+            .into_iter()
+            .filter_map(|candidate| candidate.edit)
+            .map(|edit| derive_suggestion(edit, content))
+            .collect())
+    }
+
+    fn fixable(&self) -> bool {
+        true
+    }
+
+    fn options(&self) -> &'static [&'static str] {
+        &["debug_suffixes"]
+    }
+}
+
+impl Default for RequiredDebugAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct TypeVisitor<'a> {
+    suffixes:    &'a [String],
+    /// Types with a manual `Debug` implementation
+    implemented: HashSet<String>,
+    candidates:  Vec<Candidate>
+}
+
+impl TypeVisitor<'_> {
+    /// Record a type with a required suffix that lacks `Debug`.
+    fn check<'f>(
+        &mut self,
+        kind: &'static str,
+        ident: &Ident,
+        attrs: &[Attribute],
+        start: Span,
+        fields: impl Iterator<Item = &'f Field>
+    ) {
+        let name = ident.to_string();
+        let Some(suffix) = self
+            .suffixes
+            .iter()
+            .find(|suffix| name.ends_with(suffix.as_str()))
+        else {
+            return;
+        };
+        if find_derive(attrs, "Debug").is_some()
+            || self.implemented.contains(&name)
+            || is_test_code(attrs)
+        {
+            return;
+        }
+
+        let edit = (!has_sensitive_field(fields)).then(|| derive_edit(attrs, start));
+        let position = ident.span().start();
+        self.candidates.push(Candidate {
+            kind,
+            suffix: suffix.clone(),
+            name,
+            line: position.line,
+            column: position.column,
+            edit
+        });
+    }
+}
+
+impl<'ast> Visit<'ast> for TypeVisitor<'_> {
+    fn visit_item_struct(&mut self, node: &'ast ItemStruct) {
+        let fields = match &node.fields {
+            Fields::Named(fields) => fields.named.iter().collect(),
+            _ => Vec::new()
+        };
+        let start = item_start(&node.vis, node.struct_token.span);
+        self.check(
+            "Struct",
+            &node.ident,
+            &node.attrs,
+            start,
+            fields.into_iter()
+        );
+    }
+
+    fn visit_item_enum(&mut self, node: &'ast ItemEnum) {
+        let fields = node
+            .variants
+            .iter()
+            .flat_map(|variant| match &variant.fields {
+                Fields::Named(fields) => fields.named.iter().collect(),
+                _ => Vec::new()
+            });
+        let start = item_start(&node.vis, node.enum_token.span);
+        self.check("Enum", &node.ident, &node.attrs, start, fields);
+    }
+
+    fn visit_item_mod(&mut self, node: &'ast ItemMod) {
+        if !is_test_code(&node.attrs) {
+            syn::visit::visit_item_mod(self, node);
+        }
+    }
+}
+
+/// Span of the first token after the attributes of a type.
+fn item_start(vis: &Visibility, keyword: Span) -> Span {
+    match vis {
+        Visibility::Public(token) => token.span,
+        Visibility::Restricted(restricted) => restricted.pub_token.span,
+        Visibility::Inherited => keyword
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analyze(code: &str) -> AnalysisResult {
+        let ast = syn::parse_file(code).unwrap();
+        RequiredDebugAnalyzer::new().analyze(&ast, code).unwrap()
+    }
+
+    fn fix(code: &str) -> String {
+        let ast = syn::parse_file(code).unwrap();
+        let suggestions = RequiredDebugAnalyzer::new()
+            .suggestions(&ast, code)
+            .unwrap();
+        crate::fixer::apply_suggestions(code, &suggestions).source
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        let analyzer = RequiredDebugAnalyzer::new();
+        assert_eq!(analyzer.name(), "required_debug");
+        assert_eq!(analyzer.options(), &["debug_suffixes"]);
+    }
+
+    #[test]
+    fn test_detect_error_and_config_types() {
+        let result = analyze(
+            r#"
+enum ParseError { Eof }
+
+#[derive(Clone)]
+pub(crate) struct RetryConfig { attempts: u32 }
+
+struct DbOptions { url: String, password: String }
+
+struct Parser;
+"#
+        );
+
+        assert_eq!(result.issues.len(), 3);
+        assert_eq!(result.fixable_count, 2);
+        assert_eq!(result.issues[0].line, 2);
+        assert!(
+            result.issues[0]
+                .message
+                .contains("Enum `ParseError` does not implement `Debug`")
+        );
+        assert!(result.issues[0].message.contains("`*Error` types"));
+        assert!(
+            result.issues[1]
+                .message
+                .contains("Struct `RetryConfig` does not implement `Debug`")
+        );
+        assert!(result.issues[2].message.contains("[REDACTED]"));
+        assert_eq!(result.issues[2].fix, Fix::None);
+    }
+
+    #[test]
+    fn test_ignore_types_with_debug() {
+        let result = analyze(
+            r#"
+#[derive(Debug, thiserror::Error)]
+pub enum AppError {}
+
+struct ManualConfig;
+impl std::fmt::Debug for ManualConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.write_str("ManualConfig") }
+}
+
+#[cfg(test)]
+mod tests {
+    struct FixtureConfig;
+}
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_fix_adds_debug_derive() {
+        let code = r#"mod config {
+    /// Retry settings.
+    pub(crate) struct RetryConfig;
+
+    #[derive(Clone)]
+    enum LoadError {
+        Missing
+    }
+}
+"#;
+
+        assert_eq!(
+            fix(code),
+            r#"mod config {
+    /// Retry settings.
+    #[derive(Debug)]
+    pub(crate) struct RetryConfig;
+
+    #[derive(Debug, Clone)]
+    enum LoadError {
+        Missing
+    }
+}
+"#
+        );
+    }
+
+    #[test]
+    fn test_configured_suffixes() {
+        let analyzer = RequiredDebugAnalyzer::with_suffixes(&["Settings".to_string()]);
+        let code = "struct AppSettings; struct AppConfig;";
+        let ast = syn::parse_file(code).unwrap();
+        let result = analyzer.analyze(&ast, code).unwrap();
+
+        assert_eq!(result.issues.len(), 1);
+        assert!(result.issues[0].message.contains("`AppSettings`"));
+    }
+
+    #[test]
+    fn test_default_implementation() {
+        let analyzer = RequiredDebugAnalyzer::default();
+        assert_eq!(analyzer.name(), "required_debug");
+    }
+}
@@ -0,0 +1,363 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Sensitive debug analyzer for derived `Debug` on types holding secrets.
+//!
+//! `#[derive(Debug)]` prints every field, so a `password` or `api_token`
+//! ends up in logs the first time the value is traced with `{:?}`. This
+//! analyzer flags structs and enums deriving `Debug` with a field whose name
+//! looks like a credential. Field name patterns can be extended with
+//! `metadata.quality.sensitive_fields`; under `profile = "strict"` findings
+//! are reported with [`Severity::Error`].
+
+use masterror::AppResult;
+use proc_macro2::Ident;
+use syn::{Attribute, Field, Fields, File, ItemEnum, ItemMod, ItemStruct, Type, visit::Visit};
+
+use super::{find_derive, hardcoded_secrets::SECRET_NAMES, is_test_code};
+use crate::analyzer::{AnalysisResult, Analyzer, Fix, Issue, Severity};
+
+/// Field name fragments treated as sensitive in addition to
+/// the credential names of the hardcoded secrets analyzer.
+const EXTRA_NAMES: &[&str] = &["credential"];
+
+/// Type name fragments of wrappers that already redact their `Debug` output.
+const REDACTING_TYPES: &[&str] = &["Secret", "Redact", "Sensitive"];
+
+/// Analyzer for derived `Debug` on types with credential-like fields.
+///
+/// # Examples
+///
+/// Detects this pattern:
+/// ```ignore
+/// #[derive(Debug)]
+/// pub struct Login {
+///     pub user:     String,
+///     pub password: String
+/// }
+/// ```
+///
+/// Suggests a manual implementation:
+/// ```ignore
+/// impl fmt::Debug for Login {
+///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         f.debug_struct("Login")
+///             .field("user", &self.user)
+///             .field("password", &"[REDACTED]")
+///             .finish()
+///     }
+/// }
+/// ```
+pub struct SensitiveDebugAnalyzer {
+    patterns: Vec<String>,
+    strict:   bool
+}
+
+impl SensitiveDebugAnalyzer {
+    /// Create new sensitive debug analyzer with the built-in patterns.
+    #[inline]
+    pub fn new() -> Self {
+        Self::with_config(Vec::new(), false)
+    }
+
+    /// Create analyzer with extra field name patterns and a profile.
+    ///
+    /// # Arguments
+    ///
+    /// * `fields` - Extra lowercase name fragments, see
+    ///   [`ProjectContext::sensitive_fields`](crate::project::ProjectContext::sensitive_fields)
+    /// * `strict` - Report findings as errors instead of warnings
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cargo_quality::{
+    ///     analyzer::{Analyzer, Severity},
+    ///     analyzers::SensitiveDebugAnalyzer
+    /// };
+    ///
+    /// let analyzer = SensitiveDebugAnalyzer::with_config(vec!["pin".to_string()], true);
+    /// assert_eq!(analyzer.severity(), Severity::Error);
+    /// ```
+    pub fn with_config(fields: Vec<String>, strict: bool) -> Self {
+        let patterns = SECRET_NAMES
+            .iter()
+            .chain(EXTRA_NAMES)
+            .map(|name| name.to_string())
+            .chain(fields)
+            .collect();
+
+        Self {
+            patterns,
+            strict
+        }
+    }
+}
+
+impl Analyzer for SensitiveDebugAnalyzer {
+    fn name(&self) -> &'static str {
+        "sensitive_debug"
+    }
+
+    fn analyze(&self, ast: &File, _content: &str) -> AppResult<AnalysisResult> {
+        let mut visitor = DebugVisitor {
+            patterns: &self.patterns,
+            issues:   Vec::new()
+        };
+        visitor.visit_file(ast);
+
+        Ok(AnalysisResult {
+            issues:        visitor.issues,
+            fixable_count: 0
+        })
+    }
+
+    fn severity(&self) -> Severity {
+        if self.strict {
+            Severity::Error
+        } else {
+            Severity::Warning
+        }
+    }
+
+    fn options(&self) -> &'static [&'static str] {
+        &["sensitive_fields", "profile"]
+    }
+}
+
+impl Default for SensitiveDebugAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct DebugVisitor<'a> {
+    patterns: &'a [String],
+    issues:   Vec<Issue>
+}
+
+impl DebugVisitor<'_> {
+    /// Report a type that derives `Debug` and has sensitive fields.
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - Item kind shown in the message, e.g. `Struct`
+    /// * `ident` - Name of the type
+    /// * `attrs` - Outer attributes of the type
+    /// * `fields` - All named fields of the type, including enum variants
+    fn check<'f>(
+        &mut self,
+        kind: &str,
+        ident: &Ident,
+        attrs: &[Attribute],
+        fields: impl Iterator<Item = &'f Field>
+    ) {
+        let Some(derive) = find_derive(attrs, "Debug") else {
+            return;
+        };
+
+        let sensitive: Vec<String> = fields
+            .filter(|field| !is_redacting(&field.ty))
+            .filter_map(|field| field.ident.as_ref().map(ToString::to_string))
+            .filter(|name| self.is_sensitive(name))
+            .map(|name| format!("`{name}`"))
+            .collect();
+        if sensitive.is_empty() {
+            return;
+        }
+
+        let start = derive
+            .segments
+            .last()
+            .map_or(ident.span(), |s| s.ident.span())
+            .start();
+        self.issues.push(Issue {
+            line:    start.line,
+            column:  start.column,
+            message: format!(
+                "{kind} `{ident}` derives `Debug` but field {} may hold a secret\n\
+                 Implement `Debug` manually and print the value as \"[REDACTED]\", \
+                 or wrap it in a redacting type such as `secrecy::SecretString`",
+                sensitive.join(", ")
+            ),
+            fix:     Fix::None
+        });
+    }
+
+    /// Check whether a field name contains a sensitive pattern.
+    fn is_sensitive(&self, name: &str) -> bool {
+        let lower = name.to_lowercase();
+        self.patterns
+            .iter()
+            .any(|pattern| lower.contains(pattern.as_str()))
+    }
+}
+
+impl<'ast> Visit<'ast> for DebugVisitor<'_> {
+    fn visit_item_struct(&mut self, node: &'ast ItemStruct) {
+        if let Fields::Named(fields) = &node.fields {
+            self.check("Struct", &node.ident, &node.attrs, fields.named.iter());
+        }
+    }
+
+    fn visit_item_enum(&mut self, node: &'ast ItemEnum) {
+        let fields = node
+            .variants
+            .iter()
+            .flat_map(|variant| match &variant.fields {
+                Fields::Named(fields) => fields.named.iter().collect(),
+                _ => Vec::new()
+            });
+        self.check("Enum", &node.ident, &node.attrs, fields);
+    }
+
+    fn visit_item_mod(&mut self, node: &'ast ItemMod) {
+        if !is_test_code(&node.attrs) {
+            syn::visit::visit_item_mod(self, node);
+        }
+    }
+}
+
+/// Check whether any named field looks like it holds a credential.
+///
+/// Uses the built-in patterns only, without project configuration.
+///
+/// # Arguments
+///
+/// * `fields` - Fields of a struct or enum
+///
+/// # Returns
+///
+/// `true` if a field not wrapped in a redacting type has a sensitive name
+pub(crate) fn has_sensitive_field<'f>(mut fields: impl Iterator<Item = &'f Field>) -> bool {
+    fields.any(|field| {
+        let Some(name) = &field.ident else {
+            return false;
+        };
+        let lower = name.to_string().to_lowercase();
+        !is_redacting(&field.ty)
+            && SECRET_NAMES
+                .iter()
+                .chain(EXTRA_NAMES)
+                .any(|pattern| lower.contains(pattern))
+    })
+}
+
+/// Check whether a field type is a wrapper that redacts its `Debug` output.
+fn is_redacting(ty: &Type) -> bool {
+    let Type::Path(path) = ty else {
+        return false;
+    };
+    path.path.segments.last().is_some_and(|segment| {
+        let name = segment.ident.to_string();
+        REDACTING_TYPES
+            .iter()
+            .any(|fragment| name.contains(fragment))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analyze(code: &str) -> AnalysisResult {
+        let ast = syn::parse_file(code).unwrap();
+        SensitiveDebugAnalyzer::new().analyze(&ast, code).unwrap()
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        let analyzer = SensitiveDebugAnalyzer::new();
+        assert_eq!(analyzer.name(), "sensitive_debug");
+    }
+
+    #[test]
+    fn test_detect_derived_debug_with_secret_fields() {
+        let result = analyze(
+            r#"
+#[derive(Clone, Debug)]
+pub struct Login {
+    user: String,
+    password: String,
+    api_token: String
+}
+
+#[derive(std::fmt::Debug)]
+enum Auth {
+    Anonymous,
+    Basic { user: String, credentials: String }
+}
+"#
+        );
+
+        assert_eq!(result.issues.len(), 2);
+        assert_eq!(result.issues[0].line, 2);
+        assert_eq!(result.issues[0].column, 16);
+        assert!(
+            result.issues[0]
+                .message
+                .contains("Struct `Login` derives `Debug` but field `password`, `api_token`")
+        );
+        assert!(result.issues[1].message.contains("Enum `Auth`"));
+    }
+
+    #[test]
+    fn test_ignore_manual_debug_and_redacting_types() {
+        let result = analyze(
+            r#"
+#[derive(Clone)]
+struct Login {
+    password: String
+}
+
+#[derive(Debug)]
+struct Config {
+    api_key: SecretString,
+    token: Redacted<String>,
+    name: String
+}
+
+#[cfg(test)]
+mod tests {
+    #[derive(Debug)]
+    struct Fixture { password: String }
+}
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_configured_patterns() {
+        let code = r#"
+#[derive(Debug)]
+struct Card {
+    number: String,
+    pin: u16
+}
+"#;
+        let ast = syn::parse_file(code).unwrap();
+        assert!(analyze(code).issues.is_empty());
+
+        let analyzer = SensitiveDebugAnalyzer::with_config(vec!["pin".to_string()], false);
+        let result = analyzer.analyze(&ast, code).unwrap();
+        assert_eq!(result.issues.len(), 1);
+        assert!(result.issues[0].message.contains("field `pin`"));
+    }
+
+    #[test]
+    fn test_strict_profile_is_error() {
+        assert_eq!(SensitiveDebugAnalyzer::new().severity(), Severity::Warning);
+        assert_eq!(
+            SensitiveDebugAnalyzer::with_config(Vec::new(), true).severity(),
+            Severity::Error
+        );
+    }
+
+    #[test]
+    fn test_default_implementation() {
+        let analyzer = SensitiveDebugAnalyzer::default();
+        assert_eq!(analyzer.name(), "sensitive_debug");
+    }
+}
@@ -0,0 +1,363 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Shadowing analyzer for chains of `let` rebindings inside function bodies.
+//!
+//! Rebinding a name is idiomatic for a single transformation such as
+//! `let name = name.trim();`, but a name bound again and again for unrelated
+//! values makes the reader track which binding is live at every line. This
+//! analyzer follows `let` bindings through the block scopes of each function
+//! and reports every name bound more than once, with the line of each
+//! binding. A single rebinding whose initializer uses the previous value is
+//! accepted as the conversion idiom.
+
+use std::collections::HashMap;
+
+use masterror::AppResult;
+use proc_macro2::Ident;
+use syn::{
+    Block, ExprPath, File, ImplItemFn, Item, ItemFn, ItemMod, Local, PatIdent, visit::Visit
+};
+
+use super::is_test_code;
+use crate::analyzer::{AnalysisResult, Analyzer, Fix, Issue};
+
+/// Analyzer for names rebound several times within a function.
+///
+/// # Examples
+///
+/// Detects this pattern:
+/// ```ignore
+/// fn load(path: &Path) -> Config {
+///     let data = fs::read_to_string(path)?;
+///     let data = parse(&data)?;
+///     let data = validate(data)?;
+///     data
+/// }
+/// ```
+///
+/// Suggests naming each step:
+/// ```ignore
+/// fn load(path: &Path) -> Config {
+///     let text = fs::read_to_string(path)?;
+///     let raw = parse(&text)?;
+///     validate(raw)?
+/// }
+/// ```
+pub struct ShadowingAnalyzer;
+
+/// Bindings of one name, from the first `let` to the last rebinding.
+struct Chain {
+    name:     String,
+    /// (line, column) of each binding
+    bindings: Vec<(usize, usize)>,
+    /// Whether the initializer of a rebinding used the previous binding
+    reuses:   Vec<bool>
+}
+
+impl ShadowingAnalyzer {
+    /// Create new shadowing analyzer instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Analyzer for ShadowingAnalyzer {
+    fn name(&self) -> &'static str {
+        "shadowing"
+    }
+
+    fn analyze(&self, ast: &File, _content: &str) -> AppResult<AnalysisResult> {
+        let mut visitor = FunctionVisitor {
+            issues: Vec::new()
+        };
+        visitor.visit_file(ast);
+        visitor
+            .issues
+            .sort_by_key(|issue| (issue.line, issue.column));
+
+        Ok(AnalysisResult {
+            issues:        visitor.issues,
+            fixable_count: 0
+        })
+    }
+}
+
+impl Default for ShadowingAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Finds function bodies, nested functions included.
+struct FunctionVisitor {
+    issues: Vec<Issue>
+}
+
+impl FunctionVisitor {
+    /// Report the shadowing chains of a function body.
+    fn check(&mut self, function: &Ident, body: &Block) {
+        let mut scopes = ScopeVisitor {
+            scopes: Vec::new(),
+            chains: Vec::new()
+        };
+        scopes.visit_block(body);
+
+        for chain in scopes.chains {
+            let rebindings = chain.bindings.len() - 1;
+            if rebindings == 0 || (rebindings == 1 && chain.reuses[0]) {
+                continue;
+            }
+
+            let lines: Vec<String> = chain
+                .bindings
+                .iter()
+                .map(|(line, _)| line.to_string())
+                .collect();
+            let (line, column) = chain.bindings[1];
+            self.issues.push(Issue {
+                line,
+                column,
+                message: format!(
+                    "`{}` is bound {} times in `{function}`, on lines {}\n\
+                     Give each value its own name, e.g. `raw_{0}` and `parsed_{0}`, or keep a \
+                     single `let mut {0}`",
+                    chain.name,
+                    chain.bindings.len(),
+                    lines.join(", ")
+                ),
+                fix: Fix::None
+            });
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for FunctionVisitor {
+    fn visit_item_fn(&mut self, node: &'ast ItemFn) {
+        if is_test_code(&node.attrs) {
+            return;
+        }
+        self.check(&node.sig.ident, &node.block);
+        syn::visit::visit_item_fn(self, node);
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'ast ImplItemFn) {
+        if is_test_code(&node.attrs) {
+            return;
+        }
+        self.check(&node.sig.ident, &node.block);
+        syn::visit::visit_impl_item_fn(self, node);
+    }
+
+    fn visit_item_mod(&mut self, node: &'ast ItemMod) {
+        if !is_test_code(&node.attrs) {
+            syn::visit::visit_item_mod(self, node);
+        }
+    }
+}
+
+/// Tracks `let` bindings through the block scopes of one function.
+struct ScopeVisitor {
+    /// Names bound in each enclosing block, mapped to their chain
+    scopes: Vec<HashMap<String, usize>>,
+    chains: Vec<Chain>
+}
+
+impl ScopeVisitor {
+    /// Chain of the innermost visible binding of a name.
+    fn lookup(&self, name: &str) -> Option<usize> {
+        self.scopes
+            .iter()
+            .rev()
+            .find_map(|scope| scope.get(name).copied())
+    }
+}
+
+impl<'ast> Visit<'ast> for ScopeVisitor {
+    fn visit_block(&mut self, node: &'ast Block) {
+        self.scopes.push(HashMap::new());
+        syn::visit::visit_block(self, node);
+        self.scopes.pop();
+    }
+
+    fn visit_local(&mut self, node: &'ast Local) {
+        if let Some(init) = &node.init {
+            self.visit_expr(&init.expr);
+            if let Some((_, diverge)) = &init.diverge {
+                self.visit_expr(diverge);
+            }
+        }
+
+        let mut names = BindingNames::default();
+        names.visit_pat(&node.pat);
+        for ident in names.idents {
+            let name = ident.to_string();
+            let start = ident.span().start();
+            let reuses = node.init.as_ref().is_some_and(|init| {
+                let mut uses = UsesName {
+                    name:  &name,
+                    found: false
+                };
+                uses.visit_expr(&init.expr);
+                uses.found
+            });
+
+            let index = match self.lookup(&name) {
+                Some(index) => {
+                    let chain = &mut self.chains[index];
+                    chain.bindings.push((start.line, start.column));
+                    chain.reuses.push(reuses);
+                    index
+                }
+                None => {
+                    self.chains.push(Chain {
+                        name:     name.clone(),
+                        bindings: vec![(start.line, start.column)],
+                        reuses:   Vec::new()
+                    });
+                    self.chains.len() - 1
+                }
+            };
+            if let Some(scope) = self.scopes.last_mut() {
+                scope.insert(name, index);
+            }
+        }
+    }
+
+    fn visit_item(&mut self, _node: &'ast Item) {}
+}
+
+/// Collects the names a pattern binds, except `_`-prefixed ones.
+#[derive(Default)]
+pub(crate) struct BindingNames {
+    pub(crate) idents: Vec<Ident>
+}
+
+impl<'ast> Visit<'ast> for BindingNames {
+    fn visit_pat_ident(&mut self, node: &'ast PatIdent) {
+        if !node.ident.to_string().starts_with('_') {
+            self.idents.push(node.ident.clone());
+        }
+        syn::visit::visit_pat_ident(self, node);
+    }
+}
+
+/// Checks whether an expression refers to a name.
+struct UsesName<'a> {
+    name:  &'a str,
+    found: bool
+}
+
+impl<'ast> Visit<'ast> for UsesName<'_> {
+    fn visit_expr_path(&mut self, node: &'ast ExprPath) {
+        if node.qself.is_none() && node.path.is_ident(self.name) {
+            self.found = true;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analyze(code: &str) -> AnalysisResult {
+        let ast = syn::parse_file(code).unwrap();
+        ShadowingAnalyzer::new().analyze(&ast, code).unwrap()
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        let analyzer = ShadowingAnalyzer::new();
+        assert_eq!(analyzer.name(), "shadowing");
+    }
+
+    #[test]
+    fn test_detect_shadowing_chains() {
+        let result = analyze(
+            r#"
+fn load(path: &str) -> u32 {
+    let data = read(path);
+    let count = 1;
+    let data = parse(&data);
+    if ready() {
+        let data = validate(data);
+    }
+    let count = total();
+    count
+}
+"#
+        );
+
+        assert_eq!(result.issues.len(), 2);
+        assert_eq!(result.issues[0].line, 5);
+        assert!(
+            result.issues[0]
+                .message
+                .contains("`data` is bound 3 times in `load`, on lines 3, 5, 7")
+        );
+        assert_eq!(result.issues[1].line, 9);
+        assert!(
+            result.issues[1]
+                .message
+                .contains("`count` is bound 2 times in `load`, on lines 4, 9")
+        );
+    }
+
+    #[test]
+    fn test_accept_single_conversion_and_sibling_scopes() {
+        let result = analyze(
+            r#"
+impl Parser {
+    fn parse(&self, input: &str) {
+        let input = input.trim();
+        let (key, _rest) = split(input);
+        if key.is_empty() {
+            let value = 1;
+        } else {
+            let value = 2;
+        }
+        fn nested() {
+            let key = 3;
+        }
+        let _guard = lock();
+        let _guard = lock();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    fn fixture() {
+        let a = 1;
+        let a = 2;
+    }
+}
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_detect_repeated_conversions() {
+        let result = analyze(
+            r#"
+fn normalize(name: String) -> String {
+    let name = name.trim().to_string();
+    let name = name.to_lowercase();
+    let name = name.replace(' ', "_");
+    name
+}
+"#
+        );
+
+        assert_eq!(result.issues.len(), 1);
+        assert!(result.issues[0].message.contains("`name` is bound 3 times"));
+    }
+
+    #[test]
+    fn test_default_implementation() {
+        let analyzer = ShadowingAnalyzer;
+        assert_eq!(analyzer.name(), "shadowing");
+    }
+}
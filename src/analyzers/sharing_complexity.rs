@@ -0,0 +1,422 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Sharing complexity analyzer for `Arc`/`Rc` overuse.
+//!
+//! This analyzer flags deep stacks of shared-ownership and interior-mutability
+//! wrappers such as `Arc<Mutex<Vec<T>>>` in struct fields and function
+//! signatures, and `Rc` used in a file that also spawns threads. Both usually
+//! mean the sharing model deserves its own type.
+
+use masterror::AppResult;
+use proc_macro2::Span;
+use syn::{
+    ExprCall, ExprMethodCall, Field, File, GenericArgument, PathArguments, ReturnType, Signature,
+    Type, TypePath, spanned::Spanned, visit::Visit
+};
+
+use crate::analyzer::{AnalysisResult, Analyzer, Fix, Issue};
+
+/// Default number of nested wrapper layers allowed under an `Arc`/`Rc`.
+pub const DEFAULT_MAX_DEPTH: usize = 2;
+
+/// Wrapper types that add a layer of indirection, sharing or locking.
+const WRAPPERS: &[&str] = &[
+    "Arc", "Rc", "Weak", "Mutex", "RwLock", "RefCell", "Cell", "OnceCell", "Box", "Vec",
+    "VecDeque", "HashMap", "BTreeMap", "HashSet", "BTreeSet", "Option"
+];
+
+/// Analyzer for `Arc<Mutex<Vec<...>>>`-style stacks and `Rc` in threaded code.
+///
+/// # Examples
+///
+/// Detects this pattern:
+/// ```ignore
+/// struct Cache {
+///     entries: Arc<Mutex<Vec<Entry>>>
+/// }
+/// ```
+///
+/// Suggests a dedicated type that owns the lock:
+/// ```ignore
+/// struct Entries {
+///     inner: Mutex<Vec<Entry>>
+/// }
+///
+/// struct Cache {
+///     entries: Arc<Entries>
+/// }
+/// ```
+pub struct SharingComplexityAnalyzer {
+    max_depth: usize
+}
+
+impl SharingComplexityAnalyzer {
+    /// Create new sharing complexity analyzer with the default depth.
+    #[inline]
+    pub fn new() -> Self {
+        Self::with_max_depth(DEFAULT_MAX_DEPTH)
+    }
+
+    /// Create analyzer with a custom maximum wrapper depth.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_depth` - Number of nested wrapper layers allowed, counting the
+    ///   outer `Arc`/`Rc`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cargo_quality::{analyzer::Analyzer, analyzers::SharingComplexityAnalyzer};
+    ///
+    /// let analyzer = SharingComplexityAnalyzer::with_max_depth(3);
+    /// let code = "struct S { v: Arc<Mutex<Vec<u8>>> }";
+    /// let ast = syn::parse_file(code).unwrap();
+    /// assert!(analyzer.analyze(&ast, code).unwrap().issues.is_empty());
+    /// ```
+    #[inline]
+    pub fn with_max_depth(max_depth: usize) -> Self {
+        Self {
+            max_depth
+        }
+    }
+
+    /// Build an issue for a wrapper stack deeper than allowed.
+    ///
+    /// # Arguments
+    ///
+    /// * `type_path` - Outer `Arc`/`Rc` type
+    /// * `depth` - Number of nested wrapper layers
+    /// * `max_depth` - Allowed number of layers
+    fn stack_issue(type_path: &TypePath, depth: usize, max_depth: usize) -> Issue {
+        let start = type_path.span().start();
+        let rendered = quote::quote!(#type_path)
+            .to_string()
+            .replace(' ', "")
+            .replace(',', ", ");
+
+        Issue {
+            line:    start.line,
+            column:  start.column,
+            message: format!(
+                "Sharing complexity: `{rendered}` nests {depth} wrapper layers (max {max_depth})\n\
+                 Wrap the inner state in a dedicated type that owns the lock, e.g. `Arc<State>`"
+            ),
+            fix:     Fix::None
+        }
+    }
+}
+
+impl Analyzer for SharingComplexityAnalyzer {
+    fn name(&self) -> &'static str {
+        "sharing_complexity"
+    }
+
+    fn analyze(&self, ast: &File, _content: &str) -> AppResult<AnalysisResult> {
+        let mut visitor = SharingVisitor {
+            max_depth:  self.max_depth,
+            issues:     Vec::new(),
+            first_rc:   None,
+            spawns:     false,
+            in_context: false
+        };
+        visitor.visit_file(ast);
+
+        let mut issues = visitor.issues;
+        if visitor.spawns
+            && let Some(span) = visitor.first_rc
+        {
+            let start = span.start();
+            issues.push(Issue {
+                line:    start.line,
+                column:  start.column,
+                message: "Sharing complexity: `Rc` used in code that spawns threads\n\
+                          `Rc` is not `Send`, use `Arc` for state shared across threads"
+                    .to_string(),
+                fix:     Fix::None
+            });
+        }
+
+        Ok(AnalysisResult {
+            issues,
+            fixable_count: 0
+        })
+    }
+}
+
+impl Default for SharingComplexityAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct SharingVisitor {
+    max_depth:  usize,
+    issues:     Vec<Issue>,
+    first_rc:   Option<Span>,
+    spawns:     bool,
+    in_context: bool
+}
+
+impl SharingVisitor {
+    /// Check a type from a field or signature for deep wrapper stacks.
+    fn check_type(&mut self, ty: &Type) {
+        self.in_context = true;
+        self.visit_type(ty);
+        self.in_context = false;
+    }
+}
+
+impl<'ast> Visit<'ast> for SharingVisitor {
+    fn visit_field(&mut self, node: &'ast Field) {
+        self.check_type(&node.ty);
+    }
+
+    fn visit_signature(&mut self, node: &'ast Signature) {
+        for input in &node.inputs {
+            if let syn::FnArg::Typed(pat_type) = input {
+                self.check_type(&pat_type.ty);
+            }
+        }
+        if let ReturnType::Type(_, ty) = &node.output {
+            self.check_type(ty);
+        }
+    }
+
+    fn visit_type_path(&mut self, node: &'ast TypePath) {
+        let root = node.path.segments.last().map(|seg| seg.ident.to_string());
+        let is_shared = matches!(root.as_deref(), Some("Arc" | "Rc"));
+
+        if root.as_deref() == Some("Rc") && self.first_rc.is_none() {
+            self.first_rc = Some(node.span());
+        }
+
+        if self.in_context && is_shared {
+            let depth = wrapper_depth(node);
+            if depth > self.max_depth {
+                self.issues.push(SharingComplexityAnalyzer::stack_issue(
+                    node,
+                    depth,
+                    self.max_depth
+                ));
+                return;
+            }
+        }
+
+        syn::visit::visit_type_path(self, node);
+    }
+
+    fn visit_expr_call(&mut self, node: &'ast ExprCall) {
+        if let syn::Expr::Path(path) = node.func.as_ref() {
+            let segments = &path.path.segments;
+            let last = segments.last().map(|seg| seg.ident.to_string());
+            if last.as_deref() == Some("spawn") {
+                self.spawns = true;
+            }
+            if segments.len() >= 2
+                && segments[segments.len() - 2].ident == "Rc"
+                && self.first_rc.is_none()
+            {
+                self.first_rc = Some(path.span());
+            }
+        }
+        syn::visit::visit_expr_call(self, node);
+    }
+
+    fn visit_expr_method_call(&mut self, node: &'ast ExprMethodCall) {
+        if node.method == "spawn" {
+            self.spawns = true;
+        }
+        syn::visit::visit_expr_method_call(self, node);
+    }
+}
+
+/// Count nested wrapper layers starting at a type path.
+///
+/// # Arguments
+///
+/// * `type_path` - Type to measure
+///
+/// # Returns
+///
+/// Length of the deepest chain of [`WRAPPERS`] types, `0` if the outer type is
+/// not a wrapper
+fn wrapper_depth(type_path: &TypePath) -> usize {
+    let Some(segment) = type_path.path.segments.last() else {
+        return 0;
+    };
+    if !WRAPPERS.contains(&segment.ident.to_string().as_str()) {
+        return 0;
+    }
+
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return 1;
+    };
+
+    let inner = args
+        .args
+        .iter()
+        .filter_map(|arg| match arg {
+            GenericArgument::Type(Type::Path(inner)) => Some(wrapper_depth(inner)),
+            _ => None
+        })
+        .max()
+        .unwrap_or(0);
+
+    1 + inner
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::parse_quote;
+
+    use super::*;
+
+    fn analyze(code: File) -> AnalysisResult {
+        SharingComplexityAnalyzer::new().analyze(&code, "").unwrap()
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        let analyzer = SharingComplexityAnalyzer::new();
+        assert_eq!(analyzer.name(), "sharing_complexity");
+    }
+
+    #[test]
+    fn test_detect_deep_stack_in_field() {
+        let result = analyze(parse_quote! {
+            struct Cache {
+                entries: Arc<Mutex<Vec<Entry>>>
+            }
+        });
+
+        assert_eq!(result.issues.len(), 1);
+        assert!(result.issues[0].message.contains("Arc<Mutex<Vec<Entry>>>"));
+        assert!(result.issues[0].message.contains("nests 3 wrapper layers"));
+    }
+
+    #[test]
+    fn test_allow_shallow_stack() {
+        let result = analyze(parse_quote! {
+            struct Cache {
+                entries: Arc<Mutex<State>>
+            }
+        });
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_detect_deep_stack_in_signature() {
+        let result = analyze(parse_quote! {
+            fn share(items: Rc<RefCell<HashMap<String, u32>>>) -> Arc<RwLock<Option<Box<Node>>>> {
+                todo!()
+            }
+        });
+
+        assert_eq!(result.issues.len(), 2);
+    }
+
+    #[test]
+    fn test_detect_nested_inside_other_type() {
+        let result = analyze(parse_quote! {
+            struct Pool {
+                workers: Vec<Arc<Mutex<Vec<Job>>>>
+            }
+        });
+
+        assert_eq!(result.issues.len(), 1);
+    }
+
+    #[test]
+    fn test_custom_max_depth() {
+        let analyzer = SharingComplexityAnalyzer::with_max_depth(1);
+        let code: File = parse_quote! {
+            struct Cache {
+                entries: Arc<Mutex<State>>
+            }
+        };
+
+        let result = analyzer.analyze(&code, "").unwrap();
+        assert_eq!(result.issues.len(), 1);
+    }
+
+    #[test]
+    fn test_ignore_local_types() {
+        let result = analyze(parse_quote! {
+            fn main() {
+                let entries: Arc<Mutex<Vec<u8>>> = Default::default();
+            }
+        });
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_detect_rc_with_thread_spawn() {
+        let result = analyze(parse_quote! {
+            fn main() {
+                let shared = Rc::new(5);
+                std::thread::spawn(move || {});
+            }
+        });
+
+        assert_eq!(result.issues.len(), 1);
+        assert!(result.issues[0].message.contains("use `Arc`"));
+    }
+
+    #[test]
+    fn test_ignore_rc_without_threads() {
+        let result = analyze(parse_quote! {
+            struct Tree {
+                children: Vec<Rc<Node>>
+            }
+        });
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_ignore_arc_with_threads() {
+        let result = analyze(parse_quote! {
+            fn main() {
+                let shared = Arc::new(5);
+                std::thread::spawn(move || {});
+            }
+        });
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_fixable_count_is_zero() {
+        let result = analyze(parse_quote! {
+            struct Cache {
+                entries: Arc<Mutex<Vec<Entry>>>
+            }
+        });
+
+        assert_eq!(result.fixable_count, 0);
+    }
+
+    #[test]
+    fn test_no_edits() {
+        let analyzer = SharingComplexityAnalyzer::new();
+        let code: File = parse_quote! {
+            struct Cache {
+                entries: Arc<Mutex<Vec<Entry>>>
+            }
+        };
+
+        let edits = analyzer.suggestions(&code, "").unwrap();
+        assert!(edits.is_empty());
+    }
+
+    #[test]
+    fn test_default_implementation() {
+        let analyzer = SharingComplexityAnalyzer::default();
+        assert_eq!(analyzer.name(), "sharing_complexity");
+        assert_eq!(analyzer.max_depth, DEFAULT_MAX_DEPTH);
+    }
+}
@@ -0,0 +1,393 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! SQL injection analyzer for queries built by string formatting.
+//!
+//! Queries assembled with `format!` or `+` splice values straight into SQL,
+//! so any user-controlled input can change the statement. This analyzer
+//! flags dynamically built strings passed to functions and methods named
+//! like query entry points (`query`, `query_as`, `execute`, ...), either
+//! directly or through a local binding in the same function.
+//!
+//! Calls are matched by name and the formatted values are not traced, so a
+//! query built from constants is reported too; the confidence is medium.
+
+use std::collections::HashSet;
+
+use masterror::AppResult;
+use proc_macro2::TokenTree;
+use syn::{
+    BinOp, Expr, ExprCall, ExprMethodCall, File, ImplItemFn, ItemFn, ItemMod, Lit, Local, Macro,
+    Pat, spanned::Spanned, visit::Visit
+};
+
+use super::is_test_code;
+use crate::analyzer::{AnalysisResult, Analyzer, Confidence, Fix, Issue};
+
+/// Function and method names that execute SQL.
+const SQL_FUNCTIONS: &[&str] = &[
+    "query",
+    "query_as",
+    "query_scalar",
+    "query_with",
+    "execute",
+    "sql_query"
+];
+
+/// Analyzer for SQL strings built with `format!` or concatenation.
+///
+/// # Examples
+///
+/// Detects this pattern:
+/// ```ignore
+/// let sql = format!("SELECT * FROM users WHERE name = '{name}'");
+/// sqlx::query(&sql).fetch_all(&pool).await?;
+/// ```
+///
+/// Suggests bind parameters:
+/// ```ignore
+/// sqlx::query("SELECT * FROM users WHERE name = $1")
+///     .bind(name)
+///     .fetch_all(&pool)
+///     .await?;
+/// ```
+pub struct SqlInjectionAnalyzer;
+
+impl SqlInjectionAnalyzer {
+    /// Create new SQL injection analyzer instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Analyzer for SqlInjectionAnalyzer {
+    fn name(&self) -> &'static str {
+        "sql_injection"
+    }
+
+    fn analyze(&self, ast: &File, _content: &str) -> AppResult<AnalysisResult> {
+        let mut visitor = SqlVisitor {
+            issues:  Vec::new(),
+            dynamic: HashSet::new()
+        };
+        visitor.visit_file(ast);
+
+        Ok(AnalysisResult {
+            issues:        visitor.issues,
+            fixable_count: 0
+        })
+    }
+
+    fn confidence(&self) -> Confidence {
+        Confidence::Medium
+    }
+}
+
+impl Default for SqlInjectionAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct SqlVisitor {
+    issues:  Vec<Issue>,
+    /// Local bindings in the current function initialized with a built
+    /// string
+    dynamic: HashSet<String>
+}
+
+impl SqlVisitor {
+    /// Check the SQL argument of a call to a query function.
+    fn check_call(&mut self, function: &str, args: &[&Expr]) {
+        if !SQL_FUNCTIONS.contains(&function) {
+            return;
+        }
+
+        let Some(arg) = args.first() else {
+            return;
+        };
+        let Some(source) = self.construction(arg) else {
+            return;
+        };
+
+        let start = arg.span().start();
+        self.issues.push(Issue {
+            line:    start.line,
+            column:  start.column,
+            message: format!(
+                "SQL built with {source} passed to `{function}`, possible SQL injection\n\
+                 Use a parameterized query with bind parameters, e.g. \
+                 `query(\"... WHERE id = $1\").bind(id)`"
+            ),
+            fix:     Fix::None
+        });
+    }
+
+    /// Describe how an argument was built, if it is a dynamic string.
+    ///
+    /// # Returns
+    ///
+    /// The construction as shown in the issue message, naming the local
+    /// binding when the string was built before the call
+    fn construction(&self, expr: &Expr) -> Option<String> {
+        let expr = strip_borrow(expr);
+        if let Some(source) = built_string(expr) {
+            return Some(source.to_string());
+        }
+
+        let Expr::Path(path) = expr else {
+            return None;
+        };
+        let ident = path.path.get_ident()?.to_string();
+        self.dynamic
+            .contains(&ident)
+            .then(|| format!("`format!`/concatenation in `{ident}`"))
+    }
+
+    /// Visit a function body with its own set of dynamic bindings.
+    fn with_scope(&mut self, visit: impl FnOnce(&mut Self)) {
+        let outer = std::mem::take(&mut self.dynamic);
+        visit(self);
+        self.dynamic = outer;
+    }
+}
+
+impl<'ast> Visit<'ast> for SqlVisitor {
+    fn visit_item_fn(&mut self, node: &'ast ItemFn) {
+        if !is_test_code(&node.attrs) {
+            self.with_scope(|visitor| syn::visit::visit_item_fn(visitor, node));
+        }
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'ast ImplItemFn) {
+        if !is_test_code(&node.attrs) {
+            self.with_scope(|visitor| syn::visit::visit_impl_item_fn(visitor, node));
+        }
+    }
+
+    fn visit_item_mod(&mut self, node: &'ast ItemMod) {
+        if !is_test_code(&node.attrs) {
+            syn::visit::visit_item_mod(self, node);
+        }
+    }
+
+    fn visit_local(&mut self, node: &'ast Local) {
+        let name = match &node.pat {
+            Pat::Ident(ident) => Some(ident.ident.to_string()),
+            Pat::Type(typed) => match typed.pat.as_ref() {
+                Pat::Ident(ident) => Some(ident.ident.to_string()),
+                _ => None
+            },
+            _ => None
+        };
+
+        if let Some(name) = name {
+            let built = node
+                .init
+                .as_ref()
+                .is_some_and(|init| built_string(strip_borrow(&init.expr)).is_some());
+            if built {
+                self.dynamic.insert(name);
+            } else {
+                self.dynamic.remove(&name);
+            }
+        }
+        syn::visit::visit_local(self, node);
+    }
+
+    fn visit_expr_call(&mut self, node: &'ast ExprCall) {
+        if let Expr::Path(path) = node.func.as_ref()
+            && let Some(segment) = path.path.segments.last()
+        {
+            let args: Vec<_> = node.args.iter().collect();
+            self.check_call(&segment.ident.to_string(), &args);
+        }
+        syn::visit::visit_expr_call(self, node);
+    }
+
+    fn visit_expr_method_call(&mut self, node: &'ast ExprMethodCall) {
+        let args: Vec<_> = node.args.iter().collect();
+        self.check_call(&node.method.to_string(), &args);
+        syn::visit::visit_expr_method_call(self, node);
+    }
+}
+
+/// Remove borrows, parentheses and `.as_str()` around an expression.
+fn strip_borrow(expr: &Expr) -> &Expr {
+    match expr {
+        Expr::Reference(reference) => strip_borrow(&reference.expr),
+        Expr::Paren(paren) => strip_borrow(&paren.expr),
+        Expr::MethodCall(call) if call.method == "as_str" && call.args.is_empty() => {
+            strip_borrow(&call.receiver)
+        }
+        _ => expr
+    }
+}
+
+/// Describe an expression that builds a string from runtime values.
+///
+/// # Returns
+///
+/// `Some` for `format!` with interpolation and for `+` chains that include a
+/// string literal, `format!` or a borrowed value
+fn built_string(expr: &Expr) -> Option<&'static str> {
+    match expr {
+        Expr::Macro(mac) if is_interpolating_format(&mac.mac) => Some("`format!`"),
+        Expr::Binary(binary) if matches!(binary.op, BinOp::Add(_)) => {
+            concatenates_strings(expr).then_some("string concatenation")
+        }
+        _ => None
+    }
+}
+
+/// Check whether a `+` chain has a string-like operand.
+fn concatenates_strings(expr: &Expr) -> bool {
+    match expr {
+        Expr::Binary(binary) if matches!(binary.op, BinOp::Add(_)) => {
+            concatenates_strings(&binary.left) || concatenates_strings(&binary.right)
+        }
+        Expr::Lit(lit) => matches!(lit.lit, Lit::Str(_)),
+        Expr::Reference(_) => true,
+        Expr::Macro(mac) => mac.mac.path.is_ident("format"),
+        Expr::Paren(paren) => concatenates_strings(&paren.expr),
+        _ => false
+    }
+}
+
+/// Check whether a macro is `format!` with arguments or inline placeholders.
+fn is_interpolating_format(mac: &Macro) -> bool {
+    if !mac.path.is_ident("format") {
+        return false;
+    }
+
+    let tokens: Vec<_> = mac.tokens.clone().into_iter().collect();
+    match tokens.as_slice() {
+        [TokenTree::Literal(literal)] => literal.to_string().replace("{{", "").contains('{'),
+        [] => false,
+        _ => true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analyze(code: &str) -> AnalysisResult {
+        let ast = syn::parse_file(code).unwrap();
+        SqlInjectionAnalyzer::new().analyze(&ast, code).unwrap()
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        let analyzer = SqlInjectionAnalyzer::new();
+        assert_eq!(analyzer.name(), "sql_injection");
+        assert_eq!(analyzer.confidence(), Confidence::Medium);
+    }
+
+    #[test]
+    fn test_detect_format_argument() {
+        let result = analyze(
+            r#"
+async fn find(pool: &PgPool, name: &str) {
+    sqlx::query(&format!("SELECT * FROM users WHERE name = '{name}'"))
+        .fetch_all(pool)
+        .await;
+}
+"#
+        );
+
+        assert_eq!(result.issues.len(), 1);
+        assert_eq!(result.issues[0].line, 3);
+        assert!(
+            result.issues[0]
+                .message
+                .contains("`format!` passed to `query`")
+        );
+    }
+
+    #[test]
+    fn test_detect_concatenation_in_method() {
+        let result = analyze(
+            r#"
+fn delete(conn: &Connection, id: String) {
+    conn.execute("DELETE FROM users WHERE id = " + &id, []);
+}
+"#
+        );
+
+        assert_eq!(result.issues.len(), 1);
+        assert!(result.issues[0].message.contains("string concatenation"));
+    }
+
+    #[test]
+    fn test_detect_through_local_binding() {
+        let result = analyze(
+            r#"
+async fn find(pool: &PgPool, table: &str) {
+    let sql = format!("SELECT * FROM {}", table);
+    sqlx::query_as::<_, User>(sql.as_str()).fetch_all(pool).await;
+}
+"#
+        );
+
+        assert_eq!(result.issues.len(), 1);
+        assert!(result.issues[0].message.contains("in `sql`"));
+    }
+
+    #[test]
+    fn test_ignore_parameterized_and_static_queries() {
+        let result = analyze(
+            r#"
+async fn find(pool: &PgPool, name: &str) {
+    let sql = format!("SELECT 1");
+    sqlx::query("SELECT * FROM users WHERE name = $1").bind(name).fetch_all(pool).await;
+    sqlx::query(&sql).execute(pool).await;
+    let total = counter.execute(a + b);
+}
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_binding_scoped_to_function() {
+        let result = analyze(
+            r#"
+fn build(id: u32) -> String {
+    let sql = format!("SELECT * FROM t WHERE id = {id}");
+    sql
+}
+
+fn run(conn: &Connection, sql: &str) {
+    conn.execute(sql, []);
+}
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_ignore_test_code() {
+        let result = analyze(
+            r#"
+#[cfg(test)]
+mod tests {
+    fn seed(conn: &Connection, n: u32) {
+        conn.execute(&format!("INSERT INTO t VALUES ({n})"), []);
+    }
+}
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_default_implementation() {
+        let analyzer = SqlInjectionAnalyzer;
+        assert_eq!(analyzer.name(), "sql_injection");
+    }
+}
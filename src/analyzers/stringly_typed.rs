@@ -0,0 +1,283 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Stringly typed analyzer for public functions taking many string
+//! parameters.
+//!
+//! `fn connect(host: &str, user: &str, database: &str)` compiles just as
+//! well when a caller swaps the user and the database. When a signature
+//! takes several strings that stand for different concepts, newtypes such
+//! as `Host` or `UserName` let the compiler catch the mix-up. This analyzer
+//! flags public functions and inherent methods with three or more string
+//! parameters and reports their signature.
+//!
+//! Many such signatures are fine, e.g. `replace(text, from, to)` where all
+//! strings are plain text, so the confidence is low.
+
+use masterror::AppResult;
+use quote::ToTokens;
+use syn::{
+    File, FnArg, GenericArgument, ImplItemFn, ItemFn, ItemImpl, ItemMod, PathArguments, Signature,
+    Type, TypeParamBound, Visibility, spanned::Spanned, visit::Visit
+};
+
+use super::is_test_code;
+use crate::analyzer::{AnalysisResult, Analyzer, Confidence, Fix, Issue};
+
+/// Number of string parameters from which a signature is reported.
+const MIN_STRING_PARAMS: usize = 3;
+
+/// Analyzer for public functions with several string parameters.
+///
+/// # Examples
+///
+/// Detects this pattern:
+/// ```ignore
+/// pub fn connect(host: &str, user: &str, database: &str) -> Connection { ... }
+/// ```
+///
+/// Suggests newtypes for the distinct concepts:
+/// ```ignore
+/// pub struct Host(String);
+/// pub struct UserName(String);
+///
+/// pub fn connect(host: &Host, user: &UserName, database: &str) -> Connection { ... }
+/// ```
+pub struct StringlyTypedAnalyzer;
+
+impl StringlyTypedAnalyzer {
+    /// Create new stringly typed analyzer instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Analyzer for StringlyTypedAnalyzer {
+    fn name(&self) -> &'static str {
+        "stringly_typed"
+    }
+
+    fn analyze(&self, ast: &File, content: &str) -> AppResult<AnalysisResult> {
+        let mut visitor = StringParamsVisitor {
+            content,
+            issues: Vec::new()
+        };
+        visitor.visit_file(ast);
+
+        Ok(AnalysisResult {
+            issues:        visitor.issues,
+            fixable_count: 0
+        })
+    }
+
+    fn confidence(&self) -> Confidence {
+        Confidence::Low
+    }
+}
+
+impl Default for StringlyTypedAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct StringParamsVisitor<'a> {
+    content: &'a str,
+    issues:  Vec<Issue>
+}
+
+impl StringParamsVisitor<'_> {
+    /// Report a public signature with too many string parameters.
+    fn check(&mut self, vis: &Visibility, sig: &Signature) {
+        if !matches!(vis, Visibility::Public(_)) {
+            return;
+        }
+
+        let strings: Vec<String> = sig
+            .inputs
+            .iter()
+            .filter_map(|input| match input {
+                FnArg::Typed(pat) if is_string_type(&pat.ty) => {
+                    Some(format!("`{}`", pat.pat.to_token_stream()))
+                }
+                _ => None
+            })
+            .collect();
+        if strings.len() < MIN_STRING_PARAMS {
+            return;
+        }
+
+        let start = sig.ident.span().start();
+        self.issues.push(Issue {
+            line:    start.line,
+            column:  start.column,
+            message: format!(
+                "Function `{}` takes {} string parameters ({}): `{}`\n\
+                 Callers can pass them in the wrong order unnoticed. Wrap distinct concepts \
+                 in newtypes, e.g. `struct UserName(String)`",
+                sig.ident,
+                strings.len(),
+                strings.join(", "),
+                self.signature_text(sig)
+            ),
+            fix:     Fix::None
+        });
+    }
+
+    /// Signature as written, on a single line.
+    fn signature_text(&self, sig: &Signature) -> String {
+        let text = self
+            .content
+            .get(sig.span().byte_range())
+            .map_or_else(|| sig.to_token_stream().to_string(), str::to_string);
+
+        text.split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .replace("( ", "(")
+            .replace(", )", ")")
+            .replace(" )", ")")
+    }
+}
+
+impl<'ast> Visit<'ast> for StringParamsVisitor<'_> {
+    fn visit_item_fn(&mut self, node: &'ast ItemFn) {
+        if is_test_code(&node.attrs) {
+            return;
+        }
+        self.check(&node.vis, &node.sig);
+        syn::visit::visit_item_fn(self, node);
+    }
+
+    fn visit_item_impl(&mut self, node: &'ast ItemImpl) {
+        if node.trait_.is_none() && !is_test_code(&node.attrs) {
+            syn::visit::visit_item_impl(self, node);
+        }
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'ast ImplItemFn) {
+        if is_test_code(&node.attrs) {
+            return;
+        }
+        self.check(&node.vis, &node.sig);
+        syn::visit::visit_impl_item_fn(self, node);
+    }
+
+    fn visit_item_mod(&mut self, node: &'ast ItemMod) {
+        if !is_test_code(&node.attrs) {
+            syn::visit::visit_item_mod(self, node);
+        }
+    }
+}
+
+/// Check whether a parameter type is a string.
+///
+/// Matches `&str`, `String`, `&String`, `Cow<str>` and `impl AsRef<str>` /
+/// `impl Into<String>`.
+fn is_string_type(ty: &Type) -> bool {
+    match ty {
+        Type::Reference(reference) => is_string_type(&reference.elem),
+        Type::Path(path) => path.path.segments.last().is_some_and(|segment| {
+            segment.ident == "str"
+                || segment.ident == "String"
+                || (segment.ident == "Cow" && has_string_argument(&segment.arguments))
+        }),
+        Type::ImplTrait(impl_trait) => impl_trait.bounds.iter().any(|bound| {
+            let TypeParamBound::Trait(bound) = bound else {
+                return false;
+            };
+            bound.path.segments.last().is_some_and(|segment| {
+                (segment.ident == "AsRef" || segment.ident == "Into")
+                    && has_string_argument(&segment.arguments)
+            })
+        }),
+        _ => false
+    }
+}
+
+/// Check whether generic arguments include a string type.
+fn has_string_argument(arguments: &PathArguments) -> bool {
+    let PathArguments::AngleBracketed(arguments) = arguments else {
+        return false;
+    };
+    arguments
+        .args
+        .iter()
+        .any(|argument| matches!(argument, GenericArgument::Type(ty) if is_string_type(ty)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analyze(code: &str) -> AnalysisResult {
+        let ast = syn::parse_file(code).unwrap();
+        StringlyTypedAnalyzer::new().analyze(&ast, code).unwrap()
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        let analyzer = StringlyTypedAnalyzer::new();
+        assert_eq!(analyzer.name(), "stringly_typed");
+        assert_eq!(analyzer.confidence(), Confidence::Low);
+    }
+
+    #[test]
+    fn test_detect_string_heavy_signatures() {
+        let result = analyze(
+            r#"
+pub fn connect(
+    host: &str,
+    user: String,
+    port: u16,
+    database: &'static str,
+) -> Connection { todo!() }
+
+impl Client {
+    pub fn login(&self, user: impl Into<String>, password: Cow<'_, str>, realm: &String) {}
+}
+"#
+        );
+
+        assert_eq!(result.issues.len(), 2);
+        assert_eq!(result.issues[0].line, 2);
+        assert!(result.issues[0].message.contains(
+            "`connect` takes 3 string parameters (`host`, `user`, `database`): `fn connect(host: \
+             &str, user: String, port: u16, database: &'static str) -> Connection`"
+        ));
+        assert!(
+            result.issues[1]
+                .message
+                .contains("`login` takes 3 string parameters")
+        );
+    }
+
+    #[test]
+    fn test_ignore_other_functions() {
+        let result = analyze(
+            r#"
+pub fn join(a: &str, b: &str) -> String { todo!() }
+fn private(a: &str, b: &str, c: &str) {}
+pub fn bytes(a: &[u8], b: &Path, c: Option<&str>) {}
+
+impl Display for Config {
+    fn fmt(a: &str, b: &str, c: &str) {}
+}
+
+#[cfg(test)]
+mod tests {
+    pub fn fixture(a: &str, b: &str, c: &str) {}
+}
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_default_implementation() {
+        let analyzer = StringlyTypedAnalyzer;
+        assert_eq!(analyzer.name(), "stringly_typed");
+    }
+}
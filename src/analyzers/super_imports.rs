@@ -0,0 +1,338 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Super imports analyzer for deep relative `use` paths.
+//!
+//! `use super::super::config::Limits;` only makes sense to a reader who
+//! counts the module levels of the file, and breaks as soon as the file
+//! moves. `use crate::net::config::Limits;` says where the item lives. This
+//! analyzer flags imports climbing two or more modules with `super`. When
+//! the file is under `src/`, its module path follows from its location, so
+//! the `super` chain is rewritten to the equivalent `crate::` path.
+
+use std::{
+    ops::Range,
+    path::{Component, Path}
+};
+
+use masterror::AppResult;
+use syn::{File, ItemMod, ItemUse, UseTree, visit::Visit};
+
+use crate::analyzer::{AnalysisResult, Analyzer, Fix, Issue, Suggestion, TextEdit};
+
+/// Number of `super` segments from which an import is reported.
+const MIN_DEPTH: usize = 2;
+
+/// Analyzer for `use super::super::...` imports.
+///
+/// # Examples
+///
+/// Detects this pattern in `src/net/http/client.rs`:
+/// ```ignore
+/// use super::super::config::Limits;
+/// ```
+///
+/// Fixes to:
+/// ```ignore
+/// use crate::net::config::Limits;
+/// ```
+pub struct SuperImportsAnalyzer;
+
+/// Import climbing too many modules.
+struct Finding {
+    /// Position of the first `super`
+    line:        usize,
+    column:      usize,
+    /// Byte range of the `super::...::` prefix
+    range:       Range<usize>,
+    /// Equivalent `crate::` prefix, if the module path is known
+    replacement: Option<String>
+}
+
+impl SuperImportsAnalyzer {
+    /// Create new super imports analyzer instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Find deep `super` imports, resolving them against a module path.
+    fn collect(ast: &File, module: Option<Vec<String>>) -> Vec<Finding> {
+        let mut visitor = SuperVisitor {
+            module,
+            findings: Vec::new()
+        };
+        visitor.visit_file(ast);
+        visitor.findings
+    }
+
+    /// Build the report for a set of findings.
+    fn report(findings: Vec<Finding>) -> AnalysisResult {
+        let fixable_count = findings
+            .iter()
+            .filter(|finding| finding.replacement.is_some())
+            .count();
+        let issues = findings
+            .into_iter()
+            .map(|finding| Issue {
+                line:    finding.line,
+                column:  finding.column,
+                message: "Relative import climbs two or more modules with `super`\n\
+                          Use a path starting with `crate::` instead"
+                    .to_string(),
+                fix:     finding.replacement.map_or(Fix::None, Fix::Simple)
+            })
+            .collect();
+
+        AnalysisResult {
+            issues,
+            fixable_count
+        }
+    }
+}
+
+impl Analyzer for SuperImportsAnalyzer {
+    fn name(&self) -> &'static str {
+        "super_imports"
+    }
+
+    fn analyze(&self, ast: &File, _content: &str) -> AppResult<AnalysisResult> {
+        Ok(Self::report(Self::collect(ast, None)))
+    }
+
+    fn analyze_file(&self, path: &Path, ast: &File, _content: &str) -> AppResult<AnalysisResult> {
+        Ok(Self::report(Self::collect(ast, module_path(path))))
+    }
+
+    fn file_suggestions(
+        &self,
+        path: &Path,
+        ast: &File,
+        _content: &str
+    ) -> AppResult<Vec<Suggestion>> {
+        Ok(Self::collect(ast, module_path(path))
+            .into_iter()
+            .filter_map(|finding| {
+                Some(Suggestion {
+                    edit:   TextEdit {
+                        range:       finding.range,
+                        replacement: finding.replacement?
+                    },
+                    import: None
+                })
+            })
+            .collect())
+    }
+
+    fn fixable(&self) -> bool {
+        true
+    }
+}
+
+impl Default for SuperImportsAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct SuperVisitor {
+    /// Module path of the current scope, `None` if the file's is unknown
+    module:   Option<Vec<String>>,
+    findings: Vec<Finding>
+}
+
+impl<'ast> Visit<'ast> for SuperVisitor {
+    fn visit_item_mod(&mut self, node: &'ast ItemMod) {
+        if node.content.is_none() {
+            return;
+        }
+
+        if let Some(module) = &mut self.module {
+            module.push(node.ident.to_string());
+        }
+        syn::visit::visit_item_mod(self, node);
+        if let Some(module) = &mut self.module {
+            module.pop();
+        }
+    }
+
+    fn visit_item_use(&mut self, node: &'ast ItemUse) {
+        let mut supers = Vec::new();
+        let mut tree = &node.tree;
+        while let UseTree::Path(path) = tree
+            && path.ident == "super"
+        {
+            supers.push(path);
+            tree = &path.tree;
+        }
+
+        let (Some(first), Some(last)) = (supers.first(), supers.last()) else {
+            return;
+        };
+        if supers.len() < MIN_DEPTH {
+            return;
+        }
+
+        let start = first.ident.span().start();
+        let replacement = self.module.as_ref().and_then(|module| {
+            let kept = module.len().checked_sub(supers.len())?;
+            let segments: String = module[..kept]
+                .iter()
+                .map(|segment| format!("{segment}::"))
+                .collect();
+            Some(format!("crate::{segments}"))
+        });
+
+        self.findings.push(Finding {
+            line: start.line,
+            column: start.column,
+            range: first.ident.span().byte_range().start
+                ..last.colon2_token.spans[1].byte_range().end,
+            replacement
+        });
+    }
+}
+
+/// Derive the module path of a file from its location under `src/`.
+///
+/// `src/lib.rs`, `src/main.rs` and binaries under `src/bin/` are crate
+/// roots; `src/net/mod.rs` and `src/net.rs` are `net`.
+///
+/// # Arguments
+///
+/// * `path` - Path of the file
+///
+/// # Returns
+///
+/// Module segments below the crate root, or `None` for files outside `src/`
+fn module_path(path: &Path) -> Option<Vec<String>> {
+    let components: Vec<String> = path
+        .components()
+        .filter_map(|component| match component {
+            Component::Normal(name) => Some(name.to_string_lossy().into_owned()),
+            _ => None
+        })
+        .collect();
+    let src = components.iter().rposition(|name| name == "src")?;
+    let mut segments = &components[src + 1..];
+
+    if segments.first().is_some_and(|name| name == "bin") {
+        if segments.len() <= 2 {
+            return Some(Vec::new());
+        }
+        segments = &segments[2..];
+    }
+
+    let (file, dirs) = segments.split_last()?;
+    let mut module = dirs.to_vec();
+    match file.strip_suffix(".rs")? {
+        "mod" => {}
+        "lib" | "main" if dirs.is_empty() => {}
+        name => module.push(name.to_string())
+    }
+    Some(module)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixer::apply_suggestions;
+
+    fn analyze(code: &str, path: &str) -> AnalysisResult {
+        let ast = syn::parse_file(code).unwrap();
+        SuperImportsAnalyzer::new()
+            .analyze_file(Path::new(path), &ast, code)
+            .unwrap()
+    }
+
+    fn fix(code: &str, path: &str) -> String {
+        let ast = syn::parse_file(code).unwrap();
+        let suggestions = SuperImportsAnalyzer::new()
+            .file_suggestions(Path::new(path), &ast, code)
+            .unwrap();
+        apply_suggestions(code, &suggestions).source
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        let analyzer = SuperImportsAnalyzer::new();
+        assert_eq!(analyzer.name(), "super_imports");
+    }
+
+    #[test]
+    fn test_detect_deep_super_imports() {
+        let code = "use super::Sibling;\n\
+                    use super::super::config::Limits;\n\
+                    pub use super::super::super::{Error, Result};\n";
+        let result = analyze(code, "src/net/http/client.rs");
+
+        assert_eq!(result.issues.len(), 2);
+        assert_eq!(result.issues[0].line, 2);
+        assert_eq!(result.issues[0].column, 4);
+        assert!(result.issues[0].message.contains("`crate::`"));
+        assert_eq!(result.issues[0].fix.as_simple(), Some("crate::net::"));
+        assert_eq!(result.issues[1].fix.as_simple(), Some("crate::"));
+        assert_eq!(result.fixable_count, 2);
+    }
+
+    #[test]
+    fn test_fix_rewrites_to_crate_paths() {
+        let code = "use super::super::config::Limits;\n\
+                    mod inner {\n    use super::super::super::Root;\n}\n";
+
+        assert_eq!(
+            fix(code, "./src/net/http/client.rs"),
+            "use crate::net::config::Limits;\n\
+             mod inner {\n    use crate::net::Root;\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_no_fix_without_module_path() {
+        let code = "use super::super::Helper;\n";
+
+        let result = analyze(code, "tests/common/helpers.rs");
+        assert_eq!(result.issues.len(), 1);
+        assert_eq!(result.fixable_count, 0);
+        assert_eq!(fix(code, "tests/common/helpers.rs"), code);
+
+        let result = analyze(code, "src/net.rs");
+        assert_eq!(result.fixable_count, 0);
+    }
+
+    #[test]
+    fn test_module_path() {
+        let module = |path: &str| module_path(Path::new(path));
+
+        assert_eq!(module("src/lib.rs"), Some(vec![]));
+        assert_eq!(module("src/main.rs"), Some(vec![]));
+        assert_eq!(module("src/net/mod.rs"), Some(vec!["net".to_string()]));
+        assert_eq!(
+            module("/work/app/src/net/http.rs"),
+            Some(vec!["net".to_string(), "http".to_string()])
+        );
+        assert_eq!(module("src/bin/tool.rs"), Some(vec![]));
+        assert_eq!(module("src/bin/tool/main.rs"), Some(vec![]));
+        assert_eq!(
+            module("src/bin/tool/args.rs"),
+            Some(vec!["args".to_string()])
+        );
+        assert_eq!(module("tests/it.rs"), None);
+    }
+
+    #[test]
+    fn test_analyze_without_path() {
+        let code = "use super::super::Helper;\n";
+        let ast = syn::parse_file(code).unwrap();
+        let result = SuperImportsAnalyzer::new().analyze(&ast, code).unwrap();
+
+        assert_eq!(result.issues.len(), 1);
+        assert_eq!(result.fixable_count, 0);
+    }
+
+    #[test]
+    fn test_default_implementation() {
+        let analyzer = SuperImportsAnalyzer;
+        assert_eq!(analyzer.name(), "super_imports");
+    }
+}
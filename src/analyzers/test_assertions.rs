@@ -0,0 +1,424 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Test assertions analyzer for vacuous `#[test]` functions.
+//!
+//! A test that never asserts passes as long as it does not crash, so it
+//! keeps passing after the behavior it was written for breaks. This analyzer
+//! flags `#[test]` functions whose body contains no assertion: no
+//! `assert!`-family or `panic!` macro, no `.unwrap()`/`.expect(..)` and no
+//! `?`. Calls to functions of the same file that assert, such as shared
+//! `check_*` helpers, count as assertions. `#[should_panic]` tests are
+//! skipped, as panicking is what they check.
+
+use std::collections::HashSet;
+
+use masterror::AppResult;
+use syn::{
+    Attribute, Block, Expr, ExprCall, ExprMethodCall, ExprTry, File, ImplItem, Item, ItemFn,
+    Macro, Token, punctuated::Punctuated, visit::Visit
+};
+
+use crate::analyzer::{AnalysisResult, Analyzer, Fix, Issue};
+
+/// Macros that fail the test when their condition does not hold.
+const FAILING_MACROS: &[&str] = &["panic", "unreachable", "unimplemented", "todo"];
+
+/// Methods that panic when the value is not the expected variant.
+const UNWRAP_METHODS: &[&str] = &["unwrap", "expect", "unwrap_err", "expect_err"];
+
+/// Analyzer for `#[test]` functions without assertions.
+///
+/// # Examples
+///
+/// Detects this pattern:
+/// ```ignore
+/// #[test]
+/// fn test_parse() {
+///     let config = Config::parse("port = 80");
+/// }
+/// ```
+///
+/// Suggests:
+/// ```ignore
+/// #[test]
+/// fn test_parse() {
+///     let config = Config::parse("port = 80").unwrap();
+///     assert_eq!(config.port, 80);
+/// }
+/// ```
+pub struct TestAssertionsAnalyzer;
+
+impl TestAssertionsAnalyzer {
+    /// Create new test assertions analyzer instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Analyzer for TestAssertionsAnalyzer {
+    fn name(&self) -> &'static str {
+        "test_assertions"
+    }
+
+    fn analyze(&self, ast: &File, _content: &str) -> AppResult<AnalysisResult> {
+        let helpers = asserting_functions(&ast.items);
+        let mut issues = Vec::new();
+        check_items(&ast.items, &helpers, &mut issues);
+
+        Ok(AnalysisResult {
+            issues,
+            fixable_count: 0
+        })
+    }
+}
+
+impl Default for TestAssertionsAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Report the test functions without assertions among some items.
+///
+/// Inline modules are checked recursively.
+///
+/// # Arguments
+///
+/// * `items` - Items of the module
+/// * `helpers` - Functions of the file that assert
+/// * `issues` - Receives the reported issues
+fn check_items(items: &[Item], helpers: &HashSet<String>, issues: &mut Vec<Issue>) {
+    for item in items {
+        match item {
+            Item::Fn(node)
+                if is_test(&node.attrs)
+                    && !should_panic(&node.attrs)
+                    && !asserts(&node.block, helpers) =>
+            {
+                report(node, issues);
+            }
+            Item::Mod(node) => {
+                if let Some((_, content)) = &node.content {
+                    check_items(content, helpers, issues);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Report a test function at its name.
+fn report(node: &ItemFn, issues: &mut Vec<Issue>) {
+    let start = node.sig.ident.span().start();
+    issues.push(Issue {
+        line:    start.line,
+        column:  start.column,
+        message: format!(
+            "Test `{}` contains no assertions\n\
+             Check the outcome with `assert!`/`assert_eq!`, or unwrap results so failures panic",
+            node.sig.ident
+        ),
+        fix:     Fix::None
+    });
+}
+
+/// Names of the non-test functions of a file that assert.
+///
+/// A function asserts if its body does, directly or by calling another
+/// asserting function, so the set is grown until it no longer changes.
+///
+/// # Arguments
+///
+/// * `items` - Top-level items of the file
+///
+/// # Returns
+///
+/// Names of free functions and methods that contain assertions
+fn asserting_functions(items: &[Item]) -> HashSet<String> {
+    let mut functions = Vec::new();
+    collect_functions(items, &mut functions);
+
+    let mut helpers = HashSet::new();
+    loop {
+        let found: Vec<String> = functions
+            .iter()
+            .filter(|(name, block)| !helpers.contains(name) && asserts(block, &helpers))
+            .map(|(name, _)| name.clone())
+            .collect();
+        if found.is_empty() {
+            return helpers;
+        }
+        helpers.extend(found);
+    }
+}
+
+/// Collect the non-test functions and methods of some items, recursively.
+fn collect_functions<'a>(items: &'a [Item], functions: &mut Vec<(String, &'a Block)>) {
+    for item in items {
+        match item {
+            Item::Fn(node) if !is_test(&node.attrs) => {
+                functions.push((node.sig.ident.to_string(), &node.block));
+            }
+            Item::Impl(node) => {
+                for impl_item in &node.items {
+                    if let ImplItem::Fn(method) = impl_item {
+                        functions.push((method.sig.ident.to_string(), &method.block));
+                    }
+                }
+            }
+            Item::Mod(node) => {
+                if let Some((_, content)) = &node.content {
+                    collect_functions(content, functions);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Check whether a function body contains an assertion.
+///
+/// # Arguments
+///
+/// * `block` - Function body
+/// * `helpers` - Functions known to assert
+///
+/// # Returns
+///
+/// `true` if the body asserts, panics, unwraps, uses `?` or calls a helper
+fn asserts(block: &Block, helpers: &HashSet<String>) -> bool {
+    let mut visitor = AssertionVisitor {
+        helpers,
+        found: false
+    };
+    visitor.visit_block(block);
+    visitor.found
+}
+
+struct AssertionVisitor<'a> {
+    helpers: &'a HashSet<String>,
+    found:   bool
+}
+
+impl<'ast> Visit<'ast> for AssertionVisitor<'_> {
+    /// Detect assertion and failing macros.
+    ///
+    /// # Notes
+    ///
+    /// - Arguments of other macros are visited too, e.g. `println!("{}",
+    ///   run().unwrap())`
+    fn visit_macro(&mut self, node: &'ast Macro) {
+        let Some(name) = node.path.segments.last().map(|seg| seg.ident.to_string()) else {
+            return;
+        };
+        if name.starts_with("assert")
+            || name.starts_with("debug_assert")
+            || FAILING_MACROS.contains(&name.as_str())
+        {
+            self.found = true;
+            return;
+        }
+
+        if let Ok(args) = node.parse_body_with(Punctuated::<Expr, Token![,]>::parse_terminated) {
+            for arg in &args {
+                self.visit_expr(arg);
+            }
+        }
+    }
+
+    fn visit_expr_method_call(&mut self, node: &'ast ExprMethodCall) {
+        let method = node.method.to_string();
+        if UNWRAP_METHODS.contains(&method.as_str()) || self.helpers.contains(&method) {
+            self.found = true;
+        }
+        syn::visit::visit_expr_method_call(self, node);
+    }
+
+    fn visit_expr_call(&mut self, node: &'ast ExprCall) {
+        if let Expr::Path(path) = node.func.as_ref()
+            && let Some(seg) = path.path.segments.last()
+            && self.helpers.contains(&seg.ident.to_string())
+        {
+            self.found = true;
+        }
+        syn::visit::visit_expr_call(self, node);
+    }
+
+    fn visit_expr_try(&mut self, node: &'ast ExprTry) {
+        self.found = true;
+        syn::visit::visit_expr_try(self, node);
+    }
+}
+
+/// Check whether a function is a test, including `#[tokio::test]` and
+/// similar runtime attributes.
+fn is_test(attrs: &[Attribute]) -> bool {
+    attrs.iter().any(|attr| {
+        attr.path()
+            .segments
+            .last()
+            .is_some_and(|seg| seg.ident == "test")
+    })
+}
+
+/// Check whether a test expects a panic.
+fn should_panic(attrs: &[Attribute]) -> bool {
+    attrs
+        .iter()
+        .any(|attr| attr.path().is_ident("should_panic"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analyze(code: &str) -> AnalysisResult {
+        let ast = syn::parse_file(code).unwrap();
+        TestAssertionsAnalyzer::new().analyze(&ast, code).unwrap()
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        let analyzer = TestAssertionsAnalyzer::new();
+        assert_eq!(analyzer.name(), "test_assertions");
+    }
+
+    #[test]
+    fn test_detect_tests_without_assertions() {
+        let result = analyze(
+            r#"
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_parse() {
+        let config = Config::parse("port = 80");
+    }
+
+    #[tokio::test]
+    async fn test_connect() {
+        let client = Client::connect().await;
+        println!("{:?}", client);
+    }
+}
+"#
+        );
+
+        assert_eq!(result.issues.len(), 2);
+        assert_eq!(result.issues[0].line, 5);
+        assert!(
+            result.issues[0]
+                .message
+                .contains("Test `test_parse` contains no assertions")
+        );
+        assert!(result.issues[1].message.contains("`test_connect`"));
+    }
+
+    #[test]
+    fn test_accept_assertions() {
+        let result = analyze(
+            r#"
+#[test]
+fn test_assert() {
+    let value = compute();
+    if value > 2 {
+        assert!(value < 10, "too large");
+    }
+}
+
+#[test]
+fn test_assert_eq() {
+    assert_eq!(compute(), 4);
+}
+
+#[test]
+fn test_unwrap() {
+    Config::parse("port = 80").unwrap();
+}
+
+#[test]
+fn test_unwrap_in_macro() {
+    println!("{}", Config::parse("port = 80").expect("valid"));
+}
+
+#[test]
+fn test_try() -> Result<(), Error> {
+    Config::parse("port = 80")?;
+    Ok(())
+}
+
+#[test]
+fn test_panic() {
+    match compute() {
+        4 => {}
+        other => panic!("unexpected {other}")
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_should_panic() {
+    Config::parse("");
+}
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_asserting_helpers() {
+        let result = analyze(
+            r#"
+fn check_roundtrip(input: &str) {
+    assert_eq!(encode(&decode(input)), input);
+}
+
+fn check_all(inputs: &[&str]) {
+    for input in inputs {
+        check_roundtrip(input);
+    }
+}
+
+struct Harness;
+
+impl Harness {
+    fn verify(&self) {
+        assert!(self.ready());
+    }
+}
+
+#[test]
+fn test_roundtrip() {
+    check_all(&["a", "b"]);
+}
+
+#[test]
+fn test_harness() {
+    Harness.verify();
+}
+
+#[test]
+fn test_no_helper() {
+    encode("a");
+}
+"#
+        );
+
+        assert_eq!(result.issues.len(), 1);
+        assert!(result.issues[0].message.contains("`test_no_helper`"));
+    }
+
+    #[test]
+    fn test_ignore_non_test_functions() {
+        let result = analyze("fn main() { run(); }");
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_default_implementation() {
+        let analyzer = TestAssertionsAnalyzer;
+        assert_eq!(analyzer.name(), "test_assertions");
+    }
+}
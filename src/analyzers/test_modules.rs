@@ -0,0 +1,266 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Test modules analyzer for the `mod tests` layout convention.
+//!
+//! Unit tests in this crate live in a `#[cfg(test)] mod tests` at the end of
+//! the file they test, and bring the module under test into scope with
+//! `use super::*;`. This analyzer flags inline test modules whose leading
+//! imports lack `use super::*;` and test modules followed by non-test code.
+
+use masterror::AppResult;
+use syn::{Attribute, File, Item, ItemMod, UseTree};
+
+use super::is_test_code;
+use crate::analyzer::{AnalysisResult, Analyzer, Fix, Issue};
+
+/// Analyzer for test modules that break the layout convention.
+///
+/// # Examples
+///
+/// Detects this pattern:
+/// ```ignore
+/// #[cfg(test)]
+/// mod tests {
+///     use crate::config::Config;
+///
+///     #[test]
+///     fn test_load() { ... }
+/// }
+///
+/// pub fn helper() {}
+/// ```
+///
+/// Suggests:
+/// ```ignore
+/// pub fn helper() {}
+///
+/// #[cfg(test)]
+/// mod tests {
+///     use super::*;
+///
+///     #[test]
+///     fn test_load() { ... }
+/// }
+/// ```
+pub struct TestModulesAnalyzer;
+
+impl TestModulesAnalyzer {
+    /// Create new test modules analyzer instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Analyzer for TestModulesAnalyzer {
+    fn name(&self) -> &'static str {
+        "test_modules"
+    }
+
+    fn analyze(&self, ast: &File, _content: &str) -> AppResult<AnalysisResult> {
+        let mut issues = Vec::new();
+        check_items(&ast.items, &mut issues);
+
+        Ok(AnalysisResult {
+            issues,
+            fixable_count: 0
+        })
+    }
+}
+
+impl Default for TestModulesAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Check the test modules among the items of one module.
+///
+/// Inline non-test modules are checked recursively.
+///
+/// # Arguments
+///
+/// * `items` - Items of the module
+/// * `issues` - Receives the reported issues
+fn check_items(items: &[Item], issues: &mut Vec<Issue>) {
+    for (index, item) in items.iter().enumerate() {
+        let Item::Mod(node) = item else {
+            continue;
+        };
+        let Some((_, content)) = &node.content else {
+            continue;
+        };
+        if !is_test_code(&node.attrs) {
+            check_items(content, issues);
+            continue;
+        }
+
+        if !content.is_empty() && !imports_super_glob(content) {
+            push(
+                issues,
+                node,
+                format!(
+                    "Test module `{}` does not import `use super::*;`\n\
+                     Start test modules with `use super::*;` to test the enclosing module",
+                    node.ident
+                )
+            );
+        }
+
+        if items[index + 1..]
+            .iter()
+            .any(|next| !is_test_code(item_attrs(next)))
+        {
+            push(
+                issues,
+                node,
+                format!(
+                    "Test module `{}` is placed before non-test code\n\
+                     Move test modules to the end of the file",
+                    node.ident
+                )
+            );
+        }
+    }
+}
+
+/// Report an issue at a module's name.
+fn push(issues: &mut Vec<Issue>, node: &ItemMod, message: String) {
+    let start = node.ident.span().start();
+    issues.push(Issue {
+        line: start.line,
+        column: start.column,
+        message,
+        fix: Fix::None
+    });
+}
+
+/// Check whether the leading `use` items of a module include `super::*`.
+fn imports_super_glob(items: &[Item]) -> bool {
+    items
+        .iter()
+        .map_while(|item| match item {
+            Item::Use(node) => Some(&node.tree),
+            _ => None
+        })
+        .any(|tree| {
+            matches!(tree, UseTree::Path(path)
+                if path.ident == "super" && matches!(path.tree.as_ref(), UseTree::Glob(_)))
+        })
+}
+
+/// Outer attributes of an item, empty for items without attributes.
+pub(crate) fn item_attrs(item: &Item) -> &[Attribute] {
+    match item {
+        Item::Const(node) => &node.attrs,
+        Item::Enum(node) => &node.attrs,
+        Item::ExternCrate(node) => &node.attrs,
+        Item::Fn(node) => &node.attrs,
+        Item::ForeignMod(node) => &node.attrs,
+        Item::Impl(node) => &node.attrs,
+        Item::Macro(node) => &node.attrs,
+        Item::Mod(node) => &node.attrs,
+        Item::Static(node) => &node.attrs,
+        Item::Struct(node) => &node.attrs,
+        Item::Trait(node) => &node.attrs,
+        Item::TraitAlias(node) => &node.attrs,
+        Item::Type(node) => &node.attrs,
+        Item::Union(node) => &node.attrs,
+        Item::Use(node) => &node.attrs,
+        _ => &[]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analyze(code: &str) -> AnalysisResult {
+        let ast = syn::parse_file(code).unwrap();
+        TestModulesAnalyzer::new().analyze(&ast, code).unwrap()
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        let analyzer = TestModulesAnalyzer::new();
+        assert_eq!(analyzer.name(), "test_modules");
+    }
+
+    #[test]
+    fn test_accept_conventional_layout() {
+        let result = analyze(
+            r#"
+pub fn helper() {}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+
+    #[test]
+    fn test_helper() { helper(); }
+}
+
+#[cfg(test)]
+mod fixtures;
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_detect_missing_super_glob() {
+        let result = analyze(
+            r#"
+#[cfg(test)]
+mod tests {
+    use super::helper;
+
+    fn local() {}
+    use super::*;
+}
+"#
+        );
+
+        assert_eq!(result.issues.len(), 1);
+        assert_eq!(result.issues[0].line, 3);
+        assert!(
+            result.issues[0]
+                .message
+                .contains("`tests` does not import `use super::*;`")
+        );
+    }
+
+    #[test]
+    fn test_detect_tests_before_code() {
+        let result = analyze(
+            r#"
+mod inner {
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+    }
+
+    fn helper() {}
+}
+"#
+        );
+
+        assert_eq!(result.issues.len(), 1);
+        assert_eq!(result.issues[0].line, 4);
+        assert!(
+            result.issues[0]
+                .message
+                .contains("placed before non-test code")
+        );
+    }
+
+    #[test]
+    fn test_default_implementation() {
+        let analyzer = TestModulesAnalyzer;
+        assert_eq!(analyzer.name(), "test_modules");
+    }
+}
@@ -0,0 +1,371 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Time source analyzer for hidden clock reads and local timezones.
+//!
+//! Logic that reads the clock itself cannot be tested deterministically, and
+//! servers that format timestamps in the host's local timezone produce logs
+//! and data that change with deployment. This analyzer flags
+//! `SystemTime::now()`/`Instant::now()` inside synchronous functions and
+//! `chrono::Local` in server projects. It is opt-in: enable it per project
+//! with `enable = ["time_source"]` under `[package.metadata.quality]`.
+
+use masterror::AppResult;
+use syn::{
+    Block, ExprCall, File, ImplItemFn, Item, ItemFn, ItemMod, Path, Signature, UseTree,
+    spanned::Spanned, visit::Visit
+};
+
+use super::is_test_code;
+use crate::analyzer::{AnalysisResult, Analyzer, Fix, Issue};
+
+/// Clock types whose `now()` reads the system clock.
+const CLOCKS: &[&str] = &["SystemTime", "Instant"];
+
+/// Analyzer for clock reads in pure logic and `chrono::Local` in servers.
+///
+/// # Examples
+///
+/// Detects this pattern:
+/// ```ignore
+/// fn is_expired(token: &Token) -> bool {
+///     SystemTime::now() > token.expires_at
+/// }
+/// ```
+///
+/// Suggests injecting the time:
+/// ```ignore
+/// fn is_expired(token: &Token, now: SystemTime) -> bool {
+///     now > token.expires_at
+/// }
+/// ```
+pub struct TimeSourceAnalyzer {
+    server: bool
+}
+
+impl TimeSourceAnalyzer {
+    /// Create new time source analyzer for a non-server project.
+    ///
+    /// Only clock reads are checked; use [`Self::with_server`] to also flag
+    /// `chrono::Local`.
+    #[inline]
+    pub fn new() -> Self {
+        Self::with_server(false)
+    }
+
+    /// Create analyzer for a project that may be a server.
+    ///
+    /// # Arguments
+    ///
+    /// * `server` - Whether the project is server code, see
+    ///   [`crate::project::ProjectContext::is_server`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cargo_quality::{analyzer::Analyzer, analyzers::TimeSourceAnalyzer};
+    ///
+    /// let analyzer = TimeSourceAnalyzer::with_server(true);
+    /// let code = "async fn stamp() -> String { chrono::Local::now().to_string() }";
+    /// let ast = syn::parse_file(code).unwrap();
+    /// assert_eq!(analyzer.analyze(&ast, code).unwrap().issues.len(), 1);
+    /// ```
+    #[inline]
+    pub fn with_server(server: bool) -> Self {
+        Self {
+            server
+        }
+    }
+}
+
+impl Analyzer for TimeSourceAnalyzer {
+    fn name(&self) -> &'static str {
+        "time_source"
+    }
+
+    fn analyze(&self, ast: &File, _content: &str) -> AppResult<AnalysisResult> {
+        let mut visitor = TimeVisitor {
+            issues:         Vec::new(),
+            local_in_scope: self.server && imports_chrono_local(ast),
+            server:         self.server
+        };
+        visitor.visit_file(ast);
+
+        Ok(AnalysisResult {
+            issues:        visitor.issues,
+            fixable_count: 0
+        })
+    }
+
+    fn opt_in(&self) -> bool {
+        true
+    }
+}
+
+impl Default for TimeSourceAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct TimeVisitor {
+    issues:         Vec<Issue>,
+    local_in_scope: bool,
+    server:         bool
+}
+
+impl TimeVisitor {
+    /// Flag clock reads in the body of a synchronous function.
+    ///
+    /// `main` and async functions are entry points where reading the clock
+    /// is expected, so only other functions count as pure logic.
+    fn check_function(&mut self, sig: &Signature, block: &Block) {
+        if sig.asyncness.is_some() || sig.ident == "main" {
+            return;
+        }
+
+        let mut calls = ClockCalls {
+            found: Vec::new()
+        };
+        calls.visit_block(block);
+
+        for (clock, call) in calls.found {
+            let start = call.span().start();
+            self.issues.push(Issue {
+                line:    start.line,
+                column:  start.column,
+                message: format!(
+                    "`{clock}::now()` read inside `fn {}`\n\
+                     Pass the time (or a clock) in as a parameter so the logic can be tested \
+                     deterministically",
+                    sig.ident
+                ),
+                fix:     Fix::None
+            });
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for TimeVisitor {
+    fn visit_item_fn(&mut self, node: &'ast ItemFn) {
+        if is_test_code(&node.attrs) {
+            return;
+        }
+        self.check_function(&node.sig, &node.block);
+        syn::visit::visit_item_fn(self, node);
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'ast ImplItemFn) {
+        if is_test_code(&node.attrs) {
+            return;
+        }
+        self.check_function(&node.sig, &node.block);
+        syn::visit::visit_impl_item_fn(self, node);
+    }
+
+    fn visit_item_mod(&mut self, node: &'ast ItemMod) {
+        if !is_test_code(&node.attrs) {
+            syn::visit::visit_item_mod(self, node);
+        }
+    }
+
+    fn visit_path(&mut self, node: &'ast Path) {
+        if self.server && is_chrono_local(node, self.local_in_scope) {
+            let start = node.span().start();
+            self.issues.push(Issue {
+                line:    start.line,
+                column:  start.column,
+                message: "`chrono::Local` used in server code\n\
+                          Use `chrono::Utc` so timestamps do not depend on the host timezone"
+                    .to_string(),
+                fix:     Fix::None
+            });
+        }
+        syn::visit::visit_path(self, node);
+    }
+}
+
+/// Collects `SystemTime::now()`/`Instant::now()` calls in one function body.
+///
+/// Nested items are skipped; they are checked as functions of their own.
+struct ClockCalls<'ast> {
+    found: Vec<(&'static str, &'ast ExprCall)>
+}
+
+impl<'ast> Visit<'ast> for ClockCalls<'ast> {
+    fn visit_item(&mut self, _node: &'ast Item) {}
+
+    fn visit_expr_call(&mut self, node: &'ast ExprCall) {
+        if let syn::Expr::Path(path) = node.func.as_ref() {
+            let segments: Vec<_> = path.path.segments.iter().collect();
+            if let [.., clock, now] = segments.as_slice()
+                && now.ident == "now"
+                && let Some(&name) = CLOCKS.iter().find(|&&name| clock.ident == name)
+            {
+                self.found.push((name, node));
+            }
+        }
+        syn::visit::visit_expr_call(self, node);
+    }
+}
+
+/// Check whether a path names `chrono::Local`.
+///
+/// # Arguments
+///
+/// * `path` - Path from an expression or type
+/// * `local_in_scope` - Whether the file imports `Local` from `chrono`
+fn is_chrono_local(path: &Path, local_in_scope: bool) -> bool {
+    let segments: Vec<_> = path.segments.iter().map(|seg| &seg.ident).collect();
+    let qualified = segments
+        .windows(2)
+        .any(|pair| pair[0] == "chrono" && pair[1] == "Local");
+    let imported = local_in_scope && segments.first().is_some_and(|ident| *ident == "Local");
+
+    qualified || imported
+}
+
+/// Check whether a file brings `chrono::Local` into scope.
+///
+/// Matches `use chrono::Local`, groups containing it, and glob imports from
+/// `chrono` or `chrono::prelude`.
+fn imports_chrono_local(ast: &File) -> bool {
+    fn walk(tree: &UseTree, in_chrono: bool) -> bool {
+        match tree {
+            UseTree::Path(path) => walk(&path.tree, in_chrono || path.ident == "chrono"),
+            UseTree::Name(name) => in_chrono && name.ident == "Local",
+            UseTree::Rename(_) => false,
+            UseTree::Glob(_) => in_chrono,
+            UseTree::Group(group) => group.items.iter().any(|item| walk(item, in_chrono))
+        }
+    }
+
+    ast.items.iter().any(|item| match item {
+        Item::Use(item_use) => walk(&item_use.tree, false),
+        _ => false
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::parse_quote;
+
+    use super::*;
+
+    fn server() -> TimeSourceAnalyzer {
+        TimeSourceAnalyzer::with_server(true)
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        let analyzer = TimeSourceAnalyzer::new();
+        assert_eq!(analyzer.name(), "time_source");
+        assert!(analyzer.opt_in());
+    }
+
+    #[test]
+    fn test_detect_clock_in_sync_function() {
+        let code: File = parse_quote! {
+            fn is_expired(token: &Token) -> bool {
+                SystemTime::now() > token.expires_at
+            }
+
+            impl Timer {
+                fn elapsed(&self) -> Duration {
+                    std::time::Instant::now() - self.start
+                }
+            }
+        };
+
+        let result = TimeSourceAnalyzer::new().analyze(&code, "").unwrap();
+        assert_eq!(result.issues.len(), 2);
+        assert!(result.issues[0].message.contains("`SystemTime::now()`"));
+        assert!(result.issues[1].message.contains("`fn elapsed`"));
+    }
+
+    #[test]
+    fn test_ignore_entry_points_and_tests() {
+        let code: File = parse_quote! {
+            fn main() {
+                let start = Instant::now();
+            }
+
+            async fn handle() {
+                let now = SystemTime::now();
+            }
+
+            #[cfg(test)]
+            mod tests {
+                fn fixture() -> SystemTime {
+                    SystemTime::now()
+                }
+            }
+        };
+
+        let result = TimeSourceAnalyzer::new().analyze(&code, "").unwrap();
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_nested_function_reported_once() {
+        let code: File = parse_quote! {
+            fn outer() {
+                fn inner() -> Instant {
+                    Instant::now()
+                }
+            }
+        };
+
+        let result = TimeSourceAnalyzer::new().analyze(&code, "").unwrap();
+        assert_eq!(result.issues.len(), 1);
+        assert!(result.issues[0].message.contains("`fn inner`"));
+    }
+
+    #[test]
+    fn test_detect_chrono_local_in_server() {
+        let code: File = parse_quote! {
+            use chrono::{DateTime, Local};
+
+            async fn stamp() -> DateTime<Local> {
+                Local::now()
+            }
+        };
+
+        let result = server().analyze(&code, "").unwrap();
+        assert_eq!(result.issues.len(), 2);
+        assert!(result.issues[0].message.contains("chrono::Utc"));
+    }
+
+    #[test]
+    fn test_ignore_chrono_local_outside_server() {
+        let code: File = parse_quote! {
+            async fn stamp() -> String {
+                chrono::Local::now().to_string()
+            }
+        };
+
+        let result = TimeSourceAnalyzer::new().analyze(&code, "").unwrap();
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_ignore_unrelated_local() {
+        let code: File = parse_quote! {
+            enum Scope { Local, Global }
+
+            async fn scope() -> Scope {
+                Local::new()
+            }
+        };
+
+        let result = server().analyze(&code, "").unwrap();
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_default_implementation() {
+        let analyzer = TimeSourceAnalyzer::default();
+        assert_eq!(analyzer.name(), "time_source");
+        assert!(!analyzer.server);
+    }
+}
@@ -0,0 +1,317 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! TODO comments analyzer for unresolved work markers.
+//!
+//! `TODO`, `FIXME`, `HACK` and `XXX` comments record work that was left for
+//! later and tend to outlive the branch they were written on. This opt-in
+//! analyzer reports every marker found in line, block and doc comments
+//! together with its text, so a team can list them with
+//! [`GlobalReport::display_by_file`](crate::report::GlobalReport::display_by_file)
+//! or fail CI while any remain. Markers inside string literals are ignored.
+
+use std::ops::Range;
+
+use masterror::AppResult;
+use syn::File;
+
+use super::literal_ranges;
+use crate::analyzer::{AnalysisResult, Analyzer, Fix, Issue};
+
+/// Comment markers reported by the analyzer.
+pub const MARKERS: &[&str] = &["TODO", "FIXME", "HACK", "XXX"];
+
+/// Analyzer collecting TODO/FIXME/HACK/XXX markers from comments.
+///
+/// # Examples
+///
+/// Detects this pattern:
+/// ```ignore
+/// // TODO: retry on timeout
+/// let response = client.get(url).send()?;
+/// ```
+///
+/// Reports `TODO: retry on timeout` at the comment's line.
+pub struct TodoCommentsAnalyzer;
+
+impl TodoCommentsAnalyzer {
+    /// Create new TODO comments analyzer instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Analyzer for TodoCommentsAnalyzer {
+    fn name(&self) -> &'static str {
+        "todo_comments"
+    }
+
+    fn analyze(&self, _ast: &File, content: &str) -> AppResult<AnalysisResult> {
+        let mut issues = Vec::new();
+
+        for comment in comments(content) {
+            let text = &content[comment.clone()];
+            for (offset, marker) in find_markers(text) {
+                let position = comment.start + offset;
+                let line_start = content[..position].rfind('\n').map_or(0, |idx| idx + 1);
+                let note = marker_note(&text[offset + marker.len()..]);
+
+                issues.push(Issue {
+                    line:    content[..position].matches('\n').count() + 1,
+                    column:  content[line_start..position].chars().count(),
+                    message: if note.is_empty() {
+                        format!("{marker}\nResolve the marker or track the work in an issue")
+                    } else {
+                        format!(
+                            "{marker}: {note}\nResolve the marker or track the work in an issue"
+                        )
+                    },
+                    fix:     Fix::None
+                });
+            }
+        }
+
+        Ok(AnalysisResult {
+            issues,
+            fixable_count: 0
+        })
+    }
+
+    fn opt_in(&self) -> bool {
+        true
+    }
+}
+
+impl Default for TodoCommentsAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Byte ranges of all comments in a file, string literals excluded.
+///
+/// Line comments run to the end of their line, block comments to the
+/// matching `*/` (nested block comments are honoured).
+fn comments(content: &str) -> Vec<Range<usize>> {
+    let literals = literal_ranges(content);
+    let bytes = content.as_bytes();
+    let mut comments = Vec::new();
+    let mut literals = literals.into_iter().peekable();
+    let mut index = 0;
+
+    while index + 1 < bytes.len() {
+        while literals.next_if(|literal| literal.end <= index).is_some() {}
+        if let Some(literal) = literals.peek()
+            && literal.start <= index
+        {
+            index = literal.end;
+            continue;
+        }
+
+        match &bytes[index..index + 2] {
+            b"//" => {
+                let end = content[index..]
+                    .find('\n')
+                    .map_or(content.len(), |idx| index + idx);
+                comments.push(index..end);
+                index = end;
+            }
+            b"/*" => {
+                let end = block_comment_end(content, index);
+                comments.push(index..end);
+                index = end;
+            }
+            _ => index += 1
+        }
+    }
+
+    comments
+}
+
+/// Byte offset just past the `*/` closing a block comment at `start`.
+fn block_comment_end(content: &str, start: usize) -> usize {
+    let bytes = content.as_bytes();
+    let mut depth = 0;
+    let mut index = start;
+
+    while index + 1 < bytes.len() {
+        match &bytes[index..index + 2] {
+            b"/*" => {
+                depth += 1;
+                index += 2;
+            }
+            b"*/" => {
+                depth -= 1;
+                index += 2;
+                if depth == 0 {
+                    return index;
+                }
+            }
+            _ => index += 1
+        }
+    }
+
+    content.len()
+}
+
+/// Find markers in a comment.
+///
+/// A marker is a whole uppercase word followed by `:`, `(` or the end of
+/// the line (`TODO: retry`, `FIXME(alice)`), or the first word of a plain
+/// comment (`// TODO retry`). Doc comments need the explicit form, so prose
+/// that merely mentions a marker is not reported.
+///
+/// # Returns
+///
+/// Byte offset within the comment and the marker found there
+///
+/// # Notes
+///
+/// - Markers quoted in a code span or string are examples, not work
+fn find_markers(text: &str) -> Vec<(usize, &'static str)> {
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+    let is_doc = ["///", "//!", "/**", "/*!"]
+        .iter()
+        .any(|prefix| text.starts_with(prefix));
+    let mut found = Vec::new();
+
+    for marker in MARKERS {
+        for (offset, _) in text.match_indices(marker) {
+            let before = text[..offset].chars().next_back();
+            let rest = &text[offset + marker.len()..];
+            if before.is_some_and(is_word) || rest.starts_with(is_word) {
+                continue;
+            }
+
+            let line_start = text[..offset].rfind('\n').map_or(0, |idx| idx + 1);
+            let quoted = text[line_start..offset]
+                .chars()
+                .filter(|c| matches!(c, '`' | '"'))
+                .count()
+                % 2
+                == 1;
+            if quoted {
+                continue;
+            }
+            let first_word = text[line_start..offset]
+                .trim_start_matches(['/', '*', '!', ' ', '\t'])
+                .is_empty();
+            let explicit = rest.starts_with([':', '('])
+                || rest.lines().next().unwrap_or_default().trim().is_empty()
+                || rest.trim_start().starts_with("*/");
+            if explicit || (first_word && !is_doc) {
+                found.push((offset, *marker));
+            }
+        }
+    }
+
+    found.sort_unstable();
+    found
+}
+
+/// Text following a marker, up to the end of its line.
+///
+/// Strips an `(owner)` tag, separators and a trailing block comment close.
+fn marker_note(rest: &str) -> String {
+    let line = rest.lines().next().unwrap_or_default();
+    let line = line.trim_end().trim_end_matches("*/");
+    let line = match line.strip_prefix('(') {
+        Some(tagged) => tagged.split_once(')').map_or(line, |(_, rest)| rest),
+        None => line
+    };
+
+    line.trim_start_matches([':', '-', ' ', '\t'])
+        .trim()
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analyze(code: &str) -> AnalysisResult {
+        let ast = syn::parse_file(code).unwrap();
+        TodoCommentsAnalyzer::new().analyze(&ast, code).unwrap()
+    }
+
+    fn notes(result: &AnalysisResult) -> Vec<&str> {
+        result
+            .issues
+            .iter()
+            .map(|issue| issue.message.lines().next().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        let analyzer = TodoCommentsAnalyzer::new();
+        assert_eq!(analyzer.name(), "todo_comments");
+        assert!(analyzer.opt_in());
+    }
+
+    #[test]
+    fn test_collect_markers_with_text() {
+        let result = analyze(
+            r#"
+// TODO: retry on timeout
+fn fetch() {
+    let x = 1; // FIXME(alice) - overflow on 32-bit
+    /* HACK: until upstream fix */
+}
+
+/// XXX
+fn helper() {}
+"#
+        );
+
+        assert_eq!(
+            notes(&result),
+            [
+                "TODO: retry on timeout",
+                "FIXME: overflow on 32-bit",
+                "HACK: until upstream fix",
+                "XXX"
+            ]
+        );
+        assert_eq!(result.issues[0].line, 2);
+        assert_eq!(result.issues[0].column, 3);
+        assert_eq!(result.issues[1].line, 4);
+    }
+
+    #[test]
+    fn test_ignore_strings_and_partial_words() {
+        let result = analyze(
+            r#"
+// TODOS are plural, HACKY is an adjective, the XXXL size is fine
+//! Lists TODO and FIXME markers.
+/// The `TODO` analyzer, configured by `// TODO: text` examples.
+fn main() {
+    println!("TODO: not a comment // FIXME neither");
+    let raw = r"/* HACK */";
+    let c = '/';
+}
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_plain_comment_without_colon() {
+        let result = analyze("// TODO retry on timeout\n/// TODO handled later\nfn main() {}\n");
+        assert_eq!(notes(&result), ["TODO: retry on timeout"]);
+    }
+
+    #[test]
+    fn test_nested_block_comments() {
+        let result = analyze("/* outer /* inner */ TODO: after inner */\nfn main() {}\n");
+        assert_eq!(notes(&result), ["TODO: after inner"]);
+    }
+
+    #[test]
+    fn test_default_implementation() {
+        let analyzer = TodoCommentsAnalyzer;
+        assert_eq!(analyzer.name(), "todo_comments");
+    }
+}
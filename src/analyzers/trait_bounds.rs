@@ -0,0 +1,321 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Trait bounds analyzer for overly long generic bounds and where-clauses.
+//!
+//! Signatures like `fn run<S, T>(..) where S: Clone + Send + Sync + 'static +
+//! Debug, T: ...` bury the parameters under their constraints. This analyzer
+//! reports functions and impl blocks with more than [`MAX_TRAIT_BOUNDS`]
+//! trait bounds, or whose generics and where-clause together are longer than
+//! [`MAX_BOUNDS_LENGTH`] characters.
+
+use masterror::AppResult;
+use proc_macro2::Span;
+use syn::{
+    File, GenericParam, Generics, ImplItemFn, ItemFn, ItemImpl, ItemMod, TraitItemFn,
+    TypeParamBound, WherePredicate, spanned::Spanned, visit::Visit
+};
+
+use super::is_test_code;
+use crate::analyzer::{AnalysisResult, Analyzer, Fix, Issue};
+
+/// Maximum number of trait bounds on one function or impl block.
+pub const MAX_TRAIT_BOUNDS: usize = 5;
+
+/// Maximum length in characters of generic parameters and where-clause.
+pub const MAX_BOUNDS_LENGTH: usize = 80;
+
+/// Analyzer for functions and impls with too many or too long bounds.
+///
+/// # Examples
+///
+/// Detects this pattern:
+/// ```ignore
+/// fn spawn_all<S, T>(services: S, tasks: T)
+/// where
+///     S: IntoIterator + Clone + Send + Sync + 'static,
+///     T: Iterator + Send + Debug
+/// { ... }
+/// ```
+///
+/// Suggests a helper trait with a blanket implementation:
+/// ```ignore
+/// trait Shared: Clone + Send + Sync + 'static {}
+/// impl<T: Clone + Send + Sync + 'static> Shared for T {}
+///
+/// fn spawn_all<S: IntoIterator + Shared, T: Iterator + Send + Debug>(..) { ... }
+/// ```
+pub struct TraitBoundsAnalyzer;
+
+impl TraitBoundsAnalyzer {
+    /// Create new trait bounds analyzer instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Analyzer for TraitBoundsAnalyzer {
+    fn name(&self) -> &'static str {
+        "trait_bounds"
+    }
+
+    fn analyze(&self, ast: &File, content: &str) -> AppResult<AnalysisResult> {
+        let mut visitor = BoundsVisitor {
+            issues: Vec::new(),
+            content
+        };
+        visitor.visit_file(ast);
+
+        Ok(AnalysisResult {
+            issues:        visitor.issues,
+            fixable_count: 0
+        })
+    }
+}
+
+impl Default for TraitBoundsAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct BoundsVisitor<'a> {
+    issues:  Vec<Issue>,
+    content: &'a str
+}
+
+impl BoundsVisitor<'_> {
+    /// Report generics with too many trait bounds or too much text.
+    ///
+    /// # Arguments
+    ///
+    /// * `subject` - How the item is named in the message
+    /// * `span` - Position the issue is reported at
+    /// * `generics` - Generic parameters and where-clause of the item
+    fn check(&mut self, subject: String, span: Span, generics: &Generics) {
+        let bounds = count_trait_bounds(generics);
+        let length = self.rendered_length(generics);
+
+        let problem = if bounds > MAX_TRAIT_BOUNDS {
+            format!("has {bounds} trait bounds (max {MAX_TRAIT_BOUNDS})")
+        } else if length > MAX_BOUNDS_LENGTH {
+            format!("has generic bounds {length} characters long (max {MAX_BOUNDS_LENGTH})")
+        } else {
+            return;
+        };
+
+        let start = span.start();
+        self.issues.push(Issue {
+            line:    start.line,
+            column:  start.column,
+            message: format!(
+                "{subject} {problem}\n\
+                 Name repeated bound combinations with a helper trait and a blanket impl, \
+                 or a trait alias"
+            ),
+            fix:     Fix::None
+        });
+    }
+
+    /// Length of the generic parameters and where-predicates as written,
+    /// with runs of whitespace counted as one character.
+    fn rendered_length(&self, generics: &Generics) -> usize {
+        let params = generics.params.iter().map(Spanned::span);
+        let predicates = generics
+            .where_clause
+            .iter()
+            .flat_map(|clause| clause.predicates.iter().map(Spanned::span));
+
+        params
+            .chain(predicates)
+            .filter_map(|span| self.content.get(span.byte_range()))
+            .map(|text| text.split_whitespace().collect::<Vec<_>>().join(" ").len())
+            .sum()
+    }
+}
+
+impl<'ast> Visit<'ast> for BoundsVisitor<'_> {
+    fn visit_item_fn(&mut self, node: &'ast ItemFn) {
+        if is_test_code(&node.attrs) {
+            return;
+        }
+        let subject = format!("Function `{}`", node.sig.ident);
+        self.check(subject, node.sig.ident.span(), &node.sig.generics);
+        syn::visit::visit_item_fn(self, node);
+    }
+
+    fn visit_item_impl(&mut self, node: &'ast ItemImpl) {
+        let subject = match &node.trait_ {
+            Some((_, path, _)) => format!(
+                "Impl of `{}`",
+                path.segments
+                    .last()
+                    .map(|segment| segment.ident.to_string())
+                    .unwrap_or_default()
+            ),
+            None => "Impl block".to_string()
+        };
+        self.check(subject, node.impl_token.span, &node.generics);
+        syn::visit::visit_item_impl(self, node);
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'ast ImplItemFn) {
+        if is_test_code(&node.attrs) {
+            return;
+        }
+        let subject = format!("Method `{}`", node.sig.ident);
+        self.check(subject, node.sig.ident.span(), &node.sig.generics);
+        syn::visit::visit_impl_item_fn(self, node);
+    }
+
+    fn visit_trait_item_fn(&mut self, node: &'ast TraitItemFn) {
+        let subject = format!("Method `{}`", node.sig.ident);
+        self.check(subject, node.sig.ident.span(), &node.sig.generics);
+        syn::visit::visit_trait_item_fn(self, node);
+    }
+
+    fn visit_item_mod(&mut self, node: &'ast ItemMod) {
+        if !is_test_code(&node.attrs) {
+            syn::visit::visit_item_mod(self, node);
+        }
+    }
+}
+
+/// Count trait bounds on type parameters and in the where-clause.
+///
+/// Lifetime bounds such as `'static` are not counted.
+fn count_trait_bounds(generics: &Generics) -> usize {
+    let traits = |bound: &&TypeParamBound| matches!(bound, TypeParamBound::Trait(_));
+
+    let on_params: usize = generics
+        .params
+        .iter()
+        .map(|param| match param {
+            GenericParam::Type(param) => param.bounds.iter().filter(traits).count(),
+            _ => 0
+        })
+        .sum();
+    let in_where: usize = generics
+        .where_clause
+        .iter()
+        .flat_map(|clause| clause.predicates.iter())
+        .map(|predicate| match predicate {
+            WherePredicate::Type(predicate) => predicate.bounds.iter().filter(traits).count(),
+            _ => 0
+        })
+        .sum();
+
+    on_params + in_where
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analyze(code: &str) -> AnalysisResult {
+        let ast = syn::parse_file(code).unwrap();
+        TraitBoundsAnalyzer::new().analyze(&ast, code).unwrap()
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        let analyzer = TraitBoundsAnalyzer::new();
+        assert_eq!(analyzer.name(), "trait_bounds");
+    }
+
+    #[test]
+    fn test_detect_too_many_bounds() {
+        let result = analyze(
+            r#"
+fn spawn_all<S: Clone, T>(services: S, tasks: T)
+where
+    S: IntoIterator + Send + Sync + 'static,
+    T: Iterator + Send
+{
+}
+"#
+        );
+
+        assert_eq!(result.issues.len(), 1);
+        assert_eq!(result.issues[0].line, 2);
+        assert!(
+            result.issues[0]
+                .message
+                .contains("Function `spawn_all` has 6 trait bounds (max 5)")
+        );
+    }
+
+    #[test]
+    fn test_detect_long_bounds() {
+        let result = analyze(
+            r#"
+impl<K, V> Cache for Store<K, V>
+where
+    K: std::hash::Hash + std::cmp::Eq + std::fmt::Debug,
+    V: serde::Serialize + for<'de> serde::Deserialize<'de>
+{
+}
+"#
+        );
+
+        assert_eq!(result.issues.len(), 1);
+        assert!(
+            result.issues[0]
+                .message
+                .contains("Impl of `Cache` has generic bounds")
+        );
+    }
+
+    #[test]
+    fn test_method_bounds_checked_separately() {
+        let result = analyze(
+            r#"
+impl<T: Clone + Send> Queue<T> {
+    fn push_all<I: IntoIterator<Item = T> + ExactSizeIterator + Debug + Send + Sync + Unpin>(&self, items: I) {}
+}
+
+trait Sink {
+    fn write<B: AsRef<[u8]> + Send + Sync + Unpin + Debug + Clone>(&self, buf: B);
+}
+"#
+        );
+
+        assert_eq!(result.issues.len(), 2);
+        assert!(result.issues[0].message.contains("Method `push_all`"));
+        assert!(result.issues[1].message.contains("6 trait bounds"));
+    }
+
+    #[test]
+    fn test_allow_short_bounds() {
+        let result = analyze(
+            r#"
+fn run<T: Clone + Send + Sync + 'static>(task: T) {}
+
+impl<T> Display for Wrapper<T> where T: Display {}
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_ignore_test_code() {
+        let result = analyze(
+            r#"
+#[cfg(test)]
+mod tests {
+    fn check<A: Clone + Copy + Debug, B: Clone + Copy + Debug>() {}
+}
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_default_implementation() {
+        let analyzer = TraitBoundsAnalyzer;
+        assert_eq!(analyzer.name(), "trait_bounds");
+    }
+}
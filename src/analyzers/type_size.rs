@@ -0,0 +1,189 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Type size analyzer for enums with many variants and structs with many
+//! fields.
+//!
+//! A struct with a dozen fields usually bundles several concepts that would
+//! be clearer as nested types, and an enum with dozens of variants tends to
+//! grow `match` arms everywhere it is used. This analyzer reports enums with
+//! more than 20 variants and structs with more than 12 fields.
+
+use masterror::AppResult;
+use proc_macro2::Ident;
+use syn::{File, ItemEnum, ItemMod, ItemStruct, visit::Visit};
+
+use super::is_test_code;
+use crate::analyzer::{AnalysisResult, Analyzer, Fix, Issue};
+
+/// Maximum number of variants of an enum.
+const MAX_VARIANTS: usize = 20;
+
+/// Maximum number of fields of a struct.
+const MAX_FIELDS: usize = 12;
+
+/// Analyzer for oversized enums and structs.
+///
+/// # Examples
+///
+/// Detects a struct mixing connection, retry and logging settings in 15
+/// flat fields, and suggests grouping them:
+/// ```ignore
+/// pub struct ClientConfig {
+///     connection: ConnectionConfig,
+///     retry:      RetryPolicy,
+///     logging:    LogConfig
+/// }
+/// ```
+pub struct TypeSizeAnalyzer;
+
+impl TypeSizeAnalyzer {
+    /// Create new type size analyzer instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Analyzer for TypeSizeAnalyzer {
+    fn name(&self) -> &'static str {
+        "type_size"
+    }
+
+    fn analyze(&self, ast: &File, _content: &str) -> AppResult<AnalysisResult> {
+        let mut visitor = TypeSizeVisitor {
+            issues: Vec::new()
+        };
+        visitor.visit_file(ast);
+
+        Ok(AnalysisResult {
+            issues:        visitor.issues,
+            fixable_count: 0
+        })
+    }
+}
+
+impl Default for TypeSizeAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct TypeSizeVisitor {
+    issues: Vec<Issue>
+}
+
+impl TypeSizeVisitor {
+    /// Report a type at its name.
+    fn push(&mut self, ident: &Ident, message: String) {
+        let start = ident.span().start();
+        self.issues.push(Issue {
+            line: start.line,
+            column: start.column,
+            message,
+            fix: Fix::None
+        });
+    }
+}
+
+impl<'ast> Visit<'ast> for TypeSizeVisitor {
+    fn visit_item_enum(&mut self, node: &'ast ItemEnum) {
+        let count = node.variants.len();
+        if count > MAX_VARIANTS && !is_test_code(&node.attrs) {
+            self.push(
+                &node.ident,
+                format!(
+                    "Enum `{}` has {count} variants, over the limit of {MAX_VARIANTS}\n\
+                     Group related variants into nested enums",
+                    node.ident
+                )
+            );
+        }
+    }
+
+    fn visit_item_struct(&mut self, node: &'ast ItemStruct) {
+        let count = node.fields.len();
+        if count > MAX_FIELDS && !is_test_code(&node.attrs) {
+            self.push(
+                &node.ident,
+                format!(
+                    "Struct `{}` has {count} fields, over the limit of {MAX_FIELDS}\n\
+                     Group related fields into their own types",
+                    node.ident
+                )
+            );
+        }
+    }
+
+    fn visit_item_mod(&mut self, node: &'ast ItemMod) {
+        if !is_test_code(&node.attrs) {
+            syn::visit::visit_item_mod(self, node);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analyze(code: &str) -> AnalysisResult {
+        let ast = syn::parse_file(code).unwrap();
+        TypeSizeAnalyzer::new().analyze(&ast, code).unwrap()
+    }
+
+    fn fields(count: usize) -> String {
+        (0..count).map(|i| format!("f{i}: u8, ")).collect()
+    }
+
+    fn variants(count: usize) -> String {
+        (0..count).map(|i| format!("V{i}, ")).collect()
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        let analyzer = TypeSizeAnalyzer::new();
+        assert_eq!(analyzer.name(), "type_size");
+    }
+
+    #[test]
+    fn test_detect_oversized_types() {
+        let code = format!(
+            "struct Wide {{ {} }}\nenum Many {{ {} }}\nstruct Tuple({});\n",
+            fields(13),
+            variants(21),
+            "u8, ".repeat(13)
+        );
+        let result = analyze(&code);
+
+        assert_eq!(result.issues.len(), 3);
+        assert!(
+            result.issues[0]
+                .message
+                .contains("Struct `Wide` has 13 fields, over the limit of 12")
+        );
+        assert_eq!(result.issues[1].line, 2);
+        assert!(
+            result.issues[1]
+                .message
+                .contains("Enum `Many` has 21 variants, over the limit of 20")
+        );
+    }
+
+    #[test]
+    fn test_accept_types_at_limit() {
+        let code = format!(
+            "struct Wide {{ {} }}\nenum Many {{ {} }}\n\
+             #[cfg(test)]\nmod tests {{ struct Fixture {{ {} }} }}\n",
+            fields(12),
+            variants(20),
+            fields(30)
+        );
+        assert!(analyze(&code).issues.is_empty());
+    }
+
+    #[test]
+    fn test_default_implementation() {
+        let analyzer = TypeSizeAnalyzer;
+        assert_eq!(analyzer.name(), "type_size");
+    }
+}
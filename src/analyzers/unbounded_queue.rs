@@ -0,0 +1,436 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Unbounded queue analyzer for unbounded memory growth.
+//!
+//! In long-running services an unbounded channel or a buffer that only grows
+//! turns a slow consumer into an out-of-memory crash instead of
+//! backpressure. This analyzer flags unbounded channel constructors and
+//! `Vec`/`VecDeque` buffers pushed to in long-running loops without ever
+//! being drained.
+
+use std::collections::HashSet;
+
+use masterror::AppResult;
+use syn::{
+    Block, Expr, ExprBreak, ExprCall, ExprClosure, ExprLoop, ExprMethodCall, ExprWhile, File,
+    ImplItemFn, Item, ItemFn, ItemMod, Member, spanned::Spanned, visit::Visit
+};
+
+use super::is_test_code;
+use crate::analyzer::{AnalysisResult, Analyzer, Fix, Issue};
+
+/// Methods that add an element to a buffer.
+const GROWING_METHODS: &[&str] = &["push", "push_back", "push_front", "extend"];
+
+/// Methods that remove elements from a buffer.
+const DRAINING_METHODS: &[&str] = &[
+    "pop",
+    "pop_back",
+    "pop_front",
+    "drain",
+    "clear",
+    "truncate",
+    "remove",
+    "swap_remove",
+    "retain",
+    "split_off",
+    "dedup"
+];
+
+/// Calls that wait for the next work item of a long-running `while let`.
+const RECEIVE_METHODS: &[&str] = &["recv", "accept", "next", "recv_async"];
+
+/// Analyzer for unbounded channels and ever-growing buffers.
+///
+/// # Examples
+///
+/// Detects this pattern:
+/// ```ignore
+/// let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+/// ```
+///
+/// Suggests a bounded channel:
+/// ```ignore
+/// let (tx, rx) = tokio::sync::mpsc::channel(1024);
+/// ```
+pub struct UnboundedQueueAnalyzer;
+
+impl UnboundedQueueAnalyzer {
+    /// Create new unbounded queue analyzer instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Analyzer for UnboundedQueueAnalyzer {
+    fn name(&self) -> &'static str {
+        "unbounded_queue"
+    }
+
+    fn analyze(&self, ast: &File, _content: &str) -> AppResult<AnalysisResult> {
+        let mut visitor = QueueVisitor {
+            issues: Vec::new()
+        };
+        visitor.visit_file(ast);
+
+        Ok(AnalysisResult {
+            issues:        visitor.issues,
+            fixable_count: 0
+        })
+    }
+}
+
+impl Default for UnboundedQueueAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct QueueVisitor {
+    issues: Vec<Issue>
+}
+
+impl QueueVisitor {
+    /// Flag buffers that grow inside long-running loops of a function body.
+    fn check_body(&mut self, block: &Block) {
+        let mut loops = LongRunningLoops {
+            bodies: Vec::new()
+        };
+        loops.visit_block(block);
+        if loops.bodies.is_empty() {
+            return;
+        }
+
+        let mut drained = BufferCalls {
+            methods:   DRAINING_METHODS,
+            receivers: Vec::new()
+        };
+        drained.visit_block(block);
+        let drained: HashSet<String> = drained
+            .receivers
+            .into_iter()
+            .map(|(receiver, _)| receiver)
+            .collect();
+
+        let mut reported = HashSet::new();
+        for body in loops.bodies {
+            let mut grown = BufferCalls {
+                methods:   GROWING_METHODS,
+                receivers: Vec::new()
+            };
+            grown.visit_block(body);
+
+            for (receiver, call) in grown.receivers {
+                if drained.contains(&receiver) || !reported.insert(receiver.clone()) {
+                    continue;
+                }
+
+                let start = call.method.span().start();
+                self.issues.push(Issue {
+                    line:    start.line,
+                    column:  start.column,
+                    message: format!(
+                        "Unbounded growth: `{receiver}` grows on every iteration of a \
+                         long-running loop and is never drained\n\
+                         Cap its size or hand items to a bounded channel"
+                    ),
+                    fix:     Fix::None
+                });
+            }
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for QueueVisitor {
+    fn visit_item_fn(&mut self, node: &'ast ItemFn) {
+        if !is_test_code(&node.attrs) {
+            self.check_body(&node.block);
+            syn::visit::visit_item_fn(self, node);
+        }
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'ast ImplItemFn) {
+        if !is_test_code(&node.attrs) {
+            self.check_body(&node.block);
+            syn::visit::visit_impl_item_fn(self, node);
+        }
+    }
+
+    fn visit_item_mod(&mut self, node: &'ast ItemMod) {
+        if !is_test_code(&node.attrs) {
+            syn::visit::visit_item_mod(self, node);
+        }
+    }
+
+    fn visit_expr_call(&mut self, node: &'ast ExprCall) {
+        if let Some(suggestion) = unbounded_channel(node) {
+            let start = node.span().start();
+            self.issues.push(Issue {
+                line:    start.line,
+                column:  start.column,
+                message: format!(
+                    "Unbounded channel: a slow consumer lets the queue grow without limit\n\
+                     Use {suggestion} so senders get backpressure"
+                ),
+                fix:     Fix::None
+            });
+        }
+        syn::visit::visit_expr_call(self, node);
+    }
+}
+
+/// Collects the bodies of loops that run for the lifetime of a service.
+///
+/// These are `loop` without any `break`, and `while let` loops that wait on
+/// `recv()`, `accept()` or `next()`.
+struct LongRunningLoops<'ast> {
+    bodies: Vec<&'ast Block>
+}
+
+impl<'ast> Visit<'ast> for LongRunningLoops<'ast> {
+    fn visit_expr_loop(&mut self, node: &'ast ExprLoop) {
+        let mut breaks = HasBreak {
+            found: false
+        };
+        breaks.visit_block(&node.body);
+        if !breaks.found {
+            self.bodies.push(&node.body);
+        }
+        syn::visit::visit_expr_loop(self, node);
+    }
+
+    fn visit_expr_while(&mut self, node: &'ast ExprWhile) {
+        if let Expr::Let(cond) = node.cond.as_ref() {
+            let mut receives = BufferCalls {
+                methods:   RECEIVE_METHODS,
+                receivers: Vec::new()
+            };
+            receives.visit_expr(&cond.expr);
+            if !receives.receivers.is_empty() {
+                self.bodies.push(&node.body);
+            }
+        }
+        syn::visit::visit_expr_while(self, node);
+    }
+
+    fn visit_item(&mut self, _node: &'ast Item) {}
+}
+
+struct HasBreak {
+    found: bool
+}
+
+impl<'ast> Visit<'ast> for HasBreak {
+    fn visit_expr_break(&mut self, _node: &'ast ExprBreak) {
+        self.found = true;
+    }
+
+    fn visit_expr_closure(&mut self, _node: &'ast ExprClosure) {}
+
+    fn visit_item(&mut self, _node: &'ast Item) {}
+}
+
+/// Collects calls to a set of methods, keyed by the rendered receiver.
+struct BufferCalls<'ast> {
+    methods:   &'static [&'static str],
+    receivers: Vec<(String, &'ast ExprMethodCall)>
+}
+
+impl<'ast> Visit<'ast> for BufferCalls<'ast> {
+    fn visit_expr_method_call(&mut self, node: &'ast ExprMethodCall) {
+        if self.methods.contains(&node.method.to_string().as_str())
+            && let Some(receiver) = render_place(&node.receiver)
+        {
+            self.receivers.push((receiver, node));
+        }
+        syn::visit::visit_expr_method_call(self, node);
+    }
+
+    fn visit_item(&mut self, _node: &'ast Item) {}
+}
+
+/// Render a place expression such as `buf` or `self.buffer`.
+///
+/// # Returns
+///
+/// `Some(text)` for variables and field paths, `None` for other expressions
+fn render_place(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::Path(path) => path.path.get_ident().map(ToString::to_string),
+        Expr::Field(field) => {
+            let base = render_place(&field.base)?;
+            let member = match &field.member {
+                Member::Named(ident) => ident.to_string(),
+                Member::Unnamed(index) => index.index.to_string()
+            };
+            Some(format!("{base}.{member}"))
+        }
+        Expr::Paren(paren) => render_place(&paren.expr),
+        _ => None
+    }
+}
+
+/// Check whether a call constructs an unbounded channel.
+///
+/// # Returns
+///
+/// `Some(suggestion)` naming the bounded replacement, `None` otherwise
+fn unbounded_channel(call: &ExprCall) -> Option<&'static str> {
+    let Expr::Path(path) = call.func.as_ref() else {
+        return None;
+    };
+    let segments: Vec<String> = path
+        .path
+        .segments
+        .iter()
+        .map(|seg| seg.ident.to_string())
+        .collect();
+    let last = segments.last()?.as_str();
+
+    match last {
+        "unbounded_channel" => Some("`mpsc::channel(capacity)`"),
+        "unbounded" if call.args.is_empty() => Some("`bounded(capacity)`"),
+        "channel" if call.args.is_empty() && segments.iter().any(|seg| seg == "mpsc") => {
+            Some("`mpsc::sync_channel(capacity)`")
+        }
+        _ => None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::parse_quote;
+
+    use super::*;
+
+    fn analyze(code: File) -> AnalysisResult {
+        UnboundedQueueAnalyzer::new().analyze(&code, "").unwrap()
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        let analyzer = UnboundedQueueAnalyzer::new();
+        assert_eq!(analyzer.name(), "unbounded_queue");
+    }
+
+    #[test]
+    fn test_detect_unbounded_channels() {
+        let result = analyze(parse_quote! {
+            fn setup() {
+                let (a, b) = tokio::sync::mpsc::unbounded_channel::<Job>();
+                let (c, d) = crossbeam::channel::unbounded();
+                let (e, f) = flume::unbounded::<u8>();
+                let (g, h) = std::sync::mpsc::channel();
+            }
+        });
+
+        assert_eq!(result.issues.len(), 4);
+        assert!(result.issues[0].message.contains("mpsc::channel(capacity)"));
+        assert!(result.issues[3].message.contains("sync_channel"));
+    }
+
+    #[test]
+    fn test_ignore_bounded_channels() {
+        let result = analyze(parse_quote! {
+            fn setup() {
+                let (a, b) = tokio::sync::mpsc::channel(64);
+                let (c, d) = crossbeam::channel::bounded(64);
+                let (e, f) = std::sync::mpsc::sync_channel(8);
+            }
+        });
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_detect_growing_buffer_in_endless_loop() {
+        let result = analyze(parse_quote! {
+            fn run(listener: Listener) {
+                let mut seen = Vec::new();
+                loop {
+                    let conn = listener.accept();
+                    seen.push(conn.peer());
+                }
+            }
+        });
+
+        assert_eq!(result.issues.len(), 1);
+        assert!(result.issues[0].message.contains("`seen`"));
+    }
+
+    #[test]
+    fn test_detect_field_buffer_in_receive_loop() {
+        let result = analyze(parse_quote! {
+            impl Worker {
+                async fn run(&mut self) {
+                    while let Some(event) = self.rx.recv().await {
+                        self.history.push_back(event);
+                    }
+                }
+            }
+        });
+
+        assert_eq!(result.issues.len(), 1);
+        assert!(result.issues[0].message.contains("`self.history`"));
+    }
+
+    #[test]
+    fn test_ignore_drained_buffer() {
+        let result = analyze(parse_quote! {
+            fn run(rx: Receiver<Job>) {
+                let mut batch = Vec::new();
+                while let Ok(job) = rx.recv() {
+                    batch.push(job);
+                    if batch.len() >= 100 {
+                        flush(batch.drain(..));
+                    }
+                }
+            }
+        });
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_ignore_bounded_loops() {
+        let result = analyze(parse_quote! {
+            fn collect(items: &[u8]) -> Vec<u8> {
+                let mut out = Vec::new();
+                for item in items {
+                    out.push(*item);
+                }
+                loop {
+                    out.push(0);
+                    if out.len() > 10 {
+                        break;
+                    }
+                }
+                out
+            }
+        });
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_ignore_test_code() {
+        let result = analyze(parse_quote! {
+            #[cfg(test)]
+            mod tests {
+                fn helper() {
+                    let (tx, rx) = tokio::sync::mpsc::unbounded_channel::<u8>();
+                }
+            }
+        });
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_default_implementation() {
+        let analyzer = UnboundedQueueAnalyzer;
+        assert_eq!(analyzer.name(), "unbounded_queue");
+    }
+}
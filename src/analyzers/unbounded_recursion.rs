@@ -0,0 +1,358 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Unbounded recursion analyzer for self-recursive functions without a
+//! depth limit.
+//!
+//! A function that calls itself once per level of its input uses one stack
+//! frame per level, and the main thread's stack holds only a few thousand.
+//! A directory tree with a symlink loop, a deeply nested JSON document or a
+//! long linked list turns into a stack overflow, which aborts the process
+//! instead of returning an error. This analyzer flags functions that call
+//! themselves directly, by name, as `Self::name(..)` or as `self.name(..)`,
+//! and take no parameter bounding the depth, such as `depth`, `level` or
+//! `limit`. The issue points at the first recursive call.
+
+use std::mem;
+
+use masterror::AppResult;
+use syn::{
+    Expr, ExprCall, ExprMethodCall, File, FnArg, Ident, ImplItemFn, ItemFn, ItemMod, Pat,
+    Signature, TraitItemFn, spanned::Spanned, visit::Visit
+};
+
+use super::is_test_code;
+use crate::analyzer::{AnalysisResult, Analyzer, Confidence, Fix, Issue};
+
+/// Parameter name parts that bound the recursion depth.
+const DEPTH_PARAMS: &[&str] = &[
+    "depth",
+    "level",
+    "limit",
+    "remaining",
+    "budget",
+    "fuel",
+    "max",
+    "ttl"
+];
+
+/// Analyzer for self-recursive functions without a depth limit.
+///
+/// # Examples
+///
+/// Detects this pattern:
+/// ```ignore
+/// fn collect(dir: &Path, files: &mut Vec<PathBuf>) {
+///     for entry in read_dir(dir) {
+///         collect(&entry.path(), files);
+///     }
+/// }
+/// ```
+///
+/// Suggests an explicit stack or a depth guard:
+/// ```ignore
+/// fn collect(root: &Path, files: &mut Vec<PathBuf>) {
+///     let mut stack = vec![root.to_path_buf()];
+///     while let Some(dir) = stack.pop() {
+///         stack.extend(read_dir(&dir).map(|entry| entry.path()));
+///     }
+/// }
+/// ```
+pub struct UnboundedRecursionAnalyzer;
+
+impl UnboundedRecursionAnalyzer {
+    /// Create new unbounded recursion analyzer instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Analyzer for UnboundedRecursionAnalyzer {
+    fn name(&self) -> &'static str {
+        "unbounded_recursion"
+    }
+
+    fn analyze(&self, ast: &File, _content: &str) -> AppResult<AnalysisResult> {
+        let mut visitor = RecursionVisitor {
+            current: None,
+            issues:  Vec::new()
+        };
+        visitor.visit_file(ast);
+
+        Ok(AnalysisResult {
+            issues:        visitor.issues,
+            fixable_count: 0
+        })
+    }
+
+    fn confidence(&self) -> Confidence {
+        Confidence::Medium
+    }
+}
+
+impl Default for UnboundedRecursionAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Function being visited.
+struct Frame {
+    name:   Ident,
+    /// Whether the function takes `self`, so `self.name(..)` recurses
+    method: bool,
+    /// Line and column of each recursive call
+    calls:  Vec<(usize, usize)>
+}
+
+struct RecursionVisitor {
+    /// Function being visited, `None` outside functions and for functions
+    /// that bound their depth
+    current: Option<Frame>,
+    issues:  Vec<Issue>
+}
+
+impl RecursionVisitor {
+    /// Visit a function body and report its recursive calls.
+    fn check_fn(&mut self, sig: &Signature, visit: impl FnOnce(&mut Self)) {
+        let frame = (!has_depth_param(sig)).then(|| Frame {
+            name:   sig.ident.clone(),
+            method: matches!(sig.inputs.first(), Some(FnArg::Receiver(_))),
+            calls:  Vec::new()
+        });
+
+        let outer = mem::replace(&mut self.current, frame);
+        visit(self);
+        let frame = mem::replace(&mut self.current, outer);
+
+        let Some(frame) = frame else {
+            return;
+        };
+        let Some(&(line, column)) = frame.calls.first() else {
+            return;
+        };
+        let others = match frame.calls.len() {
+            1 => String::new(),
+            2 => " (and 1 more call)".to_string(),
+            n => format!(" (and {} more calls)", n - 1)
+        };
+        self.issues.push(Issue {
+            line,
+            column,
+            message: format!(
+                "Function `{}` calls itself without a depth limit{others}\nDeep input overflows \
+                 the stack: traverse iteratively with an explicit stack, or pass a `depth` and \
+                 stop at a maximum",
+                frame.name
+            ),
+            fix: Fix::None
+        });
+    }
+
+    /// Record a recursive call.
+    fn record(&mut self, expr: &Expr) {
+        if let Some(frame) = &mut self.current {
+            let start = expr.span().start();
+            frame.calls.push((start.line, start.column));
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for RecursionVisitor {
+    fn visit_item_fn(&mut self, node: &'ast ItemFn) {
+        if !is_test_code(&node.attrs) {
+            self.check_fn(&node.sig, |visitor| {
+                syn::visit::visit_item_fn(visitor, node);
+            });
+        }
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'ast ImplItemFn) {
+        if !is_test_code(&node.attrs) {
+            self.check_fn(&node.sig, |visitor| {
+                syn::visit::visit_impl_item_fn(visitor, node);
+            });
+        }
+    }
+
+    fn visit_trait_item_fn(&mut self, node: &'ast TraitItemFn) {
+        self.check_fn(&node.sig, |visitor| {
+            syn::visit::visit_trait_item_fn(visitor, node);
+        });
+    }
+
+    fn visit_item_mod(&mut self, node: &'ast ItemMod) {
+        if !is_test_code(&node.attrs) {
+            syn::visit::visit_item_mod(self, node);
+        }
+    }
+
+    fn visit_expr_call(&mut self, node: &'ast ExprCall) {
+        if let (Some(frame), Expr::Path(path)) = (&self.current, node.func.as_ref()) {
+            let segments: Vec<&Ident> = path
+                .path
+                .segments
+                .iter()
+                .map(|segment| &segment.ident)
+                .collect();
+            let recursive = match segments.as_slice() {
+                [name] => **name == frame.name,
+                [owner, name] => *owner == "Self" && **name == frame.name,
+                _ => false
+            };
+            if recursive {
+                self.record(&node.func);
+            }
+        }
+        syn::visit::visit_expr_call(self, node);
+    }
+
+    fn visit_expr_method_call(&mut self, node: &'ast ExprMethodCall) {
+        if let Some(frame) = &self.current
+            && frame.method
+            && node.method == frame.name
+            && matches!(node.receiver.as_ref(), Expr::Path(path) if path.path.is_ident("self"))
+        {
+            self.record(&node.receiver);
+        }
+        syn::visit::visit_expr_method_call(self, node);
+    }
+}
+
+/// Check whether a function takes a parameter bounding its depth.
+fn has_depth_param(sig: &Signature) -> bool {
+    sig.inputs.iter().any(|input| {
+        let FnArg::Typed(param) = input else {
+            return false;
+        };
+        let Pat::Ident(ident) = param.pat.as_ref() else {
+            return false;
+        };
+        let name = ident.ident.to_string().to_lowercase();
+        DEPTH_PARAMS.iter().any(|part| name.contains(part))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analyze(code: &str) -> AnalysisResult {
+        let ast = syn::parse_file(code).unwrap();
+        UnboundedRecursionAnalyzer::new()
+            .analyze(&ast, code)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        let analyzer = UnboundedRecursionAnalyzer::new();
+        assert_eq!(analyzer.name(), "unbounded_recursion");
+        assert_eq!(analyzer.confidence(), Confidence::Medium);
+    }
+
+    #[test]
+    fn test_detect_self_recursion() {
+        let result = analyze(
+            r#"
+fn collect(dir: &Path, files: &mut Vec<PathBuf>) {
+    for entry in read_dir(dir) {
+        collect(&entry.path(), files);
+    }
+}
+
+impl Node {
+    fn sum(&self) -> u64 {
+        self.value + self.children.iter().map(|child| child.sum()).sum::<u64>() + self.sum()
+    }
+
+    fn walk(node: &Node) {
+        Self::walk(&node.left);
+        Self::walk(&node.right);
+    }
+}
+"#
+        );
+
+        assert_eq!(result.issues.len(), 3);
+        assert_eq!(result.fixable_count, 0);
+        assert_eq!(result.issues[0].line, 4);
+        assert_eq!(result.issues[0].column, 8);
+        assert!(result.issues[0].message.starts_with(
+            "Function `collect` calls itself without a depth limit\nDeep input overflows the \
+             stack"
+        ));
+        assert_eq!(result.issues[1].line, 10);
+        assert!(
+            result.issues[1]
+                .message
+                .starts_with("Function `sum` calls itself")
+        );
+        assert_eq!(result.issues[2].line, 14);
+        assert!(
+            result.issues[2].message.starts_with(
+                "Function `walk` calls itself without a depth limit (and 1 more call)"
+            )
+        );
+    }
+
+    #[test]
+    fn test_ignore_bounded_and_other_calls() {
+        let result = analyze(
+            r#"
+fn collect(dir: &Path, depth: usize) {
+    if depth < MAX_DEPTH {
+        collect(dir, depth + 1);
+    }
+}
+
+fn nest(value: &Value, max_level: u8) {
+    nest(value, max_level - 1);
+}
+
+fn visit_expr(&mut self, node: &Expr) {
+    syn::visit::visit_expr(self, node);
+    other.visit_expr(node);
+}
+
+fn outer() {
+    fn inner() {}
+    inner();
+}
+
+#[cfg(test)]
+mod tests {
+    fn fixture() {
+        fixture();
+    }
+}
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_nested_functions_checked_separately() {
+        let result = analyze(
+            r#"
+fn outer(depth: usize) {
+    fn inner(node: &Node) {
+        inner(node);
+    }
+    outer(depth - 1);
+}
+"#
+        );
+
+        assert_eq!(result.issues.len(), 1);
+        assert!(result.issues[0].message.starts_with("Function `inner`"));
+    }
+
+    #[test]
+    fn test_default_implementation() {
+        let analyzer = UnboundedRecursionAnalyzer;
+        assert_eq!(analyzer.name(), "unbounded_recursion");
+    }
+}
@@ -0,0 +1,313 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Unsafe docs analyzer for unsafe code without a safety justification.
+//!
+//! Every `unsafe` block relies on invariants the compiler cannot check, and
+//! every `unsafe fn` hands such invariants to its callers. Writing them down
+//! is what lets a reviewer verify the block and a caller uphold the
+//! contract. This analyzer reports `unsafe` blocks and `unsafe impl`s not
+//! preceded by a `// SAFETY:` comment, and `unsafe fn`s with neither a
+//! `# Safety` doc section nor such a comment.
+
+use masterror::AppResult;
+use proc_macro2::Span;
+use syn::{
+    Attribute, ExprUnsafe, File, ImplItemFn, ItemFn, ItemImpl, ItemMod, Signature, Stmt,
+    TraitItemFn, spanned::Spanned, visit::Visit
+};
+
+use super::{is_test_code, missing_docs::has_doc_section};
+use crate::analyzer::{AnalysisResult, Analyzer, Fix, Issue};
+
+/// Analyzer for unsafe code without a safety comment.
+///
+/// # Examples
+///
+/// Detects this pattern:
+/// ```ignore
+/// let value = unsafe { *pointer };
+/// ```
+///
+/// Suggests:
+/// ```ignore
+/// // SAFETY: `pointer` comes from `Box::into_raw` above and is not freed yet
+/// let value = unsafe { *pointer };
+/// ```
+pub struct UnsafeDocsAnalyzer;
+
+impl UnsafeDocsAnalyzer {
+    /// Create new unsafe docs analyzer instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Analyzer for UnsafeDocsAnalyzer {
+    fn name(&self) -> &'static str {
+        "unsafe_docs"
+    }
+
+    fn analyze(&self, ast: &File, content: &str) -> AppResult<AnalysisResult> {
+        let mut visitor = UnsafeVisitor {
+            lines:      content.lines().collect(),
+            statements: Vec::new(),
+            issues:     Vec::new()
+        };
+        visitor.visit_file(ast);
+
+        Ok(AnalysisResult {
+            issues:        visitor.issues,
+            fixable_count: 0
+        })
+    }
+}
+
+impl Default for UnsafeDocsAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct UnsafeVisitor<'a> {
+    lines:      Vec<&'a str>,
+    /// Start lines of the statements enclosing the current expression
+    statements: Vec<usize>,
+    issues:     Vec<Issue>
+}
+
+impl UnsafeVisitor<'_> {
+    /// Check whether the comment lines right above a line explain safety.
+    ///
+    /// Comments and attributes directly above `line` are searched for
+    /// `SAFETY:`, in any case, as is the text of the line before `column`.
+    fn has_safety_comment(&self, line: usize, column: usize) -> bool {
+        let mentions_safety = |text: &str| text.to_ascii_lowercase().contains("safety:");
+
+        let before = self
+            .lines
+            .get(line.wrapping_sub(1))
+            .map(|text| text.chars().take(column).collect::<String>());
+        if before.is_some_and(|text| text.contains("/*") && mentions_safety(&text)) {
+            return true;
+        }
+
+        self.lines[..line.saturating_sub(1).min(self.lines.len())]
+            .iter()
+            .rev()
+            .map(|text| text.trim_start())
+            .take_while(|text| {
+                text.starts_with("//")
+                    || text.starts_with("/*")
+                    || text.starts_with('*')
+                    || text.starts_with("#[")
+            })
+            .any(mentions_safety)
+    }
+
+    /// Report unsafe code at a span.
+    fn push(&mut self, span: Span, message: String) {
+        let start = span.start();
+        self.issues.push(Issue {
+            line: start.line,
+            column: start.column,
+            message,
+            fix: Fix::None
+        });
+    }
+
+    /// Report an `unsafe fn` documented neither by a `# Safety` section nor
+    /// a `SAFETY:` comment.
+    fn check_fn(&mut self, attrs: &[Attribute], sig: &Signature) {
+        let Some(unsafety) = sig.unsafety else {
+            return;
+        };
+        let start = attrs
+            .first()
+            .map_or_else(|| sig.span().start(), |attr| attr.span().start());
+        if has_doc_section(attrs, "Safety") || self.has_safety_comment(start.line, start.column) {
+            return;
+        }
+
+        self.push(
+            unsafety.span,
+            format!(
+                "Unsafe function `{}` does not document its safety contract\n\
+                 Add a `# Safety` doc section listing what callers must guarantee",
+                sig.ident
+            )
+        );
+    }
+}
+
+impl<'ast> Visit<'ast> for UnsafeVisitor<'_> {
+    fn visit_stmt(&mut self, node: &'ast Stmt) {
+        self.statements.push(node.span().start().line);
+        syn::visit::visit_stmt(self, node);
+        self.statements.pop();
+    }
+
+    fn visit_expr_unsafe(&mut self, node: &'ast ExprUnsafe) {
+        let start = node.unsafe_token.span.start();
+        let documented = self.has_safety_comment(start.line, start.column)
+            || self
+                .statements
+                .last()
+                .is_some_and(|&line| line != start.line && self.has_safety_comment(line, 0));
+        if !documented {
+            self.push(
+                node.unsafe_token.span,
+                "Unsafe block has no `// SAFETY:` comment\n\
+                 Explain above the block why the invariants it relies on hold"
+                    .to_string()
+            );
+        }
+        syn::visit::visit_expr_unsafe(self, node);
+    }
+
+    fn visit_item_fn(&mut self, node: &'ast ItemFn) {
+        if is_test_code(&node.attrs) {
+            return;
+        }
+        self.check_fn(&node.attrs, &node.sig);
+        syn::visit::visit_item_fn(self, node);
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'ast ImplItemFn) {
+        if is_test_code(&node.attrs) {
+            return;
+        }
+        self.check_fn(&node.attrs, &node.sig);
+        syn::visit::visit_impl_item_fn(self, node);
+    }
+
+    fn visit_trait_item_fn(&mut self, node: &'ast TraitItemFn) {
+        self.check_fn(&node.attrs, &node.sig);
+        syn::visit::visit_trait_item_fn(self, node);
+    }
+
+    fn visit_item_impl(&mut self, node: &'ast ItemImpl) {
+        if is_test_code(&node.attrs) {
+            return;
+        }
+        if let Some(unsafety) = node.unsafety {
+            let start = node
+                .attrs
+                .first()
+                .map_or_else(|| unsafety.span.start(), |attr| attr.span().start());
+            if !self.has_safety_comment(start.line, start.column) {
+                self.push(
+                    unsafety.span,
+                    "Unsafe impl has no `// SAFETY:` comment\n\
+                     Explain above the impl why the type upholds the trait's contract"
+                        .to_string()
+                );
+            }
+        }
+        syn::visit::visit_item_impl(self, node);
+    }
+
+    fn visit_item_mod(&mut self, node: &'ast ItemMod) {
+        if !is_test_code(&node.attrs) {
+            syn::visit::visit_item_mod(self, node);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analyze(code: &str) -> AnalysisResult {
+        let ast = syn::parse_file(code).unwrap();
+        UnsafeDocsAnalyzer::new().analyze(&ast, code).unwrap()
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        let analyzer = UnsafeDocsAnalyzer::new();
+        assert_eq!(analyzer.name(), "unsafe_docs");
+    }
+
+    #[test]
+    fn test_detect_undocumented_unsafe() {
+        let result = analyze(
+            r#"
+fn read(pointer: *const u8) -> u8 {
+    // Read the value
+    let value = unsafe { *pointer };
+    value
+}
+
+/// Reads a byte.
+pub unsafe fn read_raw(pointer: *const u8) -> u8 {
+    todo!()
+}
+
+unsafe impl Send for Handle {}
+"#
+        );
+
+        assert_eq!(result.issues.len(), 3);
+        assert_eq!(result.issues[0].line, 4);
+        assert!(
+            result.issues[0]
+                .message
+                .contains("Unsafe block has no `// SAFETY:` comment")
+        );
+        assert_eq!(result.issues[1].line, 9);
+        assert!(
+            result.issues[1]
+                .message
+                .contains("Unsafe function `read_raw` does not document")
+        );
+        assert!(result.issues[2].message.contains("Unsafe impl"));
+    }
+
+    #[test]
+    fn test_accept_documented_unsafe() {
+        let result = analyze(
+            r#"
+fn read(pointer: *const u8) -> (u8, u8) {
+    // SAFETY: the caller passes a pointer from `Box::into_raw`
+    let first = unsafe { *pointer };
+    // Safety: same pointer as above
+    let second =
+        unsafe { *pointer };
+    let third = /* SAFETY: still valid */ unsafe { *pointer };
+    (first, second)
+}
+
+/// Reads a byte.
+///
+/// # Safety
+///
+/// `pointer` must be valid for reads.
+#[inline]
+pub unsafe fn read_raw(pointer: *const u8) -> u8 {
+    todo!()
+}
+
+// SAFETY: the handle is only accessed behind a mutex
+#[allow(unused)]
+unsafe impl Send for Handle {}
+
+#[cfg(test)]
+mod tests {
+    fn fixture(pointer: *const u8) -> u8 {
+        unsafe { *pointer }
+    }
+}
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_default_implementation() {
+        let analyzer = UnsafeDocsAnalyzer;
+        assert_eq!(analyzer.name(), "unsafe_docs");
+    }
+}
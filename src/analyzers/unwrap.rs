@@ -0,0 +1,221 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Unwrap analyzer for panicking error handling in non-test code.
+//!
+//! `.unwrap()` and `.expect()` turn recoverable errors into panics. Outside
+//! tests, errors should be propagated with `?` so callers decide how to
+//! handle them.
+
+use masterror::AppResult;
+use syn::{ExprMethodCall, File, ImplItemFn, ItemFn, ItemMod, visit::Visit};
+
+use super::is_test_code;
+use crate::analyzer::{AnalysisResult, Analyzer, Fix, Issue};
+
+/// Analyzer for `.unwrap()` and `.expect()` outside test code.
+///
+/// # Examples
+///
+/// Detects this pattern:
+/// ```ignore
+/// fn load(path: &Path) -> Config {
+///     let content = fs::read_to_string(path).unwrap();
+///     toml::from_str(&content).expect("valid config")
+/// }
+/// ```
+///
+/// Suggests propagating the error:
+/// ```ignore
+/// fn load(path: &Path) -> AppResult<Config> {
+///     let content = fs::read_to_string(path).map_err(IoError::from)?;
+///     Ok(toml::from_str(&content).map_err(ParseError::from)?)
+/// }
+/// ```
+pub struct UnwrapAnalyzer;
+
+impl UnwrapAnalyzer {
+    /// Create new unwrap analyzer instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Analyzer for UnwrapAnalyzer {
+    fn name(&self) -> &'static str {
+        "unwrap"
+    }
+
+    fn analyze(&self, ast: &File, _content: &str) -> AppResult<AnalysisResult> {
+        let mut visitor = UnwrapVisitor {
+            issues: Vec::new()
+        };
+        visitor.visit_file(ast);
+
+        Ok(AnalysisResult {
+            issues:        visitor.issues,
+            fixable_count: 0
+        })
+    }
+}
+
+impl Default for UnwrapAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct UnwrapVisitor {
+    issues: Vec<Issue>
+}
+
+impl<'ast> Visit<'ast> for UnwrapVisitor {
+    fn visit_item_fn(&mut self, node: &'ast ItemFn) {
+        if !is_test_code(&node.attrs) {
+            syn::visit::visit_item_fn(self, node);
+        }
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'ast ImplItemFn) {
+        if !is_test_code(&node.attrs) {
+            syn::visit::visit_impl_item_fn(self, node);
+        }
+    }
+
+    fn visit_item_mod(&mut self, node: &'ast ItemMod) {
+        if !is_test_code(&node.attrs) {
+            syn::visit::visit_item_mod(self, node);
+        }
+    }
+
+    fn visit_expr_method_call(&mut self, node: &'ast ExprMethodCall) {
+        let method = node.method.to_string();
+        let panics = matches!(
+            (method.as_str(), node.args.len()),
+            ("unwrap", 0) | ("expect", 1)
+        );
+
+        if panics {
+            let start = node.method.span().start();
+            self.issues.push(Issue {
+                line:    start.line,
+                column:  start.column,
+                message: format!(
+                    "`.{method}()` in non-test code panics on error\n\
+                     Propagate the error with `?` (e.g. into a masterror `AppResult`) or handle \
+                     it explicitly"
+                ),
+                fix:     Fix::None
+            });
+        }
+
+        syn::visit::visit_expr_method_call(self, node);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::parse_quote;
+
+    use super::*;
+
+    fn analyze(code: File) -> AnalysisResult {
+        UnwrapAnalyzer::new().analyze(&code, "").unwrap()
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        let analyzer = UnwrapAnalyzer::new();
+        assert_eq!(analyzer.name(), "unwrap");
+    }
+
+    #[test]
+    fn test_detect_unwrap_and_expect() {
+        let result = analyze(parse_quote! {
+            fn load(path: &Path) -> Config {
+                let content = fs::read_to_string(path).unwrap();
+                toml::from_str(&content).expect("valid config")
+            }
+        });
+
+        assert_eq!(result.issues.len(), 2);
+        assert!(result.issues[0].message.contains("`.unwrap()`"));
+        assert!(result.issues[1].message.contains("`.expect()`"));
+    }
+
+    #[test]
+    fn test_detect_chained_calls() {
+        let result = analyze(parse_quote! {
+            fn first(items: &[Option<u8>]) -> u8 {
+                items.first().unwrap().unwrap()
+            }
+        });
+
+        assert_eq!(result.issues.len(), 2);
+    }
+
+    #[test]
+    fn test_detect_in_methods() {
+        let result = analyze(parse_quote! {
+            impl Store {
+                fn get(&self) -> Value {
+                    self.map.lock().unwrap().clone()
+                }
+            }
+        });
+
+        assert_eq!(result.issues.len(), 1);
+    }
+
+    #[test]
+    fn test_ignore_non_panicking_variants() {
+        let result = analyze(parse_quote! {
+            fn port() -> u16 {
+                let a = env::var("PORT").ok().and_then(|v| v.parse().ok()).unwrap_or(8080);
+                let b = value.unwrap_or_default();
+                let c = value.unwrap_or_else(|| 1);
+                a + b + c
+            }
+        });
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_ignore_test_code() {
+        let result = analyze(parse_quote! {
+            #[cfg(test)]
+            mod tests {
+                fn helper() -> u8 {
+                    parse("1").unwrap()
+                }
+            }
+
+            #[test]
+            fn standalone() {
+                parse("1").expect("parses");
+            }
+        });
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_fixable_count_is_zero() {
+        let result = analyze(parse_quote! {
+            fn main() {
+                run().unwrap();
+            }
+        });
+
+        assert_eq!(result.issues.len(), 1);
+        assert_eq!(result.fixable_count, 0);
+    }
+
+    #[test]
+    fn test_default_implementation() {
+        let analyzer = UnwrapAnalyzer;
+        assert_eq!(analyzer.name(), "unwrap");
+    }
+}
@@ -0,0 +1,683 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Variant names analyzer for enum variants with redundant prefixes.
+//!
+//! Variants are always written qualified or matched in the context of their
+//! enum, so `ErrorKind::ErrorNotFound` says "error" twice. Following the Rust
+//! API guidelines, this analyzer flags variants that repeat the enum name,
+//! and variants of enums with at least [`MIN_SHARED_VARIANTS`] variants that
+//! all start with the same word. Private enums are renamed automatically,
+//! together with every `Enum::Variant` and `Self::Variant` path in the file,
+//! as one edit per enum. The fix is only offered when every use is such a
+//! path in this file: a file importing from the enum (`use Kind::*`) uses
+//! variants unqualified, and a file declaring `mod child;` has uses in other
+//! files.
+
+use std::{collections::HashSet, ops::Range};
+
+use masterror::AppResult;
+use proc_macro2::{TokenStream, TokenTree};
+use quote::ToTokens;
+use syn::{File, ItemEnum, ItemImpl, ItemMod, Type, UsePath, Visibility, visit::Visit};
+
+use super::is_test_code;
+use crate::analyzer::{AnalysisResult, Analyzer, Fix, Issue, Suggestion, TextEdit};
+
+/// Minimum number of variants before a shared prefix is reported.
+pub const MIN_SHARED_VARIANTS: usize = 3;
+
+/// Analyzer for enum variants repeating the enum name or a common prefix.
+///
+/// # Examples
+///
+/// Detects this pattern:
+/// ```ignore
+/// enum ErrorKind {
+///     ErrorNotFound,
+///     ErrorTimeout
+/// }
+/// ```
+///
+/// Fixes to:
+/// ```ignore
+/// enum ErrorKind {
+///     NotFound,
+///     Timeout
+/// }
+/// ```
+pub struct VariantNamesAnalyzer;
+
+/// Variant whose name should be trimmed.
+struct Rename {
+    /// Index of the enum in the file, grouping the edits of its variants
+    enum_index: usize,
+    enum_name:  String,
+    variant:    String,
+    trimmed:    String,
+    /// Repeated enum name or shared prefix, shown in the message
+    repeated:   Repeated,
+    line:       usize,
+    column:     usize,
+    /// Byte ranges of the definition and all uses, for file-local enums
+    /// with a valid trimmed name
+    edits:      Option<Vec<Range<usize>>>
+}
+
+/// Why a variant name is redundant.
+enum Repeated {
+    EnumName,
+    SharedPrefix(String)
+}
+
+impl VariantNamesAnalyzer {
+    /// Create new variant names analyzer instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Collect redundant variant names with their trimmed replacements.
+    fn collect(ast: &File) -> Vec<Rename> {
+        let mut visitor = VariantVisitor {
+            enums:   0,
+            renames: Vec::new()
+        };
+        visitor.visit_file(ast);
+
+        let mut scope = ScopeVisitor {
+            child_modules: false,
+            imported:      HashSet::new()
+        };
+        scope.visit_file(ast);
+
+        let tokens = ast.to_token_stream();
+        for rename in &mut visitor.renames {
+            if scope.child_modules || scope.imported.contains(&rename.enum_name) {
+                rename.edits = None;
+            }
+            if let Some(edits) = &mut rename.edits {
+                find_paths(tokens.clone(), &rename.enum_name, &rename.variant, edits);
+                let mut impls = ImplVisitor {
+                    enum_name: &rename.enum_name,
+                    variant: &rename.variant,
+                    edits
+                };
+                impls.visit_file(ast);
+            }
+        }
+
+        visitor.renames
+    }
+}
+
+impl Analyzer for VariantNamesAnalyzer {
+    fn name(&self) -> &'static str {
+        "variant_names"
+    }
+
+    fn analyze(&self, ast: &File, _content: &str) -> AppResult<AnalysisResult> {
+        let renames = Self::collect(ast);
+        let fixable_count = renames.iter().filter(|r| r.edits.is_some()).count();
+
+        let issues = renames
+            .into_iter()
+            .map(|rename| {
+                let reason = match &rename.repeated {
+                    Repeated::EnumName => "repeats the enum name".to_string(),
+                    Repeated::SharedPrefix(prefix) => {
+                        format!("repeats the prefix `{prefix}` shared by all variants")
+                    }
+                };
+                Issue {
+                    line:    rename.line,
+                    column:  rename.column,
+                    message: format!(
+                        "Variant `{}::{}` {reason}\n\
+                         Rename it to `{}` since it is always used qualified",
+                        rename.enum_name, rename.variant, rename.trimmed
+                    ),
+                    fix:     match rename.edits {
+                        Some(_) => Fix::Simple(rename.trimmed),
+                        None => Fix::None
+                    }
+                }
+            })
+            .collect();
+
+        Ok(AnalysisResult {
+            issues,
+            fixable_count
+        })
+    }
+
+    fn suggestions(&self, ast: &File, content: &str) -> AppResult<Vec<Suggestion>> {
+        let mut enums: Vec<Vec<(Range<usize>, String)>> = Vec::new();
+        let mut current = None;
+        for rename in Self::collect(ast) {
+            let Some(edits) = rename.edits else {
+                continue;
+            };
+            if current != Some(rename.enum_index) {
+                current = Some(rename.enum_index);
+                enums.push(Vec::new());
+            }
+            if let Some(group) = enums.last_mut() {
+                group.extend(
+                    edits
+                        .into_iter()
+                        .map(|range| (range, rename.trimmed.clone()))
+                );
+            }
+        }
+
+        Ok(enums
+            .into_iter()
+            .map(|group| Suggestion {
+                edit:   rename_edit(content, group),
+                import: None
+            })
+            .collect())
+    }
+
+    fn fixable(&self) -> bool {
+        true
+    }
+}
+
+impl Default for VariantNamesAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct VariantVisitor {
+    /// Number of enums visited so far
+    enums:   usize,
+    renames: Vec<Rename>
+}
+
+impl<'ast> Visit<'ast> for VariantVisitor {
+    /// Record the variants repeating the enum name or a shared prefix.
+    ///
+    /// # Notes
+    ///
+    /// - Renaming is only safe when every use is in this file and the new names
+    ///   stay distinct and valid
+    fn visit_item_enum(&mut self, node: &'ast ItemEnum) {
+        let enum_index = self.enums;
+        self.enums += 1;
+        let enum_name = node.ident.to_string();
+        let names: Vec<String> = node.variants.iter().map(|v| v.ident.to_string()).collect();
+        let shared = shared_prefix(&names);
+
+        let trimmed: Vec<Option<(String, Repeated)>> = names
+            .iter()
+            .map(|name| {
+                trim_enum_name(&enum_name, name)
+                    .map(|trimmed| (trimmed, Repeated::EnumName))
+                    .or_else(|| {
+                        shared.as_ref().map(|prefix| {
+                            let trimmed = name[prefix.len()..].to_string();
+                            (trimmed, Repeated::SharedPrefix(prefix.clone()))
+                        })
+                    })
+            })
+            .collect();
+
+        let final_names: Vec<String> = names
+            .iter()
+            .zip(&trimmed)
+            .map(|(name, trimmed)| trimmed.as_ref().map_or(name, |(t, _)| t).clone())
+            .collect();
+        let private = matches!(node.vis, Visibility::Inherited);
+
+        for ((variant, name), trimmed) in node.variants.iter().zip(&names).zip(trimmed) {
+            let Some((trimmed, repeated)) = trimmed else {
+                continue;
+            };
+            let valid = is_valid_name(&trimmed)
+                && final_names.iter().filter(|n| **n == trimmed).count() == 1;
+
+            let start = variant.ident.span().start();
+            self.renames.push(Rename {
+                enum_index,
+                enum_name: enum_name.clone(),
+                variant: name.clone(),
+                trimmed,
+                repeated,
+                line: start.line,
+                column: start.column,
+                edits: (private && valid).then(|| vec![variant.ident.span().byte_range()])
+            });
+        }
+    }
+
+    fn visit_item_mod(&mut self, node: &'ast ItemMod) {
+        if !is_test_code(&node.attrs) {
+            syn::visit::visit_item_mod(self, node);
+        }
+    }
+}
+
+/// Finds what makes uses of an enum possible outside `Enum::Variant` paths of
+/// the file.
+struct ScopeVisitor {
+    /// Whether the file declares out-of-line modules, whose files may use
+    /// the enum through `super::`
+    child_modules: bool,
+    /// Names imported from in `use` declarations, e.g. `Kind` for
+    /// `use Kind::*`
+    imported:      HashSet<String>
+}
+
+impl<'ast> Visit<'ast> for ScopeVisitor {
+    fn visit_item_mod(&mut self, node: &'ast ItemMod) {
+        if node.content.is_none() {
+            self.child_modules = true;
+        }
+        syn::visit::visit_item_mod(self, node);
+    }
+
+    fn visit_use_path(&mut self, node: &'ast UsePath) {
+        self.imported.insert(node.ident.to_string());
+        syn::visit::visit_use_path(self, node);
+    }
+}
+
+/// Finds `Self::Variant` paths inside impl blocks of the renamed enum.
+struct ImplVisitor<'a> {
+    enum_name: &'a str,
+    variant:   &'a str,
+    edits:     &'a mut Vec<Range<usize>>
+}
+
+impl<'ast> Visit<'ast> for ImplVisitor<'_> {
+    fn visit_item_impl(&mut self, node: &'ast ItemImpl) {
+        let implements_enum = match node.self_ty.as_ref() {
+            Type::Path(path) => path
+                .path
+                .segments
+                .last()
+                .is_some_and(|segment| segment.ident == self.enum_name),
+            _ => false
+        };
+        if implements_enum {
+            find_paths(node.to_token_stream(), "Self", self.variant, self.edits);
+        }
+        syn::visit::visit_item_impl(self, node);
+    }
+}
+
+/// Collect the byte ranges of `variant` in `qualifier::variant` paths.
+///
+/// # Arguments
+///
+/// * `tokens` - Tokens to scan, including macro arguments
+/// * `qualifier` - Path segment before the variant, e.g. the enum name
+/// * `variant` - Variant name to find
+/// * `edits` - Ranges receiving each occurrence
+fn find_paths(tokens: TokenStream, qualifier: &str, variant: &str, edits: &mut Vec<Range<usize>>) {
+    let tokens: Vec<TokenTree> = tokens.into_iter().collect();
+
+    for (index, token) in tokens.iter().enumerate() {
+        if let TokenTree::Group(group) = token {
+            find_paths(group.stream(), qualifier, variant, edits);
+        }
+
+        let [
+            TokenTree::Ident(first),
+            TokenTree::Punct(colon1),
+            TokenTree::Punct(colon2),
+            TokenTree::Ident(last)
+        ] = tokens.get(index..index + 4).unwrap_or_default()
+        else {
+            continue;
+        };
+        if first == qualifier
+            && colon1.as_char() == ':'
+            && colon2.as_char() == ':'
+            && last == variant
+        {
+            edits.push(last.span().byte_range());
+        }
+    }
+}
+
+/// Build a single edit renaming all variants of an enum at once.
+///
+/// Applying only some of the renames would leave uses of the old names, so
+/// the edit spans from the first to the last occurrence.
+///
+/// # Arguments
+///
+/// * `content` - Source of the file
+/// * `renames` - Byte range of each occurrence with its new name
+///
+/// # Returns
+///
+/// Edit replacing the spanned source with every occurrence renamed
+fn rename_edit(content: &str, mut renames: Vec<(Range<usize>, String)>) -> TextEdit {
+    renames.sort_by_key(|(range, _)| range.start);
+    renames.dedup_by_key(|(range, _)| range.start);
+
+    let start = renames.first().map_or(0, |(range, _)| range.start);
+    let end = renames.last().map_or(0, |(range, _)| range.end);
+    let mut replacement = String::new();
+    let mut copied = start;
+    for (range, name) in &renames {
+        replacement.push_str(&content[copied..range.start]);
+        replacement.push_str(name);
+        copied = range.end;
+    }
+
+    TextEdit {
+        range: start..end,
+        replacement
+    }
+}
+
+/// Trim leading words shared with the enum name from a variant name.
+///
+/// Both `Color::ColorRed` and `ErrorKind::ErrorNotFound` are trimmed. Only
+/// prefixes count: a suffix such as `Shell::PowerShell` is often part of a
+/// proper name.
+///
+/// # Returns
+///
+/// Trimmed name, or `None` if the variant does not repeat the enum name
+fn trim_enum_name(enum_name: &str, variant: &str) -> Option<String> {
+    let enum_words = camel_words(enum_name);
+    let variant_words = camel_words(variant);
+
+    let shared = enum_words
+        .iter()
+        .zip(&variant_words)
+        .take_while(|(a, b)| a == b)
+        .count();
+    (shared > 0 && shared < variant_words.len()).then(|| variant_words[shared..].concat())
+}
+
+/// Leading word shared by all variant names, if the enum is large enough.
+///
+/// # Returns
+///
+/// Shared prefix when every variant has more words after it
+fn shared_prefix(names: &[String]) -> Option<String> {
+    if names.len() < MIN_SHARED_VARIANTS {
+        return None;
+    }
+
+    let first = camel_words(names.first()?).first()?.to_string();
+    names
+        .iter()
+        .all(|name| {
+            let words = camel_words(name);
+            words.len() > 1 && words[0] == first
+        })
+        .then_some(first)
+}
+
+/// Split a CamelCase identifier into words, keeping acronyms together.
+///
+/// `HTTPErrorCode` splits into `HTTP`, `Error` and `Code`.
+pub(crate) fn camel_words(name: &str) -> Vec<&str> {
+    let chars: Vec<(usize, char)> = name.char_indices().collect();
+    let mut words = Vec::new();
+    let mut start = 0;
+
+    for window in 1..chars.len() {
+        let (index, current) = chars[window];
+        let previous = chars[window - 1].1;
+        let next_lower = chars.get(window + 1).is_some_and(|(_, c)| c.is_lowercase());
+        let boundary = current.is_uppercase()
+            && (previous.is_lowercase()
+                || previous.is_ascii_digit()
+                || (previous.is_uppercase() && next_lower));
+        if boundary {
+            words.push(&name[start..index]);
+            start = index;
+        }
+    }
+    if start < name.len() {
+        words.push(&name[start..]);
+    }
+
+    words
+}
+
+/// Check whether a trimmed name is a usable variant identifier.
+fn is_valid_name(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_uppercase()) && name != "Self"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analyze(code: &str) -> AnalysisResult {
+        let ast = syn::parse_file(code).unwrap();
+        VariantNamesAnalyzer::new().analyze(&ast, code).unwrap()
+    }
+
+    fn fix(code: &str) -> String {
+        let ast = syn::parse_file(code).unwrap();
+        let suggestions = VariantNamesAnalyzer::new().suggestions(&ast, code).unwrap();
+        crate::fixer::apply_suggestions(code, &suggestions).source
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        let analyzer = VariantNamesAnalyzer::new();
+        assert_eq!(analyzer.name(), "variant_names");
+    }
+
+    #[test]
+    fn test_camel_words() {
+        assert_eq!(camel_words("HTTPErrorCode"), ["HTTP", "Error", "Code"]);
+        assert_eq!(camel_words("ErrorNotFound"), ["Error", "Not", "Found"]);
+        assert_eq!(camel_words("V2Api"), ["V2", "Api"]);
+    }
+
+    #[test]
+    fn test_detect_repeated_enum_name() {
+        let result = analyze(
+            r#"
+pub enum ErrorKind {
+    ErrorNotFound,
+    Timeout,
+    ConnectionError
+}
+
+pub enum Shell {
+    Bash,
+    PowerShell
+}
+"#
+        );
+
+        assert_eq!(result.issues.len(), 1);
+        assert_eq!(result.fixable_count, 0);
+        assert_eq!(result.issues[0].line, 3);
+        assert!(
+            result.issues[0]
+                .message
+                .contains("`ErrorKind::ErrorNotFound` repeats the enum name")
+        );
+        assert!(result.issues[0].message.contains("Rename it to `NotFound`"));
+    }
+
+    #[test]
+    fn test_detect_shared_prefix() {
+        let result = analyze(
+            r#"
+enum Event {
+    OnClick,
+    OnHover,
+    OnScroll
+}
+
+enum Pair {
+    OnStart,
+    OnStop
+}
+"#
+        );
+
+        assert_eq!(result.issues.len(), 3);
+        assert_eq!(result.fixable_count, 3);
+        assert!(
+            result.issues[0]
+                .message
+                .contains("repeats the prefix `On` shared by all variants")
+        );
+    }
+
+    #[test]
+    fn test_fix_renames_private_enum_and_uses() {
+        let code = r#"enum ErrorKind {
+    ErrorNotFound,
+    Timeout
+}
+
+impl ErrorKind {
+    fn code(&self) -> u16 {
+        match self {
+            Self::ErrorNotFound => 404,
+            Self::Timeout => 408
+        }
+    }
+}
+
+fn fail() -> ErrorKind {
+    log!("{:?}", ErrorKind::ErrorNotFound);
+    ErrorKind::ErrorNotFound
+}
+"#;
+
+        assert_eq!(
+            fix(code),
+            r#"enum ErrorKind {
+    NotFound,
+    Timeout
+}
+
+impl ErrorKind {
+    fn code(&self) -> u16 {
+        match self {
+            Self::NotFound => 404,
+            Self::Timeout => 408
+        }
+    }
+}
+
+fn fail() -> ErrorKind {
+    log!("{:?}", ErrorKind::NotFound);
+    ErrorKind::NotFound
+}
+"#
+        );
+    }
+
+    #[test]
+    fn test_fix_is_one_edit_per_enum() {
+        let code = r#"enum Event {
+    OnClick,
+    OnHover,
+    OnScroll
+}
+
+fn handle(event: Event) -> bool {
+    matches!(event, Event::OnClick | Event::OnScroll)
+}
+"#;
+        let ast = syn::parse_file(code).unwrap();
+        let suggestions = VariantNamesAnalyzer::new().suggestions(&ast, code).unwrap();
+
+        assert_eq!(suggestions.len(), 1);
+        assert_eq!(
+            fix(code),
+            r#"enum Event {
+    Click,
+    Hover,
+    Scroll
+}
+
+fn handle(event: Event) -> bool {
+    matches!(event, Event::Click | Event::Scroll)
+}
+"#
+        );
+    }
+
+    #[test]
+    fn test_no_fix_when_uses_may_be_elsewhere() {
+        for code in [
+            "enum ErrorKind { ErrorNotFound, Timeout }
+use ErrorKind::*;
+fn f() -> ErrorKind { \
+             ErrorNotFound }",
+            "enum ErrorKind { ErrorNotFound, Timeout }
+fn f() { use self::ErrorKind::{ErrorNotFound \
+             as Missing}; }",
+            "enum ErrorKind { ErrorNotFound, Timeout }
+mod child;",
+            "enum ErrorKind { ErrorNotFound, Timeout }
+#[cfg(test)]
+mod tests {
+    use \
+             super::ErrorKind::*;
+}"
+        ] {
+            let result = analyze(code);
+            assert_eq!(result.issues.len(), 1, "{code}");
+            assert_eq!(result.fixable_count, 0, "{code}");
+            assert_eq!(fix(code), code);
+        }
+    }
+
+    #[test]
+    fn test_no_fix_for_conflicting_names() {
+        let result = analyze(
+            r#"
+enum Token {
+    TokenIdent,
+    Ident,
+    TokenSelf
+}
+"#
+        );
+
+        assert_eq!(result.issues.len(), 2);
+        assert_eq!(result.fixable_count, 0);
+    }
+
+    #[test]
+    fn test_ignore_clean_enums_and_tests() {
+        let result = analyze(
+            r#"
+enum Direction {
+    DirectUp,
+    Down
+}
+
+enum Status {
+    Status
+}
+
+#[cfg(test)]
+mod tests {
+    enum Mode { ModeFast, ModeSlow }
+}
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_default_implementation() {
+        let analyzer = VariantNamesAnalyzer;
+        assert_eq!(analyzer.name(), "variant_names");
+    }
+}
@@ -0,0 +1,225 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Whitespace analyzer for trailing spaces, tab indentation and a missing
+//! final newline.
+//!
+//! Stray whitespace shows up as noise in every diff that touches the line,
+//! and tab indentation renders differently in every editor. This analyzer
+//! reports trailing spaces and tabs, lines indented with tabs and files not
+//! ending with a newline, and fixes all three. Whitespace inside multi-line
+//! string literals is part of the value and left alone.
+
+use std::ops::Range;
+
+use masterror::AppResult;
+use syn::File;
+
+use super::literal_ranges;
+use crate::analyzer::{AnalysisResult, Analyzer, Fix, Issue, Suggestion, TextEdit};
+
+/// Columns a tab is replaced with, matching rustfmt's default `tab_spaces`.
+const TAB_WIDTH: usize = 4;
+
+/// Analyzer for trailing whitespace, tab indentation and missing final
+/// newlines.
+///
+/// # Examples
+///
+/// Detects these patterns (`·` marks a space, `→` a tab):
+/// ```ignore
+/// fn main() {··
+/// →   run();
+/// }
+/// ```
+///
+/// Fixes to:
+/// ```ignore
+/// fn main() {
+///         run();
+/// }
+/// ```
+pub struct WhitespaceAnalyzer;
+
+/// Whitespace problem found on a line.
+struct Finding {
+    line:    usize,
+    column:  usize,
+    message: &'static str,
+    edit:    TextEdit
+}
+
+impl WhitespaceAnalyzer {
+    /// Create new whitespace analyzer instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Find whitespace problems in a file.
+    fn collect(content: &str) -> Vec<Finding> {
+        let literals = literal_ranges(content);
+        let in_literal = |range: &Range<usize>| {
+            literals
+                .iter()
+                .any(|literal| literal.start < range.start && range.end < literal.end)
+        };
+
+        let mut findings = Vec::new();
+        let mut offset = 0;
+        for (index, raw) in content.split_inclusive('\n').enumerate() {
+            let line = raw.trim_end_matches('\n').trim_end_matches('\r');
+            let trimmed = line.trim_end_matches([' ', '\t']);
+            let indent = &trimmed[..trimmed.len() - trimmed.trim_start_matches([' ', '\t']).len()];
+
+            let trailing = offset + trimmed.len()..offset + line.len();
+            if !trailing.is_empty() && !in_literal(&trailing) {
+                findings.push(Finding {
+                    line:    index + 1,
+                    column:  trimmed.chars().count(),
+                    message: "Line has trailing whitespace\n\
+                              Remove the spaces and tabs at the end of the line",
+                    edit:    TextEdit {
+                        range:       trailing,
+                        replacement: String::new()
+                    }
+                });
+            }
+
+            let indentation = offset..offset + indent.len();
+            if indent.contains('\t') && !in_literal(&indentation) {
+                findings.push(Finding {
+                    line:    index + 1,
+                    column:  0,
+                    message: "Line is indented with tabs\nIndent with spaces as rustfmt does",
+                    edit:    TextEdit {
+                        range:       indentation,
+                        replacement: indent.replace('\t', &" ".repeat(TAB_WIDTH))
+                    }
+                });
+            }
+
+            offset += raw.len();
+        }
+
+        if !content.is_empty() && !content.ends_with('\n') {
+            let last_line = content.rsplit('\n').next().unwrap_or_default();
+            findings.push(Finding {
+                line:    content.matches('\n').count() + 1,
+                column:  last_line.chars().count(),
+                message: "File does not end with a newline\nAdd a line break after the last line",
+                edit:    TextEdit {
+                    range:       content.len()..content.len(),
+                    replacement: "\n".to_string()
+                }
+            });
+        }
+
+        findings
+    }
+}
+
+impl Analyzer for WhitespaceAnalyzer {
+    fn name(&self) -> &'static str {
+        "whitespace"
+    }
+
+    fn analyze(&self, _ast: &File, content: &str) -> AppResult<AnalysisResult> {
+        let issues: Vec<Issue> = Self::collect(content)
+            .into_iter()
+            .map(|finding| Issue {
+                line:    finding.line,
+                column:  finding.column,
+                message: finding.message.to_string(),
+                fix:     Fix::Simple(finding.edit.replacement)
+            })
+            .collect();
+
+        Ok(AnalysisResult {
+            fixable_count: issues.len(),
+            issues
+        })
+    }
+
+    fn suggestions(&self, _ast: &File, content: &str) -> AppResult<Vec<Suggestion>> {
+        Ok(Self::collect(content)
+            .into_iter()
+            .map(|finding| Suggestion {
+                edit:   finding.edit,
+                import: None
+            })
+            .collect())
+    }
+
+    fn fixable(&self) -> bool {
+        true
+    }
+}
+
+impl Default for WhitespaceAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analyze(code: &str) -> AnalysisResult {
+        let ast = syn::parse_file(code).unwrap();
+        WhitespaceAnalyzer::new().analyze(&ast, code).unwrap()
+    }
+
+    fn fix(code: &str) -> String {
+        let ast = syn::parse_file(code).unwrap();
+        let suggestions = WhitespaceAnalyzer::new().suggestions(&ast, code).unwrap();
+        crate::fixer::apply_suggestions(code, &suggestions).source
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        let analyzer = WhitespaceAnalyzer::new();
+        assert_eq!(analyzer.name(), "whitespace");
+    }
+
+    #[test]
+    fn test_detect_whitespace_problems() {
+        let result = analyze("fn main() {  \n\t\trun(); \t\n  \n}");
+
+        assert_eq!(result.issues.len(), 5);
+        assert_eq!(result.fixable_count, 5);
+        assert_eq!(result.issues[0].line, 1);
+        assert_eq!(result.issues[0].column, 11);
+        assert!(result.issues[0].message.contains("trailing whitespace"));
+        assert!(result.issues[2].message.contains("indented with tabs"));
+        assert_eq!(result.issues[3].line, 3);
+        assert_eq!(result.issues[4].line, 4);
+        assert!(
+            result.issues[4]
+                .message
+                .contains("does not end with a newline")
+        );
+    }
+
+    #[test]
+    fn test_accept_clean_file_and_literals() {
+        let code = "fn main() {\n    let text = \"first  \n\tsecond\";\n}\r\n";
+        assert!(analyze(code).issues.is_empty());
+    }
+
+    #[test]
+    fn test_fix_whitespace() {
+        assert_eq!(
+            fix("fn main() {  \n\t\trun(); \t\n  \n}"),
+            "fn main() {\n        run();\n\n}\n"
+        );
+        assert_eq!(fix("fn main() {}\r\n"), "fn main() {}\r\n");
+    }
+
+    #[test]
+    fn test_default_implementation() {
+        let analyzer = WhitespaceAnalyzer;
+        assert_eq!(analyzer.name(), "whitespace");
+    }
+}
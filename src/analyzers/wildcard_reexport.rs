@@ -0,0 +1,547 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Wildcard re-export analyzer for `pub use path::*;`.
+//!
+//! A wildcard re-export makes the public API implicit: every public item
+//! added to the source module silently becomes part of this module's API,
+//! and readers cannot tell what is exported without opening another file.
+//! This analyzer flags `pub use ...::*` outside test code and prelude
+//! modules. When the source is an enum or a module of the same crate whose
+//! items can be read, either inline or through `mod name;` next to the
+//! analyzed file, the fix replaces `*` with the list of its public items.
+
+use std::{
+    collections::BTreeSet,
+    fs,
+    ops::Range,
+    path::{Path, PathBuf}
+};
+
+use masterror::AppResult;
+use syn::{
+    Attribute, File, ImplItemFn, Item, ItemFn, ItemMod, ItemUse, UseTree, Visibility, visit::Visit
+};
+
+use super::is_test_code;
+use crate::analyzer::{AnalysisResult, Analyzer, Fix, Issue, Suggestion, TextEdit};
+
+/// Analyzer for wildcard re-exports such as `pub use module::*;`.
+///
+/// # Examples
+///
+/// Detects this pattern:
+/// ```ignore
+/// mod error;
+///
+/// pub use error::*;
+/// ```
+///
+/// Fixes to:
+/// ```ignore
+/// mod error;
+///
+/// pub use error::{Error, ErrorKind, Result};
+/// ```
+pub struct WildcardReexportAnalyzer;
+
+/// Wildcard re-export found outside test and prelude code.
+struct Reexport {
+    /// Path before the `*`, e.g. `self::error`
+    path:        String,
+    line:        usize,
+    column:      usize,
+    /// Byte range of the `*` token
+    range:       Range<usize>,
+    /// Name of a file-level item the re-export may target (`name::*` or
+    /// `self::name::*` outside inline modules)
+    target:      Option<String>,
+    /// Explicit item list replacing `*`, when the target's items are known
+    replacement: Option<String>
+}
+
+impl WildcardReexportAnalyzer {
+    /// Create new wildcard re-export analyzer instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Collect wildcard re-exports and resolve the ones with known targets.
+    ///
+    /// # Arguments
+    ///
+    /// * `ast` - Parsed file
+    /// * `file` - Path of the file, used to read `mod name;` modules
+    fn collect(ast: &File, file: Option<&Path>) -> Vec<Reexport> {
+        let mut visitor = ReexportVisitor {
+            reexports: Vec::new(),
+            mod_depth: 0,
+            prelude:   false
+        };
+        visitor.visit_file(ast);
+
+        for reexport in &mut visitor.reexports {
+            reexport.replacement = reexport
+                .target
+                .as_deref()
+                .and_then(|target| exported_items(ast, target, file))
+                .map(|items| match items.as_slice() {
+                    [single] => single.clone(),
+                    _ => format!("{{{}}}", items.join(", "))
+                });
+        }
+        visitor.reexports
+    }
+
+    /// Build the report for a set of re-exports.
+    fn report(reexports: Vec<Reexport>) -> AnalysisResult {
+        let mut issues = Vec::new();
+        let mut fixable_count = 0;
+
+        for reexport in reexports {
+            let path = &reexport.path;
+            let (hint, fix) = match reexport.replacement {
+                Some(items) => {
+                    fixable_count += 1;
+                    (format!("use `pub use {path}::{items}`"), Fix::Simple(items))
+                }
+                None => (
+                    format!("list the items re-exported from `{path}`"),
+                    Fix::None
+                )
+            };
+
+            issues.push(Issue {
+                line: reexport.line,
+                column: reexport.column,
+                message: format!(
+                    "Wildcard re-export `pub use {path}::*` makes the public API implicit\n\
+                     Re-export the items explicitly: {hint}"
+                ),
+                fix
+            });
+        }
+
+        AnalysisResult {
+            issues,
+            fixable_count
+        }
+    }
+
+    /// Build the edits replacing `*` with explicit item lists.
+    fn edits(reexports: Vec<Reexport>) -> Vec<Suggestion> {
+        reexports
+            .into_iter()
+            .filter_map(|reexport| {
+                Some(Suggestion {
+                    edit:   TextEdit {
+                        range:       reexport.range,
+                        replacement: reexport.replacement?
+                    },
+                    import: None
+                })
+            })
+            .collect()
+    }
+}
+
+impl Analyzer for WildcardReexportAnalyzer {
+    fn name(&self) -> &'static str {
+        "wildcard_reexport"
+    }
+
+    fn analyze(&self, ast: &File, _content: &str) -> AppResult<AnalysisResult> {
+        Ok(Self::report(Self::collect(ast, None)))
+    }
+
+    fn analyze_file(&self, path: &Path, ast: &File, _content: &str) -> AppResult<AnalysisResult> {
+        Ok(Self::report(Self::collect(ast, Some(path))))
+    }
+
+    fn suggestions(&self, ast: &File, _content: &str) -> AppResult<Vec<Suggestion>> {
+        Ok(Self::edits(Self::collect(ast, None)))
+    }
+
+    fn file_suggestions(
+        &self,
+        path: &Path,
+        ast: &File,
+        _content: &str
+    ) -> AppResult<Vec<Suggestion>> {
+        Ok(Self::edits(Self::collect(ast, Some(path))))
+    }
+
+    fn fixable(&self) -> bool {
+        true
+    }
+}
+
+impl Default for WildcardReexportAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct ReexportVisitor {
+    reexports: Vec<Reexport>,
+    /// Number of inline modules around the current item
+    mod_depth: usize,
+    /// Inside a module named `prelude`
+    prelude:   bool
+}
+
+impl ReexportVisitor {
+    /// Record wildcards in a use tree below the given path segments.
+    fn walk(&mut self, tree: &UseTree, segments: &mut Vec<String>) {
+        match tree {
+            UseTree::Path(path) => {
+                segments.push(path.ident.to_string());
+                self.walk(&path.tree, segments);
+                segments.pop();
+            }
+            UseTree::Group(group) => {
+                for item in &group.items {
+                    self.walk(item, segments);
+                }
+            }
+            UseTree::Glob(glob) if !segments.iter().any(|s| s == "prelude") => {
+                let start = glob.star_token.span.start();
+                let target = match segments.as_slice() {
+                    [name] => Some(name),
+                    [prefix, name] if prefix == "self" => Some(name),
+                    _ => None
+                }
+                .filter(|_| self.mod_depth == 0)
+                .cloned();
+
+                self.reexports.push(Reexport {
+                    path: segments.join("::"),
+                    line: start.line,
+                    column: start.column,
+                    range: glob.star_token.span.byte_range(),
+                    target,
+                    replacement: None
+                });
+            }
+            _ => {}
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for ReexportVisitor {
+    fn visit_item_fn(&mut self, node: &'ast ItemFn) {
+        if !is_test_code(&node.attrs) {
+            syn::visit::visit_item_fn(self, node);
+        }
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'ast ImplItemFn) {
+        if !is_test_code(&node.attrs) {
+            syn::visit::visit_impl_item_fn(self, node);
+        }
+    }
+
+    fn visit_item_mod(&mut self, node: &'ast ItemMod) {
+        if is_test_code(&node.attrs) {
+            return;
+        }
+
+        let outer = self.prelude;
+        self.prelude |= node.ident == "prelude";
+        self.mod_depth += 1;
+        syn::visit::visit_item_mod(self, node);
+        self.mod_depth -= 1;
+        self.prelude = outer;
+    }
+
+    fn visit_item_use(&mut self, node: &'ast ItemUse) {
+        if !self.prelude && matches!(node.vis, Visibility::Public(_)) {
+            self.walk(&node.tree, &mut Vec::new());
+        }
+    }
+}
+
+/// Names a wildcard re-export of a file-level enum or module exposes.
+///
+/// # Arguments
+///
+/// * `ast` - Parsed file holding the re-export
+/// * `target` - Name of the enum or module
+/// * `file` - Path of the file, to read `mod name;` modules
+///
+/// # Returns
+///
+/// Sorted item names, or `None` if the target is unknown or its exports
+/// cannot be listed safely
+fn exported_items(ast: &File, target: &str, file: Option<&Path>) -> Option<Vec<String>> {
+    let items = ast.items.iter().find_map(|item| match item {
+        Item::Enum(item) if item.ident == target => {
+            Some(item.variants.iter().map(|v| v.ident.to_string()).collect())
+        }
+        Item::Mod(item) if item.ident == target => match &item.content {
+            Some((_, content)) => public_items(content),
+            None => public_items(&read_module(file?, item)?.items)
+        },
+        _ => None
+    })?;
+
+    Some(items).filter(|items: &Vec<String>| !items.is_empty())
+}
+
+/// Public item names of a module.
+///
+/// Items a wildcard re-exports but an explicit list cannot reproduce, such
+/// as nested re-exports, restricted visibilities and `#[cfg]`-gated items,
+/// make the list unknown.
+///
+/// # Returns
+///
+/// Sorted names of the `pub` items, or `None` if the list would change what
+/// is exported
+fn public_items(items: &[Item]) -> Option<Vec<String>> {
+    let mut names = BTreeSet::new();
+
+    for item in items {
+        let (attrs, vis, ident) = match item {
+            Item::Const(item) => (&item.attrs, &item.vis, &item.ident),
+            Item::Enum(item) => (&item.attrs, &item.vis, &item.ident),
+            Item::Fn(item) => (&item.attrs, &item.vis, &item.sig.ident),
+            Item::Mod(item) => (&item.attrs, &item.vis, &item.ident),
+            Item::Static(item) => (&item.attrs, &item.vis, &item.ident),
+            Item::Struct(item) => (&item.attrs, &item.vis, &item.ident),
+            Item::Trait(item) => (&item.attrs, &item.vis, &item.ident),
+            Item::TraitAlias(item) => (&item.attrs, &item.vis, &item.ident),
+            Item::Type(item) => (&item.attrs, &item.vis, &item.ident),
+            Item::Union(item) => (&item.attrs, &item.vis, &item.ident),
+            Item::Use(item) if !matches!(item.vis, Visibility::Inherited) => return None,
+            Item::ExternCrate(item) if !matches!(item.vis, Visibility::Inherited) => return None,
+            _ => continue
+        };
+
+        match vis {
+            Visibility::Public(_) if is_cfg_gated(attrs) => return None,
+            Visibility::Public(_) => {
+                names.insert(ident.to_string());
+            }
+            Visibility::Restricted(_) => return None,
+            Visibility::Inherited => {}
+        }
+    }
+    Some(names.into_iter().collect())
+}
+
+/// Check whether an item is only compiled under some configuration.
+fn is_cfg_gated(attrs: &[Attribute]) -> bool {
+    attrs.iter().any(|attr| attr.path().is_ident("cfg"))
+}
+
+/// Read and parse the file of a `mod name;` declaration.
+///
+/// # Arguments
+///
+/// * `file` - Path of the file declaring the module
+/// * `item` - Module declaration
+///
+/// # Returns
+///
+/// Parsed module file, `None` if it uses `#[path]`, is missing or does not
+/// parse
+fn read_module(file: &Path, item: &ItemMod) -> Option<File> {
+    if item.attrs.iter().any(|attr| attr.path().is_ident("path")) {
+        return None;
+    }
+
+    let dir = module_dir(file)?;
+    let name = item.ident.to_string();
+    [
+        dir.join(format!("{name}.rs")),
+        dir.join(&name).join("mod.rs")
+    ]
+    .iter()
+    .find_map(|candidate| fs::read_to_string(candidate).ok())
+    .and_then(|content| syn::parse_file(&content).ok())
+}
+
+/// Directory holding the files of the modules a file declares.
+///
+/// `lib.rs`, `main.rs` and `mod.rs` declare modules next to themselves;
+/// `net.rs` declares them in `net/`.
+pub(crate) fn module_dir(file: &Path) -> Option<PathBuf> {
+    let parent = file.parent()?;
+    let stem = file.file_stem()?;
+
+    if stem == "lib" || stem == "main" || stem == "mod" {
+        Some(parent.to_path_buf())
+    } else {
+        Some(parent.join(stem))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::fixer::apply_suggestions;
+
+    fn analyze(code: &str) -> AnalysisResult {
+        let ast = syn::parse_file(code).unwrap();
+        WildcardReexportAnalyzer::new().analyze(&ast, code).unwrap()
+    }
+
+    fn fix(code: &str) -> String {
+        let ast = syn::parse_file(code).unwrap();
+        let suggestions = WildcardReexportAnalyzer::new()
+            .suggestions(&ast, code)
+            .unwrap();
+        apply_suggestions(code, &suggestions).source
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        let analyzer = WildcardReexportAnalyzer::new();
+        assert_eq!(analyzer.name(), "wildcard_reexport");
+    }
+
+    #[test]
+    fn test_detect_wildcard_reexports() {
+        let result = analyze(
+            r#"
+use std::collections::*;
+pub use serde::de::*;
+pub(crate) use helpers::*;
+pub use crate::{types::*, Config};
+"#
+        );
+
+        assert_eq!(result.issues.len(), 2);
+        assert_eq!(result.issues[0].line, 3);
+        assert_eq!(result.issues[0].column, 19);
+        assert!(
+            result.issues[0]
+                .message
+                .starts_with("Wildcard re-export `pub use serde::de::*`")
+        );
+        assert!(
+            result.issues[1]
+                .message
+                .contains("list the items re-exported from `crate::types`")
+        );
+        assert_eq!(result.fixable_count, 0);
+    }
+
+    #[test]
+    fn test_fix_inline_module_and_enum() {
+        let code = r#"
+pub mod shapes {
+    pub struct Circle;
+    pub trait Area {}
+    fn private() {}
+}
+
+pub enum Mode { Fast }
+
+pub use self::shapes::*;
+pub use Mode::*;
+"#;
+        let result = analyze(code);
+
+        assert_eq!(result.fixable_count, 2);
+        assert_eq!(
+            result.issues[0].fix,
+            Fix::Simple("{Area, Circle}".to_string())
+        );
+        let fixed = fix(code);
+        assert!(fixed.contains("pub use self::shapes::{Area, Circle};"));
+        assert!(fixed.contains("pub use Mode::Fast;"));
+    }
+
+    #[test]
+    fn test_no_fix_when_list_changes_exports() {
+        let result = analyze(
+            r#"
+mod nested {
+    pub use other::Thing;
+    pub struct Local;
+}
+
+mod gated {
+    #[cfg(feature = "extra")]
+    pub struct Extra;
+}
+
+mod restricted {
+    pub(crate) fn helper() {}
+}
+
+pub use nested::*;
+pub use gated::*;
+pub use restricted::*;
+"#
+        );
+
+        assert_eq!(result.issues.len(), 3);
+        assert_eq!(result.fixable_count, 0);
+    }
+
+    #[test]
+    fn test_fix_module_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let src = temp_dir.path().join("src");
+        fs::create_dir_all(src.join("net")).unwrap();
+        fs::write(
+            src.join("error.rs"),
+            "pub struct Error;\npub type Result<T> = std::result::Result<T, Error>;\n"
+        )
+        .unwrap();
+        fs::write(src.join("net/client.rs"), "pub fn connect() {}\n").unwrap();
+
+        let analyzer = WildcardReexportAnalyzer::new();
+        let lib = "mod error;\npub use error::*;\n";
+        let ast = syn::parse_file(lib).unwrap();
+        let suggestions = analyzer
+            .file_suggestions(&src.join("lib.rs"), &ast, lib)
+            .unwrap();
+        assert_eq!(
+            apply_suggestions(lib, &suggestions).source,
+            "mod error;\npub use error::{Error, Result};\n"
+        );
+
+        let net = "mod client;\npub use self::client::*;\n";
+        let ast = syn::parse_file(net).unwrap();
+        let result = analyzer
+            .analyze_file(&src.join("net.rs"), &ast, net)
+            .unwrap();
+        assert_eq!(result.issues[0].fix, Fix::Simple("connect".to_string()));
+
+        let result = analyzer.analyze(&ast, net).unwrap();
+        assert_eq!(result.fixable_count, 0);
+    }
+
+    #[test]
+    fn test_ignore_preludes_and_tests() {
+        let result = analyze(
+            r#"
+pub use rayon::prelude::*;
+
+pub mod prelude {
+    pub use crate::analyzers::*;
+}
+
+#[cfg(test)]
+mod tests {
+    pub use super::*;
+}
+"#
+        );
+
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_default_implementation() {
+        let analyzer = WildcardReexportAnalyzer;
+        assert_eq!(analyzer.name(), "wildcard_reexport");
+    }
+}
@@ -0,0 +1,73 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Stable data model for tools built on top of cargo-quality.
+//!
+//! IDE plugins, CI wrappers and the GitHub Action need the types describing
+//! analyzers, issues, fixes, reports and diffs, but not the internals of the
+//! `cargo-qual` binary. This module re-exports exactly those types. Everything
+//! reachable from here follows semver together with [`VERSION`]:
+//!
+//! - Removing or renaming an item, or changing a signature, bumps [`VERSION`]
+//!   and the crate's major version.
+//! - Types the tool may extend later, such as [`Fix`], [`Severity`],
+//!   [`Confidence`] and the diff results, are `#[non_exhaustive]`, so new
+//!   variants and fields land in minor releases. Match them with a wildcard arm
+//!   and read their fields instead of constructing them.
+//! - [`Issue`], [`AnalysisResult`], [`TextEdit`] and [`Suggestion`] stay
+//!   constructible with struct literals, since custom analyzers build them;
+//!   adding a field to them is a breaking change.
+//!
+//! Other public modules of the crate serve the binary and may change in any
+//! release.
+//!
+//! # Examples
+//!
+//! ```
+//! use cargo_quality::api::{self, Analyzer, Fix, Severity};
+//!
+//! assert_eq!(api::VERSION, 2);
+//!
+//! let code = "fn main() { let x = std::fs::read(\"file\"); }";
+//! let ast = syn::parse_file(code).unwrap();
+//! for analyzer in api::get_analyzers() {
+//!     let result = analyzer.analyze(&ast, code).unwrap();
+//!     for issue in &result.issues {
+//!         let label = match analyzer.severity() {
+//!             Severity::Error => "error",
+//!             _ => "warning"
+//!         };
+//!         let fixable = !matches!(issue.fix, Fix::None);
+//!         println!("{label}: {}:{} {fixable}", issue.line, issue.column);
+//!     }
+//! }
+//! ```
+
+pub use crate::{
+    analyzer::{
+        AnalysisResult, Analyzer, Confidence, Fix, Issue, Severity, SourceFile, Suggestion,
+        TextEdit
+    },
+    analyzers::{get_analyzers, get_analyzers_for},
+    differ::{
+        DiffResult,
+        apply::{AppliedFile, ApplyReport},
+        apply_diff, generate_diff,
+        types::{DiffEntry, FileDiff}
+    },
+    project::ProjectContext,
+    report::{GlobalReport, Report}
+};
+
+/// Version of the stable API exposed by this module.
+///
+/// Incremented whenever an item reachable from [`crate::api`] changes in a
+/// backwards-incompatible way.
+///
+/// # History
+///
+/// - 2: [`Analyzer`] requires `Send + Sync`, so analyzers can be shared between
+///   the worker threads of `diff`. Implementors holding `Rc`, `Cell` or
+///   `RefCell` state must switch to `Arc`, atomics or `Mutex`.
+/// - 1: initial stable API.
+pub const VERSION: u32 = 2;
@@ -0,0 +1,412 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Batch fixes for scheduled CI jobs.
+//!
+//! `cargo qual bot` applies every automatic fix of the project, commits the
+//! changes to a branch and writes a machine-readable summary next to them. A
+//! workflow only has to push the branch and open a pull request from the
+//! summary's title, body and label; the analysis, the fixes and the summary
+//! stay in this crate.
+//!
+//! The summary is always written, with `"changed": false` when nothing was
+//! fixed, so the workflow can stop without parsing any output.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::Command
+};
+
+use masterror::AppResult;
+
+use crate::{error::IoError, report::json_string, snapshot::normalize_path};
+
+/// Label the pull request is tagged with by default.
+pub const DEFAULT_LABEL: &str = "quality-autofix";
+
+/// Branch the fixes are committed to by default.
+pub const DEFAULT_BRANCH: &str = "quality-autofix";
+
+/// File the summary is written to by default.
+pub const DEFAULT_SUMMARY_FILE: &str = "quality-autofix.json";
+
+/// Title of the fix commit and the pull request.
+pub const TITLE: &str = "Apply cargo-quality fixes";
+
+/// Fixes applied to a single file.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FixedFile {
+    /// Path of the file
+    pub path:      PathBuf,
+    /// Number of fixes applied
+    pub applied:   usize,
+    /// Number of fixes skipped because they overlapped an applied one
+    pub conflicts: usize,
+    /// Analyzers that proposed fixes for the file, in the order they ran
+    pub analyzers: Vec<String>
+}
+
+/// Outcome of a bot run, rendered as the summary file.
+///
+/// # Examples
+///
+/// ```
+/// use std::path::PathBuf;
+///
+/// use cargo_quality::bot::{BotSummary, FixedFile};
+///
+/// let mut summary = BotSummary::new("quality-autofix", "quality-autofix");
+/// assert!(!summary.has_changes());
+///
+/// summary.files.push(FixedFile {
+///     path:      PathBuf::from("src/lib.rs"),
+///     applied:   2,
+///     conflicts: 0,
+///     analyzers: vec!["path_import".to_string()]
+/// });
+/// assert!(summary.has_changes());
+/// assert!(summary.to_json().contains("\"changed\":true"));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BotSummary {
+    /// Label the workflow puts on the pull request
+    pub label:   String,
+    /// Branch holding the fixes
+    pub branch:  String,
+    /// Files whose content was fixed
+    pub files:   Vec<FixedFile>,
+    /// Files moved to the location a file analyzer proposed, as (from, to)
+    pub renamed: Vec<(PathBuf, PathBuf)>
+}
+
+impl BotSummary {
+    /// Creates an empty summary.
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - Label of the pull request
+    /// * `branch` - Branch the fixes are committed to
+    ///
+    /// # Returns
+    ///
+    /// Summary without fixed or renamed files
+    pub fn new(label: &str, branch: &str) -> Self {
+        Self {
+            label: label.to_string(),
+            branch: branch.to_string(),
+            ..Self::default()
+        }
+    }
+
+    /// Checks whether the run changed any file.
+    ///
+    /// # Returns
+    ///
+    /// `true` if a fix was applied or a file was renamed
+    pub fn has_changes(&self) -> bool {
+        self.files.iter().any(|file| file.applied > 0) || !self.renamed.is_empty()
+    }
+
+    /// Total number of fixes applied.
+    ///
+    /// # Returns
+    ///
+    /// Sum of the fixes applied to every file
+    pub fn applied(&self) -> usize {
+        self.files.iter().map(|file| file.applied).sum()
+    }
+
+    /// Renders the pull request description.
+    ///
+    /// # Returns
+    ///
+    /// Markdown with one table row per fixed file and one line per rename
+    pub fn body(&self) -> String {
+        let mut body = format!(
+            "Automatic fixes by `cargo qual bot`: {} fixes in {} files.\n",
+            self.applied(),
+            self.files.len()
+        );
+
+        if !self.files.is_empty() {
+            body.push_str("\n| File | Fixes | Analyzers |\n|------|-------|-----------|\n");
+            for file in &self.files {
+                let analyzers: Vec<String> = file
+                    .analyzers
+                    .iter()
+                    .map(|name| format!("`{name}`"))
+                    .collect();
+                body.push_str(&format!(
+                    "| `{}` | {} | {} |\n",
+                    path_text(&file.path),
+                    file.applied,
+                    analyzers.join(", ")
+                ));
+            }
+        }
+
+        if !self.renamed.is_empty() {
+            body.push_str("\nRenamed files:\n\n");
+            for (from, to) in &self.renamed {
+                body.push_str(&format!("- `{}` -> `{}`\n", path_text(from), path_text(to)));
+            }
+        }
+
+        let conflicts: usize = self.files.iter().map(|file| file.conflicts).sum();
+        if conflicts > 0 {
+            body.push_str(&format!(
+                "\n{conflicts} overlapping fixes were skipped and are applied by the next run.\n"
+            ));
+        }
+
+        body
+    }
+
+    /// Renders the summary as a single JSON object.
+    ///
+    /// # Returns
+    ///
+    /// JSON with the label, branch, whether anything changed, the commit and
+    /// pull request title and body, and the fixed and renamed files
+    pub fn to_json(&self) -> String {
+        let files: Vec<String> = self
+            .files
+            .iter()
+            .map(|file| {
+                let analyzers: Vec<String> = file
+                    .analyzers
+                    .iter()
+                    .map(|name| json_string(name))
+                    .collect();
+                format!(
+                    "{{\"path\":{},\"applied\":{},\"conflicts\":{},\"analyzers\":[{}]}}",
+                    json_string(&path_text(&file.path)),
+                    file.applied,
+                    file.conflicts,
+                    analyzers.join(",")
+                )
+            })
+            .collect();
+        let renamed: Vec<String> = self
+            .renamed
+            .iter()
+            .map(|(from, to)| {
+                format!(
+                    "{{\"from\":{},\"to\":{}}}",
+                    json_string(&path_text(from)),
+                    json_string(&path_text(to))
+                )
+            })
+            .collect();
+
+        format!(
+            "{{\"label\":{},\"branch\":{},\"changed\":{},\"applied\":{},\"title\":{},\
+             \"body\":{},\"files\":[{}],\"renamed\":[{}]}}\n",
+            json_string(&self.label),
+            json_string(&self.branch),
+            self.has_changes(),
+            self.applied(),
+            json_string(TITLE),
+            json_string(&self.body()),
+            files.join(","),
+            renamed.join(",")
+        )
+    }
+
+    /// Writes the summary file.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path of the summary file
+    ///
+    /// # Returns
+    ///
+    /// `AppResult<()>` - IO error if the file cannot be written
+    pub fn write(&self, path: &Path) -> AppResult<()> {
+        fs::write(path, self.to_json()).map_err(IoError::from)?;
+        Ok(())
+    }
+}
+
+/// Fails unless the working tree has no changes and no untracked files.
+///
+/// The bot commits every change of the working tree, so it has to start
+/// from a clean checkout, or unrelated edits would end up in its commit.
+///
+/// # Arguments
+///
+/// * `root` - Directory inside the repository
+///
+/// # Returns
+///
+/// `AppResult<()>` - IO error if git cannot be run or the tree is not clean
+pub fn ensure_clean(root: &Path) -> AppResult<()> {
+    let status = git(root, &["status", "--porcelain"])?;
+    if status.trim().is_empty() {
+        return Ok(());
+    }
+
+    Err(IoError::from(std::io::Error::other(format!(
+        "working tree is not clean, commit or stash these changes before running the bot:\n{}",
+        status.trim_end()
+    )))
+    .into())
+}
+
+/// Commits every change of the working tree to a branch.
+///
+/// The branch is created, or reset, at the current commit and checked out,
+/// so the bot expects a clean checkout, see [`ensure_clean`]. The commit
+/// author is taken from the git configuration of the workflow.
+///
+/// # Arguments
+///
+/// * `root` - Directory inside the repository
+/// * `branch` - Branch to commit to
+/// * `body` - Commit message body below [`TITLE`]
+///
+/// # Returns
+///
+/// `AppResult<()>` - IO error if git cannot be run or a git command fails
+pub fn commit_to_branch(root: &Path, branch: &str, body: &str) -> AppResult<()> {
+    git(root, &["checkout", "-B", branch])?;
+    git(root, &["add", "--all"])?;
+    git(root, &["commit", "--quiet", "-m", TITLE, "-m", body])?;
+    Ok(())
+}
+
+/// Runs a git command in a directory and returns its standard output.
+fn git(root: &Path, args: &[&str]) -> AppResult<String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(root)
+        .args(args)
+        .output()
+        .map_err(IoError::from)?;
+
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    } else {
+        Err(IoError::from(std::io::Error::other(format!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        )))
+        .into())
+    }
+}
+
+/// Path as shown in the summary, relative and with `/` separators.
+fn path_text(path: &Path) -> String {
+    normalize_path(&path.display().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+
+    fn summary() -> BotSummary {
+        let mut summary = BotSummary::new(DEFAULT_LABEL, "bot/fixes");
+        summary.files.push(FixedFile {
+            path:      PathBuf::from("./src/lib.rs"),
+            applied:   3,
+            conflicts: 1,
+            analyzers: vec!["path_import".to_string(), "format_args".to_string()]
+        });
+        summary
+            .renamed
+            .push((PathBuf::from("src/a/mod.rs"), PathBuf::from("src/a.rs")));
+        summary
+    }
+
+    #[test]
+    fn test_empty_summary() {
+        let summary = BotSummary::new(DEFAULT_LABEL, DEFAULT_BRANCH);
+
+        assert!(!summary.has_changes());
+        assert_eq!(
+            summary.to_json(),
+            "{\"label\":\"quality-autofix\",\"branch\":\"quality-autofix\",\"changed\":false,\
+             \"applied\":0,\"title\":\"Apply cargo-quality fixes\",\"body\":\"Automatic fixes \
+             by `cargo qual bot`: 0 fixes in 0 files.\\n\",\"files\":[],\"renamed\":[]}\n"
+        );
+    }
+
+    #[test]
+    fn test_body() {
+        let body = summary().body();
+
+        assert!(body.starts_with("Automatic fixes by `cargo qual bot`: 3 fixes in 1 files.\n"));
+        assert!(body.contains("| `src/lib.rs` | 3 | `path_import`, `format_args` |\n"));
+        assert!(body.contains("- `src/a/mod.rs` -> `src/a.rs`\n"));
+        assert!(body.contains("1 overlapping fixes were skipped"));
+    }
+
+    #[test]
+    fn test_to_json() {
+        let json = summary().to_json();
+
+        assert!(json.starts_with(
+            "{\"label\":\"quality-autofix\",\"branch\":\"bot/fixes\",\"changed\":true,\
+             \"applied\":3,"
+        ));
+        assert!(json.contains(
+            "\"files\":[{\"path\":\"src/lib.rs\",\"applied\":3,\"conflicts\":1,\
+             \"analyzers\":[\"path_import\",\"format_args\"]}]"
+        ));
+        assert!(
+            json.ends_with("\"renamed\":[{\"from\":\"src/a/mod.rs\",\"to\":\"src/a.rs\"}]}\n")
+        );
+    }
+
+    #[test]
+    fn test_rename_only_is_a_change() {
+        let mut summary = BotSummary::new(DEFAULT_LABEL, DEFAULT_BRANCH);
+        summary
+            .renamed
+            .push((PathBuf::from("src/a/mod.rs"), PathBuf::from("src/a.rs")));
+        assert!(summary.has_changes());
+    }
+
+    #[test]
+    fn test_commit_to_branch() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        git(root, &["init", "--quiet"]).expect("git is required to test the bot");
+        git(root, &["config", "user.name", "Quality Bot"]).unwrap();
+        git(root, &["config", "user.email", "bot@example.com"]).unwrap();
+        fs::write(root.join("lib.rs"), "fn main() {}\n").unwrap();
+        git(root, &["add", "--all"]).unwrap();
+        git(root, &["commit", "--quiet", "-m", "init"]).unwrap();
+
+        ensure_clean(root).unwrap();
+
+        fs::write(root.join("lib.rs"), "fn main() {}\n\nfn f() {}\n").unwrap();
+        commit_to_branch(root, "bot/fixes", "body").unwrap();
+
+        let log = Command::new("git")
+            .arg("-C")
+            .arg(root)
+            .args(["log", "-1", "--format=%s%n%b%D"])
+            .output()
+            .unwrap();
+        let log = String::from_utf8_lossy(&log.stdout);
+        assert!(log.starts_with("Apply cargo-quality fixes\nbody\n"));
+        assert!(log.contains("bot/fixes"));
+        assert!(git(root, &["diff", "--quiet", "HEAD"]).is_ok());
+    }
+
+    #[test]
+    fn test_ensure_clean_rejects_changes() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        git(root, &["init", "--quiet"]).expect("git is required to test the bot");
+
+        fs::write(root.join("notes.txt"), "draft\n").unwrap();
+        let err = ensure_clean(root).unwrap_err();
+        assert!(err.to_string().contains("?? notes.txt"));
+    }
+}
@@ -6,20 +6,30 @@
 //! Defines the CLI structure for cargo-quality with support for check, fix,
 //! and format subcommands. Uses clap derive macros for argument parsing.
 
-use clap::{Parser, Subcommand};
+use std::{path::PathBuf, process};
+
+use clap::{Parser, Subcommand, value_parser};
+
+use crate::{analyzer::Confidence, bot, exit_code::ExitCode};
 
 /// Quality analysis and fixes for Rust code.
 ///
-/// Main argument structure containing the subcommand to execute.
+/// Main argument structure containing the subcommand to execute, or the
+/// `--explain-exit-codes` flag.
 #[derive(Parser, Debug)]
 #[command(name = "cargo-qual")]
 #[command(bin_name = "cargo qual")]
 #[command(author, version, about, long_about = None)]
 #[command(disable_help_flag = true, disable_help_subcommand = true)]
+#[command(args_conflicts_with_subcommands = true)]
 pub struct QualityArgs {
-    /// Subcommand to execute
+    /// Subcommand to execute; help is shown without one
     #[command(subcommand)]
-    pub command: Command
+    pub command: Option<Command>,
+
+    /// Print the table of exit codes and what they mean
+    #[arg(long)]
+    pub explain_exit_codes: bool
 }
 
 /// Available quality analysis commands.
@@ -27,6 +37,7 @@ pub struct QualityArgs {
 /// Each variant represents a different operation mode:
 /// - Check: Report issues without modifications
 /// - Fix: Apply automatic fixes
+/// - Bot: Apply fixes on a branch for a scheduled CI job
 /// - Format: Format code according to rules
 #[derive(Subcommand, Debug)]
 pub enum Command {
@@ -46,7 +57,58 @@ pub enum Command {
 
         /// Enable colored output
         #[arg(short, long)]
-        color: bool
+        color: bool,
+
+        /// Terminal width for the grid layout instead of `COLUMNS` or the
+        /// detected width
+        #[arg(long, value_name = "N", value_parser = value_parser!(u16).range(1..))]
+        width: Option<u16>,
+
+        /// Read the files to analyze from stdin, one path per line, instead of
+        /// walking the path
+        #[arg(long)]
+        stdin: bool,
+
+        /// List every skipped path with its cause instead of only counting
+        /// them
+        #[arg(long)]
+        show_skipped: bool,
+
+        /// Print only the issue counts of an analyzer in verbose output;
+        /// repeat for several analyzers
+        #[arg(long, value_name = "ANALYZER")]
+        collapse: Vec<String>,
+
+        /// Skip analyzers whose confidence is below this level (high, medium
+        /// or low); an analyzer selected with `--analyzer` always runs
+        #[arg(long, value_name = "LEVEL", default_value_t = Confidence::Low)]
+        min_confidence: Confidence,
+
+        /// Render the same output on every machine: fixed width, no color,
+        /// relative paths with `/` and sorted files, analyzers and issues, for
+        /// snapshot tests of the output
+        #[arg(long, conflicts_with_all = ["color", "width"])]
+        deterministic: bool,
+
+        /// Output format: grouped text report, one JSON object per issue, a
+        /// markdown report with links to the source or GitHub Actions
+        /// annotations
+        #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+        format: OutputFormat,
+
+        /// Print the files and analyzers the run would use, and why skipped
+        /// analyzers are skipped, without analyzing anything
+        #[arg(long)]
+        plan: bool,
+
+        /// Compare the normalized report with a snapshot file, writing it if
+        /// missing, and fail with a diff when the report changed
+        #[arg(long, value_name = "FILE", conflicts_with = "format")]
+        snapshot: Option<PathBuf>,
+
+        /// Overwrite the snapshot file with the current report
+        #[arg(long, requires = "snapshot")]
+        update_snapshot: bool
     },
 
     /// Automatically fix quality issues
@@ -61,7 +123,41 @@ pub enum Command {
 
         /// Run specific analyzer only (e.g., inline_comments, empty_lines)
         #[arg(short, long)]
-        analyzer: Option<String>
+        analyzer: Option<String>,
+
+        /// Read the files to fix from stdin, one path per line, instead of
+        /// walking the path
+        #[arg(long, visible_alias = "fix-dirty")]
+        stdin: bool,
+
+        /// List every skipped path with its cause instead of only counting
+        /// them
+        #[arg(long)]
+        show_skipped: bool
+    },
+
+    /// Apply every fix on a branch and write a summary for a CI workflow
+    /// to open a pull request from
+    Bot {
+        /// Path to fix (default: current directory)
+        #[arg(default_value = ".")]
+        path: String,
+
+        /// Label the summary asks the workflow to put on the pull request
+        #[arg(long, value_name = "LABEL", default_value = bot::DEFAULT_LABEL)]
+        apply_label: String,
+
+        /// Branch the fixes are committed to
+        #[arg(long, value_name = "NAME", default_value = bot::DEFAULT_BRANCH)]
+        branch: String,
+
+        /// File the JSON summary is written to
+        #[arg(long, value_name = "FILE", default_value = bot::DEFAULT_SUMMARY_FILE)]
+        summary: PathBuf,
+
+        /// Apply the fixes and write the summary without committing
+        #[arg(long)]
+        no_commit: bool
     },
 
     /// Format code according to quality rules
@@ -98,7 +194,17 @@ pub enum Command {
 
         /// Enable colored output
         #[arg(short, long)]
-        color: bool
+        color: bool,
+
+        /// Terminal width for the grid layout instead of `COLUMNS` or the
+        /// detected width
+        #[arg(long, value_name = "N", value_parser = value_parser!(u16).range(1..))]
+        width: Option<u16>,
+
+        /// List every skipped path with its cause instead of only counting
+        /// them
+        #[arg(long)]
+        show_skipped: bool
     },
 
     /// Display beautiful help with examples and usage
@@ -123,12 +229,48 @@ pub enum Command {
         /// Apply fixes automatically
         #[arg(long)]
         fix: bool
+    },
+
+    /// List the available rules or export their documentation
+    Rules {
+        /// Export one page per rule in the given format instead of listing
+        #[arg(long, value_enum)]
+        export: Option<RulesFormat>,
+
+        /// Directory the exported pages are written to
+        #[arg(default_value = "docs/rules")]
+        dir: String
     }
 }
 
+/// Output formats of the check command
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum OutputFormat {
+    /// Human-readable report printed after the run
+    Text,
+    /// One JSON object per issue, streamed as each file is analyzed
+    Jsonl,
+    /// Markdown tables linking each issue to its line on the source host
+    Markdown,
+    /// GitHub Actions annotations with a preview of each fix
+    Github
+}
+
+/// Export formats of the rules command
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum RulesFormat {
+    /// One markdown page per rule plus an index
+    Markdown,
+    /// English message catalog to translate, see `metadata.quality.messages`
+    Messages
+}
+
 /// Supported shells for completion generation
 #[derive(Debug, Clone, clap::ValueEnum)]
-#[allow(clippy::enum_variant_names)]
+#[allow(
+    clippy::enum_variant_names,
+    reason = "variants are the shell names accepted on the command line"
+)]
 pub enum Shell {
     Bash,
     Fish,
@@ -141,7 +283,7 @@ impl QualityArgs {
     /// Parse command-line arguments.
     ///
     /// Handles both `cargo qual <cmd>` and direct `cargo-qual <cmd>`
-    /// invocations.
+    /// invocations. Invalid arguments exit with [`ExitCode::ConfigError`].
     ///
     /// # Returns
     ///
@@ -152,7 +294,15 @@ impl QualityArgs {
         if args.len() > 1 && args[1] == "qual" {
             args.remove(1);
         }
-        Self::parse_from(args)
+        Self::try_parse_from(args).unwrap_or_else(|err| {
+            let code = if err.use_stderr() {
+                ExitCode::ConfigError
+            } else {
+                ExitCode::Clean
+            };
+            let _ = err.print();
+            process::exit(i32::from(code.code()))
+        })
     }
 }
 
@@ -163,17 +313,37 @@ mod tests {
     #[test]
     fn test_cli_parsing_check() {
         let args = QualityArgs::parse_from(["cargo-qual", "check", "src"]);
-        match args.command {
+        match args.command.unwrap() {
             Command::Check {
                 path,
                 verbose,
                 analyzer,
-                color
+                color,
+                width,
+                stdin,
+                show_skipped,
+                collapse,
+                min_confidence,
+                deterministic,
+                format,
+                plan,
+                snapshot,
+                update_snapshot
             } => {
                 assert_eq!(path, "src");
                 assert!(!verbose);
                 assert!(analyzer.is_none());
                 assert!(!color);
+                assert!(width.is_none());
+                assert!(!stdin);
+                assert!(!show_skipped);
+                assert!(collapse.is_empty());
+                assert_eq!(min_confidence, Confidence::Low);
+                assert!(!deterministic);
+                assert_eq!(format, OutputFormat::Text);
+                assert!(!plan);
+                assert!(snapshot.is_none());
+                assert!(!update_snapshot);
             }
             _ => panic!("Expected Check command")
         }
@@ -182,24 +352,39 @@ mod tests {
     #[test]
     fn test_cli_parsing_fix() {
         let args = QualityArgs::parse_from(["cargo-qual", "fix", "--dry-run"]);
-        match args.command {
+        match args.command.unwrap() {
             Command::Fix {
                 path,
                 dry_run,
-                analyzer
+                analyzer,
+                stdin,
+                show_skipped
             } => {
                 assert_eq!(path, ".");
                 assert!(dry_run);
                 assert!(analyzer.is_none());
+                assert!(!stdin);
+                assert!(!show_skipped);
             }
             _ => panic!("Expected Fix command")
         }
     }
 
+    #[test]
+    fn test_cli_parsing_fix_dirty_alias() {
+        let args = QualityArgs::parse_from(["cargo-qual", "fix", "--fix-dirty"]);
+        match args.command.unwrap() {
+            Command::Fix {
+                stdin, ..
+            } => assert!(stdin),
+            _ => panic!("Expected Fix command")
+        }
+    }
+
     #[test]
     fn test_cli_parsing_format() {
         let args = QualityArgs::parse_from(["cargo-qual", "format"]);
-        match args.command {
+        match args.command.unwrap() {
             Command::Format {
                 path
             } => {
@@ -209,20 +394,114 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_cli_parsing_collapse() {
+        let args = QualityArgs::parse_from([
+            "cargo-qual",
+            "check",
+            "--verbose",
+            "--collapse",
+            "empty_lines",
+            "--collapse",
+            "line_length"
+        ]);
+        match args.command.unwrap() {
+            Command::Check {
+                collapse, ..
+            } => assert_eq!(collapse, ["empty_lines", "line_length"]),
+            _ => panic!("Expected Check command")
+        }
+    }
+
+    #[test]
+    fn test_cli_parsing_min_confidence() {
+        let args = QualityArgs::parse_from(["cargo-qual", "check", "--min-confidence", "high"]);
+        match args.command.unwrap() {
+            Command::Check {
+                min_confidence, ..
+            } => assert_eq!(min_confidence, Confidence::High),
+            _ => panic!("Expected Check command")
+        }
+
+        let invalid =
+            QualityArgs::try_parse_from(["cargo-qual", "check", "--min-confidence", "strict"]);
+        assert!(invalid.is_err());
+    }
+
+    #[test]
+    fn test_cli_parsing_deterministic() {
+        let args = QualityArgs::parse_from(["cargo-qual", "check", "--deterministic"]);
+        match args.command.unwrap() {
+            Command::Check {
+                deterministic, ..
+            } => assert!(deterministic),
+            _ => panic!("Expected Check command")
+        }
+
+        let conflicting =
+            QualityArgs::try_parse_from(["cargo-qual", "check", "--deterministic", "--color"]);
+        assert!(conflicting.is_err());
+    }
+
+    #[test]
+    fn test_cli_parsing_bot() {
+        let args = QualityArgs::parse_from(["cargo-qual", "bot"]);
+        match args.command.unwrap() {
+            Command::Bot {
+                path,
+                apply_label,
+                branch,
+                summary,
+                no_commit
+            } => {
+                assert_eq!(path, ".");
+                assert_eq!(apply_label, "quality-autofix");
+                assert_eq!(branch, "quality-autofix");
+                assert_eq!(summary, PathBuf::from("quality-autofix.json"));
+                assert!(!no_commit);
+            }
+            _ => panic!("Expected Bot command")
+        }
+
+        let args = QualityArgs::parse_from([
+            "cargo-qual",
+            "bot",
+            "--apply-label",
+            "autofix",
+            "--no-commit"
+        ]);
+        match args.command.unwrap() {
+            Command::Bot {
+                apply_label,
+                no_commit,
+                ..
+            } => {
+                assert_eq!(apply_label, "autofix");
+                assert!(no_commit);
+            }
+            _ => panic!("Expected Bot command")
+        }
+    }
+
     #[test]
     fn test_cli_parsing_check_verbose() {
         let args = QualityArgs::parse_from(["cargo-qual", "check", "--verbose"]);
-        match args.command {
+        match args.command.unwrap() {
             Command::Check {
                 path,
                 verbose,
                 analyzer,
-                color
+                color,
+                stdin,
+                format,
+                ..
             } => {
                 assert_eq!(path, ".");
                 assert!(verbose);
                 assert!(analyzer.is_none());
                 assert!(!color);
+                assert!(!stdin);
+                assert_eq!(format, OutputFormat::Text);
             }
             _ => panic!("Expected Check command")
         }
@@ -231,15 +510,19 @@ mod tests {
     #[test]
     fn test_cli_parsing_fix_no_dry_run() {
         let args = QualityArgs::parse_from(["cargo-qual", "fix"]);
-        match args.command {
+        match args.command.unwrap() {
             Command::Fix {
                 path,
                 dry_run,
-                analyzer
+                analyzer,
+                stdin,
+                show_skipped
             } => {
                 assert_eq!(path, ".");
                 assert!(!dry_run);
                 assert!(analyzer.is_none());
+                assert!(!stdin);
+                assert!(!show_skipped);
             }
             _ => panic!("Expected Fix command")
         }
@@ -248,7 +531,7 @@ mod tests {
     #[test]
     fn test_cli_parsing_format_with_path() {
         let args = QualityArgs::parse_from(["cargo-qual", "format", "src/"]);
-        match args.command {
+        match args.command.unwrap() {
             Command::Format {
                 path
             } => {
@@ -261,7 +544,7 @@ mod tests {
     #[test]
     fn test_cli_parsing_fmt() {
         let args = QualityArgs::parse_from(["cargo-qual", "fmt"]);
-        match args.command {
+        match args.command.unwrap() {
             Command::Fmt {
                 path
             } => {
@@ -274,7 +557,7 @@ mod tests {
     #[test]
     fn test_cli_parsing_fmt_with_path() {
         let args = QualityArgs::parse_from(["cargo-qual", "fmt", "src/"]);
-        match args.command {
+        match args.command.unwrap() {
             Command::Fmt {
                 path
             } => {
@@ -287,28 +570,73 @@ mod tests {
     #[test]
     fn test_cli_parsing_help() {
         let args = QualityArgs::parse_from(["cargo-qual", "help"]);
-        match args.command {
+        match args.command.unwrap() {
             Command::Help => {}
             _ => panic!("Expected Help command")
         }
     }
 
+    #[test]
+    fn test_cli_parsing_width() {
+        let args = QualityArgs::parse_from(["cargo-qual", "check", "--width", "50"]);
+        match args.command.unwrap() {
+            Command::Check {
+                width, ..
+            } => assert_eq!(width, Some(50)),
+            _ => panic!("Expected Check command")
+        }
+
+        let args = QualityArgs::parse_from(["cargo-qual", "diff", "--width", "120"]);
+        match args.command.unwrap() {
+            Command::Diff {
+                width, ..
+            } => assert_eq!(width, Some(120)),
+            _ => panic!("Expected Diff command")
+        }
+
+        assert!(QualityArgs::try_parse_from(["cargo-qual", "check", "--width", "0"]).is_err());
+    }
+
+    #[test]
+    fn test_cli_parsing_show_skipped() {
+        for command in ["check", "fix", "diff"] {
+            let args = QualityArgs::parse_from(["cargo-qual", command, "--show-skipped"]);
+            assert!(matches!(
+                args.command.unwrap(),
+                Command::Check {
+                    show_skipped: true,
+                    ..
+                } | Command::Fix {
+                    show_skipped: true,
+                    ..
+                } | Command::Diff {
+                    show_skipped: true,
+                    ..
+                }
+            ));
+        }
+    }
+
     #[test]
     fn test_cli_parsing_diff() {
         let args = QualityArgs::parse_from(["cargo-qual", "diff"]);
-        match args.command {
+        match args.command.unwrap() {
             Command::Diff {
                 path,
                 summary,
                 interactive,
                 analyzer,
-                color
+                color,
+                width,
+                show_skipped
             } => {
                 assert_eq!(path, ".");
                 assert!(!summary);
                 assert!(!interactive);
                 assert!(analyzer.is_none());
                 assert!(!color);
+                assert!(width.is_none());
+                assert!(!show_skipped);
             }
             _ => panic!("Expected Diff command")
         }
@@ -317,19 +645,23 @@ mod tests {
     #[test]
     fn test_cli_parsing_diff_summary() {
         let args = QualityArgs::parse_from(["cargo-qual", "diff", "--summary"]);
-        match args.command {
+        match args.command.unwrap() {
             Command::Diff {
                 path,
                 summary,
                 interactive,
                 analyzer,
-                color
+                color,
+                width,
+                show_skipped
             } => {
                 assert_eq!(path, ".");
                 assert!(summary);
                 assert!(!interactive);
                 assert!(analyzer.is_none());
                 assert!(!color);
+                assert!(width.is_none());
+                assert!(!show_skipped);
             }
             _ => panic!("Expected Diff command")
         }
@@ -338,19 +670,23 @@ mod tests {
     #[test]
     fn test_cli_parsing_diff_interactive() {
         let args = QualityArgs::parse_from(["cargo-qual", "diff", "--interactive"]);
-        match args.command {
+        match args.command.unwrap() {
             Command::Diff {
                 path,
                 summary,
                 interactive,
                 analyzer,
-                color
+                color,
+                width,
+                show_skipped
             } => {
                 assert_eq!(path, ".");
                 assert!(!summary);
                 assert!(interactive);
                 assert!(analyzer.is_none());
                 assert!(!color);
+                assert!(width.is_none());
+                assert!(!show_skipped);
             }
             _ => panic!("Expected Diff command")
         }
@@ -359,19 +695,23 @@ mod tests {
     #[test]
     fn test_cli_parsing_diff_with_path() {
         let args = QualityArgs::parse_from(["cargo-qual", "diff", "src/"]);
-        match args.command {
+        match args.command.unwrap() {
             Command::Diff {
                 path,
                 summary,
                 interactive,
                 analyzer,
-                color
+                color,
+                width,
+                show_skipped
             } => {
                 assert_eq!(path, "src/");
                 assert!(!summary);
                 assert!(!interactive);
                 assert!(analyzer.is_none());
                 assert!(!color);
+                assert!(width.is_none());
+                assert!(!show_skipped);
             }
             _ => panic!("Expected Diff command")
         }
@@ -380,7 +720,7 @@ mod tests {
     #[test]
     fn test_cli_parsing_completions() {
         let args = QualityArgs::parse_from(["cargo-qual", "completions", "fish"]);
-        match args.command {
+        match args.command.unwrap() {
             Command::Completions {
                 shell
             } => match shell {
@@ -395,19 +735,169 @@ mod tests {
     fn test_cli_parsing_check_with_analyzer() {
         let args =
             QualityArgs::parse_from(["cargo-qual", "check", "--analyzer", "inline_comments"]);
-        match args.command {
+        match args.command.unwrap() {
             Command::Check {
                 path,
                 verbose,
                 analyzer,
-                color
+                color,
+                stdin,
+                format,
+                ..
             } => {
                 assert_eq!(path, ".");
                 assert!(!verbose);
                 assert_eq!(analyzer, Some("inline_comments".to_string()));
                 assert!(!color);
+                assert!(!stdin);
+                assert_eq!(format, OutputFormat::Text);
+            }
+            _ => panic!("Expected Check command")
+        }
+    }
+
+    #[test]
+    fn test_cli_parsing_stdin() {
+        let args = QualityArgs::parse_from(["cargo-qual", "check", "--stdin"]);
+        assert!(matches!(
+            args.command.unwrap(),
+            Command::Check {
+                stdin: true,
+                ..
+            }
+        ));
+
+        let args = QualityArgs::parse_from(["cargo-qual", "fix", "--stdin"]);
+        assert!(matches!(
+            args.command.unwrap(),
+            Command::Fix {
+                stdin: true,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_cli_parsing_format_jsonl() {
+        let args = QualityArgs::parse_from(["cargo-qual", "check", "--format", "jsonl"]);
+        assert!(matches!(
+            args.command.unwrap(),
+            Command::Check {
+                format: OutputFormat::Jsonl,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_cli_parsing_plan() {
+        let args = QualityArgs::parse_from(["cargo-qual", "check", "--plan", "-a", "unwrap"]);
+        assert!(matches!(
+            args.command.unwrap(),
+            Command::Check {
+                plan: true,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_cli_parsing_snapshot() {
+        let args = QualityArgs::parse_from([
+            "cargo-qual",
+            "check",
+            "--snapshot",
+            ".quality-snapshot",
+            "--update-snapshot"
+        ]);
+        match args.command.unwrap() {
+            Command::Check {
+                snapshot,
+                update_snapshot,
+                ..
+            } => {
+                assert_eq!(snapshot, Some(PathBuf::from(".quality-snapshot")));
+                assert!(update_snapshot);
             }
             _ => panic!("Expected Check command")
         }
+
+        assert!(
+            QualityArgs::try_parse_from(["cargo-qual", "check", "--update-snapshot"]).is_err()
+        );
+        assert!(
+            QualityArgs::try_parse_from([
+                "cargo-qual",
+                "check",
+                "--snapshot",
+                "s",
+                "--format",
+                "jsonl"
+            ])
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_cli_parsing_format_markdown() {
+        let args = QualityArgs::parse_from(["cargo-qual", "check", "--format", "markdown"]);
+        assert!(matches!(
+            args.command.unwrap(),
+            Command::Check {
+                format: OutputFormat::Markdown,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_cli_parsing_format_github() {
+        let args = QualityArgs::parse_from(["cargo-qual", "check", "--format", "github"]);
+        assert!(matches!(
+            args.command.unwrap(),
+            Command::Check {
+                format: OutputFormat::Github,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_cli_parsing_explain_exit_codes() {
+        let args = QualityArgs::parse_from(["cargo-qual", "--explain-exit-codes"]);
+        assert!(args.explain_exit_codes);
+        assert!(args.command.is_none());
+
+        assert!(
+            QualityArgs::try_parse_from(["cargo-qual", "--explain-exit-codes", "check"]).is_err()
+        );
+    }
+
+    #[test]
+    fn test_cli_parsing_rules() {
+        let args = QualityArgs::parse_from(["cargo-qual", "rules"]);
+        match args.command.unwrap() {
+            Command::Rules {
+                export,
+                dir
+            } => {
+                assert!(export.is_none());
+                assert_eq!(dir, "docs/rules");
+            }
+            _ => panic!("Expected Rules command")
+        }
+
+        let args =
+            QualityArgs::parse_from(["cargo-qual", "rules", "--export", "markdown", "site/rules"]);
+        match args.command.unwrap() {
+            Command::Rules {
+                export,
+                dir
+            } => {
+                assert_eq!(export, Some(RulesFormat::Markdown));
+                assert_eq!(dir, "site/rules");
+            }
+            _ => panic!("Expected Rules command")
+        }
     }
 }
@@ -13,6 +13,10 @@ mod generator;
 pub mod types;
 
 pub use apply::apply_diff;
-pub use display::{show_full, show_interactive, show_summary};
-pub use generator::generate_diff;
-pub use types::DiffResult;
+pub use display::{show_apply_summary, show_full, show_interactive, show_summary};
+pub use generator::generate_diffs;
+#[allow(
+    unused_imports,
+    reason = "library API, the binary only diffs files in bulk"
+)]
+pub use {generator::generate_diff, types::DiffResult};
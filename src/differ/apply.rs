@@ -7,14 +7,76 @@
 //! entry carries the underlying [`crate::analyzer::TextEdit`], so changes are
 //! applied through the same [`crate::fixer::apply_suggestions`] engine as the
 //! `fix` command — collision-safe and comment-preserving. An entry is skipped
-//! if the file no longer matches the line the diff was generated from.
+//! if the file no longer matches the line the diff was generated from. A
+//! selected file move is performed after the edits, unless its target exists.
+//!
+//! The per-file outcome is returned as an [`ApplyReport`] so callers can
+//! confirm exactly what was written.
 
-use std::fs;
+use std::{fs, path::Path};
 
 use masterror::AppResult;
 
 use super::types::{DiffResult, FileDiff};
-use crate::{analyzer::Suggestion, error::IoError, fixer::apply_suggestions};
+use crate::{
+    analyzer::Suggestion, error::IoError, file_analyzer::rename_file, fixer::apply_suggestions
+};
+
+/// Outcome of applying the selected entries of a single file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct AppliedFile {
+    /// Path of the file
+    pub path:    String,
+    /// Number of line changes written
+    pub applied: usize,
+    /// Number of imports inserted
+    pub imports: usize,
+    /// Number of entries skipped because the file changed or they conflicted
+    pub skipped: usize,
+    /// Path the file was moved to, if it was moved
+    pub renamed: Option<String>
+}
+
+/// Outcome of applying a selection of diff entries across files.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ApplyReport {
+    /// Per-file outcomes, in the order of the selection
+    pub files: Vec<AppliedFile>
+}
+
+impl ApplyReport {
+    /// Total number of line changes written.
+    ///
+    /// # Returns
+    ///
+    /// Sum of applied changes across all files
+    pub fn total_applied(&self) -> usize {
+        self.files.iter().map(|f| f.applied).sum()
+    }
+
+    /// Total number of skipped entries.
+    ///
+    /// # Returns
+    ///
+    /// Sum of skipped entries across all files
+    pub fn total_skipped(&self) -> usize {
+        self.files.iter().map(|f| f.skipped).sum()
+    }
+
+    /// Number of files that were written.
+    ///
+    /// # Returns
+    ///
+    /// Count of files with at least one applied change or moved
+    pub fn files_written(&self) -> usize {
+        self.files
+            .iter()
+            .filter(|f| f.applied > 0 || f.renamed.is_some())
+            .count()
+    }
+}
 
 /// Applies selected diff entries to their files.
 ///
@@ -28,22 +90,54 @@ use crate::{analyzer::Suggestion, error::IoError, fixer::apply_suggestions};
 ///
 /// # Returns
 ///
-/// `AppResult<usize>` - Number of line changes written across all files
+/// `AppResult<ApplyReport>` - What was written to each file
 ///
 /// # Errors
 ///
 /// Returns an error if reading or writing a file fails.
-pub fn apply_diff(result: &DiffResult) -> AppResult<usize> {
-    let mut applied = 0;
+pub fn apply_diff(result: &DiffResult) -> AppResult<ApplyReport> {
+    let mut report = ApplyReport::default();
 
     for file in &result.files {
-        applied += apply_file(file)?;
+        if let Some(applied) = apply_file(file)? {
+            report.files.push(applied);
+        }
     }
 
-    Ok(applied)
+    Ok(report)
+}
+
+/// Applies the entries and the move of a single file diff.
+///
+/// # Arguments
+///
+/// * `file` - File diff with the entries to apply
+///
+/// # Returns
+///
+/// `AppResult<Option<AppliedFile>>` - Outcome for this file, `None` if
+/// nothing was selected
+fn apply_file(file: &FileDiff) -> AppResult<Option<AppliedFile>> {
+    if file.total_changes() == 0 {
+        return Ok(None);
+    }
+
+    let mut applied = apply_entries(file)?;
+
+    if let Some(rename) = &file.rename {
+        let (path, target) = (Path::new(&file.path), Path::new(&rename.target));
+        if path.exists() && !target.exists() {
+            rename_file(path, target)?;
+            applied.renamed = Some(rename.target.clone());
+        } else {
+            applied.skipped += 1;
+        }
+    }
+
+    Ok(Some(applied))
 }
 
-/// Applies the entries of a single file diff.
+/// Applies the line entries of a single file diff.
 ///
 /// # Arguments
 ///
@@ -51,10 +145,17 @@ pub fn apply_diff(result: &DiffResult) -> AppResult<usize> {
 ///
 /// # Returns
 ///
-/// `AppResult<usize>` - Number of line changes written for this file
-fn apply_file(file: &FileDiff) -> AppResult<usize> {
+/// `AppResult<AppliedFile>` - Outcome of the edits, without the file move
+fn apply_entries(file: &FileDiff) -> AppResult<AppliedFile> {
+    let mut applied = AppliedFile {
+        path:    file.path.clone(),
+        applied: 0,
+        imports: 0,
+        skipped: 0,
+        renamed: None
+    };
     if file.entries.is_empty() {
-        return Ok(0);
+        return Ok(applied);
     }
 
     let content = fs::read_to_string(&file.path).map_err(IoError::from)?;
@@ -71,14 +172,18 @@ fn apply_file(file: &FileDiff) -> AppResult<usize> {
         }
     }
 
+    applied.skipped = file.entries.len() - suggestions.len();
     if suggestions.is_empty() {
-        return Ok(0);
+        return Ok(applied);
     }
 
-    let updated = apply_suggestions(&content, &suggestions);
-    fs::write(&file.path, updated).map_err(IoError::from)?;
+    let outcome = apply_suggestions(&content, &suggestions);
+    fs::write(&file.path, &outcome.source).map_err(IoError::from)?;
 
-    Ok(suggestions.len())
+    applied.applied = outcome.applied;
+    applied.imports = outcome.imports;
+    applied.skipped += outcome.conflicts;
+    Ok(applied)
 }
 
 #[cfg(test)]
@@ -88,7 +193,7 @@ mod tests {
     use tempfile::TempDir;
 
     use super::*;
-    use crate::analyzers::get_analyzers;
+    use crate::{analyzers::get_analyzers, file_analyzer::get_file_analyzers};
 
     fn diff_for(path: &Path) -> DiffResult {
         let file = super::super::generate_diff(path.to_str().unwrap(), &get_analyzers()).unwrap();
@@ -103,12 +208,13 @@ mod tests {
         let path = temp.path().join("a.rs");
         fs::write(
             &path,
-            "//! Module doc\n\nfn main() {\n    // note\n    let x = std::fs::read_to_string(\"f\");\n}\n"
+            "//! Module doc\n\n// note\nfn main() {\n    let x = std::fs::read_to_string(\"f\");\n}\n"
         )
         .unwrap();
 
-        let applied = apply_diff(&diff_for(&path)).unwrap();
-        assert_eq!(applied, 1);
+        let report = apply_diff(&diff_for(&path)).unwrap();
+        assert_eq!(report.total_applied(), 1);
+        assert_eq!(report.files[0].imports, 1);
 
         let output = fs::read_to_string(&path).unwrap();
         assert!(output.contains("use std::fs::read_to_string;"));
@@ -129,8 +235,9 @@ mod tests {
         )
         .unwrap();
 
-        let applied = apply_diff(&diff_for(&path)).unwrap();
-        assert_eq!(applied, 2);
+        let report = apply_diff(&diff_for(&path)).unwrap();
+        assert_eq!(report.total_applied(), 2);
+        assert_eq!(report.files[0].imports, 1);
 
         let output = fs::read_to_string(&path).unwrap();
         assert_eq!(output.matches("use std::fs::read_to_string;").count(), 1);
@@ -153,8 +260,8 @@ mod tests {
             "colliding reads produce no changes"
         );
 
-        let applied = apply_diff(&result).unwrap();
-        assert_eq!(applied, 0);
+        let report = apply_diff(&result).unwrap();
+        assert_eq!(report.total_applied(), 0);
         assert!(
             fs::read_to_string(&path)
                 .unwrap()
@@ -175,14 +282,48 @@ mod tests {
         let result = diff_for(&path);
         fs::write(&path, "fn main() {\n    let y = 1;\n}\n").unwrap();
 
-        let applied = apply_diff(&result).unwrap();
-        assert_eq!(applied, 0, "stale entry is skipped");
+        let report = apply_diff(&result).unwrap();
+        assert_eq!(report.total_applied(), 0, "stale entry is skipped");
+        assert_eq!(report.total_skipped(), 1);
+        assert_eq!(report.files_written(), 0);
         assert!(fs::read_to_string(&path).unwrap().contains("let y = 1;"));
     }
 
+    #[test]
+    fn test_apply_edits_then_moves_file() {
+        let temp = TempDir::new().unwrap();
+        let dir = temp.path().join("net");
+        fs::create_dir(&dir).unwrap();
+        let path = dir.join("mod.rs");
+        fs::write(
+            &path,
+            "fn main() {\n    let x = std::fs::read_to_string(\"f\");\n}\n"
+        )
+        .unwrap();
+
+        let result = super::super::generate_diffs(
+            &[path.to_str().unwrap()],
+            &get_analyzers(),
+            &get_file_analyzers()
+        )
+        .unwrap();
+        let report = apply_diff(&result).unwrap();
+
+        let target = temp.path().join("net.rs");
+        assert_eq!(report.files[0].renamed.as_deref(), target.to_str());
+        assert_eq!(report.files_written(), 1);
+        assert!(!dir.exists());
+        assert!(
+            fs::read_to_string(&target)
+                .unwrap()
+                .contains("use std::fs::read_to_string;")
+        );
+    }
+
     #[test]
     fn test_apply_empty_result() {
-        let applied = apply_diff(&DiffResult::new()).unwrap();
-        assert_eq!(applied, 0);
+        let report = apply_diff(&DiffResult::new()).unwrap();
+        assert_eq!(report.total_applied(), 0);
+        assert!(report.files.is_empty());
     }
 }
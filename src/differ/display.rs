@@ -32,7 +32,7 @@
 //! use cargo_quality::differ::{DiffResult, display::show_full};
 //!
 //! let result = DiffResult::new();
-//! show_full(&result, false);
+//! show_full(&result, false, None);
 //! ```
 
 pub mod formatting;
@@ -43,20 +43,29 @@ pub mod types;
 
 // Re-export key types and functions for public API
 use std::{
-    collections::HashMap,
+    collections::BTreeMap,
     io::{self, Write}
 };
 
 use masterror::AppResult;
 use owo_colors::OwoColorize;
-use terminal_size::{Width, terminal_size};
 
 pub use self::{
-    grid::{calculate_columns, render_grid},
+    grid::{calculate_columns, fit_to_width, render_grid},
     render::render_file_block
 };
-use super::types::{DiffResult, FileDiff};
-use crate::error::IoError;
+use super::{
+    apply::ApplyReport,
+    types::{DiffResult, FileDiff}
+};
+use crate::{
+    error::IoError,
+    history::HistoryEntry,
+    terminal::{is_narrow, terminal_width}
+};
+
+/// Terminal width assumed when it cannot be detected.
+const DEFAULT_TERMINAL_WIDTH: usize = 80;
 
 /// Displays diff in summary mode with brief statistics.
 ///
@@ -79,9 +88,21 @@ use crate::error::IoError;
 /// Total: 6 changes in 2 files
 /// ```
 ///
+/// With the counts of a previous run, the changes per analyzer are listed
+/// with their delta before the total:
+///
+/// ```text
+/// Since last run:
+///   analyzer1: 5 (+2)
+///   analyzer2: 1 (-1)
+/// ```
+///
 /// # Arguments
 ///
 /// * `result` - Diff results to display
+/// * `previous` - Counts of the previous run, from the
+///   [`History`](crate::history::History)
+/// * `color` - Enable colored output
 ///
 /// # Examples
 ///
@@ -89,9 +110,9 @@ use crate::error::IoError;
 /// use cargo_quality::differ::{DiffResult, display::show_summary};
 ///
 /// let result = DiffResult::new();
-/// show_summary(&result, false);
+/// show_summary(&result, None, false);
 /// ```
-pub fn show_summary(result: &DiffResult, color: bool) {
+pub fn show_summary(result: &DiffResult, previous: Option<&HistoryEntry>, color: bool) {
     if color {
         println!("\n{}\n", "DIFF SUMMARY".bold());
     } else {
@@ -105,10 +126,13 @@ pub fn show_summary(result: &DiffResult, color: bool) {
             println!("{}:", file.path);
         }
 
-        let mut analyzer_counts = HashMap::new();
+        let mut analyzer_counts = BTreeMap::new();
         for entry in &file.entries {
             *analyzer_counts.entry(&entry.analyzer).or_insert(0) += 1;
         }
+        if let Some(rename) = &file.rename {
+            *analyzer_counts.entry(&rename.analyzer).or_insert(0) += 1;
+        }
 
         for (analyzer, count) in analyzer_counts {
             if color {
@@ -130,6 +154,10 @@ pub fn show_summary(result: &DiffResult, color: bool) {
         println!();
     }
 
+    if let Some(previous) = previous {
+        show_deltas(result, previous, color);
+    }
+
     let summary = format!(
         "Total: {} changes in {} files",
         result.total_changes(),
@@ -143,6 +171,43 @@ pub fn show_summary(result: &DiffResult, color: bool) {
     }
 }
 
+/// Prints the changes per analyzer with their delta since a previous run.
+///
+/// Analyzers that proposed changes in the previous run but none now are
+/// listed with a count of `0`.
+///
+/// # Arguments
+///
+/// * `result` - Diff results of the current run
+/// * `previous` - Counts of the previous run
+/// * `color` - Enable colored output
+fn show_deltas(result: &DiffResult, previous: &HistoryEntry, color: bool) {
+    let mut counts = result.analyzer_counts();
+    for analyzer in previous.counts.keys() {
+        counts.entry(analyzer.clone()).or_insert(0);
+    }
+
+    if color {
+        println!("{}", "Since last run:".bold());
+    } else {
+        println!("Since last run:");
+    }
+    for (analyzer, count) in counts {
+        let delta = previous.delta(&analyzer, count);
+        if color {
+            let delta = if count > previous.count(&analyzer) {
+                delta.red().to_string()
+            } else {
+                delta.green().to_string()
+            };
+            println!("  {}: {} {}", analyzer.green(), count, delta);
+        } else {
+            println!("  {}: {} {}", analyzer, count, delta);
+        }
+    }
+    println!();
+}
+
 /// Displays full responsive diff output with adaptive grid layout.
 ///
 /// Automatically arranges file diffs in newspaper-style columns based on
@@ -151,13 +216,16 @@ pub fn show_summary(result: &DiffResult, color: bool) {
 ///
 /// # Layout Modes
 ///
-/// - **Narrow** (< 100 chars): Single column, vertical stacking
-/// - **Medium** (100-200 chars): 2 columns side-by-side
+/// - **Narrow** (< 60 chars): Single column, long lines wrapped to the width
+/// - **Medium** (60-200 chars): Single column or 2 columns side-by-side
 /// - **Wide** (> 200 chars): 3+ columns based on content width
 ///
 /// # Arguments
 ///
 /// * `result` - Diff results to display
+/// * `color` - Enable colored output
+/// * `width` - Terminal width override; detected from `COLUMNS` or the terminal
+///   when `None`
 ///
 /// # Performance
 ///
@@ -172,23 +240,29 @@ pub fn show_summary(result: &DiffResult, color: bool) {
 /// use cargo_quality::differ::{DiffResult, display::show_full};
 ///
 /// let result = DiffResult::new();
-/// show_full(&result, false);
+/// show_full(&result, false, None);
 /// ```
-pub fn show_full(result: &DiffResult, color: bool) {
+pub fn show_full(result: &DiffResult, color: bool, width: Option<usize>) {
     if color {
         println!("\n{}\n", "DIFF OUTPUT".bold());
     } else {
         println!("\nDIFF OUTPUT\n");
     }
 
-    let term_width = terminal_size()
-        .map(|(Width(w), _)| w as usize)
-        .unwrap_or(80);
+    let term_width = terminal_width(width, DEFAULT_TERMINAL_WIDTH);
+    let narrow = is_narrow(term_width);
 
     let rendered: Vec<_> = result
         .files
         .iter()
         .map(|f| render_file_block(f, color))
+        .map(|block| {
+            if narrow {
+                fit_to_width(block, term_width)
+            } else {
+                block
+            }
+        })
         .collect();
 
     let columns = calculate_columns(&rendered, term_width);
@@ -275,12 +349,13 @@ pub fn show_interactive(result: &DiffResult, color: bool) -> AppResult<DiffResul
         println!();
 
         let mut file_selected = FileDiff::new(file.path.clone());
+        let total = file.total_changes();
 
         for (idx, entry) in file.entries.iter().enumerate() {
             if color {
                 println!(
                     "{} {}",
-                    format!("[{}/{}]", idx + 1, file.entries.len()).yellow(),
+                    format!("[{}/{}]", idx + 1, total).yellow(),
                     entry.analyzer.green()
                 );
                 println!("{}", format!("Line {}:", entry.line).dimmed());
@@ -292,7 +367,7 @@ pub fn show_interactive(result: &DiffResult, color: bool) -> AppResult<DiffResul
 
                 println!("{}", format!("+ {}", entry.modified).green());
             } else {
-                println!("[{}/{}] {}", idx + 1, file.entries.len(), entry.analyzer);
+                println!("[{}/{}] {}", idx + 1, total, entry.analyzer);
                 println!("Line {}:", entry.line);
                 println!("- {}", entry.original);
 
@@ -304,54 +379,119 @@ pub fn show_interactive(result: &DiffResult, color: bool) -> AppResult<DiffResul
             }
             println!();
 
-            if apply_all {
-                file_selected.add_entry(entry.clone());
-                continue;
+            match prompt_choice(&mut apply_all)? {
+                Choice::Apply => file_selected.add_entry(entry.clone()),
+                Choice::Skip => {}
+                Choice::Quit => {
+                    selected.add_file(file_selected);
+                    return Ok(finish_selection(selected));
+                }
             }
+        }
 
-            print!("{}", "Apply this fix? [y/n/a/q]: ".bold());
-            io::stdout().flush().map_err(IoError::from)?;
-
-            let mut input = String::new();
-            io::stdin().read_line(&mut input).map_err(IoError::from)?;
+        if let Some(rename) = &file.rename {
+            if color {
+                println!(
+                    "{} {}",
+                    format!("[{}/{}]", total, total).yellow(),
+                    rename.analyzer.green()
+                );
+                println!("{}", rename.description.dimmed());
+                println!("{}", format!("- {}", file.path).red());
+                println!("{}", format!("+ {}", rename.target).green());
+            } else {
+                println!("[{}/{}] {}", total, total, rename.analyzer);
+                println!("{}", rename.description);
+                println!("- {}", file.path);
+                println!("+ {}", rename.target);
+            }
+            println!();
 
-            match input.trim().to_lowercase().as_str() {
-                "y" | "yes" => {
-                    file_selected.add_entry(entry.clone());
-                    println!("{}", "Applied".green());
-                }
-                "n" | "no" => {
-                    println!("{}", "Skipped".yellow());
-                }
-                "a" | "all" => {
-                    apply_all = true;
-                    file_selected.add_entry(entry.clone());
-                    println!("{}", "Applying all remaining changes".green().bold());
-                }
-                "q" | "quit" => {
-                    println!("{}", "Quit".red());
+            match prompt_choice(&mut apply_all)? {
+                Choice::Apply => file_selected.rename = Some(rename.clone()),
+                Choice::Skip => {}
+                Choice::Quit => {
                     selected.add_file(file_selected);
-                    println!(
-                        "\n{}",
-                        format!(
-                            "Selected {} changes for application",
-                            selected.total_changes()
-                        )
-                        .yellow()
-                        .bold()
-                    );
-                    return Ok(selected);
-                }
-                _ => {
-                    println!("{}", "Invalid input, skipping".red());
+                    return Ok(finish_selection(selected));
                 }
             }
-            println!();
         }
 
         selected.add_file(file_selected);
     }
 
+    Ok(finish_selection(selected))
+}
+
+/// Answer to an interactive prompt.
+enum Choice {
+    /// Apply the change
+    Apply,
+    /// Leave the change out
+    Skip,
+    /// Stop asking and apply what was selected so far
+    Quit
+}
+
+/// Asks whether to apply the change shown last.
+///
+/// # Arguments
+///
+/// * `apply_all` - Set once the user answers `all`; no more questions are asked
+///   afterwards
+///
+/// # Returns
+///
+/// `AppResult<Choice>` - The answer, or error if stdin cannot be read
+fn prompt_choice(apply_all: &mut bool) -> AppResult<Choice> {
+    if *apply_all {
+        return Ok(Choice::Apply);
+    }
+
+    print!("{}", "Apply this fix? [y/n/a/q]: ".bold());
+    io::stdout().flush().map_err(IoError::from)?;
+
+    let mut input = String::new();
+    io::stdin().read_line(&mut input).map_err(IoError::from)?;
+
+    let choice = match input.trim().to_lowercase().as_str() {
+        "y" | "yes" => {
+            println!("{}", "Applied".green());
+            Choice::Apply
+        }
+        "n" | "no" => {
+            println!("{}", "Skipped".yellow());
+            Choice::Skip
+        }
+        "a" | "all" => {
+            *apply_all = true;
+            println!("{}", "Applying all remaining changes".green().bold());
+            Choice::Apply
+        }
+        "q" | "quit" => {
+            println!("{}", "Quit".red());
+            return Ok(Choice::Quit);
+        }
+        _ => {
+            println!("{}", "Invalid input, skipping".red());
+            Choice::Skip
+        }
+    };
+    println!();
+
+    Ok(choice)
+}
+
+/// Prints the number of selected changes and returns them.
+///
+/// # Arguments
+///
+/// * `selected` - Changes selected in interactive mode
+///
+/// # Returns
+///
+/// The selection, unchanged
+fn finish_selection(selected: DiffResult) -> DiffResult {
     println!(
         "\n{}",
         format!(
@@ -361,8 +501,109 @@ pub fn show_interactive(result: &DiffResult, color: bool) -> AppResult<DiffResul
         .yellow()
         .bold()
     );
+    selected
+}
+
+/// Displays a confirmation summary of changes written to disk.
+///
+/// Lists every file touched by an apply step with the number of changes and
+/// imports written, plus any entries that were skipped because the file
+/// changed since the diff was generated or the edit conflicted with another.
+///
+/// # Output Format
+///
+/// ```text
+/// APPLIED CHANGES
+///
+/// src/lib.rs: 2 changes written, 1 import added
+/// src/net/mod.rs: 0 changes written, moved to src/net.rs
+/// src/main.rs: 0 changes written, 1 skipped
+///
+/// Total: 2 changes written to 1 files
+/// ```
+///
+/// # Arguments
+///
+/// * `report` - Outcome returned by [`crate::differ::apply_diff`]
+/// * `color` - Enable colored output
+///
+/// # Examples
+///
+/// ```no_run
+/// use cargo_quality::differ::{apply::ApplyReport, display::show_apply_summary};
+///
+/// show_apply_summary(&ApplyReport::default(), false);
+/// ```
+pub fn show_apply_summary(report: &ApplyReport, color: bool) {
+    if color {
+        println!("\n{}\n", "APPLIED CHANGES".bold());
+    } else {
+        println!("\nAPPLIED CHANGES\n");
+    }
 
-    Ok(selected)
+    for file in &report.files {
+        let mut line = format!(
+            "{} {} written",
+            file.applied,
+            if file.applied == 1 {
+                "change"
+            } else {
+                "changes"
+            }
+        );
+        if file.imports > 0 {
+            line.push_str(&format!(
+                ", {} {} added",
+                file.imports,
+                if file.imports == 1 {
+                    "import"
+                } else {
+                    "imports"
+                }
+            ));
+        }
+
+        if let Some(target) = &file.renamed {
+            line.push_str(&format!(", moved to {target}"));
+        }
+
+        if color {
+            print!("{}: {}", file.path.cyan().bold(), line.green());
+            if file.skipped > 0 {
+                print!("{}", format!(", {} skipped", file.skipped).yellow());
+            }
+        } else {
+            print!("{}: {}", file.path, line);
+            if file.skipped > 0 {
+                print!(", {} skipped", file.skipped);
+            }
+        }
+        println!();
+    }
+
+    let summary = format!(
+        "Total: {} changes written to {} files",
+        report.total_applied(),
+        report.files_written()
+    );
+
+    if color {
+        println!("\n{}", summary.yellow().bold());
+    } else {
+        println!("\n{}", summary);
+    }
+
+    if report.total_skipped() > 0 {
+        let note = format!(
+            "Skipped {} changes: file changed since the diff or edits conflicted",
+            report.total_skipped()
+        );
+        if color {
+            println!("{}", note.dimmed());
+        } else {
+            println!("{}", note);
+        }
+    }
 }
 
 #[cfg(test)]
@@ -373,13 +614,28 @@ mod tests {
     #[test]
     fn test_show_summary_empty() {
         let result = DiffResult::new();
-        show_summary(&result, false);
+        show_summary(&result, None, false);
     }
 
     #[test]
     fn test_show_full_empty() {
         let result = DiffResult::new();
-        show_full(&result, false);
+        show_full(&result, false, None);
+    }
+
+    #[test]
+    fn test_show_apply_summary() {
+        let report = ApplyReport {
+            files: vec![crate::differ::apply::AppliedFile {
+                path:    "test.rs".to_string(),
+                applied: 2,
+                imports: 1,
+                skipped: 1,
+                renamed: None
+            }]
+        };
+        show_apply_summary(&report, false);
+        show_apply_summary(&report, true);
     }
 
     #[test]
@@ -398,7 +654,11 @@ mod tests {
         });
 
         result.add_file(file);
-        show_summary(&result, false);
+        show_summary(&result, None, false);
+
+        let previous = HistoryEntry::parse("test=3 unwrap=1");
+        show_summary(&result, Some(&previous), false);
+        show_summary(&result, Some(&previous), true);
     }
 
     #[test]
@@ -417,6 +677,7 @@ mod tests {
         });
 
         result.add_file(file);
-        show_full(&result, false);
+        show_full(&result, false, None);
+        show_full(&result, true, Some(30));
     }
 }
@@ -1,7 +1,10 @@
 // SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
 // SPDX-License-Identifier: MIT
 
+use console::{strip_ansi_codes, truncate_str};
+
 use super::{formatting::pad_to_width, types::RenderedFile};
+use crate::terminal::wrap_line;
 
 /// Minimum space between columns in grid layout.
 pub const COLUMN_GAP: usize = 4;
@@ -175,6 +178,62 @@ pub fn render_grid(files: &[RenderedFile], columns: usize) {
     }
 }
 
+/// Fits a rendered file into a narrow terminal.
+///
+/// Lines wider than the terminal wrap with their colors kept, so code is not
+/// cut off mid-token. Horizontal rules are cut to the width instead of
+/// wrapping onto a second line.
+///
+/// # Arguments
+///
+/// * `file` - Rendered file block
+/// * `width` - Terminal width in characters
+///
+/// # Returns
+///
+/// The block with no line wider than `width`
+///
+/// # Examples
+///
+/// ```
+/// use cargo_quality::differ::display::{grid::fit_to_width, types::RenderedFile};
+///
+/// let file = RenderedFile {
+///     lines: vec!["─".repeat(40), "+    let value = compute();".to_string()],
+///     width: 40
+/// };
+///
+/// let fitted = fit_to_width(file, 20);
+/// assert_eq!(fitted.lines[0], "─".repeat(20));
+/// assert_eq!(fitted.lines.len(), 3);
+/// assert_eq!(fitted.width, 20);
+/// ```
+pub fn fit_to_width(file: RenderedFile, width: usize) -> RenderedFile {
+    let width = width.max(1);
+    let lines = file
+        .lines
+        .iter()
+        .flat_map(|line| {
+            if is_rule(line) {
+                vec![truncate_str(line, width, "").into_owned()]
+            } else {
+                wrap_line(line, width)
+            }
+        })
+        .collect();
+
+    RenderedFile {
+        lines,
+        width: file.width.min(width)
+    }
+}
+
+/// Checks whether a line is a horizontal rule such as the header separator.
+fn is_rule(line: &str) -> bool {
+    let plain = strip_ansi_codes(line);
+    !plain.is_empty() && plain.chars().all(|ch| matches!(ch, '─' | '═'))
+}
+
 /// Renders files in single column mode.
 ///
 /// Simple vertical layout for narrow terminals or when optimal layout requires
@@ -303,6 +362,47 @@ mod tests {
         render_grid(&[file1, file2], 2);
     }
 
+    #[test]
+    fn test_fit_to_width_wraps_lines_and_cuts_rules() {
+        let file = RenderedFile {
+            lines: vec![
+                "File: src/some/deeply/nested/module.rs".to_string(),
+                "\x1b[2m────────────────────────────────────────\x1b[0m".to_string(),
+                "\x1b[32m+    let value = compute_the_value();\x1b[0m".to_string(),
+                String::new(),
+            ],
+            width: 40
+        };
+
+        let fitted = fit_to_width(file, 24);
+
+        assert_eq!(
+            fitted.lines,
+            vec![
+                "File: src/some/deeply/ne",
+                "sted/module.rs",
+                "\x1b[2m────────────────────────\x1b[0m",
+                "\x1b[32m+    let value = compute\x1b[0m",
+                "\x1b[32m_the_value();\x1b[0m",
+                "",
+            ]
+        );
+        assert_eq!(fitted.width, 24);
+    }
+
+    #[test]
+    fn test_fit_to_width_keeps_fitting_lines() {
+        let file = RenderedFile {
+            lines: vec!["short".to_string(), "─".repeat(10)],
+            width: 40
+        };
+
+        let fitted = fit_to_width(file, 30);
+
+        assert_eq!(fitted.lines, vec!["short".to_string(), "─".repeat(10)]);
+        assert_eq!(fitted.width, 30);
+    }
+
     #[test]
     fn test_calculate_columns_respects_min_width() {
         let files = vec![RenderedFile {
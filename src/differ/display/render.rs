@@ -26,6 +26,10 @@ const ESTIMATED_LINES_PER_FILE: usize = 20;
 /// ```text
 /// File: path/to/file.rs           <- Header (cyan + bold)
 /// ────────────────────────────    <- Separator
+/// Rename (mod_rs)                 <- File move, if proposed
+/// -    src/net/mod.rs             <- Current path (red)
+/// +    src/net.rs                 <- New path (green)
+///
 /// Imports (file top)              <- Import section header
 /// +    use std::fs::write;        <- Grouped imports (green)
 ///
@@ -72,6 +76,8 @@ pub fn render_file_block(file: &FileDiff, color: bool) -> RenderedFile {
 
     render_header(&mut lines, &mut max_width, &file.path, color);
 
+    render_rename(&mut lines, &mut max_width, file, color);
+
     render_imports(&mut lines, &mut max_width, file, color);
 
     render_issues(&mut lines, &mut max_width, file, color);
@@ -114,6 +120,39 @@ fn render_header(lines: &mut Vec<String>, max_width: &mut usize, path: &str, col
     }
 }
 
+/// Renders the proposed move of the file if present.
+///
+/// # Arguments
+///
+/// * `lines` - Output buffer
+/// * `max_width` - Running maximum width tracker
+/// * `file` - File diff data
+#[inline]
+fn render_rename(lines: &mut Vec<String>, max_width: &mut usize, file: &FileDiff, color: bool) {
+    let Some(rename) = &file.rename else {
+        return;
+    };
+
+    let rename_header = format!("Rename ({})", rename.analyzer);
+    let old_path = format!("-    {}", file.path);
+    let new_path = format!("+    {}", rename.target);
+    for line in [&rename_header, &old_path, &new_path] {
+        *max_width = (*max_width).max(measure_text_width(line));
+    }
+
+    if color {
+        lines.push(rename_header.green().bold().to_string());
+        lines.push(old_path.red().to_string());
+        lines.push(new_path.green().to_string());
+    } else {
+        lines.push(rename_header);
+        lines.push(old_path);
+        lines.push(new_path);
+    }
+
+    lines.push(String::new());
+}
+
 /// Renders grouped import section if present.
 ///
 /// # Arguments
@@ -336,7 +375,7 @@ mod tests {
     use super::*;
     use crate::{
         analyzer::TextEdit,
-        differ::types::{DiffEntry, FileDiff}
+        differ::types::{DiffEntry, FileDiff, FileRename}
     };
 
     #[test]
@@ -411,6 +450,21 @@ mod tests {
         assert!(rendered.lines.iter().any(|l| l.contains("analyzer2")));
     }
 
+    #[test]
+    fn test_render_file_block_with_rename() {
+        let mut file = FileDiff::new("src/net/mod.rs".to_string());
+        file.rename = Some(FileRename::new(
+            "mod_rs".to_string(),
+            "src/net.rs".to_string(),
+            "Use `net.rs`".to_string()
+        ));
+
+        let rendered = render_file_block(&file, false);
+        assert_eq!(rendered.lines[2], "Rename (mod_rs)");
+        assert_eq!(rendered.lines[3], "-    src/net/mod.rs");
+        assert_eq!(rendered.lines[4], "+    src/net.rs");
+    }
+
     #[test]
     fn test_render_respects_capacity() {
         let file = FileDiff::new("test.rs".to_string());
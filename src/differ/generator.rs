@@ -1,19 +1,29 @@
 // SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
 // SPDX-License-Identifier: MIT
 
-use std::fs;
+use std::{
+    num::NonZeroUsize,
+    panic::resume_unwind,
+    path::Path,
+    sync::atomic::{AtomicUsize, Ordering},
+    thread
+};
 
 use masterror::AppResult;
 
-use super::types::{DiffEntry, FileDiff};
+use super::types::{DiffEntry, DiffResult, FileDiff, FileRename};
 use crate::{
     analyzer::{Analyzer, Suggestion},
-    error::{IoError, ParseError}
+    file_analyzer::FileAnalyzer,
+    skipped::{Source, load_source},
+    suppression::Suppressions
 };
 
 /// Generates diff showing proposed changes.
 ///
-/// Analyzes files and compares current state with proposed fixes.
+/// Analyzes files and compares current state with proposed fixes. A file
+/// that is generated, too large or does not parse is recorded in
+/// [`FileDiff::skipped`] instead of analyzed.
 ///
 /// # Arguments
 ///
@@ -22,7 +32,7 @@ use crate::{
 ///
 /// # Returns
 ///
-/// `AppResult<FileDiff>` - Diff results or error
+/// `AppResult<FileDiff>` - Diff results, IO or analyzer error
 ///
 /// # Examples
 ///
@@ -31,13 +41,26 @@ use crate::{
 /// let diff = generate_diff("src/main.rs", &get_analyzers()).unwrap();
 /// ```
 pub fn generate_diff(file_path: &str, analyzers: &[Box<dyn Analyzer>]) -> AppResult<FileDiff> {
-    let content = fs::read_to_string(file_path).map_err(IoError::from)?;
-    let ast = syn::parse_file(&content).map_err(ParseError::from)?;
-
     let mut file_diff = FileDiff::new(file_path.to_string());
+    let (content, ast) = match load_source(Path::new(file_path))? {
+        Source::Parsed {
+            content,
+            ast
+        } => (content, ast),
+        Source::Skipped(file) => {
+            file_diff.skipped = Some(file);
+            return Ok(file_diff);
+        }
+    };
+
+    let suppressions = Suppressions::from_ast(&ast);
 
-    for analyzer in analyzers {
-        for suggestion in analyzer.suggestions(&ast, &content)? {
+    for analyzer in analyzers
+        .iter()
+        .filter(|a| a.applies_to(Path::new(file_path)))
+    {
+        let suggestions = analyzer.file_suggestions(Path::new(file_path), &ast, &content)?;
+        for suggestion in suppressions.filter_suggestions(analyzer.name(), suggestions) {
             file_diff.add_entry(entry_from_suggestion(analyzer.name(), &content, suggestion));
         }
     }
@@ -45,6 +68,120 @@ pub fn generate_diff(file_path: &str, analyzers: &[Box<dyn Analyzer>]) -> AppRes
     Ok(file_diff)
 }
 
+/// Generates diffs for many files in parallel.
+///
+/// Files are handed out one at a time to a worker per available CPU, so a
+/// few large files do not hold up the rest. The result lists the files in
+/// the order they were given, independent of which worker finished first.
+///
+/// # Arguments
+///
+/// * `files` - Paths to analyze
+/// * `analyzers` - List of analyzers to apply
+/// * `file_analyzers` - File analyzers that may propose to move a file
+///
+/// # Returns
+///
+/// `AppResult<DiffResult>` - Diffs of the files with changes and the files
+/// skipped as generated, too large or unparsable, or the error of the first
+/// failing file in input order
+///
+/// # Examples
+///
+/// ```no_run
+/// use cargo_quality::{
+///     analyzers::get_analyzers, differ::generate_diffs, file_analyzer::get_file_analyzers
+/// };
+/// let result = generate_diffs(
+///     &["src/lib.rs", "src/main.rs"],
+///     &get_analyzers(),
+///     &get_file_analyzers()
+/// )
+/// .unwrap();
+/// ```
+pub fn generate_diffs(
+    files: &[&str],
+    analyzers: &[Box<dyn Analyzer>],
+    file_analyzers: &[Box<dyn FileAnalyzer>]
+) -> AppResult<DiffResult> {
+    let workers = thread::available_parallelism()
+        .map_or(1, NonZeroUsize::get)
+        .min(files.len());
+    let next = AtomicUsize::new(0);
+
+    let mut diffs: Vec<(usize, AppResult<FileDiff>)> = thread::scope(|scope| {
+        let handles: Vec<_> = (0..workers)
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut done = Vec::new();
+                    loop {
+                        let index = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(file) = files.get(index) else {
+                            return done;
+                        };
+                        done.push((index, diff_file(file, analyzers, file_analyzers)));
+                    }
+                })
+            })
+            .collect();
+
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap_or_else(|panic| resume_unwind(panic)))
+            .collect()
+    });
+    diffs.sort_by_key(|(index, _)| *index);
+
+    let mut result = DiffResult::new();
+    for (_, diff) in diffs {
+        let mut file_diff = diff?;
+        if let Some(file) = file_diff.skipped.take() {
+            result.skipped.add(file.path, file.cause, file.detail);
+        }
+        result.add_file(file_diff);
+    }
+    Ok(result)
+}
+
+/// Generates the diff of one file, including its proposed move.
+///
+/// The file is only parsed if one of the analyzers applies to it. A file
+/// that is generated, too large or does not parse gets no entries but may
+/// still be moved.
+///
+/// # Arguments
+///
+/// * `file_path` - Path to analyze
+/// * `analyzers` - List of analyzers to apply
+/// * `file_analyzers` - File analyzers that may propose to move the file
+///
+/// # Returns
+///
+/// `AppResult<FileDiff>` - Diff results or error
+fn diff_file(
+    file_path: &str,
+    analyzers: &[Box<dyn Analyzer>],
+    file_analyzers: &[Box<dyn FileAnalyzer>]
+) -> AppResult<FileDiff> {
+    let path = Path::new(file_path);
+    let mut file_diff = if analyzers.iter().any(|a| a.applies_to(path)) {
+        generate_diff(file_path, analyzers)?
+    } else {
+        FileDiff::new(file_path.to_string())
+    };
+
+    file_diff.rename = file_analyzers.iter().find_map(|analyzer| {
+        let issue = analyzer.analyze_path(path)?;
+        Some(FileRename::new(
+            analyzer.name().to_string(),
+            issue.rename?.display().to_string(),
+            issue.message
+        ))
+    });
+
+    Ok(file_diff)
+}
+
 /// Builds a displayable diff entry from a fix suggestion.
 ///
 /// Derives the affected line number and its before/after text from the
@@ -99,7 +236,7 @@ mod tests {
     use tempfile::TempDir;
 
     use super::*;
-    use crate::analyzers::get_analyzers;
+    use crate::{analyzers::get_analyzers, file_analyzer::get_file_analyzers, skipped::SkipCause};
 
     #[test]
     fn test_generate_diff_integration() {
@@ -117,6 +254,98 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_generate_diffs_keeps_file_order() {
+        let temp_dir = TempDir::new().unwrap();
+        let files: Vec<String> = (0..32)
+            .map(|index| {
+                let path = temp_dir.path().join(format!("file_{index}.rs"));
+                let body = "let x = std::fs::read_to_string(\"f\");".repeat(index % 5 + 1);
+                std::fs::write(&path, format!("fn main() {{ {body} }}")).unwrap();
+                path.to_str().unwrap().to_string()
+            })
+            .collect();
+        let paths: Vec<&str> = files.iter().map(String::as_str).collect();
+
+        let analyzers = get_analyzers();
+        let result = generate_diffs(&paths, &analyzers, &[]).unwrap();
+
+        let expected: Vec<_> = paths
+            .iter()
+            .map(|path| generate_diff(path, &analyzers).unwrap())
+            .collect();
+        assert_eq!(result.total_files(), expected.len());
+        for (diff, expected) in result.files.iter().zip(&expected) {
+            assert_eq!(diff.path, expected.path);
+            assert_eq!(diff.total_changes(), expected.total_changes());
+        }
+    }
+
+    #[test]
+    fn test_generate_diffs_reports_first_error() {
+        let temp_dir = TempDir::new().unwrap();
+        let valid = temp_dir.path().join("valid.rs");
+        let missing = temp_dir.path().join("missing.rs");
+        std::fs::write(&valid, "fn main() {}").unwrap();
+
+        let analyzers = get_analyzers();
+        let paths = [valid.to_str().unwrap(), missing.to_str().unwrap()];
+        assert!(generate_diffs(&paths, &analyzers, &[]).is_err());
+        assert!(
+            generate_diffs(&[], &analyzers, &[])
+                .unwrap()
+                .files
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_generate_diffs_skips_unparsable_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let invalid = temp_dir.path().join("invalid.rs");
+        let generated = temp_dir.path().join("generated.rs");
+        std::fs::write(&invalid, "fn main( {").unwrap();
+        std::fs::write(
+            &generated,
+            "// @generated\nfn main() { let x = std::fs::read_to_string(\"f\"); }"
+        )
+        .unwrap();
+
+        let analyzers = get_analyzers();
+        let paths = [invalid.to_str().unwrap(), generated.to_str().unwrap()];
+        let result = generate_diffs(&paths, &analyzers, &[]).unwrap();
+
+        assert_eq!(result.total_changes(), 0);
+        assert_eq!(result.skipped.count(SkipCause::ParseError), 1);
+        assert_eq!(result.skipped.count(SkipCause::Generated), 1);
+    }
+
+    #[test]
+    fn test_generate_diffs_proposes_file_moves() {
+        let temp_dir = TempDir::new().unwrap();
+        let net = temp_dir.path().join("net");
+        std::fs::create_dir(&net).unwrap();
+        let mod_rs = net.join("mod.rs");
+        std::fs::write(&mod_rs, "pub mod http;\n").unwrap();
+
+        let paths = [mod_rs.to_str().unwrap()];
+        let result = generate_diffs(&paths, &get_analyzers(), &get_file_analyzers()).unwrap();
+
+        assert_eq!(result.total_changes(), 1);
+        let rename = result.files[0].rename.as_ref().unwrap();
+        assert_eq!(rename.analyzer, "mod_rs");
+        assert_eq!(
+            rename.target,
+            temp_dir.path().join("net.rs").display().to_string()
+        );
+        assert!(
+            generate_diffs(&paths, &get_analyzers(), &[])
+                .unwrap()
+                .files
+                .is_empty()
+        );
+    }
+
     #[test]
     fn test_generate_diff_no_issues() {
         let temp_dir = TempDir::new().unwrap();
@@ -136,9 +365,13 @@ mod tests {
         std::fs::write(&file_path, "fn main() { invalid syntax +++").unwrap();
 
         let analyzers = get_analyzers();
-        let result = generate_diff(file_path.to_str().unwrap(), &analyzers);
+        let result = generate_diff(file_path.to_str().unwrap(), &analyzers).unwrap();
 
-        assert!(result.is_err());
+        assert_eq!(result.total_changes(), 0);
+        assert_eq!(
+            result.skipped.map(|file| file.cause),
+            Some(SkipCause::ParseError)
+        );
     }
 
     #[test]
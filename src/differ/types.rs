@@ -1,7 +1,12 @@
 // SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
 // SPDX-License-Identifier: MIT
 
-use crate::analyzer::TextEdit;
+use std::collections::BTreeMap;
+
+use crate::{
+    analyzer::TextEdit,
+    skipped::{SkippedFile, SkippedFiles}
+};
 
 /// Represents a single code change.
 ///
@@ -9,6 +14,8 @@ use crate::analyzer::TextEdit;
 /// the underlying [`TextEdit`] so the same change can be applied through the
 /// shared fix engine.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
 pub struct DiffEntry {
     pub line:        usize,
     pub analyzer:    String,
@@ -19,13 +26,52 @@ pub struct DiffEntry {
     pub edit:        TextEdit
 }
 
+/// Move of a whole file proposed by a file analyzer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct FileRename {
+    pub analyzer:    String,
+    pub target:      String,
+    pub description: String
+}
+
+impl FileRename {
+    /// Creates a proposed file move.
+    ///
+    /// # Arguments
+    ///
+    /// * `analyzer` - Name of the file analyzer
+    /// * `target` - Path the file is moved to
+    /// * `description` - Why the file is moved
+    ///
+    /// # Returns
+    ///
+    /// `FileRename` for a [`FileDiff`]
+    #[inline]
+    pub fn new(analyzer: String, target: String, description: String) -> Self {
+        Self {
+            analyzer,
+            target,
+            description
+        }
+    }
+}
+
 /// Diff results for a single file.
 ///
-/// Contains all proposed changes grouped by analyzer.
+/// Contains all proposed changes grouped by analyzer, the move of the file
+/// itself if a file analyzer proposes one, and why its content was left out
+/// if it is generated, too large or does not parse.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
 pub struct FileDiff {
     pub path:    String,
-    pub entries: Vec<DiffEntry>
+    pub entries: Vec<DiffEntry>,
+    pub rename:  Option<FileRename>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub skipped: Option<SkippedFile>
 }
 
 impl FileDiff {
@@ -42,7 +88,9 @@ impl FileDiff {
     pub fn new(path: String) -> Self {
         Self {
             path,
-            entries: Vec::new()
+            entries: Vec::new(),
+            rename: None,
+            skipped: None
         }
     }
 
@@ -60,10 +108,10 @@ impl FileDiff {
     ///
     /// # Returns
     ///
-    /// Number of diff entries
+    /// Number of diff entries, plus one if the file is moved
     #[inline]
     pub fn total_changes(&self) -> usize {
-        self.entries.len()
+        self.entries.len() + usize::from(self.rename.is_some())
     }
 }
 
@@ -71,8 +119,13 @@ impl FileDiff {
 ///
 /// Aggregates changes across multiple files.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
 pub struct DiffResult {
-    pub files: Vec<FileDiff>
+    pub files:   Vec<FileDiff>,
+    /// Files left out because they are generated, too large or do not parse
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub skipped: SkippedFiles
 }
 
 impl DiffResult {
@@ -84,7 +137,8 @@ impl DiffResult {
     #[inline]
     pub fn new() -> Self {
         Self {
-            files: Vec::new()
+            files:   Vec::new(),
+            skipped: SkippedFiles::new()
         }
     }
 
@@ -119,6 +173,26 @@ impl DiffResult {
     pub fn total_files(&self) -> usize {
         self.files.len()
     }
+
+    /// Counts the changes proposed by each analyzer across all files.
+    ///
+    /// # Returns
+    ///
+    /// Number of changes per analyzer name, file moves included
+    pub fn analyzer_counts(&self) -> BTreeMap<String, usize> {
+        let mut counts = BTreeMap::new();
+        for file in &self.files {
+            let analyzers = file
+                .entries
+                .iter()
+                .map(|entry| &entry.analyzer)
+                .chain(file.rename.as_ref().map(|rename| &rename.analyzer));
+            for analyzer in analyzers {
+                *counts.entry(analyzer.clone()).or_insert(0) += 1;
+            }
+        }
+        counts
+    }
 }
 
 impl Default for DiffResult {
@@ -171,6 +245,21 @@ mod tests {
         assert_eq!(diff.total_changes(), 1);
     }
 
+    #[test]
+    fn test_file_diff_rename_counts_as_change() {
+        let mut diff = FileDiff::new("src/net/mod.rs".to_string());
+        diff.rename = Some(FileRename::new(
+            "mod_rs".to_string(),
+            "src/net.rs".to_string(),
+            "desc".to_string()
+        ));
+
+        assert_eq!(diff.total_changes(), 1);
+        let mut result = DiffResult::new();
+        result.add_file(diff);
+        assert_eq!(result.total_files(), 1);
+    }
+
     #[test]
     fn test_diff_result_new() {
         let result = DiffResult::new();
@@ -241,4 +330,56 @@ mod tests {
         assert_eq!(result.total_files(), 2);
         assert_eq!(result.total_changes(), 2);
     }
+
+    #[test]
+    fn test_diff_result_analyzer_counts() {
+        let mut result = DiffResult::new();
+        let mut file = FileDiff::new("src/net/mod.rs".to_string());
+        file.add_entry(DiffEntry {
+            line:        1,
+            analyzer:    "path_import".to_string(),
+            original:    "old".to_string(),
+            modified:    "new".to_string(),
+            description: "desc".to_string(),
+            import:      None,
+            edit:        TextEdit::default()
+        });
+        file.add_entry(file.entries[0].clone());
+        file.rename = Some(FileRename::new(
+            "mod_rs".to_string(),
+            "src/net.rs".to_string(),
+            "desc".to_string()
+        ));
+        result.add_file(file);
+
+        let counts = result.analyzer_counts();
+        assert_eq!(counts.len(), 2);
+        assert_eq!(counts["path_import"], 2);
+        assert_eq!(counts["mod_rs"], 1);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_diff_result_serde_round_trip() {
+        let mut file = FileDiff::new("src/main.rs".to_string());
+        file.add_entry(DiffEntry {
+            line:        4,
+            analyzer:    "path_import".to_string(),
+            original:    "std::fs::read(p)".to_string(),
+            modified:    "read(p)".to_string(),
+            description: "Use import".to_string(),
+            import:      Some("use std::fs::read;".to_string()),
+            edit:        TextEdit {
+                range:       10..26,
+                replacement: "read(p)".to_string()
+            }
+        });
+        let mut result = DiffResult::new();
+        result.add_file(file);
+
+        let json = serde_json::to_string(&result).unwrap();
+        let restored: DiffResult = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.total_changes(), 1);
+        assert_eq!(restored.files[0].entries[0], result.files[0].entries[0]);
+    }
 }
@@ -48,7 +48,7 @@ pub struct InvalidConfigError {
 
 impl From<InvalidConfigError> for AppError {
     fn from(err: InvalidConfigError) -> Self {
-        AppError::bad_request(format!("Invalid configuration: {}", err.message))
+        AppError::config(format!("Invalid configuration: {}", err.message))
     }
 }
 
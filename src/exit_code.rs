@@ -0,0 +1,184 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Stable process exit codes.
+//!
+//! Scripts and CI jobs branch on how `cargo qual` exits, so every command
+//! maps its outcome to one entry of a fixed table. The numeric values are
+//! part of the public contract and never change meaning:
+//!
+//! | Code | Meaning |
+//! |------|---------|
+//! | 0 | Clean: the command succeeded and `check` found no issues |
+//! | 1 | Issues: `check` found issues that need manual changes, or the report no longer matches its `--snapshot` |
+//! | 2 | Fixable only: every issue `check` found can be fixed by `fix` |
+//! | 3 | Tool error: a file could not be read, parsed or written |
+//! | 4 | Config error: invalid command line or `metadata.quality` settings |
+//! | 130 | Interrupted: the run was stopped with Ctrl-C (`SIGINT`) |
+//!
+//! No signal handler is installed: a run stopped by `SIGINT` is terminated by
+//! the default disposition, which shells and CI runners report as `128 + 2`.
+
+use masterror::{AppError, AppErrorKind};
+
+/// Outcome of a run, as reported through the process exit code.
+///
+/// # Examples
+///
+/// ```
+/// use cargo_quality::exit_code::ExitCode;
+///
+/// assert_eq!(ExitCode::for_issues(0, 0), ExitCode::Clean);
+/// assert_eq!(ExitCode::for_issues(3, 3).code(), 2);
+/// assert_eq!(ExitCode::for_issues(3, 1).code(), 1);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExitCode {
+    /// Command succeeded and no issues were found
+    Clean,
+    /// Issues were found and some need manual changes
+    Issues,
+    /// Issues were found and all of them are automatically fixable
+    FixableOnly,
+    /// Reading, parsing or writing files failed
+    ToolError,
+    /// The command line or the project configuration is invalid
+    ConfigError,
+    /// The run was interrupted by `SIGINT`
+    Interrupted
+}
+
+impl ExitCode {
+    /// All exit codes, in table order.
+    pub const ALL: [Self; 6] = [
+        Self::Clean,
+        Self::Issues,
+        Self::FixableOnly,
+        Self::ToolError,
+        Self::ConfigError,
+        Self::Interrupted
+    ];
+
+    /// Returns the numeric process exit code.
+    ///
+    /// # Returns
+    ///
+    /// Value passed to the operating system
+    #[inline]
+    pub fn code(self) -> u8 {
+        match self {
+            Self::Clean => 0,
+            Self::Issues => 1,
+            Self::FixableOnly => 2,
+            Self::ToolError => 3,
+            Self::ConfigError => 4,
+            Self::Interrupted => 130
+        }
+    }
+
+    /// Returns a one-line description of the exit code.
+    ///
+    /// # Returns
+    ///
+    /// Meaning of the code for humans
+    pub fn description(self) -> &'static str {
+        match self {
+            Self::Clean => "Clean: the command succeeded and `check` found no issues",
+            Self::Issues => "Issues: `check` found issues that need manual changes",
+            Self::FixableOnly => "Fixable only: every issue `check` found can be fixed by `fix`",
+            Self::ToolError => "Tool error: a file could not be read, parsed or written",
+            Self::ConfigError => {
+                "Config error: invalid command line or `metadata.quality` settings"
+            }
+            Self::Interrupted => "Interrupted: the run was stopped with Ctrl-C (SIGINT)"
+        }
+    }
+
+    /// Classifies the issues found by `check`.
+    ///
+    /// # Arguments
+    ///
+    /// * `total` - Number of issues found
+    /// * `fixable` - Number of those issues `fix` can resolve
+    ///
+    /// # Returns
+    ///
+    /// [`ExitCode::Clean`], [`ExitCode::FixableOnly`] or [`ExitCode::Issues`]
+    pub fn for_issues(total: usize, fixable: usize) -> Self {
+        if total == 0 {
+            Self::Clean
+        } else if fixable >= total {
+            Self::FixableOnly
+        } else {
+            Self::Issues
+        }
+    }
+
+    /// Classifies an error that aborted a command.
+    ///
+    /// # Arguments
+    ///
+    /// * `error` - Error returned by the command
+    ///
+    /// # Returns
+    ///
+    /// [`ExitCode::ConfigError`] for configuration errors,
+    /// [`ExitCode::ToolError`] otherwise
+    pub fn from_error(error: &AppError) -> Self {
+        match error.kind {
+            AppErrorKind::Config => Self::ConfigError,
+            _ => Self::ToolError
+        }
+    }
+}
+
+/// Renders the exit code table printed by `--explain-exit-codes`.
+///
+/// # Returns
+///
+/// One line per exit code with its meaning
+pub fn table() -> String {
+    ExitCode::ALL
+        .iter()
+        .map(|code| format!("{:>3}  {}\n", code.code(), code.description()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use super::*;
+    use crate::error::{InvalidConfigError, IoError};
+
+    #[test]
+    fn test_codes_are_stable() {
+        let codes: Vec<u8> = ExitCode::ALL.iter().map(|code| code.code()).collect();
+        assert_eq!(codes, [0, 1, 2, 3, 4, 130]);
+    }
+
+    #[test]
+    fn test_for_issues() {
+        assert_eq!(ExitCode::for_issues(0, 0), ExitCode::Clean);
+        assert_eq!(ExitCode::for_issues(2, 2), ExitCode::FixableOnly);
+        assert_eq!(ExitCode::for_issues(2, 1), ExitCode::Issues);
+        assert_eq!(ExitCode::for_issues(2, 0), ExitCode::Issues);
+    }
+
+    #[test]
+    fn test_from_error() {
+        let config: AppError = InvalidConfigError::new("bad".to_string()).into();
+        assert_eq!(ExitCode::from_error(&config), ExitCode::ConfigError);
+
+        let io: AppError = IoError::from(io::Error::other("disk")).into();
+        assert_eq!(ExitCode::from_error(&io), ExitCode::ToolError);
+    }
+
+    #[test]
+    fn test_table_lists_every_code() {
+        let table = table();
+        assert_eq!(table.lines().count(), ExitCode::ALL.len());
+        assert!(table.starts_with("  0  Clean"));
+        assert!(table.contains("130  Interrupted"));
+    }
+}
@@ -0,0 +1,556 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! File-level analyzers working on paths instead of syntax trees.
+//!
+//! Some rules are about where code lives rather than what it says, e.g.
+//! `src/net/mod.rs` that should be `src/net.rs`. A [`FileAnalyzer`] looks at
+//! the path of every file `check`, `fix` and `diff` visit, and may propose to
+//! move the file. Its issues are reported next to those of the [`Analyzer`]s
+//! and its renames are applied by the same commands.
+//!
+//! Moving a file also rewrites what points at it, in every file of the
+//! crate, so the project still compiles: `#[path = "..."]` attributes
+//! resolving to the old location are removed, since the new one is where the
+//! module is looked up by default, and doc comments mentioning its path are
+//! updated.
+//!
+//! [`Analyzer`]: crate::analyzer::Analyzer
+
+use std::{
+    fs::{self, read_dir, remove_dir, rename},
+    io,
+    ops::Range,
+    path::{Component, Path, PathBuf}
+};
+
+use masterror::AppResult;
+use syn::{Expr, ExprLit, ItemMod, Lit, Meta, visit::Visit};
+
+use crate::{
+    analyzer::{AnalysisResult, Fix, Issue, Severity},
+    analyzers::wildcard_reexport::module_dir,
+    error::IoError,
+    file_utils::collect_rust_files,
+    mod_rs::ModRsAnalyzer
+};
+
+/// Issue found in the location of a file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileIssue {
+    /// Human-readable message
+    pub message: String,
+    /// Path the file should be moved to, if the issue is fixable
+    pub rename:  Option<PathBuf>
+}
+
+impl FileIssue {
+    /// Converts the issue into an analysis result for reports.
+    ///
+    /// File-level issues point at the first line of the file, and the fix
+    /// holds the path the file is moved to.
+    ///
+    /// # Returns
+    ///
+    /// Result with a single issue
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::PathBuf;
+    ///
+    /// use cargo_quality::file_analyzer::FileIssue;
+    ///
+    /// let issue = FileIssue {
+    ///     message: "Use `net.rs`".to_string(),
+    ///     rename:  Some(PathBuf::from("src/net.rs"))
+    /// };
+    /// let result = issue.into_result();
+    ///
+    /// assert_eq!(result.fixable_count, 1);
+    /// assert_eq!(result.issues[0].fix.as_simple(), Some("src/net.rs"));
+    /// ```
+    pub fn into_result(self) -> AnalysisResult {
+        let fix = self.rename.map_or(Fix::None, |target| {
+            Fix::Simple(target.display().to_string())
+        });
+        let fixable_count = usize::from(fix.is_available());
+
+        AnalysisResult {
+            issues: vec![Issue {
+                line: 1,
+                column: 1,
+                message: self.message,
+                fix
+            }],
+            fixable_count
+        }
+    }
+}
+
+/// Analyzer of file locations.
+///
+/// # Examples
+///
+/// ```
+/// use std::path::Path;
+///
+/// use cargo_quality::file_analyzer::{FileAnalyzer, FileIssue};
+///
+/// struct TestsDir;
+///
+/// impl FileAnalyzer for TestsDir {
+///     fn name(&self) -> &'static str {
+///         "tests_dir"
+///     }
+///
+///     fn analyze_path(&self, path: &Path) -> Option<FileIssue> {
+///         path.starts_with("test").then(|| FileIssue {
+///             message: "Integration tests belong in `tests/`".to_string(),
+///             rename:  None
+///         })
+///     }
+/// }
+///
+/// assert!(TestsDir.analyze_path(Path::new("test/it.rs")).is_some());
+/// ```
+pub trait FileAnalyzer: Send + Sync {
+    /// Returns the analyzer name.
+    fn name(&self) -> &'static str;
+
+    /// Analyzes the location of a file.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path of the file
+    ///
+    /// # Returns
+    ///
+    /// Issue of the file, `None` if its location is fine
+    fn analyze_path(&self, path: &Path) -> Option<FileIssue>;
+
+    /// Returns the severity of the reported issues.
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+}
+
+/// Returns all file-level analyzers.
+///
+/// # Returns
+///
+/// Vector of boxed file analyzers, in the order they run
+///
+/// # Examples
+///
+/// ```
+/// use cargo_quality::file_analyzer::get_file_analyzers;
+///
+/// let analyzers = get_file_analyzers();
+/// assert_eq!(analyzers[0].name(), "mod_rs");
+/// ```
+pub fn get_file_analyzers() -> Vec<Box<dyn FileAnalyzer>> {
+    vec![Box::new(ModRsAnalyzer::new())]
+}
+
+/// Moves a file to the path proposed by a file analyzer.
+///
+/// An existing target is never overwritten. The directory the file was in
+/// is removed if the move left it empty. Within the crate of the file,
+/// `#[path]` attributes resolving to it are removed when the new location is
+/// the default one of the module, and doc comments mentioning its
+/// crate-relative path are updated.
+///
+/// A file loaded through `#[path]` looks up its submodules next to itself,
+/// like `mod.rs`, so an attribute pointing anywhere else cannot follow the
+/// move and the file is left in place.
+///
+/// # Arguments
+///
+/// * `from` - Current path of the file
+/// * `to` - Path to move the file to
+///
+/// # Returns
+///
+/// `AppResult<usize>` - Number of other files rewritten to follow the move,
+/// or IO error if the target exists, a `#[path]` attribute cannot follow the
+/// move or a file cannot be written
+pub fn rename_file(from: &Path, to: &Path) -> AppResult<usize> {
+    if to.exists() {
+        return Err(IoError::from(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!(
+                "target already exists, refusing to overwrite: {}",
+                to.display()
+            )
+        ))
+        .into());
+    }
+
+    let references = find_references(from, to)?;
+
+    rename(from, to).map_err(IoError::from)?;
+    if let Some(parent) = from.parent()
+        && is_directory_empty(parent)?
+    {
+        remove_dir(parent).map_err(IoError::from)?;
+    }
+
+    for (file, content) in &references {
+        let file = if file == from { to } else { file };
+        fs::write(file, content).map_err(IoError::from)?;
+    }
+    Ok(references.len())
+}
+
+/// Rewrites the files of the crate that refer to a file about to move.
+///
+/// # Arguments
+///
+/// * `from` - Current path of the file
+/// * `to` - Path the file is moved to
+///
+/// # Returns
+///
+/// `AppResult<Vec<(PathBuf, String)>>` - Files whose content changes, with
+/// the new content; empty outside a Cargo package, IO error if a `#[path]`
+/// attribute cannot follow the move
+fn find_references(from: &Path, to: &Path) -> AppResult<Vec<(PathBuf, String)>> {
+    let (Ok(from), Some(to_dir), Some(to_name)) = (
+        from.canonicalize(),
+        to.parent().and_then(|dir| dir.canonicalize().ok()),
+        to.file_name()
+    ) else {
+        return Ok(Vec::new());
+    };
+    let to = to_dir.join(to_name);
+    let Some(root) = from
+        .ancestors()
+        .find(|dir| dir.join("Cargo.toml").is_file())
+    else {
+        return Ok(Vec::new());
+    };
+    let Some(root_str) = root.to_str() else {
+        return Ok(Vec::new());
+    };
+
+    let mentions = (
+        slash_path(from.strip_prefix(root).unwrap_or(&from)),
+        slash_path(to.strip_prefix(root).unwrap_or(&to))
+    );
+
+    let mut references = Vec::new();
+    for file in collect_rust_files(root_str)? {
+        let content = fs::read_to_string(&file).map_err(IoError::from)?;
+        let Some(rewritten) = rewrite_references(&file, &content, &from, &to, &mentions) else {
+            return Err(IoError::from(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "{} loads {} through #[path], refusing to move it: submodules would \
+                     resolve elsewhere",
+                    file.display(),
+                    from.display()
+                )
+            ))
+            .into());
+        };
+        if rewritten != content {
+            references.push((file, rewritten));
+        }
+    }
+    Ok(references)
+}
+
+/// Points the references of one file at the new location.
+///
+/// # Arguments
+///
+/// * `file` - File holding the references
+/// * `content` - Source of the file
+/// * `from` - Canonical current path of the moved file
+/// * `to` - Canonical new path of the moved file, in an existing directory
+/// * `mentions` - Old and new crate-relative path as written in docs
+///
+/// # Returns
+///
+/// The source with `#[path]` attributes and doc comments updated, `None` if
+/// a `#[path]` attribute resolves to the file but the new location is not
+/// the default one of its module
+fn rewrite_references(
+    file: &Path,
+    content: &str,
+    from: &Path,
+    to: &Path,
+    mentions: &(String, String)
+) -> Option<String> {
+    let mut output = content.to_string();
+
+    if content.contains("path")
+        && let Ok(ast) = syn::parse_file(content)
+        && let Some(dir) = module_dir(file)
+    {
+        let mut visitor = PathAttrVisitor {
+            content,
+            dir,
+            from,
+            to,
+            removals: Vec::new(),
+            refused: false
+        };
+        visitor.visit_file(&ast);
+        if visitor.refused {
+            return None;
+        }
+        visitor.removals.sort_by_key(|range| range.start);
+        for range in visitor.removals.into_iter().rev() {
+            output.replace_range(range, "");
+        }
+    }
+
+    let (old, new) = mentions;
+    if output.contains(old.as_str()) {
+        output = output
+            .split_inclusive('\n')
+            .map(|line| {
+                let code = line.trim_start();
+                if code.starts_with("///") || code.starts_with("//!") {
+                    line.replace(old.as_str(), new)
+                } else {
+                    line.to_string()
+                }
+            })
+            .collect();
+    }
+
+    Some(output)
+}
+
+/// Finds `#[path]` attributes of out-of-line modules resolving to a file.
+struct PathAttrVisitor<'a> {
+    content:  &'a str,
+    /// Directory `#[path]` values are relative to in the current scope,
+    /// below the canonical crate root
+    dir:      PathBuf,
+    from:     &'a Path,
+    to:       &'a Path,
+    /// Byte ranges of the attributes to remove
+    removals: Vec<Range<usize>>,
+    /// Whether an attribute resolves to the file but cannot be removed
+    refused:  bool
+}
+
+impl<'ast> Visit<'ast> for PathAttrVisitor<'_> {
+    fn visit_item_mod(&mut self, node: &'ast ItemMod) {
+        if node.content.is_some() {
+            self.dir.push(node.ident.to_string());
+            syn::visit::visit_item_mod(self, node);
+            self.dir.pop();
+            return;
+        }
+
+        for attr in node
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("path"))
+        {
+            if let Meta::NameValue(meta) = &attr.meta
+                && let Expr::Lit(ExprLit {
+                    lit: Lit::Str(literal),
+                    ..
+                }) = &meta.value
+                && normalize(&self.dir.join(literal.value())) == self.from
+            {
+                let default = normalize(&self.dir.join(format!("{}.rs", node.ident)));
+                if default == self.to {
+                    let start = attr.pound_token.span.byte_range().start;
+                    let end = attr.bracket_token.span.close().byte_range().end;
+                    self.removals.push(line_range(self.content, start..end));
+                } else {
+                    self.refused = true;
+                }
+            }
+        }
+    }
+}
+
+/// Widens the range of an attribute to the whitespace following it, and to
+/// its whole line when nothing else is on it.
+///
+/// # Arguments
+///
+/// * `content` - Source holding the attribute
+/// * `range` - Byte range of the attribute
+///
+/// # Returns
+///
+/// Byte range to remove
+fn line_range(content: &str, range: Range<usize>) -> Range<usize> {
+    let rest = &content[range.end..];
+    let end = range.end + (rest.len() - rest.trim_start_matches([' ', '\t']).len());
+    let line_start = content[..range.start].rfind('\n').map_or(0, |idx| idx + 1);
+
+    if !content[line_start..range.start].trim().is_empty() {
+        return range.start..end;
+    }
+    if content[end..].starts_with("\r\n") {
+        line_start..end + 2
+    } else if content[end..].starts_with('\n') || end == content.len() {
+        line_start..(end + 1).min(content.len())
+    } else {
+        range.start..end
+    }
+}
+
+/// Resolve `.` and `..` in a path without touching the file system.
+///
+/// Directories implied by `#[path]` values need not exist, so the path
+/// cannot be canonicalized.
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            component => normalized.push(component)
+        }
+    }
+    normalized
+}
+
+/// Render a path with `/` separators, as written in source files.
+fn slash_path(path: &Path) -> String {
+    path.components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Checks if a directory is empty.
+///
+/// # Arguments
+///
+/// * `dir` - Directory path to check
+///
+/// # Returns
+///
+/// `AppResult<bool>` - true if directory has no entries
+fn is_directory_empty(dir: &Path) -> AppResult<bool> {
+    let mut entries = read_dir(dir).map_err(IoError::from)?;
+    Ok(entries.next().is_none())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::{create_dir, read_to_string, write};
+
+    use tempfile::TempDir;
+
+    use super::*;
+
+    #[test]
+    fn test_into_result_without_rename() {
+        let result = FileIssue {
+            message: "message".to_string(),
+            rename:  None
+        }
+        .into_result();
+
+        assert_eq!(result.issues.len(), 1);
+        assert_eq!(result.issues[0].line, 1);
+        assert_eq!(result.fixable_count, 0);
+    }
+
+    #[test]
+    fn test_rename_file_removes_empty_directory() {
+        let temp = TempDir::new().unwrap();
+        let dir = temp.path().join("net");
+        create_dir(&dir).unwrap();
+        write(dir.join("mod.rs"), "pub mod http;").unwrap();
+        let target = temp.path().join("net.rs");
+
+        rename_file(&dir.join("mod.rs"), &target).unwrap();
+
+        assert_eq!(read_to_string(&target).unwrap(), "pub mod http;");
+        assert!(!dir.exists());
+    }
+
+    #[test]
+    fn test_rename_file_rewrites_references() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+        write(root.join("Cargo.toml"), "[package]\nname = \"app\"\n").unwrap();
+        create_dir(root.join("src")).unwrap();
+        create_dir(root.join("src/net")).unwrap();
+        write(
+            root.join("src/lib.rs"),
+            "//! Networking lives in src/net/mod.rs.\n\
+             #[path = \"net/mod.rs\"]\nmod net;\nmod app;\n\
+             // src/net/mod.rs stays in plain comments\n"
+        )
+        .unwrap();
+        write(root.join("src/app.rs"), "").unwrap();
+        write(root.join("src/net/mod.rs"), "pub mod http;\n").unwrap();
+        write(root.join("src/net/http.rs"), "").unwrap();
+
+        let rewritten =
+            rename_file(&root.join("src/net/mod.rs"), &root.join("src/net.rs")).unwrap();
+
+        assert_eq!(rewritten, 1);
+        assert_eq!(
+            read_to_string(root.join("src/lib.rs")).unwrap(),
+            "//! Networking lives in src/net.rs.\nmod net;\nmod app;\n\
+             // src/net/mod.rs stays in plain comments\n"
+        );
+        assert!(root.join("src/net.rs").exists());
+        assert!(root.join("src/net/http.rs").exists());
+    }
+
+    #[test]
+    fn test_rename_file_refuses_path_to_other_location() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+        write(root.join("Cargo.toml"), "[package]\nname = \"app\"\n").unwrap();
+        create_dir(root.join("src")).unwrap();
+        create_dir(root.join("src/util")).unwrap();
+        write(
+            root.join("src/lib.rs"),
+            "#[path = \"util/mod.rs\"]\nmod helpers;\n"
+        )
+        .unwrap();
+        write(root.join("src/util/mod.rs"), "mod inner;\n").unwrap();
+        write(root.join("src/util/inner.rs"), "").unwrap();
+
+        assert!(rename_file(&root.join("src/util/mod.rs"), &root.join("src/util.rs")).is_err());
+        assert!(root.join("src/util/mod.rs").exists());
+        assert_eq!(
+            read_to_string(root.join("src/lib.rs")).unwrap(),
+            "#[path = \"util/mod.rs\"]\nmod helpers;\n"
+        );
+    }
+
+    #[test]
+    fn test_line_range() {
+        let code = "#[path = \"a.rs\"]\r\nmod a;\n    #[path = \"b.rs\"] mod b;\n";
+        let inline = code.rfind('#').unwrap();
+
+        assert_eq!(&code[line_range(code, 0..16)], "#[path = \"a.rs\"]\r\n");
+        assert_eq!(
+            &code[line_range(code, inline..inline + 16)],
+            "#[path = \"b.rs\"] "
+        );
+    }
+
+    #[test]
+    fn test_rename_file_refuses_to_overwrite() {
+        let temp = TempDir::new().unwrap();
+        let from = temp.path().join("a.rs");
+        let to = temp.path().join("b.rs");
+        write(&from, "a").unwrap();
+        write(&to, "b").unwrap();
+
+        assert!(rename_file(&from, &to).is_err());
+        assert_eq!(read_to_string(&to).unwrap(), "b");
+    }
+}
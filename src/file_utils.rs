@@ -1,11 +1,25 @@
 // SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
 // SPDX-License-Identifier: MIT
 
-use std::path::PathBuf;
-
-use ignore::WalkBuilder;
+use std::{
+    collections::BTreeSet,
+    fs,
+    io::BufRead,
+    path::{Path, PathBuf}
+};
+
+use ignore::{
+    WalkBuilder,
+    gitignore::{Gitignore, GitignoreBuilder}
+};
 use masterror::AppResult;
 
+use crate::{
+    error::{InvalidConfigError, IoError},
+    project::ProjectContext,
+    skipped::{SkipCause, SkippedFiles}
+};
+
 /// Collects all Rust source files from given path.
 ///
 /// Recursively walks through directories and finds all `.rs` files.
@@ -55,6 +69,195 @@ pub fn collect_rust_files(path: &str) -> AppResult<Vec<PathBuf>> {
     Ok(files)
 }
 
+/// Collects the Rust files of a project and the paths the walk leaves out.
+///
+/// Walks like [`collect_rust_files`], then drops the files matched by the
+/// `metadata.quality.exclude` globs. Directories and Rust files hidden by
+/// `.gitignore` and the other ignore files are recorded without descending
+/// into them, so an ignored `target/` costs a single entry.
+///
+/// # Arguments
+///
+/// * `path` - File or directory path to search
+/// * `project` - Project whose `exclude` globs apply
+///
+/// # Returns
+///
+/// `AppResult<(Vec<PathBuf>, SkippedFiles)>` - Sorted Rust files to analyze
+/// and the skipped paths, or an error for an invalid glob
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::path::Path;
+///
+/// use cargo_quality::{file_utils::collect_project_files, project::ProjectContext};
+///
+/// let project = ProjectContext::discover(Path::new(".")).unwrap();
+/// let (files, skipped) = collect_project_files(".", &project).unwrap();
+/// println!("{} files, {} skipped", files.len(), skipped.len());
+/// ```
+pub fn collect_project_files(
+    path: &str,
+    project: &ProjectContext
+) -> AppResult<(Vec<PathBuf>, SkippedFiles)> {
+    let mut skipped = SkippedFiles::new();
+    let root = Path::new(path);
+    if !root.is_dir() {
+        return Ok((collect_rust_files(path)?, skipped));
+    }
+
+    let mut visited = BTreeSet::new();
+    let mut files = Vec::new();
+    for entry in WalkBuilder::new(root)
+        .follow_links(true)
+        .git_ignore(true)
+        .git_global(true)
+        .git_exclude(true)
+        .build()
+        .flatten()
+    {
+        let Some(file_type) = entry.file_type() else {
+            continue;
+        };
+        if file_type.is_dir() {
+            visited.insert(entry.into_path());
+        } else if file_type.is_file() && is_rust_file(entry.path()) {
+            files.push(entry.into_path());
+        }
+    }
+
+    let walked: BTreeSet<PathBuf> = files.iter().cloned().collect();
+    let parents = visited.clone();
+    for entry in WalkBuilder::new(root)
+        .standard_filters(false)
+        .hidden(true)
+        .follow_links(true)
+        .filter_entry(move |entry| {
+            entry.depth() == 0
+                || entry
+                    .path()
+                    .parent()
+                    .is_some_and(|dir| parents.contains(dir))
+        })
+        .build()
+        .flatten()
+    {
+        let Some(file_type) = entry.file_type() else {
+            continue;
+        };
+        let ignored = if file_type.is_dir() {
+            !visited.contains(entry.path())
+        } else {
+            file_type.is_file() && is_rust_file(entry.path()) && !walked.contains(entry.path())
+        };
+        if ignored {
+            skipped.add(entry.into_path(), SkipCause::Ignored, None);
+        }
+    }
+
+    if let Some(exclude) = exclude_matcher(project)? {
+        files.retain(|file| {
+            if !is_excluded(&exclude, file) {
+                return true;
+            }
+            skipped.add(file.clone(), SkipCause::Excluded, None);
+            false
+        });
+    }
+
+    files.sort();
+    Ok((files, skipped))
+}
+
+/// Checks whether a path has the `.rs` extension.
+fn is_rust_file(path: &Path) -> bool {
+    path.extension().is_some_and(|e| e == "rs")
+}
+
+/// Builds the matcher of the `metadata.quality.exclude` globs.
+///
+/// # Arguments
+///
+/// * `project` - Project whose globs to match
+///
+/// # Returns
+///
+/// `AppResult<Option<Gitignore>>` - Matcher rooted at the manifest
+/// directory, `None` without globs, error for an invalid glob
+fn exclude_matcher(project: &ProjectContext) -> AppResult<Option<Gitignore>> {
+    let mut globs = project.exclude().peekable();
+    if globs.peek().is_none() {
+        return Ok(None);
+    }
+
+    let root = project.root().unwrap_or(Path::new("."));
+    let root = fs::canonicalize(root).map_err(IoError::from)?;
+    let mut builder = GitignoreBuilder::new(root);
+    for glob in globs {
+        builder.add_line(None, glob).map_err(|e| {
+            InvalidConfigError::new(format!("metadata.quality.exclude: {glob}: {e}"))
+        })?;
+    }
+    let matcher = builder
+        .build()
+        .map_err(|e| InvalidConfigError::new(format!("metadata.quality.exclude: {e}")))?;
+
+    Ok(Some(matcher))
+}
+
+/// Checks whether an exclude glob matches a file or one of its directories.
+fn is_excluded(matcher: &Gitignore, file: &Path) -> bool {
+    let Ok(file) = fs::canonicalize(file) else {
+        return false;
+    };
+    file.starts_with(matcher.path())
+        && matcher
+            .matched_path_or_any_parents(&file, false)
+            .is_ignore()
+}
+
+/// Reads Rust source file paths from a list, one path per line.
+///
+/// Lets editors and file watchers pass exactly the files that changed
+/// instead of walking the whole tree. Blank lines, files without the `.rs`
+/// extension and paths that no longer exist are skipped, so a watcher can
+/// forward every changed path as is.
+///
+/// # Arguments
+///
+/// * `reader` - Source of the list, usually standard input
+///
+/// # Returns
+///
+/// `AppResult<Vec<PathBuf>>` - Sorted, deduplicated Rust file paths, or an
+/// error if the list cannot be read
+///
+/// # Examples
+///
+/// ```
+/// use cargo_quality::file_utils::read_file_list;
+///
+/// let files = read_file_list("src/lib.rs\n\nREADME.md\nsrc/deleted.rs\n".as_bytes()).unwrap();
+/// assert_eq!(files, [std::path::PathBuf::from("src/lib.rs")]);
+/// ```
+pub fn read_file_list(reader: impl BufRead) -> AppResult<Vec<PathBuf>> {
+    let mut files = Vec::new();
+
+    for line in reader.lines() {
+        let line = line.map_err(IoError::from)?;
+        let path = PathBuf::from(line.trim());
+        if path.is_file() && path.extension().is_some_and(|e| e == "rs") {
+            files.push(path);
+        }
+    }
+
+    files.sort();
+    files.dedup();
+
+    Ok(files)
+}
+
 #[cfg(test)]
 mod tests {
     use std::fs;
@@ -63,6 +266,28 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn test_read_file_list() {
+        let temp_dir = TempDir::new().unwrap();
+        let first = temp_dir.path().join("a.rs");
+        let second = temp_dir.path().join("b.rs");
+        let other = temp_dir.path().join("notes.txt");
+        fs::write(&first, "fn main() {}").unwrap();
+        fs::write(&second, "fn main() {}").unwrap();
+        fs::write(&other, "text").unwrap();
+
+        let list = format!(
+            "{}\n\n  {}  \n{}\n{}\n{}\n",
+            second.display(),
+            first.display(),
+            other.display(),
+            temp_dir.path().join("deleted.rs").display(),
+            second.display()
+        );
+        let files = read_file_list(list.as_bytes()).unwrap();
+        assert_eq!(files, [first, second]);
+    }
+
     #[test]
     fn test_collect_rust_files_single_file() {
         let temp_dir = TempDir::new().unwrap();
@@ -159,6 +384,54 @@ mod tests {
         assert_eq!(files[0], file1);
     }
 
+    #[test]
+    fn test_collect_project_files_records_skipped() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::create_dir(root.join(".git")).unwrap();
+        fs::write(root.join(".gitignore"), "target/\nscratch.rs\n").unwrap();
+        fs::write(
+            root.join("Cargo.toml"),
+            "[package]\nname = \"demo\"\n\n[package.metadata.quality]\nexclude = [\"src/generated\"]\n"
+        )
+        .unwrap();
+
+        let src = root.join("src");
+        fs::create_dir_all(src.join("generated")).unwrap();
+        fs::create_dir_all(root.join("target/debug")).unwrap();
+        fs::write(src.join("lib.rs"), "fn main() {}").unwrap();
+        fs::write(src.join("generated/schema.rs"), "fn schema() {}").unwrap();
+        fs::write(root.join("scratch.rs"), "fn scratch() {}").unwrap();
+        fs::write(root.join("target/debug/build.rs"), "fn build() {}").unwrap();
+
+        let project = ProjectContext::discover(root).unwrap();
+        let (files, skipped) = collect_project_files(root.to_str().unwrap(), &project).unwrap();
+
+        assert_eq!(files, [src.join("lib.rs")]);
+        let mut paths: Vec<_> = skipped
+            .files
+            .iter()
+            .map(|file| {
+                (
+                    file.path.strip_prefix(root).unwrap().to_path_buf(),
+                    file.cause
+                )
+            })
+            .collect();
+        paths.sort();
+        assert_eq!(
+            paths,
+            [
+                (PathBuf::from("scratch.rs"), SkipCause::Ignored),
+                (
+                    PathBuf::from("src/generated/schema.rs"),
+                    SkipCause::Excluded
+                ),
+                (PathBuf::from("target"), SkipCause::Ignored)
+            ]
+        );
+    }
+
     #[test]
     fn test_collect_rust_files_respects_gitignore_in_git_repo() {
         let temp_dir = TempDir::new().unwrap();
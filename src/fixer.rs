@@ -7,31 +7,86 @@
 //! source and applied here. Because only the edited byte ranges change,
 //! comments, blank lines, and the author's formatting are preserved — unlike
 //! reprinting the AST, which drops comments and reformats the whole file.
+//!
+//! Suggestions from different analyzers may target the same bytes. Such
+//! conflicts are detected before anything is written: the first suggestion
+//! wins and every later one overlapping it is dropped, so a fix never splices
+//! two rewrites into each other.
+//!
+//! Imports required by fixes are placed the way `group_imports =
+//! "StdExternalCrate"` formats them: into the matching std, external or
+//! crate group of the file's `use` items, at their sorted position, so a
+//! follow-up `cargo qual fmt` has nothing left to move.
+
+use std::{cmp::Ordering, collections::HashSet, ops::Range};
 
-use std::collections::HashSet;
+use syn::{Item, ItemUse, spanned::Spanned};
 
-use crate::analyzer::{Suggestion, TextEdit};
+use crate::{
+    analyzer::{Suggestion, TextEdit},
+    analyzers::import_order::{Group, Import}
+};
+
+/// Outcome of applying suggestions to a single source.
+///
+/// # Examples
+///
+/// ```
+/// use cargo_quality::{
+///     analyzer::{Suggestion, TextEdit},
+///     fixer::apply_suggestions
+/// };
+///
+/// let edit = |range, text: &str| Suggestion {
+///     edit:   TextEdit {
+///         range,
+///         replacement: text.to_string()
+///     },
+///     import: None
+/// };
+/// let outcome = apply_suggestions("abcdef", &[edit(1..4, "X"), edit(2..5, "Y")]);
+///
+/// assert_eq!(outcome.source, "aXef");
+/// assert_eq!(outcome.applied, 1);
+/// assert_eq!(outcome.conflicts, 1);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FixOutcome {
+    /// The edited source
+    pub source:    String,
+    /// Number of suggestions applied
+    pub applied:   usize,
+    /// Number of suggestions dropped because they overlapped an applied one
+    pub conflicts: usize,
+    /// Number of distinct imports inserted
+    pub imports:   usize
+}
 
 /// Applies fix suggestions to the source, deduplicating their imports.
 ///
-/// Collects each suggestion's rewrite edit, inserts every distinct required
-/// import once at the top of the file, and applies them via [`apply_edits`].
-/// Comments, blank lines, and formatting outside the edits are preserved.
+/// Drops suggestions that conflict with an earlier one (see
+/// [`select_non_overlapping`]), collects the remaining rewrite edits, inserts
+/// every distinct required import once into its import group (see
+/// [`import_edits`]), and applies them via [`apply_edits`]. Comments, blank
+/// lines, and formatting outside the edits are preserved.
 ///
 /// # Arguments
 ///
 /// * `source` - Original source code
-/// * `suggestions` - Suggestions to apply
+/// * `suggestions` - Suggestions to apply, in priority order
 ///
 /// # Returns
 ///
-/// The edited source
-pub fn apply_suggestions(source: &str, suggestions: &[Suggestion]) -> String {
-    let mut edits: Vec<TextEdit> = suggestions.iter().map(|s| s.edit.clone()).collect();
+/// [`FixOutcome`] with the edited source and applied/conflict/import counts
+pub fn apply_suggestions(source: &str, suggestions: &[Suggestion]) -> FixOutcome {
+    let accepted = select_non_overlapping(suggestions);
+    let conflicts = suggestions.len() - accepted.len();
+
+    let mut edits: Vec<TextEdit> = accepted.iter().map(|s| s.edit.clone()).collect();
 
     let mut seen = HashSet::new();
     let mut imports = Vec::new();
-    for suggestion in suggestions {
+    for suggestion in &accepted {
         if let Some(import) = &suggestion.import
             && seen.insert(import.clone())
         {
@@ -39,17 +94,62 @@ pub fn apply_suggestions(source: &str, suggestions: &[Suggestion]) -> String {
         }
     }
 
-    if !imports.is_empty() {
-        let offset = import_insertion_offset(source);
-        let mut block = imports.join("\n");
-        block.push('\n');
-        edits.push(TextEdit {
-            range:       offset..offset,
-            replacement: block
-        });
+    edits.extend(import_edits(source, &imports));
+
+    FixOutcome {
+        source: apply_edits(source, edits),
+        applied: accepted.len(),
+        conflicts,
+        imports: imports.len()
     }
+}
 
-    apply_edits(source, edits)
+/// Selects the suggestions that can be applied together.
+///
+/// Walks the suggestions in order and keeps each one whose edit does not
+/// overlap an already kept edit. Earlier suggestions therefore take priority,
+/// which follows the analyzer order of [`crate::analyzers::get_analyzers`].
+///
+/// # Arguments
+///
+/// * `suggestions` - Candidate suggestions, in priority order
+///
+/// # Returns
+///
+/// References to the mutually non-overlapping suggestions, in input order
+pub fn select_non_overlapping(suggestions: &[Suggestion]) -> Vec<&Suggestion> {
+    let mut accepted: Vec<&Suggestion> = Vec::with_capacity(suggestions.len());
+
+    for suggestion in suggestions {
+        let range = &suggestion.edit.range;
+        if !accepted
+            .iter()
+            .any(|kept| ranges_overlap(&kept.edit.range, range))
+        {
+            accepted.push(suggestion);
+        }
+    }
+
+    accepted
+}
+
+/// Checks whether two edit ranges touch the same bytes.
+///
+/// Adjacent ranges do not overlap, and an insertion (empty range) only
+/// conflicts with a range that strictly contains its position. Two identical
+/// ranges always conflict, so the same rewrite is never applied twice.
+///
+/// # Arguments
+///
+/// * `a` - First byte range
+/// * `b` - Second byte range
+///
+/// # Returns
+///
+/// `true` if applying both edits would be ambiguous
+#[inline]
+fn ranges_overlap(a: &Range<usize>, b: &Range<usize>) -> bool {
+    a == b || (a.start < b.end && b.start < a.end)
 }
 
 /// Applies non-overlapping text edits to the source.
@@ -79,7 +179,7 @@ pub fn apply_suggestions(source: &str, suggestions: &[Suggestion]) -> String {
 /// assert_eq!(apply_edits(src, edits), "let x = read(\"f\");");
 /// ```
 pub fn apply_edits(source: &str, mut edits: Vec<TextEdit>) -> String {
-    edits.sort_by_key(|edit| std::cmp::Reverse(edit.range.start));
+    edits.sort_by_key(|edit| std::cmp::Reverse((edit.range.start, edit.range.end)));
 
     let mut output = source.to_string();
     for edit in edits {
@@ -89,11 +189,187 @@ pub fn apply_edits(source: &str, mut edits: Vec<TextEdit>) -> String {
     output
 }
 
-/// Computes the byte offset at which to insert `use` statements.
+/// Builds the insertions adding `use` statements to a source.
+///
+/// Each import goes into the group of its path (std, external or crate)
+/// among the file's top-level `use` items:
 ///
-/// Skips the leading run of blank lines, non-doc `//` comments, module docs
-/// (`//!`), and inner attributes (`#!`), so inserted imports stay valid Rust
-/// and land above the first item.
+/// - before the first import of its group that sorts after it, or after the
+///   last one;
+/// - as a new group, separated by a blank line, before the first import of a
+///   later group or after the last import of an earlier one;
+/// - at [`import_insertion_offset`] if the file has no imports.
+///
+/// Imports with attributes, e.g. `#[cfg(..)] use`, are not moved around.
+///
+/// # Arguments
+///
+/// * `source` - Original source code
+/// * `imports` - Distinct `use` statements to add
+///
+/// # Returns
+///
+/// One insertion per distinct position, holding the imports placed there
+///
+/// # Examples
+///
+/// ```
+/// use cargo_quality::fixer::{apply_edits, import_edits};
+///
+/// let src = "use std::fs;\n\nuse crate::config::Config;\n\nfn main() {}\n";
+/// let edits = import_edits(src, &["use std::collections::HashMap;".to_string()]);
+///
+/// assert_eq!(
+///     apply_edits(src, edits),
+///     "use std::collections::HashMap;\nuse std::fs;\n\nuse crate::config::Config;\n\nfn \
+///      main() {}\n"
+/// );
+/// ```
+pub fn import_edits(source: &str, imports: &[String]) -> Vec<TextEdit> {
+    let existing: Vec<(Import, Range<usize>)> = syn::parse_file(source)
+        .map(|file| {
+            file.items
+                .iter()
+                .filter_map(|item| match item {
+                    Item::Use(item) if item.attrs.is_empty() => Some((
+                        Import::new(item),
+                        whole_lines(source, item.span().byte_range())
+                    )),
+                    _ => None
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let mut placed: Vec<Placement> = imports
+        .iter()
+        .map(|text| {
+            let Ok(item) = syn::parse_str::<ItemUse>(text) else {
+                return Placement::at_header(source, text, None);
+            };
+            let import = Import::new(&item);
+            let same = || {
+                existing
+                    .iter()
+                    .filter(|(other, _)| other.group == import.group)
+            };
+
+            let (offset, blank_before, blank_after) = if let Some((_, range)) =
+                same().find(|(other, _)| other.sort_cmp(&import) == Ordering::Greater)
+            {
+                (range.start, false, false)
+            } else if let Some((_, range)) = same().next_back() {
+                (range.end, false, false)
+            } else if let Some((_, range)) = existing
+                .iter()
+                .find(|(other, _)| other.group > import.group)
+            {
+                (range.start, false, true)
+            } else if let Some((_, range)) = existing.last() {
+                (range.end, true, false)
+            } else {
+                return Placement::at_header(source, text, Some(import));
+            };
+
+            Placement {
+                offset,
+                text: text.clone(),
+                import: Some(import),
+                blank_before,
+                blank_after
+            }
+        })
+        .collect();
+    placed.sort_by(|a, b| a.offset.cmp(&b.offset).then_with(|| a.order(b)));
+
+    let mut edits = Vec::new();
+    for block in placed.chunk_by(|a, b| a.offset == b.offset) {
+        let offset = block[0].offset;
+        let mut replacement = String::new();
+        if offset == source.len() && !source.is_empty() && !source.ends_with('\n') {
+            replacement.push('\n');
+        }
+        if block[0].blank_before {
+            replacement.push('\n');
+        }
+        for (index, placement) in block.iter().enumerate() {
+            if index > 0 && placement.group() != block[index - 1].group() {
+                replacement.push('\n');
+            }
+            replacement.push_str(&placement.text);
+            replacement.push('\n');
+        }
+        if block[block.len() - 1].blank_after {
+            replacement.push('\n');
+        }
+        edits.push(TextEdit {
+            range: offset..offset,
+            replacement
+        });
+    }
+    edits
+}
+
+/// Position of one inserted import.
+struct Placement {
+    /// Byte offset of the insertion, always at the start of a line
+    offset:       usize,
+    /// The `use` statement
+    text:         String,
+    /// Parsed statement, `None` if it does not parse
+    import:       Option<Import>,
+    /// Starts a new group after the previous imports
+    blank_before: bool,
+    /// Starts a new group before the following imports
+    blank_after:  bool
+}
+
+impl Placement {
+    /// Place an import after the file header, for files without imports.
+    fn at_header(source: &str, text: &str, import: Option<Import>) -> Self {
+        Self {
+            offset: import_insertion_offset(source),
+            text: text.to_string(),
+            import,
+            blank_before: false,
+            blank_after: false
+        }
+    }
+
+    /// Group of the import; unparsable ones count as external.
+    fn group(&self) -> Group {
+        self.import
+            .as_ref()
+            .map_or(Group::External, |import| import.group)
+    }
+
+    /// Order imports inserted at the same offset by group, then path.
+    fn order(&self, other: &Self) -> Ordering {
+        match (&self.import, &other.import) {
+            (Some(a), Some(b)) => a.group.cmp(&b.group).then_with(|| a.sort_cmp(b)),
+            _ => self.group().cmp(&other.group())
+        }
+    }
+}
+
+/// Extend a byte range to the lines it touches, including the final newline.
+fn whole_lines(source: &str, range: Range<usize>) -> Range<usize> {
+    let start = source[..range.start]
+        .rfind('\n')
+        .map_or(0, |index| index + 1);
+    let end = source[range.end..]
+        .find('\n')
+        .map_or(source.len(), |index| range.end + index + 1);
+    start..end
+}
+
+/// Computes the byte offset at which to insert `use` statements into a file
+/// without imports.
+///
+/// Skips the leading run of blank lines, non-doc comments (`//` and
+/// `/* .. */`, e.g. SPDX headers), module docs (`//!`, `/*! .. */`) and inner
+/// attributes (`#![..]`, also when spanning several lines), so inserted
+/// imports stay valid Rust and land above the first item.
 ///
 /// # Arguments
 ///
@@ -104,23 +380,41 @@ pub fn apply_edits(source: &str, mut edits: Vec<TextEdit>) -> String {
 /// Byte offset for import insertion
 pub fn import_insertion_offset(source: &str) -> usize {
     let mut offset = 0;
+    let mut in_comment = false;
+    let mut attr_depth = 0isize;
 
     for line in source.split_inclusive('\n') {
         let trimmed = line.trim_start();
-        if trimmed.is_empty()
+        if in_comment {
+            in_comment = !line.contains("*/");
+        } else if attr_depth > 0 {
+            attr_depth += bracket_balance(line);
+        } else if trimmed.starts_with("#![") {
+            attr_depth = bracket_balance(trimmed);
+        } else if trimmed.starts_with("/*") && !trimmed.starts_with("/**") {
+            in_comment = !trimmed[2..].contains("*/");
+        } else if !(trimmed.is_empty()
             || trimmed.starts_with("//!")
             || trimmed.starts_with("#!")
-            || (trimmed.starts_with("//") && !trimmed.starts_with("///"))
+            || (trimmed.starts_with("//") && !trimmed.starts_with("///")))
         {
-            offset += line.len();
-        } else {
             break;
         }
+        offset += line.len();
     }
 
     offset
 }
 
+/// Count of `[` minus count of `]` in a line.
+fn bracket_balance(line: &str) -> isize {
+    line.chars().fold(0, |depth, c| match c {
+        '[' => depth + 1,
+        ']' => depth - 1,
+        _ => depth
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -167,6 +461,162 @@ mod tests {
         assert_eq!(apply_edits(src, Vec::new()), "unchanged");
     }
 
+    fn suggestion(range: Range<usize>, replacement: &str) -> Suggestion {
+        Suggestion {
+            edit:   TextEdit {
+                range,
+                replacement: replacement.to_string()
+            },
+            import: None
+        }
+    }
+
+    #[test]
+    fn test_apply_suggestions_drops_overlapping() {
+        let suggestions = vec![suggestion(0..4, "A"), suggestion(2..6, "B")];
+        let outcome = apply_suggestions("abcdef", &suggestions);
+
+        assert_eq!(outcome.source, "Aef");
+        assert_eq!(outcome.applied, 1);
+        assert_eq!(outcome.conflicts, 1);
+    }
+
+    #[test]
+    fn test_apply_suggestions_keeps_adjacent() {
+        let suggestions = vec![suggestion(0..2, "A"), suggestion(2..4, "B")];
+        let outcome = apply_suggestions("abcdef", &suggestions);
+
+        assert_eq!(outcome.source, "ABef");
+        assert_eq!(outcome.applied, 2);
+        assert_eq!(outcome.conflicts, 0);
+    }
+
+    #[test]
+    fn test_apply_suggestions_drops_duplicate_edit() {
+        let suggestions = vec![suggestion(1..3, "X"), suggestion(1..3, "X")];
+        let outcome = apply_suggestions("abcd", &suggestions);
+
+        assert_eq!(outcome.source, "aXd");
+        assert_eq!(outcome.conflicts, 1);
+    }
+
+    #[test]
+    fn test_apply_suggestions_counts_distinct_imports() {
+        let mut first = suggestion(12..13, "a");
+        first.import = Some("use x::a;".to_string());
+        let mut second = suggestion(15..16, "a");
+        second.import = Some("use x::a;".to_string());
+        let outcome = apply_suggestions("fn main() { b; b; }\n", &[first, second]);
+
+        assert_eq!(outcome.imports, 1);
+        assert_eq!(outcome.source, "use x::a;\nfn main() { a; a; }\n");
+    }
+
+    #[test]
+    fn test_insertion_inside_range_conflicts() {
+        let suggestions = vec![suggestion(1..5, ""), suggestion(3..3, "!")];
+        let accepted = select_non_overlapping(&suggestions);
+
+        assert_eq!(accepted.len(), 1);
+        assert_eq!(accepted[0].edit.range, 1..5);
+    }
+
+    #[test]
+    fn test_insertion_at_range_start_is_applied() {
+        let edits = vec![
+            TextEdit {
+                range:       0..0,
+                replacement: "use a;\n".to_string()
+            },
+            TextEdit {
+                range:       0..3,
+                replacement: "y".to_string()
+            },
+        ];
+        assert_eq!(apply_edits("abc", edits), "use a;\ny");
+    }
+
+    fn insert(src: &str, imports: &[&str]) -> String {
+        let imports: Vec<String> = imports.iter().map(|i| i.to_string()).collect();
+        apply_edits(src, import_edits(src, &imports))
+    }
+
+    #[test]
+    fn test_import_sorted_into_group() {
+        let src = "//! Docs\n\nuse std::fs;\nuse std::io;\n\nuse serde::Serialize;\n\n\
+                   use crate::config::Config;\n\nfn main() {}\n";
+
+        assert_eq!(
+            insert(
+                src,
+                &["use std::collections::HashMap;", "use std::path::Path;"]
+            ),
+            "//! Docs\n\nuse std::collections::HashMap;\nuse std::fs;\nuse std::io;\n\
+             use std::path::Path;\n\nuse serde::Serialize;\n\nuse crate::config::Config;\n\n\
+             fn main() {}\n"
+        );
+        assert_eq!(
+            insert(src, &["use crate::args::Args;"]),
+            src.replace(
+                "use crate::config",
+                "use crate::args::Args;\nuse crate::config"
+            )
+        );
+    }
+
+    #[test]
+    fn test_import_starts_new_group() {
+        let src = "use serde::Serialize;\n\nfn main() {}\n";
+
+        assert_eq!(
+            insert(src, &["use std::fs;"]),
+            "use std::fs;\n\nuse serde::Serialize;\n\nfn main() {}\n"
+        );
+        assert_eq!(
+            insert(src, &["use crate::config::Config;"]),
+            "use serde::Serialize;\n\nuse crate::config::Config;\n\nfn main() {}\n"
+        );
+
+        let src = "use crate::config::Config;\n\nfn main() {}\n";
+        assert_eq!(
+            insert(src, &["use serde::Serialize;", "use std::fs;"]),
+            "use std::fs;\n\nuse serde::Serialize;\n\nuse crate::config::Config;\n\n\
+             fn main() {}\n"
+        );
+    }
+
+    #[test]
+    fn test_import_after_header_without_imports() {
+        let src = "/* SPDX-License-Identifier: MIT */\n#![allow(\n    dead_code\n)]\n\n\
+                   /// Entry point\nfn main() {}\n";
+
+        assert_eq!(
+            insert(src, &["use std::fs;"]),
+            src.replace("/// Entry", "use std::fs;\n/// Entry")
+        );
+    }
+
+    #[test]
+    fn test_import_skips_attributed_imports() {
+        let src = "#[cfg(unix)]\nuse std::os::unix;\nuse serde::Serialize;\n";
+
+        assert_eq!(
+            insert(src, &["use std::fs;"]),
+            "#[cfg(unix)]\nuse std::os::unix;\nuse std::fs;\n\nuse serde::Serialize;\n"
+        );
+    }
+
+    #[test]
+    fn test_insertion_offset_skips_block_comments_and_attributes() {
+        let src = "/*\n * SPDX-License-Identifier: MIT\n */\n#![cfg_attr(\n    docsrs,\n    \
+                   feature(doc_cfg)\n)]\nfn main() {}\n";
+        let offset = import_insertion_offset(src);
+        assert_eq!(&src[offset..offset + 2], "fn");
+
+        let src = "/** Outer doc */\nfn main() {}\n";
+        assert_eq!(import_insertion_offset(src), 0);
+    }
+
     #[test]
     fn test_insertion_offset_skips_module_docs() {
         let src = "// SPDX header\n//! module doc\n\nuse std::fmt;\nfn main() {}\n";
@@ -43,12 +43,21 @@ pub fn display_help() {
     println!(
         "    {} {}",
         "EXIT:".fg::<Blue>().dimmed(),
-        "1 if any issues are found, 0 otherwise (usable as a CI gate)".fg::<Magenta>()
+        "0 clean, 1 issues, 2 fixable only, 3 tool error, 4 config error".fg::<Magenta>()
     );
     println!(
         "    {} {}",
         "OPTIONS:".fg::<Blue>().dimmed(),
-        "--verbose, -v | --analyzer, -a <NAME> | --color, -c".fg::<Magenta>()
+        "--verbose, -v | --analyzer, -a <NAME> | --color, -c | --format <FORMAT> | --plan"
+            .fg::<Magenta>()
+    );
+    println!(
+        "             {}",
+        "--width <N> | --show-skipped | --snapshot <FILE> | --update-snapshot".fg::<Magenta>()
+    );
+    println!(
+        "             {}",
+        "--collapse <ANALYZER> | --min-confidence <LEVEL> | --deterministic".fg::<Magenta>()
     );
     println!(
         "    {} {}",
@@ -63,6 +72,12 @@ pub fn display_help() {
         "             {}",
         "cargo qual check --color src/".fg::<Cyan>().italic()
     );
+    println!(
+        "             {}",
+        "cargo qual check --snapshot .quality-snapshot"
+            .fg::<Cyan>()
+            .italic()
+    );
 
     println!(
         "\n  {} {}",
@@ -73,7 +88,7 @@ pub fn display_help() {
     println!(
         "    {} {}",
         "OPTIONS:".fg::<Blue>().dimmed(),
-        "--dry-run, -d | --analyzer, -a <NAME>".fg::<Magenta>()
+        "--dry-run, -d | --analyzer, -a <NAME> | --show-skipped".fg::<Magenta>()
     );
     println!(
         "    {} {}",
@@ -85,6 +100,28 @@ pub fn display_help() {
         "cargo qual fix -a path_import".fg::<Cyan>().italic()
     );
 
+    println!(
+        "\n  {} {}",
+        "bot".fg::<Green>().bold(),
+        "[PATH]".fg::<Magenta>()
+    );
+    println!(
+        "    {} Apply every fix on a branch and write a summary for CI",
+        "→".fg::<Blue>()
+    );
+    println!(
+        "    {} {}",
+        "OPTIONS:".fg::<Blue>().dimmed(),
+        "--apply-label <LABEL> | --branch <NAME> | --summary <FILE> | --no-commit".fg::<Magenta>()
+    );
+    println!(
+        "    {} {}",
+        "EXAMPLE:".fg::<Blue>().dimmed(),
+        "cargo qual bot --apply-label quality-autofix"
+            .fg::<Cyan>()
+            .italic()
+    );
+
     println!(
         "\n  {} {}",
         "format".fg::<Green>().bold(),
@@ -135,8 +172,10 @@ pub fn display_help() {
     println!(
         "    {} {}",
         "OPTIONS:".fg::<Blue>().dimmed(),
-        "--summary, -s | --interactive, -i | --analyzer, -a <NAME> | --color, -c".fg::<Magenta>()
+        "--summary, -s | --interactive, -i | --analyzer, -a <NAME> | --color, -c | --width <N>"
+            .fg::<Magenta>()
     );
+    println!("             {}", "--show-skipped".fg::<Magenta>());
     println!(
         "    {} {}",
         "MODES:".fg::<Blue>().dimmed(),
@@ -156,6 +195,28 @@ pub fn display_help() {
         "cargo qual diff --color --summary".fg::<Cyan>().italic()
     );
 
+    println!(
+        "\n  {} {}",
+        "rules".fg::<Green>().bold(),
+        "[DIR]".fg::<Magenta>()
+    );
+    println!(
+        "    {} List rules or export one markdown page per rule",
+        "→".fg::<Blue>()
+    );
+    println!(
+        "    {} {}",
+        "OPTIONS:".fg::<Blue>().dimmed(),
+        "--export markdown | --export messages".fg::<Magenta>()
+    );
+    println!(
+        "    {} {}",
+        "EXAMPLE:".fg::<Blue>().dimmed(),
+        "cargo qual rules --export markdown docs/rules/"
+            .fg::<Cyan>()
+            .italic()
+    );
+
     println!("\n  {}", "help".fg::<Green>().bold());
     println!(
         "    {} Display this beautiful help message",
@@ -167,6 +228,17 @@ pub fn display_help() {
         "cargo qual help".fg::<Cyan>().italic()
     );
 
+    println!("\n  {}", "--explain-exit-codes".fg::<Green>().bold());
+    println!(
+        "    {} Print the stable exit codes and what they mean",
+        "→".fg::<Blue>()
+    );
+    println!(
+        "    {} {}",
+        "EXAMPLE:".fg::<Blue>().dimmed(),
+        "cargo qual --explain-exit-codes".fg::<Cyan>().italic()
+    );
+
     println!("\n  {}", "setup".fg::<Green>().bold());
     println!(
         "    {} Automatically install shell completions",
@@ -175,7 +247,8 @@ pub fn display_help() {
     println!(
         "    {} {}",
         "NOTE:".fg::<Blue>().dimmed(),
-        "Detects your shell and installs to standard location".fg::<Magenta>()
+        "Detects your shell (bash, fish, zsh, PowerShell) and installs to standard location"
+            .fg::<Magenta>()
     );
     println!(
         "    {} {}",
@@ -236,6 +309,10 @@ pub fn display_help() {
         "    {} Suggests using named arguments for clarity",
         "•".fg::<Blue>()
     );
+    println!(
+        "    {} Auto-fix inlines identifiers: (\"{{}}\", name) -> (\"{{name}}\")",
+        "•".fg::<Blue>()
+    );
 
     println!(
         "\n  {} {}",
@@ -265,7 +342,7 @@ pub fn display_help() {
         "•".fg::<Blue>()
     );
     println!(
-        "    {} Suggests moving to doc block # Notes section with code",
+        "    {} Auto-fix moves them to the doc block # Notes section",
         "•".fg::<Blue>()
     );
     println!(
@@ -273,7 +350,1110 @@ pub fn display_help() {
         "•".fg::<Blue>()
     );
     println!(
-        "    {} Use: cargo qual check -a inline_comments",
+        "    {} Use: cargo qual fix -a inline_comments",
+        "•".fg::<Blue>()
+    );
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "Needless Allocation Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Detects String/Vec<T>/PathBuf parameters that are only read",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Suggests &str, &[T] and &Path (also for &String returns)",
+        "•".fg::<Blue>()
+    );
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "Sharing Complexity Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Detects Arc<Mutex<Vec<T>>>-style stacks in fields and signatures",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Detects Rc used in code that spawns threads",
+        "•".fg::<Blue>()
+    );
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "Print Logging Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Active when Cargo.toml depends on tracing or log",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Rewrites println!/eprintln! to info!/error! with the import",
+        "•".fg::<Blue>()
+    );
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "Missing Instrument Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Opt-in: enable in [package.metadata.quality]",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Requires tracing in Cargo.toml dependencies",
+        "•".fg::<Blue>()
+    );
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "Time Source Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Opt-in: enable in [package.metadata.quality]",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Flags SystemTime::now()/Instant::now() in sync logic",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Flags chrono::Local when a server framework is a dependency",
+        "•".fg::<Blue>()
+    );
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "Unwrap Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Detects .unwrap() and .expect() outside test code",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Suggests ? with masterror error propagation",
+        "•".fg::<Blue>()
+    );
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "Unbounded Queue Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Detects unbounded_channel(), unbounded() and mpsc::channel()",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Detects buffers growing in endless or receive loops",
+        "•".fg::<Blue>()
+    );
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "Hardcoded Secrets Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Detects AWS keys, high-entropy tokens and password literals",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Reported with error severity, secrets redacted in output",
+        "•".fg::<Blue>()
+    );
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "Debug Artifacts Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Detects dbg!, println! and eprintln! in library modules",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Removes standalone statements; skips main.rs, bin/, tests/",
+        "•".fg::<Blue>()
+    );
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "Hardcoded Paths Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Detects absolute paths (/home/..., C:\\...) and http:// URLs",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Allows docs, tests, fixtures, constants and localhost",
+        "•".fg::<Blue>()
+    );
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "Function Length Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Detects functions and methods longer than 60 lines",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Counts lines of the body block, test code excluded",
+        "•".fg::<Blue>()
+    );
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "SQL Injection Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Detects format!/+ built SQL passed to query/execute",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Suggests parameterized queries with bind parameters",
+        "•".fg::<Blue>()
+    );
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "Numeric Overflow Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Detects unchecked integer +/* in loops and on parameters",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Opt-in: enable in [package.metadata.quality]",
+        "•".fg::<Blue>()
+    );
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "Nesting Depth Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Detects if/match/loop/closure nesting deeper than 4 levels",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Points at the deepest statement to refactor",
+        "•".fg::<Blue>()
+    );
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "Error Convention Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Detects pub fns returning anyhow::Error or Box<dyn Error>",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Opt-in: enable in [package.metadata.quality]",
+        "•".fg::<Blue>()
+    );
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "Bool Result Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Detects bool functions collapsing calls with .is_ok()/.is_err()",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Suggests returning Result so callers see the cause",
+        "•".fg::<Blue>()
+    );
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "Glob Import Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Detects use path::* outside tests and preludes",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Replaces globs over local enums and modules with used items",
+        "•".fg::<Blue>()
+    );
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "Nested Closures Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Detects closures nested more than 2 levels deep",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Suggests extracting inner closures into named functions",
+        "•".fg::<Blue>()
+    );
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "Import Order Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Checks std, external, crate import groups and sorting",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Works on stable without nightly rustfmt",
+        "•".fg::<Blue>()
+    );
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "Trait Bounds Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Detects more than 5 trait bounds or bounds over 80 chars",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Suggests helper traits with blanket impls or trait aliases",
+        "•".fg::<Blue>()
+    );
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "Redundant Imports Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Detects duplicated, overlapping and unused imports",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Removes duplicated or unused single-item use statements",
+        "•".fg::<Blue>()
+    );
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "Missing Inline Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Detects single-expression pub functions without #[inline]",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Inserts #[inline]; library files only",
+        "•".fg::<Blue>()
+    );
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "Missing Docs Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Detects pub fns, methods, types, traits and modules without ///",
+        "•".fg::<Blue>()
+    );
+    println!("    {} Honors #[doc(hidden)]", "•".fg::<Blue>());
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "Sensitive Debug Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Detects #[derive(Debug)] on types with password/token/secret fields",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Extra names via metadata.quality.sensitive_fields",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Errors instead of warnings with profile = strict",
+        "•".fg::<Blue>()
+    );
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "Missing Errors Doc Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Detects documented pub fns returning Result without # Errors",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Recognizes aliases such as AppResult and io::Result",
+        "•".fg::<Blue>()
+    );
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "Variant Names Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Detects variants repeating the enum name, e.g. ErrorKind::ErrorNotFound",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Detects a prefix shared by all variants of an enum with 3+ variants",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Renames variants of private enums and their uses in the file",
+        "•".fg::<Blue>()
+    );
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "Missing Panics Doc Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Detects documented pub fns that unwrap, expect, index or panic!",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Requires a # Panics section in their docs",
+        "•".fg::<Blue>()
+    );
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "License Header Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Checks the file header has SPDX-FileCopyrightText and SPDX-License-Identifier",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Opt-in unless metadata.quality.license_header is set",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Inserts the configured license_header template",
+        "•".fg::<Blue>()
+    );
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "Mergeable Impls Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Detects repeated impl Foo blocks in one module",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Blocks with docs or different attributes are kept apart",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Moves items of later blocks into the first one",
+        "•".fg::<Blue>()
+    );
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "TODO Comments Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Collects TODO, FIXME, HACK and XXX markers from comments",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Opt-in: lists the markers grouped by file and fails check",
+        "•".fg::<Blue>()
+    );
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "Test Modules Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Detects cfg(test) modules whose imports lack use super::*",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Detects test modules followed by non-test code",
+        "•".fg::<Blue>()
+    );
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "Item Order Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Detects items placed out of the group order",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Default order: const, type, impl, fn, test",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Opt-in unless item_order is set in metadata.quality",
+        "•".fg::<Blue>()
+    );
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "Line Length Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Detects lines wider than 99 columns",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Measures visual width, ANSI and Unicode aware",
+        "•".fg::<Blue>()
+    );
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "Pub Fields Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Detects pub fields on pub structs in library code",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Skips Serialize/Deserialize and clap Parser/Args types",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Allow-list type names with pub_fields_allow patterns",
+        "•".fg::<Blue>()
+    );
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "Whitespace Analyzer".fg::<Cyan>().bold()
+    );
+    println!("    {} Detects trailing spaces and tabs", "•".fg::<Blue>());
+    println!("    {} Detects tab indentation", "•".fg::<Blue>());
+    println!(
+        "    {} Detects a missing newline at the end of the file",
+        "•".fg::<Blue>()
+    );
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "Stringly Typed Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Detects public fns with 3 or more string parameters",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Suggests newtypes for distinct concepts",
+        "•".fg::<Blue>()
+    );
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "File Length Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Detects source files longer than 600 lines",
+        "•".fg::<Blue>()
+    );
+    println!("    {} Suggests extracting submodules", "•".fg::<Blue>());
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "Conversion Impls Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Detects From impls missing a fallible TryFrom back",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Detects to_x/into_x methods that should be From impls",
+        "•".fg::<Blue>()
+    );
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "Type Size Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Detects enums with more than 20 variants",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Detects structs with more than 12 fields",
+        "•".fg::<Blue>()
+    );
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "Shadowing Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Detects names bound by `let` more than once in a function",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Accepts a single `let x = x.trim()` style conversion",
+        "•".fg::<Blue>()
+    );
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "Unsafe Docs Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Detects unsafe blocks and impls without a `// SAFETY:` comment",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Detects `unsafe fn` without a `# Safety` doc section",
+        "•".fg::<Blue>()
+    );
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "Clone In Loop Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Detects `.clone()` and `.to_owned()` in for/while/loop bodies",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Reports the line of the enclosing loop",
+        "•".fg::<Blue>()
+    );
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "Missing Must Use Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Detects public methods returning `Self` without `#[must_use]`",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Detects consuming methods returning a new owned value",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Inserts the `#[must_use]` attribute",
+        "•".fg::<Blue>()
+    );
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "Default Impl Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Detects zero-argument `pub fn new()` without `Default`",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Accepts `#[derive(Default)]` and manual `impl Default`",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Generates `impl Default` delegating to `new()`",
+        "•".fg::<Blue>()
+    );
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "Missing Debug Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Detects public structs and enums without `Debug`",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Accepts derived and manual `Debug` implementations",
+        "•".fg::<Blue>()
+    );
+    println!("    {} Adds `Debug` to the derive list", "•".fg::<Blue>());
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "Numeric Cast Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Detects truncating casts like `u64 as u32`",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Detects sign-changing casts like `i32 as u32`",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Suggests `try_from` for out-of-range handling",
+        "•".fg::<Blue>()
+    );
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "Test Assertions Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Detects `#[test]` functions that never assert",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Counts `unwrap`, `expect`, `?` and `panic!` as assertions",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Follows calls to asserting helpers in the same file",
+        "•".fg::<Blue>()
+    );
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "Assert Message Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Detects `assert!`/`debug_assert!` without a message",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Covers `assert_eq!`, `assert_ne!` and their debug variants",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Ignores tests, `tests/` and `benches/`",
+        "•".fg::<Blue>()
+    );
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "Super Imports Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Detects `use super::super::...` and deeper imports",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Derives the module path from the file location",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Rewrites the `super` chain to a `crate::` path",
+        "•".fg::<Blue>()
+    );
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "Wildcard Re-export Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Detects `pub use path::*` re-exports",
+        "•".fg::<Blue>()
+    );
+    println!("    {} Skips preludes and test modules", "•".fg::<Blue>());
+    println!(
+        "    {} Replaces `*` with the public items of local modules",
+        "•".fg::<Blue>()
+    );
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "Doc Width Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Detects doc comment lines wider than 80 columns",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Skips code blocks, tables and headings",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Rewraps the paragraph like wrap_comments on stable",
+        "•".fg::<Blue>()
+    );
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "Placeholder Docs Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Detects empty and TODO-only doc comments",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Detects docs that only repeat the item name",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Skips #[doc(hidden)] items and test code",
+        "•".fg::<Blue>()
+    );
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "Duplicate Literals Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Detects string literals repeated 3+ times across files",
+        "•".fg::<Blue>()
+    );
+    println!("    {} Lists the locations of every copy", "•".fg::<Blue>());
+    println!(
+        "    {} Skips short literals, constants, attributes and tests",
+        "•".fg::<Blue>()
+    );
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "Derive Order Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Enforces Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Other derives follow in written order, serde traits last",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Auto-fix reorders the derive list in place",
+        "•".fg::<Blue>()
+    );
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "Argument Order Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Groups functions by the first word of their name",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Flags shared parameters ordered unlike the rest of the family",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Prevents swapped arguments at call sites",
+        "•".fg::<Blue>()
+    );
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "Allow Audit Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Reports #[allow(..)] without reason = \"..\"",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} A comment above or after the attribute also counts",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Keeps every lint suppression explained",
+        "•".fg::<Blue>()
+    );
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "Required Debug Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Requires Debug on *Error, *Config and *Options types",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Configure suffixes with debug_suffixes in metadata.quality",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Auto-fix adds Debug to the derives",
+        "•".fg::<Blue>()
+    );
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "Naming Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Detects single-letter let bindings",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Detects placeholders such as tmp, foo and data2",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Skips loop counters, closures and tests",
+        "•".fg::<Blue>()
+    );
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "Predicate Naming Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Detects public bool functions without is_/has_ prefixes",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Accepts contains, starts_with and similar verbs",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Skips private functions, trait impls and tests",
+        "•".fg::<Blue>()
+    );
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "Discarded Result Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Detects let _ = on calls returning Result",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Covers fs functions, write!, flush, send and local fns",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Suggests ?, logging, or .ok() with a comment",
+        "•".fg::<Blue>()
+    );
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "Global State Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Detects static mut items and unsafe impl Send/Sync",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Reported with error severity, never fixed automatically",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Suggests atomics, Mutex, OnceLock or thread_local!",
+        "•".fg::<Blue>()
+    );
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "API Types Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Detects &String, &Vec<T>, &Box<T> and Box<Vec<T>> parameters",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Suggests &str, &[T], &T and Vec<T>",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Auto-fixes free functions and inherent methods",
+        "•".fg::<Blue>()
+    );
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "Error Sources Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Detects map_err(|_| ..) and .ok().ok_or(..) before ?",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Detects From impls that ignore the converted error",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Suggests keeping the original error as the source",
+        "•".fg::<Blue>()
+    );
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "Async Blocking Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Detects thread::sleep, std::fs and blocking recv in async code",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Awaited calls and spawn_blocking closures are not reported",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Suggests tokio::time, tokio::fs or async channels",
+        "•".fg::<Blue>()
+    );
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "Data Integrity Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Detects char as u8, byte as char and from_utf8_unchecked",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Literals, constants and literal arithmetic are not reported",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Suggests u8::try_from, from_utf8 or from_utf8_lossy",
+        "•".fg::<Blue>()
+    );
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "Non-Exhaustive Errors Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Detects public error enums without #[non_exhaustive]",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Error enums: named *Error, deriving Error or implementing it",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Auto-fix inserts #[non_exhaustive] (library files only)",
+        "•".fg::<Blue>()
+    );
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "Unbounded Recursion Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Detects functions calling themselves without a depth limit",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Parameters named depth, level, limit, max, ... count as a bound",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Suggests an explicit stack or a depth guard",
+        "•".fg::<Blue>()
+    );
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "Error Display Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Detects error types without a Display implementation",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Crate-wide: Display impls in any file of the run count",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Derives of Error (thiserror) or Display count as well",
+        "•".fg::<Blue>()
+    );
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "Redundant Syntax Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Detects return expr; ending a function",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Detects ; after if, for, while, loop, match and blocks",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Auto-fix removes the return keyword and the semicolon",
+        "•".fg::<Blue>()
+    );
+
+    println!(
+        "\n  {} {}",
+        "✓".fg::<Green>(),
+        "Impl Location Analyzer".fg::<Cyan>().bold()
+    );
+    println!(
+        "    {} Detects impl Type blocks in another file than Type",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Trait and extension trait impls are allowed",
+        "•".fg::<Blue>()
+    );
+    println!(
+        "    {} Types defined in several files are skipped",
         "•".fg::<Blue>()
     );
 
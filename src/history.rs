@@ -0,0 +1,321 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Change counts of previous `diff --summary` runs.
+//!
+//! Every summary run of the whole project appends the number of changes
+//! each analyzer proposed to `target/quality-history`, one run per line, and
+//! compares the current counts with the line before. The summary then shows
+//! per-analyzer deltas such as `path_import: 12 (+3)`, answering whether a
+//! branch makes things worse without a second checkout.
+//!
+//! The file lives in the Cargo target directory, so it is local to the
+//! checkout, ignored by git and removed by `cargo clean`. Only the last
+//! [`MAX_ENTRIES`] runs are kept.
+
+use std::{cmp::Ordering, collections::BTreeMap, fs, io::ErrorKind, path::PathBuf};
+
+use masterror::AppResult;
+
+use crate::{error::IoError, project::ProjectContext};
+
+/// File name of the history inside the target directory.
+pub const HISTORY_FILE: &str = "quality-history";
+
+/// Number of runs kept in the history.
+pub const MAX_ENTRIES: usize = 100;
+
+/// Change counts of one run.
+///
+/// Stored as one line of `analyzer=count` pairs separated by spaces; a run
+/// without changes is an empty line.
+///
+/// # Examples
+///
+/// ```
+/// use cargo_quality::history::HistoryEntry;
+///
+/// let entry = HistoryEntry::parse("format_args=2 path_import=9");
+/// assert_eq!(entry.count("path_import"), 9);
+/// assert_eq!(entry.delta("path_import", 12), "(+3)");
+/// assert_eq!(entry.to_line(), "format_args=2 path_import=9");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HistoryEntry {
+    /// Number of changes per analyzer name
+    pub counts: BTreeMap<String, usize>
+}
+
+impl HistoryEntry {
+    /// Creates an entry from change counts.
+    ///
+    /// # Arguments
+    ///
+    /// * `counts` - Number of changes per analyzer name
+    ///
+    /// # Returns
+    ///
+    /// Entry without analyzers that proposed no change
+    pub fn new(counts: BTreeMap<String, usize>) -> Self {
+        Self {
+            counts: counts.into_iter().filter(|(_, count)| *count > 0).collect()
+        }
+    }
+
+    /// Parses a line of the history file.
+    ///
+    /// Pairs that are not `analyzer=count` are ignored, so a damaged line
+    /// loses only its unreadable part.
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - Line without the line break
+    ///
+    /// # Returns
+    ///
+    /// Entry with the counts of the line
+    pub fn parse(line: &str) -> Self {
+        let counts = line
+            .split_whitespace()
+            .filter_map(|pair| {
+                let (name, count) = pair.split_once('=')?;
+                Some((name.to_string(), count.parse().ok()?))
+            })
+            .collect();
+        Self::new(counts)
+    }
+
+    /// Renders the entry as a line of the history file.
+    ///
+    /// # Returns
+    ///
+    /// `analyzer=count` pairs in name order, without a line break
+    pub fn to_line(&self) -> String {
+        let pairs: Vec<String> = self
+            .counts
+            .iter()
+            .map(|(name, count)| format!("{name}={count}"))
+            .collect();
+        pairs.join(" ")
+    }
+
+    /// Number of changes an analyzer proposed in this run.
+    ///
+    /// # Arguments
+    ///
+    /// * `analyzer` - Analyzer name
+    ///
+    /// # Returns
+    ///
+    /// The count, `0` if the analyzer proposed nothing
+    pub fn count(&self, analyzer: &str) -> usize {
+        self.counts.get(analyzer).copied().unwrap_or(0)
+    }
+
+    /// Describes how a count changed since this run.
+    ///
+    /// # Arguments
+    ///
+    /// * `analyzer` - Analyzer name
+    /// * `current` - Count of the current run
+    ///
+    /// # Returns
+    ///
+    /// `(+n)`, `(-n)` or `(±0)`
+    pub fn delta(&self, analyzer: &str, current: usize) -> String {
+        let previous = self.count(analyzer);
+        match current.cmp(&previous) {
+            Ordering::Greater => format!("(+{})", current - previous),
+            Ordering::Less => format!("(-{})", previous - current),
+            Ordering::Equal => "(±0)".to_string()
+        }
+    }
+}
+
+/// History file of a project.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::BTreeMap;
+///
+/// use cargo_quality::history::{History, HistoryEntry};
+///
+/// let dir = tempfile::tempdir().unwrap();
+/// let history = History::new(dir.path().join("quality-history"));
+/// assert_eq!(history.last().unwrap(), None);
+///
+/// let entry = HistoryEntry::new(BTreeMap::from([("path_import".to_string(), 3)]));
+/// history.record(&entry).unwrap();
+/// assert_eq!(history.last().unwrap(), Some(entry));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct History {
+    path: PathBuf
+}
+
+impl History {
+    /// Creates a history stored in a file.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path of the history file
+    ///
+    /// # Returns
+    ///
+    /// History reading and writing `path`
+    #[inline]
+    pub fn new(path: PathBuf) -> Self {
+        Self {
+            path
+        }
+    }
+
+    /// History of a project, kept in its target directory.
+    ///
+    /// # Arguments
+    ///
+    /// * `project` - Project context
+    ///
+    /// # Returns
+    ///
+    /// History at `target/quality-history` next to `Cargo.toml`, or `None`
+    /// without a manifest
+    pub fn for_project(project: &ProjectContext) -> Option<Self> {
+        project
+            .root()
+            .map(|root| Self::new(root.join("target").join(HISTORY_FILE)))
+    }
+
+    /// Reads the most recent run.
+    ///
+    /// # Returns
+    ///
+    /// `AppResult<Option<HistoryEntry>>` - Last recorded run, `None` if
+    /// nothing was recorded yet, or an IO error if the file cannot be read
+    pub fn last(&self) -> AppResult<Option<HistoryEntry>> {
+        Ok(self.lines()?.last().map(|line| HistoryEntry::parse(line)))
+    }
+
+    /// Appends a run, dropping the oldest beyond [`MAX_ENTRIES`].
+    ///
+    /// # Arguments
+    ///
+    /// * `entry` - Counts of the run
+    ///
+    /// # Returns
+    ///
+    /// `AppResult<()>` - IO error if the file cannot be written
+    pub fn record(&self, entry: &HistoryEntry) -> AppResult<()> {
+        let mut lines = self.lines()?;
+        lines.push(entry.to_line());
+        let start = lines.len().saturating_sub(MAX_ENTRIES);
+
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir).map_err(IoError::from)?;
+        }
+        let content: String = lines[start..]
+            .iter()
+            .map(|line| format!("{line}\n"))
+            .collect();
+        fs::write(&self.path, content).map_err(IoError::from)?;
+        Ok(())
+    }
+
+    /// Reads the lines of the history file, none if it does not exist.
+    fn lines(&self) -> AppResult<Vec<String>> {
+        match fs::read_to_string(&self.path) {
+            Ok(content) => Ok(content.lines().map(str::to_string).collect()),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Vec::new()),
+            Err(e) => Err(IoError::from(e).into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+
+    fn entry(pairs: &[(&str, usize)]) -> HistoryEntry {
+        HistoryEntry::new(
+            pairs
+                .iter()
+                .map(|(name, count)| (name.to_string(), *count))
+                .collect()
+        )
+    }
+
+    #[test]
+    fn test_entry_line_round_trip() {
+        let entry = entry(&[("path_import", 12), ("format_args", 1), ("unwrap", 0)]);
+
+        assert_eq!(entry.to_line(), "format_args=1 path_import=12");
+        assert_eq!(HistoryEntry::parse(&entry.to_line()), entry);
+        assert_eq!(HistoryEntry::parse(""), HistoryEntry::default());
+    }
+
+    #[test]
+    fn test_parse_skips_damaged_pairs() {
+        let entry = HistoryEntry::parse("path_import=3 broken format_args=x unwrap=1");
+        assert_eq!(entry.to_line(), "path_import=3 unwrap=1");
+    }
+
+    #[test]
+    fn test_delta() {
+        let entry = entry(&[("path_import", 9), ("format_args", 2)]);
+
+        assert_eq!(entry.delta("path_import", 12), "(+3)");
+        assert_eq!(entry.delta("format_args", 0), "(-2)");
+        assert_eq!(entry.delta("format_args", 2), "(±0)");
+        assert_eq!(entry.delta("unwrap", 4), "(+4)");
+    }
+
+    #[test]
+    fn test_record_and_last() {
+        let temp_dir = TempDir::new().unwrap();
+        let history = History::new(temp_dir.path().join("target").join(HISTORY_FILE));
+        assert_eq!(history.last().unwrap(), None);
+
+        history.record(&entry(&[("path_import", 3)])).unwrap();
+        history.record(&HistoryEntry::default()).unwrap();
+        assert_eq!(history.last().unwrap(), Some(HistoryEntry::default()));
+
+        history.record(&entry(&[("unwrap", 1)])).unwrap();
+        assert_eq!(history.last().unwrap(), Some(entry(&[("unwrap", 1)])));
+        assert_eq!(
+            fs::read_to_string(&history.path).unwrap(),
+            "path_import=3\n\nunwrap=1\n"
+        );
+    }
+
+    #[test]
+    fn test_record_keeps_recent_entries() {
+        let temp_dir = TempDir::new().unwrap();
+        let history = History::new(temp_dir.path().join(HISTORY_FILE));
+
+        for count in 1..=MAX_ENTRIES + 5 {
+            history.record(&entry(&[("unwrap", count)])).unwrap();
+        }
+
+        let content = fs::read_to_string(&history.path).unwrap();
+        assert_eq!(content.lines().count(), MAX_ENTRIES);
+        assert_eq!(content.lines().next(), Some("unwrap=6"));
+    }
+
+    #[test]
+    fn test_for_project() {
+        assert_eq!(History::for_project(&ProjectContext::default()), None);
+
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join("Cargo.toml"),
+            "[package]\nname = \"a\"\n"
+        )
+        .unwrap();
+        let project = ProjectContext::discover(temp_dir.path()).unwrap();
+        let history = History::for_project(&project).unwrap();
+        assert!(history.path.ends_with("target/quality-history"));
+    }
+}
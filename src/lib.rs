@@ -13,10 +13,26 @@
 //!
 //! - **[`analyzer`]** - Core trait and types for building analyzers
 //! - **[`analyzers`]** - Built-in analyzers for common code quality issues
+//! - **[`file_analyzer`]** - File-level analyzers of file locations, e.g.
+//!   `mod.rs`
 //! - **[`formatter`]** - Code formatting with hardcoded standards
 //! - **[`differ`]** - Diff generation and visualization
 //! - **[`report`]** - Analysis report generation
+//! - **[`project`]** - Project context discovered from `Cargo.toml`
+//! - **[`plan`]** - Execution plan of a `check` run
+//! - **[`messages`]** - Message catalog for localized issue reports
 //! - **[`error`]** - Error types for quality operations
+//! - **[`exit_code`]** - Stable process exit codes for scripting
+//! - **[`suppression`]** - `#[quality::allow(rule)]` item suppressions
+//! - **[`rules`]** - Markdown rule documentation built from analyzer metadata
+//! - **[`snapshot`]** - Golden-file snapshots of `check` reports
+//! - **[`bot`]** - Batch fixes on a branch with a summary for scheduled CI
+//! - **[`history`]** - Change counts of previous `diff --summary` runs
+//! - **[`skipped`]** - Files left out of a run and why
+//! - **[`source_link`]** - Links from issues to their line on the source host
+//! - **[`terminal`]** - Terminal width detection for the text layouts
+//! - **[`api`]** - Semver-stable re-exports of the data model for plugins and
+//!   CI integrations
 //!
 //! # Quick Start
 //!
@@ -46,11 +62,151 @@
 //! | [`FormatArgsAnalyzer`] | Finds `println!("{}", x)` that should use `{x}` |
 //! | [`EmptyLinesAnalyzer`] | Finds empty lines in function bodies |
 //! | [`InlineCommentsAnalyzer`] | Finds `//` comments that should be `///` |
+//! | [`NeedlessAllocationAnalyzer`] | Finds owned `String`/`Vec<T>`/`PathBuf` params that could be borrowed |
+//! | [`SharingComplexityAnalyzer`] | Finds deep `Arc`/`Rc` wrapper stacks and `Rc` in threaded code |
+//! | [`PrintLoggingAnalyzer`] | Finds `println!` logging in projects using `tracing` or `log` |
+//! | [`MissingInstrumentAnalyzer`] | Finds public async fns invisible to `tracing` (opt-in) |
+//! | [`TimeSourceAnalyzer`] | Finds hidden clock reads and local timezones in servers (opt-in) |
+//! | [`UnwrapAnalyzer`] | Finds `.unwrap()` and `.expect()` in non-test code |
+//! | [`UnboundedQueueAnalyzer`] | Finds unbounded channels and never-drained buffers in loops |
+//! | [`HardcodedSecretsAnalyzer`] | Finds credentials hardcoded in string literals |
+//! | [`DebugArtifactsAnalyzer`] | Finds leftover debug output in library modules |
+//! | [`HardcodedPathsAnalyzer`] | Finds machine-specific paths and plain HTTP endpoints |
+//! | [`FunctionLengthAnalyzer`] | Finds functions and methods with oversized bodies |
+//! | [`SqlInjectionAnalyzer`] | Finds SQL strings built from runtime values |
+//! | [`NumericOverflowAnalyzer`] | Finds integer arithmetic that may overflow |
+//! | [`NestingDepthAnalyzer`] | Finds deeply nested control flow |
+//! | [`ErrorConventionAnalyzer`] | Finds public APIs using ad-hoc error types |
+//! | [`BoolResultAnalyzer`] | Finds functions returning bool that discard errors |
+//! | [`GlobImportAnalyzer`] | Finds glob imports and lists the used items |
+//! | [`NestedClosuresAnalyzer`] | Finds callback pyramids of nested closures |
+//! | [`ImportOrderAnalyzer`] | Checks StdExternalCrate import grouping on stable |
+//! | [`TraitBoundsAnalyzer`] | Finds overly long where-clauses and bound lists |
+//! | [`RedundantImportsAnalyzer`] | Finds duplicate, overlapping and unused imports |
+//! | [`MissingInlineAnalyzer`] | Finds trivial public functions missing #[inline] |
+//! | [`MissingDocsAnalyzer`] | Finds public items without doc comments |
+//! | [`SensitiveDebugAnalyzer`] | Finds derived Debug that would print secrets |
+//! | [`MissingErrorsDocAnalyzer`] | Finds Result-returning docs without an Errors section |
+//! | [`VariantNamesAnalyzer`] | Finds redundant prefixes in enum variant names |
+//! | [`MissingPanicsDocAnalyzer`] | Finds panicking functions without a Panics section |
+//! | [`LicenseHeaderAnalyzer`] | Checks files start with SPDX license headers |
+//! | [`MergeableImplsAnalyzer`] | Finds inherent impl blocks that could be merged |
+//! | [`TodoCommentsAnalyzer`] | Collects TODO/FIXME/HACK/XXX comment markers |
+//! | [`TestModulesAnalyzer`] | Checks the layout of cfg(test) modules |
+//! | [`ItemOrderAnalyzer`] | Checks the order of items within modules |
+//! | [`LineLengthAnalyzer`] | Checks lines against max_width |
+//! | [`PubFieldsAnalyzer`] | Checks exported structs for public fields |
+//! | [`WhitespaceAnalyzer`] | Checks trailing whitespace, tabs and final newline |
+//! | [`StringlyTypedAnalyzer`] | Checks for stringly-typed public APIs |
+//! | [`FileLengthAnalyzer`] | Checks source file length |
+//! | [`ConversionImplsAnalyzer`] | Checks conversions use From/TryFrom |
+//! | [`TypeSizeAnalyzer`] | Checks variant and field counts |
+//! | [`ShadowingAnalyzer`] | Tracks `let` rebindings per function |
+//! | [`UnsafeDocsAnalyzer`] | Checks unsafe code for a safety justification |
+//! | [`CloneInLoopAnalyzer`] | Finds per-iteration copies in loops |
+//! | [`MissingMustUseAnalyzer`] | Inserts `#[must_use]` on builder-like methods |
+//! | [`DefaultImplAnalyzer`] | Generates `impl Default` delegating to `new()` |
+//! | [`MissingDebugAnalyzer`] | Adds `Debug` to the derives of public types |
+//! | [`NumericCastAnalyzer`] | Lossy integer `as` casts that should use `try_from` |
+//! | [`TestAssertionsAnalyzer`] | Test functions that never assert, unwrap or panic |
+//! | [`AssertMessageAnalyzer`] | `assert!` and `debug_assert!` without a message outside tests |
+//! | [`SuperImportsAnalyzer`] | Imports climbing two or more modules with `super` |
+//! | [`WildcardReexportAnalyzer`] | Wildcard re-exports making the public API implicit |
+//! | [`DocWidthAnalyzer`] | Doc comments wider than 80 columns, rewrapped on stable |
+//! | [`PlaceholderDocsAnalyzer`] | Doc comments that are empty, only TODO or only the item name |
+//! | [`DuplicateLiteralsAnalyzer`] | String literals repeated 3+ times across the crate |
+//! | [`DeriveOrderAnalyzer`] | Enforces a canonical `#[derive(..)]` order (opt-in) |
+//! | [`ArgumentOrderAnalyzer`] | Inconsistent parameter order across `prefix_*` functions |
+//! | [`AllowAuditAnalyzer`] | Lint suppressions without a justification |
+//! | [`RequiredDebugAnalyzer`] | Requires `Debug` on `*Error`, `*Config` and `*Options` types |
+//! | [`NamingAnalyzer`] | Non-descriptive variable names (`x`, `tmp`, `data2`) |
+//! | [`PredicateNamingAnalyzer`] | Public `bool` functions without `is_`/`has_` style names |
+//! | [`DiscardedResultAnalyzer`] | `let _ =` bindings that silently drop a `Result` |
+//! | [`GlobalStateAnalyzer`] | `static mut` items and unsafe `Send`/`Sync` impls |
+//! | [`ApiTypesAnalyzer`] | Parameters typed `&String`, `&Vec<T>`, `&Box<T>` or `Box<Vec<T>>` |
+//! | [`ErrorSourcesAnalyzer`] | Error conversions before `?` and `From` impls that discard the source error |
+//! | [`AsyncBlockingAnalyzer`] | `thread::sleep`, `std::fs` and blocking channel receives in async code |
+//! | [`DataIntegrityAnalyzer`] | Byte and character conversions that corrupt non-ASCII text |
+//! | [`NonExhaustiveErrorsAnalyzer`] | Public error enums that break downstream matches when a variant is added |
+//! | [`UnboundedRecursionAnalyzer`] | Functions calling themselves with no depth or limit parameter |
+//! | [`ErrorDisplayAnalyzer`] | Error types named `*Error` or implementing `Error` with no `Display` |
+//! | [`RedundantSyntaxAnalyzer`] | `return expr;` ending a function and `;` after `if`, `for`, `match` and blocks |
+//! | [`ImplLocationAnalyzer`] | `impl Type` blocks living in another file than the definition of `Type` |
 //!
 //! [`PathImportAnalyzer`]: analyzers::PathImportAnalyzer
 //! [`FormatArgsAnalyzer`]: analyzers::FormatArgsAnalyzer
 //! [`EmptyLinesAnalyzer`]: analyzers::EmptyLinesAnalyzer
 //! [`InlineCommentsAnalyzer`]: analyzers::InlineCommentsAnalyzer
+//! [`NeedlessAllocationAnalyzer`]: analyzers::NeedlessAllocationAnalyzer
+//! [`SharingComplexityAnalyzer`]: analyzers::SharingComplexityAnalyzer
+//! [`PrintLoggingAnalyzer`]: analyzers::PrintLoggingAnalyzer
+//! [`MissingInstrumentAnalyzer`]: analyzers::MissingInstrumentAnalyzer
+//! [`TimeSourceAnalyzer`]: analyzers::TimeSourceAnalyzer
+//! [`UnwrapAnalyzer`]: analyzers::UnwrapAnalyzer
+//! [`UnboundedQueueAnalyzer`]: analyzers::UnboundedQueueAnalyzer
+//! [`HardcodedSecretsAnalyzer`]: analyzers::HardcodedSecretsAnalyzer
+//! [`DebugArtifactsAnalyzer`]: analyzers::DebugArtifactsAnalyzer
+//! [`HardcodedPathsAnalyzer`]: analyzers::HardcodedPathsAnalyzer
+//! [`FunctionLengthAnalyzer`]: analyzers::FunctionLengthAnalyzer
+//! [`SqlInjectionAnalyzer`]: analyzers::SqlInjectionAnalyzer
+//! [`NumericOverflowAnalyzer`]: analyzers::NumericOverflowAnalyzer
+//! [`NestingDepthAnalyzer`]: analyzers::NestingDepthAnalyzer
+//! [`ErrorConventionAnalyzer`]: analyzers::ErrorConventionAnalyzer
+//! [`BoolResultAnalyzer`]: analyzers::BoolResultAnalyzer
+//! [`GlobImportAnalyzer`]: analyzers::GlobImportAnalyzer
+//! [`NestedClosuresAnalyzer`]: analyzers::NestedClosuresAnalyzer
+//! [`ImportOrderAnalyzer`]: analyzers::ImportOrderAnalyzer
+//! [`TraitBoundsAnalyzer`]: analyzers::TraitBoundsAnalyzer
+//! [`RedundantImportsAnalyzer`]: analyzers::RedundantImportsAnalyzer
+//! [`MissingInlineAnalyzer`]: analyzers::MissingInlineAnalyzer
+//! [`MissingDocsAnalyzer`]: analyzers::MissingDocsAnalyzer
+//! [`SensitiveDebugAnalyzer`]: analyzers::SensitiveDebugAnalyzer
+//! [`MissingErrorsDocAnalyzer`]: analyzers::MissingErrorsDocAnalyzer
+//! [`VariantNamesAnalyzer`]: analyzers::VariantNamesAnalyzer
+//! [`MissingPanicsDocAnalyzer`]: analyzers::MissingPanicsDocAnalyzer
+//! [`LicenseHeaderAnalyzer`]: analyzers::LicenseHeaderAnalyzer
+//! [`MergeableImplsAnalyzer`]: analyzers::MergeableImplsAnalyzer
+//! [`TodoCommentsAnalyzer`]: analyzers::TodoCommentsAnalyzer
+//! [`TestModulesAnalyzer`]: analyzers::TestModulesAnalyzer
+//! [`ItemOrderAnalyzer`]: analyzers::ItemOrderAnalyzer
+//! [`LineLengthAnalyzer`]: analyzers::LineLengthAnalyzer
+//! [`PubFieldsAnalyzer`]: analyzers::PubFieldsAnalyzer
+//! [`WhitespaceAnalyzer`]: analyzers::WhitespaceAnalyzer
+//! [`StringlyTypedAnalyzer`]: analyzers::StringlyTypedAnalyzer
+//! [`FileLengthAnalyzer`]: analyzers::FileLengthAnalyzer
+//! [`ConversionImplsAnalyzer`]: analyzers::ConversionImplsAnalyzer
+//! [`TypeSizeAnalyzer`]: analyzers::TypeSizeAnalyzer
+//! [`ShadowingAnalyzer`]: analyzers::ShadowingAnalyzer
+//! [`UnsafeDocsAnalyzer`]: analyzers::UnsafeDocsAnalyzer
+//! [`CloneInLoopAnalyzer`]: analyzers::CloneInLoopAnalyzer
+//! [`MissingMustUseAnalyzer`]: analyzers::MissingMustUseAnalyzer
+//! [`DefaultImplAnalyzer`]: analyzers::DefaultImplAnalyzer
+//! [`MissingDebugAnalyzer`]: analyzers::MissingDebugAnalyzer
+//! [`NumericCastAnalyzer`]: analyzers::NumericCastAnalyzer
+//! [`TestAssertionsAnalyzer`]: analyzers::TestAssertionsAnalyzer
+//! [`AssertMessageAnalyzer`]: analyzers::AssertMessageAnalyzer
+//! [`SuperImportsAnalyzer`]: analyzers::SuperImportsAnalyzer
+//! [`WildcardReexportAnalyzer`]: analyzers::WildcardReexportAnalyzer
+//! [`DocWidthAnalyzer`]: analyzers::DocWidthAnalyzer
+//! [`PlaceholderDocsAnalyzer`]: analyzers::PlaceholderDocsAnalyzer
+//! [`DuplicateLiteralsAnalyzer`]: analyzers::DuplicateLiteralsAnalyzer
+//! [`DeriveOrderAnalyzer`]: analyzers::DeriveOrderAnalyzer
+//! [`ArgumentOrderAnalyzer`]: analyzers::ArgumentOrderAnalyzer
+//! [`AllowAuditAnalyzer`]: analyzers::AllowAuditAnalyzer
+//! [`RequiredDebugAnalyzer`]: analyzers::RequiredDebugAnalyzer
+//! [`NamingAnalyzer`]: analyzers::NamingAnalyzer
+//! [`PredicateNamingAnalyzer`]: analyzers::PredicateNamingAnalyzer
+//! [`DiscardedResultAnalyzer`]: analyzers::DiscardedResultAnalyzer
+//! [`GlobalStateAnalyzer`]: analyzers::GlobalStateAnalyzer
+//! [`ApiTypesAnalyzer`]: analyzers::ApiTypesAnalyzer
+//! [`ErrorSourcesAnalyzer`]: analyzers::ErrorSourcesAnalyzer
+//! [`AsyncBlockingAnalyzer`]: analyzers::AsyncBlockingAnalyzer
+//! [`DataIntegrityAnalyzer`]: analyzers::DataIntegrityAnalyzer
+//! [`NonExhaustiveErrorsAnalyzer`]: analyzers::NonExhaustiveErrorsAnalyzer
+//! [`UnboundedRecursionAnalyzer`]: analyzers::UnboundedRecursionAnalyzer
+//! [`ErrorDisplayAnalyzer`]: analyzers::ErrorDisplayAnalyzer
+//! [`RedundantSyntaxAnalyzer`]: analyzers::RedundantSyntaxAnalyzer
+//! [`ImplLocationAnalyzer`]: analyzers::ImplLocationAnalyzer
 //!
 //! # Running All Analyzers
 //!
@@ -96,12 +252,15 @@
 //!
 //! # Feature Flags
 //!
-//! This crate has no optional features. All functionality is enabled by
-//! default.
+//! - **`serde`** - Derives `Serialize` and `Deserialize` for
+//!   [`analyzer::Issue`], [`analyzer::AnalysisResult`], [`report::Report`],
+//!   [`report::GlobalReport`] and the [`differ`] result types, so every output
+//!   format shares one serialization of the data model.
 //!
 //! # Standards
 //!
-//! This tool enforces standards from [RustManifest](https://github.com/RAprogramm/RustManifest):
+//! This tool enforces standards from
+//! [RustManifest](https://github.com/RAprogramm/RustManifest):
 //!
 //! - No inline `::` paths in code (use `use` statements)
 //! - Named format arguments for readability
@@ -110,10 +269,24 @@
 
 pub mod analyzer;
 pub mod analyzers;
+pub mod api;
+pub mod bot;
 pub mod differ;
 pub mod error;
+pub mod exit_code;
+pub mod file_analyzer;
 pub mod file_utils;
 pub mod fixer;
 pub mod formatter;
+pub mod history;
+pub mod messages;
 pub mod mod_rs;
+pub mod plan;
+pub mod project;
 pub mod report;
+pub mod rules;
+pub mod skipped;
+pub mod snapshot;
+pub mod source_link;
+pub mod suppression;
+pub mod terminal;
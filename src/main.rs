@@ -21,51 +21,173 @@
 //! cargo qual format .
 //! ```
 
-use std::{fs, path::PathBuf};
+use std::{
+    collections::{BTreeSet, HashMap},
+    fs,
+    io::Write,
+    path::{Path, PathBuf},
+    process
+};
 
 use masterror::AppResult;
 
 use crate::{
-    analyzer::{AnalysisResult, Fix, Issue},
-    analyzers::get_analyzers,
-    cli::{Command, QualityArgs, Shell},
-    differ::{DiffResult, apply_diff, generate_diff, show_full, show_interactive, show_summary},
-    error::{IoError, ParseError},
-    file_utils::collect_rust_files,
-    mod_rs::{ModRsResult, find_mod_rs_issues, fix_all_mod_rs},
-    report::{GlobalReport, Report}
+    analyzer::{Analyzer, Confidence, SourceFile},
+    analyzers::{get_analyzers, get_analyzers_for},
+    bot::{BotSummary, FixedFile},
+    cli::{Command, OutputFormat, QualityArgs, RulesFormat, Shell},
+    differ::{
+        apply_diff, generate_diffs, show_apply_summary, show_full, show_interactive, show_summary
+    },
+    error::{InvalidConfigError, IoError},
+    exit_code::ExitCode,
+    file_analyzer::{FileAnalyzer, get_file_analyzers, rename_file},
+    file_utils::{collect_project_files, read_file_list},
+    history::{History, HistoryEntry},
+    messages::MessageCatalog,
+    mod_rs::{find_mod_rs_issues, fix_all_mod_rs},
+    plan::Plan,
+    project::ProjectContext,
+    report::{GlobalReport, Report},
+    skipped::{SkipCause, SkippedFiles, Source, load_source},
+    snapshot::{Snapshot, SnapshotOutcome, normalize_path, render as render_snapshot},
+    source_link::SourceLinks,
+    suppression::Suppressions,
+    terminal::{DETERMINISTIC_WIDTH, enable_ansi_colors}
 };
 
 mod analyzer;
 mod analyzers;
+mod bot;
 mod cli;
 mod differ;
 mod error;
+mod exit_code;
+mod file_analyzer;
 mod file_utils;
 mod fixer;
 mod formatter;
 mod help;
+mod history;
+mod messages;
 mod mod_rs;
+mod plan;
+mod project;
 mod report;
-
-fn main() -> AppResult<()> {
+mod rules;
+mod skipped;
+mod snapshot;
+mod source_link;
+mod suppression;
+mod terminal;
+
+fn main() -> process::ExitCode {
     let args = QualityArgs::parse_args();
 
-    match args.command {
+    let code = run(args).unwrap_or_else(|err| {
+        eprintln!("{err}");
+        ExitCode::from_error(&err)
+    });
+    process::ExitCode::from(code.code())
+}
+
+/// Runs the requested command.
+///
+/// # Arguments
+///
+/// * `args` - Parsed command line arguments
+///
+/// # Returns
+///
+/// `AppResult<ExitCode>` - Outcome of the command, or the error that aborted
+/// it; `main` maps both to the process exit code
+fn run(args: QualityArgs) -> AppResult<ExitCode> {
+    let ansi = enable_ansi_colors();
+
+    if args.explain_exit_codes {
+        print!("{}", exit_code::table());
+        return Ok(ExitCode::Clean);
+    }
+
+    let Some(command) = args.command else {
+        help::display_help();
+        return Ok(ExitCode::Clean);
+    };
+
+    match command {
+        Command::Check {
+            path,
+            analyzer,
+            stdin,
+            min_confidence,
+            plan: true,
+            ..
+        } => print_plan(
+            &path,
+            stdin_files(stdin)?,
+            analyzer.as_deref(),
+            min_confidence
+        )?,
         Command::Check {
             path,
             verbose,
             analyzer,
-            color
-        } => std::process::exit(check_command(&path, verbose, analyzer.as_deref(), color)?),
+            color,
+            width,
+            stdin,
+            show_skipped,
+            collapse,
+            min_confidence,
+            deterministic,
+            format,
+            plan: false,
+            snapshot,
+            update_snapshot
+        } => {
+            let snapshot = snapshot.map(|file| Snapshot::new(file, update_snapshot));
+            let text = TextOutput {
+                verbose,
+                color: color && ansi,
+                width: width.map(usize::from),
+                show_skipped,
+                collapse: collapse.into_iter().collect(),
+                deterministic
+            };
+            return check_quality(
+                &path,
+                stdin_files(stdin)?,
+                text,
+                analyzer.as_deref(),
+                min_confidence,
+                format,
+                snapshot.as_ref()
+            );
+        }
         Command::Fix {
             path,
             dry_run,
-            analyzer
-        } => fix_quality(&path, dry_run, analyzer.as_deref())?,
+            analyzer,
+            stdin,
+            show_skipped
+        } => {
+            return fix_quality(
+                &path,
+                stdin_files(stdin)?,
+                dry_run,
+                analyzer.as_deref(),
+                show_skipped
+            );
+        }
         Command::Format {
             path
-        } => format_quality(&path)?,
+        } => return format_quality(&path),
+        Command::Bot {
+            path,
+            apply_label,
+            branch,
+            summary,
+            no_commit
+        } => return run_bot(&path, &apply_label, &branch, &summary, !no_commit),
         Command::Fmt {
             path: _
         } => formatter::format_code()?,
@@ -74,29 +196,36 @@ fn main() -> AppResult<()> {
             summary,
             interactive,
             analyzer,
-            color
-        } => run_diff(&path, summary, interactive, analyzer.as_deref(), color)?,
-        Command::Help => {
-            help::display_help();
-            return Ok(());
+            color,
+            width,
+            show_skipped
+        } => {
+            return run_diff(
+                &path,
+                summary,
+                interactive,
+                analyzer.as_deref(),
+                color && ansi,
+                width.map(usize::from),
+                show_skipped
+            );
         }
+        Command::Help => help::display_help(),
         Command::Completions {
             shell
-        } => {
-            generate_completions(shell);
-            return Ok(());
-        }
-        Command::Setup => {
-            setup_completions()?;
-            return Ok(());
-        }
+        } => generate_completions(shell),
+        Command::Setup => setup_completions()?,
         Command::ModRs {
             path,
             fix
-        } => run_mod_rs(&path, fix)?
+        } => run_mod_rs(&path, fix)?,
+        Command::Rules {
+            export,
+            dir
+        } => run_rules(export, &dir)?
     }
 
-    Ok(())
+    Ok(ExitCode::Clean)
 }
 
 /// Generate shell completions.
@@ -124,9 +253,13 @@ fn generate_completions(shell: Shell) {
     generate(comp_shell, &mut cmd, bin_name, &mut std::io::stdout());
 }
 
+/// File name of the PowerShell profile script.
+const POWERSHELL_PROFILE: &str = "Microsoft.PowerShell_profile.ps1";
+
 /// Setup shell completions automatically.
 ///
-/// Detects current shell and installs completions to standard location.
+/// Detects current shell and installs completions to standard location. For
+/// PowerShell the script is also dot-sourced from the user's profile.
 ///
 /// # Returns
 ///
@@ -136,7 +269,7 @@ fn setup_completions() -> AppResult<()> {
 
     let Some((shell, comp_dir, file_name)) = get_completion_config(&shell_name) else {
         println!("❌ Unsupported shell: {}", shell_name);
-        println!("Supported shells: bash, fish, zsh");
+        println!("Supported shells: bash, fish, zsh, powershell");
         println!("\nManual installation:");
         println!("  cargo qual completions <shell> > <completion-file>");
         return Ok(());
@@ -148,7 +281,7 @@ fn setup_completions() -> AppResult<()> {
     if shell_name == "fish" {
         install_fish_completions(&comp_file)?;
     } else {
-        install_generated_completions(shell, &comp_file)?;
+        install_generated_completions(shell.clone(), &comp_file)?;
     }
 
     println!(
@@ -156,35 +289,77 @@ fn setup_completions() -> AppResult<()> {
         shell_name,
         comp_file.display()
     );
-    println!(
-        "\nCompletions will be available in new {} sessions",
-        shell_name
-    );
-    println!("Or run: source {}", comp_file.display());
+
+    if matches!(shell, Shell::PowerShell) {
+        let profile = comp_dir.join(POWERSHELL_PROFILE);
+        register_in_profile(&profile, &comp_file)?;
+        println!("✓ Loaded from profile: {}", profile.display());
+        println!(
+            "\nCompletions will be available in new {} sessions",
+            shell_name
+        );
+        println!("Or run: . \"{}\"", comp_file.display());
+    } else {
+        println!(
+            "\nCompletions will be available in new {} sessions",
+            shell_name
+        );
+        println!("Or run: source {}", comp_file.display());
+    }
 
     Ok(())
 }
 
 /// Detects current shell from SHELL environment variable.
 ///
+/// Windows consoles do not set `SHELL`; there the shell is PowerShell 7
+/// (`pwsh`) when `POWERSHELL_DISTRIBUTION_CHANNEL` is set and Windows
+/// PowerShell otherwise.
+///
 /// # Returns
 ///
-/// Shell name (e.g., "bash", "fish", "zsh")
+/// Shell name (e.g., "bash", "fish", "zsh", "pwsh")
 #[inline]
 fn detect_shell() -> String {
     use std::env;
 
-    let shell_path = env::var("SHELL").unwrap_or_else(|_| String::from("/bin/sh"));
-    let shell_path_buf = PathBuf::from(&shell_path);
-    shell_path_buf
-        .file_name()
-        .and_then(|s| s.to_str())
-        .unwrap_or("sh")
-        .to_string()
+    if let Some(name) = env::var("SHELL").ok().as_deref().and_then(shell_name) {
+        return name;
+    }
+
+    if cfg!(windows) {
+        if env::var_os("POWERSHELL_DISTRIBUTION_CHANNEL").is_some() {
+            String::from("pwsh")
+        } else {
+            String::from("powershell")
+        }
+    } else {
+        String::from("sh")
+    }
+}
+
+/// Extracts the shell name from a shell path.
+///
+/// Accepts both `/` and `\` separators and drops an `.exe` extension, so
+/// `C:\Program Files\Git\bin\bash.exe` is `bash`.
+///
+/// # Arguments
+///
+/// * `shell_path` - Value of the `SHELL` environment variable
+///
+/// # Returns
+///
+/// Shell name, or `None` for an empty path
+fn shell_name(shell_path: &str) -> Option<String> {
+    let file_name = shell_path.rsplit(['/', '\\']).next()?;
+    let name = file_name.strip_suffix(".exe").unwrap_or(file_name);
+    (!name.is_empty()).then(|| name.to_string())
 }
 
 /// Gets HOME directory path.
 ///
+/// Falls back to `USERPROFILE` on Windows, where `HOME` is usually unset.
+///
 /// # Returns
 ///
 /// Home directory path or "~" if not found
@@ -192,7 +367,9 @@ fn detect_shell() -> String {
 fn get_home_dir() -> String {
     use std::env;
 
-    env::var("HOME").unwrap_or_else(|_| String::from("~"))
+    env::var("HOME")
+        .or_else(|_| env::var("USERPROFILE"))
+        .unwrap_or_else(|_| String::from("~"))
 }
 
 /// Gets XDG_CONFIG_HOME directory.
@@ -227,6 +404,30 @@ fn get_xdg_data_home() -> PathBuf {
         .unwrap_or_else(|_| PathBuf::from(get_home_dir()).join(".local").join("share"))
 }
 
+/// Gets the directory of the PowerShell profile.
+///
+/// PowerShell 7 keeps its profile in `Documents\PowerShell` on Windows and
+/// in `$XDG_CONFIG_HOME/powershell` elsewhere; Windows PowerShell 5 uses
+/// `Documents\WindowsPowerShell`.
+///
+/// # Arguments
+///
+/// * `shell_name` - "pwsh" or "powershell"
+///
+/// # Returns
+///
+/// Profile directory path
+fn get_powershell_profile_dir(shell_name: &str) -> PathBuf {
+    let documents = PathBuf::from(get_home_dir()).join("Documents");
+    if shell_name == "powershell" {
+        documents.join("WindowsPowerShell")
+    } else if cfg!(windows) {
+        documents.join("PowerShell")
+    } else {
+        get_xdg_config_home().join("powershell")
+    }
+}
+
 /// Gets completion configuration for a shell.
 ///
 /// Returns None for unsupported shells.
@@ -254,10 +455,49 @@ fn get_completion_config(shell_name: &str) -> Option<(Shell, PathBuf, &'static s
             let dir = get_xdg_data_home().join("zsh").join("site-functions");
             Some((Shell::Zsh, dir, "_cargo-quality"))
         }
+        "pwsh" | "powershell" => {
+            let dir = get_powershell_profile_dir(shell_name);
+            Some((Shell::PowerShell, dir, "cargo-quality.ps1"))
+        }
         _ => None
     }
 }
 
+/// Dot-sources a completion script from a PowerShell profile.
+///
+/// PowerShell has no completion directory, so the script is loaded by a
+/// line appended to the profile. The line is added once; running setup
+/// again leaves the profile unchanged.
+///
+/// # Arguments
+///
+/// * `profile` - PowerShell profile path, created if missing
+/// * `comp_file` - Completion script path
+///
+/// # Returns
+///
+/// `AppResult<()>` - Ok if the profile loads the script
+fn register_in_profile(profile: &Path, comp_file: &Path) -> AppResult<()> {
+    let line = format!(". \"{}\"", comp_file.display());
+    let content = match fs::read_to_string(profile) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(IoError::from(e).into())
+    };
+
+    if content.lines().any(|existing| existing.trim() == line) {
+        return Ok(());
+    }
+
+    let separator = if content.is_empty() || content.ends_with('\n') {
+        ""
+    } else {
+        "\n"
+    };
+    fs::write(profile, format!("{content}{separator}{line}\n")).map_err(IoError::from)?;
+    Ok(())
+}
+
 /// Installs fish shell completions.
 ///
 /// Uses hardcoded fish completion script.
@@ -298,7 +538,7 @@ complete -c cargo -n "__fish_seen_subcommand_from qual; and __fish_seen_subcomma
     Ok(())
 }
 
-/// Installs generated completions for bash/zsh.
+/// Installs generated completions for bash/zsh/PowerShell.
 ///
 /// Uses clap_complete to generate shell-specific completions.
 ///
@@ -318,6 +558,7 @@ fn install_generated_completions(shell: Shell, comp_file: &std::path::Path) -> A
     let comp_shell = match shell {
         Shell::Bash => CompShell::Bash,
         Shell::Zsh => CompShell::Zsh,
+        Shell::PowerShell => CompShell::PowerShell,
         _ => unreachable!()
     };
 
@@ -360,152 +601,623 @@ fn run_mod_rs(path: &str, fix: bool) -> AppResult<()> {
     Ok(())
 }
 
+/// List the available rules or export their documentation.
+///
+/// Without an export format, prints one line per rule with its severity, its
+/// confidence unless high, whether it is opt-in and whether it can be fixed
+/// automatically.
+///
+/// # Arguments
+///
+/// * `export` - Format to export the rule pages in, if any
+/// * `dir` - Directory the exported pages are written to
+fn run_rules(export: Option<RulesFormat>, dir: &str) -> AppResult<()> {
+    match export {
+        Some(RulesFormat::Markdown) => {
+            let written = rules::export_markdown(Path::new(dir))?;
+            println!("Wrote {written} rule pages to {dir}");
+        }
+        Some(RulesFormat::Messages) => {
+            let written = messages::export_catalog(Path::new(dir))?;
+            println!(
+                "Wrote {written} message templates to {}",
+                Path::new(dir).join(messages::CATALOG_FILE).display()
+            );
+        }
+        None => {
+            for doc in rules::rule_docs() {
+                let mut tags = vec![doc.severity.as_str()];
+                let confidence = format!("{} confidence", doc.confidence);
+                if doc.confidence != Confidence::High {
+                    tags.push(&confidence);
+                }
+                if doc.opt_in {
+                    tags.push("opt-in");
+                }
+                if doc.fixable {
+                    tags.push("fixable");
+                }
+                println!("{:<22} {:<32} {}", doc.name, doc.title, tags.join(", "));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Settings of the text report of a check run.
+#[derive(Debug, Clone, Default)]
+struct TextOutput {
+    /// Keep files without issues in the report
+    verbose:       bool,
+    /// Enable colored output
+    color:         bool,
+    /// Terminal width override for the grid layout
+    width:         Option<usize>,
+    /// List every skipped path instead of only counting them
+    show_skipped:  bool,
+    /// Analyzers printed as counts in verbose output
+    collapse:      BTreeSet<String>,
+    /// Fixed width, no color, `/` paths and sorted output for snapshot tests
+    deterministic: bool
+}
+
 /// Check code quality without modifying files.
 ///
 /// Analyzes all Rust files in the specified path and reports issues found
-/// by each analyzer or a specific analyzer if provided. Prints detailed
-/// reports for files with issues.
+/// by each analyzer or a specific analyzer if provided. Crate-wide analyzers
+/// run once all files are parsed. Prints detailed reports for files with
+/// issues.
 ///
 /// # Arguments
 ///
 /// * `path` - File or directory path to analyze
-/// * `verbose` - Print confirmation for files without issues
+/// * `files` - Files to analyze instead of walking `path`; the mod.rs tree scan
+///   is skipped for an explicit list
+/// * `text` - Verbosity, color, width, skipped-path listing and collapsed
+///   analyzers of the text report; the project's `collapse` list is added
 /// * `analyzer_name` - Optional analyzer name to run (e.g., "inline_comments")
+/// * `min_confidence` - Lowest confidence of the analyzers that run, unless
+///   `analyzer_name` selects one
+/// * `format` - Text report, JSON lines streamed per file or markdown report
+/// * `snapshot` - Snapshot file the report is compared with instead of printed
 ///
 /// # Returns
 ///
-/// `AppResult<bool>` - `Ok(true)` if any issues were found, `Ok(false)` if the
-/// code is clean, error on IO or parse failures. The caller maps `true` to a
-/// non-zero process exit code so `check` can gate CI.
+/// `AppResult<ExitCode>` - [`ExitCode::Clean`] if the code is clean,
+/// [`ExitCode::FixableOnly`] if `fix` resolves every issue,
+/// [`ExitCode::Issues`] otherwise; with a snapshot, [`ExitCode::Issues`] only
+/// if the report changed; [`ExitCode::ToolError`] if a file did not parse.
+/// Config error for an unknown analyzer, IO error otherwise. The exit code
+/// lets `check` gate CI.
 ///
 /// # Examples
 ///
 /// ```no_run
 /// use cargo_quality::check_quality;
-/// check_quality("src/", true, None, false).unwrap();
-/// check_quality("src/", false, Some("inline_comments"), true).unwrap();
+/// let verbose = TextOutput {
+///     verbose: true,
+///     ..TextOutput::default()
+/// };
+/// check_quality(
+///     "src/",
+///     None,
+///     verbose,
+///     None,
+///     Confidence::Low,
+///     OutputFormat::Text,
+///     None
+/// )
+/// .unwrap();
+/// let colored = TextOutput {
+///     color: true,
+///     ..TextOutput::default()
+/// };
+/// check_quality(
+///     "src/",
+///     None,
+///     colored,
+///     Some("inline_comments"),
+///     Confidence::Low,
+///     OutputFormat::Text,
+///     None
+/// )
+/// .unwrap();
 /// ```
 fn check_quality(
     path: &str,
-    verbose: bool,
+    files: Option<Vec<PathBuf>>,
+    mut text: TextOutput,
     analyzer_name: Option<&str>,
-    color: bool
-) -> AppResult<bool> {
-    let files = collect_rust_files(path)?;
-    let all_analyzers = get_analyzers();
+    min_confidence: Confidence,
+    format: OutputFormat,
+    snapshot: Option<&Snapshot>
+) -> AppResult<ExitCode> {
+    let project = ProjectContext::discover(Path::new(path))?;
+    let catalog = MessageCatalog::for_project(&project)?;
+    let all_analyzers = get_analyzers_for(&project);
+    let all_file_analyzers = get_file_analyzers();
+    text.collapse.extend(project.collapse().map(str::to_string));
+    if text.deterministic {
+        text.color = false;
+        text.width = Some(DETERMINISTIC_WIDTH);
+    }
+    if let Some(name) = text.collapse.iter().find(|name| {
+        !all_analyzers.iter().any(|a| a.name() == *name)
+            && !all_file_analyzers.iter().any(|a| a.name() == *name)
+    }) {
+        print_available_analyzers(name);
+        return Err(
+            InvalidConfigError::new(format!("unknown analyzer `{name}` to collapse")).into()
+        );
+    }
+    let plan = plan_check(
+        path,
+        files,
+        analyzer_name,
+        min_confidence,
+        &project,
+        &all_analyzers,
+        &all_file_analyzers
+    )?;
+    let analyzers: Vec<_> = all_analyzers
+        .into_iter()
+        .filter(|a| plan.is_enabled(a.name()))
+        .collect();
+    let file_analyzers: Vec<_> = all_file_analyzers
+        .into_iter()
+        .filter(|a| plan.is_enabled(a.name()))
+        .collect();
+
+    let mut skipped = plan.skipped.clone();
+    let jsonl = format == OutputFormat::Jsonl;
+    let crate_wide: Vec<_> = analyzers.iter().filter(|a| a.crate_wide()).collect();
+    let mut reports = Vec::new();
+    let mut sources = Vec::new();
+    let mut suppressions = HashMap::new();
+
+    for file_path in &plan.files {
+        let mut report = Report::new(report_path(file_path, text.deterministic));
+
+        for analyzer in &file_analyzers {
+            if let Some(issue) = analyzer.analyze_path(file_path) {
+                let result = catalog.localize(analyzer.name(), issue.into_result());
+                report.add_result_with_severity(
+                    analyzer.name().to_string(),
+                    analyzer.severity(),
+                    result
+                );
+            }
+        }
 
-    let analyzers: Vec<_> = if let Some(name) = analyzer_name {
-        all_analyzers
-            .into_iter()
-            .filter(|a| a.name() == name)
-            .collect()
-    } else {
-        all_analyzers
-    };
+        let applicable: Vec<_> = analyzers
+            .iter()
+            .filter(|a| a.applies_to(file_path))
+            .collect();
+        if !applicable.is_empty() {
+            match load_source(file_path)? {
+                Source::Parsed {
+                    content,
+                    ast
+                } => {
+                    let allowed = Suppressions::from_ast(&ast);
+                    for analyzer in applicable {
+                        let result = allowed.filter_result(
+                            analyzer.name(),
+                            analyzer.analyze_file(file_path, &ast, &content)?
+                        );
+                        let result = catalog.localize(analyzer.name(), result);
+                        report.add_result_with_severity(
+                            analyzer.name().to_string(),
+                            analyzer.severity(),
+                            result
+                        );
+                    }
+
+                    if crate_wide.iter().any(|a| a.applies_to(file_path)) {
+                        suppressions.insert(file_path.clone(), allowed);
+                        sources.push(SourceFile {
+                            path: file_path.clone(),
+                            ast:  *ast
+                        });
+                    }
+                }
+                Source::Skipped(file) => skipped.add(file.path, file.cause, file.detail)
+            }
+        }
 
-    if let Some(name) = analyzer_name
-        && analyzers.is_empty()
-        && name != "mod_rs"
-    {
-        eprintln!("Unknown analyzer: {}. Available analyzers:", name);
-        for analyzer in get_analyzers() {
-            eprintln!("  - {}", analyzer.name());
+        if jsonl {
+            stream_json_lines(&report)?;
         }
-        eprintln!("  - mod_rs");
-        return Ok(false);
+        reports.push(report);
     }
 
-    let mut global_report = GlobalReport::new();
+    let crate_stage = CrateStage {
+        sources: &sources,
+        suppressions: &suppressions,
+        catalog: &catalog,
+        files: &plan.files,
+        jsonl,
+        deterministic: text.deterministic
+    };
+    for analyzer in crate_wide {
+        crate_stage.run(analyzer.as_ref(), &mut reports)?;
+    }
 
-    let should_check_mod_rs = analyzer_name.is_none() || analyzer_name == Some("mod_rs");
-    if should_check_mod_rs {
-        let mod_rs_result = find_mod_rs_issues(path)?;
-        if !mod_rs_result.is_empty() {
-            add_mod_rs_to_report(&mod_rs_result, &mut global_report);
+    let mut global_report = GlobalReport::new();
+    for report in reports {
+        if report.total_issues() > 0 || text.verbose {
+            global_report.add_report(report);
         }
     }
+    if text.deterministic {
+        global_report.make_deterministic();
+    }
 
-    if analyzer_name != Some("mod_rs") {
-        for file_path in files {
-            let content = fs::read_to_string(&file_path).map_err(IoError::from)?;
-            let ast = syn::parse_file(&content).map_err(ParseError::from)?;
+    let code = print_report(
+        &global_report,
+        &project,
+        path,
+        &text,
+        analyzer_name,
+        format,
+        snapshot
+    )?;
+    Ok(report_skipped(&skipped, text.show_skipped, code))
+}
 
-            let mut report = Report::new(file_path.display().to_string());
+/// Path of a file as shown in the report.
+///
+/// # Arguments
+///
+/// * `path` - Analyzed file
+/// * `deterministic` - Make the path relative with `/` separators
+///
+/// # Returns
+///
+/// The path as given, or normalized for `--deterministic`
+fn report_path(path: &Path, deterministic: bool) -> String {
+    let path = path.display().to_string();
+    if deterministic {
+        normalize_path(&path.replace('\\', "/"))
+    } else {
+        path
+    }
+}
 
-            for analyzer in &analyzers {
-                let result = analyzer.analyze(&ast, &content)?;
-                report.add_result(analyzer.name().to_string(), result);
-            }
+/// Crate-wide stage of a check run, after every file was analyzed.
+struct CrateStage<'a> {
+    /// Parsed files the crate-wide analyzers apply to
+    sources:       &'a [SourceFile],
+    /// Suppressions of each parsed file
+    suppressions:  &'a HashMap<PathBuf, Suppressions>,
+    /// Catalog translating the messages
+    catalog:       &'a MessageCatalog,
+    /// Files of the run, in the order of the reports
+    files:         &'a [PathBuf],
+    /// Whether issues are streamed as JSON lines
+    jsonl:         bool,
+    /// Whether file paths are normalized for `--deterministic`
+    deterministic: bool
+}
 
-            if report.total_issues() > 0 || verbose {
-                global_report.add_report(report);
+impl CrateStage<'_> {
+    /// Runs a crate-wide analyzer and adds its issues to the file reports.
+    ///
+    /// # Arguments
+    ///
+    /// * `analyzer` - Analyzer whose [`Analyzer::crate_wide`] is `true`
+    /// * `reports` - Reports of the run, one per file in [`CrateStage::files`]
+    ///
+    /// # Returns
+    ///
+    /// `AppResult<()>` - Error of the analyzer or of writing JSON lines
+    fn run(&self, analyzer: &dyn Analyzer, reports: &mut [Report]) -> AppResult<()> {
+        let files: Vec<&SourceFile> = self
+            .sources
+            .iter()
+            .filter(|file| analyzer.applies_to(&file.path))
+            .collect();
+
+        for (file_path, result) in analyzer.analyze_crate(&files)? {
+            let Some(index) = self.files.iter().position(|file| *file == file_path) else {
+                continue;
+            };
+            let result = match self.suppressions.get(&file_path) {
+                Some(allowed) => allowed.filter_result(analyzer.name(), result),
+                None => result
+            };
+
+            let mut report = Report::new(report_path(&file_path, self.deterministic));
+            report.add_result_with_severity(
+                analyzer.name().to_string(),
+                analyzer.severity(),
+                self.catalog.localize(analyzer.name(), result)
+            );
+            if self.jsonl {
+                stream_json_lines(&report)?;
+            }
+            for (name, result) in report.results {
+                reports[index].add_result_with_severity(name, analyzer.severity(), result);
             }
         }
+
+        Ok(())
+    }
+}
+
+/// Prints the report of a check run in the requested format.
+///
+/// # Arguments
+///
+/// * `global_report` - Report of the analyzed files
+/// * `project` - Project configuration, for the source links
+/// * `path` - File or directory path that was analyzed
+/// * `text` - Verbosity, color, width and collapsed analyzers of the text
+///   report
+/// * `analyzer_name` - Analyzer the run was limited to, if any
+/// * `format` - Output format of the report
+/// * `snapshot` - Snapshot file the report is compared with instead of printed
+///
+/// # Returns
+///
+/// `AppResult<ExitCode>` - Exit code of the issues or the snapshot
+/// comparison, IO error if the snapshot cannot be accessed
+fn print_report(
+    global_report: &GlobalReport,
+    project: &ProjectContext,
+    path: &str,
+    text: &TextOutput,
+    analyzer_name: Option<&str>,
+    format: OutputFormat,
+    snapshot: Option<&Snapshot>
+) -> AppResult<ExitCode> {
+    if let Some(snapshot) = snapshot {
+        return check_snapshot(snapshot, global_report);
+    }
+
+    let code = ExitCode::for_issues(global_report.total_issues(), global_report.total_fixable());
+    if format == OutputFormat::Jsonl {
+        return Ok(code);
+    }
+    if format == OutputFormat::Github {
+        print!("{}", global_report.display_github());
+        return Ok(code);
+    }
+    if format == OutputFormat::Markdown {
+        let links = SourceLinks::detect(project.repository(), Path::new(path));
+        print!("{}", global_report.display_markdown(links.as_ref()));
+        return Ok(code);
+    }
+
+    if analyzer_name.is_none() {
+        print!(
+            "{}",
+            global_report.display_by_file("todo_comments", "TODO comments", text.color)
+        );
     }
 
     if global_report.total_issues() > 0 {
         if let Some(analyzer) = analyzer_name {
-            print!("{}", global_report.display_analyzer(analyzer, color));
-        } else if verbose {
-            print!("{}", global_report.display_verbose(color));
+            print!(
+                "{}",
+                global_report.display_analyzer(analyzer, text.color, text.width)
+            );
+        } else if text.verbose {
+            print!(
+                "{}",
+                global_report.display_verbose(text.color, text.width, &text.collapse)
+            );
         } else {
-            print!("{}", global_report.display_compact(color));
+            print!("{}", global_report.display_compact(text.color));
         }
     } else {
-        print!("{}", global_report.display_compact(color));
+        print!("{}", global_report.display_compact(text.color));
+    }
+
+    Ok(code)
+}
+
+/// Prints the paths a run left out and settles its exit code.
+///
+/// The summary goes to stderr so it never mixes with JSON lines, markdown or
+/// annotations on stdout.
+///
+/// # Arguments
+///
+/// * `skipped` - Paths left out of the run
+/// * `list` - List every path with its cause, as `--show-skipped` asks
+/// * `code` - Exit code of the files that were analyzed
+///
+/// # Returns
+///
+/// [`ExitCode::ToolError`] if a file did not parse, `code` otherwise
+fn report_skipped(skipped: &SkippedFiles, list: bool, code: ExitCode) -> ExitCode {
+    if let Some(summary) = skipped.summary(list) {
+        eprint!("{summary}");
     }
 
-    Ok(global_report.total_issues() > 0)
+    if skipped.count(SkipCause::ParseError) > 0 {
+        ExitCode::ToolError
+    } else {
+        code
+    }
 }
 
-/// Runs the check command and maps the result to a process exit code.
+/// Compares the report of a check run with its snapshot file.
+///
+/// # Arguments
+///
+/// * `snapshot` - Snapshot file to compare with or write
+/// * `report` - Report of the run
+///
+/// # Returns
+///
+/// `AppResult<ExitCode>` - [`ExitCode::Issues`] if the report changed,
+/// [`ExitCode::Clean`] otherwise, IO error if the file cannot be accessed
+fn check_snapshot(snapshot: &Snapshot, report: &GlobalReport) -> AppResult<ExitCode> {
+    let path = snapshot.path().display();
+
+    match snapshot.compare(&render_snapshot(report))? {
+        SnapshotOutcome::Created => println!("Snapshot written to {path}"),
+        SnapshotOutcome::Updated => println!("Snapshot {path} updated"),
+        SnapshotOutcome::Unchanged => println!("Snapshot {path} is up to date"),
+        SnapshotOutcome::Changed(diff) => {
+            println!("Snapshot {path} does not match the report:\n\n{diff}");
+            println!("Review the changes and rerun with --update-snapshot to accept them");
+            return Ok(ExitCode::Issues);
+        }
+    }
+
+    Ok(ExitCode::Clean)
+}
+
+/// Resolves the files and analyzers of a check run.
 ///
 /// # Arguments
 ///
 /// * `path` - File or directory path to analyze
-/// * `verbose` - Print confirmation for files without issues
+/// * `files` - Files to analyze instead of walking `path`
 /// * `analyzer_name` - Optional analyzer name to run
-/// * `color` - Enable colored output
+/// * `min_confidence` - Lowest confidence of the analyzers that run, ignored
+///   when `analyzer_name` selects one
+/// * `project` - Project configuration
+/// * `analyzers` - Analyzers configured for the project
+/// * `file_analyzers` - File-level analyzers
 ///
 /// # Returns
 ///
-/// `AppResult<i32>` - `1` if any issues were found, `0` otherwise, error on IO
-/// or parse failures
-fn check_command(
+/// `AppResult<Plan>` - Plan of the run with the paths the walk left out,
+/// config error for an unknown analyzer or invalid exclude glob, IO error if
+/// the path cannot be walked
+fn plan_check(
     path: &str,
-    verbose: bool,
+    files: Option<Vec<PathBuf>>,
     analyzer_name: Option<&str>,
-    color: bool
-) -> AppResult<i32> {
-    let has_issues = check_quality(path, verbose, analyzer_name, color)?;
-    Ok(i32::from(has_issues))
+    min_confidence: Confidence,
+    project: &ProjectContext,
+    analyzers: &[Box<dyn Analyzer>],
+    file_analyzers: &[Box<dyn FileAnalyzer>]
+) -> AppResult<Plan> {
+    if let Some(name) = analyzer_name
+        && !analyzers.iter().any(|a| a.name() == name)
+        && !file_analyzers.iter().any(|a| a.name() == name)
+    {
+        print_available_analyzers(name);
+        return Err(InvalidConfigError::new(format!("unknown analyzer `{name}`")).into());
+    }
+
+    let (files, skipped) = match files {
+        Some(files) => (files, SkippedFiles::new()),
+        None => collect_project_files(path, project)?
+    };
+
+    let mut plan = Plan::new(project, analyzers, file_analyzers, files, analyzer_name);
+    if analyzer_name.is_none() {
+        plan.require_confidence(min_confidence);
+    }
+    plan.skipped = skipped;
+    Ok(plan)
 }
 
-/// Adds mod.rs issues to the global report.
+/// Prints the names of all analyzers after an unknown one was requested.
+///
+/// # Arguments
 ///
-/// Converts ModRsResult into Report format for unified display.
+/// * `name` - Requested analyzer name
+fn print_available_analyzers(name: &str) {
+    eprintln!("Unknown analyzer: {}. Available analyzers:", name);
+    for analyzer in get_analyzers() {
+        eprintln!("  - {}", analyzer.name());
+    }
+    for analyzer in get_file_analyzers() {
+        eprintln!("  - {}", analyzer.name());
+    }
+}
+
+/// Selects the file analyzers of a `fix` or `diff` run.
 ///
 /// # Arguments
 ///
-/// * `mod_rs_result` - Result from mod_rs analysis
-/// * `global_report` - Global report to add issues to
-fn add_mod_rs_to_report(mod_rs_result: &ModRsResult, global_report: &mut GlobalReport) {
-    for issue in &mod_rs_result.issues {
-        let mut report = Report::new(issue.path.display().to_string());
-
-        let analysis_result = AnalysisResult {
-            issues:        vec![Issue {
-                line:    issue.line,
-                column:  issue.column,
-                message: issue.message.clone(),
-                fix:     Fix::Simple(issue.suggested.display().to_string())
-            }],
-            fixable_count: 1
-        };
+/// * `analyzer_name` - Optional analyzer name to run
+///
+/// # Returns
+///
+/// The named file analyzer, or all of them without a name
+fn select_file_analyzers(analyzer_name: Option<&str>) -> Vec<Box<dyn FileAnalyzer>> {
+    get_file_analyzers()
+        .into_iter()
+        .filter(|a| analyzer_name.is_none_or(|name| a.name() == name))
+        .collect()
+}
 
-        report.add_result("mod_rs".to_string(), analysis_result);
-        global_report.add_report(report);
+/// Prints the plan of a check run without analyzing any file.
+///
+/// # Arguments
+///
+/// * `path` - File or directory path to analyze
+/// * `files` - Files to analyze instead of walking `path`
+/// * `analyzer_name` - Optional analyzer name to run
+/// * `min_confidence` - Lowest confidence of the analyzers that run
+///
+/// # Returns
+///
+/// `AppResult<()>` - Config error for an unknown analyzer, IO error if the
+/// path cannot be walked
+fn print_plan(
+    path: &str,
+    files: Option<Vec<PathBuf>>,
+    analyzer_name: Option<&str>,
+    min_confidence: Confidence
+) -> AppResult<()> {
+    let project = ProjectContext::discover(Path::new(path))?;
+    let analyzers = get_analyzers_for(&project);
+    let plan = plan_check(
+        path,
+        files,
+        analyzer_name,
+        min_confidence,
+        &project,
+        &analyzers,
+        &get_file_analyzers()
+    )?;
+    print!("{plan}");
+    Ok(())
+}
+
+/// Prints the issues of a file as JSON lines and flushes them right away.
+///
+/// # Arguments
+///
+/// * `report` - Report of a single file
+///
+/// # Returns
+///
+/// `AppResult<()>` - Error if stdout cannot be written
+fn stream_json_lines(report: &Report) -> AppResult<()> {
+    let mut stdout = std::io::stdout().lock();
+    stdout
+        .write_all(report.to_json_lines().as_bytes())
+        .and_then(|()| stdout.flush())
+        .map_err(IoError::from)?;
+    Ok(())
+}
+
+/// Reads the files to work on from stdin when `--stdin` is given.
+///
+/// # Arguments
+///
+/// * `stdin` - Whether the `--stdin` flag was passed
+///
+/// # Returns
+///
+/// `AppResult<Option<Vec<PathBuf>>>` - The listed files, `None` to walk the
+/// path instead
+fn stdin_files(stdin: bool) -> AppResult<Option<Vec<PathBuf>>> {
+    if !stdin {
+        return Ok(None);
     }
+    read_file_list(std::io::stdin().lock()).map(Some)
 }
 
 /// Fix quality issues automatically.
@@ -517,23 +1229,67 @@ fn add_mod_rs_to_report(mod_rs_result: &ModRsResult, global_report: &mut GlobalR
 /// # Arguments
 ///
 /// * `path` - File or directory path to fix
+/// * `files` - Files to fix instead of walking `path`
 /// * `dry_run` - If true, report fixes but do not modify files
 /// * `analyzer_name` - Optional analyzer name to run (e.g., "path_import")
+/// * `show_skipped` - List every skipped path instead of only counting them
 ///
 /// # Returns
 ///
-/// `AppResult<()>` - Ok if fixes applied successfully, error on IO or parse
-/// failures
+/// `AppResult<ExitCode>` - [`ExitCode::Clean`] once the fixes are applied,
+/// [`ExitCode::ToolError`] if a file did not parse; config error for an
+/// unknown analyzer, error on IO failures
 ///
 /// # Examples
 ///
 /// ```no_run
 /// use cargo_quality::fix_quality;
-/// fix_quality("src/", true, None).unwrap();
-/// fix_quality("src/", false, Some("path_import")).unwrap();
+/// fix_quality("src/", None, true, None, false).unwrap();
+/// fix_quality("src/", None, false, Some("path_import"), true).unwrap();
 /// ```
-fn fix_quality(path: &str, dry_run: bool, analyzer_name: Option<&str>) -> AppResult<()> {
-    let all_analyzers = get_analyzers();
+fn fix_quality(
+    path: &str,
+    files: Option<Vec<PathBuf>>,
+    dry_run: bool,
+    analyzer_name: Option<&str>,
+    show_skipped: bool
+) -> AppResult<ExitCode> {
+    let run = apply_fixes(path, files, dry_run, analyzer_name)?;
+    Ok(report_skipped(&run.skipped, show_skipped, ExitCode::Clean))
+}
+
+/// Changes made by a fix run.
+#[derive(Debug, Default)]
+struct FixRun {
+    /// Files whose content was fixed
+    fixed:   Vec<FixedFile>,
+    /// Files moved to the location a file analyzer proposed, as (from, to)
+    renamed: Vec<(PathBuf, PathBuf)>,
+    /// Paths left out of the run
+    skipped: SkippedFiles
+}
+
+/// Applies the fixes of all active analyzers or of a specific one.
+///
+/// # Arguments
+///
+/// * `path` - File or directory path to fix
+/// * `files` - Files to fix instead of walking `path`
+/// * `dry_run` - If true, report fixes but do not modify files
+/// * `analyzer_name` - Optional analyzer name to run
+///
+/// # Returns
+///
+/// `AppResult<FixRun>` - Fixed, renamed and skipped files; config error for
+/// an unknown analyzer, error on IO failures
+fn apply_fixes(
+    path: &str,
+    files: Option<Vec<PathBuf>>,
+    dry_run: bool,
+    analyzer_name: Option<&str>
+) -> AppResult<FixRun> {
+    let project = ProjectContext::discover(Path::new(path))?;
+    let all_analyzers = get_analyzers_for(&project);
 
     let analyzers: Vec<_> = if let Some(name) = analyzer_name {
         all_analyzers
@@ -542,69 +1298,224 @@ fn fix_quality(path: &str, dry_run: bool, analyzer_name: Option<&str>) -> AppRes
             .collect()
     } else {
         all_analyzers
+            .into_iter()
+            .filter(|a| project.is_active(a.as_ref()))
+            .collect()
     };
 
+    let file_analyzers = select_file_analyzers(analyzer_name);
+
     if let Some(name) = analyzer_name
         && analyzers.is_empty()
-        && name != "mod_rs"
+        && file_analyzers.is_empty()
     {
-        eprintln!("Unknown analyzer: {}. Available analyzers:", name);
-        for analyzer in get_analyzers() {
-            eprintln!("  - {}", analyzer.name());
+        print_available_analyzers(name);
+        return Err(InvalidConfigError::new(format!("unknown analyzer `{name}`")).into());
+    }
+
+    let (files, skipped) = match files {
+        Some(files) => (files, SkippedFiles::new()),
+        None => collect_project_files(path, &project)?
+    };
+    let mut run = FixRun {
+        skipped,
+        ..FixRun::default()
+    };
+    for file_path in files {
+        let applicable: Vec<_> = analyzers
+            .iter()
+            .filter(|a| a.applies_to(&file_path))
+            .map(|a| a.as_ref())
+            .collect();
+        if !applicable.is_empty()
+            && let Some(fixed) = fix_file(&file_path, &applicable, dry_run, &mut run.skipped)?
+        {
+            run.fixed.push(fixed);
+        }
+
+        let mut renames = file_analyzers
+            .iter()
+            .filter_map(|a| a.analyze_path(&file_path)?.rename);
+        if let Some(target) = renames.next()
+            && fix_location(&file_path, &target, dry_run)?
+        {
+            run.renamed.push((file_path, target));
         }
-        eprintln!("  - mod_rs");
-        return Ok(());
     }
 
-    let should_fix_mod_rs = analyzer_name.is_none() || analyzer_name == Some("mod_rs");
-    if should_fix_mod_rs {
-        let mod_rs_result = find_mod_rs_issues(path)?;
-        if !mod_rs_result.is_empty() {
-            if dry_run {
-                for issue in &mod_rs_result.issues {
-                    println!(
-                        "Would fix: {} -> {}",
-                        issue.path.display(),
-                        issue.suggested.display()
-                    );
-                }
-            } else {
-                let fixed = fix_all_mod_rs(path)?;
-                if fixed > 0 {
-                    println!("Fixed {} mod.rs files", fixed);
-                }
-            }
+    Ok(run)
+}
+
+/// Applies the fixes of the analyzers to a single file.
+///
+/// # Arguments
+///
+/// * `file_path` - File to fix
+/// * `analyzers` - Analyzers applying to the file
+/// * `dry_run` - If true, report fixes but do not modify the file
+/// * `skipped` - Receives the file if it is generated, too large or does not
+///   parse
+///
+/// # Returns
+///
+/// `AppResult<Option<FixedFile>>` - Fixes applied to the file, `None` if
+/// nothing was fixed or on a dry run; error on IO failures
+fn fix_file(
+    file_path: &Path,
+    analyzers: &[&dyn Analyzer],
+    dry_run: bool,
+    skipped: &mut SkippedFiles
+) -> AppResult<Option<FixedFile>> {
+    let (content, ast) = match load_source(file_path)? {
+        Source::Parsed {
+            content,
+            ast
+        } => (content, ast),
+        Source::Skipped(file) => {
+            skipped.add(file.path, file.cause, file.detail);
+            return Ok(None);
+        }
+    };
+
+    let suppressions = Suppressions::from_ast(&ast);
+    let mut suggestions = Vec::new();
+    let mut fixing = Vec::new();
+    for analyzer in analyzers {
+        let found = suppressions.filter_suggestions(
+            analyzer.name(),
+            analyzer.file_suggestions(file_path, &ast, &content)?
+        );
+        if !found.is_empty() {
+            fixing.push(analyzer.name().to_string());
         }
+        suggestions.extend(found);
     }
 
-    if analyzer_name != Some("mod_rs") {
-        let files = collect_rust_files(path)?;
-        for file_path in files {
-            let content = fs::read_to_string(&file_path).map_err(IoError::from)?;
-            let ast = syn::parse_file(&content).map_err(ParseError::from)?;
+    if suggestions.is_empty() {
+        return Ok(None);
+    }
 
-            let mut suggestions = Vec::new();
-            for analyzer in &analyzers {
-                suggestions.extend(analyzer.suggestions(&ast, &content)?);
-            }
+    if dry_run {
+        let fixable = fixer::select_non_overlapping(&suggestions).len();
+        println!("Would fix {} issues in {}", fixable, file_path.display());
+        return Ok(None);
+    }
 
-            let fixed = suggestions.len();
-            if fixed == 0 {
-                continue;
-            }
+    let outcome = fixer::apply_suggestions(&content, &suggestions);
+    fs::write(file_path, &outcome.source).map_err(IoError::from)?;
+    println!(
+        "Fixed {} issues in {}",
+        outcome.applied,
+        file_path.display()
+    );
+    if outcome.conflicts > 0 {
+        println!(
+            "Skipped {} conflicting fixes in {}, run again to apply them",
+            outcome.conflicts,
+            file_path.display()
+        );
+    }
 
-            if dry_run {
-                println!("Would fix {} issues in {}", fixed, file_path.display());
-                continue;
-            }
+    Ok(Some(FixedFile {
+        path:      file_path.to_path_buf(),
+        applied:   outcome.applied,
+        conflicts: outcome.conflicts,
+        analyzers: fixing
+    }))
+}
 
-            let updated = fixer::apply_suggestions(&content, &suggestions);
-            fs::write(&file_path, updated).map_err(IoError::from)?;
-            println!("Fixed {} issues in {}", fixed, file_path.display());
-        }
+/// Moves a file to the location proposed by a file analyzer.
+///
+/// An existing target is left alone and reported instead.
+///
+/// # Arguments
+///
+/// * `file_path` - File to move
+/// * `target` - Path to move the file to
+/// * `dry_run` - If true, report the move but do not perform it
+///
+/// # Returns
+///
+/// `AppResult<bool>` - Whether the file was moved, error if the file cannot
+/// be moved
+fn fix_location(file_path: &Path, target: &Path, dry_run: bool) -> AppResult<bool> {
+    if target.exists() {
+        eprintln!(
+            "Skipping {}: target {} already exists",
+            file_path.display(),
+            target.display()
+        );
+        return Ok(false);
     }
 
-    Ok(())
+    if dry_run {
+        println!(
+            "Would rename {} -> {}",
+            file_path.display(),
+            target.display()
+        );
+        return Ok(false);
+    }
+
+    let rewritten = rename_file(file_path, target)?;
+    println!("Renamed {} -> {}", file_path.display(), target.display());
+    if rewritten > 0 {
+        println!(
+            "Updated references to {} in {rewritten} files",
+            target.display()
+        );
+    }
+    Ok(true)
+}
+
+/// Applies every fix on a branch and writes the summary for a CI workflow.
+///
+/// Runs the fixes of all active analyzers on the project. When a file
+/// changed, the changes are committed to `branch` unless `commit` is false;
+/// committing requires a clean working tree, checked before any fix.
+/// The summary is written in every case, with `"changed": false` when there
+/// was nothing to fix.
+///
+/// # Arguments
+///
+/// * `path` - Directory inside the repository to fix
+/// * `label` - Label the workflow puts on the pull request
+/// * `branch` - Branch the fixes are committed to
+/// * `summary_path` - File the JSON summary is written to
+/// * `commit` - Whether to commit the changes to `branch`
+///
+/// # Returns
+///
+/// `AppResult<ExitCode>` - [`ExitCode::Clean`], [`ExitCode::ToolError`] if a
+/// file did not parse; IO error if the working tree is not clean, or a file,
+/// git or the summary fails
+fn run_bot(
+    path: &str,
+    label: &str,
+    branch: &str,
+    summary_path: &Path,
+    commit: bool
+) -> AppResult<ExitCode> {
+    if commit {
+        bot::ensure_clean(Path::new(path))?;
+    }
+    let run = apply_fixes(path, None, false, None)?;
+    let summary = BotSummary {
+        files: run.fixed,
+        renamed: run.renamed,
+        ..BotSummary::new(label, branch)
+    };
+
+    if summary.has_changes() && commit {
+        bot::commit_to_branch(Path::new(path), branch, &summary.body())?;
+        println!("Committed {} fixes to {branch}", summary.applied());
+    } else if !summary.has_changes() {
+        println!("Nothing to fix");
+    }
+    summary.write(summary_path)?;
+    println!("Wrote summary to {}", summary_path.display());
+
+    Ok(report_skipped(&run.skipped, false, ExitCode::Clean))
 }
 
 /// Format code according to quality rules.
@@ -617,9 +1528,9 @@ fn fix_quality(path: &str, dry_run: bool, analyzer_name: Option<&str>) -> AppRes
 ///
 /// # Returns
 ///
-/// `AppResult<()>` - Ok if formatting succeeds, error otherwise
-fn format_quality(path: &str) -> AppResult<()> {
-    fix_quality(path, false, None)
+/// `AppResult<ExitCode>` - Exit code of the fixes, error otherwise
+fn format_quality(path: &str) -> AppResult<ExitCode> {
+    fix_quality(path, None, false, None, false)
 }
 
 /// Show diff of proposed quality fixes.
@@ -627,7 +1538,8 @@ fn format_quality(path: &str) -> AppResult<()> {
 /// Displays changes that would be made by quality analyzers. Supports three
 /// modes:
 /// - Full: Complete unified diff output
-/// - Summary: Brief statistics by file and analyzer
+/// - Summary: Brief statistics by file and analyzer, with deltas since the
+///   previous summary of the project from its history
 /// - Interactive: User selects which changes to apply
 ///
 /// # Arguments
@@ -636,27 +1548,44 @@ fn format_quality(path: &str) -> AppResult<()> {
 /// * `summary` - Show brief summary instead of full diff
 /// * `interactive` - Enable interactive mode for selecting changes
 /// * `analyzer_name` - Optional analyzer name to run (e.g., "path_import")
+/// * `color` - Enable colored output
+/// * `width` - Terminal width override for the full diff grid
+/// * `show_skipped` - List every skipped path instead of only counting them
 ///
 /// # Returns
 ///
-/// `AppResult<()>` - Ok if diff generated successfully, error otherwise
+/// `AppResult<ExitCode>` - [`ExitCode::ToolError`] if a file did not parse,
+/// [`ExitCode::Clean`] otherwise; config error for an unknown analyzer,
+/// error if the diff cannot be generated
 ///
 /// # Examples
 ///
 /// ```no_run
 /// use cargo_quality::run_diff;
-/// run_diff("src/", false, false, None, false).unwrap();
-/// run_diff("src/", true, false, Some("path_import"), false).unwrap();
+/// run_diff("src/", false, false, None, false, None, false).unwrap();
+/// run_diff(
+///     "src/",
+///     true,
+///     false,
+///     Some("path_import"),
+///     false,
+///     Some(100),
+///     true
+/// )
+/// .unwrap();
 /// ```
 fn run_diff(
     path: &str,
     summary: bool,
     interactive: bool,
     analyzer_name: Option<&str>,
-    color: bool
-) -> AppResult<()> {
-    let files = collect_rust_files(path)?;
-    let all_analyzers = get_analyzers();
+    color: bool,
+    width: Option<usize>,
+    show_skipped: bool
+) -> AppResult<ExitCode> {
+    let project = ProjectContext::discover(Path::new(path))?;
+    let (files, mut skipped) = collect_project_files(path, &project)?;
+    let all_analyzers = get_analyzers_for(&project);
 
     let analyzers: Vec<_> = if let Some(name) = analyzer_name {
         all_analyzers
@@ -665,48 +1594,60 @@ fn run_diff(
             .collect()
     } else {
         all_analyzers
+            .into_iter()
+            .filter(|a| project.is_active(a.as_ref()))
+            .collect()
     };
 
+    let file_analyzers = select_file_analyzers(analyzer_name);
+
     if let Some(name) = analyzer_name
         && analyzers.is_empty()
+        && file_analyzers.is_empty()
     {
-        eprintln!("Unknown analyzer: {}. Available analyzers:", name);
-        for analyzer in get_analyzers() {
-            eprintln!("  - {}", analyzer.name());
-        }
-        return Ok(());
+        print_available_analyzers(name);
+        return Err(InvalidConfigError::new(format!("unknown analyzer `{name}`")).into());
     }
 
-    let mut result = DiffResult::new();
-
-    for file_path in files {
-        let Some(path_str) = file_path.to_str() else {
-            eprintln!("Skipping non-UTF-8 path: {}", file_path.display());
-            continue;
-        };
-
-        let file_diff = generate_diff(path_str, &analyzers)?;
-        result.add_file(file_diff);
-    }
+    let paths: Vec<&str> = files
+        .iter()
+        .filter_map(|file_path| {
+            let path_str = file_path.to_str();
+            if path_str.is_none() {
+                eprintln!("Skipping non-UTF-8 path: {}", file_path.display());
+            }
+            path_str
+        })
+        .collect();
+    let result = generate_diffs(&paths, &analyzers, &file_analyzers)?;
+    skipped.extend(result.skipped.clone());
+    let history = History::for_project(&project).filter(|_| summary && analyzer_name.is_none());
 
     if result.total_changes() == 0 {
         println!("No changes proposed");
-        return Ok(());
+        if let Some(history) = &history {
+            history.record(&HistoryEntry::default())?;
+        }
+        return Ok(report_skipped(&skipped, show_skipped, ExitCode::Clean));
     }
 
     if summary {
-        show_summary(&result, color);
+        let previous = history.as_ref().map(History::last).transpose()?.flatten();
+        show_summary(&result, previous.as_ref(), color);
+        if let Some(history) = &history {
+            history.record(&HistoryEntry::new(result.analyzer_counts()))?;
+        }
     } else if interactive {
         let selected = show_interactive(&result, color)?;
         if selected.total_changes() > 0 {
-            let applied = apply_diff(&selected)?;
-            println!("Applied {} changes", applied);
+            let report = apply_diff(&selected)?;
+            show_apply_summary(&report, color);
         }
     } else {
-        show_full(&result, color);
+        show_full(&result, color, width);
     }
 
-    Ok(())
+    Ok(report_skipped(&skipped, show_skipped, ExitCode::Clean))
 }
 
 #[cfg(test)]
@@ -729,6 +1670,73 @@ mod tests {
         assert!(!content.contains("__fish_seen_subcommand_from quality"));
     }
 
+    #[test]
+    fn test_shell_name() {
+        assert_eq!(shell_name("/usr/bin/fish").as_deref(), Some("fish"));
+        assert_eq!(
+            shell_name(r"C:\Program Files\Git\bin\bash.exe").as_deref(),
+            Some("bash")
+        );
+        assert_eq!(shell_name("pwsh.exe").as_deref(), Some("pwsh"));
+        assert_eq!(shell_name(""), None);
+    }
+
+    #[test]
+    fn test_get_completion_config_powershell() {
+        let (shell, dir, file_name) = get_completion_config("powershell").unwrap();
+        assert!(matches!(shell, Shell::PowerShell));
+        assert!(dir.ends_with("Documents/WindowsPowerShell"));
+        assert_eq!(file_name, "cargo-quality.ps1");
+
+        let (shell, _, _) = get_completion_config("pwsh").unwrap();
+        assert!(matches!(shell, Shell::PowerShell));
+        assert!(get_completion_config("cmd").is_none());
+    }
+
+    #[test]
+    fn test_install_powershell_completions() {
+        let temp_dir = TempDir::new().unwrap();
+        let comp_file = temp_dir.path().join("cargo-quality.ps1");
+
+        install_generated_completions(Shell::PowerShell, &comp_file).unwrap();
+
+        let content = fs::read_to_string(&comp_file).unwrap();
+        assert!(content.contains("Register-ArgumentCompleter"));
+    }
+
+    #[test]
+    fn test_register_in_profile() {
+        let temp_dir = TempDir::new().unwrap();
+        let profile = temp_dir.path().join(POWERSHELL_PROFILE);
+        let comp_file = temp_dir.path().join("cargo-quality.ps1");
+        fs::write(&profile, "Set-PSReadLineOption -EditMode Emacs").unwrap();
+
+        register_in_profile(&profile, &comp_file).unwrap();
+        register_in_profile(&profile, &comp_file).unwrap();
+
+        assert_eq!(
+            fs::read_to_string(&profile).unwrap(),
+            format!(
+                "Set-PSReadLineOption -EditMode Emacs\n. \"{}\"\n",
+                comp_file.display()
+            )
+        );
+    }
+
+    #[test]
+    fn test_register_in_new_profile() {
+        let temp_dir = TempDir::new().unwrap();
+        let profile = temp_dir.path().join(POWERSHELL_PROFILE);
+        let comp_file = temp_dir.path().join("cargo-quality.ps1");
+
+        register_in_profile(&profile, &comp_file).unwrap();
+
+        assert_eq!(
+            fs::read_to_string(&profile).unwrap(),
+            format!(". \"{}\"\n", comp_file.display())
+        );
+    }
+
     #[test]
     fn test_check_quality() {
         let temp_dir = TempDir::new().unwrap();
@@ -739,29 +1747,167 @@ mod tests {
         )
         .unwrap();
 
-        let result = check_quality(temp_dir.path().to_str().unwrap(), false, None, false);
-        assert!(result.unwrap(), "issues present should return true");
+        let result = check_quality(
+            temp_dir.path().to_str().unwrap(),
+            None,
+            TextOutput::default(),
+            None,
+            Confidence::Low,
+            OutputFormat::Text,
+            None
+        );
+        assert_ne!(
+            result.unwrap(),
+            ExitCode::Clean,
+            "issues present should fail"
+        );
     }
 
     #[test]
-    fn test_check_command_exit_codes() {
+    fn test_check_quality_exit_codes() {
         let temp_dir = TempDir::new().unwrap();
         let dirty = temp_dir.path().join("dirty.rs");
         fs::write(
             &dirty,
-            "fn main() { let x = std::fs::read_to_string(\"f\"); }"
+            "fn main() { let text = std::fs::read_to_string(\"f\"); }"
         )
         .unwrap();
         assert_eq!(
-            check_command(dirty.to_str().unwrap(), false, None, false).unwrap(),
-            1
+            check_quality(
+                dirty.to_str().unwrap(),
+                None,
+                TextOutput::default(),
+                None,
+                Confidence::Low,
+                OutputFormat::Text,
+                None
+            )
+            .unwrap(),
+            ExitCode::FixableOnly
         );
 
         let clean = temp_dir.path().join("clean.rs");
-        fs::write(&clean, "fn main() {}").unwrap();
+        fs::write(&clean, "fn main() {}\n").unwrap();
         assert_eq!(
-            check_command(clean.to_str().unwrap(), false, None, false).unwrap(),
-            0
+            check_quality(
+                clean.to_str().unwrap(),
+                None,
+                TextOutput::default(),
+                None,
+                Confidence::Low,
+                OutputFormat::Text,
+                None
+            )
+            .unwrap(),
+            ExitCode::Clean
+        );
+
+        let mixed = temp_dir.path().join("mixed.rs");
+        fs::write(&mixed, "fn main() { let x = value.unwrap(); }\n").unwrap();
+        assert_eq!(
+            check_quality(
+                mixed.to_str().unwrap(),
+                None,
+                TextOutput::default(),
+                None,
+                Confidence::Low,
+                OutputFormat::Text,
+                None
+            )
+            .unwrap(),
+            ExitCode::Issues
+        );
+
+        let unknown = check_quality(
+            clean.to_str().unwrap(),
+            None,
+            TextOutput::default(),
+            Some("no_such_rule"),
+            Confidence::Low,
+            OutputFormat::Text,
+            None
+        )
+        .unwrap_err();
+        assert_eq!(ExitCode::from_error(&unknown), ExitCode::ConfigError);
+    }
+
+    #[test]
+    fn test_check_quality_explicit_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let dirty = temp_dir.path().join("dirty.rs");
+        fs::write(
+            &dirty,
+            "fn main() { let text = std::fs::read_to_string(\"f\"); }"
+        )
+        .unwrap();
+        let clean = temp_dir.path().join("clean.rs");
+        fs::write(&clean, "fn main() {}\n").unwrap();
+        let path = temp_dir.path().to_str().unwrap();
+
+        assert_eq!(
+            check_quality(
+                path,
+                Some(vec![clean.clone()]),
+                TextOutput::default(),
+                None,
+                Confidence::Low,
+                OutputFormat::Text,
+                None
+            )
+            .unwrap(),
+            ExitCode::Clean
+        );
+        assert_ne!(
+            check_quality(
+                path,
+                Some(vec![clean, dirty]),
+                TextOutput::default(),
+                None,
+                Confidence::Low,
+                OutputFormat::Text,
+                None
+            )
+            .unwrap(),
+            ExitCode::Clean
+        );
+    }
+
+    #[test]
+    fn test_check_quality_jsonl() {
+        let temp_dir = TempDir::new().unwrap();
+        let dirty = temp_dir.path().join("dirty.rs");
+        fs::write(
+            &dirty,
+            "fn main() { let text = std::fs::read_to_string(\"f\"); }"
+        )
+        .unwrap();
+        let path = temp_dir.path().to_str().unwrap();
+
+        assert_ne!(
+            check_quality(
+                path,
+                None,
+                TextOutput::default(),
+                None,
+                Confidence::Low,
+                OutputFormat::Jsonl,
+                None
+            )
+            .unwrap(),
+            ExitCode::Clean
+        );
+        assert_eq!(
+            check_quality(
+                path,
+                None,
+                TextOutput::default(),
+                Some("mod_rs"),
+                Confidence::Low,
+                OutputFormat::Jsonl,
+                None
+            )
+            .unwrap(),
+            ExitCode::Clean
         );
     }
 
@@ -771,8 +1917,140 @@ mod tests {
         let file_path = temp_dir.path().join("clean.rs");
         fs::write(&file_path, "fn main() {}").unwrap();
 
-        let result = check_quality(temp_dir.path().to_str().unwrap(), true, None, false);
+        let result = check_quality(
+            temp_dir.path().to_str().unwrap(),
+            None,
+            TextOutput {
+                verbose: true,
+                ..TextOutput::default()
+            },
+            None,
+            Confidence::Low,
+            OutputFormat::Text,
+            None
+        );
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_check_quality_collapse_validates_names() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("main.rs"), "fn main() {}").unwrap();
+        let text = |name: &str| TextOutput {
+            verbose: true,
+            collapse: BTreeSet::from([name.to_string()]),
+            ..TextOutput::default()
+        };
+
+        let path = temp_dir.path().to_str().unwrap();
+        let result = check_quality(
+            path,
+            None,
+            text("empty_lines"),
+            None,
+            Confidence::Low,
+            OutputFormat::Text,
+            None
+        );
         assert!(result.is_ok());
+        let result = check_quality(
+            path,
+            None,
+            text("no_such_rule"),
+            None,
+            Confidence::Low,
+            OutputFormat::Text,
+            None
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_check_quality_min_confidence() {
+        let temp_dir = TempDir::new().unwrap();
+        let dirty = temp_dir.path().join("dirty.rs");
+        fs::write(
+            &dirty,
+            "fn main() { let text = std::fs::read_to_string(\"f\"); }\n"
+        )
+        .unwrap();
+        let path = dirty.to_str().unwrap();
+        let check = |analyzer: Option<&str>| {
+            check_quality(
+                path,
+                None,
+                TextOutput::default(),
+                analyzer,
+                Confidence::High,
+                OutputFormat::Text,
+                None
+            )
+            .unwrap()
+        };
+
+        assert_eq!(check(None), ExitCode::Clean);
+        assert_eq!(check(Some("path_import")), ExitCode::FixableOnly);
+    }
+
+    #[test]
+    fn test_report_path() {
+        let path = Path::new("./src/lib.rs");
+        assert_eq!(report_path(path, false), "./src/lib.rs");
+        assert_eq!(report_path(path, true), "src/lib.rs");
+        assert_eq!(report_path(Path::new("src\\main.rs"), true), "src/main.rs");
+    }
+
+    #[test]
+    fn test_check_quality_deterministic() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("main.rs"), "fn main() {}\n").unwrap();
+        let text = TextOutput {
+            verbose: true,
+            deterministic: true,
+            ..TextOutput::default()
+        };
+
+        let code = check_quality(
+            temp_dir.path().to_str().unwrap(),
+            None,
+            text,
+            None,
+            Confidence::Low,
+            OutputFormat::Text,
+            None
+        )
+        .unwrap();
+        assert_eq!(code, ExitCode::Clean);
+    }
+
+    #[test]
+    fn test_run_bot_writes_summary() {
+        let temp_dir = TempDir::new().unwrap();
+        let file_path = temp_dir.path().join("test.rs");
+        fs::write(
+            &file_path,
+            "fn main() { let text = std::fs::read_to_string(\"f\"); }\n"
+        )
+        .unwrap();
+        let summary = temp_dir.path().join("summary.json");
+        let path = temp_dir.path().to_str().unwrap();
+
+        let code = run_bot(path, "autofix", "bot/fixes", &summary, false).unwrap();
+        assert_eq!(code, ExitCode::Clean);
+        let json = fs::read_to_string(&summary).unwrap();
+        assert!(
+            json.starts_with("{\"label\":\"autofix\",\"branch\":\"bot/fixes\",\"changed\":true,")
+        );
+        assert!(json.contains("\"analyzers\":[\"path_import\"]"));
+        assert!(
+            fs::read_to_string(&file_path)
+                .unwrap()
+                .contains("use std::fs::read_to_string;")
+        );
+
+        run_bot(path, "autofix", "bot/fixes", &summary, false).unwrap();
+        let json = fs::read_to_string(&summary).unwrap();
+        assert!(json.contains("\"changed\":false"));
     }
 
     #[test]
@@ -781,10 +2059,24 @@ mod tests {
         let file_path = temp_dir.path().join("test.rs");
         fs::write(&file_path, "fn main() {}").unwrap();
 
-        let result = fix_quality(temp_dir.path().to_str().unwrap(), true, None);
+        let result = fix_quality(temp_dir.path().to_str().unwrap(), None, true, None, false);
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_fix_and_diff_unknown_analyzer() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("test.rs"), "fn main() {}").unwrap();
+        let path = temp_dir.path().to_str().unwrap();
+
+        let fix = fix_quality(path, None, false, Some("no_such_rule"), false).unwrap_err();
+        assert_eq!(ExitCode::from_error(&fix), ExitCode::ConfigError);
+
+        let diff =
+            run_diff(path, false, false, Some("no_such_rule"), false, None, false).unwrap_err();
+        assert_eq!(ExitCode::from_error(&diff), ExitCode::ConfigError);
+    }
+
     #[test]
     fn test_format_quality() {
         let temp_dir = TempDir::new().unwrap();
@@ -801,8 +2093,61 @@ mod tests {
         let file_path = temp_dir.path().join("bad.rs");
         fs::write(&file_path, "fn main() { invalid rust syntax +++").unwrap();
 
-        let result = check_quality(temp_dir.path().to_str().unwrap(), false, None, false);
-        assert!(result.is_err());
+        let result = check_quality(
+            temp_dir.path().to_str().unwrap(),
+            None,
+            TextOutput::default(),
+            None,
+            Confidence::Low,
+            OutputFormat::Text,
+            None
+        );
+        assert_eq!(result.unwrap(), ExitCode::ToolError);
+    }
+
+    #[test]
+    fn test_check_quality_skips_generated_and_broken_files() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join("schema.rs"),
+            "// @generated\nfn main() { let x = std::fs::read_to_string(\"f\"); }"
+        )
+        .unwrap();
+        let path = temp_dir.path().to_str().unwrap();
+
+        let code = check_quality(
+            path,
+            None,
+            TextOutput::default(),
+            Some("path_import"),
+            Confidence::Low,
+            OutputFormat::Text,
+            None
+        )
+        .unwrap();
+        assert_eq!(code, ExitCode::Clean);
+
+        fs::write(temp_dir.path().join("bad.rs"), "fn main( {").unwrap();
+        fs::write(
+            temp_dir.path().join("good.rs"),
+            "fn main() { let x = std::fs::read_to_string(\"f\"); }"
+        )
+        .unwrap();
+        let text = TextOutput {
+            show_skipped: true,
+            ..TextOutput::default()
+        };
+        let code = check_quality(
+            path,
+            None,
+            text,
+            Some("path_import"),
+            Confidence::Low,
+            OutputFormat::Text,
+            None
+        )
+        .unwrap();
+        assert_eq!(code, ExitCode::ToolError);
     }
 
     #[test]
@@ -811,8 +2156,8 @@ mod tests {
         let file_path = temp_dir.path().join("bad.rs");
         fs::write(&file_path, "fn main() { invalid rust +++").unwrap();
 
-        let result = fix_quality(temp_dir.path().to_str().unwrap(), false, None);
-        assert!(result.is_err());
+        let result = fix_quality(temp_dir.path().to_str().unwrap(), None, false, None, false);
+        assert_eq!(result.unwrap(), ExitCode::ToolError);
     }
 
     #[test]
@@ -825,21 +2170,54 @@ mod tests {
         )
         .unwrap();
 
-        let result = fix_quality(temp_dir.path().to_str().unwrap(), false, None);
+        let result = fix_quality(temp_dir.path().to_str().unwrap(), None, false, None, false);
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_quality_allow_suppresses_check_and_fix() {
+        let temp_dir = TempDir::new().unwrap();
+        let file_path = temp_dir.path().join("allowed.rs");
+        let source = "#[quality::allow(path_import)]\n\
+                      fn main() { let x = std::fs::read_to_string(\"f\"); }\n";
+        fs::write(&file_path, source).unwrap();
+        let path = temp_dir.path().to_str().unwrap();
+
+        let code = check_quality(
+            path,
+            None,
+            TextOutput::default(),
+            Some("path_import"),
+            Confidence::Low,
+            OutputFormat::Text,
+            None
+        )
+        .unwrap();
+        assert_eq!(code, ExitCode::Clean);
+
+        fix_quality(path, None, false, Some("path_import"), false).unwrap();
+        assert_eq!(fs::read_to_string(&file_path).unwrap(), source);
+    }
+
     #[test]
     fn test_check_quality_no_files() {
         let temp_dir = TempDir::new().unwrap();
-        let result = check_quality(temp_dir.path().to_str().unwrap(), false, None, false);
-        assert!(!result.unwrap(), "no files means no issues");
+        let result = check_quality(
+            temp_dir.path().to_str().unwrap(),
+            None,
+            TextOutput::default(),
+            None,
+            Confidence::Low,
+            OutputFormat::Text,
+            None
+        );
+        assert_eq!(result.unwrap(), ExitCode::Clean, "no files means no issues");
     }
 
     #[test]
     fn test_fix_quality_no_files() {
         let temp_dir = TempDir::new().unwrap();
-        let result = fix_quality(temp_dir.path().to_str().unwrap(), true, None);
+        let result = fix_quality(temp_dir.path().to_str().unwrap(), None, true, None, false);
         assert!(result.is_ok());
     }
 
@@ -867,7 +2245,15 @@ mod tests {
         )
         .unwrap();
 
-        let result = run_diff(temp_dir.path().to_str().unwrap(), false, false, None, false);
+        let result = run_diff(
+            temp_dir.path().to_str().unwrap(),
+            false,
+            false,
+            None,
+            false,
+            None,
+            false
+        );
         assert!(result.is_ok());
     }
 
@@ -881,17 +2267,55 @@ mod tests {
         )
         .unwrap();
 
-        let result = run_diff(temp_dir.path().to_str().unwrap(), true, false, None, false);
+        let result = run_diff(
+            temp_dir.path().to_str().unwrap(),
+            true,
+            false,
+            None,
+            false,
+            None,
+            false
+        );
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_run_diff_summary_records_history() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::write(root.join("Cargo.toml"), "[package]\nname = \"demo\"\n").unwrap();
+        fs::create_dir(root.join("src")).unwrap();
+        fs::write(
+            root.join("src").join("lib.rs"),
+            "fn run() { let x = std::fs::read_to_string(\"f\"); }\n"
+        )
+        .unwrap();
+        let path = root.to_str().unwrap();
+
+        run_diff(path, true, false, None, false, None, false).unwrap();
+        run_diff(path, true, false, Some("path_import"), false, None, false).unwrap();
+        fs::write(root.join("src").join("lib.rs"), "fn run() {}\n").unwrap();
+        run_diff(path, true, false, None, false, None, false).unwrap();
+
+        let history = fs::read_to_string(root.join("target").join("quality-history")).unwrap();
+        assert_eq!(history, "path_import=1\n\n");
+    }
+
     #[test]
     fn test_run_diff_no_changes() {
         let temp_dir = TempDir::new().unwrap();
         let file_path = temp_dir.path().join("test.rs");
         fs::write(&file_path, "fn main() {}").unwrap();
 
-        let result = run_diff(temp_dir.path().to_str().unwrap(), false, false, None, false);
+        let result = run_diff(
+            temp_dir.path().to_str().unwrap(),
+            false,
+            false,
+            None,
+            false,
+            None,
+            false
+        );
         assert!(result.is_ok());
     }
 
@@ -901,7 +2325,15 @@ mod tests {
         let file_path = temp_dir.path().join("test.rs");
         fs::write(&file_path, "fn main() { invalid +++").unwrap();
 
-        let result = run_diff(temp_dir.path().to_str().unwrap(), false, false, None, false);
-        assert!(result.is_err());
+        let result = run_diff(
+            temp_dir.path().to_str().unwrap(),
+            false,
+            false,
+            None,
+            false,
+            None,
+            false
+        );
+        assert_eq!(result.unwrap(), ExitCode::ToolError);
     }
 }
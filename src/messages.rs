@@ -0,0 +1,1200 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Message catalog for localized issue reports.
+//!
+//! Every line an analyzer can report is listed here as an English template
+//! keyed by the rule code (the analyzer name), with `{name}` placeholders for
+//! the parts that vary, such as identifiers or counts. A translation file maps
+//! these templates to other languages:
+//!
+//! ```toml
+//! [function_length]
+//! "Function `{name}` is {lines} lines long (max {max})" = "Funktion `{name}` ist {lines} Zeilen lang (max. {max})"
+//! ```
+//!
+//! Reports keep the rule codes, so tooling that filters or suppresses by rule
+//! is unaffected by the language. Lines without a translation stay English.
+
+use std::{
+    collections::{BTreeSet, HashMap},
+    fs, mem,
+    path::Path
+};
+
+use masterror::AppResult;
+use toml::{Table, Value};
+
+use crate::{
+    analyzer::AnalysisResult,
+    error::{InvalidConfigError, IoError},
+    project::ProjectContext
+};
+
+/// File name of the exported English catalog.
+pub const CATALOG_FILE: &str = "messages.toml";
+
+/// English message templates of each rule, one per reported line.
+///
+/// `{{` and `}}` stand for literal braces.
+const CATALOG: &[(&str, &[&str])] = &[
+    (
+        "bool_result",
+        &[
+            "Function `{name}` returns `bool` but collapses `{call}` with `.{check}()`",
+            "The error is discarded, so callers cannot tell why it failed. Return a `Result` (e.g. \
+         `Result<(), E>`) and let callers decide how to handle the cause"
+        ]
+    ),
+    (
+        "clone_in_loop",
+        &[
+            "`.{method}()` runs on every iteration of the loop starting on line {line}",
+            "Clone once before the loop, or borrow the value if the callee accepts a reference"
+        ]
+    ),
+    (
+        "conversion_impls",
+        &[
+            "`From<{source}> for {target}` has no reverse conversion, and {fields} optional in \
+         `{target}` but required in `{source}`",
+            "Add a fallible conversion back, e.g. `impl TryFrom<{target}> for {source} {{ type Error \
+         = MissingField; fn try_from(value: {target}) -> Result<Self, Self::Error> {{ .. }} }}`",
+            "Method `{method}` converts `{self_ty}` into `{target}` by hand",
+            "Implement `From` instead, e.g. `impl From<{source}> for {target} {{ fn from(value: \
+         {source}) -> Self {{ .. }} }}`, so callers can write `{target}::from(..)` or `.into()`"
+        ]
+    ),
+    (
+        "debug_artifacts",
+        &[
+            "Debug artifact: `{name}!` left in library code",
+            "Remove it, or use `tracing`/`log` for output that should stay"
+        ]
+    ),
+    (
+        "default_impl",
+        &[
+            "`{type}` has `pub fn new()` without arguments but no `Default` implementation",
+            "Implement `Default` by delegating to `new()`"
+        ]
+    ),
+    (
+        "empty_lines",
+        &[
+            "Empty line in function body indicates untamed complexity",
+            "More than {max} consecutive empty lines in function body"
+        ]
+    ),
+    (
+        "error_convention",
+        &[
+            "Public function `{name}` returns `{found}`",
+            "Use the project's standard error type `{expected}` so callers can match on errors"
+        ]
+    ),
+    (
+        "file_length",
+        &[
+            "File has {lines} lines, over the limit of {max}",
+            "Extract cohesive parts into submodules"
+        ]
+    ),
+    (
+        "format_args",
+        &[
+            "Use named format arguments for better readability ({count} placeholders)",
+            "Use inline format arguments instead of positional ones"
+        ]
+    ),
+    (
+        "function_length",
+        &[
+            "Function `{name}` is {lines} lines long (max {max})",
+            "Split it into smaller functions with a single responsibility each"
+        ]
+    ),
+    (
+        "glob_import",
+        &[
+            "Glob import `use {path}::*` hides where names come from",
+            "Enumerate the imported items: {items}"
+        ]
+    ),
+    (
+        "hardcoded_paths",
+        &[
+            "Hardcoded URL `{url}`",
+            "Read the endpoint from configuration, and prefer `https://`",
+            "Hardcoded absolute path `{path}`",
+            "Read it from configuration or build it from a named constant relative to a \
+         configurable base directory"
+        ]
+    ),
+    (
+        "hardcoded_secrets",
+        &[
+            "Hardcoded secret: {kind}",
+            "Load it from the environment or a secret store instead of source code"
+        ]
+    ),
+    (
+        "import_order",
+        &[
+            "Import `{path}` ({group}) comes after {previous} imports",
+            "Order import groups as std, external, then crate (`self`/`super`/`crate`)",
+            "Missing blank line between {previous} and {group} imports before `{path}`",
+            "Separate import groups with an empty line",
+            "Import `{path}` should come before `{previous}`",
+            "Sort imports alphabetically within each group"
+        ]
+    ),
+    (
+        "inline_comments",
+        &[
+            "Inline comment found: \"{comment}\"",
+            "Move to doc block # Notes section:"
+        ]
+    ),
+    (
+        "item_order",
+        &[
+            "`{name}` ({group}) is placed after {previous} at line {line}",
+            "Order items as {order}"
+        ]
+    ),
+    (
+        "license_header",
+        &[
+            "File header has no {tags} tag",
+            "Start the file with SPDX comments declaring its copyright and license"
+        ]
+    ),
+    (
+        "line_length",
+        &[
+            "Line is {width} characters wide, over the limit of {max}",
+            "Break the line or move long literals into constants"
+        ]
+    ),
+    (
+        "mergeable_impls",
+        &[
+            "Impl block for `{type}` repeats the one at line {line}",
+            "Merge the blocks to keep the type's API in one place"
+        ]
+    ),
+    (
+        "missing_debug",
+        &[
+            "Public {kind} `{name}` does not implement `Debug`",
+            "Derive `Debug` so the type can be inspected and embedded in other `Debug` types",
+            "Implement `Debug` manually and print credential fields as \"[REDACTED]\"",
+            "Implement `Debug` manually, or derive it once every field type implements `Debug`"
+        ]
+    ),
+    (
+        "missing_docs",
+        &[
+            "Public {kind} `{name}` has no doc comment",
+            "Add a `///` comment explaining what it is for, or mark it `#[doc(hidden)]`"
+        ]
+    ),
+    (
+        "missing_errors_doc",
+        &[
+            "Public function `{name}` returns `Result` but its docs have no `# Errors` section",
+            "Add `# Errors` describing the conditions under which it fails"
+        ]
+    ),
+    (
+        "missing_inline",
+        &[
+            "Trivial public function `{name}` is missing `#[inline]`",
+            "Mark small library functions `#[inline]` so other crates can inline them"
+        ]
+    ),
+    (
+        "missing_instrument",
+        &[
+            "Public async fn `{name}` has no `#[instrument]` and emits no tracing events",
+            "Add `#[tracing::instrument]` so calls appear in traces"
+        ]
+    ),
+    (
+        "missing_must_use",
+        &[
+            "Method `{name}` only returns a new value but is missing `#[must_use]`",
+            "Mark it `#[must_use]` so a call whose result is dropped is a warning"
+        ]
+    ),
+    (
+        "missing_panics_doc",
+        &[
+            "Public function `{name}` can panic ({cause}) but its docs have no `# Panics` section",
+            "Add `# Panics` describing when it panics, or return an error instead"
+        ]
+    ),
+    (
+        "needless_allocation",
+        &[
+            "Needless allocation: parameter `{name}: {owned}` is only read",
+            "Take `{name}: {borrowed}` instead, e.g. `fn {function}({name}: {borrowed})`",
+            "Needless allocation: `{function}` returns `&{owned}`",
+            "Return `{borrowed}` instead so the container type stays private"
+        ]
+    ),
+    (
+        "nested_closures",
+        &[
+            "Closure nested {levels} levels deep (max {max})",
+            "Extract the inner closures into named functions to flatten the callbacks"
+        ]
+    ),
+    (
+        "nesting_depth",
+        &[
+            "Function `{name}` nests {levels} levels deep (max {max})",
+            "Flatten it with early returns, `?`, `let ... else`, or extract the inner blocks into \
+         functions"
+        ]
+    ),
+    (
+        "numeric_cast",
+        &[
+            "`{source} as {target}` may {loss}",
+            "Use `{target}::try_from(..)` and handle the out-of-range case"
+        ]
+    ),
+    (
+        "assert_message",
+        &[
+            "`{name}!` without a message",
+            "Add a message explaining the invariant, or return an error if the condition can fail at runtime"
+        ]
+    ),
+    (
+        "super_imports",
+        &[
+            "Relative import climbs two or more modules with `super`",
+            "Use a path starting with `crate::` instead"
+        ]
+    ),
+    (
+        "wildcard_reexport",
+        &[
+            "Wildcard re-export `pub use {path}::*` makes the public API implicit",
+            "Re-export the items explicitly: {hint}"
+        ]
+    ),
+    (
+        "doc_width",
+        &[
+            "Doc comment line is {width} characters wide, over the comment width of {max}",
+            "Rewrap the paragraph"
+        ]
+    ),
+    (
+        "placeholder_docs",
+        &[
+            "Doc comment of {kind} `{name}` is empty",
+            "Describe what the {kind} is for, or remove the empty `///` lines",
+            "Doc comment of {kind} `{name}` is only a placeholder",
+            "Write the documentation, or track the missing docs in an issue",
+            "Doc comment of {kind} `{name}` only repeats its name",
+            "Explain what the {kind} does and when to use it, not what it is called"
+        ]
+    ),
+    (
+        "duplicate_literals",
+        &[
+            "String literal \"{literal}\" appears {count} times in the crate, also at {locations}",
+            "Extract a named constant, e.g. `const {constant}: &str = \"{literal}\";`, and use it \
+         everywhere"
+        ]
+    ),
+    (
+        "derive_order",
+        &[
+            "Derives `{current}` are out of order",
+            "List them as `{sorted}`: {order}, then other derives, then serde traits"
+        ]
+    ),
+    (
+        "argument_order",
+        &[
+            "Function `{name}` takes `{first}` before `{second}`, unlike `{other}` at line {line}",
+            "Keep the parameters shared by `{prefix}_*` functions in one order, so arguments \
+             cannot be swapped at call sites"
+        ]
+    ),
+    (
+        "allow_audit",
+        &[
+            "`{attribute}` has no justification",
+            "Add `reason = \"..\"` to the attribute, or a comment on the line above, explaining \
+             why the lint does not apply here"
+        ]
+    ),
+    (
+        "required_debug",
+        &[
+            "{kind} `{name}` does not implement `Debug`",
+            "`*{suffix}` types end up in logs and panic messages; derive `Debug` so they can be \
+             printed",
+            "Implement `Debug` manually and print credential fields as \"[REDACTED]\""
+        ]
+    ),
+    (
+        "naming",
+        &[
+            "Variable `{name}` has a {kind} name",
+            "Name the binding after what it holds, e.g. `config` or `retry_count`"
+        ]
+    ),
+    (
+        "predicate_naming",
+        &[
+            "Public function `{name}` returns `bool` but is not named as a question",
+            "Rename it with a predicate prefix, e.g. `is_{name}` or `has_{name}`, so call sites \
+             read as conditions"
+        ]
+    ),
+    (
+        "discarded_result",
+        &[
+            "`let _ =` discards the `Result` of `{call}`",
+            "Propagate the error with `?`, log it, or call `.ok()` with a comment explaining why \
+             the failure does not matter"
+        ]
+    ),
+    (
+        "global_state",
+        &[
+            "`static mut {name}` can be accessed from several threads without synchronization",
+            "Use an atomic, a `Mutex`/`RwLock` in a `static`, `OnceLock` or `thread_local!` \
+             instead",
+            "`unsafe impl {trait}` asserts thread safety the compiler could not prove",
+            "Wrap the non-thread-safe fields in synchronized types so the trait is derived \
+             automatically, or document why every access is synchronized"
+        ]
+    ),
+    (
+        "api_types",
+        &[
+            "Parameter `{name}` has type `{found}`",
+            "Take `{suggested}` instead: callers can still pass `{found}`, and other borrowed \
+             forms work too",
+            "Take `{suggested}` instead: the vector already keeps its items on the heap, the box \
+             only adds an allocation"
+        ]
+    ),
+    (
+        "error_sources",
+        &[
+            "Function `{name}` discards the source of {count} converted with `?` ({lines})",
+            "Keep the original error: wrap it in a variant such as `map_err(Error::Io)` or store \
+             it as the `source` of the new error",
+            "`From<{source}>` for `{target}` discards the converted error",
+            "Store the error in the target type so callers can reach it through `Error::source`"
+        ]
+    ),
+    (
+        "async_blocking",
+        &[
+            "Blocking call `{call}` inside {context}",
+            "Use the runtime's timer instead, e.g. `tokio::time::sleep(..).await`",
+            "Use the runtime's file system API, e.g. `tokio::fs`, or move the call into \
+             `spawn_blocking`",
+            "Use an async channel such as `tokio::sync::mpsc` and `.recv().await`, or move the \
+             call into `spawn_blocking`"
+        ]
+    ),
+    (
+        "data_integrity",
+        &[
+            "`{cast}` truncates characters above U+00FF",
+            "Use `u8::try_from(..)` or check `is_ascii()` first; to store text as bytes, encode \
+             it with `encode_utf8`",
+            "`{cast}` reads the byte as Latin-1 and corrupts UTF-8 input",
+            "Decode the bytes with `str::from_utf8` or `String::from_utf8_lossy`, or check \
+             `is_ascii()` first",
+            "`{function}` skips UTF-8 validation",
+            "Invalid input is undefined behavior: use `from_utf8` and handle the error, or \
+             `String::from_utf8_lossy`"
+        ]
+    ),
+    (
+        "non_exhaustive_errors",
+        &[
+            "Public error enum `{name}` is missing `#[non_exhaustive]`",
+            "Mark it `#[non_exhaustive]` so new variants are not breaking changes"
+        ]
+    ),
+    (
+        "unbounded_recursion",
+        &[
+            "Function `{name}` calls itself without a depth limit",
+            "Function `{name}` calls itself without a depth limit (and {count} more {calls})",
+            "Deep input overflows the stack: traverse iteratively with an explicit stack, or \
+             pass a `depth` and stop at a maximum"
+        ]
+    ),
+    (
+        "error_display",
+        &[
+            "Error type `{name}` has no `Display` implementation",
+            "Implement `fmt::Display` with a message for users, or derive it with \
+             `thiserror::Error`"
+        ]
+    ),
+    (
+        "redundant_syntax",
+        &[
+            "Redundant `return` in the last statement of `{name}`",
+            "End the function with the expression itself: `{value}`",
+            "Redundant `;` after {kind}",
+            "Block-like expressions end the statement on their own"
+        ]
+    ),
+    (
+        "impl_location",
+        &[
+            "Inherent impl of `{name}` is far from its definition in {path}",
+            "Move the impl next to the type, or turn it into an extension trait"
+        ]
+    ),
+    (
+        "test_assertions",
+        &[
+            "Test `{name}` contains no assertions",
+            "Check the outcome with `assert!`/`assert_eq!`, or unwrap results so failures panic"
+        ]
+    ),
+    (
+        "numeric_overflow",
+        &[
+            "Unchecked integer `{method}` {context} may overflow",
+            "Use `checked_{method}` or `saturating_{method}` to handle overflow explicitly"
+        ]
+    ),
+    ("path_import", &["Use import instead of path: {path}"]),
+    (
+        "print_logging",
+        &[
+            "`{name}!` used for logging in a project that depends on `{krate}`",
+            "Use `{krate}::{level}!` so output respects levels and filters"
+        ]
+    ),
+    (
+        "pub_fields",
+        &[
+            "Struct `{name}` exposes public field {fields}",
+            "Struct `{name}` exposes public fields {fields}",
+            "Make the fields private and add accessor methods, so the representation can change \
+         without breaking callers"
+        ]
+    ),
+    (
+        "redundant_imports",
+        &[
+            "Import `{path}` is duplicated (first imported on line {line})",
+            "Remove the repeated import",
+            "Import `{path}` is never used",
+            "Remove the unused import",
+            "`{name}` is imported from `{module}`, which is also imported and used as \
+         `{parent}::{name}`",
+            "Use one form consistently: drop the item import or the path prefix"
+        ]
+    ),
+    (
+        "sensitive_debug",
+        &[
+            "{kind} `{name}` derives `Debug` but field {fields} may hold a secret",
+            "Implement `Debug` manually and print the value as \"[REDACTED]\", or wrap it in a \
+         redacting type such as `secrecy::SecretString`"
+        ]
+    ),
+    (
+        "shadowing",
+        &[
+            "`{name}` is bound {count} times in `{function}`, on lines {lines}",
+            "Give each value its own name, e.g. `raw_{name}` and `parsed_{name}`, or keep a single \
+         `let mut {name}`"
+        ]
+    ),
+    (
+        "sharing_complexity",
+        &[
+            "Sharing complexity: `{type}` nests {depth} wrapper layers (max {max})",
+            "Wrap the inner state in a dedicated type that owns the lock, e.g. `Arc<State>`",
+            "Sharing complexity: `Rc` used in code that spawns threads",
+            "`Rc` is not `Send`, use `Arc` for state shared across threads"
+        ]
+    ),
+    (
+        "sql_injection",
+        &[
+            "SQL built with {source} passed to `{function}`, possible SQL injection",
+            "Use a parameterized query with bind parameters, e.g. `query(\"... WHERE id = \
+         $1\").bind(id)`"
+        ]
+    ),
+    (
+        "stringly_typed",
+        &[
+            "Function `{name}` takes {count} string parameters ({params}): `{signature}`",
+            "Callers can pass them in the wrong order unnoticed. Wrap distinct concepts in \
+         newtypes, e.g. `struct UserName(String)`"
+        ]
+    ),
+    (
+        "test_modules",
+        &[
+            "Test module `{name}` does not import `use super::*;`",
+            "Start test modules with `use super::*;` to test the enclosing module",
+            "Test module `{name}` is placed before non-test code",
+            "Move test modules to the end of the file"
+        ]
+    ),
+    (
+        "time_source",
+        &[
+            "`{clock}::now()` read inside `fn {function}`",
+            "Pass the time (or a clock) in as a parameter so the logic can be tested \
+         deterministically",
+            "`chrono::Local` used in server code",
+            "Use `chrono::Utc` so timestamps do not depend on the host timezone"
+        ]
+    ),
+    (
+        "todo_comments",
+        &["Resolve the marker or track the work in an issue"]
+    ),
+    (
+        "trait_bounds",
+        &[
+            "Name repeated bound combinations with a helper trait and a blanket impl, or a trait \
+         alias"
+        ]
+    ),
+    (
+        "type_size",
+        &[
+            "Enum `{name}` has {count} variants, over the limit of {max}",
+            "Group related variants into nested enums",
+            "Struct `{name}` has {count} fields, over the limit of {max}",
+            "Group related fields into their own types"
+        ]
+    ),
+    (
+        "unbounded_queue",
+        &[
+            "Unbounded growth: `{receiver}` grows on every iteration of a long-running loop and is \
+         never drained",
+            "Cap its size or hand items to a bounded channel",
+            "Unbounded channel: a slow consumer lets the queue grow without limit",
+            "Use {suggestion} so senders get backpressure"
+        ]
+    ),
+    (
+        "unsafe_docs",
+        &[
+            "Unsafe function `{name}` does not document its safety contract",
+            "Add a `# Safety` doc section listing what callers must guarantee",
+            "Unsafe block has no `// SAFETY:` comment",
+            "Explain above the block why the invariants it relies on hold",
+            "Unsafe impl has no `// SAFETY:` comment",
+            "Explain above the impl why the type upholds the trait's contract"
+        ]
+    ),
+    (
+        "unwrap",
+        &[
+            "`.{method}()` in non-test code panics on error",
+            "Propagate the error with `?` (e.g. into a masterror `AppResult`) or handle it explicitly"
+        ]
+    ),
+    (
+        "variant_names",
+        &[
+            "Variant `{enum}::{variant}` {reason}",
+            "Rename it to `{name}` since it is always used qualified"
+        ]
+    ),
+    (
+        "whitespace",
+        &[
+            "Line has trailing whitespace",
+            "Remove the spaces and tabs at the end of the line",
+            "Line is indented with tabs",
+            "Indent with spaces as rustfmt does",
+            "File does not end with a newline",
+            "Add a line break after the last line"
+        ]
+    )
+];
+
+/// Part of a parsed message template.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    /// Text that appears as written
+    Literal(String),
+    /// Placeholder standing for a varying value
+    Hole(String)
+}
+
+/// Translation of a single English template.
+#[derive(Debug, Clone)]
+struct Translation {
+    english:    Vec<Segment>,
+    translated: Vec<Segment>
+}
+
+/// Translations of issue messages, keyed by rule code.
+///
+/// # Examples
+///
+/// ```
+/// use cargo_quality::messages::MessageCatalog;
+///
+/// let catalog = MessageCatalog::from_toml_str(
+///     r#"
+///     [file_length]
+///     "File has {lines} lines, over the limit of {max}" = "Datei hat {lines} Zeilen (max. {max})"
+///     "#
+/// )
+/// .unwrap();
+///
+/// assert_eq!(
+///     catalog.translate(
+///         "file_length",
+///         "File has 900 lines, over the limit of 500\nExtract cohesive parts into submodules"
+///     ),
+///     "Datei hat 900 Zeilen (max. 500)\nExtract cohesive parts into submodules"
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MessageCatalog {
+    translations: HashMap<String, Vec<Translation>>
+}
+
+impl MessageCatalog {
+    /// Loads translations from a TOML file.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Translation file
+    ///
+    /// # Returns
+    ///
+    /// `AppResult<MessageCatalog>` - Catalog with the file's translations
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or is not a valid
+    /// translation file (see [`MessageCatalog::from_toml_str`]).
+    pub fn load(path: &Path) -> AppResult<Self> {
+        let content = fs::read_to_string(path).map_err(IoError::from)?;
+        Self::from_toml_str(&content)
+    }
+
+    /// Loads the translations configured for a project.
+    ///
+    /// # Arguments
+    ///
+    /// * `project` - Project context with the optional `messages` file
+    ///
+    /// # Returns
+    ///
+    /// `AppResult<MessageCatalog>` - Configured catalog, or an empty one when
+    /// no translation file is set
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configured file cannot be loaded.
+    pub fn for_project(project: &ProjectContext) -> AppResult<Self> {
+        project
+            .messages()
+            .map_or_else(|| Ok(Self::default()), Self::load)
+    }
+
+    /// Parses translations from TOML text.
+    ///
+    /// Each table is named after a rule code and maps English templates of
+    /// that rule to their translations.
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - Translation file contents
+    ///
+    /// # Returns
+    ///
+    /// `AppResult<MessageCatalog>` - Parsed catalog
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the text is not valid TOML, names an unknown rule
+    /// or template, or a translation uses a placeholder its template lacks.
+    pub fn from_toml_str(content: &str) -> AppResult<Self> {
+        let table: Table = content
+            .parse()
+            .map_err(|e| InvalidConfigError::new(format!("message catalog: {}", e)))?;
+
+        let mut translations = HashMap::new();
+        for (rule, entries) in &table {
+            let Some(known) = templates(rule) else {
+                return Err(config_error(format!("unknown rule `{rule}`")));
+            };
+            let Value::Table(entries) = entries else {
+                return Err(config_error(format!(
+                    "`{rule}` must be a table of message translations"
+                )));
+            };
+
+            let mut parsed = Vec::with_capacity(entries.len());
+            for (english, translated) in entries {
+                if !known.contains(&english.as_str()) {
+                    return Err(config_error(format!(
+                        "`{rule}` has no message \"{english}\""
+                    )));
+                }
+                let Value::String(translated) = translated else {
+                    return Err(config_error(format!(
+                        "translation of \"{english}\" in `{rule}` must be a string"
+                    )));
+                };
+                parsed.push(parse_translation(rule, english, translated)?);
+            }
+            translations.insert(rule.clone(), parsed);
+        }
+
+        Ok(Self {
+            translations
+        })
+    }
+
+    /// Translates an issue message line by line.
+    ///
+    /// # Arguments
+    ///
+    /// * `rule` - Code of the rule that reported the message
+    /// * `message` - English message
+    ///
+    /// # Returns
+    ///
+    /// The message with every line that matches a translated template
+    /// replaced by its translation
+    pub fn translate(&self, rule: &str, message: &str) -> String {
+        let Some(translations) = self.translations.get(rule) else {
+            return message.to_string();
+        };
+
+        message
+            .split('\n')
+            .map(|line| {
+                translations
+                    .iter()
+                    .find_map(|translation| {
+                        let values = match_segments(&translation.english, line, Vec::new())?;
+                        Some(render(&translation.translated, &values))
+                    })
+                    .unwrap_or_else(|| line.to_string())
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Translates the messages of an analysis result.
+    ///
+    /// # Arguments
+    ///
+    /// * `rule` - Name of the analyzer that produced the result
+    /// * `result` - Analysis result with English messages
+    ///
+    /// # Returns
+    ///
+    /// The result with translated messages
+    pub fn localize(&self, rule: &str, mut result: AnalysisResult) -> AnalysisResult {
+        if self.translations.contains_key(rule) {
+            for issue in &mut result.issues {
+                issue.message = self.translate(rule, &issue.message);
+            }
+        }
+        result
+    }
+}
+
+/// English message templates of a rule.
+///
+/// # Arguments
+///
+/// * `rule` - Rule code
+///
+/// # Returns
+///
+/// `Some` templates, or `None` for an unknown rule
+pub fn templates(rule: &str) -> Option<&'static [&'static str]> {
+    CATALOG
+        .iter()
+        .find(|(name, _)| *name == rule)
+        .map(|(_, templates)| *templates)
+}
+
+/// Renders the English catalog as a translation file.
+///
+/// Every template maps to itself, so translators can copy the file and
+/// replace the values.
+///
+/// # Returns
+///
+/// TOML text with one table per rule
+pub fn english_catalog() -> String {
+    let mut table = Table::new();
+    for (rule, templates) in CATALOG {
+        let entries = templates
+            .iter()
+            .map(|template| (template.to_string(), Value::String(template.to_string())))
+            .collect();
+        table.insert(rule.to_string(), Value::Table(entries));
+    }
+    table.to_string()
+}
+
+/// Writes the English catalog into a directory as [`CATALOG_FILE`].
+///
+/// # Arguments
+///
+/// * `dir` - Output directory, created if missing
+///
+/// # Returns
+///
+/// `AppResult<usize>` - Number of templates written, or IO error
+pub fn export_catalog(dir: &Path) -> AppResult<usize> {
+    fs::create_dir_all(dir).map_err(IoError::from)?;
+    fs::write(dir.join(CATALOG_FILE), english_catalog()).map_err(IoError::from)?;
+    Ok(CATALOG.iter().map(|(_, templates)| templates.len()).sum())
+}
+
+/// Parse and cross-check a template and its translation.
+fn parse_translation(rule: &str, english: &str, translated: &str) -> AppResult<Translation> {
+    let english = parse_template(english)
+        .ok_or_else(|| config_error(format!("malformed message \"{english}\" in `{rule}`")))?;
+    let translated = parse_template(translated).ok_or_else(|| {
+        config_error(format!(
+            "malformed translation \"{translated}\" in `{rule}`"
+        ))
+    })?;
+
+    let available: BTreeSet<&str> = holes(&english).collect();
+    if let Some(unknown) = holes(&translated).find(|hole| !available.contains(hole)) {
+        return Err(config_error(format!(
+            "translation \"{}\" in `{rule}` uses unknown placeholder `{{{unknown}}}`",
+            render(&translated, &[])
+        )));
+    }
+
+    Ok(Translation {
+        english,
+        translated
+    })
+}
+
+/// Split a template into literal text and placeholders.
+///
+/// # Returns
+///
+/// `None` for unbalanced braces or adjacent placeholders
+fn parse_template(template: &str) -> Option<Vec<Segment>> {
+    let mut segments = Vec::new();
+    let mut literal = String::new();
+    let mut chars = template.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                literal.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                literal.push('}');
+            }
+            '{' => {
+                let mut name = String::new();
+                loop {
+                    match chars.next()? {
+                        '}' => break,
+                        c => name.push(c)
+                    }
+                }
+                let adjacent = matches!(segments.last(), Some(Segment::Hole(_)));
+                if name.is_empty() || (literal.is_empty() && adjacent) {
+                    return None;
+                }
+                if !literal.is_empty() {
+                    segments.push(Segment::Literal(mem::take(&mut literal)));
+                }
+                segments.push(Segment::Hole(name));
+            }
+            '}' => return None,
+            c => literal.push(c)
+        }
+    }
+    if !literal.is_empty() {
+        segments.push(Segment::Literal(literal));
+    }
+
+    Some(segments)
+}
+
+/// Placeholder names of a parsed template.
+fn holes(segments: &[Segment]) -> impl Iterator<Item = &str> {
+    segments.iter().filter_map(|segment| match segment {
+        Segment::Hole(name) => Some(name.as_str()),
+        Segment::Literal(_) => None
+    })
+}
+
+/// Match a line against a template, capturing placeholder values.
+///
+/// A placeholder used twice must capture the same text both times.
+fn match_segments<'a>(
+    segments: &'a [Segment],
+    text: &str,
+    values: Vec<(&'a str, String)>
+) -> Option<Vec<(&'a str, String)>> {
+    let Some((first, rest)) = segments.split_first() else {
+        return text.is_empty().then_some(values);
+    };
+
+    match first {
+        Segment::Literal(literal) => match_segments(rest, text.strip_prefix(literal)?, values),
+        Segment::Hole(name) => {
+            let candidates: Vec<usize> = match rest.first() {
+                Some(Segment::Literal(next)) => {
+                    text.match_indices(next.as_str()).map(|(i, _)| i).collect()
+                }
+                _ => vec![text.len()]
+            };
+
+            candidates
+                .into_iter()
+                .filter(|end| *end > 0)
+                .find_map(|end| {
+                    let value = &text[..end];
+                    let known = values.iter().find(|(hole, _)| hole == name);
+                    if known.is_some_and(|(_, known)| known != value) {
+                        return None;
+                    }
+
+                    let mut values = values.clone();
+                    if known.is_none() {
+                        values.push((name.as_str(), value.to_string()));
+                    }
+                    match_segments(rest, &text[end..], values)
+                })
+        }
+    }
+}
+
+/// Fill a template's placeholders with captured values.
+fn render(segments: &[Segment], values: &[(&str, String)]) -> String {
+    segments
+        .iter()
+        .map(|segment| match segment {
+            Segment::Literal(text) => text.clone(),
+            Segment::Hole(name) => values
+                .iter()
+                .find(|(hole, _)| hole == name)
+                .map_or_else(|| format!("{{{name}}}"), |(_, value)| value.clone())
+        })
+        .collect()
+}
+
+/// Build an invalid message catalog error.
+fn config_error(message: String) -> masterror::AppError {
+    InvalidConfigError::new(format!("message catalog: {message}")).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::{analyzer::SourceFile, analyzers::get_analyzers, file_utils::collect_rust_files};
+
+    /// Lines that only repeat source text, by rule and line index.
+    const UNTEMPLATED: &[(&str, usize)] = &[
+        ("todo_comments", 0),
+        ("trait_bounds", 0),
+        ("inline_comments", 2)
+    ];
+
+    const GERMAN: &str = r#"
+        [function_length]
+        "Function `{name}` is {lines} lines long (max {max})" = "Funktion `{name}` ist {lines} Zeilen lang (max. {max})"
+        "Split it into smaller functions with a single responsibility each" = "Teile sie in kleinere Funktionen auf"
+
+        [shadowing]
+        "Give each value its own name, e.g. `raw_{name}` and `parsed_{name}`, or keep a single `let mut {name}`" = "Benenne `{name}` je Wert eindeutig"
+    "#;
+
+    #[test]
+    fn test_catalog_covers_every_rule() {
+        for analyzer in get_analyzers() {
+            let templates = templates(analyzer.name())
+                .unwrap_or_else(|| panic!("`{}` missing from catalog", analyzer.name()));
+            for template in templates {
+                assert!(parse_template(template).is_some(), "malformed: {template}");
+            }
+        }
+        assert_eq!(CATALOG.len(), get_analyzers().len());
+    }
+
+    #[test]
+    fn test_catalog_matches_reported_messages() {
+        let root = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("src");
+        let mut checked = 0;
+        let mut check = |rule: &str, result: AnalysisResult| {
+            let templates: Vec<_> = templates(rule)
+                .unwrap()
+                .iter()
+                .map(|template| parse_template(template).unwrap())
+                .collect();
+            for issue in result.issues {
+                for (index, line) in issue.message.lines().enumerate() {
+                    if UNTEMPLATED.contains(&(rule, index)) {
+                        continue;
+                    }
+                    assert!(
+                        templates
+                            .iter()
+                            .any(|t| match_segments(t, line, Vec::new()).is_some()),
+                        "`{rule}` reported a line missing from the catalog: {line}"
+                    );
+                    checked += 1;
+                }
+            }
+        };
+
+        let mut sources = Vec::new();
+        for file in collect_rust_files(root.to_str().unwrap()).unwrap() {
+            let content = fs::read_to_string(&file).unwrap();
+            let ast = syn::parse_file(&content).unwrap();
+            for analyzer in get_analyzers() {
+                check(analyzer.name(), analyzer.analyze(&ast, &content).unwrap());
+            }
+            sources.push(SourceFile {
+                path: file,
+                ast
+            });
+        }
+
+        let files: Vec<&SourceFile> = sources.iter().collect();
+        for analyzer in get_analyzers().iter().filter(|a| a.crate_wide()) {
+            for (_, result) in analyzer.analyze_crate(&files).unwrap() {
+                check(analyzer.name(), result);
+            }
+        }
+
+        assert!(checked > 0);
+    }
+
+    #[test]
+    fn test_translate_message() {
+        let catalog = MessageCatalog::from_toml_str(GERMAN).unwrap();
+
+        assert_eq!(
+            catalog.translate(
+                "function_length",
+                "Function `run` is 80 lines long (max 60)\nSplit it into smaller functions with \
+                 a single responsibility each"
+            ),
+            "Funktion `run` ist 80 Zeilen lang (max. 60)\nTeile sie in kleinere Funktionen auf"
+        );
+        assert_eq!(
+            catalog.translate(
+                "shadowing",
+                "`id` is bound 2 times in `load`, on lines 3, 4\nGive each value its own name, \
+                 e.g. `raw_id` and `parsed_id`, or keep a single `let mut id`"
+            ),
+            "`id` is bound 2 times in `load`, on lines 3, 4\nBenenne `id` je Wert eindeutig"
+        );
+        assert_eq!(
+            catalog.translate("unwrap", "`.unwrap()` in non-test code panics on error"),
+            "`.unwrap()` in non-test code panics on error"
+        );
+    }
+
+    #[test]
+    fn test_localize_result() {
+        let catalog = MessageCatalog::from_toml_str(GERMAN).unwrap();
+        let result = AnalysisResult {
+            issues:        vec![crate::analyzer::Issue {
+                line:    1,
+                column:  0,
+                message: "Function `f` is 61 lines long (max 60)".to_string(),
+                fix:     crate::analyzer::Fix::None
+            }],
+            fixable_count: 0
+        };
+
+        let localized = catalog.localize("function_length", result);
+        assert_eq!(
+            localized.issues[0].message,
+            "Funktion `f` ist 61 Zeilen lang (max. 60)"
+        );
+    }
+
+    #[test]
+    fn test_invalid_translations_are_errors() {
+        for content in [
+            "[unknown_rule]\n\"x\" = \"y\"",
+            "unwrap = \"not a table\"",
+            "[unwrap]\n\"Not a template\" = \"y\"",
+            "[unwrap]\n\"`.{method}()` in non-test code panics on error\" = 1",
+            "[unwrap]\n\"`.{method}()` in non-test code panics on error\" = \"{other}\"",
+            "[unwrap]\n\"`.{method}()` in non-test code panics on error\" = \"{method\"",
+            "[unwrap"
+        ] {
+            assert!(
+                MessageCatalog::from_toml_str(content).is_err(),
+                "accepted: {content}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_template() {
+        assert_eq!(
+            parse_template("a {x} {{b}}"),
+            Some(vec![
+                Segment::Literal("a ".to_string()),
+                Segment::Hole("x".to_string()),
+                Segment::Literal(" {b}".to_string())
+            ])
+        );
+        assert_eq!(parse_template("{a}{b}"), None);
+        assert_eq!(parse_template("a }"), None);
+    }
+
+    #[test]
+    fn test_english_catalog_round_trips() {
+        let temp_dir = TempDir::new().unwrap();
+        let written = export_catalog(temp_dir.path()).unwrap();
+
+        let content = fs::read_to_string(temp_dir.path().join(CATALOG_FILE)).unwrap();
+        let catalog = MessageCatalog::from_toml_str(&content).unwrap();
+        assert_eq!(
+            catalog.translations.values().map(Vec::len).sum::<usize>(),
+            written
+        );
+        assert_eq!(
+            catalog.translate("numeric_cast", "`u64 as u8` may truncate the value"),
+            "`u64 as u8` may truncate the value"
+        );
+    }
+}
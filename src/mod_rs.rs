@@ -16,22 +16,25 @@
 //!
 //! The `mod.rs` file content is moved to a file named after the parent
 //! directory, placed one level up in the directory hierarchy.
+//!
+//! [`ModRsAnalyzer`] runs the same detection as a
+//! [`FileAnalyzer`] in `check`, `fix` and `diff`.
 
-use std::{
-    fs::{read_dir, remove_dir as remove_directory, rename},
-    io,
-    path::{Path, PathBuf}
-};
+use std::path::{Path, PathBuf};
 
 use ignore::WalkBuilder;
 use masterror::AppResult;
 
-use crate::error::IoError;
+use crate::file_analyzer::{FileAnalyzer, FileIssue, rename_file};
 
 /// Result of mod.rs detection.
 ///
 /// Contains information about a found `mod.rs` file and the suggested fix.
 #[derive(Debug, Clone)]
+#[allow(
+    dead_code,
+    reason = "library API, the binary reports positions through ModRsAnalyzer"
+)]
 pub struct ModRsIssue {
     /// Path to the mod.rs file
     pub path:      PathBuf,
@@ -76,6 +79,54 @@ impl ModRsResult {
     }
 }
 
+/// File analyzer for `mod.rs` files.
+///
+/// # Examples
+///
+/// ```
+/// use std::path::{Path, PathBuf};
+///
+/// use cargo_quality::{file_analyzer::FileAnalyzer, mod_rs::ModRsAnalyzer};
+///
+/// let analyzer = ModRsAnalyzer::new();
+/// let issue = analyzer.analyze_path(Path::new("src/net/mod.rs")).unwrap();
+///
+/// assert_eq!(issue.rename, Some(PathBuf::from("src/net.rs")));
+/// assert!(analyzer.analyze_path(Path::new("src/net.rs")).is_none());
+/// ```
+pub struct ModRsAnalyzer;
+
+impl ModRsAnalyzer {
+    /// Create new mod.rs analyzer instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl FileAnalyzer for ModRsAnalyzer {
+    fn name(&self) -> &'static str {
+        "mod_rs"
+    }
+
+    fn analyze_path(&self, path: &Path) -> Option<FileIssue> {
+        if !is_mod_rs(path) {
+            return None;
+        }
+
+        create_issue(path).map(|issue| FileIssue {
+            message: issue.message,
+            rename:  Some(issue.suggested)
+        })
+    }
+}
+
+impl Default for ModRsAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Finds all `mod.rs` files in the given path.
 ///
 /// Recursively searches for files named `mod.rs` that should be converted
@@ -197,6 +248,9 @@ fn create_issue(path: &Path) -> Option<ModRsIssue> {
 /// 2. Writing it to the new location (parent_name.rs)
 /// 3. Removing the original mod.rs file
 /// 4. Removing the empty parent directory if it becomes empty
+/// 5. Removing `#[path]` attributes of the crate that load the old location and
+///    pointing doc comments at the new one; the move is refused when an
+///    attribute is not for the module's default location
 ///
 /// # Arguments
 ///
@@ -217,23 +271,7 @@ fn create_issue(path: &Path) -> Option<ModRsIssue> {
 /// }
 /// ```
 pub fn fix_mod_rs(issue: &ModRsIssue) -> AppResult<()> {
-    if issue.suggested.exists() {
-        return Err(IoError::from(io::Error::new(
-            io::ErrorKind::AlreadyExists,
-            format!(
-                "target already exists, refusing to overwrite: {}",
-                issue.suggested.display()
-            )
-        ))
-        .into());
-    }
-
-    rename(&issue.path, &issue.suggested).map_err(IoError::from)?;
-    if let Some(parent) = issue.path.parent()
-        && is_directory_empty(parent)?
-    {
-        remove_directory(parent).map_err(IoError::from)?;
-    }
+    rename_file(&issue.path, &issue.suggested)?;
     Ok(())
 }
 
@@ -276,20 +314,6 @@ pub fn fix_all_mod_rs(path: &str) -> AppResult<usize> {
     Ok(applied)
 }
 
-/// Checks if a directory is empty.
-///
-/// # Arguments
-///
-/// * `dir` - Directory path to check
-///
-/// # Returns
-///
-/// `AppResult<bool>` - true if directory has no entries
-fn is_directory_empty(dir: &Path) -> AppResult<bool> {
-    let mut entries = read_dir(dir).map_err(IoError::from)?;
-    Ok(entries.next().is_none())
-}
-
 #[cfg(test)]
 mod tests {
     use std::fs::{create_dir, read_to_string, write};
@@ -526,6 +550,20 @@ mod tests {
         assert!(result.is_empty());
     }
 
+    #[test]
+    fn test_analyzer_matches_scan() {
+        let analyzer = ModRsAnalyzer::new();
+        assert_eq!(analyzer.name(), "mod_rs");
+
+        let issue = analyzer
+            .analyze_path(Path::new("src/analyzers/mod.rs"))
+            .unwrap();
+        assert!(issue.message.contains("`analyzers.rs`"));
+        assert_eq!(issue.rename, Some(PathBuf::from("src/analyzers.rs")));
+        assert!(analyzer.analyze_path(Path::new("mod.rs")).is_none());
+        assert!(analyzer.analyze_path(Path::new("src/lib.rs")).is_none());
+    }
+
     #[test]
     fn test_result_default() {
         let result = ModRsResult::default();
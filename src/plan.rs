@@ -0,0 +1,368 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Execution plan of a `check` run.
+//!
+//! Which analyzers run on which files depends on `--analyzer`,
+//! `--min-confidence`, the `enable` list and profile of
+//! `[package.metadata.quality]`, and the files each analyzer applies to. `cargo
+//! qual check --plan` prints the resolved plan without analyzing anything, so
+//! configuration layering can be debugged without reading through a full
+//! report.
+
+use std::{fmt, path::PathBuf};
+
+use crate::{
+    analyzer::{Analyzer, Confidence, Severity},
+    file_analyzer::FileAnalyzer,
+    project::ProjectContext,
+    skipped::SkippedFiles
+};
+
+/// Why an analyzer does not run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SkipReason {
+    /// Another analyzer was selected with `--analyzer`
+    NotSelected,
+    /// The analyzer is opt-in and missing from the `enable` list
+    OptIn,
+    /// The analyzer applies to none of the files, e.g. library-only rules
+    /// on binaries
+    NoMatchingFiles,
+    /// The analyzer's confidence is below `--min-confidence`
+    LowConfidence
+}
+
+impl fmt::Display for SkipReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::NotSelected => "not selected by --analyzer",
+            Self::OptIn => "opt-in, not listed in metadata.quality.enable",
+            Self::NoMatchingFiles => "applies to none of the files",
+            Self::LowConfidence => "confidence below --min-confidence"
+        })
+    }
+}
+
+/// Planned run of one analyzer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlannedAnalyzer {
+    /// Analyzer name
+    pub name:       &'static str,
+    /// Severity of the reported issues
+    pub severity:   Severity,
+    /// Confidence that the reported issues are real problems
+    pub confidence: Confidence,
+    /// Number of files the analyzer applies to
+    pub files:      usize,
+    /// Why the analyzer is skipped, `None` if it runs
+    pub skipped:    Option<SkipReason>
+}
+
+/// Files and analyzers of a `check` run, resolved from the command line and
+/// the project configuration.
+///
+/// # Examples
+///
+/// ```
+/// use std::path::PathBuf;
+///
+/// use cargo_quality::{
+///     analyzers::get_analyzers_for,
+///     file_analyzer::get_file_analyzers,
+///     plan::{Plan, SkipReason},
+///     project::ProjectContext
+/// };
+///
+/// let project = ProjectContext::default();
+/// let analyzers = get_analyzers_for(&project);
+/// let plan = Plan::new(
+///     &project,
+///     &analyzers,
+///     &get_file_analyzers(),
+///     vec![PathBuf::from("src/lib.rs")],
+///     Some("unwrap")
+/// );
+///
+/// assert!(plan.is_enabled("unwrap"));
+/// let path_import = plan.analyzers.iter().find(|a| a.name == "path_import");
+/// assert_eq!(path_import.unwrap().skipped, Some(SkipReason::NotSelected));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Plan {
+    /// Files to analyze
+    pub files:     Vec<PathBuf>,
+    /// Every analyzer, in the order they run, file analyzers last
+    pub analyzers: Vec<PlannedAnalyzer>,
+    /// Whether the `strict` profile is active
+    pub strict:    bool,
+    /// Paths the directory walk left out
+    pub skipped:   SkippedFiles
+}
+
+impl Plan {
+    /// Resolves which analyzers run on which files.
+    ///
+    /// # Arguments
+    ///
+    /// * `project` - Project configuration
+    /// * `analyzers` - Analyzers configured for the project
+    /// * `file_analyzers` - File-level analyzers, which apply to every file
+    /// * `files` - Files to analyze
+    /// * `selected` - Analyzer chosen with `--analyzer`, if any
+    ///
+    /// # Returns
+    ///
+    /// Plan listing every analyzer, with the reason it is skipped if it does
+    /// not run
+    pub fn new(
+        project: &ProjectContext,
+        analyzers: &[Box<dyn Analyzer>],
+        file_analyzers: &[Box<dyn FileAnalyzer>],
+        files: Vec<PathBuf>,
+        selected: Option<&str>
+    ) -> Self {
+        let mut analyzers: Vec<_> = analyzers
+            .iter()
+            .map(|analyzer| {
+                let matching = files
+                    .iter()
+                    .filter(|file| analyzer.applies_to(file))
+                    .count();
+                let skipped = match selected {
+                    Some(name) if name != analyzer.name() => Some(SkipReason::NotSelected),
+                    None if !project.is_active(analyzer.as_ref()) => Some(SkipReason::OptIn),
+                    _ if matching == 0 && !files.is_empty() => Some(SkipReason::NoMatchingFiles),
+                    _ => None
+                };
+
+                PlannedAnalyzer {
+                    name: analyzer.name(),
+                    severity: analyzer.severity(),
+                    confidence: analyzer.confidence(),
+                    files: matching,
+                    skipped
+                }
+            })
+            .collect();
+        analyzers.extend(file_analyzers.iter().map(|analyzer| {
+            PlannedAnalyzer {
+                name:       analyzer.name(),
+                severity:   analyzer.severity(),
+                confidence: Confidence::High,
+                files:      files.len(),
+                skipped:    selected
+                    .filter(|name| *name != analyzer.name())
+                    .map(|_| SkipReason::NotSelected)
+            }
+        }));
+
+        Self {
+            files,
+            analyzers,
+            strict: project.is_strict(),
+            skipped: SkippedFiles::new()
+        }
+    }
+
+    /// Skips the running analyzers whose confidence is below a minimum.
+    ///
+    /// Called for `--min-confidence`, so strict CI gates only run precise
+    /// rules. Analyzers that are already skipped keep their reason.
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - Lowest confidence of the analyzers that keep running
+    pub fn require_confidence(&mut self, min: Confidence) {
+        for analyzer in &mut self.analyzers {
+            if analyzer.skipped.is_none() && analyzer.confidence < min {
+                analyzer.skipped = Some(SkipReason::LowConfidence);
+            }
+        }
+    }
+
+    /// Checks whether an analyzer runs.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Analyzer name
+    ///
+    /// # Returns
+    ///
+    /// `true` if the analyzer is planned and not skipped
+    pub fn is_enabled(&self, name: &str) -> bool {
+        self.analyzers
+            .iter()
+            .any(|analyzer| analyzer.name == name && analyzer.skipped.is_none())
+    }
+}
+
+impl fmt::Display for Plan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (enabled, skipped): (Vec<_>, Vec<_>) = self
+            .analyzers
+            .iter()
+            .partition(|analyzer| analyzer.skipped.is_none());
+        let width = self
+            .analyzers
+            .iter()
+            .map(|analyzer| analyzer.name.len())
+            .max()
+            .unwrap_or(0);
+
+        writeln!(f, "Files ({}):", self.files.len())?;
+        for file in &self.files {
+            writeln!(f, "  {}", file.display())?;
+        }
+
+        if !self.skipped.is_empty() {
+            writeln!(f, "\nSkipped paths ({}):", self.skipped.len())?;
+            for file in &self.skipped.files {
+                writeln!(f, "  {}  {}", file.path.display(), file.cause)?;
+            }
+        }
+
+        let profile = if self.strict { "strict" } else { "default" };
+        writeln!(f, "\nProfile: {profile}")?;
+
+        writeln!(f, "\nEnabled analyzers ({}):", enabled.len())?;
+        for analyzer in &enabled {
+            let plural = if analyzer.files == 1 { "" } else { "s" };
+            writeln!(
+                f,
+                "  {:width$}  {:7}  {} file{plural}",
+                analyzer.name, analyzer.severity, analyzer.files
+            )?;
+        }
+
+        writeln!(f, "\nSkipped analyzers ({}):", skipped.len())?;
+        for analyzer in &skipped {
+            if let Some(reason) = analyzer.skipped {
+                writeln!(f, "  {:width$}  {reason}", analyzer.name)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        analyzers::get_analyzers_for, file_analyzer::get_file_analyzers, skipped::SkipCause
+    };
+
+    fn plan(manifest: &str, files: &[&str], selected: Option<&str>) -> Plan {
+        let project = ProjectContext::from_manifest_str(manifest).unwrap();
+        let analyzers = get_analyzers_for(&project);
+        let files = files.iter().map(PathBuf::from).collect();
+        Plan::new(&project, &analyzers, &get_file_analyzers(), files, selected)
+    }
+
+    fn skipped(plan: &Plan, name: &str) -> Option<SkipReason> {
+        plan.analyzers
+            .iter()
+            .find(|analyzer| analyzer.name == name)
+            .unwrap()
+            .skipped
+    }
+
+    #[test]
+    fn test_default_plan() {
+        let plan = plan("", &["src/lib.rs", "src/main.rs"], None);
+
+        assert!(plan.is_enabled("unwrap"));
+        assert_eq!(
+            skipped(&plan, "missing_instrument"),
+            Some(SkipReason::OptIn)
+        );
+        let debug = plan
+            .analyzers
+            .iter()
+            .find(|analyzer| analyzer.name == "debug_artifacts")
+            .unwrap();
+        assert_eq!(debug.files, 1);
+        assert!(!plan.strict);
+        assert!(plan.is_enabled("mod_rs"));
+    }
+
+    #[test]
+    fn test_enabled_and_selected() {
+        let manifest = "[package.metadata.quality]\n\
+                        enable = [\"missing_instrument\"]\n\
+                        profile = \"strict\"";
+        let plan_all = plan(manifest, &["src/lib.rs"], None);
+        assert!(plan_all.is_enabled("missing_instrument"));
+        assert!(plan_all.strict);
+
+        let selected = plan(manifest, &["src/lib.rs"], Some("todo_comments"));
+        assert!(selected.is_enabled("todo_comments"));
+        assert_eq!(skipped(&selected, "unwrap"), Some(SkipReason::NotSelected));
+        assert_eq!(skipped(&selected, "mod_rs"), Some(SkipReason::NotSelected));
+
+        let mod_rs = plan(manifest, &["src/lib.rs"], Some("mod_rs"));
+        assert!(mod_rs.is_enabled("mod_rs"));
+        assert!(!mod_rs.is_enabled("unwrap"));
+    }
+
+    #[test]
+    fn test_no_matching_files() {
+        let plan = plan("", &["src/main.rs"], None);
+        assert_eq!(
+            skipped(&plan, "debug_artifacts"),
+            Some(SkipReason::NoMatchingFiles)
+        );
+    }
+
+    #[test]
+    fn test_require_confidence() {
+        let mut plan = plan("", &["src/lib.rs"], None);
+        plan.require_confidence(Confidence::Medium);
+        assert!(plan.is_enabled("unwrap"));
+        assert!(plan.is_enabled("path_import"));
+        assert_eq!(
+            skipped(&plan, "duplicate_literals"),
+            Some(SkipReason::LowConfidence)
+        );
+        assert_eq!(
+            skipped(&plan, "missing_instrument"),
+            Some(SkipReason::OptIn)
+        );
+
+        plan.require_confidence(Confidence::High);
+        assert_eq!(
+            skipped(&plan, "path_import"),
+            Some(SkipReason::LowConfidence)
+        );
+        assert!(plan.is_enabled("mod_rs"));
+        assert!(
+            plan.to_string()
+                .contains("confidence below --min-confidence\n")
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let output = plan("", &["src/lib.rs"], None).to_string();
+
+        assert!(output.starts_with("Files (1):\n  src/lib.rs\n\nProfile: default\n"));
+        assert!(output.contains("\n  unwrap "));
+        assert!(output.contains("  warning  1 file\n"));
+        assert!(output.contains("\n  mod_rs "));
+        assert!(output.contains("opt-in, not listed in metadata.quality.enable\n"));
+        assert!(!output.contains("Skipped paths"));
+    }
+
+    #[test]
+    fn test_display_skipped_paths() {
+        let mut plan = plan("", &["src/lib.rs"], None);
+        plan.skipped
+            .add(PathBuf::from("target"), SkipCause::Ignored, None);
+
+        let output = plan.to_string();
+        assert!(output.contains(
+            "  src/lib.rs\n\nSkipped paths (1):\n  target  ignored by .gitignore\n\nProfile"
+        ));
+    }
+}
@@ -0,0 +1,1558 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Project context discovered from the nearest `Cargo.toml`.
+//!
+//! Some analyzers only make sense for projects with particular dependencies,
+//! e.g. flagging `println!` logging is only useful when `tracing` or `log` is
+//! available. This module locates the manifest that owns the analyzed path
+//! and exposes what the analyzers need from it.
+//!
+//! Per-project settings live under `[package.metadata.quality]` (or
+//! `[workspace.metadata.quality]` in a virtual manifest):
+//!
+//! ```toml
+//! [package.metadata.quality]
+//! enable = ["missing_instrument"]
+//! collapse = ["empty_lines"]
+//! error_type = "masterror::AppError"
+//! sensitive_fields = ["pin", "ssn"]
+//! profile = "strict"
+//! license_header = """
+//! // SPDX-FileCopyrightText: 2025 Jane Doe <jane@example.com>
+//! // SPDX-License-Identifier: MIT
+//! """
+//! item_order = ["const", "type", "impl", "fn", "test"]
+//! pub_fields_allow = ["*Config", "*Row"]
+//! debug_suffixes = ["Error", "Config", "Options", "Settings"]
+//! exclude = ["src/generated/**", "benches/fixtures"]
+//! generated_marker = "codegen::generated"
+//! messages = "i18n/quality.de.toml"
+//! repository = "https://github.com/org/repo"
+//!
+//! [package.metadata.quality.empty_lines]
+//! max_consecutive = 1
+//! allow_before_comment = true
+//! ```
+
+use std::{
+    collections::BTreeSet,
+    fs,
+    path::{Path, PathBuf}
+};
+
+use ignore::gitignore::GitignoreBuilder;
+use masterror::AppResult;
+use toml::{Table, Value};
+
+use crate::{
+    analyzer::Analyzer,
+    analyzers::{GENERATED_MARKER, empty_lines::EmptyLinesPolicy, item_order::ITEM_GROUPS},
+    error::{InvalidConfigError, IoError}
+};
+
+/// Dependencies that mark a project as a network server.
+const SERVER_CRATES: &[&str] = &[
+    "axum",
+    "actix-web",
+    "hyper",
+    "poem",
+    "rocket",
+    "salvo",
+    "tonic",
+    "warp"
+];
+
+/// Structured logging crate a project depends on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoggingCrate {
+    /// The `tracing` crate
+    Tracing,
+    /// The `log` crate
+    Log
+}
+
+impl LoggingCrate {
+    /// Returns the crate name used in `use` paths.
+    ///
+    /// # Returns
+    ///
+    /// `"tracing"` or `"log"`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cargo_quality::project::LoggingCrate;
+    ///
+    /// assert_eq!(LoggingCrate::Tracing.crate_name(), "tracing");
+    /// ```
+    #[inline]
+    pub fn crate_name(self) -> &'static str {
+        match self {
+            Self::Tracing => "tracing",
+            Self::Log => "log"
+        }
+    }
+}
+
+/// Information about the Cargo project that owns the analyzed files.
+///
+/// The default context describes "no manifest found": no dependencies, so
+/// dependency-driven analyzers stay silent.
+///
+/// # Examples
+///
+/// ```
+/// use cargo_quality::project::{LoggingCrate, ProjectContext};
+///
+/// let manifest = r#"
+///     [package]
+///     name = "service"
+///
+///     [dependencies]
+///     tracing = "0.1"
+/// "#;
+/// let project = ProjectContext::from_manifest_str(manifest).unwrap();
+/// assert!(project.has_dependency("tracing"));
+/// assert_eq!(project.logging_crate(), Some(LoggingCrate::Tracing));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProjectContext {
+    dependencies:     BTreeSet<String>,
+    enabled:          BTreeSet<String>,
+    collapse:         BTreeSet<String>,
+    error_type:       Option<String>,
+    sensitive_fields: BTreeSet<String>,
+    strict:           bool,
+    license_header:   Option<String>,
+    item_order:       Option<Vec<String>>,
+    pub_fields_allow: BTreeSet<String>,
+    debug_suffixes:   Option<Vec<String>>,
+    exclude:          BTreeSet<String>,
+    empty_lines:      EmptyLinesPolicy,
+    generated_marker: Option<String>,
+    messages:         Option<PathBuf>,
+    repository:       Option<String>,
+    root:             Option<PathBuf>
+}
+
+impl ProjectContext {
+    /// Discovers the project context for a file or directory.
+    ///
+    /// Walks up from `path` to the nearest directory containing a
+    /// `Cargo.toml` and reads its dependencies. If no manifest is found the
+    /// default (empty) context is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - File or directory being analyzed
+    ///
+    /// # Returns
+    ///
+    /// `AppResult<ProjectContext>` - Discovered context
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the manifest exists but cannot be read or parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::path::Path;
+    ///
+    /// use cargo_quality::project::ProjectContext;
+    ///
+    /// let project = ProjectContext::discover(Path::new("src/")).unwrap();
+    /// println!("{:?}", project.logging_crate());
+    /// ```
+    pub fn discover(path: &Path) -> AppResult<Self> {
+        let Some(manifest_path) = find_manifest(path) else {
+            return Ok(Self::default());
+        };
+
+        let content = fs::read_to_string(&manifest_path).map_err(IoError::from)?;
+        let mut project = Self::from_manifest_str(&content)?;
+        if let Some(dir) = manifest_path.parent() {
+            project.messages = project.messages.map(|messages| dir.join(messages));
+            project.root = Some(dir.to_path_buf());
+        }
+        Ok(project)
+    }
+
+    /// Builds a context from manifest text.
+    ///
+    /// Collects dependency names from `[dependencies]`,
+    /// `[workspace.dependencies]` and `[target.*.dependencies]`. Dev and build
+    /// dependencies are ignored because they do not describe what the shipped
+    /// code can use. Opt-in analyzers are read from the `enable` list,
+    /// analyzers printed as counts in verbose output from `collapse`, extra
+    /// sensitive field names from `sensitive_fields`, type name patterns
+    /// exempt from the `pub_fields` analyzer from `pub_fields_allow`, globs
+    /// of files left out of directory walks from `exclude`, the
+    /// standard error type
+    /// from `error_type` and the check profile from `profile` of
+    /// `[package.metadata.quality]` and `[workspace.metadata.quality]`, as are
+    /// the `license_header` template, the `item_order` group order, the
+    /// `debug_suffixes` of the `required_debug` analyzer, the
+    /// `empty_lines` policy, the `generated_marker` attribute and the
+    /// `messages` translation file and the `repository` URL issues link to,
+    /// which defaults to `package.repository`. Lists of names are merged; for
+    /// other settings the package setting wins.
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - Contents of a `Cargo.toml`
+    ///
+    /// # Returns
+    ///
+    /// `AppResult<ProjectContext>` - Context with the declared dependencies
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the text is not valid TOML or the quality metadata
+    /// is malformed.
+    pub fn from_manifest_str(content: &str) -> AppResult<Self> {
+        let manifest: Table = content
+            .parse()
+            .map_err(|e| InvalidConfigError::new(format!("Cargo.toml: {}", e)))?;
+
+        let mut dependencies = BTreeSet::new();
+        let mut enabled = BTreeSet::new();
+        let mut collapse = BTreeSet::new();
+        let mut error_type = None;
+        let mut sensitive_fields = BTreeSet::new();
+        let mut strict = None;
+        let mut license_header = None;
+        let mut item_order = None;
+        let mut pub_fields_allow = BTreeSet::new();
+        let mut debug_suffixes = None;
+        let mut exclude = BTreeSet::new();
+        let mut max_consecutive = None;
+        let mut allow_before_comment = None;
+        let mut generated_marker = None;
+        let mut messages = None;
+        let mut repository = None;
+        collect_dependency_names(manifest.get("dependencies"), &mut dependencies);
+
+        if let Some(Value::Table(package)) = manifest.get("package")
+            && let Some(quality) = quality_table(package)
+        {
+            collect_enabled(quality, &mut enabled)?;
+            collect_collapse(quality, &mut collapse)?;
+            collect_sensitive_fields(quality, &mut sensitive_fields)?;
+            collect_pub_fields_allow(quality, &mut pub_fields_allow)?;
+            collect_exclude(quality, &mut exclude)?;
+            error_type = read_string(quality, "error_type", "a type path string")?;
+            strict = read_strict(quality)?;
+            license_header = read_string(quality, "license_header", "a header string")?;
+            item_order = read_item_order(quality)?;
+            debug_suffixes = read_debug_suffixes(quality)?;
+            read_empty_lines(quality, &mut max_consecutive, &mut allow_before_comment)?;
+            generated_marker = read_string(quality, "generated_marker", "an attribute path")?;
+            messages = read_string(quality, "messages", "a file path")?;
+            repository = read_string(quality, "repository", "a URL string")?;
+        }
+
+        if let Some(Value::Table(workspace)) = manifest.get("workspace") {
+            collect_dependency_names(workspace.get("dependencies"), &mut dependencies);
+            if let Some(quality) = quality_table(workspace) {
+                collect_enabled(quality, &mut enabled)?;
+                collect_collapse(quality, &mut collapse)?;
+                collect_sensitive_fields(quality, &mut sensitive_fields)?;
+                collect_pub_fields_allow(quality, &mut pub_fields_allow)?;
+                collect_exclude(quality, &mut exclude)?;
+                if error_type.is_none() {
+                    error_type = read_string(quality, "error_type", "a type path string")?;
+                }
+                if strict.is_none() {
+                    strict = read_strict(quality)?;
+                }
+                if license_header.is_none() {
+                    license_header = read_string(quality, "license_header", "a header string")?;
+                }
+                if item_order.is_none() {
+                    item_order = read_item_order(quality)?;
+                }
+                if debug_suffixes.is_none() {
+                    debug_suffixes = read_debug_suffixes(quality)?;
+                }
+                read_empty_lines(quality, &mut max_consecutive, &mut allow_before_comment)?;
+                if generated_marker.is_none() {
+                    generated_marker =
+                        read_string(quality, "generated_marker", "an attribute path")?;
+                }
+                if messages.is_none() {
+                    messages = read_string(quality, "messages", "a file path")?;
+                }
+                if repository.is_none() {
+                    repository = read_string(quality, "repository", "a URL string")?;
+                }
+            }
+        }
+
+        if repository.is_none() {
+            repository = package_repository(&manifest);
+        }
+
+        if let Some(Value::Table(targets)) = manifest.get("target") {
+            for target in targets.values() {
+                if let Value::Table(target) = target {
+                    collect_dependency_names(target.get("dependencies"), &mut dependencies);
+                }
+            }
+        }
+
+        Ok(Self {
+            dependencies,
+            enabled,
+            collapse,
+            error_type,
+            sensitive_fields,
+            strict: strict.unwrap_or(false),
+            license_header,
+            item_order,
+            pub_fields_allow,
+            debug_suffixes,
+            exclude,
+            empty_lines: EmptyLinesPolicy {
+                max_consecutive:      max_consecutive.unwrap_or(0),
+                allow_before_comment: allow_before_comment.unwrap_or(false)
+            },
+            generated_marker,
+            messages: messages.map(PathBuf::from),
+            repository,
+            root: None
+        })
+    }
+
+    /// Checks whether the project depends on a crate.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Dependency key as written in `Cargo.toml`
+    ///
+    /// # Returns
+    ///
+    /// `true` if the dependency is declared
+    #[inline]
+    pub fn has_dependency(&self, name: &str) -> bool {
+        self.dependencies.contains(name)
+    }
+
+    /// Checks whether an analyzer runs in a default (unfiltered) run.
+    ///
+    /// Regular analyzers always run; opt-in analyzers run only when the
+    /// project lists them in `enable`.
+    ///
+    /// # Arguments
+    ///
+    /// * `analyzer` - Analyzer to check
+    ///
+    /// # Returns
+    ///
+    /// `true` if the analyzer should run
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cargo_quality::{analyzers::MissingInstrumentAnalyzer, project::ProjectContext};
+    ///
+    /// let manifest = "[package.metadata.quality]\nenable = [\"missing_instrument\"]";
+    /// let project = ProjectContext::from_manifest_str(manifest).unwrap();
+    /// assert!(project.is_active(&MissingInstrumentAnalyzer::new()));
+    /// assert!(!ProjectContext::default().is_active(&MissingInstrumentAnalyzer::new()));
+    /// ```
+    pub fn is_active(&self, analyzer: &dyn Analyzer) -> bool {
+        !analyzer.opt_in() || self.enabled.contains(analyzer.name())
+    }
+
+    /// Returns the analyzers printed as counts in verbose output.
+    ///
+    /// # Returns
+    ///
+    /// Analyzer names from `metadata.quality.collapse`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cargo_quality::project::ProjectContext;
+    ///
+    /// let manifest = "[package.metadata.quality]\ncollapse = [\"empty_lines\"]";
+    /// let project = ProjectContext::from_manifest_str(manifest).unwrap();
+    /// assert_eq!(project.collapse().collect::<Vec<_>>(), ["empty_lines"]);
+    /// ```
+    pub fn collapse(&self) -> impl Iterator<Item = &str> {
+        self.collapse.iter().map(String::as_str)
+    }
+
+    /// Checks whether the project is a network server.
+    ///
+    /// A project counts as a server when it depends on a web or RPC
+    /// framework such as `axum`, `actix-web` or `tonic`.
+    ///
+    /// # Returns
+    ///
+    /// `true` if a server framework is a dependency
+    pub fn is_server(&self) -> bool {
+        SERVER_CRATES.iter().any(|name| self.has_dependency(name))
+    }
+
+    /// Returns the project's standard error type, if configured.
+    ///
+    /// # Returns
+    ///
+    /// Type path from `metadata.quality.error_type`, e.g.
+    /// `"masterror::AppError"`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cargo_quality::project::ProjectContext;
+    ///
+    /// let manifest = "[package.metadata.quality]\nerror_type = \"crate::Error\"";
+    /// let project = ProjectContext::from_manifest_str(manifest).unwrap();
+    /// assert_eq!(project.error_type(), Some("crate::Error"));
+    /// ```
+    pub fn error_type(&self) -> Option<&str> {
+        self.error_type.as_deref()
+    }
+
+    /// Returns extra field name patterns treated as sensitive.
+    ///
+    /// # Returns
+    ///
+    /// Names from `metadata.quality.sensitive_fields`, in addition to the
+    /// built-in patterns of the `sensitive_debug` analyzer
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cargo_quality::project::ProjectContext;
+    ///
+    /// let manifest = "[package.metadata.quality]\nsensitive_fields = [\"pin\"]";
+    /// let project = ProjectContext::from_manifest_str(manifest).unwrap();
+    /// assert_eq!(project.sensitive_fields().collect::<Vec<_>>(), ["pin"]);
+    /// ```
+    pub fn sensitive_fields(&self) -> impl Iterator<Item = &str> {
+        self.sensitive_fields.iter().map(String::as_str)
+    }
+
+    /// Returns type name patterns exempt from the `pub_fields` analyzer.
+    ///
+    /// # Returns
+    ///
+    /// Patterns from `metadata.quality.pub_fields_allow`, where `*` matches
+    /// any text
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cargo_quality::project::ProjectContext;
+    ///
+    /// let manifest = "[package.metadata.quality]\npub_fields_allow = [\"*Config\"]";
+    /// let project = ProjectContext::from_manifest_str(manifest).unwrap();
+    /// assert_eq!(project.pub_fields_allow().collect::<Vec<_>>(), ["*Config"]);
+    /// ```
+    pub fn pub_fields_allow(&self) -> impl Iterator<Item = &str> {
+        self.pub_fields_allow.iter().map(String::as_str)
+    }
+
+    /// Returns the globs of files left out of directory walks.
+    ///
+    /// # Returns
+    ///
+    /// Gitignore-style globs from `metadata.quality.exclude`, relative to the
+    /// directory of `Cargo.toml`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cargo_quality::project::ProjectContext;
+    ///
+    /// let manifest = "[package.metadata.quality]\nexclude = [\"src/generated/**\"]";
+    /// let project = ProjectContext::from_manifest_str(manifest).unwrap();
+    /// assert_eq!(project.exclude().collect::<Vec<_>>(), ["src/generated/**"]);
+    /// ```
+    pub fn exclude(&self) -> impl Iterator<Item = &str> {
+        self.exclude.iter().map(String::as_str)
+    }
+
+    /// Returns the directory of the discovered `Cargo.toml`.
+    ///
+    /// # Returns
+    ///
+    /// Directory the `exclude` globs are relative to; `None` for a context
+    /// built from manifest text or without a manifest
+    pub fn root(&self) -> Option<&Path> {
+        self.root.as_deref()
+    }
+
+    /// Checks whether the project uses the strict profile.
+    ///
+    /// Under `profile = "strict"` some analyzers report their findings as
+    /// errors instead of warnings.
+    ///
+    /// # Returns
+    ///
+    /// `true` if `metadata.quality.profile` is `"strict"`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cargo_quality::project::ProjectContext;
+    ///
+    /// let manifest = "[package.metadata.quality]\nprofile = \"strict\"";
+    /// let project = ProjectContext::from_manifest_str(manifest).unwrap();
+    /// assert!(project.is_strict());
+    /// assert!(!ProjectContext::default().is_strict());
+    /// ```
+    #[inline]
+    pub fn is_strict(&self) -> bool {
+        self.strict
+    }
+
+    /// Returns the license header template, if configured.
+    ///
+    /// # Returns
+    ///
+    /// Text of `metadata.quality.license_header`, inserted at the top of
+    /// files without SPDX headers
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cargo_quality::project::ProjectContext;
+    ///
+    /// let manifest =
+    ///     "[package.metadata.quality]\nlicense_header = \"// SPDX-License-Identifier: MIT\"";
+    /// let project = ProjectContext::from_manifest_str(manifest).unwrap();
+    /// assert_eq!(
+    ///     project.license_header(),
+    ///     Some("// SPDX-License-Identifier: MIT")
+    /// );
+    /// ```
+    pub fn license_header(&self) -> Option<&str> {
+        self.license_header.as_deref()
+    }
+
+    /// Returns the configured item group order, if any.
+    ///
+    /// # Returns
+    ///
+    /// Group names from `metadata.quality.item_order`, each one of
+    /// [`ITEM_GROUPS`](crate::analyzers::item_order::ITEM_GROUPS)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cargo_quality::project::ProjectContext;
+    ///
+    /// let manifest = "[package.metadata.quality]\nitem_order = [\"type\", \"fn\"]";
+    /// let project = ProjectContext::from_manifest_str(manifest).unwrap();
+    /// assert_eq!(
+    ///     project.item_order(),
+    ///     Some(&["type".to_string(), "fn".to_string()][..])
+    /// );
+    /// ```
+    pub fn item_order(&self) -> Option<&[String]> {
+        self.item_order.as_deref()
+    }
+
+    /// Returns the configured type name suffixes that require `Debug`, if
+    /// any.
+    ///
+    /// # Returns
+    ///
+    /// Suffixes from `metadata.quality.debug_suffixes`, replacing
+    /// [`DEBUG_SUFFIXES`](crate::analyzers::required_debug::DEBUG_SUFFIXES)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cargo_quality::project::ProjectContext;
+    ///
+    /// let manifest = "[package.metadata.quality]\ndebug_suffixes = [\"Settings\"]";
+    /// let project = ProjectContext::from_manifest_str(manifest).unwrap();
+    /// assert_eq!(
+    ///     project.debug_suffixes(),
+    ///     Some(&["Settings".to_string()][..])
+    /// );
+    /// ```
+    pub fn debug_suffixes(&self) -> Option<&[String]> {
+        self.debug_suffixes.as_deref()
+    }
+
+    /// Returns the empty lines accepted inside function bodies.
+    ///
+    /// # Returns
+    ///
+    /// Policy from the `metadata.quality.empty_lines` table: `max_consecutive`
+    /// empty lines in a row are accepted, and with `allow_before_comment`
+    /// an empty line followed by a comment is too. Both default to off.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cargo_quality::project::ProjectContext;
+    ///
+    /// let manifest = "[package.metadata.quality.empty_lines]\nmax_consecutive = 1";
+    /// let project = ProjectContext::from_manifest_str(manifest).unwrap();
+    /// assert_eq!(project.empty_lines().max_consecutive, 1);
+    /// assert!(!project.empty_lines().allow_before_comment);
+    /// ```
+    pub fn empty_lines(&self) -> EmptyLinesPolicy {
+        self.empty_lines
+    }
+
+    /// Returns the marker attribute of generated items.
+    ///
+    /// The `empty_lines` and `function_length` analyzers skip items
+    /// annotated with it.
+    ///
+    /// # Returns
+    ///
+    /// Attribute path from `metadata.quality.generated_marker`, or
+    /// [`GENERATED_MARKER`] (`"quality::generated"`) if not configured
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cargo_quality::project::ProjectContext;
+    ///
+    /// let manifest = "[package.metadata.quality]\ngenerated_marker = \"codegen::generated\"";
+    /// let project = ProjectContext::from_manifest_str(manifest).unwrap();
+    /// assert_eq!(project.generated_marker(), "codegen::generated");
+    /// assert_eq!(
+    ///     ProjectContext::default().generated_marker(),
+    ///     "quality::generated"
+    /// );
+    /// ```
+    pub fn generated_marker(&self) -> &str {
+        self.generated_marker.as_deref().unwrap_or(GENERATED_MARKER)
+    }
+
+    /// Returns the translation file for issue messages, if configured.
+    ///
+    /// A relative path is resolved against the directory of the manifest
+    /// when the context is discovered from disk.
+    ///
+    /// # Returns
+    ///
+    /// Path from `metadata.quality.messages`, see
+    /// [`crate::messages::MessageCatalog`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    ///
+    /// use cargo_quality::project::ProjectContext;
+    ///
+    /// let manifest = "[package.metadata.quality]\nmessages = \"i18n/de.toml\"";
+    /// let project = ProjectContext::from_manifest_str(manifest).unwrap();
+    /// assert_eq!(project.messages(), Some(Path::new("i18n/de.toml")));
+    /// ```
+    pub fn messages(&self) -> Option<&Path> {
+        self.messages.as_deref()
+    }
+
+    /// Returns the repository URL issue links point to, if known.
+    ///
+    /// # Returns
+    ///
+    /// URL from `metadata.quality.repository`, falling back to
+    /// `package.repository` and `workspace.package.repository`, see
+    /// [`crate::source_link::SourceLinks`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cargo_quality::project::ProjectContext;
+    ///
+    /// let manifest = "[package]\nrepository = \"https://github.com/org/repo\"";
+    /// let project = ProjectContext::from_manifest_str(manifest).unwrap();
+    /// assert_eq!(project.repository(), Some("https://github.com/org/repo"));
+    /// ```
+    pub fn repository(&self) -> Option<&str> {
+        self.repository.as_deref()
+    }
+
+    /// Returns the structured logging crate the project uses.
+    ///
+    /// `tracing` takes precedence over `log` when both are declared.
+    ///
+    /// # Returns
+    ///
+    /// `Some(LoggingCrate)` if `tracing` or `log` is a dependency
+    pub fn logging_crate(&self) -> Option<LoggingCrate> {
+        if self.has_dependency("tracing") {
+            Some(LoggingCrate::Tracing)
+        } else if self.has_dependency("log") {
+            Some(LoggingCrate::Log)
+        } else {
+            None
+        }
+    }
+}
+
+/// Finds the nearest `Cargo.toml` at or above a path.
+///
+/// # Arguments
+///
+/// * `path` - File or directory to start from
+///
+/// # Returns
+///
+/// `Some(PathBuf)` with the manifest path, `None` if there is none
+fn find_manifest(path: &Path) -> Option<PathBuf> {
+    let absolute = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    let start = if absolute.is_file() {
+        absolute.parent()?.to_path_buf()
+    } else {
+        absolute
+    };
+
+    start
+        .ancestors()
+        .map(|dir| dir.join("Cargo.toml"))
+        .find(|candidate| candidate.is_file())
+}
+
+/// Adds the keys of a dependency table to a set.
+///
+/// # Arguments
+///
+/// * `table` - Value of a `dependencies` key, if present
+/// * `names` - Set receiving the dependency names
+fn collect_dependency_names(table: Option<&Value>, names: &mut BTreeSet<String>) {
+    if let Some(Value::Table(table)) = table {
+        names.extend(table.keys().cloned());
+    }
+}
+
+/// Returns the `metadata.quality` table of a section.
+///
+/// # Arguments
+///
+/// * `section` - `[package]` or `[workspace]` table
+///
+/// # Returns
+///
+/// `Some(&Table)` if the section has quality metadata
+fn quality_table(section: &Table) -> Option<&Table> {
+    let Some(Value::Table(metadata)) = section.get("metadata") else {
+        return None;
+    };
+    match metadata.get("quality") {
+        Some(Value::Table(quality)) => Some(quality),
+        _ => None
+    }
+}
+
+/// Adds the analyzers listed in `metadata.quality.enable` to a set.
+///
+/// # Arguments
+///
+/// * `quality` - `metadata.quality` table
+/// * `enabled` - Set receiving the analyzer names
+///
+/// # Returns
+///
+/// `AppResult<()>` - Error if `enable` is not an array of strings
+fn collect_enabled(quality: &Table, enabled: &mut BTreeSet<String>) -> AppResult<()> {
+    let Some(list) = quality.get("enable") else {
+        return Ok(());
+    };
+
+    let invalid = || {
+        InvalidConfigError::new(
+            "metadata.quality.enable must be a list of analyzer names".to_string()
+        )
+    };
+    let Value::Array(items) = list else {
+        return Err(invalid().into());
+    };
+    for item in items {
+        let Value::String(name) = item else {
+            return Err(invalid().into());
+        };
+        enabled.insert(name.clone());
+    }
+
+    Ok(())
+}
+
+/// Collects analyzer names from `metadata.quality.collapse`.
+///
+/// # Arguments
+///
+/// * `quality` - `metadata.quality` table
+/// * `collapse` - Set receiving the analyzer names
+///
+/// # Returns
+///
+/// `AppResult<()>` - Error if `collapse` is not an array of strings
+fn collect_collapse(quality: &Table, collapse: &mut BTreeSet<String>) -> AppResult<()> {
+    let Some(list) = quality.get("collapse") else {
+        return Ok(());
+    };
+
+    let invalid = || {
+        InvalidConfigError::new(
+            "metadata.quality.collapse must be a list of analyzer names".to_string()
+        )
+    };
+    let Value::Array(items) = list else {
+        return Err(invalid().into());
+    };
+    for item in items {
+        let Value::String(name) = item else {
+            return Err(invalid().into());
+        };
+        collapse.insert(name.clone());
+    }
+
+    Ok(())
+}
+
+/// Collects extra sensitive field names from `metadata.quality`.
+///
+/// # Arguments
+///
+/// * `quality` - `metadata.quality` table
+/// * `fields` - Set receiving the lowercased field names
+///
+/// # Returns
+///
+/// `AppResult<()>` - Error if `sensitive_fields` is not an array of strings
+fn collect_sensitive_fields(quality: &Table, fields: &mut BTreeSet<String>) -> AppResult<()> {
+    let Some(list) = quality.get("sensitive_fields") else {
+        return Ok(());
+    };
+
+    let invalid = || {
+        InvalidConfigError::new(
+            "metadata.quality.sensitive_fields must be a list of field names".to_string()
+        )
+    };
+    let Value::Array(items) = list else {
+        return Err(invalid().into());
+    };
+    for item in items {
+        let Value::String(name) = item else {
+            return Err(invalid().into());
+        };
+        fields.insert(name.to_lowercase());
+    }
+
+    Ok(())
+}
+
+/// Collects type name patterns from `metadata.quality.pub_fields_allow`.
+///
+/// # Arguments
+///
+/// * `quality` - `metadata.quality` table
+/// * `patterns` - Set receiving the patterns
+///
+/// # Returns
+///
+/// `AppResult<()>` - Error if `pub_fields_allow` is not an array of strings
+fn collect_pub_fields_allow(quality: &Table, patterns: &mut BTreeSet<String>) -> AppResult<()> {
+    let Some(list) = quality.get("pub_fields_allow") else {
+        return Ok(());
+    };
+
+    let invalid = || {
+        InvalidConfigError::new(
+            "metadata.quality.pub_fields_allow must be a list of type name patterns".to_string()
+        )
+    };
+    let Value::Array(items) = list else {
+        return Err(invalid().into());
+    };
+    for item in items {
+        let Value::String(pattern) = item else {
+            return Err(invalid().into());
+        };
+        patterns.insert(pattern.clone());
+    }
+
+    Ok(())
+}
+
+/// Collects file globs from `metadata.quality.exclude`.
+///
+/// # Arguments
+///
+/// * `quality` - `metadata.quality` table
+/// * `globs` - Set receiving the globs
+///
+/// # Returns
+///
+/// `AppResult<()>` - Error if `exclude` is not an array of valid globs
+fn collect_exclude(quality: &Table, globs: &mut BTreeSet<String>) -> AppResult<()> {
+    let Some(list) = quality.get("exclude") else {
+        return Ok(());
+    };
+
+    let invalid = || {
+        InvalidConfigError::new(
+            "metadata.quality.exclude must be a list of file globs".to_string()
+        )
+    };
+    let Value::Array(items) = list else {
+        return Err(invalid().into());
+    };
+    for item in items {
+        let Value::String(glob) = item else {
+            return Err(invalid().into());
+        };
+        let glob = glob_separators(glob, cfg!(windows));
+        GitignoreBuilder::new("")
+            .add_line(None, &glob)
+            .map_err(|e| {
+                InvalidConfigError::new(format!("metadata.quality.exclude: {glob}: {e}"))
+            })?;
+        globs.insert(glob);
+    }
+
+    Ok(())
+}
+
+/// Turns the separators of an exclude glob into `/`.
+///
+/// Gitignore-style globs separate directories with `/` and use `\` to
+/// escape special characters. A glob written on Windows as
+/// `src\generated\**` means directories, so there every `\` is read as a
+/// separator; elsewhere the glob is kept as written.
+///
+/// # Arguments
+///
+/// * `glob` - Glob from `metadata.quality.exclude`
+/// * `backslash_separators` - Whether `\` separates directories
+///
+/// # Returns
+///
+/// The glob with `/` separators
+fn glob_separators(glob: &str, backslash_separators: bool) -> String {
+    if backslash_separators {
+        glob.replace('\\', "/")
+    } else {
+        glob.to_string()
+    }
+}
+
+/// Reads `metadata.quality.profile`.
+///
+/// # Arguments
+///
+/// * `quality` - `metadata.quality` table
+///
+/// # Returns
+///
+/// `AppResult<Option<bool>>` - Whether the profile is strict, or an error if
+/// it is neither `"default"` nor `"strict"`
+fn read_strict(quality: &Table) -> AppResult<Option<bool>> {
+    match quality.get("profile") {
+        None => Ok(None),
+        Some(Value::String(profile)) if profile == "strict" => Ok(Some(true)),
+        Some(Value::String(profile)) if profile == "default" => Ok(Some(false)),
+        Some(_) => Err(InvalidConfigError::new(
+            "metadata.quality.profile must be \"default\" or \"strict\"".to_string()
+        )
+        .into())
+    }
+}
+
+/// Reads a string setting from `metadata.quality`.
+///
+/// # Arguments
+///
+/// * `quality` - `metadata.quality` table
+/// * `key` - Setting name, e.g. `error_type`
+/// * `expected` - Description of a valid value for the error message
+///
+/// # Returns
+///
+/// `AppResult<Option<String>>` - Error if the setting is not a string
+fn read_string(quality: &Table, key: &str, expected: &str) -> AppResult<Option<String>> {
+    match quality.get(key) {
+        None => Ok(None),
+        Some(Value::String(value)) => Ok(Some(value.clone())),
+        Some(_) => Err(InvalidConfigError::new(format!(
+            "metadata.quality.{key} must be {expected}"
+        ))
+        .into())
+    }
+}
+
+/// Reads Cargo's own `repository` key of the package or workspace.
+///
+/// A package inheriting the key with `repository.workspace = true` falls
+/// back to `[workspace.package]`.
+///
+/// # Arguments
+///
+/// * `manifest` - Parsed `Cargo.toml`
+///
+/// # Returns
+///
+/// Repository URL, if declared
+fn package_repository(manifest: &Table) -> Option<String> {
+    let declared = |table: Option<&Value>| match table?.get("repository")? {
+        Value::String(url) => Some(url.clone()),
+        _ => None
+    };
+
+    declared(manifest.get("package")).or_else(|| {
+        declared(
+            manifest
+                .get("workspace")
+                .and_then(|workspace| workspace.get("package"))
+        )
+    })
+}
+
+/// Reads `metadata.quality.item_order`.
+///
+/// # Arguments
+///
+/// * `quality` - `metadata.quality` table
+///
+/// # Returns
+///
+/// `AppResult<Option<Vec<String>>>` - Group order, or an error if it is not
+/// a list of distinct group names
+fn read_item_order(quality: &Table) -> AppResult<Option<Vec<String>>> {
+    let Some(list) = quality.get("item_order") else {
+        return Ok(None);
+    };
+
+    let invalid = || {
+        InvalidConfigError::new(format!(
+            "metadata.quality.item_order must be a list of distinct groups from {}",
+            ITEM_GROUPS.join(", ")
+        ))
+    };
+    let Value::Array(items) = list else {
+        return Err(invalid().into());
+    };
+    let mut order = Vec::new();
+    for item in items {
+        match item {
+            Value::String(group)
+                if ITEM_GROUPS.contains(&group.as_str()) && !order.contains(group) =>
+            {
+                order.push(group.clone());
+            }
+            _ => return Err(invalid().into())
+        }
+    }
+
+    Ok(Some(order))
+}
+
+/// Reads `metadata.quality.debug_suffixes`.
+///
+/// # Arguments
+///
+/// * `quality` - `metadata.quality` table
+///
+/// # Returns
+///
+/// `AppResult<Option<Vec<String>>>` - Suffixes, or an error if they are not
+/// a list of non-empty strings
+fn read_debug_suffixes(quality: &Table) -> AppResult<Option<Vec<String>>> {
+    let Some(list) = quality.get("debug_suffixes") else {
+        return Ok(None);
+    };
+
+    let invalid = || {
+        InvalidConfigError::new(
+            "metadata.quality.debug_suffixes must be a list of type name suffixes".to_string()
+        )
+    };
+    let Value::Array(items) = list else {
+        return Err(invalid().into());
+    };
+    let mut suffixes = Vec::new();
+    for item in items {
+        match item {
+            Value::String(suffix) if !suffix.is_empty() => suffixes.push(suffix.clone()),
+            _ => return Err(invalid().into())
+        }
+    }
+
+    Ok(Some(suffixes))
+}
+
+/// Reads the `metadata.quality.empty_lines` table.
+///
+/// Settings already read from a higher-priority table are kept.
+///
+/// # Arguments
+///
+/// * `quality` - `metadata.quality` table
+/// * `max_consecutive` - `max_consecutive` setting, filled if unset
+/// * `allow_before_comment` - `allow_before_comment` setting, filled if unset
+///
+/// # Returns
+///
+/// `AppResult<()>` - Error if the table has unknown keys or values of the
+/// wrong type
+fn read_empty_lines(
+    quality: &Table,
+    max_consecutive: &mut Option<usize>,
+    allow_before_comment: &mut Option<bool>
+) -> AppResult<()> {
+    let Some(policy) = quality.get("empty_lines") else {
+        return Ok(());
+    };
+
+    let invalid = || {
+        InvalidConfigError::new(
+            "metadata.quality.empty_lines must be a table with a non-negative integer \
+             max_consecutive and a boolean allow_before_comment"
+                .to_string()
+        )
+    };
+    let Value::Table(policy) = policy else {
+        return Err(invalid().into());
+    };
+    for (key, value) in policy {
+        match (key.as_str(), value) {
+            ("max_consecutive", Value::Integer(count)) => {
+                let count = usize::try_from(*count).map_err(|_| invalid())?;
+                max_consecutive.get_or_insert(count);
+            }
+            ("allow_before_comment", Value::Boolean(allow)) => {
+                allow_before_comment.get_or_insert(*allow);
+            }
+            _ => return Err(invalid().into())
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+
+    #[test]
+    fn test_default_has_no_dependencies() {
+        let project = ProjectContext::default();
+        assert!(!project.has_dependency("tracing"));
+        assert_eq!(project.logging_crate(), None);
+    }
+
+    #[test]
+    fn test_collects_all_dependency_tables() {
+        let manifest = r#"
+            [dependencies]
+            serde = "1"
+
+            [dev-dependencies]
+            tempfile = "3"
+
+            [workspace.dependencies]
+            log = "0.4"
+
+            [target.'cfg(unix)'.dependencies]
+            libc = "0.2"
+        "#;
+        let project = ProjectContext::from_manifest_str(manifest).unwrap();
+
+        assert!(project.has_dependency("serde"));
+        assert!(project.has_dependency("log"));
+        assert!(project.has_dependency("libc"));
+        assert!(!project.has_dependency("tempfile"));
+    }
+
+    #[test]
+    fn test_tracing_preferred_over_log() {
+        let manifest = "[dependencies]\nlog = \"0.4\"\ntracing = \"0.1\"\n";
+        let project = ProjectContext::from_manifest_str(manifest).unwrap();
+        assert_eq!(project.logging_crate(), Some(LoggingCrate::Tracing));
+    }
+
+    #[test]
+    fn test_server_detection() {
+        let manifest = "[dependencies]\naxum = \"0.8\"\n";
+        assert!(
+            ProjectContext::from_manifest_str(manifest)
+                .unwrap()
+                .is_server()
+        );
+        assert!(!ProjectContext::default().is_server());
+    }
+
+    #[test]
+    fn test_enable_list_from_package_and_workspace() {
+        let manifest = r#"
+            [package.metadata.quality]
+            enable = ["missing_instrument"]
+
+            [workspace.metadata.quality]
+            enable = ["other"]
+        "#;
+        let project = ProjectContext::from_manifest_str(manifest).unwrap();
+
+        assert!(project.enabled.contains("missing_instrument"));
+        assert!(project.enabled.contains("other"));
+    }
+
+    #[test]
+    fn test_invalid_enable_list_is_error() {
+        let manifest = "[package.metadata.quality]\nenable = \"missing_instrument\"";
+        assert!(ProjectContext::from_manifest_str(manifest).is_err());
+
+        let manifest = "[package.metadata.quality]\nenable = [1]";
+        assert!(ProjectContext::from_manifest_str(manifest).is_err());
+    }
+
+    #[test]
+    fn test_collapse_list_from_package_and_workspace() {
+        let manifest = r#"
+            [package.metadata.quality]
+            collapse = ["empty_lines"]
+
+            [workspace.metadata.quality]
+            collapse = ["line_length"]
+        "#;
+        let project = ProjectContext::from_manifest_str(manifest).unwrap();
+        assert_eq!(
+            project.collapse().collect::<Vec<_>>(),
+            ["empty_lines", "line_length"]
+        );
+
+        let manifest = "[package.metadata.quality]\ncollapse = \"empty_lines\"";
+        assert!(ProjectContext::from_manifest_str(manifest).is_err());
+    }
+
+    #[test]
+    fn test_error_type_package_overrides_workspace() {
+        let manifest = r#"
+            [package.metadata.quality]
+            error_type = "crate::Error"
+
+            [workspace.metadata.quality]
+            error_type = "masterror::AppError"
+        "#;
+        let project = ProjectContext::from_manifest_str(manifest).unwrap();
+        assert_eq!(project.error_type(), Some("crate::Error"));
+
+        let manifest = "[workspace.metadata.quality]\nerror_type = \"masterror::AppError\"";
+        let project = ProjectContext::from_manifest_str(manifest).unwrap();
+        assert_eq!(project.error_type(), Some("masterror::AppError"));
+        assert_eq!(ProjectContext::default().error_type(), None);
+    }
+
+    #[test]
+    fn test_invalid_error_type_is_error() {
+        let manifest = "[package.metadata.quality]\nerror_type = 1";
+        assert!(ProjectContext::from_manifest_str(manifest).is_err());
+    }
+
+    #[test]
+    fn test_sensitive_fields_merged_and_lowercased() {
+        let manifest = r#"
+            [package.metadata.quality]
+            sensitive_fields = ["PIN"]
+
+            [workspace.metadata.quality]
+            sensitive_fields = ["ssn"]
+        "#;
+        let project = ProjectContext::from_manifest_str(manifest).unwrap();
+        assert_eq!(
+            project.sensitive_fields().collect::<Vec<_>>(),
+            ["pin", "ssn"]
+        );
+
+        let manifest = "[package.metadata.quality]\nsensitive_fields = \"pin\"";
+        assert!(ProjectContext::from_manifest_str(manifest).is_err());
+    }
+
+    #[test]
+    fn test_exclude_merged_and_validated() {
+        let manifest = r#"
+            [package.metadata.quality]
+            exclude = ["src/generated/**"]
+
+            [workspace.metadata.quality]
+            exclude = ["benches/fixtures", "src/generated/**"]
+        "#;
+        let project = ProjectContext::from_manifest_str(manifest).unwrap();
+        assert_eq!(
+            project.exclude().collect::<Vec<_>>(),
+            ["benches/fixtures", "src/generated/**"]
+        );
+
+        let manifest = "[package.metadata.quality]\nexclude = \"src/generated\"";
+        assert!(ProjectContext::from_manifest_str(manifest).is_err());
+
+        let manifest = "[package.metadata.quality]\nexclude = [\"src/{generated\"]";
+        let error = ProjectContext::from_manifest_str(manifest).unwrap_err();
+        assert!(error.to_string().contains("src/{generated"));
+    }
+
+    #[test]
+    fn test_glob_separators() {
+        assert_eq!(
+            glob_separators(r"src\generated\**", true),
+            "src/generated/**"
+        );
+        assert_eq!(glob_separators(r"src/\#notes.rs", false), r"src/\#notes.rs");
+        assert_eq!(
+            glob_separators("src/generated/**", true),
+            "src/generated/**"
+        );
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn test_exclude_backslash_separators() {
+        let manifest = r#"
+            [package.metadata.quality]
+            exclude = ['src\generated\**']
+        "#;
+        let project = ProjectContext::from_manifest_str(manifest).unwrap();
+        assert_eq!(project.exclude().collect::<Vec<_>>(), ["src/generated/**"]);
+    }
+
+    #[test]
+    fn test_pub_fields_allow_merged() {
+        let manifest = r#"
+            [package.metadata.quality]
+            pub_fields_allow = ["*Config"]
+
+            [workspace.metadata.quality]
+            pub_fields_allow = ["*Row", "*Config"]
+        "#;
+        let project = ProjectContext::from_manifest_str(manifest).unwrap();
+        assert_eq!(
+            project.pub_fields_allow().collect::<Vec<_>>(),
+            ["*Config", "*Row"]
+        );
+
+        let manifest = "[package.metadata.quality]\npub_fields_allow = \"*Config\"";
+        assert!(ProjectContext::from_manifest_str(manifest).is_err());
+    }
+
+    #[test]
+    fn test_profile_package_overrides_workspace() {
+        let manifest = r#"
+            [package.metadata.quality]
+            profile = "default"
+
+            [workspace.metadata.quality]
+            profile = "strict"
+        "#;
+        let project = ProjectContext::from_manifest_str(manifest).unwrap();
+        assert!(!project.is_strict());
+
+        let manifest = "[workspace.metadata.quality]\nprofile = \"strict\"";
+        let project = ProjectContext::from_manifest_str(manifest).unwrap();
+        assert!(project.is_strict());
+    }
+
+    #[test]
+    fn test_unknown_profile_is_error() {
+        let manifest = "[package.metadata.quality]\nprofile = \"pedantic\"";
+        assert!(ProjectContext::from_manifest_str(manifest).is_err());
+    }
+
+    #[test]
+    fn test_license_header_package_overrides_workspace() {
+        let manifest = r#"
+            [package.metadata.quality]
+            license_header = "// SPDX-License-Identifier: MIT"
+
+            [workspace.metadata.quality]
+            license_header = "// SPDX-License-Identifier: Apache-2.0"
+        "#;
+        let project = ProjectContext::from_manifest_str(manifest).unwrap();
+        assert_eq!(
+            project.license_header(),
+            Some("// SPDX-License-Identifier: MIT")
+        );
+        assert_eq!(ProjectContext::default().license_header(), None);
+
+        let manifest = "[package.metadata.quality]\nlicense_header = true";
+        assert!(ProjectContext::from_manifest_str(manifest).is_err());
+    }
+
+    #[test]
+    fn test_item_order_package_overrides_workspace() {
+        let manifest = r#"
+            [package.metadata.quality]
+            item_order = ["type", "impl"]
+
+            [workspace.metadata.quality]
+            item_order = ["fn"]
+        "#;
+        let project = ProjectContext::from_manifest_str(manifest).unwrap();
+        assert_eq!(
+            project.item_order(),
+            Some(&["type".to_string(), "impl".to_string()][..])
+        );
+        assert_eq!(ProjectContext::default().item_order(), None);
+
+        for invalid in ["\"fn\"", "[\"fn\", \"macro\"]", "[\"fn\", \"fn\"]"] {
+            let manifest = format!("[package.metadata.quality]\nitem_order = {invalid}");
+            assert!(ProjectContext::from_manifest_str(&manifest).is_err());
+        }
+    }
+
+    #[test]
+    fn test_debug_suffixes_package_overrides_workspace() {
+        let manifest = r#"
+            [package.metadata.quality]
+            debug_suffixes = ["Settings"]
+
+            [workspace.metadata.quality]
+            debug_suffixes = ["Error"]
+        "#;
+        let project = ProjectContext::from_manifest_str(manifest).unwrap();
+        assert_eq!(
+            project.debug_suffixes(),
+            Some(&["Settings".to_string()][..])
+        );
+        assert_eq!(ProjectContext::default().debug_suffixes(), None);
+
+        for invalid in ["\"Error\"", "[\"Error\", 1]", "[\"\"]"] {
+            let manifest = format!("[package.metadata.quality]\ndebug_suffixes = {invalid}");
+            assert!(ProjectContext::from_manifest_str(&manifest).is_err());
+        }
+    }
+
+    #[test]
+    fn test_empty_lines_package_overrides_workspace() {
+        let manifest = r#"
+            [package.metadata.quality.empty_lines]
+            max_consecutive = 1
+
+            [workspace.metadata.quality.empty_lines]
+            max_consecutive = 2
+            allow_before_comment = true
+        "#;
+        let project = ProjectContext::from_manifest_str(manifest).unwrap();
+
+        assert_eq!(
+            project.empty_lines(),
+            EmptyLinesPolicy {
+                max_consecutive:      1,
+                allow_before_comment: true
+            }
+        );
+        assert_eq!(
+            ProjectContext::default().empty_lines(),
+            EmptyLinesPolicy::default()
+        );
+
+        for invalid in [
+            "empty_lines = 1",
+            "empty_lines = { max_consecutive = -1 }",
+            "empty_lines = { allow_before_comment = \"yes\" }",
+            "empty_lines = { max_consecutve = 1 }"
+        ] {
+            let manifest = format!("[package.metadata.quality]\n{invalid}");
+            assert!(ProjectContext::from_manifest_str(&manifest).is_err());
+        }
+    }
+
+    #[test]
+    fn test_generated_marker_from_workspace() {
+        let manifest = r#"
+            [workspace.metadata.quality]
+            generated_marker = "codegen::generated"
+        "#;
+        let project = ProjectContext::from_manifest_str(manifest).unwrap();
+        assert_eq!(project.generated_marker(), "codegen::generated");
+
+        let invalid = "[package.metadata.quality]\ngenerated_marker = true";
+        assert!(ProjectContext::from_manifest_str(invalid).is_err());
+    }
+
+    #[test]
+    fn test_messages_resolved_against_manifest() {
+        let temp = TempDir::new().unwrap();
+        fs::write(
+            temp.path().join("Cargo.toml"),
+            "[workspace.metadata.quality]\nmessages = \"i18n/de.toml\"\n"
+        )
+        .unwrap();
+
+        let project = ProjectContext::discover(temp.path()).unwrap();
+        assert_eq!(
+            project.messages(),
+            Some(
+                temp.path()
+                    .canonicalize()
+                    .unwrap()
+                    .join("i18n/de.toml")
+                    .as_path()
+            )
+        );
+
+        let invalid = "[package.metadata.quality]\nmessages = [\"de.toml\"]";
+        assert!(ProjectContext::from_manifest_str(invalid).is_err());
+    }
+
+    #[test]
+    fn test_repository_sources() {
+        let manifest = r#"
+[package]
+repository = "https://github.com/org/package"
+
+[package.metadata.quality]
+repository = "https://git.example.com/org/mirror"
+"#;
+        let project = ProjectContext::from_manifest_str(manifest).unwrap();
+        assert_eq!(
+            project.repository(),
+            Some("https://git.example.com/org/mirror")
+        );
+
+        let manifest = r#"
+[package]
+repository.workspace = true
+
+[workspace.package]
+repository = "https://github.com/org/workspace"
+"#;
+        let project = ProjectContext::from_manifest_str(manifest).unwrap();
+        assert_eq!(
+            project.repository(),
+            Some("https://github.com/org/workspace")
+        );
+
+        assert_eq!(ProjectContext::default().repository(), None);
+        let invalid = "[package.metadata.quality]\nrepository = 1";
+        assert!(ProjectContext::from_manifest_str(invalid).is_err());
+    }
+
+    #[test]
+    fn test_invalid_manifest_is_error() {
+        assert!(ProjectContext::from_manifest_str("[dependencies").is_err());
+    }
+
+    #[test]
+    fn test_discover_walks_up_from_file() {
+        let temp = TempDir::new().unwrap();
+        fs::write(
+            temp.path().join("Cargo.toml"),
+            "[package]\nname = \"x\"\n\n[dependencies]\nlog = \"0.4\"\n"
+        )
+        .unwrap();
+        fs::create_dir(temp.path().join("src")).unwrap();
+        let file = temp.path().join("src").join("lib.rs");
+        fs::write(&file, "").unwrap();
+
+        let project = ProjectContext::discover(&file).unwrap();
+        assert_eq!(project.logging_crate(), Some(LoggingCrate::Log));
+    }
+}
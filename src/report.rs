@@ -6,13 +6,26 @@
 //! Provides structured output of quality issues found during analysis,
 //! grouping results by analyzer and file.
 
-use std::{collections::HashMap, fmt};
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+    fmt
+};
 
-use console::measure_text_width;
+use console::{measure_text_width, truncate_str};
 use owo_colors::OwoColorize;
-use terminal_size::{Width, terminal_size};
 
-use crate::analyzer::AnalysisResult;
+use crate::{
+    analyzer::{AnalysisResult, Fix, Severity},
+    snapshot::normalize_path,
+    source_link::SourceLinks,
+    terminal::{is_narrow, terminal_width, wrap_line}
+};
+
+/// Maximum number of characters of a fix preview in an annotation.
+const PREVIEW_MAX_CHARS: usize = 300;
+
+/// Maximum number of lines of a fix preview in an annotation.
+const PREVIEW_MAX_LINES: usize = 8;
 
 /// Minimum space between columns in grid layout.
 const COLUMN_GAP: usize = 4;
@@ -23,6 +36,9 @@ const MIN_ANALYZER_WIDTH: usize = 40;
 /// Maximum width for an analyzer column to enable multi-column layout.
 const MAX_ANALYZER_WIDTH: usize = 80;
 
+/// Terminal width assumed when it cannot be detected.
+const DEFAULT_TERMINAL_WIDTH: usize = 170;
+
 /// Rendered analyzer block for grid layout.
 struct RenderedAnalyzer {
     lines: Vec<String>,
@@ -30,10 +46,17 @@ struct RenderedAnalyzer {
 }
 
 /// Renders a single analyzer block with issues.
+///
+/// Lines wider than the block are truncated. On a narrow terminal the block
+/// spans the terminal width and long lines wrap instead, so no text is lost.
+/// A collapsed block only counts the files and distinct messages.
 fn render_analyzer_block(
     analyzer_name: &str,
-    message_map: &HashMap<String, Vec<(String, Vec<usize>)>>,
-    color: bool
+    severity: Severity,
+    message_map: &BTreeMap<String, Vec<(String, Vec<usize>)>>,
+    color: bool,
+    term_width: usize,
+    collapsed: bool
 ) -> RenderedAnalyzer {
     let mut content_lines = Vec::new();
     let mut max_width = MIN_ANALYZER_WIDTH;
@@ -43,105 +66,137 @@ fn render_analyzer_block(
         .map(|files| files.iter().map(|(_, lines)| lines.len()).sum::<usize>())
         .sum();
 
-    let header = if color {
-        format!(
+    let header = match (severity, color) {
+        (Severity::Error, true) => format!(
+            "[{}] - {} errors",
+            analyzer_name.red().bold(),
+            total_issues.to_string().red()
+        ),
+        (Severity::Error, false) => format!("[{}] - {} errors", analyzer_name, total_issues),
+        (Severity::Warning, true) => format!(
             "[{}] - {} issues",
             analyzer_name.yellow().bold(),
             total_issues.to_string().cyan()
-        )
-    } else {
-        format!("[{}] - {} issues", analyzer_name, total_issues)
+        ),
+        (Severity::Warning, false) => format!("[{}] - {} issues", analyzer_name, total_issues)
     };
 
     max_width = max_width.max(measure_text_width(&header));
     content_lines.push(header);
 
-    for (message, file_list) in message_map {
-        let msg_line = format!("  {}", message);
-        max_width = max_width.max(measure_text_width(&msg_line));
-        content_lines.push(msg_line);
-        content_lines.push(String::new());
-
-        for (file_path, mut file_lines) in file_list.iter().map(|(f, l)| (f, l.clone())) {
-            file_lines.sort_unstable();
-
-            let file_line = if color {
-                format!("  {} → Lines: ", file_path.blue())
-            } else {
-                format!("  {} → Lines: ", file_path)
-            };
-
-            let lines_str: Vec<String> = file_lines.iter().map(|l| l.to_string()).collect();
-            let joined = if color {
-                lines_str
-                    .iter()
-                    .map(|l| format!("{}", l.magenta()))
-                    .collect::<Vec<_>>()
-                    .join(", ")
-            } else {
-                lines_str.join(", ")
-            };
-
-            let plain_joined_len = lines_str.join(", ").len();
+    if collapsed {
+        let files: HashSet<&str> = message_map
+            .values()
+            .flatten()
+            .map(|(file_path, _)| file_path.as_str())
+            .collect();
+        let summary = format!(
+            "  {} files, {} distinct messages (collapsed)",
+            files.len(),
+            message_map.len()
+        );
+        max_width = max_width.max(measure_text_width(&summary));
+        content_lines.push(summary);
+    } else {
+        for (message, file_list) in message_map {
+            let msg_line = format!("  {}", message);
+            max_width = max_width.max(measure_text_width(&msg_line));
+            content_lines.push(msg_line);
+            content_lines.push(String::new());
+
+            for (file_path, mut file_lines) in file_list.iter().map(|(f, l)| (f, l.clone())) {
+                file_lines.sort_unstable();
+
+                let file_line = if color {
+                    format!("  {} → Lines: ", file_path.blue())
+                } else {
+                    format!("  {} → Lines: ", file_path)
+                };
+
+                let lines_str: Vec<String> = file_lines.iter().map(|l| l.to_string()).collect();
+                let joined = if color {
+                    lines_str
+                        .iter()
+                        .map(|l| format!("{}", l.magenta()))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                } else {
+                    lines_str.join(", ")
+                };
+
+                let plain_joined_len = lines_str.join(", ").len();
+
+                if plain_joined_len > 60 {
+                    let mut line_chunks = Vec::new();
+                    let mut current_line = String::new();
+                    let mut current_len = 0;
+
+                    for (i, line_num) in lines_str.iter().enumerate() {
+                        let separator = if i == 0 { "" } else { ", " };
+                        let addition = if color {
+                            format!("{}{}", separator, line_num.magenta())
+                        } else {
+                            format!("{}{}", separator, line_num)
+                        };
 
-            if plain_joined_len > 60 {
-                let mut line_chunks = Vec::new();
-                let mut current_line = String::new();
-                let mut current_len = 0;
+                        let addition_len = separator.len() + line_num.len();
 
-                for (i, line_num) in lines_str.iter().enumerate() {
-                    let separator = if i == 0 { "" } else { ", " };
-                    let addition = if color {
-                        format!("{}{}", separator, line_num.magenta())
-                    } else {
-                        format!("{}{}", separator, line_num)
-                    };
+                        if current_len + addition_len > 60 && current_len > 0 {
+                            line_chunks.push(current_line.clone());
+                            current_line = if color {
+                                format!("{}", line_num.magenta())
+                            } else {
+                                line_num.clone()
+                            };
+                            current_len = line_num.len();
+                        } else {
+                            current_line.push_str(&addition);
+                            current_len += addition_len;
+                        }
+                    }
 
-                    let addition_len = separator.len() + line_num.len();
+                    if !current_line.is_empty() {
+                        line_chunks.push(current_line);
+                    }
 
-                    if current_len + addition_len > 60 && current_len > 0 {
-                        line_chunks.push(current_line.clone());
-                        current_line = if color {
-                            format!("{}", line_num.magenta())
+                    for (i, chunk) in line_chunks.iter().enumerate() {
+                        let full_line = if i == 0 {
+                            format!("{}{}", file_line, chunk)
                         } else {
-                            line_num.clone()
+                            format!("  {} {}", " ".repeat(file_path.len() + 9), chunk)
                         };
-                        current_len = line_num.len();
-                    } else {
-                        current_line.push_str(&addition);
-                        current_len += addition_len;
+                        max_width = max_width.max(measure_text_width(&full_line));
+                        content_lines.push(full_line);
                     }
-                }
-
-                if !current_line.is_empty() {
-                    line_chunks.push(current_line);
-                }
-
-                for (i, chunk) in line_chunks.iter().enumerate() {
-                    let full_line = if i == 0 {
-                        format!("{}{}", file_line, chunk)
-                    } else {
-                        format!("  {} {}", " ".repeat(file_path.len() + 9), chunk)
-                    };
+                } else {
+                    let full_line = format!("{}{}", file_line, joined);
                     max_width = max_width.max(measure_text_width(&full_line));
                     content_lines.push(full_line);
                 }
-            } else {
-                let full_line = format!("{}{}", file_line, joined);
-                max_width = max_width.max(measure_text_width(&full_line));
-                content_lines.push(full_line);
             }
-        }
 
-        content_lines.push(String::new());
+            content_lines.push(String::new());
+        }
     }
 
-    let final_width = max_width.clamp(MIN_ANALYZER_WIDTH, MAX_ANALYZER_WIDTH);
+    let narrow = is_narrow(term_width);
+    let final_width = if narrow {
+        term_width.max(1)
+    } else {
+        max_width.clamp(MIN_ANALYZER_WIDTH, MAX_ANALYZER_WIDTH.min(term_width))
+    };
     let separator = "─".repeat(final_width);
     let footer = "═".repeat(final_width);
+    let fit = |line: &String| {
+        if narrow {
+            wrap_line(line, final_width)
+        } else {
+            vec![truncate_str(line, final_width, "...").into_owned()]
+        }
+    };
 
     let mut lines = Vec::with_capacity(content_lines.len() + 2);
-    lines.push(content_lines[0].clone());
+    lines.extend(fit(&content_lines[0]));
     lines.push(if color {
         separator.dimmed().to_string()
     } else {
@@ -149,17 +204,7 @@ fn render_analyzer_block(
     });
 
     for line in &content_lines[1..] {
-        let line_width = measure_text_width(line);
-        if line_width > final_width {
-            let mut truncated = line.clone();
-            while measure_text_width(&truncated) > final_width - 3 {
-                truncated.pop();
-            }
-            truncated.push_str("...");
-            lines.push(truncated);
-        } else {
-            lines.push(line.clone());
-        }
+        lines.extend(fit(line));
     }
 
     lines.push(if color {
@@ -261,11 +306,14 @@ fn render_grid(analyzers: &[RenderedAnalyzer], columns: usize) -> String {
 ///
 /// Aggregates results from multiple analyzers for a single file and
 /// provides formatted output with issue counts and suggestions.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Report {
     /// File path being analyzed
     pub file_path: String,
     /// Analysis results grouped by analyzer name
-    pub results:   Vec<(String, AnalysisResult)>
+    pub results:   Vec<(String, AnalysisResult)>,
+    /// Severity of analyzers that differ from [`Severity::Warning`]
+    severities:    HashMap<String, Severity>
 }
 
 impl Report {
@@ -281,7 +329,8 @@ impl Report {
     pub fn new(file_path: String) -> Self {
         Self {
             file_path,
-            results: Vec::new()
+            results: Vec::new(),
+            severities: HashMap::new()
         }
     }
 
@@ -295,6 +344,41 @@ impl Report {
         self.results.push((analyzer_name, result));
     }
 
+    /// Add analysis result from an analyzer with a given severity.
+    ///
+    /// # Arguments
+    ///
+    /// * `analyzer_name` - Name of analyzer that produced results
+    /// * `severity` - Severity of the analyzer's issues
+    /// * `result` - Analysis result to add
+    pub fn add_result_with_severity(
+        &mut self,
+        analyzer_name: String,
+        severity: Severity,
+        result: AnalysisResult
+    ) {
+        if severity != Severity::Warning {
+            self.severities.insert(analyzer_name.clone(), severity);
+        }
+        self.add_result(analyzer_name, result);
+    }
+
+    /// Get the severity of an analyzer's issues.
+    ///
+    /// # Arguments
+    ///
+    /// * `analyzer_name` - Name of analyzer
+    ///
+    /// # Returns
+    ///
+    /// Severity recorded for the analyzer, [`Severity::Warning`] by default
+    pub fn severity(&self, analyzer_name: &str) -> Severity {
+        self.severities
+            .get(analyzer_name)
+            .copied()
+            .unwrap_or_default()
+    }
+
     /// Calculate total issues across all analyzers.
     ///
     /// # Returns
@@ -312,6 +396,99 @@ impl Report {
     pub fn total_fixable(&self) -> usize {
         self.results.iter().map(|(_, r)| r.fixable_count).sum()
     }
+
+    /// Calculate total issues reported with [`Severity::Error`].
+    ///
+    /// # Returns
+    ///
+    /// Sum of error issues
+    pub fn total_errors(&self) -> usize {
+        self.results
+            .iter()
+            .filter(|(name, _)| self.severity(name) == Severity::Error)
+            .map(|(_, r)| r.issues.len())
+            .sum()
+    }
+
+    /// Render every issue as a single-line JSON object.
+    ///
+    /// Each line holds the file, analyzer, severity, position, message and
+    /// whether a fix is available, so that results can be streamed one issue
+    /// at a time.
+    ///
+    /// # Returns
+    ///
+    /// One JSON object per issue, each terminated by a newline
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cargo_quality::{
+    ///     analyzer::{AnalysisResult, Fix, Issue},
+    ///     report::Report
+    /// };
+    ///
+    /// let mut report = Report::new("src/lib.rs".to_string());
+    /// report.add_result(
+    ///     "path_import".to_string(),
+    ///     AnalysisResult {
+    ///         issues:        vec![Issue {
+    ///             line:    3,
+    ///             column:  4,
+    ///             message: "Use import".to_string(),
+    ///             fix:     Fix::None
+    ///         }],
+    ///         fixable_count: 0
+    ///     }
+    /// );
+    /// assert_eq!(
+    ///     report.to_json_lines(),
+    ///     "{\"file\":\"src/lib.rs\",\"analyzer\":\"path_import\",\"severity\":\"warning\",\
+    ///      \"line\":3,\"column\":4,\"message\":\"Use import\",\"fixable\":false}\n"
+    /// );
+    /// ```
+    pub fn to_json_lines(&self) -> String {
+        let mut output = String::new();
+
+        for (analyzer_name, result) in &self.results {
+            for issue in &result.issues {
+                output.push_str(&format!(
+                    "{{\"file\":{},\"analyzer\":{},\"severity\":\"{}\",\"line\":{},\
+                     \"column\":{},\"message\":{},\"fixable\":{}}}\n",
+                    json_string(&self.file_path),
+                    json_string(analyzer_name),
+                    self.severity(analyzer_name),
+                    issue.line,
+                    issue.column,
+                    json_string(&issue.message),
+                    issue.fix.is_available()
+                ));
+            }
+        }
+
+        output
+    }
+}
+
+/// Quote and escape a string as a JSON string literal.
+pub(crate) fn json_string(value: &str) -> String {
+    let mut output = String::with_capacity(value.len() + 2);
+    output.push('"');
+
+    for c in value.chars() {
+        match c {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            '\n' => output.push_str("\\n"),
+            '\r' => output.push_str("\\r"),
+            '\t' => output.push_str("\\t"),
+            c if c.is_control() => output.push_str(&format!("\\u{:04x}", c as u32)),
+            c => output.push(c)
+        }
+    }
+
+    output.push('"');
+    output
 }
 
 impl fmt::Display for Report {
@@ -324,7 +501,10 @@ impl fmt::Display for Report {
                 continue;
             }
 
-            writeln!(f, "\n[{}]", analyzer_name)?;
+            match self.severity(analyzer_name) {
+                Severity::Error => writeln!(f, "\n[{}] (error)", analyzer_name)?,
+                Severity::Warning => writeln!(f, "\n[{}]", analyzer_name)?
+            }
             for issue in &result.issues {
                 write!(f, "  {}:{} - {}", issue.line, issue.column, issue.message)?;
                 if issue.fix.is_available() {
@@ -332,7 +512,11 @@ impl fmt::Display for Report {
                         write!(f, "\n    Fix: Add import: {}", import)?;
                         write!(f, "\n    (Will replace path with short name)")?;
                     } else if let Some(simple) = issue.fix.as_simple() {
-                        write!(f, "\n    Fix: {}", simple)?;
+                        if simple.is_empty() {
+                            write!(f, "\n    Fix: remove")?;
+                        } else {
+                            write!(f, "\n    Fix: {}", simple)?;
+                        }
                     }
                 }
                 writeln!(f)?;
@@ -341,6 +525,9 @@ impl fmt::Display for Report {
 
         writeln!(f, "\nTotal issues: {}", self.total_issues())?;
         writeln!(f, "Fixable: {}", self.total_fixable())?;
+        if self.total_errors() > 0 {
+            writeln!(f, "Errors: {}", self.total_errors())?;
+        }
 
         Ok(())
     }
@@ -349,6 +536,8 @@ impl fmt::Display for Report {
 /// Global report aggregator across multiple files.
 ///
 /// Collects reports from multiple files and provides globally grouped output.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
 pub struct GlobalReport {
     /// Collection of per-file reports
     pub reports: Vec<Report>
@@ -367,6 +556,41 @@ impl GlobalReport {
         self.reports.push(report);
     }
 
+    /// Make the report independent of the platform and the run order.
+    ///
+    /// File paths become relative to the working directory with `/`
+    /// separators, and files, analyzers and issues are sorted, so the same
+    /// code renders the same report on every machine. Used by
+    /// `--deterministic`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cargo_quality::{
+    ///     analyzer::AnalysisResult,
+    ///     report::{GlobalReport, Report}
+    /// };
+    ///
+    /// let mut global = GlobalReport::new();
+    /// global.add_report(Report::new("./src\\main.rs".to_string()));
+    /// global.add_report(Report::new("./src/lib.rs".to_string()));
+    /// global.make_deterministic();
+    ///
+    /// assert_eq!(global.reports[0].file_path, "src/lib.rs");
+    /// ```
+    pub fn make_deterministic(&mut self) {
+        for report in &mut self.reports {
+            report.file_path = normalize_path(&report.file_path.replace('\\', "/"));
+            report.results.sort_by(|(a, _), (b, _)| a.cmp(b));
+            for (_, result) in &mut report.results {
+                result.issues.sort_by(|a, b| {
+                    (a.line, a.column, &a.message).cmp(&(b.line, b.column, &b.message))
+                });
+            }
+        }
+        self.reports.sort_by(|a, b| a.file_path.cmp(&b.file_path));
+    }
+
     /// Calculate total issues across all files.
     pub fn total_issues(&self) -> usize {
         self.reports.iter().map(|r| r.total_issues()).sum()
@@ -377,6 +601,38 @@ impl GlobalReport {
         self.reports.iter().map(|r| r.total_fixable()).sum()
     }
 
+    /// Calculate total error-severity issues across all files.
+    pub fn total_errors(&self) -> usize {
+        self.reports.iter().map(|r| r.total_errors()).sum()
+    }
+
+    /// Get the severity of an analyzer's issues across all files.
+    fn severity(&self, analyzer_name: &str) -> Severity {
+        self.reports
+            .iter()
+            .map(|r| r.severity(analyzer_name))
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// Append the error count line when any error-severity issues exist.
+    fn push_errors(&self, output: &mut String, color: bool) {
+        let errors = self.total_errors();
+        if errors == 0 {
+            return;
+        }
+
+        if color {
+            output.push_str(&format!(
+                "{}: {}\n",
+                "Errors".red().bold(),
+                errors.to_string().red().bold()
+            ));
+        } else {
+            output.push_str(&format!("Errors: {}\n", errors));
+        }
+    }
+
     /// Display summary only (total issues and fixable count).
     pub fn display_compact(&self, color: bool) -> String {
         let mut output = String::new();
@@ -396,16 +652,29 @@ impl GlobalReport {
             output.push_str(&format!("Total issues: {}\n", self.total_issues()));
             output.push_str(&format!("Fixable: {}\n", self.total_fixable()));
         }
+        self.push_errors(&mut output, color);
 
         output
     }
 
     /// Display details for a specific analyzer only.
-    pub fn display_analyzer(&self, analyzer_name: &str, color: bool) -> String {
+    ///
+    /// # Arguments
+    ///
+    /// * `analyzer_name` - Analyzer whose issues are shown
+    /// * `color` - Enable colored output
+    /// * `width` - Terminal width override; detected from `COLUMNS` or the
+    ///   terminal when `None`
+    pub fn display_analyzer(
+        &self,
+        analyzer_name: &str,
+        color: bool,
+        width: Option<usize>
+    ) -> String {
         type FileLines = Vec<(String, Vec<usize>)>;
-        type MessageGroups = HashMap<String, FileLines>;
+        type MessageGroups = BTreeMap<String, FileLines>;
 
-        let mut message_map: MessageGroups = HashMap::new();
+        let mut message_map: MessageGroups = BTreeMap::new();
 
         for report in &self.reports {
             for (name, result) in &report.results {
@@ -431,7 +700,14 @@ impl GlobalReport {
             return String::new();
         }
 
-        let rendered = render_analyzer_block(analyzer_name, &message_map, color);
+        let rendered = render_analyzer_block(
+            analyzer_name,
+            self.severity(analyzer_name),
+            &message_map,
+            color,
+            terminal_width(width, DEFAULT_TERMINAL_WIDTH),
+            false
+        );
         let mut output = String::new();
 
         for line in &rendered.lines {
@@ -456,6 +732,69 @@ impl GlobalReport {
             output.push_str(&format!("Total issues: {}\n", self.total_issues()));
             output.push_str(&format!("Fixable: {}\n", self.total_fixable()));
         }
+        self.push_errors(&mut output, color);
+
+        output
+    }
+
+    /// Display one analyzer's issues as a list grouped by file.
+    ///
+    /// Each issue is shown with its line and the first line of its message,
+    /// e.g. to list the TODO markers left in a project.
+    ///
+    /// # Arguments
+    ///
+    /// * `analyzer_name` - Analyzer whose issues are listed
+    /// * `title` - Section heading
+    /// * `color` - Enable colored output
+    ///
+    /// # Returns
+    ///
+    /// Rendered section, or an empty string if the analyzer found nothing
+    pub fn display_by_file(&self, analyzer_name: &str, title: &str, color: bool) -> String {
+        let mut files: Vec<(&str, Vec<(usize, &str)>)> = self
+            .reports
+            .iter()
+            .map(|report| {
+                let entries = report
+                    .results
+                    .iter()
+                    .filter(|(name, _)| name == analyzer_name)
+                    .flat_map(|(_, result)| &result.issues)
+                    .map(|issue| (issue.line, issue.message.lines().next().unwrap_or_default()))
+                    .collect();
+                (report.file_path.as_str(), entries)
+            })
+            .filter(|(_, entries): &(&str, Vec<_>)| !entries.is_empty())
+            .collect();
+        if files.is_empty() {
+            return String::new();
+        }
+        files.sort_by_key(|(path, _)| *path);
+
+        let total: usize = files.iter().map(|(_, entries)| entries.len()).sum();
+        let mut output = if color {
+            format!("{} ({})\n", title.yellow().bold(), total.to_string().cyan())
+        } else {
+            format!("{} ({})\n", title, total)
+        };
+
+        for (path, mut entries) in files {
+            entries.sort_by_key(|(line, _)| *line);
+            if color {
+                output.push_str(&format!("  {}\n", path.blue()));
+            } else {
+                output.push_str(&format!("  {}\n", path));
+            }
+            for (line, text) in entries {
+                if color {
+                    output.push_str(&format!("    {:>5}  {}\n", line.magenta(), text));
+                } else {
+                    output.push_str(&format!("    {:>5}  {}\n", line, text));
+                }
+            }
+        }
+        output.push('\n');
 
         output
     }
@@ -463,10 +802,24 @@ impl GlobalReport {
     /// Display detailed report with grid layout (verbose mode).
     ///
     /// Groups issues by analyzer and message across all files,
-    /// then shows which files have each issue in grid layout.
-    pub fn display_verbose(&self, color: bool) -> String {
+    /// then shows which files have each issue in grid layout. Below 60
+    /// columns the analyzers are stacked in a single column.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - Enable colored output
+    /// * `width` - Terminal width override; detected from `COLUMNS` or the
+    ///   terminal when `None`
+    /// * `collapsed` - Analyzers whose block only counts their issues, e.g. a
+    ///   noisy `empty_lines`
+    pub fn display_verbose(
+        &self,
+        color: bool,
+        width: Option<usize>,
+        collapsed: &BTreeSet<String>
+    ) -> String {
         type FileLines = Vec<(String, Vec<usize>)>;
-        type MessageGroups = HashMap<String, FileLines>;
+        type MessageGroups = BTreeMap<String, FileLines>;
         type AnalyzerGroups = HashMap<String, MessageGroups>;
 
         let mut analyzer_groups: AnalyzerGroups = HashMap::new();
@@ -496,19 +849,27 @@ impl GlobalReport {
         let mut analyzer_names: Vec<_> = analyzer_groups.keys().cloned().collect();
         analyzer_names.sort();
 
+        let term_width = terminal_width(width, DEFAULT_TERMINAL_WIDTH);
         let rendered_analyzers: Vec<RenderedAnalyzer> = analyzer_names
             .iter()
             .map(|name| {
                 let message_map = &analyzer_groups[name];
-                render_analyzer_block(name, message_map, color)
+                render_analyzer_block(
+                    name,
+                    self.severity(name),
+                    message_map,
+                    color,
+                    term_width,
+                    collapsed.contains(name)
+                )
             })
             .collect();
 
-        let term_width = terminal_size()
-            .map(|(Width(w), _)| w as usize)
-            .unwrap_or(170);
-
-        let columns = calculate_columns(&rendered_analyzers, term_width);
+        let columns = if is_narrow(term_width) {
+            1
+        } else {
+            calculate_columns(&rendered_analyzers, term_width)
+        };
 
         let mut output = render_grid(&rendered_analyzers, columns);
 
@@ -527,11 +888,194 @@ impl GlobalReport {
             output.push_str(&format!("\nTotal issues: {}\n", self.total_issues()));
             output.push_str(&format!("Fixable: {}\n", self.total_fixable()));
         }
+        self.push_errors(&mut output, color);
+
+        output
+    }
+
+    /// Display a markdown report, e.g. for PR comments.
+    ///
+    /// Lists the issues of each file in a table sorted by line. With source
+    /// links, every line number links to the offending line on the source
+    /// host.
+    ///
+    /// # Arguments
+    ///
+    /// * `links` - Source hosting links for the checkout, if known
+    ///
+    /// # Returns
+    ///
+    /// Markdown document with a summary and one table per file
+    pub fn display_markdown(&self, links: Option<&SourceLinks>) -> String {
+        let mut output = format!(
+            "## Quality Report\n\n**{} issues**, {} fixable\n",
+            self.total_issues(),
+            self.total_fixable()
+        );
+
+        for report in self.reports.iter().filter(|r| r.total_issues() > 0) {
+            let mut issues: Vec<_> = report
+                .results
+                .iter()
+                .flat_map(|(name, result)| result.issues.iter().map(move |issue| (name, issue)))
+                .collect();
+            issues.sort_by_key(|(_, issue)| (issue.line, issue.column));
+
+            output.push_str(&format!(
+                "\n### `{}`\n\n| Line | Rule | Message |\n|-----:|------|---------|\n",
+                report.file_path
+            ));
+            for (name, issue) in issues {
+                let line = match links.and_then(|links| links.url(&report.file_path, issue.line)) {
+                    Some(url) => format!("[{}]({url})", issue.line),
+                    None => issue.line.to_string()
+                };
+                output.push_str(&format!(
+                    "| {line} | `{name}` | {} |\n",
+                    issue.message.replace('|', "\\|").replace('\n', "<br>")
+                ));
+            }
+        }
+
+        output
+    }
+
+    /// Display GitHub Actions workflow commands, one annotation per issue.
+    ///
+    /// Each issue becomes a `::warning` or `::error` command that GitHub
+    /// shows on the offending line of the pull request. Fixable issues carry
+    /// a preview of the replacement, truncated to a few lines, so the
+    /// annotation says what to change without running the tool locally.
+    ///
+    /// # Returns
+    ///
+    /// One workflow command per issue, sorted by file and position
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cargo_quality::{
+    ///     analyzer::{AnalysisResult, Fix, Issue},
+    ///     report::{GlobalReport, Report}
+    /// };
+    ///
+    /// let mut report = Report::new("src/lib.rs".to_string());
+    /// report.add_result(
+    ///     "format_args".to_string(),
+    ///     AnalysisResult {
+    ///         issues:        vec![Issue {
+    ///             line:    3,
+    ///             column:  4,
+    ///             message: "Use inline format args".to_string(),
+    ///             fix:     Fix::Simple("println!(\"{x}\")".to_string())
+    ///         }],
+    ///         fixable_count: 1
+    ///     }
+    /// );
+    /// let mut global = GlobalReport::new();
+    /// global.add_report(report);
+    ///
+    /// assert_eq!(
+    ///     global.display_github(),
+    ///     "::warning file=src/lib.rs,line=3,col=5,title=format_args::Use inline format \
+    ///      args%0A%0ASuggested fix:%0Aprintln!(\"{x}\")\n"
+    /// );
+    /// ```
+    pub fn display_github(&self) -> String {
+        let mut issues: Vec<_> = self
+            .reports
+            .iter()
+            .flat_map(|report| {
+                report.results.iter().flat_map(move |(name, result)| {
+                    result.issues.iter().map(move |issue| (report, name, issue))
+                })
+            })
+            .collect();
+        issues.sort_by(|(a, a_name, a_issue), (b, b_name, b_issue)| {
+            (&a.file_path, a_issue.line, a_issue.column, a_name).cmp(&(
+                &b.file_path,
+                b_issue.line,
+                b_issue.column,
+                b_name
+            ))
+        });
+
+        let mut output = String::new();
+        for (report, name, issue) in issues {
+            let mut message = issue.message.clone();
+            if let Some(preview) = fix_preview(&issue.fix) {
+                message.push_str("\n\nSuggested fix:\n");
+                message.push_str(&preview);
+            }
+
+            output.push_str(&format!(
+                "::{} file={},line={},col={},title={}::{}\n",
+                report.severity(name),
+                escape_property(&normalize_path(&report.file_path)),
+                issue.line,
+                issue.column + 1,
+                escape_property(name),
+                escape_data(&message)
+            ));
+        }
 
         output
     }
 }
 
+/// Describe the change a fix makes, shortened for an annotation.
+///
+/// Long replacements are cut at a character boundary after a few lines,
+/// with `...` marking the cut.
+///
+/// # Arguments
+///
+/// * `fix` - Fix of an issue
+///
+/// # Returns
+///
+/// Preview of the replacement, `None` if the issue has no fix
+fn fix_preview(fix: &Fix) -> Option<String> {
+    let preview = match fix {
+        Fix::Simple(replacement) if replacement.is_empty() => {
+            "remove the flagged code".to_string()
+        }
+        Fix::Simple(replacement) => replacement.clone(),
+        Fix::WithImport {
+            import,
+            pattern,
+            replacement
+        } => format!("{import}\n{pattern} -> {replacement}"),
+        _ => return None
+    };
+
+    let mut truncated: String = preview
+        .lines()
+        .take(PREVIEW_MAX_LINES)
+        .collect::<Vec<_>>()
+        .join("\n");
+    if let Some((end, _)) = truncated.char_indices().nth(PREVIEW_MAX_CHARS) {
+        truncated.truncate(end);
+    }
+    if truncated.len() < preview.trim_end().len() {
+        truncated.push_str("\n...");
+    }
+    Some(truncated)
+}
+
+/// Escape the message of a workflow command.
+fn escape_data(value: &str) -> String {
+    value
+        .replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+/// Escape a property value of a workflow command.
+fn escape_property(value: &str) -> String {
+    escape_data(value).replace(':', "%3A").replace(',', "%2C")
+}
+
 impl Default for GlobalReport {
     fn default() -> Self {
         Self::new()
@@ -543,6 +1087,314 @@ mod tests {
     use super::*;
     use crate::analyzer::Issue;
 
+    #[test]
+    fn test_display_markdown() {
+        let issue = |line: usize, message: &str| Issue {
+            line,
+            column: 0,
+            message: message.to_string(),
+            fix: crate::analyzer::Fix::None
+        };
+
+        let mut global = GlobalReport::new();
+        let mut report = Report::new("/repo/src/lib.rs".to_string());
+        report.add_result(
+            "unwrap".to_string(),
+            AnalysisResult {
+                issues:        vec![issue(9, "Avoid unwrap\nUse `?`")],
+                fixable_count: 0
+            }
+        );
+        report.add_result(
+            "path_import".to_string(),
+            AnalysisResult {
+                issues:        vec![issue(2, "a | b")],
+                fixable_count: 1
+            }
+        );
+        global.add_report(report);
+        global.add_report(Report::new("/repo/src/clean.rs".to_string()));
+
+        let table = "| Line | Rule | Message |\n|-----:|------|---------|\n";
+        assert_eq!(
+            global.display_markdown(None),
+            format!(
+                "## Quality Report\n\n**2 issues**, 1 fixable\n\n### `/repo/src/lib.rs`\n\n\
+                 {table}| 2 | `path_import` | a \\| b |\n\
+                 | 9 | `unwrap` | Avoid unwrap<br>Use `?` |\n"
+            )
+        );
+
+        let links = SourceLinks::new("https://github.com/org/repo", "abc", "/repo");
+        let markdown = global.display_markdown(Some(&links));
+        assert!(
+            markdown.contains(
+                "| [9](https://github.com/org/repo/blob/abc/src/lib.rs#L9) | `unwrap` |"
+            )
+        );
+        assert!(!markdown.contains("clean.rs"));
+    }
+
+    #[test]
+    fn test_display_github() {
+        let issue = |line: usize, message: &str, fix: Fix| Issue {
+            line,
+            column: 0,
+            message: message.to_string(),
+            fix
+        };
+
+        let mut report = Report::new("./src/a,b.rs".to_string());
+        report.add_result_with_severity(
+            "hardcoded_secrets".to_string(),
+            Severity::Error,
+            AnalysisResult {
+                issues:        vec![issue(7, "Secret: 100%", Fix::None)],
+                fixable_count: 0
+            }
+        );
+        report.add_result(
+            "path_import".to_string(),
+            AnalysisResult {
+                issues:        vec![issue(
+                    2,
+                    "Use import",
+                    Fix::WithImport {
+                        import:      "use std::fs::read;".to_string(),
+                        pattern:     "std::fs::read".to_string(),
+                        replacement: "read".to_string()
+                    }
+                )],
+                fixable_count: 1
+            }
+        );
+        let mut global = GlobalReport::new();
+        global.add_report(report);
+
+        assert_eq!(
+            global.display_github(),
+            "::warning file=src/a%2Cb.rs,line=2,col=1,title=path_import::Use import%0A%0A\
+             Suggested fix:%0Ause std::fs::read;%0Astd::fs::read -> read\n\
+             ::error file=src/a%2Cb.rs,line=7,col=1,title=hardcoded_secrets::Secret: 100%25\n"
+        );
+    }
+
+    #[test]
+    fn test_fix_preview_truncated() {
+        assert_eq!(
+            fix_preview(&Fix::Simple(String::new())).as_deref(),
+            Some("remove the flagged code")
+        );
+        assert_eq!(fix_preview(&Fix::None), None);
+
+        let long = "é".repeat(PREVIEW_MAX_CHARS + 10);
+        let preview = fix_preview(&Fix::Simple(long)).unwrap();
+        assert!(preview.ends_with("\n..."));
+        assert_eq!(preview.chars().count(), PREVIEW_MAX_CHARS + 4);
+
+        let many_lines = "line\n".repeat(PREVIEW_MAX_LINES + 2);
+        let preview = fix_preview(&Fix::Simple(many_lines)).unwrap();
+        assert_eq!(preview.lines().count(), PREVIEW_MAX_LINES + 1);
+
+        let exact = "line\n".repeat(PREVIEW_MAX_LINES);
+        assert!(!fix_preview(&Fix::Simple(exact)).unwrap().ends_with("..."));
+    }
+
+    fn wide_report() -> GlobalReport {
+        let mut global = GlobalReport::new();
+        for (file, analyzer) in [
+            ("src/alpha.rs", "path_import"),
+            ("src/beta.rs", "format_args")
+        ] {
+            let mut report = Report::new(file.to_string());
+            report.add_result(
+                analyzer.to_string(),
+                AnalysisResult {
+                    issues:        vec![Issue {
+                        line:    7,
+                        column:  1,
+                        message: "A message long enough to overflow a narrow and a medium terminal window"
+                            .to_string(),
+                        fix:     Fix::None
+                    }],
+                    fixable_count: 0
+                }
+            );
+            global.add_report(report);
+        }
+        global
+    }
+
+    #[test]
+    fn test_display_verbose_grid_width() {
+        let global = wide_report();
+
+        let wide = global.display_verbose(false, Some(200), &BTreeSet::new());
+        assert!(
+            wide.lines()
+                .any(|line| { line.contains("[format_args]") && line.contains("[path_import]") })
+        );
+
+        let medium = global.display_verbose(false, Some(80), &BTreeSet::new());
+        assert!(
+            !medium
+                .lines()
+                .any(|line| line.contains("[format_args]") && line.contains("[path_import]"))
+        );
+        assert!(medium.lines().all(|line| measure_text_width(line) <= 80));
+    }
+
+    #[test]
+    fn test_display_verbose_narrow_wraps_lines() {
+        let global = wide_report();
+
+        for color in [false, true] {
+            let output = global.display_verbose(color, Some(30), &BTreeSet::new());
+            assert!(output.lines().all(|line| measure_text_width(line) <= 30));
+            assert!(!output.contains("..."));
+        }
+
+        let plain = global.display_verbose(false, Some(30), &BTreeSet::new());
+        assert!(plain.contains(&"─".repeat(30)));
+        assert!(
+            plain
+                .replace('\n', "")
+                .contains("overflow a narrow and a medium terminal window")
+        );
+        assert!(plain.contains("src/alpha.rs"));
+    }
+
+    #[test]
+    fn test_display_verbose_collapsed_counts_only() {
+        let global = wide_report();
+        let collapsed = BTreeSet::from(["path_import".to_string()]);
+        let output = global.display_verbose(false, Some(200), &collapsed);
+
+        assert!(output.contains("[path_import] - 1 issues"));
+        assert!(output.contains("1 files, 1 distinct messages (collapsed)"));
+        assert!(!output.contains("src/alpha.rs"));
+        assert!(output.contains("src/beta.rs"));
+    }
+
+    #[test]
+    fn test_display_analyzer_truncates_to_width() {
+        let global = wide_report();
+        let output = global.display_analyzer("path_import", true, Some(60));
+
+        assert!(output.lines().all(|line| measure_text_width(line) <= 60));
+        assert!(output.contains("and a medium..."));
+    }
+
+    #[test]
+    fn test_display_by_file() {
+        let marker = |line: usize, message: &str| Issue {
+            line,
+            column: 0,
+            message: message.to_string(),
+            fix: crate::analyzer::Fix::None
+        };
+
+        let mut global = GlobalReport::new();
+        for (path, issues) in [
+            ("src/b.rs", vec![marker(9, "FIXME: overflow\nResolve it")]),
+            (
+                "src/a.rs",
+                vec![marker(30, "XXX"), marker(4, "TODO: retry\nResolve it")]
+            ),
+            ("src/c.rs", Vec::new())
+        ] {
+            let mut report = Report::new(path.to_string());
+            report.add_result(
+                "todo_comments".to_string(),
+                AnalysisResult {
+                    issues,
+                    fixable_count: 0
+                }
+            );
+            global.add_report(report);
+        }
+
+        assert_eq!(
+            global.display_by_file("todo_comments", "TODO comments", false),
+            "TODO comments (3)\n  src/a.rs\n        4  TODO: retry\n       30  XXX\n  \
+             src/b.rs\n        9  FIXME: overflow\n\n"
+        );
+        assert!(
+            global
+                .display_by_file("unwrap", "Unwraps", false)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_report_json_lines() {
+        let mut report = Report::new("src/\"odd\".rs".to_string());
+        report.add_result_with_severity(
+            "hardcoded_secrets".to_string(),
+            Severity::Error,
+            AnalysisResult {
+                issues:        vec![
+                    Issue {
+                        line:    1,
+                        column:  0,
+                        message: "Secret\n\tRemove it\u{1}".to_string(),
+                        fix:     crate::analyzer::Fix::Simple(String::new())
+                    },
+                    Issue {
+                        line:    7,
+                        column:  2,
+                        message: "C:\\key".to_string(),
+                        fix:     crate::analyzer::Fix::None
+                    },
+                ],
+                fixable_count: 1
+            }
+        );
+
+        let output = report.to_json_lines();
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with(
+            r#"{"file":"src/\"odd\".rs","analyzer":"hardcoded_secrets","severity":"error","#
+        ));
+        assert!(lines[0].ends_with(
+            r#""line":1,"column":0,"message":"Secret\n\tRemove it\u0001","fixable":true}"#
+        ));
+        assert!(lines[1].contains(r#""line":7,"column":2,"message":"C:\\key","fixable":false"#));
+        assert!(Report::new("a.rs".to_string()).to_json_lines().is_empty());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_global_report_serde_round_trip() {
+        let mut report = Report::new("src/lib.rs".to_string());
+        report.add_result_with_severity(
+            "hardcoded_secrets".to_string(),
+            Severity::Error,
+            AnalysisResult {
+                issues:        vec![Issue {
+                    line:    3,
+                    column:  8,
+                    message: "Secret".to_string(),
+                    fix:     crate::analyzer::Fix::Simple(String::new())
+                }],
+                fixable_count: 1
+            }
+        );
+        let mut global = GlobalReport::new();
+        global.add_report(report);
+
+        let json = serde_json::to_string(&global).unwrap();
+        assert!(json.contains(r#""severities":{"hardcoded_secrets":"error"}"#));
+
+        let restored: GlobalReport = serde_json::from_str(&json).unwrap();
+        let report = &restored.reports[0];
+        assert_eq!(report.file_path, "src/lib.rs");
+        assert_eq!(report.severity("hardcoded_secrets"), Severity::Error);
+        assert_eq!(report.results[0].1.issues[0].line, 3);
+        assert_eq!(report.total_fixable(), 1);
+    }
+
     #[test]
     fn test_report_creation() {
         let report = Report::new("test.rs".to_string());
@@ -610,6 +1462,118 @@ mod tests {
         assert!(output.contains("Fixable: 1"));
     }
 
+    #[test]
+    fn test_make_deterministic() {
+        let issue = |line: usize, message: &str| Issue {
+            line,
+            column: 1,
+            message: message.to_string(),
+            fix: Fix::None
+        };
+        let mut main = Report::new("./src/main.rs".to_string());
+        main.add_result(
+            "unwrap".to_string(),
+            AnalysisResult {
+                issues:        vec![issue(9, "Avoid unwrap"), issue(2, "Avoid unwrap")],
+                fixable_count: 0
+            }
+        );
+        main.add_result(
+            "empty_lines".to_string(),
+            AnalysisResult {
+                issues:        vec![issue(4, "Empty line")],
+                fixable_count: 0
+            }
+        );
+        let mut global = GlobalReport::new();
+        global.add_report(main);
+        global.add_report(Report::new("./src\\lib.rs".to_string()));
+
+        global.make_deterministic();
+
+        assert_eq!(global.reports[0].file_path, "src/lib.rs");
+        let main = &global.reports[1];
+        assert_eq!(main.file_path, "src/main.rs");
+        assert_eq!(main.results[0].0, "empty_lines");
+        let lines: Vec<usize> = main.results[1].1.issues.iter().map(|i| i.line).collect();
+        assert_eq!(lines, [2, 9]);
+    }
+
+    #[test]
+    fn test_verbose_messages_sorted() {
+        let mut report = Report::new("src/lib.rs".to_string());
+        let issues = ["Zeta message", "Alpha message", "Mid message"]
+            .iter()
+            .enumerate()
+            .map(|(line, message)| Issue {
+                line:    line + 1,
+                column:  1,
+                message: message.to_string(),
+                fix:     Fix::None
+            })
+            .collect();
+        report.add_result(
+            "style".to_string(),
+            AnalysisResult {
+                issues,
+                fixable_count: 0
+            }
+        );
+        let mut global = GlobalReport::new();
+        global.add_report(report);
+
+        let output = global.display_verbose(false, Some(120), &BTreeSet::new());
+        let alpha = output.find("Alpha message").unwrap();
+        let mid = output.find("Mid message").unwrap();
+        let zeta = output.find("Zeta message").unwrap();
+        assert!(alpha < mid && mid < zeta);
+    }
+
+    #[test]
+    fn test_report_error_severity() {
+        let mut report = Report::new("test.rs".to_string());
+        let issue = Issue {
+            line:    3,
+            column:  1,
+            message: "Secret".to_string(),
+            fix:     crate::analyzer::Fix::None
+        };
+
+        report.add_result_with_severity(
+            "secrets".to_string(),
+            Severity::Error,
+            AnalysisResult {
+                issues:        vec![issue.clone()],
+                fixable_count: 0
+            }
+        );
+        report.add_result(
+            "style".to_string(),
+            AnalysisResult {
+                issues:        vec![issue],
+                fixable_count: 0
+            }
+        );
+
+        assert_eq!(report.severity("secrets"), Severity::Error);
+        assert_eq!(report.severity("style"), Severity::Warning);
+        assert_eq!(report.total_errors(), 1);
+
+        let output = format!("{}", report);
+        assert!(output.contains("[secrets] (error)"));
+        assert!(output.contains("Errors: 1"));
+
+        let mut global = GlobalReport::new();
+        global.add_report(report);
+        assert_eq!(global.total_errors(), 1);
+        assert!(global.display_compact(false).contains("Errors: 1"));
+        assert!(
+            global
+                .display_analyzer("secrets", false, None)
+                .contains("[secrets] - 1 errors")
+        );
+    }
+
     #[test]
     fn test_report_display_without_issues() {
         let mut report = Report::new("test.rs".to_string());
@@ -0,0 +1,339 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Rule documentation generated from analyzer metadata.
+//!
+//! Each page combines what an analyzer reports about itself (severity,
+//! opt-in, auto-fix support, configuration keys) with its section of the
+//! README, which holds the description and the bad/good examples. Both come
+//! from the binary that runs the checks, so exported pages stay in sync with
+//! the code.
+
+use std::{fs, path::Path};
+
+use masterror::AppResult;
+
+use crate::{
+    analyzer::{Analyzer, Confidence, Severity},
+    analyzers::get_analyzers,
+    error::IoError,
+    suppression::ALLOW_ATTRIBUTE
+};
+
+/// README the rule descriptions are taken from.
+const README: &str = include_str!("../README.md");
+
+/// Heading of the README list mapping rule names to section titles.
+const RULE_LIST_HEADING: &str = "## Available Analyzers";
+
+/// Heading of the README chapter holding one section per rule.
+const RULE_SECTIONS_HEADING: &str = "## Analyzers";
+
+/// File name of the generated index page.
+pub const INDEX_FILE: &str = "README.md";
+
+/// Documentation of a single rule.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleDoc {
+    /// Analyzer name, as used by `--analyzer` and `#[quality::allow]`
+    pub name:       &'static str,
+    /// Human-readable title, e.g. "Unwrap Analyzer"
+    pub title:      String,
+    /// Severity of the reported issues
+    pub severity:   Severity,
+    /// Confidence that the reported issues are real problems
+    pub confidence: Confidence,
+    /// Whether the rule only runs when enabled
+    pub opt_in:     bool,
+    /// Whether `fix` resolves the reported issues
+    pub fixable:    bool,
+    /// Keys of `[package.metadata.quality]` configuring the rule
+    pub options:    &'static [&'static str],
+    /// Description and examples in markdown
+    pub body:       String
+}
+
+impl RuleDoc {
+    /// Builds the documentation of an analyzer.
+    ///
+    /// # Arguments
+    ///
+    /// * `analyzer` - Analyzer to document
+    ///
+    /// # Returns
+    ///
+    /// Rule documentation, with an empty title and body if the README has no
+    /// section for the analyzer
+    pub fn from_analyzer(analyzer: &dyn Analyzer) -> Self {
+        let name = analyzer.name();
+        let title = readme_title(name).unwrap_or_default();
+        let body = readme_section(&title)
+            .map(|section| link_rule_pages(&section))
+            .unwrap_or_default();
+
+        Self {
+            name,
+            title,
+            severity: analyzer.severity(),
+            confidence: analyzer.confidence(),
+            opt_in: analyzer.opt_in(),
+            fixable: analyzer.fixable(),
+            options: analyzer.options(),
+            body
+        }
+    }
+
+    /// File name of the rule's page.
+    ///
+    /// # Returns
+    ///
+    /// `"<name>.md"`
+    pub fn file_name(&self) -> String {
+        format!("{}.md", self.name)
+    }
+
+    /// Renders the rule's page.
+    ///
+    /// # Returns
+    ///
+    /// Markdown page with the rule's metadata, description and examples
+    pub fn to_markdown(&self) -> String {
+        let enabled = if self.opt_in {
+            format!("No, opt in with `enable = [\"{}\"]`", self.name)
+        } else {
+            "Yes".to_string()
+        };
+        let options = if self.options.is_empty() {
+            "None".to_string()
+        } else {
+            self.options
+                .iter()
+                .map(|key| format!("`{key}`"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+
+        format!(
+            "# `{name}` - {title}\n\n\
+             | Property | Value |\n\
+             |----------|-------|\n\
+             | Severity | {severity} |\n\
+             | Confidence | {confidence} |\n\
+             | Enabled by default | {enabled} |\n\
+             | Auto-fix | {fix} |\n\
+             | Options in `[package.metadata.quality]` | {options} |\n\n\
+             {body}\n\n\
+             ## Suppressing\n\n\
+             ```rust\n#[{ALLOW_ATTRIBUTE}({name})]\n```\n",
+            name = self.name,
+            title = self.title,
+            severity = self.severity,
+            confidence = self.confidence,
+            fix = if self.fixable { "Yes" } else { "No" },
+            body = self.body
+        )
+    }
+}
+
+/// Documents all built-in analyzers.
+///
+/// # Returns
+///
+/// Documentation of each rule, in the order analyzers run
+///
+/// # Examples
+///
+/// ```
+/// use cargo_quality::rules::rule_docs;
+///
+/// let docs = rule_docs();
+/// let unwrap = docs.iter().find(|doc| doc.name == "unwrap").unwrap();
+/// assert_eq!(unwrap.title, "Unwrap Analyzer");
+/// assert!(unwrap.to_markdown().contains("Bad:"));
+/// ```
+pub fn rule_docs() -> Vec<RuleDoc> {
+    get_analyzers()
+        .iter()
+        .map(|analyzer| RuleDoc::from_analyzer(analyzer.as_ref()))
+        .collect()
+}
+
+/// Renders the index page linking every rule.
+///
+/// # Arguments
+///
+/// * `docs` - Documented rules
+///
+/// # Returns
+///
+/// Markdown table of the rules with their metadata
+pub fn index_markdown(docs: &[RuleDoc]) -> String {
+    let mut index = String::from(
+        "# Rules\n\n\
+         | Rule | Title | Severity | Confidence | Default | Auto-fix |\n\
+         |------|-------|----------|------------|---------|----------|\n"
+    );
+    for doc in docs {
+        index.push_str(&format!(
+            "| [`{}`]({}) | {} | {} | {} | {} | {} |\n",
+            doc.name,
+            doc.file_name(),
+            doc.title,
+            doc.severity,
+            doc.confidence,
+            if doc.opt_in { "opt-in" } else { "on" },
+            if doc.fixable { "yes" } else { "no" }
+        ));
+    }
+    index
+}
+
+/// Writes one markdown page per rule plus an index into a directory.
+///
+/// The directory is created if missing; existing pages are overwritten.
+///
+/// # Arguments
+///
+/// * `dir` - Output directory
+///
+/// # Returns
+///
+/// `AppResult<usize>` - Number of rule pages written, or IO error
+pub fn export_markdown(dir: &Path) -> AppResult<usize> {
+    let docs = rule_docs();
+    fs::create_dir_all(dir).map_err(IoError::from)?;
+
+    for doc in &docs {
+        fs::write(dir.join(doc.file_name()), doc.to_markdown()).map_err(IoError::from)?;
+    }
+    fs::write(dir.join(INDEX_FILE), index_markdown(&docs)).map_err(IoError::from)?;
+
+    Ok(docs.len())
+}
+
+/// Title of a rule from the README list of available analyzers.
+fn readme_title(name: &str) -> Option<String> {
+    let prefix = format!("- `{name}` - ");
+    chapter(RULE_LIST_HEADING)?
+        .lines()
+        .find_map(|line| line.strip_prefix(&prefix))
+        .map(|title| title.trim().to_string())
+}
+
+/// Body of a rule's README section, without its heading.
+fn readme_section(title: &str) -> Option<String> {
+    if title.is_empty() {
+        return None;
+    }
+
+    let chapter = chapter(RULE_SECTIONS_HEADING)?;
+    let heading = format!("### {title}\n");
+    let start = chapter.find(&heading)? + heading.len();
+    let rest = &chapter[start..];
+    let end = rest.find("\n### ").unwrap_or(rest.len());
+
+    Some(rest[..end].trim().to_string())
+}
+
+/// Text of a top-level README chapter, up to its "Back to top" link.
+fn chapter(heading: &str) -> Option<&'static str> {
+    let heading = format!("\n{heading}\n");
+    let start = README.find(&heading)? + heading.len();
+    let rest = &README[start..];
+    let end = rest.find("\n<div align=").unwrap_or(rest.len());
+
+    Some(&rest[..end])
+}
+
+/// Point README anchors of other rules to their generated pages.
+fn link_rule_pages(body: &str) -> String {
+    get_analyzers()
+        .iter()
+        .filter_map(|analyzer| {
+            readme_title(analyzer.name()).map(|title| (analyzer.name(), anchor(&title)))
+        })
+        .fold(body.to_string(), |body, (name, anchor)| {
+            body.replace(&format!("](#{anchor})"), &format!("]({name}.md)"))
+        })
+}
+
+/// GitHub anchor of a markdown heading.
+fn anchor(title: &str) -> String {
+    title
+        .to_lowercase()
+        .chars()
+        .filter_map(|c| match c {
+            ' ' => Some('-'),
+            c if c.is_alphanumeric() || c == '-' || c == '_' => Some(c),
+            _ => None
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+
+    #[test]
+    fn test_every_rule_is_documented() {
+        for doc in rule_docs() {
+            assert!(
+                !doc.title.is_empty(),
+                "`{}` missing from README list",
+                doc.name
+            );
+            assert!(
+                !doc.body.is_empty(),
+                "`{}` has no README section",
+                doc.title
+            );
+        }
+    }
+
+    #[test]
+    fn test_rule_page_contents() {
+        let docs = rule_docs();
+        let find = |name: &str| docs.iter().find(|doc| doc.name == name).unwrap();
+
+        let page = find("missing_debug").to_markdown();
+        assert!(page.starts_with("# `missing_debug` - Missing Debug Analyzer\n"));
+        assert!(page.contains("| Auto-fix | Yes |"));
+        assert!(page.contains("| Confidence | high |"));
+        assert!(page.contains("| Enabled by default | Yes |"));
+        assert!(page.contains("[Sensitive Debug Analyzer](sensitive_debug.md)"));
+        assert!(page.contains("#[quality::allow(missing_debug)]"));
+        assert!(!page.contains("### "));
+
+        let page = find("missing_instrument").to_markdown();
+        assert!(page.contains("opt in with `enable = [\"missing_instrument\"]`"));
+        assert!(page.contains("| Auto-fix | No |"));
+
+        let page = find("duplicate_literals").to_markdown();
+        assert!(page.contains("| Confidence | low |"));
+
+        let page = find("sensitive_debug").to_markdown();
+        assert!(page.contains("| `sensitive_fields`, `profile` |"));
+    }
+
+    #[test]
+    fn test_export_markdown() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path().join("docs/rules");
+
+        let written = export_markdown(&dir).unwrap();
+
+        assert_eq!(written, get_analyzers().len());
+        assert!(dir.join("unwrap.md").exists());
+        let index = fs::read_to_string(dir.join(INDEX_FILE)).unwrap();
+        assert!(index.contains("| [`unwrap`](unwrap.md) | Unwrap Analyzer |"));
+        assert_eq!(index.lines().count(), written + 4);
+    }
+
+    #[test]
+    fn test_anchor() {
+        assert_eq!(anchor("TODO Comments Analyzer"), "todo-comments-analyzer");
+        assert_eq!(anchor("Philosophy & Standards"), "philosophy--standards");
+    }
+}
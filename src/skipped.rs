@@ -0,0 +1,404 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Files left out of a run and why.
+//!
+//! A run silently analyzing fewer files than expected makes a clean report
+//! hard to trust. Every path a walk or a command leaves out is recorded here
+//! with its cause, so `check`, `fix` and `diff` can end with a summary such
+//! as `Skipped 3 paths: 2 ignored by .gitignore, 1 parse error` and list the
+//! paths with `--show-skipped`.
+
+use std::{
+    fmt, fs,
+    path::{Path, PathBuf}
+};
+
+use masterror::AppResult;
+use syn::File;
+
+use crate::error::IoError;
+
+/// Files larger than this are skipped unread, in bytes.
+///
+/// Hand-written Rust files stay far below it; larger ones are generated
+/// tables or bindings that take long to parse and cannot be fixed by hand.
+pub const MAX_FILE_SIZE: u64 = 1024 * 1024;
+
+/// Number of leading lines searched for a generated-file header.
+const GENERATED_HEADER_LINES: usize = 5;
+
+/// Header phrases of generated files, lowercase.
+const GENERATED_MARKERS: &[&str] = &["@generated", "do not edit", "automatically generated"];
+
+/// Why a path is left out of a run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SkipCause {
+    /// Hidden by `.gitignore`, `.ignore` or the global git excludes
+    Ignored,
+    /// Matched by a glob of `metadata.quality.exclude`
+    Excluded,
+    /// Not valid Rust syntax
+    ParseError,
+    /// Larger than [`MAX_FILE_SIZE`]
+    TooLarge,
+    /// Starts with a generated-file header such as `// @generated`
+    Generated
+}
+
+impl fmt::Display for SkipCause {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Ignored => "ignored by .gitignore",
+            Self::Excluded => "excluded by metadata.quality.exclude",
+            Self::ParseError => "parse error",
+            Self::TooLarge => "too large",
+            Self::Generated => "generated"
+        })
+    }
+}
+
+/// Path left out of a run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SkippedFile {
+    /// Skipped file, or directory for paths hidden by ignore files
+    pub path:   PathBuf,
+    /// Why it was skipped
+    pub cause:  SkipCause,
+    /// Details such as the parse error, if any
+    pub detail: Option<String>
+}
+
+/// Paths left out of a run, in the order they were skipped.
+///
+/// # Examples
+///
+/// ```
+/// use std::path::PathBuf;
+///
+/// use cargo_quality::skipped::{SkipCause, SkippedFiles};
+///
+/// let mut skipped = SkippedFiles::new();
+/// skipped.add(PathBuf::from("target"), SkipCause::Ignored, None);
+/// skipped.add(PathBuf::from("src/schema.rs"), SkipCause::Generated, None);
+///
+/// assert_eq!(
+///     skipped.summary(false).unwrap(),
+///     "Skipped 2 paths: 1 ignored by .gitignore, 1 generated (list them with --show-skipped)\n"
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SkippedFiles {
+    /// Skipped paths
+    pub files: Vec<SkippedFile>
+}
+
+impl SkippedFiles {
+    /// Create an empty list.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a skipped path.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Skipped file or directory
+    /// * `cause` - Why it was skipped
+    /// * `detail` - Details shown with `--show-skipped`
+    pub fn add(&mut self, path: PathBuf, cause: SkipCause, detail: Option<String>) {
+        self.files.push(SkippedFile {
+            path,
+            cause,
+            detail
+        });
+    }
+
+    /// Record the paths of another list.
+    pub fn extend(&mut self, other: SkippedFiles) {
+        self.files.extend(other.files);
+    }
+
+    /// Number of skipped paths.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.files.len()
+    }
+
+    /// Whether nothing was skipped.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+
+    /// Number of paths skipped for a cause.
+    pub fn count(&self, cause: SkipCause) -> usize {
+        self.files.iter().filter(|file| file.cause == cause).count()
+    }
+
+    /// Render the end-of-run summary.
+    ///
+    /// # Arguments
+    ///
+    /// * `list` - Also list every path with its cause, as `--show-skipped` asks
+    ///
+    /// # Returns
+    ///
+    /// Summary line counting the paths per cause, followed by the list if
+    /// requested; `None` if nothing was skipped
+    pub fn summary(&self, list: bool) -> Option<String> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let mut causes: Vec<SkipCause> = self.files.iter().map(|file| file.cause).collect();
+        causes.sort();
+        causes.dedup();
+        let counts: Vec<String> = causes
+            .iter()
+            .map(|cause| format!("{} {cause}", self.count(*cause)))
+            .collect();
+        let plural = if self.len() == 1 { "" } else { "s" };
+        let mut output = format!("Skipped {} path{plural}: {}", self.len(), counts.join(", "));
+
+        if !list {
+            output.push_str(" (list them with --show-skipped)\n");
+            return Some(output);
+        }
+
+        output.push('\n');
+        let mut files: Vec<&SkippedFile> = self.files.iter().collect();
+        files.sort_by(|a, b| a.path.cmp(&b.path));
+        for file in files {
+            match &file.detail {
+                Some(detail) => output.push_str(&format!(
+                    "  {}  {}: {detail}\n",
+                    file.path.display(),
+                    file.cause
+                )),
+                None => output.push_str(&format!("  {}  {}\n", file.path.display(), file.cause))
+            }
+        }
+        Some(output)
+    }
+}
+
+/// Source file ready for analysis, or the reason it was skipped.
+#[derive(Debug)]
+pub enum Source {
+    /// Content and syntax tree of the file
+    Parsed {
+        /// File content
+        content: String,
+        /// Parsed syntax tree
+        ast:     Box<File>
+    },
+    /// The file is left out of the run
+    Skipped(SkippedFile)
+}
+
+/// Read and parse a Rust file, skipping files no analyzer should touch.
+///
+/// Files over [`MAX_FILE_SIZE`], files starting with a generated-file header
+/// and files that do not parse are skipped instead of failing the run.
+///
+/// # Arguments
+///
+/// * `path` - Rust file to load
+///
+/// # Returns
+///
+/// `AppResult<Source>` - Parsed file or skip record, IO error if the file
+/// cannot be read
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::path::Path;
+///
+/// use cargo_quality::skipped::{Source, load_source};
+///
+/// if let Source::Parsed {
+///     content,
+///     ast
+/// } = load_source(Path::new("src/lib.rs")).unwrap()
+/// {
+///     println!("{} items in {} bytes", ast.items.len(), content.len());
+/// }
+/// ```
+pub fn load_source(path: &Path) -> AppResult<Source> {
+    let skip = |cause, detail| {
+        Ok(Source::Skipped(SkippedFile {
+            path: path.to_path_buf(),
+            cause,
+            detail
+        }))
+    };
+
+    let size = fs::metadata(path).map_err(IoError::from)?.len();
+    if size > MAX_FILE_SIZE {
+        return skip(
+            SkipCause::TooLarge,
+            Some(format!(
+                "{} KiB, over {} KiB",
+                size / 1024,
+                MAX_FILE_SIZE / 1024
+            ))
+        );
+    }
+
+    let content = fs::read_to_string(path).map_err(IoError::from)?;
+    if is_generated(&content) {
+        return skip(SkipCause::Generated, None);
+    }
+
+    match syn::parse_file(&content) {
+        Ok(ast) => Ok(Source::Parsed {
+            content,
+            ast: Box::new(ast)
+        }),
+        Err(error) => {
+            let start = error.span().start();
+            skip(
+                SkipCause::ParseError,
+                Some(format!("{}:{}: {error}", start.line, start.column + 1))
+            )
+        }
+    }
+}
+
+/// Checks whether a file starts with a generated-file header.
+///
+/// Tools such as `prost-build` and `bindgen` mark their output with
+/// comments like `// @generated` or `/* automatically generated by
+/// rust-bindgen */` near the top of the file.
+///
+/// # Arguments
+///
+/// * `content` - File content
+///
+/// # Returns
+///
+/// `true` if a comment among the first lines carries a generated marker
+pub fn is_generated(content: &str) -> bool {
+    content
+        .lines()
+        .take(GENERATED_HEADER_LINES)
+        .map(str::trim_start)
+        .filter(|line| line.starts_with("//") || line.starts_with("/*") || line.starts_with('*'))
+        .any(|line| {
+            let line = line.to_lowercase();
+            GENERATED_MARKERS.iter().any(|marker| line.contains(marker))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+
+    fn load(content: &str) -> Source {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("file.rs");
+        fs::write(&path, content).unwrap();
+        load_source(&path).unwrap()
+    }
+
+    fn cause(source: &Source) -> Option<SkipCause> {
+        match source {
+            Source::Skipped(file) => Some(file.cause),
+            Source::Parsed {
+                ..
+            } => None
+        }
+    }
+
+    #[test]
+    fn test_load_source_parsed() {
+        let source = load("fn main() {}\n");
+        assert!(
+            matches!(source, Source::Parsed { ref content, .. } if content == "fn main() {}\n")
+        );
+    }
+
+    #[test]
+    fn test_load_source_parse_error() {
+        let source = load("fn main() {\n    let x = ;\n}\n");
+        assert_eq!(cause(&source), Some(SkipCause::ParseError));
+        let Source::Skipped(file) = source else {
+            unreachable!("checked above");
+        };
+        assert!(file.detail.unwrap().starts_with("2:13: "));
+    }
+
+    #[test]
+    fn test_load_source_generated() {
+        let source = load("// This file is @generated by prost-build.\npub struct Message;\n");
+        assert_eq!(cause(&source), Some(SkipCause::Generated));
+
+        let source =
+            load("/* automatically generated by rust-bindgen 0.69 */\n\npub const A: u32 = 1;\n");
+        assert_eq!(cause(&source), Some(SkipCause::Generated));
+    }
+
+    #[test]
+    fn test_load_source_too_large() {
+        let line = "const VALUE: u32 = 1;\n";
+        let content = line.repeat(MAX_FILE_SIZE as usize / line.len() + 1);
+        assert_eq!(cause(&load(&content)), Some(SkipCause::TooLarge));
+    }
+
+    #[test]
+    fn test_is_generated_only_in_header_comments() {
+        assert!(is_generated("//! Bindings.\n// DO NOT EDIT\n"));
+        assert!(!is_generated(
+            "fn main() {\n    let s = \"@generated\";\n}\n"
+        ));
+        assert!(!is_generated(
+            "fn a() {}\nfn b() {}\nfn c() {}\nfn d() {}\nfn e() {}\n// @generated\n"
+        ));
+    }
+
+    #[test]
+    fn test_summary() {
+        let mut skipped = SkippedFiles::new();
+        assert_eq!(skipped.summary(true), None);
+
+        skipped.add(
+            PathBuf::from("src/broken.rs"),
+            SkipCause::ParseError,
+            Some("2:13: expected expression".to_string())
+        );
+        skipped.add(PathBuf::from("target"), SkipCause::Ignored, None);
+        skipped.add(PathBuf::from("build"), SkipCause::Ignored, None);
+
+        assert_eq!(
+            skipped.summary(false).unwrap(),
+            "Skipped 3 paths: 2 ignored by .gitignore, 1 parse error (list them with \
+             --show-skipped)\n"
+        );
+        assert_eq!(
+            skipped.summary(true).unwrap(),
+            "Skipped 3 paths: 2 ignored by .gitignore, 1 parse error\n  build  ignored by \
+             .gitignore\n  src/broken.rs  parse error: 2:13: expected expression\n  target  \
+             ignored by .gitignore\n"
+        );
+        assert_eq!(skipped.count(SkipCause::Ignored), 2);
+    }
+
+    #[test]
+    fn test_summary_single_path() {
+        let mut skipped = SkippedFiles::new();
+        skipped.add(PathBuf::from("src/schema.rs"), SkipCause::Generated, None);
+
+        assert_eq!(
+            skipped.summary(false).unwrap(),
+            "Skipped 1 path: 1 generated (list them with --show-skipped)\n"
+        );
+    }
+}
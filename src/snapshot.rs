@@ -0,0 +1,369 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Golden-file snapshots of `check` reports.
+//!
+//! `cargo qual check --snapshot .quality-snapshot` writes a normalized report
+//! on the first run and compares against it on every later one. Any change,
+//! whether an issue appeared or disappeared, fails the run with a line diff,
+//! so the snapshot file is reviewed like code. `--update-snapshot` accepts
+//! the current report.
+//!
+//! The normalized report holds no colors, absolute paths or totals that
+//! depend on the terminal: one line per issue, sorted by file, position and
+//! analyzer, with `/` as path separator.
+
+use std::{
+    env, fs,
+    io::ErrorKind,
+    path::{Component, Path, PathBuf}
+};
+
+use masterror::AppResult;
+
+use crate::{error::IoError, report::GlobalReport};
+
+/// Snapshot file of a `check` run.
+///
+/// # Examples
+///
+/// ```
+/// use cargo_quality::snapshot::{Snapshot, SnapshotOutcome};
+///
+/// let dir = tempfile::TempDir::new().unwrap();
+/// let snapshot = Snapshot::new(dir.path().join(".quality-snapshot"), false);
+///
+/// assert_eq!(snapshot.compare("a\n").unwrap(), SnapshotOutcome::Created);
+/// assert_eq!(snapshot.compare("a\n").unwrap(), SnapshotOutcome::Unchanged);
+/// assert!(matches!(
+///     snapshot.compare("b\n").unwrap(),
+///     SnapshotOutcome::Changed(_)
+/// ));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Snapshot {
+    /// Path of the snapshot file
+    path:   PathBuf,
+    /// Whether the file is overwritten instead of compared
+    update: bool
+}
+
+/// Result of comparing a report with its snapshot.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SnapshotOutcome {
+    /// No snapshot existed, so it was written
+    Created,
+    /// The snapshot was overwritten with `--update-snapshot`
+    Updated,
+    /// The report matches the snapshot
+    Unchanged,
+    /// The report differs from the snapshot; holds the line diff
+    Changed(String)
+}
+
+impl Snapshot {
+    /// Creates a snapshot for a file.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path of the snapshot file
+    /// * `update` - Overwrite the file with the current report
+    ///
+    /// # Returns
+    ///
+    /// Snapshot that is compared or written by [`Snapshot::compare`]
+    pub fn new(path: impl Into<PathBuf>, update: bool) -> Self {
+        Self {
+            path: path.into(),
+            update
+        }
+    }
+
+    /// Returns the path of the snapshot file.
+    #[inline]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Compares a rendered report with the snapshot file.
+    ///
+    /// A missing file is created, and with `update` the file is always
+    /// overwritten. An existing file is never modified otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `rendered` - Report rendered with [`render`]
+    ///
+    /// # Returns
+    ///
+    /// `AppResult<SnapshotOutcome>` - Outcome of the comparison, or IO error
+    /// if the file cannot be read or written
+    pub fn compare(&self, rendered: &str) -> AppResult<SnapshotOutcome> {
+        let expected = match fs::read_to_string(&self.path) {
+            Ok(expected) => Some(expected),
+            Err(err) if err.kind() == ErrorKind::NotFound => None,
+            Err(err) => return Err(IoError::from(err).into())
+        };
+
+        match expected {
+            Some(expected) if expected == rendered => Ok(SnapshotOutcome::Unchanged),
+            Some(expected) if !self.update => {
+                Ok(SnapshotOutcome::Changed(line_diff(&expected, rendered)))
+            }
+            existing => {
+                fs::write(&self.path, rendered).map_err(IoError::from)?;
+                Ok(match existing {
+                    Some(_) => SnapshotOutcome::Updated,
+                    None => SnapshotOutcome::Created
+                })
+            }
+        }
+    }
+}
+
+/// Renders a report in the normalized snapshot format.
+///
+/// Every issue becomes `file:line:column [analyzer] message`; further lines
+/// of the message follow, indented by four spaces. Paths are made relative
+/// to the working directory and use `/`.
+///
+/// # Arguments
+///
+/// * `report` - Report of the run
+///
+/// # Returns
+///
+/// Normalized report, ending with the issue count
+///
+/// # Examples
+///
+/// ```
+/// use cargo_quality::{
+///     analyzer::{AnalysisResult, Fix, Issue},
+///     report::{GlobalReport, Report},
+///     snapshot::render
+/// };
+///
+/// let mut report = Report::new("./src/lib.rs".to_string());
+/// report.add_result(
+///     "unwrap".to_string(),
+///     AnalysisResult {
+///         issues:        vec![Issue {
+///             line:    3,
+///             column:  8,
+///             message: "Avoid unwrap".to_string(),
+///             fix:     Fix::None
+///         }],
+///         fixable_count: 0
+///     }
+/// );
+/// let mut global = GlobalReport::new();
+/// global.add_report(report);
+///
+/// assert_eq!(
+///     render(&global),
+///     "src/lib.rs:3:8 [unwrap] Avoid unwrap\n\n1 issues\n"
+/// );
+/// ```
+pub fn render(report: &GlobalReport) -> String {
+    let mut issues: Vec<_> = report
+        .reports
+        .iter()
+        .flat_map(|file| {
+            let path = normalize_path(&file.file_path);
+            file.results.iter().flat_map(move |(name, result)| {
+                let path = path.clone();
+                result.issues.iter().map(move |issue| {
+                    (path.clone(), issue.line, issue.column, name, &issue.message)
+                })
+            })
+        })
+        .collect();
+    issues.sort();
+
+    let mut output = String::new();
+    for (path, line, column, name, message) in &issues {
+        let mut lines = message.lines();
+        output.push_str(&format!(
+            "{path}:{line}:{column} [{name}] {}\n",
+            lines.next().unwrap_or_default()
+        ));
+        for rest in lines {
+            output.push_str(&format!("    {rest}\n"));
+        }
+    }
+    output.push_str(&format!("\n{} issues\n", issues.len()));
+    output
+}
+
+/// Make a reported path stable across machines and checkouts.
+pub(crate) fn normalize_path(path: &str) -> String {
+    let path = Path::new(path);
+    let relative = env::current_dir()
+        .ok()
+        .and_then(|dir| path.strip_prefix(dir).ok())
+        .unwrap_or(path);
+
+    let segments: Vec<_> = relative
+        .components()
+        .filter_map(|component| match component {
+            Component::CurDir => None,
+            Component::RootDir => Some(String::new()),
+            component => Some(component.as_os_str().to_string_lossy().into_owned())
+        })
+        .collect();
+    segments.join("/")
+}
+
+/// Diff two texts line by line.
+///
+/// The common head and tail are skipped, and the changed middle is aligned
+/// on its longest common subsequence. Removed lines start with `-`, added
+/// lines with `+`, and the unchanged lines in between with two spaces.
+///
+/// # Arguments
+///
+/// * `expected` - Snapshot content
+/// * `actual` - Current report
+///
+/// # Returns
+///
+/// Changed lines in order, each prefixed with `-`, `+` or spaces
+fn line_diff(expected: &str, actual: &str) -> String {
+    let old: Vec<&str> = expected.lines().collect();
+    let new: Vec<&str> = actual.lines().collect();
+
+    let head = old
+        .iter()
+        .zip(&new)
+        .take_while(|(old, new)| old == new)
+        .count();
+    let tail = old[head..]
+        .iter()
+        .rev()
+        .zip(new[head..].iter().rev())
+        .take_while(|(old, new)| old == new)
+        .count();
+    let old = &old[head..old.len() - tail];
+    let new = &new[head..new.len() - tail];
+
+    let mut lengths = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lengths[i][j] = if old[i] == new[j] {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+        }
+    }
+
+    let mut output = String::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            output.push_str(&format!("  {}\n", old[i]));
+            i += 1;
+            j += 1;
+        } else if i < old.len() && (j == new.len() || lengths[i + 1][j] >= lengths[i][j + 1]) {
+            output.push_str(&format!("- {}\n", old[i]));
+            i += 1;
+        } else {
+            output.push_str(&format!("+ {}\n", new[j]));
+            j += 1;
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::{
+        analyzer::{AnalysisResult, Fix, Issue},
+        report::Report
+    };
+
+    fn report(files: &[(&str, &str, usize, &str)]) -> GlobalReport {
+        let mut global = GlobalReport::new();
+        for (file, analyzer, line, message) in files {
+            let mut report = Report::new(file.to_string());
+            report.add_result(
+                analyzer.to_string(),
+                AnalysisResult {
+                    issues:        vec![Issue {
+                        line:    *line,
+                        column:  0,
+                        message: message.to_string(),
+                        fix:     Fix::None
+                    }],
+                    fixable_count: 0
+                }
+            );
+            global.add_report(report);
+        }
+        global
+    }
+
+    #[test]
+    fn test_render_sorted_and_normalized() {
+        let global = report(&[
+            ("./src/main.rs", "unwrap", 4, "Avoid unwrap\nUse `?`"),
+            ("src/lib.rs", "todo_comments", 9, "TODO comment"),
+            ("./src/lib.rs", "path_import", 2, "Use import")
+        ]);
+
+        assert_eq!(
+            render(&global),
+            "src/lib.rs:2:0 [path_import] Use import\n\
+             src/lib.rs:9:0 [todo_comments] TODO comment\n\
+             src/main.rs:4:0 [unwrap] Avoid unwrap\n    Use `?`\n\
+             \n3 issues\n"
+        );
+    }
+
+    #[test]
+    fn test_render_relative_to_working_directory() {
+        let file = env::current_dir().unwrap().join("src/lib.rs");
+        let global = report(&[(file.to_str().unwrap(), "unwrap", 1, "Avoid unwrap")]);
+
+        assert!(render(&global).starts_with("src/lib.rs:1:0 "));
+    }
+
+    #[test]
+    fn test_compare_and_update() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("snapshot");
+
+        let snapshot = Snapshot::new(&path, false);
+        assert_eq!(
+            snapshot.compare("a\nb\n").unwrap(),
+            SnapshotOutcome::Created
+        );
+        assert_eq!(
+            snapshot.compare("a\nb\n").unwrap(),
+            SnapshotOutcome::Unchanged
+        );
+        assert_eq!(
+            snapshot.compare("a\nc\n").unwrap(),
+            SnapshotOutcome::Changed("- b\n+ c\n".to_string())
+        );
+        assert_eq!(fs::read_to_string(&path).unwrap(), "a\nb\n");
+
+        let update = Snapshot::new(&path, true);
+        assert_eq!(update.compare("a\nc\n").unwrap(), SnapshotOutcome::Updated);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "a\nc\n");
+        assert_eq!(update.path(), path);
+    }
+
+    #[test]
+    fn test_line_diff() {
+        assert_eq!(
+            line_diff("a\nb\nc\nd\n", "a\nc\nd\ne\n"),
+            "- b\n  c\n  d\n+ e\n"
+        );
+        assert_eq!(line_diff("x\n", "x\ny\n"), "+ y\n");
+    }
+}
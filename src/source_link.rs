@@ -0,0 +1,222 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Links from reported issues to the offending line on the source host.
+//!
+//! Markdown reports and PR comments link every issue to
+//! `<repository>/blob/<commit>/<file>#L<line>`, so reviewers can jump
+//! straight to the code. The repository URL comes from
+//! `metadata.quality.repository` or `package.repository` in `Cargo.toml`,
+//! and in GitHub Actions from the workflow environment. The commit and the
+//! checkout root are read from git; pinning the commit keeps the links valid
+//! after the branch moves on.
+
+use std::{
+    env, fs,
+    path::{Component, Path, PathBuf},
+    process::Command
+};
+
+/// Builds source hosting URLs for files of a git checkout.
+///
+/// # Examples
+///
+/// ```
+/// use cargo_quality::source_link::SourceLinks;
+///
+/// let links = SourceLinks::new("https://github.com/org/repo.git", "abc123", "/work/repo");
+/// assert_eq!(
+///     links.url("/work/repo/src/lib.rs", 12).as_deref(),
+///     Some("https://github.com/org/repo/blob/abc123/src/lib.rs#L12")
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceLinks {
+    /// Repository web URL, without trailing `/` or `.git`
+    repository: String,
+    /// Commit the links point at
+    commit:     String,
+    /// Root directory of the checkout
+    root:       PathBuf
+}
+
+impl SourceLinks {
+    /// Creates links for a checkout of a repository at a commit.
+    ///
+    /// # Arguments
+    ///
+    /// * `repository` - Repository web URL; a trailing `/` or `.git` is dropped
+    /// * `commit` - Commit SHA the links point at
+    /// * `root` - Root directory of the checkout
+    ///
+    /// # Returns
+    ///
+    /// Link builder for files under `root`
+    pub fn new(repository: &str, commit: impl Into<String>, root: impl Into<PathBuf>) -> Self {
+        let repository = repository.trim().trim_end_matches('/');
+        let repository = repository.strip_suffix(".git").unwrap_or(repository);
+
+        Self {
+            repository: repository.to_string(),
+            commit:     commit.into(),
+            root:       root.into()
+        }
+    }
+
+    /// Detects the repository, commit and checkout root of a path.
+    ///
+    /// The configured repository wins; without one, GitHub Actions'
+    /// `GITHUB_SERVER_URL` and `GITHUB_REPOSITORY` are used. The commit and
+    /// root are read with `git rev-parse`.
+    ///
+    /// # Arguments
+    ///
+    /// * `repository` - Repository URL from the project configuration
+    /// * `path` - Analyzed file or directory
+    ///
+    /// # Returns
+    ///
+    /// Link builder, or `None` if no repository is known or `path` is not in
+    /// a git checkout
+    pub fn detect(repository: Option<&str>, path: &Path) -> Option<Self> {
+        let repository = match repository {
+            Some(repository) => repository.to_string(),
+            None => github_actions_repository()?
+        };
+
+        let dir = match path.parent() {
+            _ if path.is_dir() => path,
+            Some(parent) if !parent.as_os_str().is_empty() => parent,
+            _ => Path::new(".")
+        };
+        let output = Command::new("git")
+            .arg("-C")
+            .arg(dir)
+            .args(["rev-parse", "--show-toplevel", "HEAD"])
+            .output()
+            .ok()
+            .filter(|output| output.status.success())?;
+        let stdout = String::from_utf8(output.stdout).ok()?;
+        let mut lines = stdout.lines();
+        let root = fs::canonicalize(lines.next()?).ok()?;
+        let commit = lines.next()?;
+
+        Some(Self::new(&repository, commit, root))
+    }
+
+    /// Builds the URL of a line of a file.
+    ///
+    /// # Arguments
+    ///
+    /// * `file` - Path of the file, absolute or relative to the working
+    ///   directory
+    /// * `line` - 1-based line number; `0` links to the whole file
+    ///
+    /// # Returns
+    ///
+    /// URL of the line, or `None` if the file is outside the checkout
+    pub fn url(&self, file: &str, line: usize) -> Option<String> {
+        let file = Path::new(file);
+        let relative = fs::canonicalize(file)
+            .ok()
+            .and_then(|absolute| {
+                absolute
+                    .strip_prefix(&self.root)
+                    .ok()
+                    .map(Path::to_path_buf)
+            })
+            .or_else(|| file.strip_prefix(&self.root).ok().map(Path::to_path_buf))?;
+
+        let segments: Vec<String> = relative
+            .components()
+            .map(|component| match component {
+                Component::Normal(segment) => Some(encode_segment(&segment.to_string_lossy())),
+                _ => None
+            })
+            .collect::<Option<_>>()?;
+
+        let mut url = format!(
+            "{}/blob/{}/{}",
+            self.repository,
+            self.commit,
+            segments.join("/")
+        );
+        if line > 0 {
+            url.push_str(&format!("#L{line}"));
+        }
+        Some(url)
+    }
+}
+
+/// Repository URL of the running GitHub Actions workflow.
+fn github_actions_repository() -> Option<String> {
+    let server = env::var("GITHUB_SERVER_URL").ok()?;
+    let repository = env::var("GITHUB_REPOSITORY").ok()?;
+    Some(format!("{server}/{repository}"))
+}
+
+/// Percent-encode the characters of a path segment that break URLs.
+fn encode_segment(segment: &str) -> String {
+    segment
+        .chars()
+        .map(|c| match c {
+            ' ' => "%20".to_string(),
+            '#' => "%23".to_string(),
+            '?' => "%3F".to_string(),
+            '%' => "%25".to_string(),
+            c => c.to_string()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+
+    #[test]
+    fn test_repository_normalized() {
+        let links = SourceLinks::new("https://gitlab.com/org/repo/", "sha", "/repo");
+        assert_eq!(
+            links.url("/repo/src/main.rs", 3).unwrap(),
+            "https://gitlab.com/org/repo/blob/sha/src/main.rs#L3"
+        );
+
+        let links = SourceLinks::new("https://github.com/org/repo.git", "sha", "/repo");
+        assert_eq!(
+            links.url("/repo/Cargo.toml", 0).unwrap(),
+            "https://github.com/org/repo/blob/sha/Cargo.toml"
+        );
+    }
+
+    #[test]
+    fn test_url_outside_checkout() {
+        let links = SourceLinks::new("https://github.com/org/repo", "sha", "/repo");
+        assert!(links.url("/elsewhere/lib.rs", 1).is_none());
+    }
+
+    #[test]
+    fn test_url_normalizes_path() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = fs::canonicalize(temp_dir.path()).unwrap();
+        fs::create_dir(root.join("my src")).unwrap();
+        fs::write(root.join("my src/lib.rs"), "").unwrap();
+        let links = SourceLinks::new("https://github.com/org/repo", "sha", &root);
+
+        let file = root.join("my src/../my src/lib.rs");
+        assert_eq!(
+            links.url(file.to_str().unwrap(), 7).unwrap(),
+            "https://github.com/org/repo/blob/sha/my%20src/lib.rs#L7"
+        );
+    }
+
+    #[test]
+    fn test_detect_without_git() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("lib.rs");
+        fs::write(&path, "").unwrap();
+
+        assert!(SourceLinks::detect(Some("https://github.com/org/repo"), &path).is_none());
+    }
+}
@@ -0,0 +1,340 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Item-level suppressions written as `#[quality::allow(rule, ...)]`.
+//!
+//! The `cargo-quality-attributes` crate provides `allow` as an attribute that
+//! expands to the item unchanged but rejects unknown rule names at compile
+//! time, so suppressions are typed and cannot silently rot after a typo or a
+//! rename. This module finds those attributes and drops the issues and fix
+//! suggestions of the named analyzers that fall inside the annotated item.
+
+use std::ops::Range;
+
+use syn::{
+    Attribute, File, ImplItem, Item, Path, TraitItem, punctuated::Punctuated, spanned::Spanned,
+    visit::Visit
+};
+
+use crate::{
+    analyzer::{AnalysisResult, Suggestion},
+    analyzers::test_modules::item_attrs
+};
+
+/// Path of the suppression attribute.
+pub const ALLOW_ATTRIBUTE: &str = "quality::allow";
+
+/// Item annotated with `#[quality::allow(...)]`.
+#[derive(Debug, Clone)]
+struct Region {
+    rules: Vec<String>,
+    lines: Range<usize>,
+    bytes: Range<usize>
+}
+
+/// Suppressions declared in a source file.
+///
+/// # Examples
+///
+/// ```
+/// use cargo_quality::{
+///     analyzer::Analyzer, analyzers::UnwrapAnalyzer, suppression::Suppressions
+/// };
+///
+/// let code = "#[quality::allow(unwrap)]\nfn f() { let x = y.unwrap(); }";
+/// let ast = syn::parse_file(code).unwrap();
+/// let suppressions = Suppressions::from_ast(&ast);
+///
+/// let result = UnwrapAnalyzer::new().analyze(&ast, code).unwrap();
+/// assert_eq!(result.issues.len(), 1);
+/// assert!(
+///     suppressions
+///         .filter_result("unwrap", result)
+///         .issues
+///         .is_empty()
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Suppressions {
+    regions: Vec<Region>
+}
+
+impl Suppressions {
+    /// Collects the suppressions of a parsed file.
+    ///
+    /// Items, impl items and trait items at any depth are considered.
+    ///
+    /// # Arguments
+    ///
+    /// * `ast` - Parsed file
+    ///
+    /// # Returns
+    ///
+    /// Suppressions declared in the file
+    pub fn from_ast(ast: &File) -> Self {
+        let mut visitor = AllowVisitor {
+            regions: Vec::new()
+        };
+        visitor.visit_file(ast);
+
+        Self {
+            regions: visitor.regions
+        }
+    }
+
+    /// Checks whether the file declares no suppressions.
+    ///
+    /// # Returns
+    ///
+    /// `true` if no item carries `#[quality::allow(...)]`
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.regions.is_empty()
+    }
+
+    /// Checks whether a rule is allowed on a line.
+    ///
+    /// # Arguments
+    ///
+    /// * `rule` - Analyzer name
+    /// * `line` - 1-based line number
+    ///
+    /// # Returns
+    ///
+    /// `true` if an item spanning the line allows the rule
+    pub fn allows_line(&self, rule: &str, line: usize) -> bool {
+        self.regions
+            .iter()
+            .any(|region| region.lines.contains(&line) && region.allows(rule))
+    }
+
+    /// Checks whether a rule is allowed at a byte offset.
+    ///
+    /// The end of an item counts as inside it, so insertions right after
+    /// the annotated item are suppressed too.
+    ///
+    /// # Arguments
+    ///
+    /// * `rule` - Analyzer name
+    /// * `offset` - Byte offset in the source
+    ///
+    /// # Returns
+    ///
+    /// `true` if an item covering the offset allows the rule
+    pub fn allows_offset(&self, rule: &str, offset: usize) -> bool {
+        self.regions.iter().any(|region| {
+            region.bytes.start <= offset && offset <= region.bytes.end && region.allows(rule)
+        })
+    }
+
+    /// Removes suppressed issues from an analysis result.
+    ///
+    /// # Arguments
+    ///
+    /// * `rule` - Name of the analyzer that produced the result
+    /// * `result` - Analysis result
+    ///
+    /// # Returns
+    ///
+    /// The result without issues inside items allowing the rule
+    pub fn filter_result(&self, rule: &str, mut result: AnalysisResult) -> AnalysisResult {
+        if self.is_empty() {
+            return result;
+        }
+
+        let (suppressed, kept) = result
+            .issues
+            .into_iter()
+            .partition::<Vec<_>, _>(|issue| self.allows_line(rule, issue.line));
+        let suppressed_fixable = suppressed
+            .iter()
+            .filter(|issue| issue.fix.is_available())
+            .count();
+
+        result.issues = kept;
+        result.fixable_count = result.fixable_count.saturating_sub(suppressed_fixable);
+        result
+    }
+
+    /// Removes suppressed fix suggestions.
+    ///
+    /// # Arguments
+    ///
+    /// * `rule` - Name of the analyzer that produced the suggestions
+    /// * `suggestions` - Fix suggestions
+    ///
+    /// # Returns
+    ///
+    /// Suggestions whose edit does not start inside an item allowing the rule
+    pub fn filter_suggestions(&self, rule: &str, suggestions: Vec<Suggestion>) -> Vec<Suggestion> {
+        suggestions
+            .into_iter()
+            .filter(|suggestion| !self.allows_offset(rule, suggestion.edit.range.start))
+            .collect()
+    }
+}
+
+impl Region {
+    /// Check whether the region allows a rule.
+    fn allows(&self, rule: &str) -> bool {
+        self.rules.iter().any(|allowed| allowed == rule)
+    }
+}
+
+struct AllowVisitor {
+    regions: Vec<Region>
+}
+
+impl AllowVisitor {
+    /// Record the item if its attributes allow any rules.
+    fn record(&mut self, attrs: &[Attribute], node: &impl Spanned) {
+        let rules = allowed_rules(attrs);
+        if rules.is_empty() {
+            return;
+        }
+
+        let span = node.span();
+        self.regions.push(Region {
+            rules,
+            lines: span.start().line..span.end().line + 1,
+            bytes: span.byte_range()
+        });
+    }
+}
+
+impl<'ast> Visit<'ast> for AllowVisitor {
+    fn visit_item(&mut self, node: &'ast Item) {
+        self.record(item_attrs(node), node);
+        syn::visit::visit_item(self, node);
+    }
+
+    fn visit_impl_item(&mut self, node: &'ast ImplItem) {
+        let attrs = match node {
+            ImplItem::Const(item) => &item.attrs,
+            ImplItem::Fn(item) => &item.attrs,
+            ImplItem::Type(item) => &item.attrs,
+            ImplItem::Macro(item) => &item.attrs,
+            _ => return syn::visit::visit_impl_item(self, node)
+        };
+        self.record(attrs, node);
+        syn::visit::visit_impl_item(self, node);
+    }
+
+    fn visit_trait_item(&mut self, node: &'ast TraitItem) {
+        let attrs = match node {
+            TraitItem::Const(item) => &item.attrs,
+            TraitItem::Fn(item) => &item.attrs,
+            TraitItem::Type(item) => &item.attrs,
+            TraitItem::Macro(item) => &item.attrs,
+            _ => return syn::visit::visit_trait_item(self, node)
+        };
+        self.record(attrs, node);
+        syn::visit::visit_trait_item(self, node);
+    }
+}
+
+/// Rule names listed in `#[quality::allow(...)]` attributes.
+fn allowed_rules(attrs: &[Attribute]) -> Vec<String> {
+    attrs
+        .iter()
+        .filter(|attr| is_allow(attr.path()))
+        .filter_map(|attr| {
+            attr.parse_args_with(Punctuated::<Path, syn::Token![,]>::parse_terminated)
+                .ok()
+        })
+        .flatten()
+        .filter_map(|rule| rule.get_ident().map(ToString::to_string))
+        .collect()
+}
+
+/// Check whether an attribute path is `quality::allow`.
+fn is_allow(path: &Path) -> bool {
+    let segments: Vec<String> = path
+        .segments
+        .iter()
+        .map(|segment| segment.ident.to_string())
+        .collect();
+    segments.join("::") == ALLOW_ATTRIBUTE
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzer::{Fix, Issue, TextEdit};
+
+    fn issue(line: usize) -> Issue {
+        Issue {
+            line,
+            column: 1,
+            message: "issue".to_string(),
+            fix: Fix::Simple("fix".to_string())
+        }
+    }
+
+    #[test]
+    fn test_allow_covers_annotated_items() {
+        let code = r#"fn before() {}
+
+#[quality::allow(unwrap, empty_lines)]
+fn allowed() {
+    run();
+}
+
+impl Worker {
+    #[quality::allow(function_length)]
+    fn long(&self) {}
+
+    fn short(&self) {}
+}
+"#;
+        let suppressions = Suppressions::from_ast(&syn::parse_file(code).unwrap());
+
+        assert!(!suppressions.allows_line("unwrap", 1));
+        assert!(suppressions.allows_line("unwrap", 3));
+        assert!(suppressions.allows_line("empty_lines", 5));
+        assert!(!suppressions.allows_line("function_length", 5));
+        assert!(suppressions.allows_line("function_length", 10));
+        assert!(!suppressions.allows_line("function_length", 12));
+    }
+
+    #[test]
+    fn test_ignore_other_attributes() {
+        let code = "#[allow(unwrap)]\n#[other::allow(unwrap)]\nfn f() {}";
+        let suppressions = Suppressions::from_ast(&syn::parse_file(code).unwrap());
+
+        assert!(suppressions.is_empty());
+    }
+
+    #[test]
+    fn test_filter_result_and_suggestions() {
+        let code = "fn a() {}\n#[quality::allow(unwrap)]\nfn b() {}\n";
+        let suppressions = Suppressions::from_ast(&syn::parse_file(code).unwrap());
+
+        let result = suppressions.filter_result(
+            "unwrap",
+            AnalysisResult {
+                issues:        vec![issue(1), issue(3)],
+                fixable_count: 2
+            }
+        );
+        assert_eq!(result.issues.len(), 1);
+        assert_eq!(result.issues[0].line, 1);
+        assert_eq!(result.fixable_count, 1);
+
+        let suggestion = |start: usize| Suggestion {
+            edit:   TextEdit {
+                range:       start..start,
+                replacement: String::new()
+            },
+            import: None
+        };
+        let kept = suppressions.filter_suggestions("unwrap", vec![suggestion(3), suggestion(40)]);
+        assert_eq!(kept, vec![suggestion(3)]);
+        assert_eq!(
+            suppressions
+                .filter_suggestions("path_import", vec![suggestion(40)])
+                .len(),
+            1
+        );
+    }
+}
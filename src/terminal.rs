@@ -0,0 +1,243 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Terminal width detection for the text layouts.
+//!
+//! The grid layouts of the check report and the diff adapt to the terminal
+//! width. The width comes from `--width`, then the `COLUMNS` environment
+//! variable, then the terminal itself. Below [`NARROW_WIDTH`] columns both
+//! layouts fall back to a single column with lines wrapped to the width.
+//!
+//! Legacy Windows consoles print ANSI escape sequences verbatim until
+//! virtual terminal processing is switched on; [`enable_ansi_colors`] does
+//! that once at startup.
+
+use std::{env, mem};
+
+use console::{AnsiCodeIterator, measure_text_width};
+use terminal_size::{Width, terminal_size};
+use unicode_width::UnicodeWidthChar;
+
+/// Widths below this use the narrow single-column layout.
+pub const NARROW_WIDTH: usize = 60;
+
+/// Width of the layouts with `--deterministic`, whatever the terminal.
+pub const DETERMINISTIC_WIDTH: usize = 120;
+
+/// ANSI escape sequence resetting all styles.
+const RESET: &str = "\x1b[0m";
+
+/// Resolves the width to lay the output out for.
+///
+/// # Arguments
+///
+/// * `width` - Width given with `--width`
+/// * `default` - Width used when neither `COLUMNS` nor the terminal tell
+///
+/// # Returns
+///
+/// The first positive width of the override, the `COLUMNS` environment
+/// variable and the detected terminal, or `default`
+///
+/// # Examples
+///
+/// ```
+/// use cargo_quality::terminal::terminal_width;
+///
+/// assert_eq!(terminal_width(Some(72), 80), 72);
+/// ```
+pub fn terminal_width(width: Option<usize>, default: usize) -> usize {
+    resolve_width(
+        width,
+        env::var("COLUMNS").ok().as_deref(),
+        terminal_size().map(|(Width(w), _)| usize::from(w)),
+        default
+    )
+}
+
+/// Picks the width from its sources in order of precedence.
+fn resolve_width(
+    width: Option<usize>,
+    columns: Option<&str>,
+    detected: Option<usize>,
+    default: usize
+) -> usize {
+    width
+        .or_else(|| columns.and_then(|columns| columns.trim().parse().ok()))
+        .filter(|width| *width > 0)
+        .or(detected.filter(|width| *width > 0))
+        .unwrap_or(default)
+}
+
+/// Checks whether a width needs the narrow single-column layout.
+///
+/// # Examples
+///
+/// ```
+/// use cargo_quality::terminal::is_narrow;
+///
+/// assert!(is_narrow(40));
+/// assert!(!is_narrow(80));
+/// ```
+#[inline]
+pub const fn is_narrow(width: usize) -> bool {
+    width < NARROW_WIDTH
+}
+
+/// Prepares standard output for ANSI colors.
+///
+/// On Windows this switches on virtual terminal processing of the console,
+/// which `cmd.exe` and Windows PowerShell leave off. Elsewhere terminals
+/// interpret ANSI escape sequences already and nothing is done.
+///
+/// # Returns
+///
+/// `false` if standard output is a console that cannot render colors,
+/// `true` otherwise, including when the output is redirected
+pub fn enable_ansi_colors() -> bool {
+    #[cfg(windows)]
+    {
+        let term = console::Term::stdout();
+        !term.is_term() || term.features().colors_supported()
+    }
+    #[cfg(not(windows))]
+    {
+        true
+    }
+}
+
+/// Wraps a line to a visual width, keeping its ANSI colors intact.
+///
+/// Lines break between characters and at line breaks of multi-line
+/// messages, never inside an escape sequence. A
+/// style active at a break is reset at the end of the line and restored at
+/// the start of the next, so colors do not bleed into the padding.
+///
+/// # Arguments
+///
+/// * `line` - Line, possibly with ANSI escape sequences
+/// * `width` - Maximum visual width of each line
+///
+/// # Returns
+///
+/// The line split into parts no wider than `width`
+///
+/// # Examples
+///
+/// ```
+/// use cargo_quality::terminal::wrap_line;
+///
+/// assert_eq!(wrap_line("abcdef", 4), vec!["abcd", "ef"]);
+/// ```
+pub fn wrap_line(line: &str, width: usize) -> Vec<String> {
+    let width = width.max(1);
+    if measure_text_width(line) <= width && !line.contains('\n') {
+        return vec![line.to_string()];
+    }
+
+    let mut lines = Vec::new();
+    let mut current = String::new();
+    let mut current_width = 0;
+    let mut active = String::new();
+
+    for (part, is_ansi) in AnsiCodeIterator::new(line) {
+        if is_ansi {
+            if part == RESET {
+                active.clear();
+            } else {
+                active.push_str(part);
+            }
+            current.push_str(part);
+            continue;
+        }
+
+        for ch in part.chars() {
+            let ch_width = ch.width().unwrap_or(0);
+            let newline = ch == '\n';
+            if newline || (current_width > 0 && current_width + ch_width > width) {
+                if !active.is_empty() {
+                    current.push_str(RESET);
+                }
+                lines.push(mem::take(&mut current));
+                current.push_str(&active);
+                current_width = 0;
+            }
+            if newline {
+                continue;
+            }
+            current.push(ch);
+            current_width += ch_width;
+        }
+    }
+    lines.push(current);
+
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_width_precedence() {
+        assert_eq!(resolve_width(Some(50), Some("100"), Some(120), 80), 50);
+        assert_eq!(resolve_width(None, Some("100"), Some(120), 80), 100);
+        assert_eq!(resolve_width(None, None, Some(120), 80), 120);
+        assert_eq!(resolve_width(None, None, None, 80), 80);
+    }
+
+    #[test]
+    fn test_resolve_width_ignores_invalid_columns() {
+        assert_eq!(resolve_width(None, Some("wide"), Some(120), 80), 120);
+        assert_eq!(resolve_width(None, Some("0"), None, 80), 80);
+        assert_eq!(resolve_width(None, Some(" 72 "), None, 80), 72);
+    }
+
+    #[cfg(not(windows))]
+    #[test]
+    fn test_enable_ansi_colors() {
+        assert!(enable_ansi_colors());
+    }
+
+    #[test]
+    fn test_is_narrow() {
+        assert!(is_narrow(59));
+        assert!(!is_narrow(60));
+    }
+
+    #[test]
+    fn test_wrap_line_plain() {
+        assert_eq!(wrap_line("short", 10), vec!["short"]);
+        assert_eq!(wrap_line("abcdefghij", 4), vec!["abcd", "efgh", "ij"]);
+    }
+
+    #[test]
+    fn test_wrap_line_keeps_colors() {
+        let line = "\x1b[31mabcdef\x1b[0m tail";
+        let wrapped = wrap_line(line, 4);
+
+        assert_eq!(
+            wrapped,
+            vec!["\x1b[31mabcd\x1b[0m", "\x1b[31mef\x1b[0m t", "ail"]
+        );
+        for part in &wrapped {
+            assert!(measure_text_width(part) <= 4);
+        }
+    }
+
+    #[test]
+    fn test_wrap_line_breaks_at_newlines() {
+        assert_eq!(
+            wrap_line("Too wide\nBreak it up", 8),
+            vec!["Too wide", "Break it", " up"]
+        );
+    }
+
+    #[test]
+    fn test_wrap_line_wide_characters() {
+        let wrapped = wrap_line("日本語テキスト", 5);
+
+        assert!(wrapped.iter().all(|part| measure_text_width(part) <= 5));
+        assert_eq!(wrapped.concat(), "日本語テキスト");
+    }
+}